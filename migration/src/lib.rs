@@ -9,6 +9,27 @@ mod m20260111_201358_create_checkins;
 mod m20260111_201427_create_boarding_passes;
 mod m20260111_201209_add_email_verification_resend_tracking;
 mod m20260111_201309_add_login_tracking_fields;
+mod m20261019_090000_add_passenger_accompanying_adult;
+mod m20261019_100000_add_boarding_pass_revoked_at;
+mod m20261019_110000_add_boarding_pass_wallet_version;
+mod m20261019_120000_add_checkin_boarded_at;
+mod m20261019_130000_add_booking_fare_family;
+mod m20261019_130100_add_flight_boarding_sequence;
+mod m20261019_140000_create_no_shows;
+mod m20261019_150000_add_checkin_excess_baggage;
+mod m20261019_160000_create_bags;
+mod m20261019_170000_create_airlines;
+mod m20261019_180000_create_aircraft;
+mod m20261019_190000_add_airport_search_indexes;
+mod m20261019_200000_create_routes;
+mod m20261019_210000_create_flight_codeshares;
+mod m20261019_220000_create_flight_status_events;
+mod m20261019_230000_create_compensation_claims;
+mod m20261020_090000_create_notifications;
+mod m20261020_100000_create_dead_letters;
+mod m20261020_110000_add_checkin_baggage_piece_weights;
+mod m20261020_120000_backfill_flight_airlines;
+mod m20261020_130000_add_checkin_standby_status;
 pub mod helpers;
 
 pub struct Migrator;
@@ -27,6 +48,27 @@ impl MigratorTrait for Migrator {
             Box::new(m20260111_201427_create_boarding_passes::Migration),
             Box::new(m20260111_201209_add_email_verification_resend_tracking::Migration),
             Box::new(m20260111_201309_add_login_tracking_fields::Migration),
+            Box::new(m20261019_090000_add_passenger_accompanying_adult::Migration),
+            Box::new(m20261019_100000_add_boarding_pass_revoked_at::Migration),
+            Box::new(m20261019_110000_add_boarding_pass_wallet_version::Migration),
+            Box::new(m20261019_120000_add_checkin_boarded_at::Migration),
+            Box::new(m20261019_130000_add_booking_fare_family::Migration),
+            Box::new(m20261019_130100_add_flight_boarding_sequence::Migration),
+            Box::new(m20261019_140000_create_no_shows::Migration),
+            Box::new(m20261019_150000_add_checkin_excess_baggage::Migration),
+            Box::new(m20261019_160000_create_bags::Migration),
+            Box::new(m20261019_170000_create_airlines::Migration),
+            Box::new(m20261019_180000_create_aircraft::Migration),
+            Box::new(m20261019_190000_add_airport_search_indexes::Migration),
+            Box::new(m20261019_200000_create_routes::Migration),
+            Box::new(m20261019_210000_create_flight_codeshares::Migration),
+            Box::new(m20261019_220000_create_flight_status_events::Migration),
+            Box::new(m20261019_230000_create_compensation_claims::Migration),
+            Box::new(m20261020_090000_create_notifications::Migration),
+            Box::new(m20261020_100000_create_dead_letters::Migration),
+            Box::new(m20261020_110000_add_checkin_baggage_piece_weights::Migration),
+            Box::new(m20261020_120000_backfill_flight_airlines::Migration),
+            Box::new(m20261020_130000_add_checkin_standby_status::Migration),
         ]
     }
 }
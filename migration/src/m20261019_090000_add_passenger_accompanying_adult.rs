@@ -0,0 +1,55 @@
+use crate::helpers::exec_unprepared;
+use sea_orm_migration::prelude::*;
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        // accompanying_adult_id (infant -> adult on the same booking)
+        exec_unprepared(
+            manager,
+            r#"
+            ALTER TABLE passengers
+            ADD COLUMN IF NOT EXISTS accompanying_adult_id bigint NULL
+            REFERENCES passengers(id) ON DELETE SET NULL;
+            "#,
+        )
+        .await?;
+
+        // one infant per adult
+        exec_unprepared(
+            manager,
+            r#"
+            CREATE UNIQUE INDEX IF NOT EXISTS uq_passengers_accompanying_adult_id
+            ON passengers (accompanying_adult_id)
+            WHERE accompanying_adult_id IS NOT NULL;
+            "#,
+        )
+        .await?;
+
+        Ok(())
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        exec_unprepared(
+            manager,
+            r#"
+            DROP INDEX IF EXISTS uq_passengers_accompanying_adult_id;
+            "#,
+        )
+        .await?;
+
+        exec_unprepared(
+            manager,
+            r#"
+            ALTER TABLE passengers
+            DROP COLUMN IF EXISTS accompanying_adult_id;
+            "#,
+        )
+        .await?;
+
+        Ok(())
+    }
+}
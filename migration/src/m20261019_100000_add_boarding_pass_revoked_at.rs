@@ -0,0 +1,35 @@
+use crate::helpers::exec_unprepared;
+use sea_orm_migration::prelude::*;
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        // revoked passes fail verification at the gate
+        exec_unprepared(
+            manager,
+            r#"
+            ALTER TABLE boarding_passes
+            ADD COLUMN IF NOT EXISTS revoked_at timestamp NULL;
+            "#,
+        )
+        .await?;
+
+        Ok(())
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        exec_unprepared(
+            manager,
+            r#"
+            ALTER TABLE boarding_passes
+            DROP COLUMN IF EXISTS revoked_at;
+            "#,
+        )
+        .await?;
+
+        Ok(())
+    }
+}
@@ -0,0 +1,37 @@
+use crate::helpers::exec_unprepared;
+use sea_orm_migration::prelude::*;
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        // wallet passes move to a new version when the gate or boarding time on them changes
+        exec_unprepared(
+            manager,
+            r#"
+            ALTER TABLE boarding_passes
+            ADD COLUMN IF NOT EXISTS wallet_pass_version integer NOT NULL DEFAULT 1,
+            ADD COLUMN IF NOT EXISTS wallet_pass_fingerprint varchar(255) NULL;
+            "#,
+        )
+        .await?;
+
+        Ok(())
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        exec_unprepared(
+            manager,
+            r#"
+            ALTER TABLE boarding_passes
+            DROP COLUMN IF EXISTS wallet_pass_fingerprint,
+            DROP COLUMN IF EXISTS wallet_pass_version;
+            "#,
+        )
+        .await?;
+
+        Ok(())
+    }
+}
@@ -0,0 +1,57 @@
+use crate::helpers::exec_unprepared;
+use sea_orm_migration::prelude::*;
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        // set when the passenger is scanned at the gate (status BOARDED)
+        exec_unprepared(
+            manager,
+            r#"
+            ALTER TABLE checkins
+            ADD COLUMN IF NOT EXISTS boarded_at timestamp NULL;
+            "#,
+        )
+        .await?;
+
+        exec_unprepared(
+            manager,
+            r#"
+            ALTER TABLE checkins
+                DROP CONSTRAINT IF EXISTS ck_checkins_status,
+                ADD CONSTRAINT ck_checkins_status
+                CHECK (status IN ('PENDING','CHECKED_IN','BOARDED','OFFLOADED','CANCELLED'));
+            "#,
+        )
+        .await?;
+
+        Ok(())
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        exec_unprepared(
+            manager,
+            r#"
+            ALTER TABLE checkins
+                DROP CONSTRAINT IF EXISTS ck_checkins_status,
+                ADD CONSTRAINT ck_checkins_status
+                CHECK (status IN ('PENDING','CHECKED_IN','CANCELLED'));
+            "#,
+        )
+        .await?;
+
+        exec_unprepared(
+            manager,
+            r#"
+            ALTER TABLE checkins
+            DROP COLUMN IF EXISTS boarded_at;
+            "#,
+        )
+        .await?;
+
+        Ok(())
+    }
+}
@@ -0,0 +1,35 @@
+use crate::helpers::exec_unprepared;
+use sea_orm_migration::prelude::*;
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        // fare brand the booking was sold under; existing bookings are STANDARD
+        exec_unprepared(
+            manager,
+            r#"
+            ALTER TABLE bookings
+            ADD COLUMN IF NOT EXISTS fare_family varchar(20) NOT NULL DEFAULT 'STANDARD';
+            "#,
+        )
+        .await?;
+
+        Ok(())
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        exec_unprepared(
+            manager,
+            r#"
+            ALTER TABLE bookings
+            DROP COLUMN IF EXISTS fare_family;
+            "#,
+        )
+        .await?;
+
+        Ok(())
+    }
+}
@@ -0,0 +1,35 @@
+use crate::helpers::exec_unprepared;
+use sea_orm_migration::prelude::*;
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        // last boarding sequence number handed out on the flight, bumped atomically per pass
+        exec_unprepared(
+            manager,
+            r#"
+            ALTER TABLE flights
+            ADD COLUMN IF NOT EXISTS boarding_sequence integer NOT NULL DEFAULT 0;
+            "#,
+        )
+        .await?;
+
+        Ok(())
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        exec_unprepared(
+            manager,
+            r#"
+            ALTER TABLE flights
+            DROP COLUMN IF EXISTS boarding_sequence;
+            "#,
+        )
+        .await?;
+
+        Ok(())
+    }
+}
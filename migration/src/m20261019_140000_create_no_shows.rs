@@ -0,0 +1,168 @@
+use crate::helpers::exec_unprepared;
+use crate::m20260111_201319_create_flights::Flights;
+use crate::m20260111_201326_create_booking::Bookings;
+use crate::m20260111_201350_create_passengers::Passengers;
+use sea_orm_migration::{prelude::*, schema::*};
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .create_table(
+                Table::create()
+                    .table(NoShows::Table)
+                    .if_not_exists()
+                    .col(
+                        ColumnDef::new(NoShows::Id)
+                            .big_integer()
+                            .not_null()
+                            .auto_increment()
+                            .primary_key(),
+                    )
+                    .col(ColumnDef::new(NoShows::FlightId).big_integer().not_null())
+                    .col(ColumnDef::new(NoShows::BookingId).big_integer().not_null())
+                    .col(
+                        ColumnDef::new(NoShows::PassengerId)
+                            .big_integer()
+                            .not_null(),
+                    )
+                    .col(big_integer_null(NoShows::CheckinId))
+                    .col(
+                        ColumnDef::new(NoShows::FareFamily)
+                            .string_len(20)
+                            .not_null(),
+                    )
+                    .col(ColumnDef::new(NoShows::FareAmount).decimal().not_null())
+                    .col(ColumnDef::new(NoShows::PenaltyAmount).decimal().not_null())
+                    .col(ColumnDef::new(NoShows::Currency).string().not_null())
+                    .col(ColumnDef::new(NoShows::RecordedAt).date_time().not_null())
+                    .col(
+                        ColumnDef::new(NoShows::CreatedAt)
+                            .date_time()
+                            .not_null()
+                            .default(Expr::current_timestamp()),
+                    )
+                    .col(
+                        ColumnDef::new(NoShows::UpdatedAt)
+                            .date_time()
+                            .not_null()
+                            .default(Expr::current_timestamp()),
+                    )
+                    .col(big_integer_null(NoShows::CreatedBy))
+                    .col(big_integer_null(NoShows::UpdatedBy))
+                    .foreign_key(
+                        ForeignKey::create()
+                            .name("fk_no_shows_flight")
+                            .from(NoShows::Table, NoShows::FlightId)
+                            .to(Flights::Table, Flights::Id)
+                            .on_delete(ForeignKeyAction::Restrict),
+                    )
+                    .foreign_key(
+                        ForeignKey::create()
+                            .name("fk_no_shows_booking")
+                            .from(NoShows::Table, NoShows::BookingId)
+                            .to(Bookings::Table, Bookings::Id)
+                            .on_delete(ForeignKeyAction::Cascade),
+                    )
+                    .foreign_key(
+                        ForeignKey::create()
+                            .name("fk_no_shows_passenger")
+                            .from(NoShows::Table, NoShows::PassengerId)
+                            .to(Passengers::Table, Passengers::Id)
+                            .on_delete(ForeignKeyAction::Cascade),
+                    )
+                    // Re-running the job for a flight must not record a passenger twice.
+                    .index(
+                        Index::create()
+                            .name("uq_no_shows_flight_passenger")
+                            .table(NoShows::Table)
+                            .col(NoShows::FlightId)
+                            .col(NoShows::PassengerId)
+                            .unique(),
+                    )
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .create_index(
+                Index::create()
+                    .name("idx_no_shows_booking_id")
+                    .table(NoShows::Table)
+                    .col(NoShows::BookingId)
+                    .to_owned(),
+            )
+            .await?;
+
+        // the job picks up departed flights where this is still NULL
+        exec_unprepared(
+            manager,
+            r#"
+            ALTER TABLE flights
+            ADD COLUMN IF NOT EXISTS no_shows_processed_at timestamp NULL;
+            "#,
+        )
+        .await?;
+
+        exec_unprepared(
+            manager,
+            r#"
+            ALTER TABLE bookings
+                DROP CONSTRAINT IF EXISTS ck_bookings_status,
+                ADD CONSTRAINT ck_bookings_status
+                CHECK (status IN ('DRAFT','CONFIRMED','CANCELLED','EXPIRED','NO_SHOW'));
+            "#,
+        )
+        .await?;
+
+        Ok(())
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        exec_unprepared(
+            manager,
+            r#"
+            ALTER TABLE bookings
+                DROP CONSTRAINT IF EXISTS ck_bookings_status,
+                ADD CONSTRAINT ck_bookings_status
+                CHECK (status IN ('DRAFT','CONFIRMED','CANCELLED','EXPIRED'));
+            "#,
+        )
+        .await?;
+
+        exec_unprepared(
+            manager,
+            r#"
+            ALTER TABLE flights
+            DROP COLUMN IF EXISTS no_shows_processed_at;
+            "#,
+        )
+        .await?;
+
+        manager
+            .drop_table(Table::drop().table(NoShows::Table).to_owned())
+            .await
+    }
+}
+
+#[derive(DeriveIden)]
+pub enum NoShows {
+    Table,
+    Id,
+    FlightId,
+    BookingId,
+    PassengerId,
+    CheckinId,
+    FareFamily,
+    FareAmount,
+    PenaltyAmount,
+    Currency,
+    RecordedAt,
+    CreatedAt,
+    UpdatedAt,
+    CreatedBy,
+    UpdatedBy,
+}
@@ -0,0 +1,69 @@
+use crate::helpers::exec_unprepared;
+use sea_orm_migration::prelude::*;
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        // allowance code (2PC / 30KG) and the excess charge that must be paid before check-in
+        exec_unprepared(
+            manager,
+            r#"
+            ALTER TABLE checkins
+            ADD COLUMN IF NOT EXISTS baggage_allowance varchar(10) NULL,
+            ADD COLUMN IF NOT EXISTS excess_baggage_pieces integer NOT NULL DEFAULT 0,
+            ADD COLUMN IF NOT EXISTS excess_baggage_weight_kg decimal NOT NULL DEFAULT 0,
+            ADD COLUMN IF NOT EXISTS excess_baggage_fee decimal NOT NULL DEFAULT 0,
+            ADD COLUMN IF NOT EXISTS excess_baggage_currency varchar(3) NULL,
+            ADD COLUMN IF NOT EXISTS excess_baggage_paid_at timestamp NULL,
+            ADD COLUMN IF NOT EXISTS excess_baggage_payment_txn_id varchar(100) NULL;
+            "#,
+        )
+        .await?;
+
+        exec_unprepared(
+            manager,
+            r#"
+            ALTER TABLE checkins
+                DROP CONSTRAINT IF EXISTS ck_checkins_excess_baggage,
+                ADD CONSTRAINT ck_checkins_excess_baggage
+                CHECK (excess_baggage_pieces >= 0
+                    AND excess_baggage_weight_kg >= 0
+                    AND excess_baggage_fee >= 0);
+            "#,
+        )
+        .await?;
+
+        Ok(())
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        exec_unprepared(
+            manager,
+            r#"
+            ALTER TABLE checkins
+            DROP CONSTRAINT IF EXISTS ck_checkins_excess_baggage;
+            "#,
+        )
+        .await?;
+
+        exec_unprepared(
+            manager,
+            r#"
+            ALTER TABLE checkins
+            DROP COLUMN IF EXISTS excess_baggage_payment_txn_id,
+            DROP COLUMN IF EXISTS excess_baggage_paid_at,
+            DROP COLUMN IF EXISTS excess_baggage_currency,
+            DROP COLUMN IF EXISTS excess_baggage_fee,
+            DROP COLUMN IF EXISTS excess_baggage_weight_kg,
+            DROP COLUMN IF EXISTS excess_baggage_pieces,
+            DROP COLUMN IF EXISTS baggage_allowance;
+            "#,
+        )
+        .await?;
+
+        Ok(())
+    }
+}
@@ -0,0 +1,271 @@
+use crate::helpers::exec_unprepared;
+use crate::m20260111_201312_create_airports::Airports;
+use crate::m20260111_201319_create_flights::Flights;
+use crate::m20260111_201326_create_booking::Bookings;
+use crate::m20260111_201350_create_passengers::Passengers;
+use crate::m20260111_201358_create_checkins::Checkins;
+use sea_orm_migration::{prelude::*, schema::*};
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        // serial part of the 10-digit license plate; wraps like printed tag stock does
+        exec_unprepared(
+            manager,
+            r#"
+            CREATE SEQUENCE IF NOT EXISTS bag_tag_serial_seq
+                MINVALUE 1 MAXVALUE 999999 CYCLE;
+            "#,
+        )
+        .await?;
+
+        manager
+            .create_table(
+                Table::create()
+                    .table(Bags::Table)
+                    .if_not_exists()
+                    .col(
+                        ColumnDef::new(Bags::Id)
+                            .big_integer()
+                            .not_null()
+                            .auto_increment()
+                            .primary_key(),
+                    )
+                    .col(ColumnDef::new(Bags::TagNumber).string_len(10).not_null())
+                    .col(ColumnDef::new(Bags::CheckinId).big_integer().not_null())
+                    .col(ColumnDef::new(Bags::BookingId).big_integer().not_null())
+                    .col(ColumnDef::new(Bags::PassengerId).big_integer().not_null())
+                    .col(ColumnDef::new(Bags::FlightId).big_integer().not_null())
+                    .col(
+                        ColumnDef::new(Bags::DestinationAirportId)
+                            .big_integer()
+                            .not_null(),
+                    )
+                    .col(ColumnDef::new(Bags::WeightKg).decimal().not_null())
+                    .col(
+                        ColumnDef::new(Bags::Status)
+                            .string_len(20)
+                            .not_null()
+                            .default("CHECKED"),
+                    )
+                    .col(string_null(Bags::LastLocation))
+                    .col(
+                        ColumnDef::new(Bags::CreatedAt)
+                            .date_time()
+                            .not_null()
+                            .default(Expr::current_timestamp()),
+                    )
+                    .col(
+                        ColumnDef::new(Bags::UpdatedAt)
+                            .date_time()
+                            .not_null()
+                            .default(Expr::current_timestamp()),
+                    )
+                    .col(big_integer_null(Bags::CreatedBy))
+                    .col(big_integer_null(Bags::UpdatedBy))
+                    .col(
+                        ColumnDef::new(Bags::Version)
+                            .integer()
+                            .not_null()
+                            .default(1),
+                    )
+                    .foreign_key(
+                        ForeignKey::create()
+                            .name("fk_bags_checkin")
+                            .from(Bags::Table, Bags::CheckinId)
+                            .to(Checkins::Table, Checkins::Id)
+                            .on_delete(ForeignKeyAction::Cascade),
+                    )
+                    .foreign_key(
+                        ForeignKey::create()
+                            .name("fk_bags_booking")
+                            .from(Bags::Table, Bags::BookingId)
+                            .to(Bookings::Table, Bookings::Id)
+                            .on_delete(ForeignKeyAction::Cascade),
+                    )
+                    .foreign_key(
+                        ForeignKey::create()
+                            .name("fk_bags_passenger")
+                            .from(Bags::Table, Bags::PassengerId)
+                            .to(Passengers::Table, Passengers::Id)
+                            .on_delete(ForeignKeyAction::Cascade),
+                    )
+                    .foreign_key(
+                        ForeignKey::create()
+                            .name("fk_bags_flight")
+                            .from(Bags::Table, Bags::FlightId)
+                            .to(Flights::Table, Flights::Id)
+                            .on_delete(ForeignKeyAction::Restrict),
+                    )
+                    .foreign_key(
+                        ForeignKey::create()
+                            .name("fk_bags_destination_airport")
+                            .from(Bags::Table, Bags::DestinationAirportId)
+                            .to(Airports::Table, Airports::Id)
+                            .on_delete(ForeignKeyAction::Restrict),
+                    )
+                    .to_owned(),
+            )
+            .await?;
+
+        // not unique: serials wrap after 999999, so a tag number can be reused later
+        manager
+            .create_index(
+                Index::create()
+                    .name("idx_bags_tag_number")
+                    .table(Bags::Table)
+                    .col(Bags::TagNumber)
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .create_index(
+                Index::create()
+                    .name("idx_bags_booking_id")
+                    .table(Bags::Table)
+                    .col(Bags::BookingId)
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .create_index(
+                Index::create()
+                    .name("idx_bags_checkin_id")
+                    .table(Bags::Table)
+                    .col(Bags::CheckinId)
+                    .to_owned(),
+            )
+            .await?;
+
+        exec_unprepared(
+            manager,
+            r#"
+            ALTER TABLE bags
+                DROP CONSTRAINT IF EXISTS ck_bags_status,
+                ADD CONSTRAINT ck_bags_status
+                CHECK (status IN ('CHECKED','LOADED','TRANSFERRED','ARRIVED','DELAYED')),
+                DROP CONSTRAINT IF EXISTS ck_bags_weight_kg,
+                ADD CONSTRAINT ck_bags_weight_kg CHECK (weight_kg > 0);
+            "#,
+        )
+        .await?;
+
+        manager
+            .create_table(
+                Table::create()
+                    .table(BagStatusEvents::Table)
+                    .if_not_exists()
+                    .col(
+                        ColumnDef::new(BagStatusEvents::Id)
+                            .big_integer()
+                            .not_null()
+                            .auto_increment()
+                            .primary_key(),
+                    )
+                    .col(
+                        ColumnDef::new(BagStatusEvents::BagId)
+                            .big_integer()
+                            .not_null(),
+                    )
+                    .col(
+                        ColumnDef::new(BagStatusEvents::Status)
+                            .string_len(20)
+                            .not_null(),
+                    )
+                    .col(string_null(BagStatusEvents::Location))
+                    .col(string_null(BagStatusEvents::Note))
+                    .col(
+                        ColumnDef::new(BagStatusEvents::RecordedAt)
+                            .date_time()
+                            .not_null(),
+                    )
+                    .col(
+                        ColumnDef::new(BagStatusEvents::CreatedAt)
+                            .date_time()
+                            .not_null()
+                            .default(Expr::current_timestamp()),
+                    )
+                    .col(
+                        ColumnDef::new(BagStatusEvents::UpdatedAt)
+                            .date_time()
+                            .not_null()
+                            .default(Expr::current_timestamp()),
+                    )
+                    .col(big_integer_null(BagStatusEvents::CreatedBy))
+                    .col(big_integer_null(BagStatusEvents::UpdatedBy))
+                    .foreign_key(
+                        ForeignKey::create()
+                            .name("fk_bag_status_events_bag")
+                            .from(BagStatusEvents::Table, BagStatusEvents::BagId)
+                            .to(Bags::Table, Bags::Id)
+                            .on_delete(ForeignKeyAction::Cascade),
+                    )
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .create_index(
+                Index::create()
+                    .name("idx_bag_status_events_bag_id")
+                    .table(BagStatusEvents::Table)
+                    .col(BagStatusEvents::BagId)
+                    .to_owned(),
+            )
+            .await?;
+
+        Ok(())
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .drop_table(Table::drop().table(BagStatusEvents::Table).to_owned())
+            .await?;
+
+        manager
+            .drop_table(Table::drop().table(Bags::Table).to_owned())
+            .await?;
+
+        exec_unprepared(manager, "DROP SEQUENCE IF EXISTS bag_tag_serial_seq;").await
+    }
+}
+
+#[derive(DeriveIden)]
+pub enum Bags {
+    Table,
+    Id,
+    TagNumber,
+    CheckinId,
+    BookingId,
+    PassengerId,
+    FlightId,
+    DestinationAirportId,
+    WeightKg,
+    Status,
+    LastLocation,
+    CreatedAt,
+    UpdatedAt,
+    CreatedBy,
+    UpdatedBy,
+    Version,
+}
+
+#[derive(DeriveIden)]
+pub enum BagStatusEvents {
+    Table,
+    Id,
+    BagId,
+    Status,
+    Location,
+    Note,
+    RecordedAt,
+    CreatedAt,
+    UpdatedAt,
+    CreatedBy,
+    UpdatedBy,
+}
@@ -0,0 +1,74 @@
+use sea_orm_migration::{prelude::*, schema::*};
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .create_table(
+                Table::create()
+                    .table(Airlines::Table)
+                    .if_not_exists()
+                    .col(
+                        ColumnDef::new(Airlines::Id)
+                            .big_integer()
+                            .not_null()
+                            .auto_increment()
+                            .primary_key(),
+                    )
+                    .col(ColumnDef::new(Airlines::IataCode).string_len(2).not_null())
+                    .col(ColumnDef::new(Airlines::IcaoCode).string_len(3).null())
+                    .col(ColumnDef::new(Airlines::Name).string().not_null())
+                    .col(string_null(Airlines::Callsign))
+                    .col(
+                        ColumnDef::new(Airlines::CountryCode)
+                            .string_len(2)
+                            .not_null(),
+                    )
+                    .col(string_null(Airlines::LogoUrl))
+                    .col(
+                        ColumnDef::new(Airlines::IsActive)
+                            .boolean()
+                            .not_null()
+                            .default(true),
+                    )
+                    .index(
+                        Index::create()
+                            .name("uq_airlines_iata_code")
+                            .table(Airlines::Table)
+                            .col(Airlines::IataCode)
+                            .unique(),
+                    )
+                    .index(
+                        Index::create()
+                            .name("uq_airlines_icao_code")
+                            .table(Airlines::Table)
+                            .col(Airlines::IcaoCode)
+                            .unique(),
+                    )
+                    .to_owned(),
+            )
+            .await
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .drop_table(Table::drop().table(Airlines::Table).to_owned())
+            .await
+    }
+}
+
+#[derive(DeriveIden)]
+pub enum Airlines {
+    Table,
+    Id,
+    IataCode,
+    IcaoCode,
+    Name,
+    Callsign,
+    CountryCode,
+    LogoUrl,
+    IsActive,
+}
@@ -0,0 +1,115 @@
+use crate::helpers::exec_unprepared;
+use crate::m20260111_201319_create_flights::Flights;
+use crate::m20261019_170000_create_airlines::Airlines;
+use sea_orm_migration::prelude::*;
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .create_table(
+                Table::create()
+                    .table(Aircraft::Table)
+                    .if_not_exists()
+                    .col(
+                        ColumnDef::new(Aircraft::Id)
+                            .big_integer()
+                            .not_null()
+                            .auto_increment()
+                            .primary_key(),
+                    )
+                    .col(
+                        ColumnDef::new(Aircraft::TailNumber)
+                            .string_len(10)
+                            .not_null(),
+                    )
+                    .col(
+                        ColumnDef::new(Aircraft::AircraftType)
+                            .string_len(4)
+                            .not_null(),
+                    )
+                    .col(
+                        ColumnDef::new(Aircraft::SeatConfiguration)
+                            .string()
+                            .not_null(),
+                    )
+                    .col(ColumnDef::new(Aircraft::AirlineId).big_integer().not_null())
+                    .col(
+                        ColumnDef::new(Aircraft::Status)
+                            .string_len(20)
+                            .not_null()
+                            .default("ACTIVE"),
+                    )
+                    .index(
+                        Index::create()
+                            .name("uq_aircraft_tail_number")
+                            .table(Aircraft::Table)
+                            .col(Aircraft::TailNumber)
+                            .unique(),
+                    )
+                    .foreign_key(
+                        ForeignKey::create()
+                            .name("fk_aircraft_airline")
+                            .from(Aircraft::Table, Aircraft::AirlineId)
+                            .to(Airlines::Table, Airlines::Id)
+                            .on_delete(ForeignKeyAction::Restrict),
+                    )
+                    .to_owned(),
+            )
+            .await?;
+
+        exec_unprepared(
+            manager,
+            r#"
+            ALTER TABLE aircraft
+                DROP CONSTRAINT IF EXISTS ck_aircraft_status,
+                ADD CONSTRAINT ck_aircraft_status
+                CHECK (status IN ('ACTIVE','MAINTENANCE'));
+            "#,
+        )
+        .await?;
+
+        // rotation checks look up a tail's flights by time
+        manager
+            .create_index(
+                Index::create()
+                    .name("idx_flights_tail_number_departure_time")
+                    .table(Flights::Table)
+                    .col(Flights::TailNumber)
+                    .col(Flights::DepartureTime)
+                    .to_owned(),
+            )
+            .await
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .drop_index(
+                Index::drop()
+                    .name("idx_flights_tail_number_departure_time")
+                    .table(Flights::Table)
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .drop_table(Table::drop().table(Aircraft::Table).to_owned())
+            .await
+    }
+}
+
+// `AircraftType` names the `aircraft_type` column.
+#[allow(clippy::enum_variant_names)]
+#[derive(DeriveIden)]
+pub enum Aircraft {
+    Table,
+    Id,
+    TailNumber,
+    AircraftType,
+    SeatConfiguration,
+    AirlineId,
+    Status,
+}
@@ -0,0 +1,45 @@
+use crate::helpers::exec_unprepared;
+use sea_orm_migration::prelude::*;
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        // trigram indexes back the fuzzy name/city match of the airport autocomplete
+        exec_unprepared(
+            manager,
+            r#"
+            CREATE EXTENSION IF NOT EXISTS pg_trgm;
+
+            CREATE INDEX IF NOT EXISTS idx_airports_name_trgm
+            ON airports USING gin (lower(name) gin_trgm_ops);
+
+            CREATE INDEX IF NOT EXISTS idx_airports_city_trgm
+            ON airports USING gin (lower(city) gin_trgm_ops);
+
+            CREATE INDEX IF NOT EXISTS idx_airports_latitude_longitude
+            ON airports (latitude, longitude);
+            "#,
+        )
+        .await?;
+
+        Ok(())
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        // the extension stays; other objects may depend on it
+        exec_unprepared(
+            manager,
+            r#"
+            DROP INDEX IF EXISTS idx_airports_latitude_longitude;
+            DROP INDEX IF EXISTS idx_airports_city_trgm;
+            DROP INDEX IF EXISTS idx_airports_name_trgm;
+            "#,
+        )
+        .await?;
+
+        Ok(())
+    }
+}
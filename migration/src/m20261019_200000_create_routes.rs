@@ -0,0 +1,175 @@
+use crate::helpers::exec_unprepared;
+use crate::m20260111_201312_create_airports::Airports;
+use sea_orm_migration::prelude::*;
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .create_table(
+                Table::create()
+                    .table(Routes::Table)
+                    .if_not_exists()
+                    .col(
+                        ColumnDef::new(Routes::Id)
+                            .big_integer()
+                            .not_null()
+                            .auto_increment()
+                            .primary_key(),
+                    )
+                    .col(
+                        ColumnDef::new(Routes::OriginAirportId)
+                            .big_integer()
+                            .not_null(),
+                    )
+                    .col(
+                        ColumnDef::new(Routes::DestinationAirportId)
+                            .big_integer()
+                            .not_null(),
+                    )
+                    .col(ColumnDef::new(Routes::DistanceKm).integer().not_null())
+                    .col(
+                        ColumnDef::new(Routes::BlockTimeMinutes)
+                            .integer()
+                            .not_null(),
+                    )
+                    .col(ColumnDef::new(Routes::IsDomestic).boolean().not_null())
+                    .col(
+                        ColumnDef::new(Routes::IsActive)
+                            .boolean()
+                            .not_null()
+                            .default(true),
+                    )
+                    .index(
+                        Index::create()
+                            .name("uq_routes_origin_destination")
+                            .table(Routes::Table)
+                            .col(Routes::OriginAirportId)
+                            .col(Routes::DestinationAirportId)
+                            .unique(),
+                    )
+                    .foreign_key(
+                        ForeignKey::create()
+                            .name("fk_routes_origin_airport")
+                            .from(Routes::Table, Routes::OriginAirportId)
+                            .to(Airports::Table, Airports::Id)
+                            .on_delete(ForeignKeyAction::Restrict),
+                    )
+                    .foreign_key(
+                        ForeignKey::create()
+                            .name("fk_routes_destination_airport")
+                            .from(Routes::Table, Routes::DestinationAirportId)
+                            .to(Airports::Table, Airports::Id)
+                            .on_delete(ForeignKeyAction::Restrict),
+                    )
+                    .to_owned(),
+            )
+            .await?;
+
+        exec_unprepared(
+            manager,
+            r#"
+            ALTER TABLE routes
+                DROP CONSTRAINT IF EXISTS ck_routes_distinct_airports,
+                ADD CONSTRAINT ck_routes_distinct_airports
+                CHECK (origin_airport_id <> destination_airport_id),
+                DROP CONSTRAINT IF EXISTS ck_routes_block_time,
+                ADD CONSTRAINT ck_routes_block_time
+                CHECK (block_time_minutes BETWEEN 1 AND 1440),
+                DROP CONSTRAINT IF EXISTS ck_routes_distance,
+                ADD CONSTRAINT ck_routes_distance
+                CHECK (distance_km >= 0);
+            "#,
+        )
+        .await?;
+
+        exec_unprepared(
+            manager,
+            r#"
+            ALTER TABLE flights
+            ADD COLUMN IF NOT EXISTS route_id bigint NULL
+                CONSTRAINT fk_flights_route REFERENCES routes (id) ON DELETE RESTRICT;
+
+            CREATE INDEX IF NOT EXISTS idx_flights_route_id ON flights (route_id);
+            "#,
+        )
+        .await?;
+
+        // Open a route for every pair already flown, measured by haversine, with the
+        // average scheduled time as block time. Pairs whose airports lack coordinates
+        // stay without a route until one is created.
+        exec_unprepared(
+            manager,
+            r#"
+            INSERT INTO routes (
+                origin_airport_id, destination_airport_id, distance_km,
+                block_time_minutes, is_domestic, is_active
+            )
+            SELECT
+                f.origin_airport_id,
+                f.destination_airport_id,
+                ROUND(2 * 6371.0088 * ASIN(LEAST(1, SQRT(
+                    POWER(SIN(RADIANS((d.latitude - o.latitude)::float8) / 2), 2)
+                    + COS(RADIANS(o.latitude::float8)) * COS(RADIANS(d.latitude::float8))
+                    * POWER(SIN(RADIANS((d.longitude - o.longitude)::float8) / 2), 2)
+                ))))::integer,
+                GREATEST(1, LEAST(1440, ROUND(AVG(
+                    EXTRACT(EPOCH FROM (f.arrival_time - f.departure_time)) / 60
+                ))))::integer,
+                o.country_code = d.country_code,
+                TRUE
+            FROM flights f
+            JOIN airports o ON o.id = f.origin_airport_id
+            JOIN airports d ON d.id = f.destination_airport_id
+            WHERE o.latitude IS NOT NULL AND o.longitude IS NOT NULL
+              AND d.latitude IS NOT NULL AND d.longitude IS NOT NULL
+              AND f.origin_airport_id <> f.destination_airport_id
+            GROUP BY
+                f.origin_airport_id, f.destination_airport_id,
+                o.latitude, o.longitude, o.country_code,
+                d.latitude, d.longitude, d.country_code
+            ON CONFLICT (origin_airport_id, destination_airport_id) DO NOTHING;
+
+            UPDATE flights f
+            SET route_id = r.id
+            FROM routes r
+            WHERE r.origin_airport_id = f.origin_airport_id
+              AND r.destination_airport_id = f.destination_airport_id
+              AND f.route_id IS NULL;
+            "#,
+        )
+        .await?;
+
+        Ok(())
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        exec_unprepared(
+            manager,
+            r#"
+            DROP INDEX IF EXISTS idx_flights_route_id;
+            ALTER TABLE flights DROP COLUMN IF EXISTS route_id;
+            "#,
+        )
+        .await?;
+
+        manager
+            .drop_table(Table::drop().table(Routes::Table).to_owned())
+            .await
+    }
+}
+
+#[derive(DeriveIden)]
+pub enum Routes {
+    Table,
+    Id,
+    OriginAirportId,
+    DestinationAirportId,
+    DistanceKm,
+    BlockTimeMinutes,
+    IsDomestic,
+    IsActive,
+}
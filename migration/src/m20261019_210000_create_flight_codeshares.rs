@@ -0,0 +1,132 @@
+use crate::helpers::exec_unprepared;
+use crate::m20260111_201319_create_flights::Flights;
+use sea_orm_migration::{prelude::*, schema::*};
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .create_table(
+                Table::create()
+                    .table(FlightCodeshares::Table)
+                    .if_not_exists()
+                    .col(
+                        ColumnDef::new(FlightCodeshares::Id)
+                            .big_integer()
+                            .not_null()
+                            .auto_increment()
+                            .primary_key(),
+                    )
+                    .col(
+                        ColumnDef::new(FlightCodeshares::FlightId)
+                            .big_integer()
+                            .not_null(),
+                    )
+                    .col(
+                        ColumnDef::new(FlightCodeshares::AirlineCode)
+                            .string()
+                            .not_null(),
+                    )
+                    .col(
+                        ColumnDef::new(FlightCodeshares::FlightNumber)
+                            .string()
+                            .not_null(),
+                    )
+                    .col(
+                        ColumnDef::new(FlightCodeshares::FlightKey)
+                            .string()
+                            .not_null()
+                            .unique_key(),
+                    )
+                    .col(
+                        ColumnDef::new(FlightCodeshares::CreatedAt)
+                            .date_time()
+                            .not_null()
+                            .default(Expr::current_timestamp()),
+                    )
+                    .col(
+                        ColumnDef::new(FlightCodeshares::UpdatedAt)
+                            .date_time()
+                            .not_null()
+                            .default(Expr::current_timestamp()),
+                    )
+                    .col(big_integer_null(FlightCodeshares::CreatedBy))
+                    .col(big_integer_null(FlightCodeshares::UpdatedBy))
+                    .foreign_key(
+                        ForeignKey::create()
+                            .name("fk_flight_codeshares_flight")
+                            .from(FlightCodeshares::Table, FlightCodeshares::FlightId)
+                            .to(Flights::Table, Flights::Id)
+                            .on_delete(ForeignKeyAction::Cascade),
+                    )
+                    // One marketing designator per partner airline on a flight.
+                    .index(
+                        Index::create()
+                            .name("uq_flight_codeshares_flight_airline")
+                            .table(FlightCodeshares::Table)
+                            .col(FlightCodeshares::FlightId)
+                            .col(FlightCodeshares::AirlineCode)
+                            .unique(),
+                    )
+                    .to_owned(),
+            )
+            .await?;
+
+        // Bookings made before codeshares were sold under the operating designator.
+        exec_unprepared(
+            manager,
+            r#"
+            ALTER TABLE bookings
+            ADD COLUMN IF NOT EXISTS marketing_airline_code varchar NULL,
+            ADD COLUMN IF NOT EXISTS marketing_flight_number varchar NULL;
+
+            UPDATE bookings b
+            SET marketing_airline_code = f.airline_code,
+                marketing_flight_number = f.flight_number
+            FROM flights f
+            WHERE f.id = b.flight_id
+              AND b.marketing_airline_code IS NULL;
+
+            ALTER TABLE bookings
+            ALTER COLUMN marketing_airline_code SET NOT NULL,
+            ALTER COLUMN marketing_flight_number SET NOT NULL;
+            "#,
+        )
+        .await?;
+
+        Ok(())
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        exec_unprepared(
+            manager,
+            r#"
+            ALTER TABLE bookings
+            DROP COLUMN IF EXISTS marketing_airline_code,
+            DROP COLUMN IF EXISTS marketing_flight_number;
+            "#,
+        )
+        .await?;
+
+        manager
+            .drop_table(Table::drop().table(FlightCodeshares::Table).to_owned())
+            .await
+    }
+}
+
+#[derive(DeriveIden)]
+pub enum FlightCodeshares {
+    Table,
+    Id,
+    FlightId,
+    AirlineCode,
+    FlightNumber,
+    FlightKey,
+    CreatedAt,
+    UpdatedAt,
+    CreatedBy,
+    UpdatedBy,
+}
@@ -0,0 +1,163 @@
+use crate::helpers::exec_unprepared;
+use crate::m20260111_201319_create_flights::Flights;
+use sea_orm_migration::{prelude::*, schema::*};
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        // Latest known operational times; the history below keeps every revision.
+        exec_unprepared(
+            manager,
+            r#"
+            ALTER TABLE flights
+            ADD COLUMN IF NOT EXISTS estimated_departure timestamp NULL,
+            ADD COLUMN IF NOT EXISTS estimated_arrival timestamp NULL,
+            ADD COLUMN IF NOT EXISTS actual_off_block timestamp NULL,
+            ADD COLUMN IF NOT EXISTS actual_takeoff timestamp NULL,
+            ADD COLUMN IF NOT EXISTS actual_landing timestamp NULL,
+            ADD COLUMN IF NOT EXISTS actual_on_block timestamp NULL;
+            "#,
+        )
+        .await?;
+
+        manager
+            .create_table(
+                Table::create()
+                    .table(FlightStatusEvents::Table)
+                    .if_not_exists()
+                    .col(
+                        ColumnDef::new(FlightStatusEvents::Id)
+                            .big_integer()
+                            .not_null()
+                            .auto_increment()
+                            .primary_key(),
+                    )
+                    .col(
+                        ColumnDef::new(FlightStatusEvents::FlightId)
+                            .big_integer()
+                            .not_null(),
+                    )
+                    .col(
+                        ColumnDef::new(FlightStatusEvents::FromStatus)
+                            .string_len(20)
+                            .not_null(),
+                    )
+                    .col(
+                        ColumnDef::new(FlightStatusEvents::ToStatus)
+                            .string_len(20)
+                            .not_null(),
+                    )
+                    .col(
+                        ColumnDef::new(FlightStatusEvents::ScheduledDeparture)
+                            .date_time()
+                            .not_null(),
+                    )
+                    .col(
+                        ColumnDef::new(FlightStatusEvents::ScheduledArrival)
+                            .date_time()
+                            .not_null(),
+                    )
+                    .col(date_time_null(FlightStatusEvents::EstimatedDeparture))
+                    .col(date_time_null(FlightStatusEvents::EstimatedArrival))
+                    .col(date_time_null(FlightStatusEvents::ActualOffBlock))
+                    .col(date_time_null(FlightStatusEvents::ActualTakeoff))
+                    .col(date_time_null(FlightStatusEvents::ActualLanding))
+                    .col(date_time_null(FlightStatusEvents::ActualOnBlock))
+                    .col(integer_null(FlightStatusEvents::DelayMinutes))
+                    // Comma separated IATA codes, most significant first.
+                    .col(string_null(FlightStatusEvents::DelayCodes))
+                    .col(text_null(FlightStatusEvents::Reason))
+                    .col(big_integer_null(FlightStatusEvents::ChangedBy))
+                    .col(
+                        ColumnDef::new(FlightStatusEvents::RecordedAt)
+                            .date_time()
+                            .not_null(),
+                    )
+                    .col(
+                        ColumnDef::new(FlightStatusEvents::CreatedAt)
+                            .date_time()
+                            .not_null()
+                            .default(Expr::current_timestamp()),
+                    )
+                    .col(
+                        ColumnDef::new(FlightStatusEvents::UpdatedAt)
+                            .date_time()
+                            .not_null()
+                            .default(Expr::current_timestamp()),
+                    )
+                    .col(big_integer_null(FlightStatusEvents::CreatedBy))
+                    .col(big_integer_null(FlightStatusEvents::UpdatedBy))
+                    .foreign_key(
+                        ForeignKey::create()
+                            .name("fk_flight_status_events_flight")
+                            .from(FlightStatusEvents::Table, FlightStatusEvents::FlightId)
+                            .to(Flights::Table, Flights::Id)
+                            .on_delete(ForeignKeyAction::Cascade),
+                    )
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .create_index(
+                Index::create()
+                    .name("idx_flight_status_events_flight_recorded_at")
+                    .table(FlightStatusEvents::Table)
+                    .col(FlightStatusEvents::FlightId)
+                    .col(FlightStatusEvents::RecordedAt)
+                    .to_owned(),
+            )
+            .await?;
+
+        Ok(())
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .drop_table(Table::drop().table(FlightStatusEvents::Table).to_owned())
+            .await?;
+
+        exec_unprepared(
+            manager,
+            r#"
+            ALTER TABLE flights
+            DROP COLUMN IF EXISTS estimated_departure,
+            DROP COLUMN IF EXISTS estimated_arrival,
+            DROP COLUMN IF EXISTS actual_off_block,
+            DROP COLUMN IF EXISTS actual_takeoff,
+            DROP COLUMN IF EXISTS actual_landing,
+            DROP COLUMN IF EXISTS actual_on_block;
+            "#,
+        )
+        .await
+    }
+}
+
+#[derive(DeriveIden)]
+pub enum FlightStatusEvents {
+    Table,
+    Id,
+    FlightId,
+    FromStatus,
+    ToStatus,
+    ScheduledDeparture,
+    ScheduledArrival,
+    EstimatedDeparture,
+    EstimatedArrival,
+    ActualOffBlock,
+    ActualTakeoff,
+    ActualLanding,
+    ActualOnBlock,
+    DelayMinutes,
+    DelayCodes,
+    Reason,
+    ChangedBy,
+    RecordedAt,
+    CreatedAt,
+    UpdatedAt,
+    CreatedBy,
+    UpdatedBy,
+}
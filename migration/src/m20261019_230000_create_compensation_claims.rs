@@ -0,0 +1,196 @@
+use crate::helpers::exec_unprepared;
+use crate::m20260111_201319_create_flights::Flights;
+use crate::m20260111_201326_create_booking::Bookings;
+use sea_orm_migration::{prelude::*, schema::*};
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .create_table(
+                Table::create()
+                    .table(CompensationClaims::Table)
+                    .if_not_exists()
+                    .col(
+                        ColumnDef::new(CompensationClaims::Id)
+                            .big_integer()
+                            .not_null()
+                            .auto_increment()
+                            .primary_key(),
+                    )
+                    .col(
+                        ColumnDef::new(CompensationClaims::BookingId)
+                            .big_integer()
+                            .not_null(),
+                    )
+                    .col(
+                        ColumnDef::new(CompensationClaims::FlightId)
+                            .big_integer()
+                            .not_null(),
+                    )
+                    .col(
+                        ColumnDef::new(CompensationClaims::UserId)
+                            .big_integer()
+                            .not_null(),
+                    )
+                    .col(
+                        ColumnDef::new(CompensationClaims::Reason)
+                            .string_len(20)
+                            .not_null(),
+                    )
+                    .col(
+                        ColumnDef::new(CompensationClaims::Status)
+                            .string_len(20)
+                            .not_null()
+                            .default("SUBMITTED"),
+                    )
+                    .col(
+                        ColumnDef::new(CompensationClaims::DistanceKm)
+                            .integer()
+                            .not_null(),
+                    )
+                    .col(integer_null(CompensationClaims::ArrivalDelayMinutes))
+                    .col(
+                        ColumnDef::new(CompensationClaims::PassengerCount)
+                            .integer()
+                            .not_null(),
+                    )
+                    .col(
+                        ColumnDef::new(CompensationClaims::AmountPerPassenger)
+                            .decimal()
+                            .not_null(),
+                    )
+                    .col(
+                        ColumnDef::new(CompensationClaims::TotalAmount)
+                            .decimal()
+                            .not_null(),
+                    )
+                    .col(
+                        ColumnDef::new(CompensationClaims::Currency)
+                            .string_len(3)
+                            .not_null(),
+                    )
+                    .col(text_null(CompensationClaims::CustomerNote))
+                    .col(text_null(CompensationClaims::DecisionNote))
+                    .col(big_integer_null(CompensationClaims::DecidedBy))
+                    .col(
+                        ColumnDef::new(CompensationClaims::SubmittedAt)
+                            .date_time()
+                            .not_null(),
+                    )
+                    .col(date_time_null(CompensationClaims::DecidedAt))
+                    .col(
+                        ColumnDef::new(CompensationClaims::CreatedAt)
+                            .date_time()
+                            .not_null()
+                            .default(Expr::current_timestamp()),
+                    )
+                    .col(
+                        ColumnDef::new(CompensationClaims::UpdatedAt)
+                            .date_time()
+                            .not_null()
+                            .default(Expr::current_timestamp()),
+                    )
+                    .col(big_integer_null(CompensationClaims::CreatedBy))
+                    .col(big_integer_null(CompensationClaims::UpdatedBy))
+                    .col(
+                        ColumnDef::new(CompensationClaims::Version)
+                            .integer()
+                            .not_null()
+                            .default(1),
+                    )
+                    .foreign_key(
+                        ForeignKey::create()
+                            .name("fk_compensation_claims_booking")
+                            .from(CompensationClaims::Table, CompensationClaims::BookingId)
+                            .to(Bookings::Table, Bookings::Id)
+                            .on_delete(ForeignKeyAction::Cascade),
+                    )
+                    .foreign_key(
+                        ForeignKey::create()
+                            .name("fk_compensation_claims_flight")
+                            .from(CompensationClaims::Table, CompensationClaims::FlightId)
+                            .to(Flights::Table, Flights::Id)
+                            .on_delete(ForeignKeyAction::Restrict),
+                    )
+                    .to_owned(),
+            )
+            .await?;
+
+        // one claim per booking; a rejection is final and goes through customer service
+        manager
+            .create_index(
+                Index::create()
+                    .name("uq_compensation_claims_booking_id")
+                    .table(CompensationClaims::Table)
+                    .col(CompensationClaims::BookingId)
+                    .unique()
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .create_index(
+                Index::create()
+                    .name("idx_compensation_claims_status")
+                    .table(CompensationClaims::Table)
+                    .col(CompensationClaims::Status)
+                    .col(CompensationClaims::SubmittedAt)
+                    .to_owned(),
+            )
+            .await?;
+
+        exec_unprepared(
+            manager,
+            r#"
+            ALTER TABLE compensation_claims
+                DROP CONSTRAINT IF EXISTS ck_compensation_claims_status,
+                ADD CONSTRAINT ck_compensation_claims_status
+                CHECK (status IN ('SUBMITTED','APPROVED','REJECTED')),
+                DROP CONSTRAINT IF EXISTS ck_compensation_claims_reason,
+                ADD CONSTRAINT ck_compensation_claims_reason
+                CHECK (reason IN ('DELAY','CANCELLATION')),
+                DROP CONSTRAINT IF EXISTS ck_compensation_claims_amounts,
+                ADD CONSTRAINT ck_compensation_claims_amounts
+                CHECK (passenger_count > 0 AND amount_per_passenger >= 0 AND total_amount >= 0);
+            "#,
+        )
+        .await
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .drop_table(Table::drop().table(CompensationClaims::Table).to_owned())
+            .await
+    }
+}
+
+#[derive(DeriveIden)]
+pub enum CompensationClaims {
+    Table,
+    Id,
+    BookingId,
+    FlightId,
+    UserId,
+    Reason,
+    Status,
+    DistanceKm,
+    ArrivalDelayMinutes,
+    PassengerCount,
+    AmountPerPassenger,
+    TotalAmount,
+    Currency,
+    CustomerNote,
+    DecisionNote,
+    DecidedBy,
+    SubmittedAt,
+    DecidedAt,
+    CreatedAt,
+    UpdatedAt,
+    CreatedBy,
+    UpdatedBy,
+    Version,
+}
@@ -0,0 +1,301 @@
+use crate::helpers::exec_unprepared;
+use crate::m20260111_201209_create_users::Users;
+use crate::m20260111_201319_create_flights::Flights;
+use crate::m20260111_201326_create_booking::Bookings;
+use sea_orm_migration::{prelude::*, schema::*};
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .create_table(
+                Table::create()
+                    .table(Notifications::Table)
+                    .if_not_exists()
+                    .col(
+                        ColumnDef::new(Notifications::Id)
+                            .big_integer()
+                            .not_null()
+                            .auto_increment()
+                            .primary_key(),
+                    )
+                    .col(
+                        ColumnDef::new(Notifications::Kind)
+                            .string_len(30)
+                            .not_null(),
+                    )
+                    .col(
+                        ColumnDef::new(Notifications::Channel)
+                            .string_len(10)
+                            .not_null(),
+                    )
+                    .col(
+                        ColumnDef::new(Notifications::Status)
+                            .string_len(20)
+                            .not_null()
+                            .default("PENDING"),
+                    )
+                    .col(big_integer_null(Notifications::UserId))
+                    .col(big_integer_null(Notifications::BookingId))
+                    .col(big_integer_null(Notifications::FlightId))
+                    .col(
+                        ColumnDef::new(Notifications::Recipient)
+                            .string_len(255)
+                            .not_null(),
+                    )
+                    .col(
+                        ColumnDef::new(Notifications::Locale)
+                            .string_len(10)
+                            .not_null(),
+                    )
+                    .col(text_null(Notifications::Subject))
+                    .col(ColumnDef::new(Notifications::Body).text().not_null())
+                    .col(
+                        ColumnDef::new(Notifications::DedupKey)
+                            .string_len(200)
+                            .not_null(),
+                    )
+                    .col(
+                        ColumnDef::new(Notifications::Attempts)
+                            .integer()
+                            .not_null()
+                            .default(0),
+                    )
+                    .col(text_null(Notifications::LastError))
+                    .col(string_null(Notifications::ProviderMessageId))
+                    .col(
+                        ColumnDef::new(Notifications::ScheduledAt)
+                            .date_time()
+                            .not_null(),
+                    )
+                    .col(date_time_null(Notifications::SentAt))
+                    .col(
+                        ColumnDef::new(Notifications::CreatedAt)
+                            .date_time()
+                            .not_null()
+                            .default(Expr::current_timestamp()),
+                    )
+                    .col(
+                        ColumnDef::new(Notifications::UpdatedAt)
+                            .date_time()
+                            .not_null()
+                            .default(Expr::current_timestamp()),
+                    )
+                    .col(big_integer_null(Notifications::CreatedBy))
+                    .col(big_integer_null(Notifications::UpdatedBy))
+                    .col(
+                        ColumnDef::new(Notifications::Version)
+                            .integer()
+                            .not_null()
+                            .default(1),
+                    )
+                    .foreign_key(
+                        ForeignKey::create()
+                            .name("fk_notifications_user")
+                            .from(Notifications::Table, Notifications::UserId)
+                            .to(Users::Table, Users::Id)
+                            .on_delete(ForeignKeyAction::Cascade),
+                    )
+                    .foreign_key(
+                        ForeignKey::create()
+                            .name("fk_notifications_booking")
+                            .from(Notifications::Table, Notifications::BookingId)
+                            .to(Bookings::Table, Bookings::Id)
+                            .on_delete(ForeignKeyAction::Cascade),
+                    )
+                    .foreign_key(
+                        ForeignKey::create()
+                            .name("fk_notifications_flight")
+                            .from(Notifications::Table, Notifications::FlightId)
+                            .to(Flights::Table, Flights::Id)
+                            .on_delete(ForeignKeyAction::Cascade),
+                    )
+                    .to_owned(),
+            )
+            .await?;
+
+        // redelivered events and repeated flight updates must not notify twice
+        manager
+            .create_index(
+                Index::create()
+                    .name("uq_notifications_dedup_key")
+                    .table(Notifications::Table)
+                    .col(Notifications::DedupKey)
+                    .unique()
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .create_index(
+                Index::create()
+                    .name("idx_notifications_status_scheduled_at")
+                    .table(Notifications::Table)
+                    .col(Notifications::Status)
+                    .col(Notifications::ScheduledAt)
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .create_index(
+                Index::create()
+                    .name("idx_notifications_user_id")
+                    .table(Notifications::Table)
+                    .col(Notifications::UserId)
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .create_index(
+                Index::create()
+                    .name("idx_notifications_booking_id")
+                    .table(Notifications::Table)
+                    .col(Notifications::BookingId)
+                    .to_owned(),
+            )
+            .await?;
+
+        exec_unprepared(
+            manager,
+            r#"
+            ALTER TABLE notifications
+                DROP CONSTRAINT IF EXISTS ck_notifications_status,
+                ADD CONSTRAINT ck_notifications_status
+                CHECK (status IN ('PENDING','SENT','FAILED','CANCELLED')),
+                DROP CONSTRAINT IF EXISTS ck_notifications_channel,
+                ADD CONSTRAINT ck_notifications_channel
+                CHECK (channel IN ('EMAIL','SMS','PUSH')),
+                DROP CONSTRAINT IF EXISTS ck_notifications_kind,
+                ADD CONSTRAINT ck_notifications_kind
+                CHECK (kind IN ('EMAIL_VERIFICATION','BOOKING_CONFIRMATION','CHECKIN_REMINDER','FLIGHT_DISRUPTION'));
+            "#,
+        )
+        .await?;
+
+        manager
+            .create_table(
+                Table::create()
+                    .table(NotificationPreferences::Table)
+                    .if_not_exists()
+                    .col(
+                        ColumnDef::new(NotificationPreferences::UserId)
+                            .big_integer()
+                            .not_null()
+                            .primary_key(),
+                    )
+                    .col(string_len_null(NotificationPreferences::Locale, 10))
+                    .col(
+                        ColumnDef::new(NotificationPreferences::EmailEnabled)
+                            .boolean()
+                            .not_null()
+                            .default(true),
+                    )
+                    .col(
+                        ColumnDef::new(NotificationPreferences::SmsEnabled)
+                            .boolean()
+                            .not_null()
+                            .default(false),
+                    )
+                    .col(
+                        ColumnDef::new(NotificationPreferences::PushEnabled)
+                            .boolean()
+                            .not_null()
+                            .default(false),
+                    )
+                    .col(text_null(NotificationPreferences::PushToken))
+                    .col(
+                        ColumnDef::new(NotificationPreferences::CreatedAt)
+                            .date_time()
+                            .not_null()
+                            .default(Expr::current_timestamp()),
+                    )
+                    .col(
+                        ColumnDef::new(NotificationPreferences::UpdatedAt)
+                            .date_time()
+                            .not_null()
+                            .default(Expr::current_timestamp()),
+                    )
+                    .col(big_integer_null(NotificationPreferences::CreatedBy))
+                    .col(big_integer_null(NotificationPreferences::UpdatedBy))
+                    .col(
+                        ColumnDef::new(NotificationPreferences::Version)
+                            .integer()
+                            .not_null()
+                            .default(1),
+                    )
+                    .foreign_key(
+                        ForeignKey::create()
+                            .name("fk_notification_preferences_user")
+                            .from(
+                                NotificationPreferences::Table,
+                                NotificationPreferences::UserId,
+                            )
+                            .to(Users::Table, Users::Id)
+                            .on_delete(ForeignKeyAction::Cascade),
+                    )
+                    .to_owned(),
+            )
+            .await
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .drop_table(
+                Table::drop()
+                    .table(NotificationPreferences::Table)
+                    .to_owned(),
+            )
+            .await?;
+        manager
+            .drop_table(Table::drop().table(Notifications::Table).to_owned())
+            .await
+    }
+}
+
+#[derive(DeriveIden)]
+pub enum Notifications {
+    Table,
+    Id,
+    Kind,
+    Channel,
+    Status,
+    UserId,
+    BookingId,
+    FlightId,
+    Recipient,
+    Locale,
+    Subject,
+    Body,
+    DedupKey,
+    Attempts,
+    LastError,
+    ProviderMessageId,
+    ScheduledAt,
+    SentAt,
+    CreatedAt,
+    UpdatedAt,
+    CreatedBy,
+    UpdatedBy,
+    Version,
+}
+
+#[derive(DeriveIden)]
+pub enum NotificationPreferences {
+    Table,
+    UserId,
+    Locale,
+    EmailEnabled,
+    SmsEnabled,
+    PushEnabled,
+    PushToken,
+    CreatedAt,
+    UpdatedAt,
+    CreatedBy,
+    UpdatedBy,
+    Version,
+}
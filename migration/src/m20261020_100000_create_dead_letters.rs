@@ -0,0 +1,190 @@
+use crate::helpers::exec_unprepared;
+use sea_orm_migration::{prelude::*, schema::*};
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .create_table(
+                Table::create()
+                    .table(DeadLetters::Table)
+                    .if_not_exists()
+                    .col(
+                        ColumnDef::new(DeadLetters::Id)
+                            .big_integer()
+                            .not_null()
+                            .auto_increment()
+                            .primary_key(),
+                    )
+                    .col(
+                        ColumnDef::new(DeadLetters::ConsumerGroup)
+                            .string_len(255)
+                            .not_null(),
+                    )
+                    .col(
+                        ColumnDef::new(DeadLetters::Handler)
+                            .string_len(255)
+                            .not_null(),
+                    )
+                    .col(
+                        ColumnDef::new(DeadLetters::OriginalTopic)
+                            .string_len(255)
+                            .not_null(),
+                    )
+                    .col(
+                        ColumnDef::new(DeadLetters::OriginalPartition)
+                            .integer()
+                            .not_null(),
+                    )
+                    .col(
+                        ColumnDef::new(DeadLetters::OriginalOffset)
+                            .big_integer()
+                            .not_null(),
+                    )
+                    .col(text_null(DeadLetters::MessageKey))
+                    .col(ColumnDef::new(DeadLetters::Payload).blob().not_null())
+                    .col(
+                        ColumnDef::new(DeadLetters::Headers)
+                            .json_binary()
+                            .not_null(),
+                    )
+                    .col(ColumnDef::new(DeadLetters::Error).text().not_null())
+                    .col(
+                        ColumnDef::new(DeadLetters::Attempts)
+                            .integer()
+                            .not_null()
+                            .default(1),
+                    )
+                    .col(
+                        ColumnDef::new(DeadLetters::DeadLetterTopic)
+                            .string_len(255)
+                            .not_null(),
+                    )
+                    .col(
+                        ColumnDef::new(DeadLetters::DeadLetterPartition)
+                            .integer()
+                            .not_null(),
+                    )
+                    .col(
+                        ColumnDef::new(DeadLetters::DeadLetterOffset)
+                            .big_integer()
+                            .not_null(),
+                    )
+                    .col(
+                        ColumnDef::new(DeadLetters::Status)
+                            .string_len(20)
+                            .not_null()
+                            .default("PENDING"),
+                    )
+                    .col(ColumnDef::new(DeadLetters::FailedAt).date_time().not_null())
+                    .col(date_time_null(DeadLetters::ResolvedAt))
+                    .col(big_integer_null(DeadLetters::ResolvedBy))
+                    .col(text_null(DeadLetters::ResolutionNote))
+                    .col(
+                        ColumnDef::new(DeadLetters::CreatedAt)
+                            .date_time()
+                            .not_null()
+                            .default(Expr::current_timestamp()),
+                    )
+                    .col(
+                        ColumnDef::new(DeadLetters::UpdatedAt)
+                            .date_time()
+                            .not_null()
+                            .default(Expr::current_timestamp()),
+                    )
+                    .col(big_integer_null(DeadLetters::CreatedBy))
+                    .col(big_integer_null(DeadLetters::UpdatedBy))
+                    .col(
+                        ColumnDef::new(DeadLetters::Version)
+                            .integer()
+                            .not_null()
+                            .default(1),
+                    )
+                    .to_owned(),
+            )
+            .await?;
+
+        // the archiver may read a dead-letter topic message again after a restart
+        manager
+            .create_index(
+                Index::create()
+                    .name("uq_dead_letters_source")
+                    .table(DeadLetters::Table)
+                    .col(DeadLetters::DeadLetterTopic)
+                    .col(DeadLetters::DeadLetterPartition)
+                    .col(DeadLetters::DeadLetterOffset)
+                    .unique()
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .create_index(
+                Index::create()
+                    .name("idx_dead_letters_status")
+                    .table(DeadLetters::Table)
+                    .col(DeadLetters::Status)
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .create_index(
+                Index::create()
+                    .name("idx_dead_letters_consumer_group")
+                    .table(DeadLetters::Table)
+                    .col(DeadLetters::ConsumerGroup)
+                    .to_owned(),
+            )
+            .await?;
+
+        exec_unprepared(
+            manager,
+            r#"
+            ALTER TABLE dead_letters
+                DROP CONSTRAINT IF EXISTS ck_dead_letters_status,
+                ADD CONSTRAINT ck_dead_letters_status
+                CHECK (status IN ('PENDING','REPLAYED','DISCARDED'));
+            "#,
+        )
+        .await
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .drop_table(Table::drop().table(DeadLetters::Table).to_owned())
+            .await
+    }
+}
+
+#[derive(DeriveIden)]
+pub enum DeadLetters {
+    Table,
+    Id,
+    ConsumerGroup,
+    Handler,
+    OriginalTopic,
+    OriginalPartition,
+    OriginalOffset,
+    MessageKey,
+    Payload,
+    Headers,
+    Error,
+    Attempts,
+    DeadLetterTopic,
+    DeadLetterPartition,
+    DeadLetterOffset,
+    Status,
+    FailedAt,
+    ResolvedAt,
+    ResolvedBy,
+    ResolutionNote,
+    CreatedAt,
+    UpdatedAt,
+    CreatedBy,
+    UpdatedBy,
+    Version,
+}
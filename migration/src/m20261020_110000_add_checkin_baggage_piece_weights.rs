@@ -0,0 +1,35 @@
+use crate::helpers::exec_unprepared;
+use sea_orm_migration::prelude::*;
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        // weight of each checked bag, so a piece allowance can be checked bag by bag
+        exec_unprepared(
+            manager,
+            r#"
+            ALTER TABLE checkins
+            ADD COLUMN IF NOT EXISTS baggage_piece_weights jsonb NOT NULL DEFAULT '[]'::jsonb;
+            "#,
+        )
+        .await?;
+
+        Ok(())
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        exec_unprepared(
+            manager,
+            r#"
+            ALTER TABLE checkins
+            DROP COLUMN IF EXISTS baggage_piece_weights;
+            "#,
+        )
+        .await?;
+
+        Ok(())
+    }
+}
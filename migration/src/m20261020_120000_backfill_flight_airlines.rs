@@ -0,0 +1,207 @@
+use std::collections::HashSet;
+
+use crate::helpers::exec_unprepared;
+use sea_orm_migration::prelude::*;
+use sea_orm_migration::sea_orm::{ConnectionTrait, Statement};
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        // flights created before the airline registry still need an airline to be updated;
+        // the code doubles as the name and the most common origin country stands in for the
+        // home country until someone edits the row
+        exec_unprepared(
+            manager,
+            r#"
+            INSERT INTO airlines (iata_code, name, country_code, is_active)
+            SELECT upper(trim(f.airline_code)),
+                   upper(trim(f.airline_code)),
+                   mode() WITHIN GROUP (ORDER BY a.country_code),
+                   true
+            FROM flights f
+            JOIN airports a ON a.id = f.origin_airport_id
+            WHERE upper(trim(f.airline_code)) ~ '^[A-Z0-9]{2}$'
+            GROUP BY upper(trim(f.airline_code))
+            ON CONFLICT (iata_code) DO NOTHING;
+            "#,
+        )
+        .await?;
+
+        let db = manager.get_connection();
+        let backend = db.get_database_backend();
+        let rows = db
+            .query_all_raw(Statement::from_string(
+                backend,
+                r#"
+                SELECT id, airline_code, flight_number, flight_key,
+                       to_char(departure_date, 'YYYY-MM-DD') AS departure_date
+                FROM flights
+                ORDER BY id;
+                "#,
+            ))
+            .await?;
+        let mut flights = Vec::with_capacity(rows.len());
+        for row in rows {
+            flights.push(FlightRow {
+                id: row.try_get("", "id")?,
+                airline_code: row.try_get("", "airline_code")?,
+                flight_number: row.try_get("", "flight_number")?,
+                flight_key: row.try_get("", "flight_key")?,
+                departure_date: row.try_get("", "departure_date")?,
+            });
+        }
+
+        for update in plan_prefix_strip(&flights) {
+            db.execute_raw(Statement::from_sql_and_values(
+                backend,
+                r#"
+                UPDATE flights
+                SET flight_number = $1, flight_key = $2, version = version + 1
+                WHERE id = $3;
+                "#,
+                [
+                    update.flight_number.into(),
+                    update.flight_key.into(),
+                    update.id.into(),
+                ],
+            ))
+            .await?;
+        }
+
+        Ok(())
+    }
+
+    async fn down(&self, _manager: &SchemaManager) -> Result<(), DbErr> {
+        // data backfill; the original prefixes are not kept
+        Ok(())
+    }
+}
+
+struct FlightRow {
+    id: i64,
+    airline_code: String,
+    flight_number: String,
+    flight_key: String,
+    departure_date: String,
+}
+
+#[derive(Debug, PartialEq, Eq)]
+struct FlightUpdate {
+    id: i64,
+    flight_number: String,
+    flight_key: String,
+}
+
+/// `VN123` -> `123`, keyed `VN123_2026-10-20` like the flight service does; the designator
+/// already lives in airline_code. Rows whose new key would clash with another flight are
+/// left for manual cleanup.
+fn plan_prefix_strip(flights: &[FlightRow]) -> Vec<FlightUpdate> {
+    let mut taken: HashSet<String> = flights.iter().map(|f| f.flight_key.clone()).collect();
+    let mut updates = Vec::new();
+
+    for flight in flights {
+        let Some(flight_number) = strip_prefix(&flight.airline_code, &flight.flight_number) else {
+            continue;
+        };
+        let airline_code = flight.airline_code.trim().to_uppercase();
+        let flight_key = format!(
+            "{}{}_{}",
+            airline_code, flight_number, flight.departure_date
+        );
+        if flight_key != flight.flight_key && taken.contains(&flight_key) {
+            continue;
+        }
+        taken.remove(&flight.flight_key);
+        taken.insert(flight_key.clone());
+        updates.push(FlightUpdate {
+            id: flight.id,
+            flight_number,
+            flight_key,
+        });
+    }
+
+    updates
+}
+
+/// The flight number without the carrier prefix, when it has one and the rest is 1-4
+/// digits with an optional suffix letter.
+fn strip_prefix(airline_code: &str, flight_number: &str) -> Option<String> {
+    let airline_code = airline_code.trim().to_uppercase();
+    if !(2..=3).contains(&airline_code.len())
+        || !airline_code.chars().all(|c| c.is_ascii_alphanumeric())
+    {
+        return None;
+    }
+    let rest = flight_number
+        .trim()
+        .to_uppercase()
+        .strip_prefix(airline_code.as_str())?
+        .trim_start()
+        .to_string();
+
+    let digits = rest.chars().take_while(|c| c.is_ascii_digit()).count();
+    let suffix = &rest[digits..];
+    let valid = (1..=4).contains(&digits)
+        && (suffix.is_empty()
+            || (suffix.len() == 1 && suffix.chars().all(|c| c.is_ascii_uppercase())));
+    valid.then_some(rest)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn flight(id: i64, flight_number: &str, departure_date: &str) -> FlightRow {
+        FlightRow {
+            id,
+            airline_code: "VN".to_string(),
+            flight_number: flight_number.to_string(),
+            flight_key: format!("VN{flight_number}_{departure_date}"),
+            departure_date: departure_date.to_string(),
+        }
+    }
+
+    #[test]
+    fn backfills_one_flight_number_on_two_dates() {
+        let flights = [
+            flight(1, "VN123", "2026-10-20"),
+            flight(2, "VN123", "2026-10-21"),
+        ];
+
+        assert_eq!(
+            plan_prefix_strip(&flights),
+            vec![
+                FlightUpdate {
+                    id: 1,
+                    flight_number: "123".to_string(),
+                    flight_key: "VN123_2026-10-20".to_string(),
+                },
+                FlightUpdate {
+                    id: 2,
+                    flight_number: "123".to_string(),
+                    flight_key: "VN123_2026-10-21".to_string(),
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn skips_a_flight_whose_key_is_taken() {
+        let flights = [
+            flight(1, "123", "2026-10-20"),
+            flight(2, "VN123", "2026-10-20"),
+        ];
+
+        assert!(plan_prefix_strip(&flights).is_empty());
+    }
+
+    #[test]
+    fn strips_only_a_valid_remainder() {
+        assert_eq!(strip_prefix("VN", "vn 123A"), Some("123A".to_string()));
+        assert_eq!(strip_prefix("VN", "123"), None);
+        assert_eq!(strip_prefix("VN", "VN12345"), None);
+    }
+}
@@ -0,0 +1,48 @@
+use crate::helpers::exec_unprepared;
+use sea_orm_migration::prelude::*;
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        // passengers listed at the gate without a seat until they are cleared
+        exec_unprepared(
+            manager,
+            r#"
+            ALTER TABLE checkins
+                DROP CONSTRAINT IF EXISTS ck_checkins_status,
+                ADD CONSTRAINT ck_checkins_status
+                CHECK (status IN ('PENDING','CHECKED_IN','BOARDED','OFFLOADED','CANCELLED','STANDBY'));
+            "#,
+        )
+        .await?;
+
+        Ok(())
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        exec_unprepared(
+            manager,
+            r#"
+            UPDATE checkins SET status = 'PENDING', checked_in_at = NULL
+            WHERE status = 'STANDBY';
+            "#,
+        )
+        .await?;
+
+        exec_unprepared(
+            manager,
+            r#"
+            ALTER TABLE checkins
+                DROP CONSTRAINT IF EXISTS ck_checkins_status,
+                ADD CONSTRAINT ck_checkins_status
+                CHECK (status IN ('PENDING','CHECKED_IN','BOARDED','OFFLOADED','CANCELLED'));
+            "#,
+        )
+        .await?;
+
+        Ok(())
+    }
+}
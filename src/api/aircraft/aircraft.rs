@@ -0,0 +1,181 @@
+use crate::application::aircraft::aircraft_command::UpdateAircraftCommand;
+use crate::core::app_state::AppState;
+use crate::core::context::request_context::RequestContext;
+use crate::core::response::common::{ClientResponseError, EntityResponse};
+use crate::presentation::aircraft::aircraft_request::{
+    CreateAircraftRequest, UpdateAircraftRequest,
+};
+use crate::presentation::aircraft::aircraft_serializer::AircraftSerializer;
+use crate::presentation::http::ApiResult;
+use axum::extract::{Path, Query, State};
+use axum::http::StatusCode;
+use axum::{Extension, Json};
+use serde::Deserialize;
+
+#[derive(Deserialize)]
+pub struct AircraftListQuery {
+    pub airline_code: Option<String>,
+}
+
+#[utoipa::path(
+    post,
+    path = "",
+    tags = ["aircraft"],
+    request_body = CreateAircraftRequest,
+    responses(
+        (status = 201, description = "Aircraft created successfully", body = EntityResponse<bool>),
+        (status = 400, description = "Bad request", body = ClientResponseError),
+        (status = 401, description = "Unauthorized", body = ClientResponseError),
+        (status = 404, description = "Airline not found", body = ClientResponseError),
+        (status = 409, description = "Tail number already registered", body = ClientResponseError),
+        (status = 500, description = "Internal server error", body = ClientResponseError)
+    ),
+    security(("jwt" = []))
+)]
+pub async fn controller_create_aircraft(
+    State(state): State<AppState>,
+    Extension(ctx): Extension<RequestContext>,
+    Json(req): Json<CreateAircraftRequest>,
+) -> ApiResult<(StatusCode, Json<EntityResponse<bool>>)> {
+    let command = req.to_command();
+    let result = state.aircraft_service.create_aircraft(ctx, command).await?;
+
+    Ok((
+        StatusCode::CREATED,
+        Json(EntityResponse {
+            message: "Aircraft created successfully.".to_string(),
+            data: Some(result),
+            total: 1,
+        }),
+    ))
+}
+
+#[utoipa::path(
+    put,
+    path = "/{id}",
+    tags = ["aircraft"],
+    request_body = UpdateAircraftRequest,
+    params(
+        ("id" = i64, Path, description = "Aircraft ID")
+    ),
+    responses(
+        (status = 200, description = "Aircraft updated successfully", body = EntityResponse<bool>),
+        (status = 400, description = "Bad request", body = ClientResponseError),
+        (status = 401, description = "Unauthorized", body = ClientResponseError),
+        (status = 404, description = "Aircraft not found", body = ClientResponseError),
+        (status = 500, description = "Internal server error", body = ClientResponseError)
+    ),
+    security(("jwt" = []))
+)]
+pub async fn controller_update_aircraft(
+    State(state): State<AppState>,
+    Extension(ctx): Extension<RequestContext>,
+    Path(id): Path<i64>,
+    Json(req): Json<UpdateAircraftRequest>,
+) -> ApiResult<Json<EntityResponse<bool>>> {
+    let command: UpdateAircraftCommand = req.into();
+    let result = state
+        .aircraft_service
+        .update_aircraft(ctx, id, command)
+        .await?;
+
+    Ok(Json(EntityResponse {
+        message: "Aircraft updated successfully.".to_string(),
+        data: Some(result),
+        total: 1,
+    }))
+}
+
+#[utoipa::path(
+    get,
+    path = "/{id}",
+    tags = ["aircraft"],
+    params(
+        ("id" = i64, Path, description = "Aircraft ID")
+    ),
+    responses(
+        (status = 200, description = "Aircraft retrieved successfully", body = EntityResponse<AircraftSerializer>),
+        (status = 401, description = "Unauthorized", body = ClientResponseError),
+        (status = 404, description = "Aircraft not found", body = ClientResponseError),
+        (status = 500, description = "Internal server error", body = ClientResponseError)
+    ),
+    security(("jwt" = []))
+)]
+pub async fn controller_get_aircraft_by_id(
+    State(state): State<AppState>,
+    Extension(ctx): Extension<RequestContext>,
+    Path(id): Path<i64>,
+) -> ApiResult<Json<EntityResponse<AircraftSerializer>>> {
+    let result = state.aircraft_service.get_aircraft_by_id(ctx, id).await?;
+
+    Ok(Json(EntityResponse {
+        message: "Aircraft retrieved successfully.".to_string(),
+        data: Some(result.into()),
+        total: 1,
+    }))
+}
+
+#[utoipa::path(
+    get,
+    path = "/tail/{tail_number}",
+    tags = ["aircraft"],
+    params(
+        ("tail_number" = String, Path, description = "Aircraft registration")
+    ),
+    responses(
+        (status = 200, description = "Aircraft retrieved successfully", body = EntityResponse<AircraftSerializer>),
+        (status = 401, description = "Unauthorized", body = ClientResponseError),
+        (status = 404, description = "Aircraft not found", body = ClientResponseError),
+        (status = 500, description = "Internal server error", body = ClientResponseError)
+    ),
+    security(("jwt" = []))
+)]
+pub async fn controller_get_aircraft_by_tail_number(
+    State(state): State<AppState>,
+    Extension(ctx): Extension<RequestContext>,
+    Path(tail_number): Path<String>,
+) -> ApiResult<Json<EntityResponse<AircraftSerializer>>> {
+    let result = state
+        .aircraft_service
+        .get_aircraft_by_tail_number(ctx, tail_number)
+        .await?;
+
+    Ok(Json(EntityResponse {
+        message: "Aircraft retrieved successfully.".to_string(),
+        data: Some(result.into()),
+        total: 1,
+    }))
+}
+
+#[utoipa::path(
+    get,
+    path = "",
+    tags = ["aircraft"],
+    params(
+        ("airline_code" = Option<String>, Query, description = "Only return this operator's fleet")
+    ),
+    responses(
+        (status = 200, description = "Aircraft retrieved successfully", body = EntityResponse<Vec<AircraftSerializer>>),
+        (status = 401, description = "Unauthorized", body = ClientResponseError),
+        (status = 404, description = "Airline not found", body = ClientResponseError),
+        (status = 500, description = "Internal server error", body = ClientResponseError)
+    ),
+    security(("jwt" = []))
+)]
+pub async fn controller_list_aircraft(
+    State(state): State<AppState>,
+    Extension(ctx): Extension<RequestContext>,
+    Query(params): Query<AircraftListQuery>,
+) -> ApiResult<Json<EntityResponse<Vec<AircraftSerializer>>>> {
+    let result = state
+        .aircraft_service
+        .list_aircraft(ctx, params.airline_code)
+        .await?;
+    let data: Vec<AircraftSerializer> = result.into_iter().map(Into::into).collect();
+
+    Ok(Json(EntityResponse {
+        message: "Aircraft retrieved successfully.".to_string(),
+        total: data.len() as i64,
+        data: Some(data),
+    }))
+}
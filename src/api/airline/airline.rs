@@ -0,0 +1,203 @@
+use crate::application::airline::airline_command::UpdateAirlineCommand;
+use crate::core::app_state::AppState;
+use crate::core::context::request_context::RequestContext;
+use crate::core::response::common::{ClientResponseError, EntityResponse};
+use crate::presentation::airline::airline_request::{CreateAirlineRequest, UpdateAirlineRequest};
+use crate::presentation::airline::airline_serializer::AirlineSerializer;
+use crate::presentation::http::ApiResult;
+use axum::extract::{Path, Query, State};
+use axum::http::StatusCode;
+use axum::{Extension, Json};
+use serde::Deserialize;
+
+#[derive(Deserialize)]
+pub struct AirlineListQuery {
+    #[serde(default)]
+    pub active_only: bool,
+}
+
+#[utoipa::path(
+    post,
+    path = "",
+    tags = ["airline"],
+    request_body = CreateAirlineRequest,
+    responses(
+        (status = 201, description = "Airline created successfully", body = EntityResponse<bool>),
+        (status = 400, description = "Bad request", body = ClientResponseError),
+        (status = 401, description = "Unauthorized", body = ClientResponseError),
+        (status = 500, description = "Internal server error", body = ClientResponseError)
+    ),
+    security(("jwt" = []))
+)]
+pub async fn controller_create_airline(
+    State(state): State<AppState>,
+    Extension(ctx): Extension<RequestContext>,
+    Json(req): Json<CreateAirlineRequest>,
+) -> ApiResult<(StatusCode, Json<EntityResponse<bool>>)> {
+    let command = req.to_command();
+    let result = state.airline_service.create_airline(ctx, command).await?;
+
+    Ok((
+        StatusCode::CREATED,
+        Json(EntityResponse {
+            message: "Airline created successfully.".to_string(),
+            data: Some(result),
+            total: 1,
+        }),
+    ))
+}
+
+#[utoipa::path(
+    put,
+    path = "/{id}",
+    tags = ["airline"],
+    request_body = UpdateAirlineRequest,
+    params(
+        ("id" = i64, Path, description = "Airline ID")
+    ),
+    responses(
+        (status = 200, description = "Airline updated successfully", body = EntityResponse<bool>),
+        (status = 400, description = "Bad request", body = ClientResponseError),
+        (status = 401, description = "Unauthorized", body = ClientResponseError),
+        (status = 404, description = "Airline not found", body = ClientResponseError),
+        (status = 500, description = "Internal server error", body = ClientResponseError)
+    ),
+    security(("jwt" = []))
+)]
+pub async fn controller_update_airline(
+    State(state): State<AppState>,
+    Extension(ctx): Extension<RequestContext>,
+    Path(id): Path<i64>,
+    Json(req): Json<UpdateAirlineRequest>,
+) -> ApiResult<Json<EntityResponse<bool>>> {
+    let command: UpdateAirlineCommand = req.into();
+    let result = state.airline_service.update_airline(ctx, id, command).await?;
+
+    Ok(Json(EntityResponse {
+        message: "Airline updated successfully.".to_string(),
+        data: Some(result),
+        total: 1,
+    }))
+}
+
+#[utoipa::path(
+    get,
+    path = "/{id}",
+    tags = ["airline"],
+    params(
+        ("id" = i64, Path, description = "Airline ID")
+    ),
+    responses(
+        (status = 200, description = "Airline retrieved successfully", body = EntityResponse<AirlineSerializer>),
+        (status = 401, description = "Unauthorized", body = ClientResponseError),
+        (status = 404, description = "Airline not found", body = ClientResponseError),
+        (status = 500, description = "Internal server error", body = ClientResponseError)
+    ),
+    security(("jwt" = []))
+)]
+pub async fn controller_get_airline_by_id(
+    State(state): State<AppState>,
+    Extension(ctx): Extension<RequestContext>,
+    Path(id): Path<i64>,
+) -> ApiResult<Json<EntityResponse<AirlineSerializer>>> {
+    let result = state.airline_service.get_airline_by_id(ctx, id).await?;
+
+    Ok(Json(EntityResponse {
+        message: "Airline retrieved successfully.".to_string(),
+        data: Some(result.into()),
+        total: 1,
+    }))
+}
+
+#[utoipa::path(
+    get,
+    path = "/iata/{iata_code}",
+    tags = ["airline"],
+    params(
+        ("iata_code" = String, Path, description = "IATA airline designator")
+    ),
+    responses(
+        (status = 200, description = "Airline retrieved successfully", body = EntityResponse<AirlineSerializer>),
+        (status = 401, description = "Unauthorized", body = ClientResponseError),
+        (status = 404, description = "Airline not found", body = ClientResponseError),
+        (status = 500, description = "Internal server error", body = ClientResponseError)
+    ),
+    security(("jwt" = []))
+)]
+pub async fn controller_get_airline_by_iata_code(
+    State(state): State<AppState>,
+    Extension(ctx): Extension<RequestContext>,
+    Path(iata_code): Path<String>,
+) -> ApiResult<Json<EntityResponse<AirlineSerializer>>> {
+    let result = state
+        .airline_service
+        .get_airline_by_iata_code(ctx, iata_code)
+        .await?;
+
+    Ok(Json(EntityResponse {
+        message: "Airline retrieved successfully.".to_string(),
+        data: Some(result.into()),
+        total: 1,
+    }))
+}
+
+#[utoipa::path(
+    get,
+    path = "",
+    tags = ["airline"],
+    params(
+        ("active_only" = Option<bool>, Query, description = "Only return active airlines")
+    ),
+    responses(
+        (status = 200, description = "Airlines retrieved successfully", body = EntityResponse<Vec<AirlineSerializer>>),
+        (status = 401, description = "Unauthorized", body = ClientResponseError),
+        (status = 500, description = "Internal server error", body = ClientResponseError)
+    ),
+    security(("jwt" = []))
+)]
+pub async fn controller_list_airlines(
+    State(state): State<AppState>,
+    Extension(ctx): Extension<RequestContext>,
+    Query(params): Query<AirlineListQuery>,
+) -> ApiResult<Json<EntityResponse<Vec<AirlineSerializer>>>> {
+    let result = state
+        .airline_service
+        .list_airlines(ctx, params.active_only)
+        .await?;
+    let data: Vec<AirlineSerializer> = result.into_iter().map(Into::into).collect();
+
+    Ok(Json(EntityResponse {
+        message: "Airlines retrieved successfully.".to_string(),
+        total: data.len() as i64,
+        data: Some(data),
+    }))
+}
+
+#[utoipa::path(
+    delete,
+    path = "/{id}",
+    tags = ["airline"],
+    params(
+        ("id" = i64, Path, description = "Airline ID")
+    ),
+    responses(
+        (status = 200, description = "Airline deactivated successfully", body = EntityResponse<bool>),
+        (status = 401, description = "Unauthorized", body = ClientResponseError),
+        (status = 404, description = "Airline not found", body = ClientResponseError),
+        (status = 500, description = "Internal server error", body = ClientResponseError)
+    ),
+    security(("jwt" = []))
+)]
+pub async fn controller_deactivate_airline(
+    State(state): State<AppState>,
+    Extension(ctx): Extension<RequestContext>,
+    Path(id): Path<i64>,
+) -> ApiResult<Json<EntityResponse<bool>>> {
+    let result = state.airline_service.deactivate_airline(ctx, id).await?;
+
+    Ok(Json(EntityResponse {
+        message: "Airline deactivated successfully.".to_string(),
+        data: Some(result),
+        total: 1,
+    }))
+}
@@ -2,12 +2,18 @@ use crate::application::airport::airport_command::UpdateAirportCommand;
 use crate::core::app_state::AppState;
 use crate::core::context::request_context::RequestContext;
 use crate::core::response::common::{ClientResponseError, EntityResponse};
-use crate::presentation::airport::airport_request::{CreateAirportRequest, UpdateAirportRequest};
-use crate::presentation::airport::airport_serializer::AirportSerializer;
+use crate::presentation::airport::airport_request::{
+    CreateAirportRequest, ImportAirportsQuery, ImportAirportsRequest, NearbyAirportsQuery,
+    SearchAirportsQuery, UpdateAirportRequest,
+};
+use crate::presentation::airport::airport_serializer::{
+    AirportImportReportSerializer, AirportSerializer, NearbyAirportSerializer,
+};
 use crate::presentation::http::ApiResult;
 use axum::extract::{Path, Query, State};
 use axum::http::StatusCode;
 use axum::{Extension, Json};
+use axum_extra::extract::Multipart;
 use serde::Deserialize;
 
 #[derive(Deserialize)]
@@ -201,3 +207,103 @@ pub async fn controller_deactivate_airport(
         total: 1,
     }))
 }
+
+#[utoipa::path(
+    get,
+    path = "/search",
+    tags = ["airport"],
+    params(SearchAirportsQuery),
+    responses(
+        (status = 200, description = "Airports retrieved successfully", body = EntityResponse<Vec<AirportSerializer>>),
+        (status = 400, description = "Bad request", body = ClientResponseError),
+        (status = 401, description = "Unauthorized", body = ClientResponseError),
+        (status = 500, description = "Internal server error", body = ClientResponseError)
+    ),
+    security(("jwt" = []))
+)]
+pub async fn controller_search_airports(
+    State(state): State<AppState>,
+    Extension(ctx): Extension<RequestContext>,
+    Query(params): Query<SearchAirportsQuery>,
+) -> ApiResult<Json<EntityResponse<Vec<AirportSerializer>>>> {
+    let result = state
+        .airport_service
+        .search_airports(ctx, params.into())
+        .await?;
+    let data: Vec<AirportSerializer> = result.into_iter().map(Into::into).collect();
+
+    Ok(Json(EntityResponse {
+        message: "Airports retrieved successfully.".to_string(),
+        total: data.len() as i64,
+        data: Some(data),
+    }))
+}
+
+#[utoipa::path(
+    get,
+    path = "/nearby",
+    tags = ["airport"],
+    params(NearbyAirportsQuery),
+    responses(
+        (status = 200, description = "Airports retrieved successfully", body = EntityResponse<Vec<NearbyAirportSerializer>>),
+        (status = 400, description = "Bad request", body = ClientResponseError),
+        (status = 401, description = "Unauthorized", body = ClientResponseError),
+        (status = 500, description = "Internal server error", body = ClientResponseError)
+    ),
+    security(("jwt" = []))
+)]
+pub async fn controller_find_nearby_airports(
+    State(state): State<AppState>,
+    Extension(ctx): Extension<RequestContext>,
+    Query(params): Query<NearbyAirportsQuery>,
+) -> ApiResult<Json<EntityResponse<Vec<NearbyAirportSerializer>>>> {
+    let result = state
+        .airport_service
+        .find_nearby_airports(ctx, params.into())
+        .await?;
+    let data: Vec<NearbyAirportSerializer> = result.into_iter().map(Into::into).collect();
+
+    Ok(Json(EntityResponse {
+        message: "Airports retrieved successfully.".to_string(),
+        total: data.len() as i64,
+        data: Some(data),
+    }))
+}
+
+#[utoipa::path(
+    post,
+    path = "/import",
+    tags = ["airport"],
+    request_body(content = ImportAirportsRequest, content_type = "multipart/form-data"),
+    params(ImportAirportsQuery),
+    responses(
+        (status = 200, description = "Airport import processed", body = EntityResponse<AirportImportReportSerializer>),
+        (status = 400, description = "Bad request", body = ClientResponseError),
+        (status = 401, description = "Unauthorized", body = ClientResponseError),
+        (status = 500, description = "Internal server error", body = ClientResponseError)
+    ),
+    security(("jwt" = []))
+)]
+pub async fn controller_import_airports(
+    State(state): State<AppState>,
+    Extension(ctx): Extension<RequestContext>,
+    Query(params): Query<ImportAirportsQuery>,
+    multipart: Multipart,
+) -> ApiResult<Json<EntityResponse<AirportImportReportSerializer>>> {
+    let req = ImportAirportsRequest::from_multipart(multipart).await?;
+    let result = state
+        .airport_service
+        .import_airports(ctx, req.to_command(params.dry_run))
+        .await?;
+
+    let message = if result.dry_run {
+        "Airport import dry run completed."
+    } else {
+        "Airport import completed."
+    };
+    Ok(Json(EntityResponse {
+        message: message.to_string(),
+        data: Some(result.into()),
+        total: 1,
+    }))
+}
@@ -0,0 +1,115 @@
+use crate::core::app_state::AppState;
+use crate::core::context::request_context::RequestContext;
+use crate::core::response::common::{ClientResponseError, EntityResponse};
+use crate::presentation::baggage::baggage_request::{IssueBagTagRequest, UpdateBagStatusRequest};
+use crate::presentation::baggage::baggage_serializer::BagSerializer;
+use crate::presentation::http::ApiResult;
+use axum::extract::{Path, State};
+use axum::{Extension, Json};
+
+#[utoipa::path(
+    post,
+    path = "/{id}/bags",
+    tags = ["baggage"],
+    params(
+        ("id" = i64, Path, description = "Checkin ID")
+    ),
+    request_body = IssueBagTagRequest,
+    responses(
+        (status = 200, description = "Bag tag issued", body = EntityResponse<BagSerializer>),
+        (status = 400, description = "Bad request or all declared bags already tagged", body = ClientResponseError),
+        (status = 401, description = "Unauthorized", body = ClientResponseError),
+        (status = 403, description = "Forbidden", body = ClientResponseError),
+        (status = 404, description = "Checkin not found", body = ClientResponseError),
+        (status = 500, description = "Internal server error", body = ClientResponseError)
+    ),
+    security(("jwt" = []))
+)]
+pub async fn controller_issue_bag_tag(
+    State(state): State<AppState>,
+    Extension(ctx): Extension<RequestContext>,
+    Path(id): Path<i64>,
+    Json(req): Json<IssueBagTagRequest>,
+) -> ApiResult<Json<EntityResponse<BagSerializer>>> {
+    let result = state
+        .baggage_service
+        .issue_bag_tag(ctx, id, req.into())
+        .await?;
+
+    Ok(Json(EntityResponse {
+        message: "Bag tag issued successfully.".to_string(),
+        data: Some(result.into()),
+        total: 1,
+    }))
+}
+
+#[utoipa::path(
+    put,
+    path = "/{tag_number}/status",
+    tags = ["baggage"],
+    params(
+        ("tag_number" = String, Path, description = "10-digit bag tag number")
+    ),
+    request_body = UpdateBagStatusRequest,
+    responses(
+        (status = 200, description = "Bag status updated", body = EntityResponse<BagSerializer>),
+        (status = 400, description = "Bad request", body = ClientResponseError),
+        (status = 401, description = "Unauthorized", body = ClientResponseError),
+        (status = 403, description = "Forbidden", body = ClientResponseError),
+        (status = 404, description = "Bag not found", body = ClientResponseError),
+        (status = 409, description = "Status transition not allowed or version conflict", body = ClientResponseError),
+        (status = 500, description = "Internal server error", body = ClientResponseError)
+    ),
+    security(("jwt" = []))
+)]
+pub async fn controller_update_bag_status(
+    State(state): State<AppState>,
+    Extension(ctx): Extension<RequestContext>,
+    Path(tag_number): Path<String>,
+    Json(req): Json<UpdateBagStatusRequest>,
+) -> ApiResult<Json<EntityResponse<BagSerializer>>> {
+    let result = state
+        .baggage_service
+        .update_bag_status(ctx, tag_number, req.into())
+        .await?;
+
+    Ok(Json(EntityResponse {
+        message: "Bag status updated successfully.".to_string(),
+        data: Some(result.into()),
+        total: 1,
+    }))
+}
+
+#[utoipa::path(
+    get,
+    path = "/booking/{code}",
+    tags = ["baggage"],
+    params(
+        ("code" = String, Path, description = "Booking code")
+    ),
+    responses(
+        (status = 200, description = "Bags retrieved successfully", body = EntityResponse<Vec<BagSerializer>>),
+        (status = 401, description = "Unauthorized", body = ClientResponseError),
+        (status = 403, description = "Forbidden", body = ClientResponseError),
+        (status = 404, description = "Booking not found", body = ClientResponseError),
+        (status = 500, description = "Internal server error", body = ClientResponseError)
+    ),
+    security(("jwt" = []))
+)]
+pub async fn controller_track_bags_by_booking_code(
+    State(state): State<AppState>,
+    Extension(ctx): Extension<RequestContext>,
+    Path(code): Path<String>,
+) -> ApiResult<Json<EntityResponse<Vec<BagSerializer>>>> {
+    let result = state
+        .baggage_service
+        .track_bags_by_booking_code(ctx, code)
+        .await?;
+    let data: Vec<BagSerializer> = result.into_iter().map(Into::into).collect();
+
+    Ok(Json(EntityResponse {
+        message: "Bags retrieved successfully.".to_string(),
+        data: Some(data.clone()),
+        total: data.len() as i64,
+    }))
+}
@@ -0,0 +1,247 @@
+use crate::application::boarding::boarding_command::BoardingActionCommand;
+use crate::core::app_state::AppState;
+use crate::core::context::request_context::RequestContext;
+use crate::core::response::common::{ClientResponseError, EntityResponse};
+use crate::presentation::boarding::boarding_request::{
+    ClearStandbyRequest, ScanBoardingPassRequest,
+};
+use crate::presentation::boarding::boarding_serializer::{
+    BoardingReconciliationSerializer, BoardingStatusSerializer,
+};
+use crate::presentation::http::ApiResult;
+use axum::extract::{Path, State};
+use axum::{Extension, Json};
+
+#[utoipa::path(
+    post,
+    path = "/{id}/boarding/scan",
+    tags = ["boarding"],
+    params(
+        ("id" = i64, Path, description = "Flight ID")
+    ),
+    request_body = ScanBoardingPassRequest,
+    responses(
+        (status = 200, description = "Passenger boarded", body = EntityResponse<BoardingStatusSerializer>),
+        (status = 400, description = "Pass rejected (signature, wrong flight, revoked or outside boarding time)", body = ClientResponseError),
+        (status = 401, description = "Unauthorized", body = ClientResponseError),
+        (status = 403, description = "Forbidden", body = ClientResponseError),
+        (status = 404, description = "Flight or boarding pass not found", body = ClientResponseError),
+        (status = 409, description = "Passenger already boarded", body = ClientResponseError),
+        (status = 500, description = "Internal server error", body = ClientResponseError)
+    ),
+    security(("jwt" = []))
+)]
+pub async fn controller_scan_boarding_pass(
+    State(state): State<AppState>,
+    Extension(ctx): Extension<RequestContext>,
+    Path(id): Path<i64>,
+    Json(req): Json<ScanBoardingPassRequest>,
+) -> ApiResult<Json<EntityResponse<BoardingStatusSerializer>>> {
+    let command = req.to_command(id);
+    let result = state
+        .boarding_service
+        .scan_boarding_pass(ctx, command)
+        .await?;
+
+    Ok(Json(EntityResponse {
+        message: "Passenger boarded successfully.".to_string(),
+        data: Some(result.into()),
+        total: 1,
+    }))
+}
+
+#[utoipa::path(
+    post,
+    path = "/{id}/boarding/{checkin_id}/undo",
+    tags = ["boarding"],
+    params(
+        ("id" = i64, Path, description = "Flight ID"),
+        ("checkin_id" = i64, Path, description = "Checkin ID")
+    ),
+    responses(
+        (status = 200, description = "Boarding scan reverted", body = EntityResponse<BoardingStatusSerializer>),
+        (status = 400, description = "Bad request", body = ClientResponseError),
+        (status = 401, description = "Unauthorized", body = ClientResponseError),
+        (status = 403, description = "Forbidden", body = ClientResponseError),
+        (status = 404, description = "Flight or checkin not found", body = ClientResponseError),
+        (status = 409, description = "Passenger is not boarded", body = ClientResponseError),
+        (status = 500, description = "Internal server error", body = ClientResponseError)
+    ),
+    security(("jwt" = []))
+)]
+pub async fn controller_undo_boarding(
+    State(state): State<AppState>,
+    Extension(ctx): Extension<RequestContext>,
+    Path((id, checkin_id)): Path<(i64, i64)>,
+) -> ApiResult<Json<EntityResponse<BoardingStatusSerializer>>> {
+    let result = state
+        .boarding_service
+        .undo_boarding(
+            ctx,
+            BoardingActionCommand {
+                flight_id: id,
+                checkin_id,
+            },
+        )
+        .await?;
+
+    Ok(Json(EntityResponse {
+        message: "Boarding reverted successfully.".to_string(),
+        data: Some(result.into()),
+        total: 1,
+    }))
+}
+
+#[utoipa::path(
+    post,
+    path = "/{id}/boarding/{checkin_id}/offload",
+    tags = ["boarding"],
+    params(
+        ("id" = i64, Path, description = "Flight ID"),
+        ("checkin_id" = i64, Path, description = "Checkin ID")
+    ),
+    responses(
+        (status = 200, description = "Passenger offloaded", body = EntityResponse<BoardingStatusSerializer>),
+        (status = 400, description = "Bad request", body = ClientResponseError),
+        (status = 401, description = "Unauthorized", body = ClientResponseError),
+        (status = 403, description = "Forbidden", body = ClientResponseError),
+        (status = 404, description = "Flight or checkin not found", body = ClientResponseError),
+        (status = 409, description = "Passenger cannot be offloaded in the current status", body = ClientResponseError),
+        (status = 500, description = "Internal server error", body = ClientResponseError)
+    ),
+    security(("jwt" = []))
+)]
+pub async fn controller_offload_passenger(
+    State(state): State<AppState>,
+    Extension(ctx): Extension<RequestContext>,
+    Path((id, checkin_id)): Path<(i64, i64)>,
+) -> ApiResult<Json<EntityResponse<BoardingStatusSerializer>>> {
+    let result = state
+        .boarding_service
+        .offload_passenger(
+            ctx,
+            BoardingActionCommand {
+                flight_id: id,
+                checkin_id,
+            },
+        )
+        .await?;
+
+    Ok(Json(EntityResponse {
+        message: "Passenger offloaded successfully.".to_string(),
+        data: Some(result.into()),
+        total: 1,
+    }))
+}
+
+#[utoipa::path(
+    post,
+    path = "/{id}/boarding/{checkin_id}/standby",
+    tags = ["boarding"],
+    params(
+        ("id" = i64, Path, description = "Flight ID"),
+        ("checkin_id" = i64, Path, description = "Checkin ID")
+    ),
+    responses(
+        (status = 200, description = "Passenger listed on standby", body = EntityResponse<BoardingStatusSerializer>),
+        (status = 400, description = "Bad request", body = ClientResponseError),
+        (status = 401, description = "Unauthorized", body = ClientResponseError),
+        (status = 403, description = "Forbidden", body = ClientResponseError),
+        (status = 404, description = "Flight or checkin not found", body = ClientResponseError),
+        (status = 409, description = "Checkin is not pending", body = ClientResponseError),
+        (status = 500, description = "Internal server error", body = ClientResponseError)
+    ),
+    security(("jwt" = []))
+)]
+pub async fn controller_list_on_standby(
+    State(state): State<AppState>,
+    Extension(ctx): Extension<RequestContext>,
+    Path((id, checkin_id)): Path<(i64, i64)>,
+) -> ApiResult<Json<EntityResponse<BoardingStatusSerializer>>> {
+    let result = state
+        .boarding_service
+        .list_on_standby(
+            ctx,
+            BoardingActionCommand {
+                flight_id: id,
+                checkin_id,
+            },
+        )
+        .await?;
+
+    Ok(Json(EntityResponse {
+        message: "Passenger listed on standby successfully.".to_string(),
+        data: Some(result.into()),
+        total: 1,
+    }))
+}
+
+#[utoipa::path(
+    post,
+    path = "/{id}/boarding/{checkin_id}/standby/clear",
+    tags = ["boarding"],
+    params(
+        ("id" = i64, Path, description = "Flight ID"),
+        ("checkin_id" = i64, Path, description = "Checkin ID")
+    ),
+    request_body = ClearStandbyRequest,
+    responses(
+        (status = 200, description = "Standby passenger cleared into a seat", body = EntityResponse<BoardingStatusSerializer>),
+        (status = 400, description = "Bad request", body = ClientResponseError),
+        (status = 401, description = "Unauthorized", body = ClientResponseError),
+        (status = 403, description = "Forbidden", body = ClientResponseError),
+        (status = 404, description = "Flight or checkin not found", body = ClientResponseError),
+        (status = 409, description = "Passenger is not on standby", body = ClientResponseError),
+        (status = 500, description = "Internal server error", body = ClientResponseError)
+    ),
+    security(("jwt" = []))
+)]
+pub async fn controller_clear_standby(
+    State(state): State<AppState>,
+    Extension(ctx): Extension<RequestContext>,
+    Path((id, checkin_id)): Path<(i64, i64)>,
+    Json(req): Json<ClearStandbyRequest>,
+) -> ApiResult<Json<EntityResponse<BoardingStatusSerializer>>> {
+    let command = req.to_command(id, checkin_id);
+    let result = state.boarding_service.clear_standby(ctx, command).await?;
+
+    Ok(Json(EntityResponse {
+        message: "Standby passenger cleared successfully.".to_string(),
+        data: Some(result.into()),
+        total: 1,
+    }))
+}
+
+#[utoipa::path(
+    get,
+    path = "/{id}/boarding/reconciliation",
+    tags = ["boarding"],
+    params(
+        ("id" = i64, Path, description = "Flight ID")
+    ),
+    responses(
+        (status = 200, description = "Boarding reconciliation built successfully", body = EntityResponse<BoardingReconciliationSerializer>),
+        (status = 401, description = "Unauthorized", body = ClientResponseError),
+        (status = 403, description = "Forbidden", body = ClientResponseError),
+        (status = 404, description = "Flight not found", body = ClientResponseError),
+        (status = 500, description = "Internal server error", body = ClientResponseError)
+    ),
+    security(("jwt" = []))
+)]
+pub async fn controller_reconcile_boarding(
+    State(state): State<AppState>,
+    Extension(ctx): Extension<RequestContext>,
+    Path(id): Path<i64>,
+) -> ApiResult<Json<EntityResponse<BoardingReconciliationSerializer>>> {
+    let result = state.boarding_service.reconcile_boarding(ctx, id).await?;
+    let data: BoardingReconciliationSerializer = result.into();
+
+    Ok(Json(EntityResponse {
+        message: format!(
+            "{} boarded, {} no-show(s), {} offloaded, {} on standby.",
+            data.boarded, data.no_show, data.offloaded, data.standby
+        ),
+        total: data.booked as i64,
+        data: Some(data),
+    }))
+}
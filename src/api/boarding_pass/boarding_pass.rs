@@ -1,13 +1,20 @@
+use crate::application::boarding_pass::boarding_pass_command::{
+    BoardingPassDocumentFormat, RenderBoardingPassCommand,
+};
+use crate::application::boarding_pass::view::boarding_pass_view::BoardingPassFileView;
 use crate::core::app_state::AppState;
 use crate::core::context::request_context::RequestContext;
 use crate::core::response::common::{ClientResponseError, EntityResponse};
 use crate::presentation::boarding_pass::boarding_pass_request::{
-    IssueBoardingPassRequest, ListBoardingPassesQuery,
+    IssueBoardingPassRequest, ListBoardingPassesQuery, VerifyBoardingPassRequest,
+};
+use crate::presentation::boarding_pass::boarding_pass_serializer::{
+    BoardingPassSerializer, BoardingPassVerificationSerializer,
 };
-use crate::presentation::boarding_pass::boarding_pass_serializer::BoardingPassSerializer;
 use crate::presentation::http::ApiResult;
 use axum::extract::{Path, Query, State};
-use axum::http::StatusCode;
+use axum::http::{StatusCode, header};
+use axum::response::{IntoResponse, Response};
 use axum::{Extension, Json};
 
 #[utoipa::path(
@@ -137,3 +144,158 @@ pub async fn controller_list_by_booking(
         total: data.len() as i64,
     }))
 }
+
+#[utoipa::path(
+    post,
+    path = "/verify",
+    tags = ["boarding_pass"],
+    request_body = VerifyBoardingPassRequest,
+    responses(
+        (status = 200, description = "Boarding pass verified (see `valid` and `reason`)", body = EntityResponse<BoardingPassVerificationSerializer>),
+        (status = 401, description = "Unauthorized", body = ClientResponseError),
+        (status = 403, description = "Forbidden", body = ClientResponseError),
+        (status = 500, description = "Internal server error", body = ClientResponseError)
+    ),
+    security(("jwt" = []))
+)]
+pub async fn controller_verify_boarding_pass(
+    State(state): State<AppState>,
+    Extension(ctx): Extension<RequestContext>,
+    Json(req): Json<VerifyBoardingPassRequest>,
+) -> ApiResult<Json<EntityResponse<BoardingPassVerificationSerializer>>> {
+    let command = req.to_command();
+    let result = state
+        .boarding_pass_service
+        .verify_boarding_pass(ctx, command)
+        .await?;
+
+    Ok(Json(EntityResponse {
+        message: if result.valid {
+            "Boarding pass is valid.".to_string()
+        } else {
+            "Boarding pass is not valid.".to_string()
+        },
+        data: Some(result.into()),
+        total: 1,
+    }))
+}
+
+#[utoipa::path(
+    get,
+    path = "/{code}/pdf",
+    tags = ["boarding_pass"],
+    params(
+        ("code" = String, Path, description = "Boarding pass code")
+    ),
+    responses(
+        (status = 200, description = "Printable boarding pass", content_type = "application/pdf", body = Vec<u8>),
+        (status = 401, description = "Unauthorized", body = ClientResponseError),
+        (status = 403, description = "Forbidden", body = ClientResponseError),
+        (status = 404, description = "Boarding pass not found", body = ClientResponseError),
+        (status = 500, description = "Internal server error", body = ClientResponseError)
+    ),
+    security(("jwt" = []))
+)]
+pub async fn controller_get_pdf(
+    State(state): State<AppState>,
+    Extension(ctx): Extension<RequestContext>,
+    Path(code): Path<String>,
+) -> ApiResult<Response> {
+    let file = state
+        .boarding_pass_service
+        .render_boarding_pass(
+            ctx,
+            RenderBoardingPassCommand {
+                code,
+                format: BoardingPassDocumentFormat::Pdf,
+            },
+        )
+        .await?;
+
+    Ok(inline_file(file))
+}
+
+#[utoipa::path(
+    get,
+    path = "/{code}/png",
+    tags = ["boarding_pass"],
+    params(
+        ("code" = String, Path, description = "Boarding pass code")
+    ),
+    responses(
+        (status = 200, description = "Boarding pass image", content_type = "image/png", body = Vec<u8>),
+        (status = 401, description = "Unauthorized", body = ClientResponseError),
+        (status = 403, description = "Forbidden", body = ClientResponseError),
+        (status = 404, description = "Boarding pass not found", body = ClientResponseError),
+        (status = 500, description = "Internal server error", body = ClientResponseError)
+    ),
+    security(("jwt" = []))
+)]
+pub async fn controller_get_png(
+    State(state): State<AppState>,
+    Extension(ctx): Extension<RequestContext>,
+    Path(code): Path<String>,
+) -> ApiResult<Response> {
+    let file = state
+        .boarding_pass_service
+        .render_boarding_pass(
+            ctx,
+            RenderBoardingPassCommand {
+                code,
+                format: BoardingPassDocumentFormat::Png,
+            },
+        )
+        .await?;
+
+    Ok(inline_file(file))
+}
+
+#[utoipa::path(
+    get,
+    path = "/{code}/pkpass",
+    tags = ["boarding_pass"],
+    params(
+        ("code" = String, Path, description = "Boarding pass code")
+    ),
+    responses(
+        (status = 200, description = "Signed wallet pass bundle", content_type = "application/vnd.apple.pkpass", body = Vec<u8>),
+        (status = 400, description = "Wallet passes are not configured", body = ClientResponseError),
+        (status = 401, description = "Unauthorized", body = ClientResponseError),
+        (status = 403, description = "Forbidden", body = ClientResponseError),
+        (status = 404, description = "Boarding pass not found", body = ClientResponseError),
+        (status = 500, description = "Internal server error", body = ClientResponseError)
+    ),
+    security(("jwt" = []))
+)]
+pub async fn controller_get_pkpass(
+    State(state): State<AppState>,
+    Extension(ctx): Extension<RequestContext>,
+    Path(code): Path<String>,
+) -> ApiResult<Response> {
+    let file = state
+        .boarding_pass_service
+        .render_boarding_pass(
+            ctx,
+            RenderBoardingPassCommand {
+                code,
+                format: BoardingPassDocumentFormat::Pkpass,
+            },
+        )
+        .await?;
+
+    Ok(inline_file(file))
+}
+
+fn inline_file(file: BoardingPassFileView) -> Response {
+    (
+        [
+            (header::CONTENT_TYPE, file.content_type),
+            (
+                header::CONTENT_DISPOSITION,
+                format!("inline; filename=\"{}\"", file.file_name),
+            ),
+        ],
+        file.content,
+    )
+        .into_response()
+}
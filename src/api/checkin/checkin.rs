@@ -1,9 +1,12 @@
-use crate::application::checkin::checkin_command::{CancelCheckinCommand, UpdateCheckinCommand};
+use crate::application::checkin::checkin_command::{
+    CancelCheckinCommand, PayExcessBaggageCommand, UpdateCheckinCommand,
+};
 use crate::core::app_state::AppState;
 use crate::core::context::request_context::RequestContext;
 use crate::core::response::common::{ClientResponseError, EntityResponse};
 use crate::presentation::checkin::checkin_request::{
-    CancelCheckinRequest, CreateCheckinRequest, ListCheckinsQuery, UpdateCheckinRequest,
+    CancelCheckinRequest, CreateCheckinRequest, ListCheckinsQuery, PayExcessBaggageRequest,
+    UpdateCheckinRequest,
 };
 use crate::presentation::checkin::checkin_serializer::CheckinSerializer;
 use crate::presentation::http::ApiResult;
@@ -71,6 +74,44 @@ pub async fn controller_update_checkin(
     }))
 }
 
+#[utoipa::path(
+    post,
+    path = "/{id}/excess-baggage/payment",
+    tags = ["checkin"],
+    request_body = PayExcessBaggageRequest,
+    params(
+        ("id" = i64, Path, description = "Check-in ID")
+    ),
+    responses(
+        (status = 200, description = "Excess baggage paid and check-in completed", body = EntityResponse<bool>),
+        (status = 400, description = "Bad request", body = ClientResponseError),
+        (status = 401, description = "Unauthorized", body = ClientResponseError),
+        (status = 403, description = "Only staff or the payment callback can confirm a payment", body = ClientResponseError),
+        (status = 404, description = "Check-in not found", body = ClientResponseError),
+        (status = 409, description = "Check-in changed concurrently", body = ClientResponseError),
+        (status = 500, description = "Internal server error", body = ClientResponseError)
+    ),
+    security(("jwt" = []))
+)]
+pub async fn controller_pay_excess_baggage(
+    State(state): State<AppState>,
+    Extension(ctx): Extension<RequestContext>,
+    Path(id): Path<i64>,
+    Json(req): Json<PayExcessBaggageRequest>,
+) -> ApiResult<Json<EntityResponse<bool>>> {
+    let command: PayExcessBaggageCommand = req.into();
+    let result = state
+        .checkin_service
+        .pay_excess_baggage(ctx, id, command)
+        .await?;
+
+    Ok(Json(EntityResponse {
+        message: "Excess baggage paid and check-in completed.".to_string(),
+        data: Some(result),
+        total: 1,
+    }))
+}
+
 #[utoipa::path(
     delete,
     path = "/{id}",
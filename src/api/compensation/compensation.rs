@@ -0,0 +1,211 @@
+use crate::core::app_state::AppState;
+use crate::core::context::request_context::RequestContext;
+use crate::core::response::common::{ClientResponseError, EntityResponse};
+use crate::presentation::compensation::compensation_request::{
+    DecideCompensationClaimRequest, ListCompensationClaimsQuery, SubmitCompensationClaimRequest,
+};
+use crate::presentation::compensation::compensation_serializer::{
+    CompensationAssessmentSerializer, CompensationClaimSerializer,
+};
+use crate::presentation::http::ApiResult;
+use axum::extract::{Path, Query, State};
+use axum::{Extension, Json};
+
+#[utoipa::path(
+    get,
+    path = "/assessment/{code}",
+    tags = ["compensation"],
+    params(
+        ("code" = String, Path, description = "Booking code")
+    ),
+    responses(
+        (status = 200, description = "Compensation assessed", body = EntityResponse<CompensationAssessmentSerializer>),
+        (status = 400, description = "Flight distance unknown", body = ClientResponseError),
+        (status = 401, description = "Unauthorized", body = ClientResponseError),
+        (status = 403, description = "Forbidden", body = ClientResponseError),
+        (status = 404, description = "Booking not found", body = ClientResponseError),
+        (status = 500, description = "Internal server error", body = ClientResponseError)
+    ),
+    security(("jwt" = []))
+)]
+pub async fn controller_assess_compensation(
+    State(state): State<AppState>,
+    Extension(ctx): Extension<RequestContext>,
+    Path(code): Path<String>,
+) -> ApiResult<Json<EntityResponse<CompensationAssessmentSerializer>>> {
+    let result = state.compensation_service.assess_booking(ctx, code).await?;
+
+    Ok(Json(EntityResponse {
+        message: "Compensation assessed successfully.".to_string(),
+        data: Some(result.into()),
+        total: 1,
+    }))
+}
+
+#[utoipa::path(
+    post,
+    path = "/claims",
+    tags = ["compensation"],
+    request_body = SubmitCompensationClaimRequest,
+    responses(
+        (status = 200, description = "Claim submitted", body = EntityResponse<CompensationClaimSerializer>),
+        (status = 400, description = "Booking not eligible or not confirmed", body = ClientResponseError),
+        (status = 401, description = "Unauthorized", body = ClientResponseError),
+        (status = 403, description = "Forbidden", body = ClientResponseError),
+        (status = 404, description = "Booking not found", body = ClientResponseError),
+        (status = 409, description = "Booking already has a claim", body = ClientResponseError),
+        (status = 500, description = "Internal server error", body = ClientResponseError)
+    ),
+    security(("jwt" = []))
+)]
+pub async fn controller_submit_compensation_claim(
+    State(state): State<AppState>,
+    Extension(ctx): Extension<RequestContext>,
+    Json(req): Json<SubmitCompensationClaimRequest>,
+) -> ApiResult<Json<EntityResponse<CompensationClaimSerializer>>> {
+    let result = state
+        .compensation_service
+        .submit_claim(ctx, req.into())
+        .await?;
+
+    Ok(Json(EntityResponse {
+        message: "Compensation claim submitted successfully.".to_string(),
+        data: Some(result.into()),
+        total: 1,
+    }))
+}
+
+#[utoipa::path(
+    get,
+    path = "/claims",
+    tags = ["compensation"],
+    params(ListCompensationClaimsQuery),
+    responses(
+        (status = 200, description = "Claims retrieved successfully", body = EntityResponse<Vec<CompensationClaimSerializer>>),
+        (status = 400, description = "Invalid status", body = ClientResponseError),
+        (status = 401, description = "Unauthorized", body = ClientResponseError),
+        (status = 403, description = "Forbidden", body = ClientResponseError),
+        (status = 500, description = "Internal server error", body = ClientResponseError)
+    ),
+    security(("jwt" = []))
+)]
+pub async fn controller_list_compensation_claims(
+    State(state): State<AppState>,
+    Extension(ctx): Extension<RequestContext>,
+    Query(params): Query<ListCompensationClaimsQuery>,
+) -> ApiResult<Json<EntityResponse<Vec<CompensationClaimSerializer>>>> {
+    let result = state
+        .compensation_service
+        .list_claims(ctx, params.status)
+        .await?;
+    let data: Vec<CompensationClaimSerializer> = result.into_iter().map(Into::into).collect();
+
+    Ok(Json(EntityResponse {
+        message: "Compensation claims retrieved successfully.".to_string(),
+        data: Some(data.clone()),
+        total: data.len() as i64,
+    }))
+}
+
+#[utoipa::path(
+    get,
+    path = "/claims/{id}",
+    tags = ["compensation"],
+    params(
+        ("id" = i64, Path, description = "Claim ID")
+    ),
+    responses(
+        (status = 200, description = "Claim retrieved successfully", body = EntityResponse<CompensationClaimSerializer>),
+        (status = 401, description = "Unauthorized", body = ClientResponseError),
+        (status = 403, description = "Forbidden", body = ClientResponseError),
+        (status = 404, description = "Claim not found", body = ClientResponseError),
+        (status = 500, description = "Internal server error", body = ClientResponseError)
+    ),
+    security(("jwt" = []))
+)]
+pub async fn controller_get_compensation_claim(
+    State(state): State<AppState>,
+    Extension(ctx): Extension<RequestContext>,
+    Path(id): Path<i64>,
+) -> ApiResult<Json<EntityResponse<CompensationClaimSerializer>>> {
+    let result = state.compensation_service.get_claim(ctx, id).await?;
+
+    Ok(Json(EntityResponse {
+        message: "Compensation claim retrieved successfully.".to_string(),
+        data: Some(result.into()),
+        total: 1,
+    }))
+}
+
+#[utoipa::path(
+    post,
+    path = "/claims/{id}/approve",
+    tags = ["compensation"],
+    params(
+        ("id" = i64, Path, description = "Claim ID")
+    ),
+    request_body = DecideCompensationClaimRequest,
+    responses(
+        (status = 200, description = "Claim approved", body = EntityResponse<CompensationClaimSerializer>),
+        (status = 401, description = "Unauthorized", body = ClientResponseError),
+        (status = 403, description = "Forbidden", body = ClientResponseError),
+        (status = 404, description = "Claim not found", body = ClientResponseError),
+        (status = 409, description = "Claim already decided or version conflict", body = ClientResponseError),
+        (status = 500, description = "Internal server error", body = ClientResponseError)
+    ),
+    security(("jwt" = []))
+)]
+pub async fn controller_approve_compensation_claim(
+    State(state): State<AppState>,
+    Extension(ctx): Extension<RequestContext>,
+    Path(id): Path<i64>,
+    Json(req): Json<DecideCompensationClaimRequest>,
+) -> ApiResult<Json<EntityResponse<CompensationClaimSerializer>>> {
+    let result = state
+        .compensation_service
+        .approve_claim(ctx, id, req.into())
+        .await?;
+
+    Ok(Json(EntityResponse {
+        message: "Compensation claim approved successfully.".to_string(),
+        data: Some(result.into()),
+        total: 1,
+    }))
+}
+
+#[utoipa::path(
+    post,
+    path = "/claims/{id}/reject",
+    tags = ["compensation"],
+    params(
+        ("id" = i64, Path, description = "Claim ID")
+    ),
+    request_body = DecideCompensationClaimRequest,
+    responses(
+        (status = 200, description = "Claim rejected", body = EntityResponse<CompensationClaimSerializer>),
+        (status = 400, description = "Rejection note missing", body = ClientResponseError),
+        (status = 401, description = "Unauthorized", body = ClientResponseError),
+        (status = 403, description = "Forbidden", body = ClientResponseError),
+        (status = 404, description = "Claim not found", body = ClientResponseError),
+        (status = 409, description = "Claim already decided or version conflict", body = ClientResponseError),
+        (status = 500, description = "Internal server error", body = ClientResponseError)
+    ),
+    security(("jwt" = []))
+)]
+pub async fn controller_reject_compensation_claim(
+    State(state): State<AppState>,
+    Extension(ctx): Extension<RequestContext>,
+    Path(id): Path<i64>,
+    Json(req): Json<DecideCompensationClaimRequest>,
+) -> ApiResult<Json<EntityResponse<CompensationClaimSerializer>>> {
+    let result = state
+        .compensation_service
+        .reject_claim(ctx, id, req.into())
+        .await?;
+
+    Ok(Json(EntityResponse {
+        message: "Compensation claim rejected successfully.".to_string(),
+        data: Some(result.into()),
+        total: 1,
+    }))
+}
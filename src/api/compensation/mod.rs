@@ -0,0 +1 @@
+pub mod compensation;
\ No newline at end of file
@@ -0,0 +1,145 @@
+use crate::core::app_state::AppState;
+use crate::core::context::request_context::RequestContext;
+use crate::core::response::common::{ClientResponseError, EntityResponse};
+use crate::presentation::dead_letter::dead_letter_request::{
+    ListDeadLettersQuery, ResolveDeadLetterRequest,
+};
+use crate::presentation::dead_letter::dead_letter_serializer::DeadLetterSerializer;
+use crate::presentation::http::ApiResult;
+use axum::extract::{Path, Query, State};
+use axum::{Extension, Json};
+
+#[utoipa::path(
+    get,
+    path = "",
+    tags = ["dead_letter"],
+    params(ListDeadLettersQuery),
+    responses(
+        (status = 200, description = "Dead letters retrieved successfully", body = EntityResponse<Vec<DeadLetterSerializer>>),
+        (status = 400, description = "Invalid status", body = ClientResponseError),
+        (status = 401, description = "Unauthorized", body = ClientResponseError),
+        (status = 403, description = "Forbidden", body = ClientResponseError),
+        (status = 500, description = "Internal server error", body = ClientResponseError)
+    ),
+    security(("jwt" = []))
+)]
+pub async fn controller_list_dead_letters(
+    State(state): State<AppState>,
+    Extension(ctx): Extension<RequestContext>,
+    Query(params): Query<ListDeadLettersQuery>,
+) -> ApiResult<Json<EntityResponse<Vec<DeadLetterSerializer>>>> {
+    let result = state
+        .dead_letter_service
+        .list_dead_letters(ctx, params.status, params.consumer_group)
+        .await?;
+    let data: Vec<DeadLetterSerializer> = result.into_iter().map(Into::into).collect();
+
+    Ok(Json(EntityResponse {
+        message: "Dead letters retrieved successfully.".to_string(),
+        data: Some(data.clone()),
+        total: data.len() as i64,
+    }))
+}
+
+#[utoipa::path(
+    get,
+    path = "/{id}",
+    tags = ["dead_letter"],
+    params(
+        ("id" = i64, Path, description = "Dead letter ID")
+    ),
+    responses(
+        (status = 200, description = "Dead letter retrieved successfully", body = EntityResponse<DeadLetterSerializer>),
+        (status = 401, description = "Unauthorized", body = ClientResponseError),
+        (status = 403, description = "Forbidden", body = ClientResponseError),
+        (status = 404, description = "Dead letter not found", body = ClientResponseError),
+        (status = 500, description = "Internal server error", body = ClientResponseError)
+    ),
+    security(("jwt" = []))
+)]
+pub async fn controller_get_dead_letter(
+    State(state): State<AppState>,
+    Extension(ctx): Extension<RequestContext>,
+    Path(id): Path<i64>,
+) -> ApiResult<Json<EntityResponse<DeadLetterSerializer>>> {
+    let result = state.dead_letter_service.get_dead_letter(ctx, id).await?;
+
+    Ok(Json(EntityResponse {
+        message: "Dead letter retrieved successfully.".to_string(),
+        data: Some(result.into()),
+        total: 1,
+    }))
+}
+
+#[utoipa::path(
+    post,
+    path = "/{id}/replay",
+    tags = ["dead_letter"],
+    params(
+        ("id" = i64, Path, description = "Dead letter ID")
+    ),
+    request_body = ResolveDeadLetterRequest,
+    responses(
+        (status = 200, description = "Dead letter replayed", body = EntityResponse<DeadLetterSerializer>),
+        (status = 400, description = "Dead letter names no handler", body = ClientResponseError),
+        (status = 401, description = "Unauthorized", body = ClientResponseError),
+        (status = 403, description = "Forbidden", body = ClientResponseError),
+        (status = 404, description = "Dead letter not found", body = ClientResponseError),
+        (status = 409, description = "Dead letter already resolved or version conflict", body = ClientResponseError),
+        (status = 500, description = "Internal server error", body = ClientResponseError)
+    ),
+    security(("jwt" = []))
+)]
+pub async fn controller_replay_dead_letter(
+    State(state): State<AppState>,
+    Extension(ctx): Extension<RequestContext>,
+    Path(id): Path<i64>,
+    Json(req): Json<ResolveDeadLetterRequest>,
+) -> ApiResult<Json<EntityResponse<DeadLetterSerializer>>> {
+    let result = state
+        .dead_letter_service
+        .replay_dead_letter(ctx, id, req.into())
+        .await?;
+
+    Ok(Json(EntityResponse {
+        message: "Dead letter replayed successfully.".to_string(),
+        data: Some(result.into()),
+        total: 1,
+    }))
+}
+
+#[utoipa::path(
+    post,
+    path = "/{id}/discard",
+    tags = ["dead_letter"],
+    params(
+        ("id" = i64, Path, description = "Dead letter ID")
+    ),
+    request_body = ResolveDeadLetterRequest,
+    responses(
+        (status = 200, description = "Dead letter discarded", body = EntityResponse<DeadLetterSerializer>),
+        (status = 401, description = "Unauthorized", body = ClientResponseError),
+        (status = 403, description = "Forbidden", body = ClientResponseError),
+        (status = 404, description = "Dead letter not found", body = ClientResponseError),
+        (status = 409, description = "Dead letter already resolved or version conflict", body = ClientResponseError),
+        (status = 500, description = "Internal server error", body = ClientResponseError)
+    ),
+    security(("jwt" = []))
+)]
+pub async fn controller_discard_dead_letter(
+    State(state): State<AppState>,
+    Extension(ctx): Extension<RequestContext>,
+    Path(id): Path<i64>,
+    Json(req): Json<ResolveDeadLetterRequest>,
+) -> ApiResult<Json<EntityResponse<DeadLetterSerializer>>> {
+    let result = state
+        .dead_letter_service
+        .discard_dead_letter(ctx, id, req.into())
+        .await?;
+
+    Ok(Json(EntityResponse {
+        message: "Dead letter discarded successfully.".to_string(),
+        data: Some(result.into()),
+        total: 1,
+    }))
+}
@@ -0,0 +1 @@
+pub mod dead_letter;
\ No newline at end of file
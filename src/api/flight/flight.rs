@@ -1,11 +1,13 @@
-use crate::application::flight::flight_command::{SearchFlightCommand, UpdateFlightCommand};
+use crate::application::flight::flight_command::{
+    AddCodeshareCommand, SearchFlightCommand, UpdateFlightCommand,
+};
 use crate::core::app_state::AppState;
 use crate::core::context::request_context::RequestContext;
 use crate::core::response::common::{ClientResponseError, EntityResponse};
 use crate::presentation::flight::flight_request::{
-    CreateFlightRequest, SearchFlightQuery, UpdateFlightRequest,
+    AddCodeshareRequest, CreateFlightRequest, SearchFlightQuery, UpdateFlightRequest,
 };
-use crate::presentation::flight::flight_serializer::FlightSerializer;
+use crate::presentation::flight::flight_serializer::{FlightSerializer, FlightTimelineSerializer};
 use crate::presentation::http::ApiResult;
 use axum::extract::{Path, Query, State};
 use axum::http::StatusCode;
@@ -104,6 +106,35 @@ pub async fn controller_get_flight_by_id(
     }))
 }
 
+#[utoipa::path(
+    get,
+    path = "/{id}/timeline",
+    tags = ["flight"],
+    params(
+        ("id" = i64, Path, description = "Flight ID")
+    ),
+    responses(
+        (status = 200, description = "Flight timeline retrieved successfully", body = EntityResponse<FlightTimelineSerializer>),
+        (status = 401, description = "Unauthorized", body = ClientResponseError),
+        (status = 404, description = "Flight not found", body = ClientResponseError),
+        (status = 500, description = "Internal server error", body = ClientResponseError)
+    ),
+    security(("jwt" = []))
+)]
+pub async fn controller_get_flight_timeline(
+    State(state): State<AppState>,
+    Extension(ctx): Extension<RequestContext>,
+    Path(id): Path<i64>,
+) -> ApiResult<Json<EntityResponse<FlightTimelineSerializer>>> {
+    let result = state.flight_service.get_flight_timeline(ctx, id).await?;
+
+    Ok(Json(EntityResponse {
+        message: "Flight timeline retrieved successfully.".to_string(),
+        data: Some(result.into()),
+        total: 1,
+    }))
+}
+
 #[utoipa::path(
     get,
     path = "/key/{flight_key}",
@@ -189,3 +220,73 @@ pub async fn controller_cancel_flight(
         total: 1,
     }))
 }
+
+#[utoipa::path(
+    post,
+    path = "/{id}/codeshares",
+    tags = ["flight"],
+    request_body = AddCodeshareRequest,
+    params(
+        ("id" = i64, Path, description = "Operating flight ID")
+    ),
+    responses(
+        (status = 201, description = "Codeshare added successfully", body = EntityResponse<bool>),
+        (status = 400, description = "Bad request", body = ClientResponseError),
+        (status = 401, description = "Unauthorized", body = ClientResponseError),
+        (status = 404, description = "Flight or airline not found", body = ClientResponseError),
+        (status = 409, description = "Flight key already in use", body = ClientResponseError),
+        (status = 500, description = "Internal server error", body = ClientResponseError)
+    ),
+    security(("jwt" = []))
+)]
+pub async fn controller_add_codeshare(
+    State(state): State<AppState>,
+    Extension(ctx): Extension<RequestContext>,
+    Path(id): Path<i64>,
+    Json(req): Json<AddCodeshareRequest>,
+) -> ApiResult<(StatusCode, Json<EntityResponse<bool>>)> {
+    let command: AddCodeshareCommand = req.into();
+    let result = state.flight_service.add_codeshare(ctx, id, command).await?;
+
+    Ok((
+        StatusCode::CREATED,
+        Json(EntityResponse {
+            message: "Codeshare added successfully.".to_string(),
+            data: Some(result),
+            total: 1,
+        }),
+    ))
+}
+
+#[utoipa::path(
+    delete,
+    path = "/{id}/codeshares/{airline_code}",
+    tags = ["flight"],
+    params(
+        ("id" = i64, Path, description = "Operating flight ID"),
+        ("airline_code" = String, Path, description = "Marketing airline IATA designator")
+    ),
+    responses(
+        (status = 200, description = "Codeshare removed successfully", body = EntityResponse<bool>),
+        (status = 401, description = "Unauthorized", body = ClientResponseError),
+        (status = 404, description = "Flight or codeshare not found", body = ClientResponseError),
+        (status = 500, description = "Internal server error", body = ClientResponseError)
+    ),
+    security(("jwt" = []))
+)]
+pub async fn controller_remove_codeshare(
+    State(state): State<AppState>,
+    Extension(ctx): Extension<RequestContext>,
+    Path((id, airline_code)): Path<(i64, String)>,
+) -> ApiResult<Json<EntityResponse<bool>>> {
+    let result = state
+        .flight_service
+        .remove_codeshare(ctx, id, airline_code)
+        .await?;
+
+    Ok(Json(EntityResponse {
+        message: "Codeshare removed successfully.".to_string(),
+        data: Some(result),
+        total: 1,
+    }))
+}
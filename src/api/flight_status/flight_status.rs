@@ -0,0 +1,265 @@
+use std::collections::HashSet;
+use std::convert::Infallible;
+use std::sync::Arc;
+use std::time::Duration;
+
+use crate::application::flight_status::flight_status_command::SubscribeFlightStatusCommand;
+use crate::application::flight_status::use_case::flight_status_service::MAX_SUBSCRIBED_FLIGHTS;
+use crate::application::flight_status::use_case::flight_status_service_interface::FlightStatusServiceInterface;
+use crate::application::flight_status::view::flight_status_view::FlightStatusUpdateView;
+use crate::core::app_state::AppState;
+use crate::core::context::request_context::RequestContext;
+use crate::core::response::common::ClientResponseError;
+use crate::infrastructure::runtime::shutdown;
+use crate::presentation::flight_status::flight_status_request::{
+    FlightStatusSocketRequest, FlightStatusStreamQuery,
+};
+use crate::presentation::flight_status::flight_status_serializer::{
+    FlightStatusSocketMessage, FlightStatusUpdateSerializer,
+};
+use crate::presentation::http::ApiResult;
+use axum::Extension;
+use axum::extract::State;
+use axum::extract::ws::{CloseFrame, Message, WebSocket, WebSocketUpgrade, close_code};
+use axum::response::sse::{Event, KeepAlive, Sse};
+use axum::response::{IntoResponse, Response};
+use axum_extra::extract::Query;
+use futures::Stream;
+use futures::stream::{self, StreamExt};
+use tokio::sync::broadcast;
+
+const PING_INTERVAL: Duration = Duration::from_secs(30);
+
+fn sse_event(view: FlightStatusUpdateView) -> Event {
+    let payload = FlightStatusUpdateSerializer::from(view);
+    Event::default()
+        .event("flight_status")
+        .json_data(&payload)
+        .unwrap_or_else(|_| Event::default().comment("unserializable update"))
+}
+
+#[utoipa::path(
+    get,
+    path = "/status/stream",
+    tags = ["flight"],
+    params(FlightStatusStreamQuery),
+    responses(
+        (status = 200, description = "Server-Sent Events: current status first, then every change", content_type = "text/event-stream", body = FlightStatusUpdateSerializer),
+        (status = 400, description = "Bad request", body = ClientResponseError),
+        (status = 401, description = "Unauthorized", body = ClientResponseError),
+        (status = 403, description = "Booking belongs to another user", body = ClientResponseError),
+        (status = 404, description = "Flight or booking not found", body = ClientResponseError),
+        (status = 500, description = "Internal server error", body = ClientResponseError)
+    ),
+    security(("jwt" = []))
+)]
+pub async fn controller_stream_flight_status(
+    State(state): State<AppState>,
+    Extension(ctx): Extension<RequestContext>,
+    Query(query): Query<FlightStatusStreamQuery>,
+) -> ApiResult<Sse<impl Stream<Item = Result<Event, Infallible>>>> {
+    // Subscribe before loading the snapshots so no change in between is missed.
+    let updates = state.flight_status_service.updates();
+    let current = state
+        .flight_status_service
+        .subscribe(ctx, query.into())
+        .await?;
+    let followed: HashSet<i64> = current.iter().map(|v| v.flight_id).collect();
+
+    let changes = stream::unfold((updates, followed), |(mut updates, followed)| async move {
+        loop {
+            match updates.recv().await {
+                Ok(view) if followed.contains(&view.flight_id) => {
+                    return Some((view, (updates, followed)));
+                }
+                Ok(_) => {}
+                Err(broadcast::error::RecvError::Lagged(skipped)) => {
+                    tracing::warn!("flight status stream lagged, {} update(s) dropped", skipped);
+                }
+                Err(broadcast::error::RecvError::Closed) => return None,
+            }
+        }
+    });
+
+    // End the stream on shutdown; graceful shutdown waits for every open response.
+    let events = stream::iter(current)
+        .chain(changes)
+        .take_until(shutdown::requested())
+        .map(|view| Ok(sse_event(view)));
+
+    Ok(Sse::new(events).keep_alive(KeepAlive::default()))
+}
+
+#[utoipa::path(
+    get,
+    path = "/status/ws",
+    tags = ["flight"],
+    params(FlightStatusStreamQuery),
+    responses(
+        (status = 101, description = "WebSocket upgrade; sends `flight_status` messages and accepts `subscribe`/`unsubscribe` actions", body = FlightStatusSocketMessage),
+        (status = 400, description = "Bad request", body = ClientResponseError),
+        (status = 401, description = "Unauthorized", body = ClientResponseError),
+        (status = 403, description = "Booking belongs to another user", body = ClientResponseError),
+        (status = 404, description = "Flight or booking not found", body = ClientResponseError),
+        (status = 500, description = "Internal server error", body = ClientResponseError)
+    ),
+    security(("jwt" = []))
+)]
+pub async fn controller_flight_status_socket(
+    State(state): State<AppState>,
+    Extension(ctx): Extension<RequestContext>,
+    Query(query): Query<FlightStatusStreamQuery>,
+    ws: WebSocketUpgrade,
+) -> ApiResult<Response> {
+    let service = state.flight_status_service.clone();
+    let updates = service.updates();
+
+    // Follow nothing until the first `subscribe` message when the query is empty.
+    let command: SubscribeFlightStatusCommand = query.into();
+    let current = if command.flight_ids.is_empty() && command.booking_codes.is_empty() {
+        Vec::new()
+    } else {
+        service.subscribe(ctx.clone(), command).await?
+    };
+
+    Ok(ws
+        .on_upgrade(move |socket| run_socket(socket, service, ctx, updates, current))
+        .into_response())
+}
+
+async fn send(socket: &mut WebSocket, message: FlightStatusSocketMessage) -> bool {
+    let Ok(text) = serde_json::to_string(&message) else {
+        return true;
+    };
+    socket.send(Message::Text(text.into())).await.is_ok()
+}
+
+async fn send_update(socket: &mut WebSocket, view: FlightStatusUpdateView) -> bool {
+    send(socket, FlightStatusSocketMessage::FlightStatus(view.into())).await
+}
+
+async fn run_socket(
+    mut socket: WebSocket,
+    service: Arc<dyn FlightStatusServiceInterface>,
+    ctx: RequestContext,
+    mut updates: broadcast::Receiver<FlightStatusUpdateView>,
+    current: Vec<FlightStatusUpdateView>,
+) {
+    let mut followed: HashSet<i64> = current.iter().map(|v| v.flight_id).collect();
+    for view in current {
+        if !send_update(&mut socket, view).await {
+            return;
+        }
+    }
+
+    let mut ping = tokio::time::interval(PING_INTERVAL);
+    ping.tick().await;
+    let stopping = shutdown::requested();
+    tokio::pin!(stopping);
+
+    loop {
+        tokio::select! {
+            _ = &mut stopping => {
+                let _ = socket
+                    .send(Message::Close(Some(CloseFrame {
+                        code: close_code::AWAY,
+                        reason: "server shutting down".into(),
+                    })))
+                    .await;
+                return;
+            },
+            update = updates.recv() => match update {
+                Ok(view) if followed.contains(&view.flight_id) => {
+                    if !send_update(&mut socket, view).await {
+                        return;
+                    }
+                }
+                Ok(_) => {}
+                Err(broadcast::error::RecvError::Lagged(skipped)) => {
+                    tracing::warn!("flight status socket lagged, {} update(s) dropped", skipped);
+                }
+                Err(broadcast::error::RecvError::Closed) => return,
+            },
+            incoming = socket.recv() => {
+                let text = match incoming {
+                    Some(Ok(Message::Text(text))) => text,
+                    Some(Ok(Message::Close(_))) | Some(Err(_)) | None => return,
+                    Some(Ok(_)) => continue,
+                };
+                let replies = match serde_json::from_str(text.as_str()) {
+                    Ok(request) => {
+                        handle_request(service.as_ref(), &ctx, &mut followed, request).await
+                    }
+                    Err(err) => vec![FlightStatusSocketMessage::Error {
+                        message: format!("Invalid message: {err}"),
+                    }],
+                };
+                for reply in replies {
+                    if !send(&mut socket, reply).await {
+                        return;
+                    }
+                }
+            },
+            _ = ping.tick() => {
+                if socket.send(Message::Ping(Default::default())).await.is_err() {
+                    return;
+                }
+            },
+        }
+    }
+}
+
+async fn handle_request(
+    service: &dyn FlightStatusServiceInterface,
+    ctx: &RequestContext,
+    followed: &mut HashSet<i64>,
+    request: FlightStatusSocketRequest,
+) -> Vec<FlightStatusSocketMessage> {
+    match request {
+        FlightStatusSocketRequest::Subscribe {
+            flight_ids,
+            booking_codes,
+        } => subscribe(service, ctx, followed, flight_ids, booking_codes).await,
+        FlightStatusSocketRequest::Unsubscribe { flight_ids } => {
+            for id in flight_ids {
+                followed.remove(&id);
+            }
+            Vec::new()
+        }
+    }
+}
+
+/// Adds flights to the socket's set and answers with their current status.
+async fn subscribe(
+    service: &dyn FlightStatusServiceInterface,
+    ctx: &RequestContext,
+    followed: &mut HashSet<i64>,
+    flight_ids: Vec<i64>,
+    booking_codes: Vec<String>,
+) -> Vec<FlightStatusSocketMessage> {
+    let command = SubscribeFlightStatusCommand {
+        flight_ids,
+        booking_codes,
+    };
+    match service.subscribe(ctx.clone(), command).await {
+        Ok(current) => {
+            let added = current
+                .iter()
+                .filter(|v| !followed.contains(&v.flight_id))
+                .count();
+            if followed.len() + added > MAX_SUBSCRIBED_FLIGHTS {
+                return vec![FlightStatusSocketMessage::Error {
+                    message: format!("At most {MAX_SUBSCRIBED_FLIGHTS} flights can be followed"),
+                }];
+            }
+            followed.extend(current.iter().map(|v| v.flight_id));
+            current
+                .into_iter()
+                .map(|v| FlightStatusSocketMessage::FlightStatus(v.into()))
+                .collect()
+        }
+        Err(err) => vec![FlightStatusSocketMessage::Error {
+            message: err.to_string(),
+        }],
+    }
+}
@@ -0,0 +1 @@
+pub mod flight_status;
\ No newline at end of file
@@ -0,0 +1,80 @@
+use crate::application::manifest::manifest_command::{ExportManifestCommand, ManifestFormat};
+use crate::core::app_state::AppState;
+use crate::core::context::request_context::RequestContext;
+use crate::core::response::common::{ClientResponseError, EntityResponse};
+use crate::presentation::http::ApiResult;
+use crate::presentation::manifest::manifest_request::ManifestExportQuery;
+use crate::presentation::manifest::manifest_serializer::FlightManifestSerializer;
+use axum::extract::{Path, Query, State};
+use axum::http::header;
+use axum::response::{IntoResponse, Response};
+use axum::{Extension, Json};
+
+#[utoipa::path(
+    get,
+    path = "/{id}/manifest",
+    tags = ["manifest"],
+    params(
+        ("id" = i64, Path, description = "Flight ID"),
+        ManifestExportQuery
+    ),
+    responses(
+        (status = 200, description = "Manifest built successfully (CSV/PAXLST formats are returned as attachments)", body = EntityResponse<FlightManifestSerializer>),
+        (status = 400, description = "Bad request", body = ClientResponseError),
+        (status = 401, description = "Unauthorized", body = ClientResponseError),
+        (status = 403, description = "Forbidden", body = ClientResponseError),
+        (status = 404, description = "Flight not found", body = ClientResponseError),
+        (status = 500, description = "Internal server error", body = ClientResponseError)
+    ),
+    security(("jwt" = []))
+)]
+pub async fn controller_export_flight_manifest(
+    State(state): State<AppState>,
+    Extension(ctx): Extension<RequestContext>,
+    Path(id): Path<i64>,
+    Query(params): Query<ManifestExportQuery>,
+) -> ApiResult<Response> {
+    let format = ManifestFormat::parse(params.format.as_deref().unwrap_or("json"))?;
+
+    if format == ManifestFormat::Json {
+        let result = state.manifest_service.get_flight_manifest(ctx, id).await?;
+        let data: FlightManifestSerializer = result.into();
+
+        return Ok(Json(EntityResponse {
+            message: format!(
+                "Manifest built successfully with {} validation issue(s).",
+                data.issues.len()
+            ),
+            total: data.passenger_count as i64,
+            data: Some(data),
+        })
+        .into_response());
+    }
+
+    let export = state
+        .manifest_service
+        .export_flight_manifest(
+            ctx,
+            ExportManifestCommand {
+                flight_id: id,
+                format,
+            },
+        )
+        .await?;
+
+    Ok((
+        [
+            (header::CONTENT_TYPE, export.content_type),
+            (
+                header::CONTENT_DISPOSITION,
+                format!("attachment; filename=\"{}\"", export.file_name),
+            ),
+            (
+                header::HeaderName::from_static("x-manifest-issues"),
+                export.issue_count.to_string(),
+            ),
+        ],
+        export.content,
+    )
+        .into_response())
+}
@@ -1,11 +1,22 @@
 pub mod address;
+pub mod airline;
+pub mod aircraft;
 pub mod airport;
 pub mod auth;
+pub mod baggage;
+pub mod boarding;
 pub mod boarding_pass;
 pub mod booking;
 pub mod checkin;
+pub mod compensation;
+pub mod dead_letter;
 pub mod flight;
+pub mod flight_status;
+pub mod manifest;
+pub mod no_show;
+pub mod notification;
 pub mod passenger;
+pub mod route;
 pub mod server;
 pub mod user;
 
@@ -63,15 +74,53 @@ pub fn build_routes() -> AppRoutes {
         .routes(routes!(api::airport::airport::controller_get_airport_by_id))
         .routes(routes!(api::airport::airport::controller_get_airport_by_iata_code))
         .routes(routes!(api::airport::airport::controller_list_airports))
-        .routes(routes!(api::airport::airport::controller_deactivate_airport));
+        .routes(routes!(api::airport::airport::controller_deactivate_airport))
+        .routes(routes!(api::airport::airport::controller_search_airports))
+        .routes(routes!(api::airport::airport::controller_find_nearby_airports))
+        .routes(routes!(api::airport::airport::controller_import_airports));
+
+    let airline_routes = OpenApiRouter::new()
+        .routes(routes!(api::airline::airline::controller_create_airline))
+        .routes(routes!(api::airline::airline::controller_update_airline))
+        .routes(routes!(api::airline::airline::controller_get_airline_by_id))
+        .routes(routes!(api::airline::airline::controller_get_airline_by_iata_code))
+        .routes(routes!(api::airline::airline::controller_list_airlines))
+        .routes(routes!(api::airline::airline::controller_deactivate_airline));
+
+    let aircraft_routes = OpenApiRouter::new()
+        .routes(routes!(api::aircraft::aircraft::controller_create_aircraft))
+        .routes(routes!(api::aircraft::aircraft::controller_update_aircraft))
+        .routes(routes!(api::aircraft::aircraft::controller_get_aircraft_by_id))
+        .routes(routes!(api::aircraft::aircraft::controller_get_aircraft_by_tail_number))
+        .routes(routes!(api::aircraft::aircraft::controller_list_aircraft));
+
+    let route_routes = OpenApiRouter::new()
+        .routes(routes!(api::route::route::controller_create_route))
+        .routes(routes!(api::route::route::controller_update_route))
+        .routes(routes!(api::route::route::controller_get_route_by_id))
+        .routes(routes!(api::route::route::controller_list_routes));
 
     let flight_routes = OpenApiRouter::new()
         .routes(routes!(api::flight::flight::controller_create_flight))
         .routes(routes!(api::flight::flight::controller_update_flight))
         .routes(routes!(api::flight::flight::controller_get_flight_by_id))
+        .routes(routes!(api::flight::flight::controller_get_flight_timeline))
+        .routes(routes!(api::flight_status::flight_status::controller_stream_flight_status))
+        .routes(routes!(api::flight_status::flight_status::controller_flight_status_socket))
         .routes(routes!(api::flight::flight::controller_get_flight_by_key))
         .routes(routes!(api::flight::flight::controller_search_flights))
-        .routes(routes!(api::flight::flight::controller_cancel_flight));
+        .routes(routes!(api::flight::flight::controller_cancel_flight))
+        .routes(routes!(api::flight::flight::controller_add_codeshare))
+        .routes(routes!(api::flight::flight::controller_remove_codeshare))
+        .routes(routes!(api::manifest::manifest::controller_export_flight_manifest))
+        .routes(routes!(api::boarding::boarding::controller_scan_boarding_pass))
+        .routes(routes!(api::boarding::boarding::controller_undo_boarding))
+        .routes(routes!(api::boarding::boarding::controller_offload_passenger))
+        .routes(routes!(api::boarding::boarding::controller_list_on_standby))
+        .routes(routes!(api::boarding::boarding::controller_clear_standby))
+        .routes(routes!(api::boarding::boarding::controller_reconcile_boarding))
+        .routes(routes!(api::no_show::no_show::controller_process_no_shows))
+        .routes(routes!(api::no_show::no_show::controller_list_no_shows));
 
     let booking_routes = OpenApiRouter::new()
         .routes(routes!(api::booking::booking::controller_create_booking))
@@ -92,6 +141,8 @@ pub fn build_routes() -> AppRoutes {
     let checkin_routes = OpenApiRouter::new()
         .routes(routes!(api::checkin::checkin::controller_create_checkin))
         .routes(routes!(api::checkin::checkin::controller_update_checkin))
+        .routes(routes!(api::checkin::checkin::controller_pay_excess_baggage))
+        .routes(routes!(api::baggage::baggage::controller_issue_bag_tag))
         .routes(routes!(api::checkin::checkin::controller_cancel_checkin))
         .routes(routes!(api::checkin::checkin::controller_get_checkin_by_id))
         .routes(routes!(api::checkin::checkin::controller_list_checkins_by_booking));
@@ -102,7 +153,56 @@ pub fn build_routes() -> AppRoutes {
         ))
         .routes(routes!(api::boarding_pass::boarding_pass::controller_get_by_checkin_id))
         .routes(routes!(api::boarding_pass::boarding_pass::controller_get_by_code))
-        .routes(routes!(api::boarding_pass::boarding_pass::controller_list_by_booking));
+        .routes(routes!(api::boarding_pass::boarding_pass::controller_list_by_booking))
+        .routes(routes!(
+            api::boarding_pass::boarding_pass::controller_verify_boarding_pass
+        ))
+        .routes(routes!(api::boarding_pass::boarding_pass::controller_get_pdf))
+        .routes(routes!(api::boarding_pass::boarding_pass::controller_get_png))
+        .routes(routes!(api::boarding_pass::boarding_pass::controller_get_pkpass));
+
+    let bag_routes = OpenApiRouter::new()
+        .routes(routes!(api::baggage::baggage::controller_update_bag_status))
+        .routes(routes!(
+            api::baggage::baggage::controller_track_bags_by_booking_code
+        ));
+
+    let compensation_routes = OpenApiRouter::new()
+        .routes(routes!(
+            api::compensation::compensation::controller_assess_compensation
+        ))
+        .routes(routes!(
+            api::compensation::compensation::controller_submit_compensation_claim
+        ))
+        .routes(routes!(
+            api::compensation::compensation::controller_list_compensation_claims
+        ))
+        .routes(routes!(
+            api::compensation::compensation::controller_get_compensation_claim
+        ))
+        .routes(routes!(
+            api::compensation::compensation::controller_approve_compensation_claim
+        ))
+        .routes(routes!(
+            api::compensation::compensation::controller_reject_compensation_claim
+        ));
+
+    let notification_routes = OpenApiRouter::new()
+        .routes(routes!(
+            api::notification::notification::controller_list_notifications
+        ))
+        .routes(routes!(
+            api::notification::notification::controller_get_notification_preferences
+        ))
+        .routes(routes!(
+            api::notification::notification::controller_update_notification_preferences
+        ));
+
+    let dead_letter_routes = OpenApiRouter::new()
+        .routes(routes!(api::dead_letter::dead_letter::controller_list_dead_letters))
+        .routes(routes!(api::dead_letter::dead_letter::controller_get_dead_letter))
+        .routes(routes!(api::dead_letter::dead_letter::controller_replay_dead_letter))
+        .routes(routes!(api::dead_letter::dead_letter::controller_discard_dead_letter));
 
     // ---- Assemble ----
     let public = OpenApiRouter::new()
@@ -115,10 +215,17 @@ pub fn build_routes() -> AppRoutes {
         .nest("/api/v1/users", user_routes)
         .nest("/api/v1/addresses", address_routes)
         .nest("/api/v1/airports", airport_routes)
+        .nest("/api/v1/airlines", airline_routes)
+        .nest("/api/v1/aircraft", aircraft_routes)
+        .nest("/api/v1/routes", route_routes)
         .nest("/api/v1/flights", flight_routes)
         .nest("/api/v1/bookings", booking_routes)
         .nest("/api/v1/passengers", passenger_routes)
         .nest("/api/v1/checkins", checkin_routes)
+        .nest("/api/v1/bags", bag_routes)
+        .nest("/api/v1/compensation", compensation_routes)
+        .nest("/api/v1/notifications", notification_routes)
+        .nest("/api/v1/dead-letters", dead_letter_routes)
         .nest("/api/v1/boarding-passes", boarding_pass_routes);
 
     AppRoutes { public, protected }
@@ -0,0 +1 @@
+pub mod no_show;
@@ -0,0 +1,75 @@
+use crate::core::app_state::AppState;
+use crate::core::context::request_context::RequestContext;
+use crate::core::response::common::{ClientResponseError, EntityResponse};
+use crate::presentation::http::ApiResult;
+use crate::presentation::no_show::no_show_serializer::{
+    NoShowProcessingSerializer, NoShowSerializer,
+};
+use axum::extract::{Path, State};
+use axum::{Extension, Json};
+
+#[utoipa::path(
+    post,
+    path = "/{id}/no-shows",
+    tags = ["no_show"],
+    params(
+        ("id" = i64, Path, description = "Flight ID")
+    ),
+    responses(
+        (status = 200, description = "No-shows recorded and penalties applied", body = EntityResponse<NoShowProcessingSerializer>),
+        (status = 401, description = "Unauthorized", body = ClientResponseError),
+        (status = 403, description = "Forbidden", body = ClientResponseError),
+        (status = 404, description = "Flight not found", body = ClientResponseError),
+        (status = 409, description = "Flight has not departed", body = ClientResponseError),
+        (status = 500, description = "Internal server error", body = ClientResponseError)
+    ),
+    security(("jwt" = []))
+)]
+pub async fn controller_process_no_shows(
+    State(state): State<AppState>,
+    Extension(ctx): Extension<RequestContext>,
+    Path(id): Path<i64>,
+) -> ApiResult<Json<EntityResponse<NoShowProcessingSerializer>>> {
+    let result = state
+        .no_show_service
+        .process_flight_no_shows(ctx, id)
+        .await?;
+    let data: NoShowProcessingSerializer = result.into();
+
+    Ok(Json(EntityResponse {
+        message: format!("{} no-show(s) recorded.", data.no_shows.len()),
+        total: data.no_shows.len() as i64,
+        data: Some(data),
+    }))
+}
+
+#[utoipa::path(
+    get,
+    path = "/{id}/no-shows",
+    tags = ["no_show"],
+    params(
+        ("id" = i64, Path, description = "Flight ID")
+    ),
+    responses(
+        (status = 200, description = "No-shows retrieved successfully", body = EntityResponse<Vec<NoShowSerializer>>),
+        (status = 401, description = "Unauthorized", body = ClientResponseError),
+        (status = 403, description = "Forbidden", body = ClientResponseError),
+        (status = 404, description = "Flight not found", body = ClientResponseError),
+        (status = 500, description = "Internal server error", body = ClientResponseError)
+    ),
+    security(("jwt" = []))
+)]
+pub async fn controller_list_no_shows(
+    State(state): State<AppState>,
+    Extension(ctx): Extension<RequestContext>,
+    Path(id): Path<i64>,
+) -> ApiResult<Json<EntityResponse<Vec<NoShowSerializer>>>> {
+    let result = state.no_show_service.list_flight_no_shows(ctx, id).await?;
+    let data: Vec<NoShowSerializer> = result.into_iter().map(Into::into).collect();
+
+    Ok(Json(EntityResponse {
+        message: "No-shows retrieved successfully.".to_string(),
+        total: data.len() as i64,
+        data: Some(data),
+    }))
+}
@@ -0,0 +1 @@
+pub mod notification;
\ No newline at end of file
@@ -0,0 +1,99 @@
+use crate::core::app_state::AppState;
+use crate::core::context::request_context::RequestContext;
+use crate::core::response::common::{ClientResponseError, EntityResponse};
+use crate::presentation::http::ApiResult;
+use crate::presentation::notification::notification_request::{
+    ListNotificationsQuery, UpdateNotificationPreferenceRequest,
+};
+use crate::presentation::notification::notification_serializer::{
+    NotificationPreferenceSerializer, NotificationSerializer,
+};
+use axum::extract::{Query, State};
+use axum::{Extension, Json};
+
+#[utoipa::path(
+    get,
+    path = "",
+    tags = ["notification"],
+    params(ListNotificationsQuery),
+    responses(
+        (status = 200, description = "Notifications retrieved successfully", body = EntityResponse<Vec<NotificationSerializer>>),
+        (status = 400, description = "Invalid status", body = ClientResponseError),
+        (status = 401, description = "Unauthorized", body = ClientResponseError),
+        (status = 403, description = "Forbidden", body = ClientResponseError),
+        (status = 500, description = "Internal server error", body = ClientResponseError)
+    ),
+    security(("jwt" = []))
+)]
+pub async fn controller_list_notifications(
+    State(state): State<AppState>,
+    Extension(ctx): Extension<RequestContext>,
+    Query(params): Query<ListNotificationsQuery>,
+) -> ApiResult<Json<EntityResponse<Vec<NotificationSerializer>>>> {
+    let result = state
+        .notification_service
+        .list_notifications(ctx, params.user_id, params.status)
+        .await?;
+    let data: Vec<NotificationSerializer> = result.into_iter().map(Into::into).collect();
+
+    Ok(Json(EntityResponse {
+        message: "Notifications retrieved successfully.".to_string(),
+        data: Some(data.clone()),
+        total: data.len() as i64,
+    }))
+}
+
+#[utoipa::path(
+    get,
+    path = "/preferences",
+    tags = ["notification"],
+    responses(
+        (status = 200, description = "Preferences retrieved successfully", body = EntityResponse<NotificationPreferenceSerializer>),
+        (status = 401, description = "Unauthorized", body = ClientResponseError),
+        (status = 500, description = "Internal server error", body = ClientResponseError)
+    ),
+    security(("jwt" = []))
+)]
+pub async fn controller_get_notification_preferences(
+    State(state): State<AppState>,
+    Extension(ctx): Extension<RequestContext>,
+) -> ApiResult<Json<EntityResponse<NotificationPreferenceSerializer>>> {
+    let result = state.notification_service.get_preference(ctx).await?;
+
+    Ok(Json(EntityResponse {
+        message: "Notification preferences retrieved successfully.".to_string(),
+        data: Some(result.into()),
+        total: 1,
+    }))
+}
+
+#[utoipa::path(
+    put,
+    path = "/preferences",
+    tags = ["notification"],
+    request_body = UpdateNotificationPreferenceRequest,
+    responses(
+        (status = 200, description = "Preferences updated successfully", body = EntityResponse<NotificationPreferenceSerializer>),
+        (status = 400, description = "Invalid locale or missing push token", body = ClientResponseError),
+        (status = 401, description = "Unauthorized", body = ClientResponseError),
+        (status = 409, description = "Preferences changed concurrently", body = ClientResponseError),
+        (status = 500, description = "Internal server error", body = ClientResponseError)
+    ),
+    security(("jwt" = []))
+)]
+pub async fn controller_update_notification_preferences(
+    State(state): State<AppState>,
+    Extension(ctx): Extension<RequestContext>,
+    Json(req): Json<UpdateNotificationPreferenceRequest>,
+) -> ApiResult<Json<EntityResponse<NotificationPreferenceSerializer>>> {
+    let result = state
+        .notification_service
+        .update_preference(ctx, req.into())
+        .await?;
+
+    Ok(Json(EntityResponse {
+        message: "Notification preferences updated successfully.".to_string(),
+        data: Some(result.into()),
+        total: 1,
+    }))
+}
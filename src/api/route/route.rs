@@ -0,0 +1,149 @@
+use crate::application::route::route_command::UpdateRouteCommand;
+use crate::core::app_state::AppState;
+use crate::core::context::request_context::RequestContext;
+use crate::core::response::common::{ClientResponseError, EntityResponse};
+use crate::presentation::http::ApiResult;
+use crate::presentation::route::route_request::{CreateRouteRequest, UpdateRouteRequest};
+use crate::presentation::route::route_serializer::RouteSerializer;
+use axum::extract::{Path, Query, State};
+use axum::http::StatusCode;
+use axum::{Extension, Json};
+use serde::Deserialize;
+
+#[derive(Deserialize)]
+pub struct RouteListQuery {
+    pub origin: Option<String>,
+    pub destination: Option<String>,
+    #[serde(default)]
+    pub active_only: bool,
+}
+
+#[utoipa::path(
+    post,
+    path = "",
+    tags = ["route"],
+    request_body = CreateRouteRequest,
+    responses(
+        (status = 201, description = "Route created successfully", body = EntityResponse<bool>),
+        (status = 400, description = "Bad request", body = ClientResponseError),
+        (status = 401, description = "Unauthorized", body = ClientResponseError),
+        (status = 404, description = "Airport not found", body = ClientResponseError),
+        (status = 409, description = "Route already exists", body = ClientResponseError),
+        (status = 500, description = "Internal server error", body = ClientResponseError)
+    ),
+    security(("jwt" = []))
+)]
+pub async fn controller_create_route(
+    State(state): State<AppState>,
+    Extension(ctx): Extension<RequestContext>,
+    Json(req): Json<CreateRouteRequest>,
+) -> ApiResult<(StatusCode, Json<EntityResponse<bool>>)> {
+    let command = req.to_command();
+    let result = state.route_service.create_route(ctx, command).await?;
+
+    Ok((
+        StatusCode::CREATED,
+        Json(EntityResponse {
+            message: "Route created successfully.".to_string(),
+            data: Some(result),
+            total: 1,
+        }),
+    ))
+}
+
+#[utoipa::path(
+    put,
+    path = "/{id}",
+    tags = ["route"],
+    request_body = UpdateRouteRequest,
+    params(
+        ("id" = i64, Path, description = "Route ID")
+    ),
+    responses(
+        (status = 200, description = "Route updated successfully", body = EntityResponse<bool>),
+        (status = 400, description = "Bad request", body = ClientResponseError),
+        (status = 401, description = "Unauthorized", body = ClientResponseError),
+        (status = 404, description = "Route not found", body = ClientResponseError),
+        (status = 500, description = "Internal server error", body = ClientResponseError)
+    ),
+    security(("jwt" = []))
+)]
+pub async fn controller_update_route(
+    State(state): State<AppState>,
+    Extension(ctx): Extension<RequestContext>,
+    Path(id): Path<i64>,
+    Json(req): Json<UpdateRouteRequest>,
+) -> ApiResult<Json<EntityResponse<bool>>> {
+    let command: UpdateRouteCommand = req.into();
+    let result = state.route_service.update_route(ctx, id, command).await?;
+
+    Ok(Json(EntityResponse {
+        message: "Route updated successfully.".to_string(),
+        data: Some(result),
+        total: 1,
+    }))
+}
+
+#[utoipa::path(
+    get,
+    path = "/{id}",
+    tags = ["route"],
+    params(
+        ("id" = i64, Path, description = "Route ID")
+    ),
+    responses(
+        (status = 200, description = "Route retrieved successfully", body = EntityResponse<RouteSerializer>),
+        (status = 401, description = "Unauthorized", body = ClientResponseError),
+        (status = 404, description = "Route not found", body = ClientResponseError),
+        (status = 500, description = "Internal server error", body = ClientResponseError)
+    ),
+    security(("jwt" = []))
+)]
+pub async fn controller_get_route_by_id(
+    State(state): State<AppState>,
+    Extension(ctx): Extension<RequestContext>,
+    Path(id): Path<i64>,
+) -> ApiResult<Json<EntityResponse<RouteSerializer>>> {
+    let result = state.route_service.get_route_by_id(ctx, id).await?;
+
+    Ok(Json(EntityResponse {
+        message: "Route retrieved successfully.".to_string(),
+        data: Some(result.into()),
+        total: 1,
+    }))
+}
+
+#[utoipa::path(
+    get,
+    path = "",
+    tags = ["route"],
+    params(
+        ("origin" = Option<String>, Query, description = "Origin airport IATA code"),
+        ("destination" = Option<String>, Query, description = "Destination airport IATA code"),
+        ("active_only" = Option<bool>, Query, description = "Only return active routes")
+    ),
+    responses(
+        (status = 200, description = "Routes retrieved successfully", body = EntityResponse<Vec<RouteSerializer>>),
+        (status = 401, description = "Unauthorized", body = ClientResponseError),
+        (status = 404, description = "Airport not found", body = ClientResponseError),
+        (status = 500, description = "Internal server error", body = ClientResponseError)
+    ),
+    security(("jwt" = []))
+)]
+pub async fn controller_list_routes(
+    State(state): State<AppState>,
+    Extension(ctx): Extension<RequestContext>,
+    Query(params): Query<RouteListQuery>,
+) -> ApiResult<Json<EntityResponse<Vec<RouteSerializer>>>> {
+    let result = state
+        .route_service
+        .list_routes(ctx, params.origin, params.destination, params.active_only)
+        .await?;
+    let data: Vec<RouteSerializer> = result.into_iter().map(Into::into).collect();
+
+    Ok(Json(EntityResponse {
+        message: "Routes retrieved successfully.".to_string(),
+        total: data.len() as i64,
+        data: Some(data),
+    }))
+}
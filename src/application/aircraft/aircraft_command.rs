@@ -0,0 +1,24 @@
+use validator::Validate;
+
+#[derive(Debug, Clone, Validate)]
+pub struct CreateAircraftCommand {
+    #[validate(length(min = 2, max = 10))]
+    pub tail_number: String,
+    #[validate(length(min = 2, max = 4))]
+    pub aircraft_type: String,
+    #[validate(length(min = 1, max = 50))]
+    pub seat_configuration: String,
+    #[validate(length(equal = 2))]
+    pub airline_code: String,
+}
+
+#[derive(Debug, Clone, Validate)]
+pub struct UpdateAircraftCommand {
+    #[validate(length(min = 2, max = 4))]
+    pub aircraft_type: Option<String>,
+    #[validate(length(min = 1, max = 50))]
+    pub seat_configuration: Option<String>,
+    #[validate(length(equal = 2))]
+    pub airline_code: Option<String>,
+    pub status: Option<String>,
+}
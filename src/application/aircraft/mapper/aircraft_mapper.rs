@@ -0,0 +1,23 @@
+use crate::application::aircraft::view::aircraft_view::AircraftView;
+use crate::domain::aircraft::entity::{Aircraft, AircraftStatus};
+
+pub fn aircraft_status_to_string(status: &AircraftStatus) -> String {
+    match status {
+        AircraftStatus::Active => "ACTIVE",
+        AircraftStatus::Maintenance => "MAINTENANCE",
+    }
+    .to_string()
+}
+
+impl From<Aircraft> for AircraftView {
+    fn from(aircraft: Aircraft) -> Self {
+        Self {
+            id: aircraft.id,
+            status: aircraft_status_to_string(&aircraft.status),
+            tail_number: aircraft.tail_number,
+            aircraft_type: aircraft.aircraft_type,
+            seat_configuration: aircraft.seat_configuration,
+            airline_id: aircraft.airline_id,
+        }
+    }
+}
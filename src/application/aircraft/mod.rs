@@ -0,0 +1,4 @@
+pub mod aircraft_command;
+pub mod mapper;
+pub mod use_case;
+pub mod view;
@@ -0,0 +1,320 @@
+use crate::application::aircraft::aircraft_command::{
+    CreateAircraftCommand, UpdateAircraftCommand,
+};
+use crate::application::aircraft::use_case::aircraft_service_interface::AircraftServiceInterface;
+use crate::application::aircraft::view::aircraft_view::AircraftView;
+use crate::application::common::cache_helper::{cache_get_json, cache_set_json};
+use crate::application::common::cache_interface::CacheInterface;
+use crate::application::common::use_case_error::{UseCaseError, UseCaseResult};
+use crate::core::context::request_context::RequestContext;
+use crate::domain::aircraft::aircraft_repository_interface::AircraftRepositoryInterface;
+use crate::domain::aircraft::entity::{
+    Aircraft, AircraftStatus, CreateAircraftProps, UpdateAircraftProps,
+};
+use crate::domain::aircraft::error::AircraftDomainError;
+use crate::domain::airline::airline_repository_interface::AirlineRepositoryInterface;
+use crate::domain::airline::entity::Airline;
+use crate::domain::error::DomainError;
+use std::sync::Arc;
+use validator::Validate;
+
+pub struct AircraftService {
+    pub cache: Arc<dyn CacheInterface>,
+    pub aircraft_repo: Arc<dyn AircraftRepositoryInterface>,
+    pub airline_repo: Arc<dyn AirlineRepositoryInterface>,
+}
+
+impl AircraftService {
+    pub fn new(
+        cache: Arc<dyn CacheInterface>,
+        aircraft_repo: Arc<dyn AircraftRepositoryInterface>,
+        airline_repo: Arc<dyn AirlineRepositoryInterface>,
+    ) -> Self {
+        Self {
+            cache,
+            aircraft_repo,
+            airline_repo,
+        }
+    }
+
+    fn aircraft_id_cache_key(id: i64) -> String {
+        format!("aircraft:id:{id}")
+    }
+
+    fn aircraft_tail_cache_key(tail_number: &str) -> String {
+        format!("aircraft:tail:{tail_number}")
+    }
+
+    fn aircraft_list_cache_key(airline_id: Option<i64>) -> String {
+        match airline_id {
+            Some(airline_id) => format!("aircraft:airline:{airline_id}"),
+            None => "aircraft:all".to_string(),
+        }
+    }
+
+    fn validation_error(field: &'static str, message: String) -> UseCaseError {
+        UseCaseError::Domain(DomainError::Aircraft(AircraftDomainError::Validation {
+            field,
+            message,
+        }))
+    }
+
+    fn parse_status(value: &str) -> UseCaseResult<AircraftStatus> {
+        match value.trim().to_uppercase().as_str() {
+            "ACTIVE" => Ok(AircraftStatus::Active),
+            "MAINTENANCE" => Ok(AircraftStatus::Maintenance),
+            _ => Err(Self::validation_error(
+                "status",
+                format!("Invalid aircraft status: {value}"),
+            )),
+        }
+    }
+
+    async fn invalidate_list_cache(&self, airline_ids: &[i64]) {
+        let keys = airline_ids
+            .iter()
+            .map(|id| Self::aircraft_list_cache_key(Some(*id)))
+            .chain([Self::aircraft_list_cache_key(None)]);
+
+        for key in keys {
+            if let Err(err) = self.cache.del(&key).await {
+                tracing::warn!("cache del failed key={}: {}", key, err);
+            }
+        }
+    }
+
+    async fn invalidate_aircraft_cache(&self, aircraft: &Aircraft, airline_ids: &[i64]) {
+        let keys = [
+            Self::aircraft_id_cache_key(aircraft.id),
+            Self::aircraft_tail_cache_key(&aircraft.tail_number),
+        ];
+
+        for key in keys {
+            if let Err(err) = self.cache.del(&key).await {
+                tracing::warn!("cache del failed key={}: {}", key, err);
+            }
+        }
+        self.invalidate_list_cache(airline_ids).await;
+    }
+
+    async fn cache_aircraft_view(&self, view: &AircraftView) {
+        let keys = [
+            Self::aircraft_id_cache_key(view.id),
+            Self::aircraft_tail_cache_key(&view.tail_number),
+        ];
+
+        for key in keys {
+            if let Err(err) = cache_set_json(self.cache.as_ref(), &key, view, 86400).await {
+                tracing::warn!("cache set failed key={}: {}", key, err);
+            }
+        }
+    }
+
+    async fn load_aircraft(&self, id: i64) -> UseCaseResult<Aircraft> {
+        self.aircraft_repo
+            .find_aircraft_by_id(id)
+            .await
+            .map_err(|e| UseCaseError::Unexpected(e.to_string()))?
+            .ok_or_else(|| UseCaseError::NotFound(format!("Aircraft with id {} not found", id)))
+    }
+
+    async fn load_airline(&self, airline_code: &str) -> UseCaseResult<Airline> {
+        let iata_code = airline_code.trim().to_uppercase();
+        self.airline_repo
+            .find_airline_by_iata_code(&iata_code)
+            .await
+            .map_err(|e| UseCaseError::Unexpected(e.to_string()))?
+            .ok_or_else(|| {
+                UseCaseError::NotFound(format!("Airline with iata_code {} not found", iata_code))
+            })
+    }
+}
+
+#[async_trait::async_trait]
+impl AircraftServiceInterface for AircraftService {
+    async fn create_aircraft(
+        &self,
+        ctx: RequestContext,
+        command: CreateAircraftCommand,
+    ) -> UseCaseResult<bool> {
+        if !ctx.is_admin() {
+            return Err(UseCaseError::PermissionDenied);
+        }
+        let _ = ctx
+            .require_user()
+            .map_err(|_| UseCaseError::PermissionDenied)?;
+
+        command
+            .validate()
+            .map_err(|e| Self::validation_error("command", e.to_string()))?;
+
+        let tail_number = command.tail_number.trim().to_uppercase();
+        let tail_exists = self
+            .aircraft_repo
+            .tail_number_exists(&tail_number)
+            .await
+            .map_err(|e| UseCaseError::Unexpected(e.to_string()))?;
+        if tail_exists {
+            return Err(UseCaseError::AlreadyExists(format!(
+                "Aircraft with tail_number {} already exists",
+                tail_number
+            )));
+        }
+
+        let airline = self.load_airline(&command.airline_code).await?;
+        airline.ensure_active()?;
+
+        let aircraft = Aircraft::new(CreateAircraftProps {
+            tail_number,
+            aircraft_type: command.aircraft_type.trim().to_uppercase(),
+            seat_configuration: command.seat_configuration.trim().to_uppercase(),
+            airline_id: airline.id,
+        })?;
+
+        self.aircraft_repo
+            .create_aircraft(&aircraft)
+            .await
+            .map_err(|e| UseCaseError::Unexpected(e.to_string()))?;
+
+        self.invalidate_list_cache(&[airline.id]).await;
+
+        Ok(true)
+    }
+
+    async fn update_aircraft(
+        &self,
+        ctx: RequestContext,
+        id: i64,
+        command: UpdateAircraftCommand,
+    ) -> UseCaseResult<bool> {
+        if !ctx.is_admin() {
+            return Err(UseCaseError::PermissionDenied);
+        }
+        let _ = ctx
+            .require_user()
+            .map_err(|_| UseCaseError::PermissionDenied)?;
+
+        command
+            .validate()
+            .map_err(|e| Self::validation_error("command", e.to_string()))?;
+
+        let mut aircraft = self.load_aircraft(id).await?;
+        let previous_airline_id = aircraft.airline_id;
+
+        let airline_id = match command.airline_code.as_deref() {
+            Some(code) => {
+                let airline = self.load_airline(code).await?;
+                airline.ensure_active()?;
+                Some(airline.id)
+            }
+            None => None,
+        };
+        let status = command
+            .status
+            .as_deref()
+            .map(Self::parse_status)
+            .transpose()?;
+
+        aircraft.update(UpdateAircraftProps {
+            aircraft_type: command.aircraft_type.map(|v| v.trim().to_uppercase()),
+            seat_configuration: command.seat_configuration.map(|v| v.trim().to_uppercase()),
+            airline_id,
+            status,
+        })?;
+
+        self.aircraft_repo
+            .update_aircraft(&aircraft)
+            .await
+            .map_err(|e| UseCaseError::Unexpected(e.to_string()))?;
+
+        self.invalidate_aircraft_cache(&aircraft, &[previous_airline_id, aircraft.airline_id])
+            .await;
+
+        Ok(true)
+    }
+
+    async fn get_aircraft_by_id(
+        &self,
+        ctx: RequestContext,
+        id: i64,
+    ) -> UseCaseResult<AircraftView> {
+        let _ = ctx.user_id().ok_or(UseCaseError::PermissionDenied)?;
+
+        let cache_key = Self::aircraft_id_cache_key(id);
+        match cache_get_json::<AircraftView>(self.cache.as_ref(), &cache_key).await {
+            Ok(Some(cached)) => return Ok(cached),
+            Ok(None) => {}
+            Err(err) => tracing::warn!("cache get aircraft failed key={}: {}", cache_key, err),
+        }
+
+        let view: AircraftView = self.load_aircraft(id).await?.into();
+        self.cache_aircraft_view(&view).await;
+
+        Ok(view)
+    }
+
+    async fn get_aircraft_by_tail_number(
+        &self,
+        ctx: RequestContext,
+        tail_number: String,
+    ) -> UseCaseResult<AircraftView> {
+        let _ = ctx.user_id().ok_or(UseCaseError::PermissionDenied)?;
+        let normalized_tail = tail_number.trim().to_uppercase();
+
+        let cache_key = Self::aircraft_tail_cache_key(&normalized_tail);
+        match cache_get_json::<AircraftView>(self.cache.as_ref(), &cache_key).await {
+            Ok(Some(cached)) => return Ok(cached),
+            Ok(None) => {}
+            Err(err) => tracing::warn!("cache get aircraft failed key={}: {}", cache_key, err),
+        }
+
+        let aircraft = self
+            .aircraft_repo
+            .find_aircraft_by_tail_number(&normalized_tail)
+            .await
+            .map_err(|e| UseCaseError::Unexpected(e.to_string()))?
+            .ok_or_else(|| {
+                UseCaseError::NotFound(format!(
+                    "Aircraft with tail_number {} not found",
+                    normalized_tail
+                ))
+            })?;
+
+        let view: AircraftView = aircraft.into();
+        self.cache_aircraft_view(&view).await;
+
+        Ok(view)
+    }
+
+    async fn list_aircraft(
+        &self,
+        ctx: RequestContext,
+        airline_code: Option<String>,
+    ) -> UseCaseResult<Vec<AircraftView>> {
+        let _ = ctx.user_id().ok_or(UseCaseError::PermissionDenied)?;
+
+        let airline_id = match airline_code.as_deref() {
+            Some(code) => Some(self.load_airline(code).await?.id),
+            None => None,
+        };
+
+        let cache_key = Self::aircraft_list_cache_key(airline_id);
+        if let Ok(Some(cached)) = cache_get_json(self.cache.as_ref(), &cache_key).await {
+            return Ok(cached);
+        }
+
+        let fleet = self
+            .aircraft_repo
+            .list_aircraft(airline_id)
+            .await
+            .map_err(|e| UseCaseError::Unexpected(e.to_string()))?;
+
+        let views: Vec<AircraftView> = fleet.into_iter().map(Into::into).collect();
+
+        if let Err(err) = cache_set_json(self.cache.as_ref(), &cache_key, &views, 3600).await {
+            tracing::warn!("cache set failed key={}: {}", cache_key, err);
+        }
+
+        Ok(views)
+    }
+}
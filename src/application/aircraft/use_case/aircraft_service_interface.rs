@@ -0,0 +1,38 @@
+use crate::application::aircraft::aircraft_command::{
+    CreateAircraftCommand, UpdateAircraftCommand,
+};
+use crate::application::aircraft::view::aircraft_view::AircraftView;
+use crate::application::common::use_case_error::UseCaseResult;
+use crate::core::context::request_context::RequestContext;
+
+#[async_trait::async_trait]
+pub trait AircraftServiceInterface: Send + Sync {
+    async fn create_aircraft(
+        &self,
+        ctx: RequestContext,
+        command: CreateAircraftCommand,
+    ) -> UseCaseResult<bool>;
+
+    async fn update_aircraft(
+        &self,
+        ctx: RequestContext,
+        id: i64,
+        command: UpdateAircraftCommand,
+    ) -> UseCaseResult<bool>;
+
+    async fn get_aircraft_by_id(&self, ctx: RequestContext, id: i64)
+    -> UseCaseResult<AircraftView>;
+
+    async fn get_aircraft_by_tail_number(
+        &self,
+        ctx: RequestContext,
+        tail_number: String,
+    ) -> UseCaseResult<AircraftView>;
+
+    /// Whole fleet, or one operator's fleet when `airline_code` is given.
+    async fn list_aircraft(
+        &self,
+        ctx: RequestContext,
+        airline_code: Option<String>,
+    ) -> UseCaseResult<Vec<AircraftView>>;
+}
@@ -0,0 +1,2 @@
+pub mod aircraft_service;
+pub mod aircraft_service_interface;
@@ -0,0 +1,11 @@
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct AircraftView {
+    pub id: i64,
+    pub tail_number: String,
+    pub aircraft_type: String,
+    pub seat_configuration: String,
+    pub airline_id: i64,
+    pub status: String,
+}
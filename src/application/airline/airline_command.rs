@@ -0,0 +1,31 @@
+use validator::Validate;
+
+#[derive(Debug, Clone, Validate)]
+pub struct CreateAirlineCommand {
+    #[validate(length(equal = 2))]
+    pub iata_code: String,
+    #[validate(length(equal = 3))]
+    pub icao_code: Option<String>,
+    #[validate(length(min = 1, max = 255))]
+    pub name: String,
+    pub callsign: Option<String>,
+    #[validate(length(equal = 2))]
+    pub country_code: String,
+    #[validate(url)]
+    pub logo_url: Option<String>,
+    pub is_active: bool,
+}
+
+#[derive(Debug, Clone, Validate)]
+pub struct UpdateAirlineCommand {
+    #[validate(length(equal = 3))]
+    pub icao_code: Option<String>,
+    #[validate(length(min = 1, max = 255))]
+    pub name: Option<String>,
+    pub callsign: Option<String>,
+    #[validate(length(equal = 2))]
+    pub country_code: Option<String>,
+    #[validate(url)]
+    pub logo_url: Option<String>,
+    pub is_active: Option<bool>,
+}
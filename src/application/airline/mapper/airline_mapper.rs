@@ -0,0 +1,17 @@
+use crate::application::airline::view::airline_view::AirlineView;
+use crate::domain::airline::entity::Airline;
+
+impl From<Airline> for AirlineView {
+    fn from(airline: Airline) -> Self {
+        Self {
+            id: airline.id,
+            iata_code: airline.iata_code,
+            icao_code: airline.icao_code,
+            name: airline.name,
+            callsign: airline.callsign,
+            country_code: airline.country_code,
+            logo_url: airline.logo_url,
+            is_active: airline.is_active,
+        }
+    }
+}
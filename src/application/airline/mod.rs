@@ -0,0 +1,4 @@
+pub mod airline_command;
+pub mod mapper;
+pub mod use_case;
+pub mod view;
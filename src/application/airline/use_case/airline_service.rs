@@ -0,0 +1,341 @@
+use crate::application::airline::airline_command::{CreateAirlineCommand, UpdateAirlineCommand};
+use crate::application::airline::use_case::airline_service_interface::AirlineServiceInterface;
+use crate::application::airline::view::airline_view::AirlineView;
+use crate::application::common::cache_helper::{cache_get_json, cache_set_json};
+use crate::application::common::cache_interface::CacheInterface;
+use crate::application::common::event_publisher::AirlineEventPublisher;
+use crate::application::common::use_case_error::{UseCaseError, UseCaseResult};
+use crate::core::context::request_context::RequestContext;
+use crate::domain::airline::airline_repository_interface::AirlineRepositoryInterface;
+use crate::domain::airline::entity::{Airline, CreateAirlineProps, UpdateAirlineProps};
+use crate::domain::airline::error::AirlineDomainError;
+use crate::domain::airline::events::airline_created::AirlineCreatedEvent;
+use crate::domain::airline::events::airline_deactivated::AirlineDeactivatedEvent;
+use crate::domain::airline::events::airline_updated::AirlineUpdatedEvent;
+use crate::domain::error::DomainError;
+use std::sync::Arc;
+use validator::Validate;
+
+pub struct AirlineService {
+    pub cache: Arc<dyn CacheInterface>,
+    pub airline_repo: Arc<dyn AirlineRepositoryInterface>,
+    pub event_publisher: Arc<dyn AirlineEventPublisher>,
+}
+
+impl AirlineService {
+    pub fn new(
+        cache: Arc<dyn CacheInterface>,
+        airline_repo: Arc<dyn AirlineRepositoryInterface>,
+        event_publisher: Arc<dyn AirlineEventPublisher>,
+    ) -> Self {
+        Self {
+            cache,
+            airline_repo,
+            event_publisher,
+        }
+    }
+
+    fn airline_id_cache_key(id: i64) -> String {
+        format!("airline:id:{id}")
+    }
+
+    fn airline_iata_cache_key(iata_code: &str) -> String {
+        format!("airline:iata:{iata_code}")
+    }
+
+    fn airlines_cache_key(active_only: bool) -> String {
+        if active_only {
+            "airlines:active".to_string()
+        } else {
+            "airlines:all".to_string()
+        }
+    }
+
+    fn validation_error(field: &'static str, message: String) -> UseCaseError {
+        UseCaseError::Domain(DomainError::Airline(AirlineDomainError::Validation {
+            field,
+            message,
+        }))
+    }
+
+    fn normalize_optional(value: Option<String>) -> Option<String> {
+        value
+            .map(|v| v.trim().to_string())
+            .filter(|v| !v.is_empty())
+    }
+
+    async fn invalidate_list_cache(&self) {
+        let keys = [
+            Self::airlines_cache_key(true),
+            Self::airlines_cache_key(false),
+        ];
+
+        for key in keys {
+            if let Err(err) = self.cache.del(&key).await {
+                tracing::warn!("cache del failed key={}: {}", key, err);
+            }
+        }
+    }
+
+    async fn invalidate_airline_cache(&self, id: i64, iata_code: &str) {
+        let keys = [
+            Self::airline_id_cache_key(id),
+            Self::airline_iata_cache_key(iata_code),
+            Self::airlines_cache_key(true),
+            Self::airlines_cache_key(false),
+        ];
+
+        for key in keys {
+            if let Err(err) = self.cache.del(&key).await {
+                tracing::warn!("cache del failed key={}: {}", key, err);
+            }
+        }
+    }
+
+    async fn cache_airline_view(&self, view: &AirlineView) {
+        let keys = [
+            Self::airline_id_cache_key(view.id),
+            Self::airline_iata_cache_key(&view.iata_code),
+        ];
+
+        for key in keys {
+            if let Err(err) = cache_set_json(self.cache.as_ref(), &key, view, 86400).await {
+                tracing::warn!("cache set failed key={}: {}", key, err);
+            }
+        }
+    }
+
+    async fn load_airline(&self, id: i64) -> UseCaseResult<Airline> {
+        self.airline_repo
+            .find_airline_by_id(id)
+            .await
+            .map_err(|e| UseCaseError::Unexpected(e.to_string()))?
+            .ok_or_else(|| UseCaseError::NotFound(format!("Airline with id {} not found", id)))
+    }
+}
+
+#[async_trait::async_trait]
+impl AirlineServiceInterface for AirlineService {
+    async fn create_airline(
+        &self,
+        ctx: RequestContext,
+        command: CreateAirlineCommand,
+    ) -> UseCaseResult<bool> {
+        if !ctx.is_admin() {
+            return Err(UseCaseError::PermissionDenied);
+        }
+        let _ = ctx
+            .require_user()
+            .map_err(|_| UseCaseError::PermissionDenied)?;
+
+        command
+            .validate()
+            .map_err(|e| Self::validation_error("command", e.to_string()))?;
+
+        let iata_code = command.iata_code.trim().to_uppercase();
+        let iata_exists = self
+            .airline_repo
+            .iata_code_exists(&iata_code)
+            .await
+            .map_err(|e| UseCaseError::Unexpected(e.to_string()))?;
+        if iata_exists {
+            return Err(UseCaseError::AlreadyExists(format!(
+                "Airline with iata_code {} already exists",
+                iata_code
+            )));
+        }
+
+        let props = CreateAirlineProps {
+            iata_code,
+            icao_code: command.icao_code.map(|code| code.trim().to_uppercase()),
+            name: command.name.trim().to_string(),
+            callsign: Self::normalize_optional(command.callsign).map(|v| v.to_uppercase()),
+            country_code: command.country_code.trim().to_uppercase(),
+            logo_url: Self::normalize_optional(command.logo_url),
+        };
+
+        let mut airline = Airline::new(props)?;
+        airline.is_active = command.is_active;
+
+        let airline_id = self
+            .airline_repo
+            .create_airline(&airline)
+            .await
+            .map_err(|e| UseCaseError::Unexpected(e.to_string()))?;
+
+        self.invalidate_list_cache().await;
+        let _ = self
+            .event_publisher
+            .publish_airline_created(AirlineCreatedEvent::new(
+                airline_id,
+                airline.iata_code.clone(),
+                chrono::Utc::now().naive_utc(),
+            ))
+            .await;
+
+        Ok(true)
+    }
+
+    async fn update_airline(
+        &self,
+        ctx: RequestContext,
+        id: i64,
+        command: UpdateAirlineCommand,
+    ) -> UseCaseResult<bool> {
+        if !ctx.is_admin() {
+            return Err(UseCaseError::PermissionDenied);
+        }
+        let _ = ctx
+            .require_user()
+            .map_err(|_| UseCaseError::PermissionDenied)?;
+
+        command
+            .validate()
+            .map_err(|e| Self::validation_error("command", e.to_string()))?;
+
+        let mut airline = self.load_airline(id).await?;
+
+        let props = UpdateAirlineProps {
+            icao_code: command.icao_code.map(|code| code.trim().to_uppercase()),
+            name: command.name.map(|v| v.trim().to_string()),
+            callsign: Self::normalize_optional(command.callsign).map(|v| v.to_uppercase()),
+            country_code: command.country_code.map(|v| v.trim().to_uppercase()),
+            logo_url: Self::normalize_optional(command.logo_url),
+        };
+        airline.update(props)?;
+
+        if let Some(is_active) = command.is_active {
+            airline.is_active = is_active;
+        }
+
+        self.airline_repo
+            .update_airline(&airline)
+            .await
+            .map_err(|e| UseCaseError::Unexpected(e.to_string()))?;
+
+        self.invalidate_airline_cache(airline.id, &airline.iata_code)
+            .await;
+        let _ = self
+            .event_publisher
+            .publish_airline_updated(AirlineUpdatedEvent::new(
+                airline.id,
+                airline.iata_code.clone(),
+                chrono::Utc::now().naive_utc(),
+            ))
+            .await;
+
+        Ok(true)
+    }
+
+    async fn get_airline_by_id(&self, ctx: RequestContext, id: i64) -> UseCaseResult<AirlineView> {
+        let _ = ctx.user_id().ok_or(UseCaseError::PermissionDenied)?;
+
+        let cache_key = Self::airline_id_cache_key(id);
+        match cache_get_json::<AirlineView>(self.cache.as_ref(), &cache_key).await {
+            Ok(Some(cached)) => return Ok(cached),
+            Ok(None) => {}
+            Err(err) => tracing::warn!("cache get airline failed key={}: {}", cache_key, err),
+        }
+
+        let view: AirlineView = self.load_airline(id).await?.into();
+        self.cache_airline_view(&view).await;
+
+        Ok(view)
+    }
+
+    async fn get_airline_by_iata_code(
+        &self,
+        ctx: RequestContext,
+        iata_code: String,
+    ) -> UseCaseResult<AirlineView> {
+        let _ = ctx.user_id().ok_or(UseCaseError::PermissionDenied)?;
+        let normalized_iata = iata_code.trim().to_uppercase();
+        if normalized_iata.len() != 2 {
+            return Err(Self::validation_error(
+                "iata_code",
+                "Must be 2 characters".to_string(),
+            ));
+        }
+
+        let cache_key = Self::airline_iata_cache_key(&normalized_iata);
+        match cache_get_json::<AirlineView>(self.cache.as_ref(), &cache_key).await {
+            Ok(Some(cached)) => return Ok(cached),
+            Ok(None) => {}
+            Err(err) => tracing::warn!("cache get airline failed key={}: {}", cache_key, err),
+        }
+
+        let airline = self
+            .airline_repo
+            .find_airline_by_iata_code(&normalized_iata)
+            .await
+            .map_err(|e| UseCaseError::Unexpected(e.to_string()))?
+            .ok_or_else(|| {
+                UseCaseError::NotFound(format!(
+                    "Airline with iata_code {} not found",
+                    normalized_iata
+                ))
+            })?;
+
+        let view: AirlineView = airline.into();
+        self.cache_airline_view(&view).await;
+
+        Ok(view)
+    }
+
+    async fn list_airlines(
+        &self,
+        ctx: RequestContext,
+        active_only: bool,
+    ) -> UseCaseResult<Vec<AirlineView>> {
+        let _ = ctx.user_id().ok_or(UseCaseError::PermissionDenied)?;
+
+        let cache_key = Self::airlines_cache_key(active_only);
+        if let Ok(Some(cached)) = cache_get_json(self.cache.as_ref(), &cache_key).await {
+            return Ok(cached);
+        }
+
+        let airlines = self
+            .airline_repo
+            .list_airlines(active_only)
+            .await
+            .map_err(|e| UseCaseError::Unexpected(e.to_string()))?;
+
+        let views: Vec<AirlineView> = airlines.into_iter().map(Into::into).collect();
+
+        if let Err(err) = cache_set_json(self.cache.as_ref(), &cache_key, &views, 3600).await {
+            tracing::warn!("cache set failed key={}: {}", cache_key, err);
+        }
+
+        Ok(views)
+    }
+
+    async fn deactivate_airline(&self, ctx: RequestContext, id: i64) -> UseCaseResult<bool> {
+        if !ctx.is_admin() {
+            return Err(UseCaseError::PermissionDenied);
+        }
+        let _ = ctx
+            .require_user()
+            .map_err(|_| UseCaseError::PermissionDenied)?;
+
+        let mut airline = self.load_airline(id).await?;
+
+        airline.deactivate();
+        self.airline_repo
+            .update_airline(&airline)
+            .await
+            .map_err(|e| UseCaseError::Unexpected(e.to_string()))?;
+
+        self.invalidate_airline_cache(airline.id, &airline.iata_code)
+            .await;
+        let _ = self
+            .event_publisher
+            .publish_airline_deactivated(AirlineDeactivatedEvent::new(
+                airline.id,
+                airline.iata_code.clone(),
+                chrono::Utc::now().naive_utc(),
+            ))
+            .await;
+
+        Ok(true)
+    }
+}
@@ -0,0 +1,36 @@
+use crate::application::airline::airline_command::{CreateAirlineCommand, UpdateAirlineCommand};
+use crate::application::airline::view::airline_view::AirlineView;
+use crate::application::common::use_case_error::UseCaseResult;
+use crate::core::context::request_context::RequestContext;
+
+#[async_trait::async_trait]
+pub trait AirlineServiceInterface: Send + Sync {
+    async fn create_airline(
+        &self,
+        ctx: RequestContext,
+        command: CreateAirlineCommand,
+    ) -> UseCaseResult<bool>;
+
+    async fn update_airline(
+        &self,
+        ctx: RequestContext,
+        id: i64,
+        command: UpdateAirlineCommand,
+    ) -> UseCaseResult<bool>;
+
+    async fn get_airline_by_id(&self, ctx: RequestContext, id: i64) -> UseCaseResult<AirlineView>;
+
+    async fn get_airline_by_iata_code(
+        &self,
+        ctx: RequestContext,
+        iata_code: String,
+    ) -> UseCaseResult<AirlineView>;
+
+    async fn list_airlines(
+        &self,
+        ctx: RequestContext,
+        active_only: bool,
+    ) -> UseCaseResult<Vec<AirlineView>>;
+
+    async fn deactivate_airline(&self, ctx: RequestContext, id: i64) -> UseCaseResult<bool>;
+}
@@ -0,0 +1,2 @@
+pub mod airline_service;
+pub mod airline_service_interface;
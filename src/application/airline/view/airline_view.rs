@@ -0,0 +1,13 @@
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct AirlineView {
+    pub id: i64,
+    pub iata_code: String,
+    pub icao_code: Option<String>,
+    pub name: String,
+    pub callsign: Option<String>,
+    pub country_code: String,
+    pub logo_url: Option<String>,
+    pub is_active: bool,
+}
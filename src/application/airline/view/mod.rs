@@ -0,0 +1 @@
+pub mod airline_view;
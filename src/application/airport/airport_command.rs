@@ -25,3 +25,32 @@ pub struct UpdateAirportCommand {
     pub longitude: Option<f64>,
     pub is_active: Option<bool>,
 }
+
+/// OurAirports `airports.csv` and `countries.csv` contents.
+#[derive(Debug, Clone)]
+pub struct ImportAirportsCommand {
+    pub airports_csv: String,
+    pub countries_csv: String,
+    // Only report what would change.
+    pub dry_run: bool,
+}
+
+#[derive(Debug, Clone, Validate)]
+pub struct SearchAirportsCommand {
+    #[validate(length(min = 2, max = 64))]
+    pub query: String,
+    #[validate(range(min = 1, max = 50))]
+    pub limit: Option<u64>,
+}
+
+#[derive(Debug, Clone, Validate)]
+pub struct NearbyAirportsCommand {
+    #[validate(range(min = -90.0, max = 90.0))]
+    pub latitude: f64,
+    #[validate(range(min = -180.0, max = 180.0))]
+    pub longitude: f64,
+    #[validate(range(min = 1.0, max = 2000.0))]
+    pub radius_km: Option<f64>,
+    #[validate(range(min = 1, max = 100))]
+    pub limit: Option<u64>,
+}
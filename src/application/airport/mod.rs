@@ -1,4 +1,5 @@
 pub mod view;
 pub mod use_case;
 pub mod mapper;
-pub mod airport_command;
\ No newline at end of file
+pub mod airport_command;
+pub mod parser;
\ No newline at end of file
@@ -0,0 +1,190 @@
+use crate::application::common::use_case_error::{UseCaseError, UseCaseResult};
+use crate::domain::airport::error::AirportDomainError;
+use crate::domain::error::DomainError;
+use std::collections::HashMap;
+
+/// One `airports.csv` row from OurAirports, reduced to the columns the import uses.
+#[derive(Debug, Clone)]
+pub struct OurAirportsRecord {
+    // 1-based line the row starts on, for the import report.
+    pub line: usize,
+    pub ident: String,
+    pub airport_type: String,
+    pub name: String,
+    pub latitude_deg: String,
+    pub longitude_deg: String,
+    pub iso_country: String,
+    pub municipality: String,
+    pub scheduled_service: bool,
+    pub icao_code: String,
+    pub iata_code: String,
+    // Not part of the upstream export; honoured when a mirror adds it.
+    pub time_zone: String,
+}
+
+fn invalid_file(field: &'static str, message: String) -> UseCaseError {
+    UseCaseError::Domain(DomainError::Airport(AirportDomainError::Validation {
+        field,
+        message,
+    }))
+}
+
+/// Splits RFC 4180 CSV into rows, each tagged with the line it starts on.
+fn parse_csv(content: &str, field: &'static str) -> UseCaseResult<Vec<(usize, Vec<String>)>> {
+    let content = content.strip_prefix('\u{feff}').unwrap_or(content);
+
+    let mut rows = Vec::new();
+    let mut row = Vec::new();
+    let mut value = String::new();
+    let mut in_quotes = false;
+    let mut line = 1;
+    let mut row_line = 1;
+
+    let mut chars = content.chars().peekable();
+    while let Some(c) = chars.next() {
+        if c == '\n' {
+            line += 1;
+        }
+
+        if in_quotes {
+            match c {
+                '"' if chars.peek() == Some(&'"') => {
+                    chars.next();
+                    value.push('"');
+                }
+                '"' => in_quotes = false,
+                _ => value.push(c),
+            }
+            continue;
+        }
+
+        match c {
+            '"' if value.is_empty() => in_quotes = true,
+            ',' => row.push(std::mem::take(&mut value)),
+            '\r' => {}
+            '\n' => {
+                row.push(std::mem::take(&mut value));
+                if row.iter().any(|v| !v.is_empty()) {
+                    rows.push((row_line, std::mem::take(&mut row)));
+                }
+                row.clear();
+                row_line = line;
+            }
+            _ => value.push(c),
+        }
+    }
+
+    if in_quotes {
+        return Err(invalid_file(
+            field,
+            format!("Unterminated quoted value starting on line {row_line}"),
+        ));
+    }
+    if !value.is_empty() || !row.is_empty() {
+        row.push(value);
+        rows.push((row_line, row));
+    }
+
+    Ok(rows)
+}
+
+/// Header lookup, so column order in the export does not matter.
+struct Columns(HashMap<String, usize>);
+
+impl Columns {
+    fn new(header: &[String]) -> Self {
+        Self(
+            header
+                .iter()
+                .enumerate()
+                .map(|(i, name)| (name.trim().to_lowercase(), i))
+                .collect(),
+        )
+    }
+
+    fn require(&self, names: &[&str], field: &'static str) -> UseCaseResult<()> {
+        match names.iter().find(|name| !self.0.contains_key(**name)) {
+            Some(missing) => Err(invalid_file(field, format!("Missing column {missing}"))),
+            None => Ok(()),
+        }
+    }
+
+    fn get(&self, row: &[String], name: &str) -> String {
+        self.0
+            .get(name)
+            .and_then(|i| row.get(*i))
+            .map(|v| v.trim().to_string())
+            .unwrap_or_default()
+    }
+}
+
+pub fn parse_airports(content: &str) -> UseCaseResult<Vec<OurAirportsRecord>> {
+    let mut rows = parse_csv(content, "airports_csv")?.into_iter();
+    let (_, header) = rows
+        .next()
+        .ok_or_else(|| invalid_file("airports_csv", "File is empty".to_string()))?;
+
+    let columns = Columns::new(&header);
+    columns.require(
+        &[
+            "ident",
+            "type",
+            "name",
+            "latitude_deg",
+            "longitude_deg",
+            "iso_country",
+            "municipality",
+            "scheduled_service",
+            "iata_code",
+        ],
+        "airports_csv",
+    )?;
+
+    Ok(rows
+        .map(|(line, row)| {
+            // Older exports only carry the ICAO code in `gps_code`.
+            let icao_code = match columns.get(&row, "icao_code") {
+                code if code.is_empty() => columns.get(&row, "gps_code"),
+                code => code,
+            };
+
+            OurAirportsRecord {
+                line,
+                ident: columns.get(&row, "ident"),
+                airport_type: columns.get(&row, "type"),
+                name: columns.get(&row, "name"),
+                latitude_deg: columns.get(&row, "latitude_deg"),
+                longitude_deg: columns.get(&row, "longitude_deg"),
+                iso_country: columns.get(&row, "iso_country").to_uppercase(),
+                municipality: columns.get(&row, "municipality"),
+                scheduled_service: columns
+                    .get(&row, "scheduled_service")
+                    .eq_ignore_ascii_case("yes"),
+                icao_code: icao_code.to_uppercase(),
+                iata_code: columns.get(&row, "iata_code").to_uppercase(),
+                time_zone: columns.get(&row, "timezone"),
+            }
+        })
+        .collect())
+}
+
+/// `countries.csv`: ISO country code to country name.
+pub fn parse_countries(content: &str) -> UseCaseResult<HashMap<String, String>> {
+    let mut rows = parse_csv(content, "countries_csv")?.into_iter();
+    let (_, header) = rows
+        .next()
+        .ok_or_else(|| invalid_file("countries_csv", "File is empty".to_string()))?;
+
+    let columns = Columns::new(&header);
+    columns.require(&["code", "name"], "countries_csv")?;
+
+    Ok(rows
+        .map(|(_, row)| {
+            (
+                columns.get(&row, "code").to_uppercase(),
+                columns.get(&row, "name"),
+            )
+        })
+        .filter(|(code, _)| !code.is_empty())
+        .collect())
+}
@@ -1,6 +1,16 @@
-use crate::application::airport::airport_command::{CreateAirportCommand, UpdateAirportCommand};
+use crate::application::airport::airport_command::{
+    CreateAirportCommand, ImportAirportsCommand, NearbyAirportsCommand, SearchAirportsCommand,
+    UpdateAirportCommand,
+};
+use crate::application::airport::parser::our_airports_parser::{
+    OurAirportsRecord, parse_airports, parse_countries,
+};
 use crate::application::airport::use_case::airport_service_interface::AirportServiceInterface;
+use crate::application::airport::view::airport_import_view::{
+    AirportImportIssueView, AirportImportReportView,
+};
 use crate::application::airport::view::airport_view::AirportView;
+use crate::application::airport::view::nearby_airport_view::NearbyAirportView;
 use crate::application::common::cache_helper::{cache_get_json, cache_set_json};
 use crate::application::common::cache_interface::CacheInterface;
 use crate::application::common::event_publisher::AirportEventPublisher;
@@ -8,19 +18,58 @@ use crate::application::common::use_case_error::{UseCaseError, UseCaseResult};
 use crate::core::context::request_context::RequestContext;
 use crate::domain::airport;
 use crate::domain::airport::airport_repository_interface::AirportRepositoryInterface;
-use crate::domain::airport::entity::{CreateAirportProps, UpdateAirportProps};
+use crate::domain::airport::country_time_zones::CountryTimeZones;
+use crate::domain::airport::entity::{Airport, CreateAirportProps, UpdateAirportProps};
+use crate::domain::airport::error::AirportDomainError;
 use crate::domain::airport::events::airport_created::AirportCreatedEvent;
 use crate::domain::airport::events::airport_deactivated::AirportDeactivatedEvent;
 use crate::domain::airport::events::airport_updated::AirportUpdatedEvent;
-use crate::domain::airport::error::AirportDomainError;
+use crate::domain::airport::geo::{BoundingBox, GeoPoint};
+use crate::domain::airport::rules::iata_code_must_be_valid::IAtaCodeMustBeValid;
+use crate::domain::airport::search::match_score;
+use crate::domain::business_rule_interface::BusinessRuleInterface;
 use crate::domain::error::DomainError;
+use chrono_tz::Tz;
 use rust_decimal::Decimal;
+use std::collections::HashMap;
 use std::sync::Arc;
 use validator::Validate;
 
+// OurAirports also lists heliports, seaplane bases, balloonports and closed fields.
+const IMPORTED_AIRPORT_TYPES: [&str; 3] = ["large_airport", "medium_airport", "small_airport"];
+
+// Keys per DEL when invalidating after an import.
+const CACHE_INVALIDATION_BATCH: usize = 1000;
+
+const DEFAULT_SEARCH_LIMIT: u64 = 10;
+// Candidates fetched per result, so ranking has room to reorder the trigram order.
+const SEARCH_CANDIDATE_FACTOR: u64 = 5;
+const DEFAULT_NEARBY_RADIUS_KM: f64 = 100.0;
+const DEFAULT_NEARBY_LIMIT: u64 = 20;
+
+/// An import row that passed validation, before duplicate and conflict checks.
+struct ImportCandidate {
+    line: usize,
+    ident: String,
+    scheduled_service: bool,
+    props: CreateAirportProps,
+}
+
+impl ImportCandidate {
+    fn issue(&self, reason: String) -> AirportImportIssueView {
+        AirportImportIssueView {
+            line: self.line,
+            ident: self.ident.clone(),
+            iata_code: self.props.iata_code.clone(),
+            reason,
+        }
+    }
+}
+
 pub struct AirportService {
     pub cache: Arc<dyn CacheInterface>,
     pub airport_repo: Arc<dyn AirportRepositoryInterface>,
+    pub country_time_zones: Arc<CountryTimeZones>,
     pub event_publisher: Arc<dyn AirportEventPublisher>,
 }
 
@@ -28,11 +77,13 @@ impl AirportService {
     pub fn new(
         cache: Arc<dyn CacheInterface>,
         airport_repo: Arc<dyn AirportRepositoryInterface>,
+        country_time_zones: Arc<CountryTimeZones>,
         event_publisher: Arc<dyn AirportEventPublisher>,
     ) -> Self {
         Self {
             cache,
             airport_repo,
+            country_time_zones,
             event_publisher,
         }
     }
@@ -109,6 +160,168 @@ impl AirportService {
             }
         }
     }
+
+    async fn invalidate_imported_cache(&self, updated: &[Airport]) {
+        let mut keys: Vec<String> = updated
+            .iter()
+            .flat_map(|airport| {
+                [
+                    Self::airport_id_cache_key(airport.id),
+                    Self::airport_iata_cache_key(&airport.iata_code),
+                ]
+            })
+            .collect();
+        keys.push(Self::airports_cache_key(true));
+        keys.push(Self::airports_cache_key(false));
+
+        for batch in keys.chunks(CACHE_INVALIDATION_BATCH) {
+            if let Err(err) = self.cache.del_many(batch).await {
+                tracing::warn!("cache del failed for {} keys: {}", batch.len(), err);
+            }
+        }
+    }
+
+    fn parse_coordinate(value: &str, limit: u32, field: &str) -> Result<Decimal, String> {
+        let coordinate = value
+            .parse::<Decimal>()
+            .map_err(|_| format!("Invalid {field}: {value}"))?;
+        if coordinate.abs() > Decimal::from(limit) {
+            return Err(format!("{field} out of range: {value}"));
+        }
+        Ok(coordinate.round_dp(6))
+    }
+
+    /// The row's own zone wins, then the zone already on file, then the country default.
+    fn resolve_time_zone(
+        &self,
+        record: &OurAirportsRecord,
+        existing: Option<&Airport>,
+    ) -> Result<String, String> {
+        if !record.time_zone.is_empty() {
+            record
+                .time_zone
+                .parse::<Tz>()
+                .map_err(|_| format!("Unknown time zone {}", record.time_zone))?;
+            return Ok(record.time_zone.clone());
+        }
+
+        existing
+            .map(|airport| airport.time_zone.clone())
+            .or_else(|| {
+                self.country_time_zones
+                    .time_zone_for(&record.iso_country)
+                    .map(str::to_string)
+            })
+            .ok_or_else(|| format!("No time zone configured for country {}", record.iso_country))
+    }
+
+    fn import_candidate(
+        &self,
+        record: &OurAirportsRecord,
+        countries: &HashMap<String, String>,
+        existing: Option<&Airport>,
+    ) -> Result<ImportCandidate, String> {
+        if !IMPORTED_AIRPORT_TYPES.contains(&record.airport_type.as_str()) {
+            return Err(format!(
+                "Airport type {} is not imported",
+                record.airport_type
+            ));
+        }
+        IAtaCodeMustBeValid {
+            iata_code: &record.iata_code,
+        }
+        .check_broken()
+        .map_err(|e| e.to_string())?;
+        Self::validate_uppercase_code(&record.iata_code, 3, "iata_code")
+            .map_err(|e| e.to_string())?;
+
+        if !countries.contains_key(&record.iso_country) {
+            return Err(format!("Unknown country {}", record.iso_country));
+        }
+        if record.name.is_empty() {
+            return Err("Missing name".to_string());
+        }
+        if record.municipality.is_empty() {
+            return Err("Missing municipality".to_string());
+        }
+
+        let latitude = Self::parse_coordinate(&record.latitude_deg, 90, "latitude")?;
+        let longitude = Self::parse_coordinate(&record.longitude_deg, 180, "longitude")?;
+        let time_zone = self.resolve_time_zone(record, existing)?;
+        let icao_code = Some(record.icao_code.clone())
+            .filter(|code| code.len() == 4 && code.chars().all(|c| c.is_ascii_uppercase()));
+
+        Ok(ImportCandidate {
+            line: record.line,
+            ident: record.ident.clone(),
+            scheduled_service: record.scheduled_service,
+            props: CreateAirportProps {
+                iata_code: record.iata_code.clone(),
+                icao_code,
+                name: record.name.clone(),
+                city: record.municipality.clone(),
+                country_code: record.iso_country.clone(),
+                time_zone,
+                latitude: Some(latitude),
+                longitude: Some(longitude),
+            },
+        })
+    }
+
+    /// Keeps one row per IATA code. Several rows claiming a code are resolved in favour
+    /// of the only one with scheduled service; otherwise all of them are conflicts.
+    fn dedupe_candidates(
+        candidates: Vec<ImportCandidate>,
+        report: &mut AirportImportReportView,
+    ) -> Vec<ImportCandidate> {
+        let mut by_iata: HashMap<String, Vec<ImportCandidate>> = HashMap::new();
+        for candidate in candidates {
+            by_iata
+                .entry(candidate.props.iata_code.clone())
+                .or_default()
+                .push(candidate);
+        }
+
+        let mut kept = Vec::with_capacity(by_iata.len());
+        for (iata_code, mut rows) in by_iata {
+            if rows.len() == 1 {
+                kept.append(&mut rows);
+                continue;
+            }
+
+            let scheduled: Vec<usize> = rows
+                .iter()
+                .enumerate()
+                .filter(|(_, row)| row.scheduled_service)
+                .map(|(i, _)| i)
+                .collect();
+            if let [winner] = scheduled[..] {
+                let winner = rows.swap_remove(winner);
+                for row in rows {
+                    report.skipped.push(row.issue(format!(
+                        "IATA code {iata_code} is also on line {}, which has scheduled service",
+                        winner.line
+                    )));
+                }
+                kept.push(winner);
+                continue;
+            }
+
+            let lines = rows
+                .iter()
+                .map(|row| row.line.to_string())
+                .collect::<Vec<_>>()
+                .join(", ");
+            for row in rows {
+                report
+                    .conflicts
+                    .push(row.issue(format!("IATA code {iata_code} appears on lines {lines}")));
+            }
+        }
+
+        kept.sort_by_key(|candidate| candidate.line);
+        kept
+    }
 }
 
 #[async_trait::async_trait]
@@ -374,4 +587,197 @@ impl AirportServiceInterface for AirportService {
 
         Ok(true)
     }
+
+    async fn import_airports(
+        &self,
+        ctx: RequestContext,
+        command: ImportAirportsCommand,
+    ) -> UseCaseResult<AirportImportReportView> {
+        // The CLI import runs as the system actor.
+        if !ctx.is_admin() && !ctx.is_system() {
+            return Err(UseCaseError::PermissionDenied);
+        }
+
+        let records = parse_airports(&command.airports_csv)?;
+        let countries = parse_countries(&command.countries_csv)?;
+
+        let existing: HashMap<String, Airport> = self
+            .airport_repo
+            .list_airports(false)
+            .await
+            .map_err(|e| UseCaseError::Unexpected(e.to_string()))?
+            .into_iter()
+            .map(|airport| (airport.iata_code.clone(), airport))
+            .collect();
+
+        let mut report = AirportImportReportView {
+            dry_run: command.dry_run,
+            ..Default::default()
+        };
+
+        let mut candidates = Vec::new();
+        for record in &records {
+            if record.iata_code.is_empty() {
+                report.without_iata_code += 1;
+                continue;
+            }
+            match self.import_candidate(record, &countries, existing.get(&record.iata_code)) {
+                Ok(candidate) => candidates.push(candidate),
+                Err(reason) => report.skipped.push(AirportImportIssueView {
+                    line: record.line,
+                    ident: record.ident.clone(),
+                    iata_code: record.iata_code.clone(),
+                    reason,
+                }),
+            }
+        }
+
+        // ICAO codes are unique as well; the first claim on file or in the file wins.
+        let mut icao_owners: HashMap<String, String> = existing
+            .values()
+            .filter_map(|airport| {
+                airport
+                    .icao_code
+                    .clone()
+                    .map(|icao_code| (icao_code, airport.iata_code.clone()))
+            })
+            .collect();
+
+        let mut inserts = Vec::new();
+        let mut updates = Vec::new();
+        for candidate in Self::dedupe_candidates(candidates, &mut report) {
+            let iata_code = candidate.props.iata_code.clone();
+            if let Some(icao_code) = candidate.props.icao_code.clone() {
+                let owner = icao_owners
+                    .entry(icao_code.clone())
+                    .or_insert_with(|| iata_code.clone());
+                if *owner != iata_code {
+                    report.conflicts.push(
+                        candidate
+                            .issue(format!("ICAO code {icao_code} already belongs to {owner}")),
+                    );
+                    continue;
+                }
+            }
+
+            match existing.get(&iata_code) {
+                Some(current) => {
+                    let mut airport = current.clone();
+                    airport.update(UpdateAirportProps {
+                        iata_code: None,
+                        icao_code: candidate.props.icao_code,
+                        name: Some(candidate.props.name),
+                        city: Some(candidate.props.city),
+                        country_code: Some(candidate.props.country_code),
+                        time_zone: Some(candidate.props.time_zone),
+                        latitude: candidate.props.latitude,
+                        longitude: candidate.props.longitude,
+                    })?;
+                    if airport == *current {
+                        report.unchanged += 1;
+                    } else {
+                        report.updates.push(iata_code);
+                        updates.push(airport);
+                    }
+                }
+                None => {
+                    let mut airport = Airport::new(candidate.props)?;
+                    airport.is_active = candidate.scheduled_service;
+                    report.inserts.push(iata_code);
+                    inserts.push(airport);
+                }
+            }
+        }
+
+        if command.dry_run || (inserts.is_empty() && updates.is_empty()) {
+            return Ok(report);
+        }
+
+        self.airport_repo
+            .import_airports(&inserts, &updates)
+            .await
+            .map_err(|e| UseCaseError::Unexpected(e.to_string()))?;
+        self.invalidate_imported_cache(&updates).await;
+
+        Ok(report)
+    }
+
+    async fn search_airports(
+        &self,
+        ctx: RequestContext,
+        command: SearchAirportsCommand,
+    ) -> UseCaseResult<Vec<AirportView>> {
+        let _ = ctx.user_id().ok_or(UseCaseError::PermissionDenied)?;
+
+        let command = SearchAirportsCommand {
+            query: command.query.trim().to_string(),
+            ..command
+        };
+        command
+            .validate()
+            .map_err(|e| Self::validation_error("command", e.to_string()))?;
+        let limit = command.limit.unwrap_or(DEFAULT_SEARCH_LIMIT);
+
+        let candidates = self
+            .airport_repo
+            .search_airport_candidates(&command.query, limit * SEARCH_CANDIDATE_FACTOR)
+            .await
+            .map_err(|e| UseCaseError::Unexpected(e.to_string()))?;
+
+        let mut ranked: Vec<(u32, Airport)> = candidates
+            .into_iter()
+            .filter_map(|airport| match_score(&airport, &command.query).map(|s| (s, airport)))
+            .collect();
+        ranked.sort_by(|(score_a, a), (score_b, b)| {
+            score_b
+                .cmp(score_a)
+                .then_with(|| a.iata_code.cmp(&b.iata_code))
+        });
+
+        Ok(ranked
+            .into_iter()
+            .take(limit as usize)
+            .map(|(_, airport)| airport.into())
+            .collect())
+    }
+
+    async fn find_nearby_airports(
+        &self,
+        ctx: RequestContext,
+        command: NearbyAirportsCommand,
+    ) -> UseCaseResult<Vec<NearbyAirportView>> {
+        let _ = ctx.user_id().ok_or(UseCaseError::PermissionDenied)?;
+
+        command
+            .validate()
+            .map_err(|e| Self::validation_error("command", e.to_string()))?;
+        let center = GeoPoint::new(command.latitude, command.longitude)?;
+        let radius_km = command.radius_km.unwrap_or(DEFAULT_NEARBY_RADIUS_KM);
+        let limit = command.limit.unwrap_or(DEFAULT_NEARBY_LIMIT);
+
+        let airports = self
+            .airport_repo
+            .list_airports_in_bounding_box(&BoundingBox::around(center, radius_km))
+            .await
+            .map_err(|e| UseCaseError::Unexpected(e.to_string()))?;
+
+        // The box is a prefilter; its corners lie outside the radius.
+        let mut nearby: Vec<(f64, Airport)> = airports
+            .into_iter()
+            .filter_map(|airport| {
+                let distance_km = center.distance_km(&airport.location()?);
+                (distance_km <= radius_km).then_some((distance_km, airport))
+            })
+            .collect();
+        nearby.sort_by(|(a, _), (b, _)| a.total_cmp(b));
+
+        Ok(nearby
+            .into_iter()
+            .take(limit as usize)
+            .map(|(distance_km, airport)| NearbyAirportView {
+                airport: airport.into(),
+                distance_km: (distance_km * 10.0).round() / 10.0,
+            })
+            .collect())
+    }
 }
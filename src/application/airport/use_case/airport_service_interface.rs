@@ -1,5 +1,10 @@
-use crate::application::airport::airport_command::{CreateAirportCommand, UpdateAirportCommand};
+use crate::application::airport::airport_command::{
+    CreateAirportCommand, ImportAirportsCommand, NearbyAirportsCommand, SearchAirportsCommand,
+    UpdateAirportCommand,
+};
+use crate::application::airport::view::airport_import_view::AirportImportReportView;
 use crate::application::airport::view::airport_view::AirportView;
+use crate::application::airport::view::nearby_airport_view::NearbyAirportView;
 use crate::application::common::use_case_error::UseCaseResult;
 use crate::core::context::request_context::RequestContext;
 
@@ -33,4 +38,25 @@ pub trait AirportServiceInterface: Send + Sync {
     ) -> UseCaseResult<Vec<AirportView>>;
 
     async fn deactivate_airport(&self, ctx: RequestContext, id: i64) -> UseCaseResult<bool>;
+
+    /// Autocomplete over active airports by IATA/ICAO code, name and city, best match first.
+    async fn search_airports(
+        &self,
+        ctx: RequestContext,
+        command: SearchAirportsCommand,
+    ) -> UseCaseResult<Vec<AirportView>>;
+
+    /// Active airports within a radius of a point, nearest first.
+    async fn find_nearby_airports(
+        &self,
+        ctx: RequestContext,
+        command: NearbyAirportsCommand,
+    ) -> UseCaseResult<Vec<NearbyAirportView>>;
+
+    /// Upserts airports by IATA code from OurAirports CSV files.
+    async fn import_airports(
+        &self,
+        ctx: RequestContext,
+        command: ImportAirportsCommand,
+    ) -> UseCaseResult<AirportImportReportView>;
 }
@@ -0,0 +1,23 @@
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct AirportImportIssueView {
+    pub line: usize,
+    pub ident: String,
+    pub iata_code: String,
+    pub reason: String,
+}
+
+/// Outcome of an airport import; in a dry run, what the import would do.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct AirportImportReportView {
+    pub dry_run: bool,
+    // IATA codes of airports inserted or updated.
+    pub inserts: Vec<String>,
+    pub updates: Vec<String>,
+    pub unchanged: usize,
+    // Rows with no IATA code are not airports this system can fly to.
+    pub without_iata_code: usize,
+    pub skipped: Vec<AirportImportIssueView>,
+    pub conflicts: Vec<AirportImportIssueView>,
+}
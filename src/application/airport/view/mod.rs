@@ -1 +1,3 @@
-pub mod airport_view;
\ No newline at end of file
+pub mod airport_view;
+pub mod airport_import_view;
+pub mod nearby_airport_view;
\ No newline at end of file
@@ -0,0 +1,9 @@
+use crate::application::airport::view::airport_view::AirportView;
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct NearbyAirportView {
+    pub airport: AirportView,
+    // Great-circle distance from the search point, rounded to 0.1 km.
+    pub distance_km: f64,
+}
@@ -0,0 +1,15 @@
+use rust_decimal::Decimal;
+
+/// A bag dropped at the counter; the weight is converted to kilograms on issue.
+#[derive(Debug, Clone)]
+pub struct IssueBagTagCommand {
+    pub weight: Decimal,
+    pub weight_unit: String,
+}
+
+#[derive(Debug, Clone)]
+pub struct UpdateBagStatusCommand {
+    pub status: String,
+    pub location: Option<String>,
+    pub note: Option<String>,
+}
@@ -0,0 +1,46 @@
+use crate::application::baggage::view::bag_view::{BagStatusView, BagView};
+use crate::domain::baggage::entity::{Bag, BagStatus, BagStatusEntry};
+
+impl From<BagStatusEntry> for BagStatusView {
+    fn from(value: BagStatusEntry) -> Self {
+        Self {
+            status: bag_status_to_string(&value.status),
+            location: value.location,
+            note: value.note,
+            recorded_at: value.recorded_at.format("%Y-%m-%dT%H:%M:%S").to_string(),
+        }
+    }
+}
+
+pub fn bag_to_view(
+    bag: Bag,
+    destination_iata_code: String,
+    history: Vec<BagStatusEntry>,
+) -> BagView {
+    BagView {
+        id: bag.id,
+        tag_number: bag.tag_number,
+        checkin_id: bag.checkin_id,
+        booking_id: bag.booking_id,
+        passenger_id: bag.passenger_id,
+        flight_id: bag.flight_id,
+        destination_airport_id: bag.destination_airport_id,
+        destination_iata_code,
+        weight_kg: bag.weight_kg.to_string(),
+        status: bag_status_to_string(&bag.status),
+        last_location: bag.last_location,
+        history: history.into_iter().map(BagStatusView::from).collect(),
+        version: bag.version,
+    }
+}
+
+pub fn bag_status_to_string(value: &BagStatus) -> String {
+    match value {
+        BagStatus::Checked => "CHECKED",
+        BagStatus::Loaded => "LOADED",
+        BagStatus::Transferred => "TRANSFERRED",
+        BagStatus::Arrived => "ARRIVED",
+        BagStatus::Delayed => "DELAYED",
+    }
+    .to_string()
+}
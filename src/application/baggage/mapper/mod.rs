@@ -0,0 +1 @@
+pub mod bag_mapper;
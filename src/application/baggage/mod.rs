@@ -0,0 +1,4 @@
+pub mod baggage_command;
+pub mod mapper;
+pub mod use_case;
+pub mod view;
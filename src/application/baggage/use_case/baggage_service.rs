@@ -0,0 +1,340 @@
+use crate::application::baggage::baggage_command::{IssueBagTagCommand, UpdateBagStatusCommand};
+use crate::application::baggage::mapper::bag_mapper::{bag_status_to_string, bag_to_view};
+use crate::application::baggage::use_case::baggage_service_interface::BaggageServiceInterface;
+use crate::application::baggage::view::bag_view::BagView;
+use crate::application::common::event_publisher::BaggageEventPublisher;
+use crate::application::common::use_case_error::{UseCaseError, UseCaseResult};
+use crate::core::context::request_context::RequestContext;
+use crate::domain::airport::airport_repository_interface::AirportRepositoryInterface;
+use crate::domain::baggage::bag_repository_interface::BagRepositoryInterface;
+use crate::domain::baggage::bag_tag_issuer::BagTagIssuer;
+use crate::domain::baggage::entity::{Bag, BagStatus, CreateBagProps};
+use crate::domain::baggage::error::BaggageDomainError;
+use crate::domain::baggage::events::bag_status_changed::BagStatusChangedEvent;
+use crate::domain::booking::booking_repository_interface::BookingRepositoryInterface;
+use crate::domain::checkin::baggage_allowance_policy::WeightUnit;
+use crate::domain::checkin::checkin_repository_interface::CheckinRepositoryInterface;
+use crate::domain::checkin::entity::{Checkin, CheckinStatus};
+use crate::domain::error::DomainError;
+use crate::domain::flight::entity::{Flight, FlightStatus};
+use crate::domain::flight::flight_repository_interface::FlightRepositoryInterface;
+use std::collections::HashMap;
+use std::sync::Arc;
+
+pub struct BaggageService {
+    pub bag_repo: Arc<dyn BagRepositoryInterface>,
+    pub checkin_repo: Arc<dyn CheckinRepositoryInterface>,
+    pub booking_repo: Arc<dyn BookingRepositoryInterface>,
+    pub flight_repo: Arc<dyn FlightRepositoryInterface>,
+    pub airport_repo: Arc<dyn AirportRepositoryInterface>,
+    pub tag_issuer: Arc<BagTagIssuer>,
+    pub event_publisher: Arc<dyn BaggageEventPublisher>,
+}
+
+impl BaggageService {
+    pub fn new(
+        bag_repo: Arc<dyn BagRepositoryInterface>,
+        checkin_repo: Arc<dyn CheckinRepositoryInterface>,
+        booking_repo: Arc<dyn BookingRepositoryInterface>,
+        flight_repo: Arc<dyn FlightRepositoryInterface>,
+        airport_repo: Arc<dyn AirportRepositoryInterface>,
+        tag_issuer: Arc<BagTagIssuer>,
+        event_publisher: Arc<dyn BaggageEventPublisher>,
+    ) -> Self {
+        Self {
+            bag_repo,
+            checkin_repo,
+            booking_repo,
+            flight_repo,
+            airport_repo,
+            tag_issuer,
+            event_publisher,
+        }
+    }
+
+    // Bags are handled by airport staff only; admins manage data, not bag belts.
+    fn ensure_baggage_agent(ctx: &RequestContext) -> UseCaseResult<i64> {
+        ctx.require_staff()
+            .map_err(|_| UseCaseError::PermissionDenied)
+    }
+
+    fn parse_status(value: &str) -> UseCaseResult<BagStatus> {
+        match value.trim().to_uppercase().as_str() {
+            "CHECKED" => Ok(BagStatus::Checked),
+            "LOADED" => Ok(BagStatus::Loaded),
+            "TRANSFERRED" => Ok(BagStatus::Transferred),
+            "ARRIVED" => Ok(BagStatus::Arrived),
+            "DELAYED" => Ok(BagStatus::Delayed),
+            _ => Err(UseCaseError::Domain(DomainError::Baggage(
+                BaggageDomainError::Validation {
+                    field: "status",
+                    message: format!("Invalid status: {value}"),
+                },
+            ))),
+        }
+    }
+
+    fn normalize_location(location: Option<String>) -> Option<String> {
+        location
+            .map(|v| v.trim().to_uppercase())
+            .filter(|v| !v.is_empty())
+    }
+
+    async fn load_checkin(&self, checkin_id: i64) -> UseCaseResult<Checkin> {
+        self.checkin_repo
+            .find_checkin_by_id(checkin_id)
+            .await
+            .map_err(|e| UseCaseError::Unexpected(e.to_string()))?
+            .ok_or_else(|| {
+                UseCaseError::NotFound(format!("Checkin with id {} not found", checkin_id))
+            })
+    }
+
+    async fn load_flight(&self, flight_id: i64) -> UseCaseResult<Flight> {
+        self.flight_repo
+            .find_flight_by_id(flight_id)
+            .await
+            .map_err(|e| UseCaseError::Unexpected(e.to_string()))?
+            .ok_or_else(|| {
+                UseCaseError::NotFound(format!("Flight with id {} not found", flight_id))
+            })
+    }
+
+    async fn airport_iata_code(&self, airport_id: i64) -> UseCaseResult<String> {
+        self.airport_repo
+            .find_airport_by_id(airport_id)
+            .await
+            .map_err(|e| UseCaseError::Unexpected(e.to_string()))?
+            .map(|airport| airport.iata_code)
+            .ok_or_else(|| {
+                UseCaseError::NotFound(format!("Airport with id {} not found", airport_id))
+            })
+    }
+
+    async fn bag_view(&self, bag: Bag) -> UseCaseResult<BagView> {
+        let history = self
+            .bag_repo
+            .list_status_history(&[bag.id])
+            .await
+            .map_err(|e| UseCaseError::Unexpected(e.to_string()))?;
+        let destination = self.airport_iata_code(bag.destination_airport_id).await?;
+        Ok(bag_to_view(bag, destination, history))
+    }
+
+    async fn publish_status_changed(
+        &self,
+        bag: &Bag,
+        previous_status: Option<BagStatus>,
+        occurred_at: chrono::NaiveDateTime,
+    ) {
+        let _ = self
+            .event_publisher
+            .publish_bag_status_changed(BagStatusChangedEvent::new(
+                bag,
+                previous_status.as_ref().map(bag_status_to_string),
+                bag_status_to_string(&bag.status),
+                occurred_at,
+            ))
+            .await;
+    }
+}
+
+#[async_trait::async_trait]
+impl BaggageServiceInterface for BaggageService {
+    async fn issue_bag_tag(
+        &self,
+        ctx: RequestContext,
+        checkin_id: i64,
+        command: IssueBagTagCommand,
+    ) -> UseCaseResult<BagView> {
+        Self::ensure_baggage_agent(&ctx)?;
+
+        let checkin = self.load_checkin(checkin_id).await?;
+        // Pending check-ins may still owe excess baggage fees.
+        if checkin.status != CheckinStatus::CheckedIn {
+            return Err(UseCaseError::BusinessRule(format!(
+                "Checkin {} is not checked in",
+                checkin.id
+            )));
+        }
+
+        let booking = self
+            .booking_repo
+            .find_booking_by_id(checkin.booking_id)
+            .await
+            .map_err(|e| UseCaseError::Unexpected(e.to_string()))?
+            .ok_or_else(|| {
+                UseCaseError::NotFound(format!("Booking with id {} not found", checkin.booking_id))
+            })?;
+        let flight = self.load_flight(booking.flight_id).await?;
+        if !matches!(
+            flight.status,
+            FlightStatus::Scheduled | FlightStatus::Delayed
+        ) {
+            return Err(UseCaseError::BusinessRule(format!(
+                "Flight {} no longer accepts baggage",
+                flight.flight_number
+            )));
+        }
+
+        // The declared bag count is what the allowance and excess fee were assessed on.
+        let tagged = self
+            .bag_repo
+            .list_bags_by_checkin(checkin.id)
+            .await
+            .map_err(|e| UseCaseError::Unexpected(e.to_string()))?;
+        if tagged.len() as i32 >= checkin.baggage_count {
+            return Err(UseCaseError::BusinessRule(format!(
+                "All {} declared bags of checkin {} are already tagged",
+                checkin.baggage_count, checkin.id
+            )));
+        }
+
+        let unit = WeightUnit::parse(&command.weight_unit)?;
+        let origin = self.airport_iata_code(flight.origin_airport_id).await?;
+
+        let serial = self
+            .bag_repo
+            .next_tag_serial()
+            .await
+            .map_err(|e| UseCaseError::Unexpected(e.to_string()))?;
+        let tag_number = self.tag_issuer.tag_number(&flight.airline_code, serial)?;
+
+        let now = chrono::Utc::now().naive_utc();
+        let (mut bag, mut entry) = Bag::new(
+            CreateBagProps {
+                tag_number,
+                checkin_id: checkin.id,
+                booking_id: booking.id,
+                passenger_id: checkin.passenger_id,
+                flight_id: flight.id,
+                destination_airport_id: flight.destination_airport_id,
+                weight_kg: unit.to_kg(command.weight).round_dp(2),
+                location: origin,
+            },
+            now,
+        )?;
+
+        bag.id = self
+            .bag_repo
+            .create_bag(&bag, &entry)
+            .await
+            .map_err(|e| UseCaseError::Unexpected(e.to_string()))?;
+        entry.bag_id = bag.id;
+
+        self.publish_status_changed(&bag, None, now).await;
+
+        let destination = self.airport_iata_code(bag.destination_airport_id).await?;
+        Ok(bag_to_view(bag, destination, vec![entry]))
+    }
+
+    async fn update_bag_status(
+        &self,
+        ctx: RequestContext,
+        tag_number: String,
+        command: UpdateBagStatusCommand,
+    ) -> UseCaseResult<BagView> {
+        Self::ensure_baggage_agent(&ctx)?;
+
+        let tag_number = tag_number.trim().to_string();
+        let mut bag = self
+            .bag_repo
+            .find_bag_by_tag(&tag_number)
+            .await
+            .map_err(|e| UseCaseError::Unexpected(e.to_string()))?
+            .ok_or_else(|| {
+                UseCaseError::Domain(DomainError::Baggage(BaggageDomainError::BagNotFound {
+                    tag_number: tag_number.clone(),
+                }))
+            })?;
+
+        let status = Self::parse_status(&command.status)?;
+        let note = command
+            .note
+            .map(|v| v.trim().to_string())
+            .filter(|v| !v.is_empty());
+
+        let now = chrono::Utc::now().naive_utc();
+        let previous_status = bag.status;
+        let expected_version = bag.version;
+        let entry = bag.update_status(
+            status,
+            Self::normalize_location(command.location),
+            note,
+            now,
+        )?;
+
+        self.bag_repo
+            .update_bag_status(&bag, expected_version, &entry)
+            .await
+            .map_err(|e| match e {
+                DomainError::Baggage(BaggageDomainError::OptimisticLockConflict) => {
+                    UseCaseError::Domain(e)
+                }
+                _ => UseCaseError::Unexpected(e.to_string()),
+            })?;
+        bag.version = expected_version + 1;
+
+        self.publish_status_changed(&bag, Some(previous_status), now)
+            .await;
+
+        self.bag_view(bag).await
+    }
+
+    async fn track_bags_by_booking_code(
+        &self,
+        ctx: RequestContext,
+        booking_code: String,
+    ) -> UseCaseResult<Vec<BagView>> {
+        let actor_user_id = ctx.user_id().ok_or(UseCaseError::PermissionDenied)?;
+
+        let code = booking_code.trim().to_uppercase();
+        let booking = self
+            .booking_repo
+            .find_booking_by_code(&code)
+            .await
+            .map_err(|e| UseCaseError::Unexpected(e.to_string()))?
+            .ok_or_else(|| {
+                UseCaseError::NotFound(format!("Booking with code {} not found", code))
+            })?;
+
+        if !ctx.is_admin() && !ctx.is_staff() && actor_user_id != booking.user_id {
+            return Err(UseCaseError::PermissionDenied);
+        }
+
+        let bags = self
+            .bag_repo
+            .list_bags_by_booking(booking.id)
+            .await
+            .map_err(|e| UseCaseError::Unexpected(e.to_string()))?;
+        let bag_ids: Vec<i64> = bags.iter().map(|b| b.id).collect();
+
+        let mut history_by_bag: HashMap<i64, Vec<_>> = HashMap::new();
+        for entry in self
+            .bag_repo
+            .list_status_history(&bag_ids)
+            .await
+            .map_err(|e| UseCaseError::Unexpected(e.to_string()))?
+        {
+            history_by_bag.entry(entry.bag_id).or_default().push(entry);
+        }
+
+        // Bags on one booking nearly always share a destination.
+        let mut iata_codes: HashMap<i64, String> = HashMap::new();
+        let mut views = Vec::with_capacity(bags.len());
+        for bag in bags {
+            let destination = match iata_codes.get(&bag.destination_airport_id) {
+                Some(code) => code.clone(),
+                None => {
+                    let code = self.airport_iata_code(bag.destination_airport_id).await?;
+                    iata_codes.insert(bag.destination_airport_id, code.clone());
+                    code
+                }
+            };
+            let history = history_by_bag.remove(&bag.id).unwrap_or_default();
+            views.push(bag_to_view(bag, destination, history));
+        }
+
+        Ok(views)
+    }
+}
@@ -0,0 +1,27 @@
+use crate::application::baggage::baggage_command::{IssueBagTagCommand, UpdateBagStatusCommand};
+use crate::application::baggage::view::bag_view::BagView;
+use crate::application::common::use_case_error::UseCaseResult;
+use crate::core::context::request_context::RequestContext;
+
+#[async_trait::async_trait]
+pub trait BaggageServiceInterface: Send + Sync {
+    async fn issue_bag_tag(
+        &self,
+        ctx: RequestContext,
+        checkin_id: i64,
+        command: IssueBagTagCommand,
+    ) -> UseCaseResult<BagView>;
+
+    async fn update_bag_status(
+        &self,
+        ctx: RequestContext,
+        tag_number: String,
+        command: UpdateBagStatusCommand,
+    ) -> UseCaseResult<BagView>;
+
+    async fn track_bags_by_booking_code(
+        &self,
+        ctx: RequestContext,
+        booking_code: String,
+    ) -> UseCaseResult<Vec<BagView>>;
+}
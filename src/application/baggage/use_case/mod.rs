@@ -0,0 +1,2 @@
+pub mod baggage_service;
+pub mod baggage_service_interface;
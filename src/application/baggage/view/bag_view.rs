@@ -0,0 +1,26 @@
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct BagView {
+    pub id: i64,
+    pub tag_number: String,
+    pub checkin_id: i64,
+    pub booking_id: i64,
+    pub passenger_id: i64,
+    pub flight_id: i64,
+    pub destination_airport_id: i64,
+    pub destination_iata_code: String,
+    pub weight_kg: String,
+    pub status: String,
+    pub last_location: Option<String>,
+    pub history: Vec<BagStatusView>,
+    pub version: i32,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct BagStatusView {
+    pub status: String,
+    pub location: Option<String>,
+    pub note: Option<String>,
+    pub recorded_at: String,
+}
@@ -0,0 +1,20 @@
+#[derive(Debug, Clone)]
+pub struct ScanBoardingPassCommand {
+    pub flight_id: i64,
+    pub barcode_payload: String,
+}
+
+/// Targets one passenger's check-in on the flight being boarded.
+#[derive(Debug, Clone)]
+pub struct BoardingActionCommand {
+    pub flight_id: i64,
+    pub checkin_id: i64,
+}
+
+/// Clears a standby passenger on the flight into a seat.
+#[derive(Debug, Clone)]
+pub struct ClearStandbyCommand {
+    pub flight_id: i64,
+    pub checkin_id: i64,
+    pub seat_no: String,
+}
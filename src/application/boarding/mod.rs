@@ -0,0 +1,3 @@
+pub mod boarding_command;
+pub mod use_case;
+pub mod view;
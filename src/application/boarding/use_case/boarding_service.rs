@@ -0,0 +1,447 @@
+use crate::application::boarding::boarding_command::{
+    BoardingActionCommand, ClearStandbyCommand, ScanBoardingPassCommand,
+};
+use crate::application::boarding::use_case::boarding_service_interface::BoardingServiceInterface;
+use crate::application::boarding::view::boarding_view::{
+    BoardingReconciliationView, BoardingStatusView,
+};
+use crate::application::boarding_pass::boarding_pass_signer::BoardingPassSigner;
+use crate::application::boarding_pass::use_case::boarding_pass_service::{
+    BarcodeRejection, BoardingPassService,
+};
+use crate::application::checkin::mapper::checkin_mapper::checkin_status_to_string;
+use crate::application::common::cache_interface::CacheInterface;
+use crate::application::common::use_case_error::{UseCaseError, UseCaseResult};
+use crate::core::context::request_context::RequestContext;
+use crate::domain::boarding_pass::boarding_pass_repository_interface::BoardingPassRepositoryInterface;
+use crate::domain::boarding_pass::entity::BoardingPass;
+use crate::domain::boarding_pass::error::BoardingPassDomainError;
+use crate::domain::booking::booking_repository_interface::BookingRepositoryInterface;
+use crate::domain::booking::entity::{Booking, BookingStatus};
+use crate::domain::checkin::checkin_repository_interface::CheckinRepositoryInterface;
+use crate::domain::checkin::entity::{Checkin, CheckinStatus};
+use crate::domain::checkin::error::CheckinDomainError;
+use crate::domain::error::DomainError;
+use crate::domain::flight::entity::{Flight, FlightStatus};
+use crate::domain::flight::error::FlightDomainError;
+use crate::domain::flight::flight_repository_interface::FlightRepositoryInterface;
+use crate::domain::passenger::entity::Passenger;
+use crate::domain::passenger::passenger_repository_interface::PassengerRepositoryInterface;
+use std::sync::Arc;
+
+pub struct BoardingService {
+    pub cache: Arc<dyn CacheInterface>,
+    pub flight_repo: Arc<dyn FlightRepositoryInterface>,
+    pub booking_repo: Arc<dyn BookingRepositoryInterface>,
+    pub passenger_repo: Arc<dyn PassengerRepositoryInterface>,
+    pub checkin_repo: Arc<dyn CheckinRepositoryInterface>,
+    pub boarding_pass_repo: Arc<dyn BoardingPassRepositoryInterface>,
+    pub signer: Arc<dyn BoardingPassSigner>,
+}
+
+impl BoardingService {
+    pub fn new(
+        cache: Arc<dyn CacheInterface>,
+        flight_repo: Arc<dyn FlightRepositoryInterface>,
+        booking_repo: Arc<dyn BookingRepositoryInterface>,
+        passenger_repo: Arc<dyn PassengerRepositoryInterface>,
+        checkin_repo: Arc<dyn CheckinRepositoryInterface>,
+        boarding_pass_repo: Arc<dyn BoardingPassRepositoryInterface>,
+        signer: Arc<dyn BoardingPassSigner>,
+    ) -> Self {
+        Self {
+            cache,
+            flight_repo,
+            booking_repo,
+            passenger_repo,
+            checkin_repo,
+            boarding_pass_repo,
+            signer,
+        }
+    }
+
+    fn checkin_cache_key(booking_id: i64, passenger_id: i64) -> String {
+        format!("checkin:booking:{booking_id}:passenger:{passenger_id}")
+    }
+
+    // Gate control is an airport operation; admins manage data but do not board passengers.
+    fn ensure_gate_agent(ctx: &RequestContext) -> UseCaseResult<i64> {
+        ctx.require_staff()
+            .map_err(|_| UseCaseError::PermissionDenied)
+    }
+
+    async fn load_flight(&self, flight_id: i64) -> UseCaseResult<Flight> {
+        self.flight_repo
+            .find_flight_by_id(flight_id)
+            .await
+            .map_err(|e| UseCaseError::Unexpected(e.to_string()))?
+            .ok_or_else(|| {
+                UseCaseError::NotFound(format!("Flight with id {} not found", flight_id))
+            })
+    }
+
+    async fn load_checkin(&self, checkin_id: i64) -> UseCaseResult<Checkin> {
+        self.checkin_repo
+            .find_checkin_by_id(checkin_id)
+            .await
+            .map_err(|e| UseCaseError::Unexpected(e.to_string()))?
+            .ok_or_else(|| {
+                UseCaseError::NotFound(format!("Checkin with id {} not found", checkin_id))
+            })
+    }
+
+    async fn load_booking(&self, booking_id: i64) -> UseCaseResult<Booking> {
+        self.booking_repo
+            .find_booking_by_id(booking_id)
+            .await
+            .map_err(|e| UseCaseError::Unexpected(e.to_string()))?
+            .ok_or_else(|| {
+                UseCaseError::NotFound(format!("Booking with id {} not found", booking_id))
+            })
+    }
+
+    async fn load_passenger(&self, passenger_id: i64) -> UseCaseResult<Passenger> {
+        self.passenger_repo
+            .find_passenger_by_id(passenger_id)
+            .await
+            .map_err(|e| UseCaseError::Unexpected(e.to_string()))?
+            .ok_or_else(|| {
+                UseCaseError::NotFound(format!("Passenger with id {} not found", passenger_id))
+            })
+    }
+
+    fn flight_label(flight: &Flight) -> String {
+        if flight.flight_number.starts_with(&flight.airline_code) {
+            flight.flight_number.clone()
+        } else {
+            format!("{}{}", flight.airline_code, flight.flight_number)
+        }
+    }
+
+    fn ensure_flight_boarding(flight: &Flight) -> UseCaseResult<()> {
+        match flight.status {
+            FlightStatus::Cancelled => Err(UseCaseError::BusinessRule(format!(
+                "Flight {} has been cancelled",
+                Self::flight_label(flight)
+            ))),
+            FlightStatus::Departed | FlightStatus::Arrived => Err(UseCaseError::Domain(
+                DomainError::BoardingPass(BoardingPassDomainError::BoardingTimePassed),
+            )),
+            FlightStatus::Scheduled | FlightStatus::Delayed => Ok(()),
+        }
+    }
+
+    // Loads the check-in's booking and makes sure it travels on `flight`.
+    async fn load_booking_on_flight(
+        &self,
+        checkin: &Checkin,
+        flight: &Flight,
+    ) -> UseCaseResult<Booking> {
+        let booking = self.load_booking(checkin.booking_id).await?;
+        if booking.flight_id != flight.id {
+            return Err(UseCaseError::BusinessRule(format!(
+                "Checkin {} is not on flight {}",
+                checkin.id,
+                Self::flight_label(flight)
+            )));
+        }
+        Ok(booking)
+    }
+
+    async fn save_checkin(&self, checkin: &Checkin, expected_version: i32) -> UseCaseResult<()> {
+        // A second scan of the same pass racing this one loses on the version check.
+        self.checkin_repo
+            .update_checkin(checkin, expected_version)
+            .await
+            .map_err(|e| match e {
+                DomainError::Flight(FlightDomainError::OptimisticLockConflict)
+                | DomainError::Checkin(CheckinDomainError::OptimisticLockConflict) => {
+                    UseCaseError::Domain(e)
+                }
+                _ => UseCaseError::Unexpected(e.to_string()),
+            })?;
+
+        let cache_key = Self::checkin_cache_key(checkin.booking_id, checkin.passenger_id);
+        if let Err(err) = self.cache.del(&cache_key).await {
+            tracing::warn!("cache del failed key={}: {}", cache_key, err);
+        }
+
+        Ok(())
+    }
+
+    fn status_view(
+        checkin: &Checkin,
+        booking: &Booking,
+        passenger: &Passenger,
+        boarding_pass: Option<&BoardingPass>,
+    ) -> BoardingStatusView {
+        BoardingStatusView {
+            checkin_id: checkin.id,
+            booking_code: booking.booking_code.clone(),
+            passenger_id: passenger.id,
+            passenger_name: format!(
+                "{}/{}",
+                passenger.last_name.trim(),
+                passenger.first_name.trim()
+            ),
+            seat_no: checkin.seat_no.clone(),
+            boarding_pass_code: boarding_pass.map(|bp| bp.boarding_pass_code.clone()),
+            status: checkin_status_to_string(&checkin.status),
+            boarded_at: checkin
+                .boarded_at
+                .map(|v| v.format("%Y-%m-%dT%H:%M:%S").to_string()),
+        }
+    }
+
+    async fn apply_action(
+        &self,
+        command: BoardingActionCommand,
+        action: impl FnOnce(&mut Checkin) -> Result<(), DomainError> + Send,
+    ) -> UseCaseResult<BoardingStatusView> {
+        let flight = self.load_flight(command.flight_id).await?;
+        Self::ensure_flight_boarding(&flight)?;
+        let mut checkin = self.load_checkin(command.checkin_id).await?;
+        let booking = self.load_booking_on_flight(&checkin, &flight).await?;
+        let passenger = self.load_passenger(checkin.passenger_id).await?;
+
+        let expected_version = checkin.version;
+        action(&mut checkin)?;
+        self.save_checkin(&checkin, expected_version).await?;
+
+        let boarding_pass = self
+            .boarding_pass_repo
+            .find_boarding_pass_by_checkin(checkin.id)
+            .await
+            .map_err(|e| UseCaseError::Unexpected(e.to_string()))?;
+
+        Ok(Self::status_view(
+            &checkin,
+            &booking,
+            &passenger,
+            boarding_pass.as_ref(),
+        ))
+    }
+}
+
+#[async_trait::async_trait]
+impl BoardingServiceInterface for BoardingService {
+    async fn scan_boarding_pass(
+        &self,
+        ctx: RequestContext,
+        command: ScanBoardingPassCommand,
+    ) -> UseCaseResult<BoardingStatusView> {
+        Self::ensure_gate_agent(&ctx)?;
+
+        let flight = self.load_flight(command.flight_id).await?;
+        Self::ensure_flight_boarding(&flight)?;
+
+        let boarding_pass = BoardingPassService::authenticate_barcode(
+            self.signer.as_ref(),
+            self.boarding_pass_repo.as_ref(),
+            command.barcode_payload.trim(),
+        )
+        .await?
+        .map_err(BarcodeRejection::into_error)?
+        .boarding_pass;
+
+        let mut checkin = self.load_checkin(boarding_pass.checkin_id).await?;
+        let booking = self.load_booking(checkin.booking_id).await?;
+        if booking.flight_id != flight.id {
+            return Err(UseCaseError::BusinessRule(format!(
+                "Boarding pass {} is not valid for flight {}",
+                boarding_pass.boarding_pass_code,
+                Self::flight_label(&flight)
+            )));
+        }
+
+        let now = chrono::Utc::now().naive_utc();
+        // The pass may carry its own boarding time; otherwise the flight's applies.
+        let boarding_time = boarding_pass.boarding_time.or(flight.boarding_time);
+        if boarding_time.is_some_and(|boarding_time| now < boarding_time) {
+            return Err(UseCaseError::Domain(DomainError::BoardingPass(
+                BoardingPassDomainError::BoardingTimeNotReached,
+            )));
+        }
+        if flight.has_departed_by(now) {
+            return Err(UseCaseError::Domain(DomainError::BoardingPass(
+                BoardingPassDomainError::BoardingTimePassed,
+            )));
+        }
+
+        let expected_version = checkin.version;
+        checkin.board(now)?;
+        self.save_checkin(&checkin, expected_version).await?;
+
+        let passenger = self.load_passenger(checkin.passenger_id).await?;
+        Ok(Self::status_view(
+            &checkin,
+            &booking,
+            &passenger,
+            Some(&boarding_pass),
+        ))
+    }
+
+    async fn undo_boarding(
+        &self,
+        ctx: RequestContext,
+        command: BoardingActionCommand,
+    ) -> UseCaseResult<BoardingStatusView> {
+        Self::ensure_gate_agent(&ctx)?;
+        self.apply_action(command, Checkin::undo_boarding).await
+    }
+
+    async fn offload_passenger(
+        &self,
+        ctx: RequestContext,
+        command: BoardingActionCommand,
+    ) -> UseCaseResult<BoardingStatusView> {
+        Self::ensure_gate_agent(&ctx)?;
+        self.apply_action(command, Checkin::offload).await
+    }
+
+    async fn list_on_standby(
+        &self,
+        ctx: RequestContext,
+        command: BoardingActionCommand,
+    ) -> UseCaseResult<BoardingStatusView> {
+        Self::ensure_gate_agent(&ctx)?;
+        let now = chrono::Utc::now().naive_utc();
+        self.apply_action(command, |checkin| checkin.list_on_standby(now))
+            .await
+    }
+
+    async fn clear_standby(
+        &self,
+        ctx: RequestContext,
+        command: ClearStandbyCommand,
+    ) -> UseCaseResult<BoardingStatusView> {
+        Self::ensure_gate_agent(&ctx)?;
+        let seat_no = command.seat_no.trim().to_uppercase();
+        if seat_no.is_empty() {
+            return Err(UseCaseError::Domain(DomainError::Checkin(
+                CheckinDomainError::Validation {
+                    field: "seat_no",
+                    message: "A seat is required to clear a standby passenger".to_string(),
+                },
+            )));
+        }
+
+        self.apply_action(
+            BoardingActionCommand {
+                flight_id: command.flight_id,
+                checkin_id: command.checkin_id,
+            },
+            |checkin| checkin.clear_standby(seat_no),
+        )
+        .await
+    }
+
+    async fn reconcile_boarding(
+        &self,
+        ctx: RequestContext,
+        flight_id: i64,
+    ) -> UseCaseResult<BoardingReconciliationView> {
+        Self::ensure_gate_agent(&ctx)?;
+
+        let flight = self.load_flight(flight_id).await?;
+        let bookings = self
+            .booking_repo
+            .list_bookings_by_flight(flight.id)
+            .await
+            .map_err(|e| UseCaseError::Unexpected(e.to_string()))?;
+
+        let mut booked = 0;
+        let mut not_checked_in = 0;
+        let mut checked_in = 0;
+        let mut boarded = 0;
+        let mut offloaded = 0;
+        let mut boarded_seats = 0;
+        let mut no_shows = Vec::new();
+        let mut standby = Vec::new();
+
+        // No-show processing closes bookings nobody flew on; they still count here.
+        for booking in bookings
+            .iter()
+            .filter(|b| matches!(b.status, BookingStatus::Confirmed | BookingStatus::NoShow))
+        {
+            let passengers = self
+                .passenger_repo
+                .list_passengers_by_booking(booking.id)
+                .await
+                .map_err(|e| UseCaseError::Unexpected(e.to_string()))?;
+
+            for passenger in &passengers {
+                booked += 1;
+
+                let checkin = self
+                    .checkin_repo
+                    .find_checkin_by_booking_and_passenger(booking.id, passenger.id)
+                    .await
+                    .map_err(|e| UseCaseError::Unexpected(e.to_string()))?;
+                let Some(checkin) = checkin else {
+                    not_checked_in += 1;
+                    continue;
+                };
+
+                match checkin.status {
+                    CheckinStatus::Pending | CheckinStatus::Cancelled => not_checked_in += 1,
+                    CheckinStatus::Offloaded => offloaded += 1,
+                    CheckinStatus::Standby => {
+                        standby.push(Self::status_view(&checkin, booking, passenger, None))
+                    }
+                    CheckinStatus::Boarded => {
+                        checked_in += 1;
+                        boarded += 1;
+                        if passenger.passenger_type.occupies_seat() {
+                            boarded_seats += 1;
+                        }
+                    }
+                    CheckinStatus::CheckedIn => {
+                        checked_in += 1;
+                        let boarding_pass = self
+                            .boarding_pass_repo
+                            .find_boarding_pass_by_checkin(checkin.id)
+                            .await
+                            .map_err(|e| UseCaseError::Unexpected(e.to_string()))?;
+                        no_shows.push(Self::status_view(
+                            &checkin,
+                            booking,
+                            passenger,
+                            boarding_pass.as_ref(),
+                        ));
+                    }
+                }
+            }
+        }
+
+        no_shows.sort_by(|a, b| a.passenger_name.cmp(&b.passenger_name));
+        // Standby passengers are cleared in the order they were listed.
+        standby.sort_by_key(|v| v.checkin_id);
+
+        Ok(BoardingReconciliationView {
+            flight_id: flight.id,
+            airline_code: flight.airline_code.clone(),
+            flight_number: flight.flight_number.clone(),
+            departure_time: flight
+                .departure_time
+                .format("%Y-%m-%dT%H:%M:%S")
+                .to_string(),
+            total_seats: flight.total_seats,
+            booked,
+            not_checked_in,
+            checked_in,
+            boarded,
+            no_show: no_shows.len(),
+            offloaded,
+            standby: standby.len(),
+            // Seats not taken by a boarded passenger, the ceiling for clearing standby.
+            unoccupied_seats: (flight.total_seats - boarded_seats).max(0),
+            no_shows,
+            standby_passengers: standby,
+            generated_at: chrono::Utc::now()
+                .naive_utc()
+                .format("%Y-%m-%dT%H:%M:%S")
+                .to_string(),
+        })
+    }
+}
@@ -0,0 +1,47 @@
+use crate::application::boarding::boarding_command::{
+    BoardingActionCommand, ClearStandbyCommand, ScanBoardingPassCommand,
+};
+use crate::application::boarding::view::boarding_view::{
+    BoardingReconciliationView, BoardingStatusView,
+};
+use crate::application::common::use_case_error::UseCaseResult;
+use crate::core::context::request_context::RequestContext;
+
+#[async_trait::async_trait]
+pub trait BoardingServiceInterface: Send + Sync {
+    async fn scan_boarding_pass(
+        &self,
+        ctx: RequestContext,
+        command: ScanBoardingPassCommand,
+    ) -> UseCaseResult<BoardingStatusView>;
+
+    async fn undo_boarding(
+        &self,
+        ctx: RequestContext,
+        command: BoardingActionCommand,
+    ) -> UseCaseResult<BoardingStatusView>;
+
+    async fn offload_passenger(
+        &self,
+        ctx: RequestContext,
+        command: BoardingActionCommand,
+    ) -> UseCaseResult<BoardingStatusView>;
+
+    async fn list_on_standby(
+        &self,
+        ctx: RequestContext,
+        command: BoardingActionCommand,
+    ) -> UseCaseResult<BoardingStatusView>;
+
+    async fn clear_standby(
+        &self,
+        ctx: RequestContext,
+        command: ClearStandbyCommand,
+    ) -> UseCaseResult<BoardingStatusView>;
+
+    async fn reconcile_boarding(
+        &self,
+        ctx: RequestContext,
+        flight_id: i64,
+    ) -> UseCaseResult<BoardingReconciliationView>;
+}
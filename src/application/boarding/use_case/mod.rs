@@ -0,0 +1,2 @@
+pub mod boarding_service;
+pub mod boarding_service_interface;
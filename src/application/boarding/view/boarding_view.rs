@@ -0,0 +1,35 @@
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct BoardingStatusView {
+    pub checkin_id: i64,
+    pub booking_code: String,
+    pub passenger_id: i64,
+    pub passenger_name: String,
+    pub seat_no: Option<String>,
+    pub boarding_pass_code: Option<String>,
+    pub status: String,
+    pub boarded_at: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct BoardingReconciliationView {
+    pub flight_id: i64,
+    pub airline_code: String,
+    pub flight_number: String,
+    pub departure_time: String,
+    pub total_seats: i32,
+
+    pub booked: usize,
+    pub not_checked_in: usize,
+    pub checked_in: usize,
+    pub boarded: usize,
+    pub no_show: usize,
+    pub offloaded: usize,
+    pub standby: usize,
+    pub unoccupied_seats: i32,
+
+    pub no_shows: Vec<BoardingStatusView>,
+    pub standby_passengers: Vec<BoardingStatusView>,
+    pub generated_at: String,
+}
@@ -5,9 +5,42 @@ pub struct IssueBoardingPassCommand {
     pub checkin_id: i64,
     pub gate: Option<String>,
     pub terminal: Option<String>,
-    pub boarding_group: Option<String>,
-    pub sequence_no: Option<String>,
     pub boarding_time: Option<NaiveDateTime>,
     pub barcode_format: String,
-    pub barcode_payload: Option<String>,
+}
+
+#[derive(Debug, Clone)]
+pub struct VerifyBoardingPassCommand {
+    pub barcode_payload: String,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum BoardingPassDocumentFormat {
+    Pdf,
+    Png,
+    Pkpass,
+}
+
+impl BoardingPassDocumentFormat {
+    pub fn content_type(&self) -> &'static str {
+        match self {
+            Self::Pdf => "application/pdf",
+            Self::Png => "image/png",
+            Self::Pkpass => "application/vnd.apple.pkpass",
+        }
+    }
+
+    pub fn extension(&self) -> &'static str {
+        match self {
+            Self::Pdf => "pdf",
+            Self::Png => "png",
+            Self::Pkpass => "pkpass",
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct RenderBoardingPassCommand {
+    pub code: String,
+    pub format: BoardingPassDocumentFormat,
 }
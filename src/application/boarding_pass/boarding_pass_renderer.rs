@@ -0,0 +1,38 @@
+use crate::application::common::use_case_error::UseCaseResult;
+
+/// Printable content of a boarding pass. Times are already formatted in the local
+/// time of the airport they refer to.
+#[derive(Debug, Clone)]
+pub struct BoardingPassDocument {
+    pub boarding_pass_code: String,
+    pub booking_code: String,
+    pub passenger_name: String,
+    /// Designator the passenger booked, which may be a partner's codeshare.
+    pub flight: String,
+    /// Operating flight, set when it differs from `flight`.
+    pub operated_by: Option<String>,
+    pub cabin: String,
+    pub origin_iata_code: String,
+    pub origin_city: String,
+    pub destination_iata_code: String,
+    pub destination_city: String,
+    /// RFC 3339, used where a machine-readable departure is needed.
+    pub departure_at: String,
+    pub departure_date: String,
+    pub departure_time: String,
+    pub arrival_time: String,
+    pub boarding_time: Option<String>,
+    pub terminal: Option<String>,
+    pub gate: Option<String>,
+    pub boarding_group: Option<String>,
+    pub sequence_no: Option<String>,
+    pub seat: Option<String>,
+    pub barcode_format: String,
+    pub barcode_payload: String,
+}
+
+pub trait BoardingPassRenderer: Send + Sync {
+    fn render_pdf(&self, document: &BoardingPassDocument) -> UseCaseResult<Vec<u8>>;
+
+    fn render_png(&self, document: &BoardingPassDocument) -> UseCaseResult<Vec<u8>>;
+}
@@ -0,0 +1,10 @@
+use crate::application::common::use_case_error::UseCaseResult;
+use crate::domain::boarding_pass::bcbp::model::BcbpPass;
+
+pub trait BoardingPassSigner: Send + Sync {
+    /// Fills the BCBP security section with a signature over the rest of the pass.
+    fn sign(&self, pass: &mut BcbpPass) -> UseCaseResult<()>;
+
+    /// `Ok(false)` when the signature is missing, malformed or made with an unknown key.
+    fn verify(&self, pass: &BcbpPass) -> UseCaseResult<bool>;
+}
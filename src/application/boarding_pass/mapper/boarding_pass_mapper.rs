@@ -17,6 +17,10 @@ impl From<BoardingPass> for BoardingPassView {
             issued_at: value.issued_at.format("%Y-%m-%dT%H:%M:%S").to_string(),
             barcode_format: value.barcode_format,
             barcode_payload: value.barcode_payload,
+            revoked_at: value
+                .revoked_at
+                .map(|v| v.format("%Y-%m-%dT%H:%M:%S").to_string()),
+            wallet_pass_version: value.wallet_pass_version,
         }
     }
 }
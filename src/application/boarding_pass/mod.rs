@@ -1,4 +1,7 @@
 pub mod view;
 pub mod use_case;
 pub mod mapper;
-pub mod boarding_pass_command;
\ No newline at end of file
+pub mod boarding_pass_command;
+pub mod boarding_pass_signer;
+pub mod boarding_pass_renderer;
+pub mod wallet_pass_generator;
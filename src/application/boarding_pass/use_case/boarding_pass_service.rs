@@ -1,32 +1,141 @@
-use crate::application::boarding_pass::boarding_pass_command::IssueBoardingPassCommand;
+use crate::application::boarding_pass::boarding_pass_command::{
+    BoardingPassDocumentFormat, IssueBoardingPassCommand, RenderBoardingPassCommand,
+    VerifyBoardingPassCommand,
+};
+use crate::application::boarding_pass::boarding_pass_renderer::{
+    BoardingPassDocument, BoardingPassRenderer,
+};
+use crate::application::boarding_pass::boarding_pass_signer::BoardingPassSigner;
 use crate::application::boarding_pass::use_case::boarding_pass_service_interface::BoardingPassServiceInterface;
-use crate::application::boarding_pass::view::boarding_pass_view::BoardingPassView;
+use crate::application::boarding_pass::view::boarding_pass_view::{
+    BoardingPassFileView, BoardingPassVerificationView, BoardingPassView,
+};
+use crate::application::boarding_pass::wallet_pass_generator::WalletPassGenerator;
+use crate::application::checkin::mapper::checkin_mapper::seat_class_to_string;
 use crate::application::common::cache_helper::{cache_get_json, cache_set_json};
 use crate::application::common::cache_interface::CacheInterface;
 use crate::application::common::event_publisher::BoardingPassEventPublisher;
 use crate::application::common::use_case_error::{UseCaseError, UseCaseResult};
 use crate::core::context::request_context::{Actor, RequestContext};
+use crate::domain::airport::airport_repository_interface::AirportRepositoryInterface;
+use crate::domain::airport::entity::Airport;
+use crate::domain::boarding_pass::bcbp::factory::{
+    BcbpLegSource, build_bcbp, printable_name, transliterate,
+};
+use crate::domain::boarding_pass::bcbp::model::BcbpPass;
+use crate::domain::boarding_pass::bcbp::{decoder, encoder};
+use crate::domain::boarding_pass::boarding_group_policy::{
+    BoardingGroupInput, BoardingGroupPolicy,
+};
 use crate::domain::boarding_pass::boarding_pass_repository_interface::BoardingPassRepositoryInterface;
 use crate::domain::boarding_pass::entity::{
     BoardingPass, CreateBoardingPassProps, UpdateBoardingPassProps,
 };
-use crate::domain::boarding_pass::events::boarding_pass_issued::BoardingPassIssuedEvent;
 use crate::domain::boarding_pass::error::BoardingPassDomainError;
+use crate::domain::boarding_pass::events::boarding_pass_issued::BoardingPassIssuedEvent;
 use crate::domain::booking::booking_repository_interface::BookingRepositoryInterface;
+use crate::domain::booking::entity::Booking;
 use crate::domain::checkin::checkin_repository_interface::CheckinRepositoryInterface;
-use crate::domain::checkin::entity::CheckinStatus;
+use crate::domain::checkin::entity::{Checkin, CheckinStatus};
 use crate::domain::error::DomainError;
+use crate::domain::flight::entity::{Flight, FlightStatus};
+use crate::domain::flight::flight_repository_interface::FlightRepositoryInterface;
+use crate::domain::passenger::entity::Passenger;
 use crate::domain::passenger::passenger_repository_interface::PassengerRepositoryInterface;
-use serde_json::json;
+use chrono::{NaiveDateTime, TimeZone, Utc};
+use chrono_tz::Tz;
 use std::sync::Arc;
 use uuid::Uuid;
 
+// Records a boarding pass is built from, reached through its check-in.
+struct PassItinerary {
+    booking: Booking,
+    passenger: Passenger,
+    flight: Flight,
+    origin: Airport,
+    destination: Airport,
+}
+
+/// A barcode that decodes, carries a valid signature and is the current payload of a live
+/// pass.
+pub(crate) struct AuthenticatedPass {
+    pub bcbp: BcbpPass,
+    pub boarding_pass: BoardingPass,
+}
+
+/// Why a barcode failed [`BoardingPassService::authenticate_barcode`]; keeps whatever was
+/// decoded so callers can still show it.
+pub(crate) enum BarcodeRejection {
+    Malformed(DomainError),
+    InvalidSignature(BcbpPass),
+    MissingCode(BcbpPass),
+    UnknownPass(BcbpPass, String),
+    Revoked(BcbpPass),
+    Superseded(BcbpPass),
+}
+
+impl BarcodeRejection {
+    pub fn bcbp(&self) -> Option<&BcbpPass> {
+        match self {
+            Self::Malformed(_) => None,
+            Self::InvalidSignature(bcbp)
+            | Self::MissingCode(bcbp)
+            | Self::UnknownPass(bcbp, _)
+            | Self::Revoked(bcbp)
+            | Self::Superseded(bcbp) => Some(bcbp),
+        }
+    }
+
+    /// The error a gate scan fails with.
+    pub fn into_error(self) -> UseCaseError {
+        match self {
+            Self::Malformed(err) => UseCaseError::Domain(err),
+            Self::InvalidSignature(_) => {
+                UseCaseError::BusinessRule("Invalid or unknown boarding pass signature".to_string())
+            }
+            Self::MissingCode(_) => {
+                UseCaseError::BusinessRule("Boarding pass code is missing".to_string())
+            }
+            Self::UnknownPass(_, code) => {
+                UseCaseError::NotFound(format!("Boarding pass with code {} not found", code))
+            }
+            Self::Revoked(_) => UseCaseError::Domain(DomainError::BoardingPass(
+                BoardingPassDomainError::BoardingPassRevoked,
+            )),
+            Self::Superseded(_) => UseCaseError::BusinessRule(
+                "Boarding pass has been superseded by a newer version".to_string(),
+            ),
+        }
+    }
+}
+
+impl std::fmt::Display for BarcodeRejection {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Malformed(err) => write!(f, "{err}"),
+            Self::InvalidSignature(_) => write!(f, "Invalid or unknown signature"),
+            Self::MissingCode(_) => write!(f, "Boarding pass code is missing"),
+            Self::UnknownPass(_, code) => write!(f, "Unknown boarding pass {code}"),
+            Self::Revoked(_) => write!(f, "{}", BoardingPassDomainError::BoardingPassRevoked),
+            Self::Superseded(_) => {
+                write!(f, "Boarding pass has been superseded by a newer version")
+            }
+        }
+    }
+}
+
 pub struct BoardingPassService {
     pub cache: Arc<dyn CacheInterface>,
     pub boarding_pass_repo: Arc<dyn BoardingPassRepositoryInterface>,
     pub checkin_repo: Arc<dyn CheckinRepositoryInterface>,
     pub booking_repo: Arc<dyn BookingRepositoryInterface>,
     pub passenger_repo: Arc<dyn PassengerRepositoryInterface>,
+    pub flight_repo: Arc<dyn FlightRepositoryInterface>,
+    pub airport_repo: Arc<dyn AirportRepositoryInterface>,
+    pub boarding_group_policy: Arc<BoardingGroupPolicy>,
+    pub signer: Arc<dyn BoardingPassSigner>,
+    pub renderer: Arc<dyn BoardingPassRenderer>,
+    pub wallet_pass_generator: Option<Arc<dyn WalletPassGenerator>>,
     pub event_publisher: Arc<dyn BoardingPassEventPublisher>,
 }
 
@@ -37,6 +146,12 @@ impl BoardingPassService {
         checkin_repo: Arc<dyn CheckinRepositoryInterface>,
         booking_repo: Arc<dyn BookingRepositoryInterface>,
         passenger_repo: Arc<dyn PassengerRepositoryInterface>,
+        flight_repo: Arc<dyn FlightRepositoryInterface>,
+        airport_repo: Arc<dyn AirportRepositoryInterface>,
+        boarding_group_policy: Arc<BoardingGroupPolicy>,
+        signer: Arc<dyn BoardingPassSigner>,
+        renderer: Arc<dyn BoardingPassRenderer>,
+        wallet_pass_generator: Option<Arc<dyn WalletPassGenerator>>,
         event_publisher: Arc<dyn BoardingPassEventPublisher>,
     ) -> Self {
         Self {
@@ -45,6 +160,12 @@ impl BoardingPassService {
             checkin_repo,
             booking_repo,
             passenger_repo,
+            flight_repo,
+            airport_repo,
+            boarding_group_policy,
+            signer,
+            renderer,
+            wallet_pass_generator,
             event_publisher,
         }
     }
@@ -72,6 +193,306 @@ impl BoardingPassService {
         Ok(())
     }
 
+    fn verification_view(bcbp: Option<&BcbpPass>) -> BoardingPassVerificationView {
+        let leg = bcbp.and_then(|b| b.legs.first());
+        let non_empty = |v: &str| (!v.is_empty()).then(|| v.to_string());
+
+        BoardingPassVerificationView {
+            valid: false,
+            reason: None,
+            boarding_pass_code: leg.and_then(|l| non_empty(l.airline_data.trim())),
+            key_id: bcbp
+                .and_then(|b| b.security.as_ref())
+                .map(|s| s.security_type.clone()),
+            passenger_name: bcbp.map(|b| b.passenger_name.clone()),
+            flight: leg.map(|l| format!("{}{}", l.operating_carrier, l.flight_number)),
+            from_airport: leg.map(|l| l.from_airport.clone()),
+            to_airport: leg.map(|l| l.to_airport.clone()),
+            seat: leg.and_then(|l| non_empty(&l.seat)),
+            expires_at: None,
+        }
+    }
+
+    fn rejected(
+        mut view: BoardingPassVerificationView,
+        reason: String,
+    ) -> BoardingPassVerificationView {
+        view.valid = false;
+        view.reason = Some(reason);
+        view
+    }
+
+    /// Decodes a scanned barcode, checks its signature and matches it to the latest payload
+    /// of a live pass. Shared by pass verification and the gate scan.
+    pub(crate) async fn authenticate_barcode(
+        signer: &dyn BoardingPassSigner,
+        boarding_pass_repo: &dyn BoardingPassRepositoryInterface,
+        payload: &str,
+    ) -> UseCaseResult<Result<AuthenticatedPass, BarcodeRejection>> {
+        let bcbp = match decoder::decode(payload) {
+            Ok(bcbp) => bcbp,
+            Err(err) => return Ok(Err(BarcodeRejection::Malformed(err))),
+        };
+        if !signer.verify(&bcbp)? {
+            return Ok(Err(BarcodeRejection::InvalidSignature(bcbp)));
+        }
+
+        let Some(code) = bcbp
+            .legs
+            .first()
+            .map(|leg| leg.airline_data.trim().to_string())
+            .filter(|code| !code.is_empty())
+        else {
+            return Ok(Err(BarcodeRejection::MissingCode(bcbp)));
+        };
+        let Some(boarding_pass) = boarding_pass_repo
+            .find_boarding_pass_by_code(&code)
+            .await
+            .map_err(|e| UseCaseError::Unexpected(e.to_string()))?
+        else {
+            return Ok(Err(BarcodeRejection::UnknownPass(bcbp, code)));
+        };
+
+        if boarding_pass.is_revoked() {
+            return Ok(Err(BarcodeRejection::Revoked(bcbp)));
+        }
+        // Passes are re-issued when their data changes; only the latest payload boards.
+        if boarding_pass.barcode_payload.as_deref() != Some(payload) {
+            return Ok(Err(BarcodeRejection::Superseded(bcbp)));
+        }
+
+        Ok(Ok(AuthenticatedPass {
+            bcbp,
+            boarding_pass,
+        }))
+    }
+
+    async fn load_itinerary(&self, checkin: &Checkin) -> UseCaseResult<PassItinerary> {
+        let booking = self
+            .booking_repo
+            .find_booking_by_id(checkin.booking_id)
+            .await
+            .map_err(|e| UseCaseError::Unexpected(e.to_string()))?
+            .ok_or_else(|| {
+                UseCaseError::NotFound(format!("Booking with id {} not found", checkin.booking_id))
+            })?;
+        let passenger = self
+            .passenger_repo
+            .find_passenger_by_id(checkin.passenger_id)
+            .await
+            .map_err(|e| UseCaseError::Unexpected(e.to_string()))?
+            .ok_or_else(|| {
+                UseCaseError::NotFound(format!(
+                    "Passenger with id {} not found",
+                    checkin.passenger_id
+                ))
+            })?;
+        let flight = self
+            .flight_repo
+            .find_flight_by_id(booking.flight_id)
+            .await
+            .map_err(|e| UseCaseError::Unexpected(e.to_string()))?
+            .ok_or_else(|| {
+                UseCaseError::NotFound(format!("Flight with id {} not found", booking.flight_id))
+            })?;
+        let origin = self
+            .airport_repo
+            .find_airport_by_id(flight.origin_airport_id)
+            .await
+            .map_err(|e| UseCaseError::Unexpected(e.to_string()))?
+            .ok_or_else(|| {
+                UseCaseError::NotFound(format!(
+                    "Airport with id {} not found",
+                    flight.origin_airport_id
+                ))
+            })?;
+        let destination = self
+            .airport_repo
+            .find_airport_by_id(flight.destination_airport_id)
+            .await
+            .map_err(|e| UseCaseError::Unexpected(e.to_string()))?
+            .ok_or_else(|| {
+                UseCaseError::NotFound(format!(
+                    "Airport with id {} not found",
+                    flight.destination_airport_id
+                ))
+            })?;
+
+        Ok(PassItinerary {
+            booking,
+            passenger,
+            flight,
+            origin,
+            destination,
+        })
+    }
+
+    // Falls back to UTC, labelled as such, when the airport has no usable time zone.
+    fn local_time(utc: NaiveDateTime, time_zone: &str, pattern: &str) -> String {
+        match time_zone.trim().parse::<Tz>() {
+            Ok(tz) => Utc
+                .from_utc_datetime(&utc)
+                .with_timezone(&tz)
+                .format(pattern)
+                .to_string(),
+            Err(_) => format!("{} UTC", utc.format(pattern)),
+        }
+    }
+
+    fn build_document(
+        boarding_pass: &BoardingPass,
+        checkin: &Checkin,
+        itinerary: &PassItinerary,
+    ) -> BoardingPassDocument {
+        let PassItinerary {
+            booking,
+            passenger,
+            flight,
+            origin,
+            destination,
+        } = itinerary;
+
+        let designator = |airline_code: &str, flight_number: &str| {
+            if flight_number.starts_with(airline_code) {
+                flight_number.to_string()
+            } else {
+                format!("{airline_code}{flight_number}")
+            }
+        };
+        let operating = designator(&flight.airline_code, &flight.flight_number);
+        let marketing = designator(
+            &booking.marketing_airline_code,
+            &booking.marketing_flight_number,
+        );
+        let operated_by = (marketing != operating).then(|| operating.clone());
+        let seat = if passenger.passenger_type.occupies_seat() {
+            checkin
+                .seat_no
+                .clone()
+                .filter(|seat| !seat.starts_with("AUTO-"))
+        } else {
+            Some("INF".to_string())
+        };
+        // Gate and boarding time set on the flight apply until the pass overrides them.
+        let boarding_time = boarding_pass.boarding_time.or(flight.boarding_time);
+
+        BoardingPassDocument {
+            boarding_pass_code: boarding_pass.boarding_pass_code.clone(),
+            booking_code: booking.booking_code.clone(),
+            // The PDF and PNG fonts are ASCII only, so names and cities are transliterated.
+            passenger_name: printable_name(&passenger.last_name, &passenger.first_name),
+            flight: marketing,
+            operated_by,
+            cabin: seat_class_to_string(&checkin.seat_class).replace('_', " "),
+            origin_iata_code: origin.iata_code.clone(),
+            origin_city: transliterate(&origin.city),
+            destination_iata_code: destination.iata_code.clone(),
+            destination_city: transliterate(&destination.city),
+            departure_at: Utc.from_utc_datetime(&flight.departure_time).to_rfc3339(),
+            departure_date: Self::local_time(flight.departure_time, &origin.time_zone, "%d %b %Y"),
+            departure_time: Self::local_time(flight.departure_time, &origin.time_zone, "%H:%M"),
+            arrival_time: Self::local_time(flight.arrival_time, &destination.time_zone, "%H:%M"),
+            boarding_time: boarding_time.map(|t| Self::local_time(t, &origin.time_zone, "%H:%M")),
+            terminal: boarding_pass
+                .terminal
+                .clone()
+                .or_else(|| flight.terminal_departure.clone()),
+            gate: boarding_pass.gate.clone().or_else(|| flight.gate.clone()),
+            boarding_group: boarding_pass.boarding_group.clone(),
+            sequence_no: boarding_pass.sequence_no.clone(),
+            seat,
+            barcode_format: boarding_pass.barcode_format.clone(),
+            // Passes issued before BCBP payloads existed still scan by their code.
+            barcode_payload: boarding_pass
+                .barcode_payload
+                .clone()
+                .unwrap_or_else(|| boarding_pass.boarding_pass_code.clone()),
+        }
+    }
+
+    // Returns the wallet pass version for `document`, moving to the next one when its
+    // gate or boarding time differs from the last generated pass.
+    async fn wallet_pass_version(
+        &self,
+        boarding_pass: &BoardingPass,
+        document: &BoardingPassDocument,
+    ) -> UseCaseResult<i32> {
+        let fingerprint = format!(
+            "{}|{}|{}",
+            document.gate.as_deref().unwrap_or(""),
+            document.terminal.as_deref().unwrap_or(""),
+            document.boarding_time.as_deref().unwrap_or("")
+        );
+
+        let mut boarding_pass = boarding_pass.clone();
+        let expected_version = boarding_pass.wallet_pass_version;
+        if !boarding_pass.refresh_wallet_pass(fingerprint) {
+            return Ok(expected_version);
+        }
+
+        let saved = self
+            .boarding_pass_repo
+            .update_wallet_pass(&boarding_pass, expected_version)
+            .await
+            .map_err(|e| UseCaseError::Unexpected(e.to_string()))?;
+        if !saved {
+            // A concurrent request stored the new version first; use it.
+            return Ok(self
+                .boarding_pass_repo
+                .find_boarding_pass_by_id(boarding_pass.id)
+                .await
+                .map_err(|e| UseCaseError::Unexpected(e.to_string()))?
+                .map(|bp| bp.wallet_pass_version)
+                .unwrap_or(boarding_pass.wallet_pass_version));
+        }
+
+        if boarding_pass.wallet_pass_version != expected_version {
+            for key in [
+                Self::by_checkin_cache_key(boarding_pass.checkin_id),
+                Self::by_code_cache_key(&boarding_pass.boarding_pass_code),
+            ] {
+                if let Err(err) = self.cache.del(&key).await {
+                    tracing::warn!("cache del failed key={}: {}", key, err);
+                }
+            }
+        }
+
+        Ok(boarding_pass.wallet_pass_version)
+    }
+
+    fn build_barcode_payload(
+        &self,
+        checkin: &Checkin,
+        itinerary: &PassItinerary,
+        boarding_pass: &BoardingPass,
+    ) -> UseCaseResult<String> {
+        let PassItinerary {
+            booking,
+            passenger,
+            flight,
+            origin,
+            destination,
+        } = itinerary;
+
+        let mut bcbp = build_bcbp(
+            passenger,
+            &[BcbpLegSource {
+                booking_code: &booking.booking_code,
+                flight,
+                marketing_carrier: &booking.marketing_airline_code,
+                origin_iata_code: &origin.iata_code,
+                destination_iata_code: &destination.iata_code,
+                checkin,
+                boarding_pass: Some(boarding_pass),
+            }],
+            &boarding_pass.boarding_pass_code,
+            boarding_pass.issued_at,
+        )?;
+        self.signer.sign(&mut bcbp)?;
+
+        Ok(encoder::encode(&bcbp)?)
+    }
+
     async fn generate_unique_code(&self) -> UseCaseResult<String> {
         for _ in 0..10 {
             let code = format!(
@@ -147,24 +568,13 @@ impl BoardingPassServiceInterface for BoardingPassService {
         } else {
             command.barcode_format.trim().to_uppercase()
         };
-        let barcode_payload = command.barcode_payload.or_else(|| {
-            Some(
-                json!({
-                    "checkin_id": checkin.id,
-                    "booking_id": checkin.booking_id,
-                    "passenger_id": checkin.passenger_id,
-                    "seat_no": checkin.seat_no,
-                })
-                .to_string(),
-            )
-        });
 
         let mut boarding_pass = BoardingPass::new(
             CreateBoardingPassProps {
                 checkin_id: command.checkin_id,
                 boarding_pass_code: code,
                 barcode_format,
-                barcode_payload,
+                barcode_payload: None,
                 issued_at: now,
             },
             checkin.status.clone(),
@@ -172,11 +582,31 @@ impl BoardingPassServiceInterface for BoardingPassService {
         boarding_pass.update_from(UpdateBoardingPassProps {
             gate: command.gate,
             terminal: command.terminal,
-            boarding_group: command.boarding_group,
-            sequence_no: command.sequence_no,
+            boarding_group: None,
+            sequence_no: None,
             boarding_time: command.boarding_time,
         })?;
 
+        let itinerary = self.load_itinerary(&checkin).await?;
+        let sequence = self
+            .flight_repo
+            .next_boarding_sequence(itinerary.flight.id)
+            .await
+            .map_err(|e| UseCaseError::Unexpected(e.to_string()))?;
+        let boarding_group = self.boarding_group_policy.group_for(&BoardingGroupInput {
+            seat_class: &checkin.seat_class,
+            fare_family: itinerary.booking.fare_family,
+            operating_carrier: &itinerary.flight.airline_code,
+            ff_airline_code: itinerary.passenger.ff_airline_code.as_deref(),
+            ff_number: itinerary.passenger.ff_number.as_deref(),
+            seat_no: checkin.seat_no.as_deref(),
+        });
+        boarding_pass.assign_boarding_position(boarding_group, sequence);
+
+        // Always built from the check-in record, so only data we hold gets signed.
+        let barcode_payload = self.build_barcode_payload(&checkin, &itinerary, &boarding_pass)?;
+        boarding_pass.barcode_payload = Some(barcode_payload);
+
         let _ = self
             .boarding_pass_repo
             .create_boarding_pass(&boarding_pass)
@@ -229,7 +659,9 @@ impl BoardingPassServiceInterface for BoardingPassService {
             .find_checkin_by_id(checkin_id)
             .await
             .map_err(|e| UseCaseError::Unexpected(e.to_string()))?
-            .ok_or_else(|| UseCaseError::NotFound(format!("Checkin with id {} not found", checkin_id)))?;
+            .ok_or_else(|| {
+                UseCaseError::NotFound(format!("Checkin with id {} not found", checkin_id))
+            })?;
         let booking = self
             .booking_repo
             .find_booking_by_id(checkin.booking_id)
@@ -253,7 +685,10 @@ impl BoardingPassServiceInterface for BoardingPassService {
             .await
             .map_err(|e| UseCaseError::Unexpected(e.to_string()))?
             .ok_or_else(|| {
-                UseCaseError::NotFound(format!("Boarding pass for checkin {} not found", checkin_id))
+                UseCaseError::NotFound(format!(
+                    "Boarding pass for checkin {} not found",
+                    checkin_id
+                ))
             })?;
 
         let view: BoardingPassView = boarding_pass.into();
@@ -314,7 +749,10 @@ impl BoardingPassServiceInterface for BoardingPassService {
             .await
             .map_err(|e| UseCaseError::Unexpected(e.to_string()))?
             .ok_or_else(|| {
-                UseCaseError::NotFound(format!("Boarding pass with code {} not found", normalized_code))
+                UseCaseError::NotFound(format!(
+                    "Boarding pass with code {} not found",
+                    normalized_code
+                ))
             })?;
 
         let checkin = self
@@ -362,7 +800,9 @@ impl BoardingPassServiceInterface for BoardingPassService {
             .find_booking_by_id(booking_id)
             .await
             .map_err(|e| UseCaseError::Unexpected(e.to_string()))?
-            .ok_or_else(|| UseCaseError::NotFound(format!("Booking with id {} not found", booking_id)))?;
+            .ok_or_else(|| {
+                UseCaseError::NotFound(format!("Booking with id {} not found", booking_id))
+            })?;
         Self::ensure_owner_or_admin(&ctx, actor_user_id, booking.user_id)?;
 
         let passengers = self
@@ -381,7 +821,8 @@ impl BoardingPassServiceInterface for BoardingPassService {
             let Some(checkin) = checkin_opt else { continue };
 
             let checkin_key = Self::by_checkin_cache_key(checkin.id);
-            if let Ok(Some(cached)) = cache_get_json::<BoardingPassView>(self.cache.as_ref(), &checkin_key).await
+            if let Ok(Some(cached)) =
+                cache_get_json::<BoardingPassView>(self.cache.as_ref(), &checkin_key).await
             {
                 result.push(cached);
                 continue;
@@ -408,4 +849,162 @@ impl BoardingPassServiceInterface for BoardingPassService {
 
         Ok(result)
     }
+
+    async fn verify_boarding_pass(
+        &self,
+        ctx: RequestContext,
+        command: VerifyBoardingPassCommand,
+    ) -> UseCaseResult<BoardingPassVerificationView> {
+        if !ctx.is_admin() && !ctx.is_staff() && !ctx.is_system() {
+            return Err(UseCaseError::PermissionDenied);
+        }
+
+        let payload = command.barcode_payload.trim();
+        let (view, boarding_pass) = match Self::authenticate_barcode(
+            self.signer.as_ref(),
+            self.boarding_pass_repo.as_ref(),
+            payload,
+        )
+        .await?
+        {
+            Ok(pass) => (
+                Self::verification_view(Some(&pass.bcbp)),
+                pass.boarding_pass,
+            ),
+            Err(rejection) => {
+                return Ok(Self::rejected(
+                    Self::verification_view(rejection.bcbp()),
+                    rejection.to_string(),
+                ));
+            }
+        };
+
+        let checkin = self
+            .checkin_repo
+            .find_checkin_by_id(boarding_pass.checkin_id)
+            .await
+            .map_err(|e| UseCaseError::Unexpected(e.to_string()))?
+            .ok_or_else(|| {
+                UseCaseError::NotFound(format!(
+                    "Checkin with id {} not found",
+                    boarding_pass.checkin_id
+                ))
+            })?;
+        if checkin.status == CheckinStatus::Boarded {
+            return Ok(Self::rejected(
+                view,
+                BoardingPassDomainError::BoardingPassAlreadyUsed.to_string(),
+            ));
+        }
+        if checkin.status != CheckinStatus::CheckedIn {
+            return Ok(Self::rejected(
+                view,
+                BoardingPassDomainError::CheckinNotCompleted.to_string(),
+            ));
+        }
+
+        let booking = self
+            .booking_repo
+            .find_booking_by_id(checkin.booking_id)
+            .await
+            .map_err(|e| UseCaseError::Unexpected(e.to_string()))?
+            .ok_or_else(|| {
+                UseCaseError::NotFound(format!("Booking with id {} not found", checkin.booking_id))
+            })?;
+        let flight = self
+            .flight_repo
+            .find_flight_by_id(booking.flight_id)
+            .await
+            .map_err(|e| UseCaseError::Unexpected(e.to_string()))?
+            .ok_or_else(|| {
+                UseCaseError::NotFound(format!("Flight with id {} not found", booking.flight_id))
+            })?;
+
+        let mut view = view;
+        view.expires_at = Some(
+            flight
+                .expected_departure()
+                .format("%Y-%m-%dT%H:%M:%S")
+                .to_string(),
+        );
+
+        if flight.status == FlightStatus::Cancelled {
+            return Ok(Self::rejected(
+                view,
+                "Flight has been cancelled".to_string(),
+            ));
+        }
+        if flight.has_departed_by(chrono::Utc::now().naive_utc()) {
+            return Ok(Self::rejected(
+                view,
+                "Boarding pass has expired".to_string(),
+            ));
+        }
+
+        view.valid = true;
+        Ok(view)
+    }
+
+    async fn render_boarding_pass(
+        &self,
+        ctx: RequestContext,
+        command: RenderBoardingPassCommand,
+    ) -> UseCaseResult<BoardingPassFileView> {
+        let actor_user_id = ctx.user_id().ok_or(UseCaseError::PermissionDenied)?;
+        let normalized_code = command.code.trim().to_uppercase();
+
+        let boarding_pass = self
+            .boarding_pass_repo
+            .find_boarding_pass_by_code(&normalized_code)
+            .await
+            .map_err(|e| UseCaseError::Unexpected(e.to_string()))?
+            .ok_or_else(|| {
+                UseCaseError::NotFound(format!(
+                    "Boarding pass with code {} not found",
+                    normalized_code
+                ))
+            })?;
+        let checkin = self
+            .checkin_repo
+            .find_checkin_by_id(boarding_pass.checkin_id)
+            .await
+            .map_err(|e| UseCaseError::Unexpected(e.to_string()))?
+            .ok_or_else(|| {
+                UseCaseError::NotFound(format!(
+                    "Checkin with id {} not found",
+                    boarding_pass.checkin_id
+                ))
+            })?;
+        let itinerary = self.load_itinerary(&checkin).await?;
+        Self::ensure_owner_or_admin(&ctx, actor_user_id, itinerary.booking.user_id)?;
+
+        if boarding_pass.is_revoked() {
+            return Err(UseCaseError::Domain(DomainError::BoardingPass(
+                BoardingPassDomainError::BoardingPassRevoked,
+            )));
+        }
+
+        let document = Self::build_document(&boarding_pass, &checkin, &itinerary);
+        let content = match command.format {
+            BoardingPassDocumentFormat::Pdf => self.renderer.render_pdf(&document)?,
+            BoardingPassDocumentFormat::Png => self.renderer.render_png(&document)?,
+            BoardingPassDocumentFormat::Pkpass => {
+                let generator = self.wallet_pass_generator.as_ref().ok_or_else(|| {
+                    UseCaseError::BusinessRule("Wallet passes are not configured".to_string())
+                })?;
+                let version = self.wallet_pass_version(&boarding_pass, &document).await?;
+                generator.generate(&document, version)?
+            }
+        };
+
+        Ok(BoardingPassFileView {
+            file_name: format!(
+                "boarding_pass_{}.{}",
+                boarding_pass.boarding_pass_code,
+                command.format.extension()
+            ),
+            content_type: command.format.content_type().to_string(),
+            content,
+        })
+    }
 }
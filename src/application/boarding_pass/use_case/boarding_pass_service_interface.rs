@@ -1,5 +1,9 @@
-use crate::application::boarding_pass::boarding_pass_command::IssueBoardingPassCommand;
-use crate::application::boarding_pass::view::boarding_pass_view::BoardingPassView;
+use crate::application::boarding_pass::boarding_pass_command::{
+    IssueBoardingPassCommand, RenderBoardingPassCommand, VerifyBoardingPassCommand,
+};
+use crate::application::boarding_pass::view::boarding_pass_view::{
+    BoardingPassFileView, BoardingPassVerificationView, BoardingPassView,
+};
 use crate::application::common::use_case_error::UseCaseResult;
 use crate::core::context::request_context::RequestContext;
 
@@ -28,4 +32,16 @@ pub trait BoardingPassServiceInterface: Send + Sync {
         ctx: RequestContext,
         booking_id: i64,
     ) -> UseCaseResult<Vec<BoardingPassView>>;
+
+    async fn verify_boarding_pass(
+        &self,
+        ctx: RequestContext,
+        command: VerifyBoardingPassCommand,
+    ) -> UseCaseResult<BoardingPassVerificationView>;
+
+    async fn render_boarding_pass(
+        &self,
+        ctx: RequestContext,
+        command: RenderBoardingPassCommand,
+    ) -> UseCaseResult<BoardingPassFileView>;
 }
@@ -13,4 +13,27 @@ pub struct BoardingPassView {
     pub issued_at: String,
     pub barcode_format: String,
     pub barcode_payload: Option<String>,
+    pub revoked_at: Option<String>,
+    pub wallet_pass_version: i32,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct BoardingPassVerificationView {
+    pub valid: bool,
+    pub reason: Option<String>,
+    pub boarding_pass_code: Option<String>,
+    pub key_id: Option<String>,
+    pub passenger_name: Option<String>,
+    pub flight: Option<String>,
+    pub from_airport: Option<String>,
+    pub to_airport: Option<String>,
+    pub seat: Option<String>,
+    pub expires_at: Option<String>,
+}
+
+#[derive(Debug, Clone)]
+pub struct BoardingPassFileView {
+    pub file_name: String,
+    pub content_type: String,
+    pub content: Vec<u8>,
 }
@@ -0,0 +1,8 @@
+use crate::application::boarding_pass::boarding_pass_renderer::BoardingPassDocument;
+use crate::application::common::use_case_error::UseCaseResult;
+
+pub trait WalletPassGenerator: Send + Sync {
+    /// Builds a signed `.pkpass` bundle. `version` is shown on the pass so a re-issued
+    /// pass can be told apart from the one already in the wallet.
+    fn generate(&self, document: &BoardingPassDocument, version: i32) -> UseCaseResult<Vec<u8>>;
+}
@@ -4,12 +4,14 @@ use validator::Validate;
 #[derive(Debug, Clone, Validate)]
 pub struct CreateBookingCommand {
     pub flight_id: i64,
+    pub flight_designator: Option<String>,
     pub base_amount: Decimal,
     pub taxes_amount: Decimal,
     pub fees_amount: Decimal,
     pub discount_amount: Decimal,
     pub total_amount: Decimal,
     pub currency: String,
+    pub fare_family: Option<String>,
     pub contact_email: String,
     pub contact_phone: Option<String>,
     pub contact_full_name: String,
@@ -1,5 +1,7 @@
 use crate::application::booking::view::booking_view::BookingView;
-use crate::domain::booking::entity::{Booking, BookingStatus, PaymentMethod, PaymentStatus};
+use crate::domain::booking::entity::{
+    Booking, BookingStatus, FareFamily, PaymentMethod, PaymentStatus,
+};
 use chrono::NaiveDateTime;
 
 impl From<Booking> for BookingView {
@@ -16,6 +18,8 @@ impl From<Booking> for BookingView {
             booking_code: value.booking_code,
             user_id: value.user_id,
             flight_id: value.flight_id,
+            marketing_airline_code: value.marketing_airline_code,
+            marketing_flight_number: value.marketing_flight_number,
             status: booking_status_to_string(&value.status),
             cancellation_reason: value.cancellation_reason,
             base_amount: value.base_amount.to_string(),
@@ -24,6 +28,7 @@ impl From<Booking> for BookingView {
             discount_amount: value.discount_amount.to_string(),
             total_amount: value.total_amount.to_string(),
             currency: value.currency,
+            fare_family: fare_family_to_string(&value.fare_family),
             contact_email: value.contact_email,
             contact_phone: value.contact_phone,
             contact_full_name: value.contact_full_name,
@@ -49,6 +54,7 @@ fn booking_status_to_string(status: &BookingStatus) -> String {
         BookingStatus::Confirmed => "CONFIRMED",
         BookingStatus::Cancelled => "CANCELLED",
         BookingStatus::Expired => "EXPIRED",
+        BookingStatus::NoShow => "NO_SHOW",
     }
     .to_string()
 }
@@ -63,6 +69,15 @@ fn payment_status_to_string(status: &PaymentStatus) -> String {
     .to_string()
 }
 
+pub fn fare_family_to_string(fare_family: &FareFamily) -> String {
+    match fare_family {
+        FareFamily::Basic => "BASIC",
+        FareFamily::Standard => "STANDARD",
+        FareFamily::Flex => "FLEX",
+    }
+    .to_string()
+}
+
 fn payment_method_to_string(method: &PaymentMethod) -> String {
     match method {
         PaymentMethod::Card => "CARD",
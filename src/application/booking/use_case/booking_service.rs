@@ -11,14 +11,19 @@ use crate::core::context::request_context::RequestContext;
 use crate::domain::booking;
 use crate::domain::booking::booking_repository_interface::BookingRepositoryInterface;
 use crate::domain::booking::entity::{
-    BookingStatus, CreateBookingProps, PaymentMethod, PaymentStatus,
+    BookingStatus, CreateBookingProps, FareFamily, PaymentMethod, PaymentStatus,
 };
 use crate::domain::booking::events::booking_cancelled::BookingCancelledEvent;
 use crate::domain::booking::events::booking_confirmed::BookingConfirmedEvent;
 use crate::domain::booking::events::booking_created::BookingCreatedEvent;
 use crate::domain::booking::error::BookingDomainError;
+use crate::domain::business_rule_interface::BusinessRuleInterface;
 use crate::domain::error::DomainError;
+use crate::domain::flight::entity::Flight;
 use crate::domain::flight::flight_repository_interface::FlightRepositoryInterface;
+use crate::domain::passenger::entity::Passenger;
+use crate::domain::passenger::passenger_repository_interface::PassengerRepositoryInterface;
+use crate::domain::passenger::rules::minors_must_be_accompanied::MinorsMustBeAccompanied;
 use crate::domain::user::user_repository_interface::UserRepositoryInterface;
 use std::sync::Arc;
 use uuid::Uuid;
@@ -28,6 +33,7 @@ pub struct BookingService {
     pub cache: Arc<dyn CacheInterface>,
     pub booking_repo: Arc<dyn BookingRepositoryInterface>,
     pub flight_repo: Arc<dyn FlightRepositoryInterface>,
+    pub passenger_repo: Arc<dyn PassengerRepositoryInterface>,
     pub user_repo: Arc<dyn UserRepositoryInterface>,
     pub event_publisher: Arc<dyn BookingEventPublisher>,
 }
@@ -37,6 +43,7 @@ impl BookingService {
         cache: Arc<dyn CacheInterface>,
         booking_repo: Arc<dyn BookingRepositoryInterface>,
         flight_repo: Arc<dyn FlightRepositoryInterface>,
+        passenger_repo: Arc<dyn PassengerRepositoryInterface>,
         user_repo: Arc<dyn UserRepositoryInterface>,
         event_publisher: Arc<dyn BookingEventPublisher>,
     ) -> Self {
@@ -44,6 +51,7 @@ impl BookingService {
             cache,
             booking_repo,
             flight_repo,
+            passenger_repo,
             user_repo,
             event_publisher,
         }
@@ -86,6 +94,23 @@ impl BookingService {
         }
     }
 
+    fn parse_fare_family(fare_family: Option<&str>) -> UseCaseResult<FareFamily> {
+        let Some(fare_family) = fare_family else {
+            return Ok(FareFamily::Standard);
+        };
+        match fare_family.trim().to_uppercase().as_str() {
+            "BASIC" => Ok(FareFamily::Basic),
+            "STANDARD" => Ok(FareFamily::Standard),
+            "FLEX" => Ok(FareFamily::Flex),
+            _ => Err(UseCaseError::Domain(DomainError::Booking(
+                BookingDomainError::Validation {
+                    field: "fare_family",
+                    message: format!("Invalid fare family: {fare_family}"),
+                },
+            ))),
+        }
+    }
+
     fn ensure_owner_or_admin(
         ctx: &RequestContext,
         actor_user_id: i64,
@@ -97,6 +122,34 @@ impl BookingService {
         Ok(())
     }
 
+    /// The operating designator unless the request names one of the flight's codeshares.
+    async fn resolve_marketing_designator(
+        &self,
+        flight: &Flight,
+        designator: Option<&str>,
+    ) -> UseCaseResult<(String, String)> {
+        let designator = designator
+            .map(|d| d.replace(' ', "").to_uppercase())
+            .filter(|d| !d.is_empty() && *d != flight.designator());
+        let Some(designator) = designator else {
+            return Ok((flight.airline_code.clone(), flight.flight_number.clone()));
+        };
+
+        self.flight_repo
+            .list_codeshares_by_flight_ids(&[flight.id])
+            .await
+            .map_err(|e| UseCaseError::Unexpected(e.to_string()))?
+            .into_iter()
+            .find(|codeshare| codeshare.designator() == designator)
+            .map(|codeshare| (codeshare.airline_code, codeshare.flight_number))
+            .ok_or_else(|| {
+                UseCaseError::Domain(DomainError::Booking(BookingDomainError::Validation {
+                    field: "flight_designator",
+                    message: format!("{designator} is not a designator of flight {}", flight.id),
+                }))
+            })
+    }
+
     async fn generate_unique_booking_code(&self) -> UseCaseResult<String> {
         for _ in 0..10 {
             let code: String = Uuid::new_v4()
@@ -179,6 +232,9 @@ impl BookingServiceInterface for BookingService {
             return Err(UseCaseError::BusinessRule("No seats available".to_string()));
         }
         flight.validate_seat_reservation()?;
+        let (marketing_airline_code, marketing_flight_number) = self
+            .resolve_marketing_designator(&flight, command.flight_designator.as_deref())
+            .await?;
 
         let expected_total =
             command.base_amount + command.taxes_amount + command.fees_amount - command.discount_amount;
@@ -191,16 +247,20 @@ impl BookingServiceInterface for BookingService {
             )));
         }
 
+        let fare_family = Self::parse_fare_family(command.fare_family.as_deref())?;
         let booking_code = self.generate_unique_booking_code().await?;
         let props = CreateBookingProps {
             booking_code: booking_code.clone(),
             user_id,
             flight_id: command.flight_id,
+            marketing_airline_code,
+            marketing_flight_number,
             base_amount: command.base_amount,
             taxes_amount: command.taxes_amount,
             fees_amount: command.fees_amount,
             discount_amount: command.discount_amount,
             currency: command.currency,
+            fare_family,
             contact_email: command.contact_email,
             contact_full_name: command.contact_full_name,
             contact_phone: command.contact_phone,
@@ -268,7 +328,8 @@ impl BookingServiceInterface for BookingService {
 
         booking.confirm(now)?;
 
-        let mut flight = self
+        // Seats are already held by the booking's passengers, so only the flight state is checked.
+        let flight = self
             .flight_repo
             .find_flight_by_id(booking.flight_id)
             .await
@@ -276,14 +337,22 @@ impl BookingServiceInterface for BookingService {
             .ok_or_else(|| {
                 UseCaseError::NotFound(format!("Flight with id {} not found", booking.flight_id))
             })?;
-        flight.validate_seat_reservation()?;
-        let flight_expected_version = flight.version;
-        flight.reserve_seat()?;
+        flight.ensure_open_for_sale()?;
 
-        self.flight_repo
-            .update_flight(&flight, flight_expected_version)
+        let passengers = self
+            .passenger_repo
+            .list_passengers_by_booking(booking.id)
             .await
             .map_err(|e| UseCaseError::Unexpected(e.to_string()))?;
+        if passengers.is_empty() {
+            return Err(UseCaseError::BusinessRule(
+                "Booking must have at least one passenger before confirmation".to_string(),
+            ));
+        }
+        MinorsMustBeAccompanied {
+            passengers: &passengers,
+        }
+        .check_broken()?;
 
         let booking_expected_version = booking.version;
         self.booking_repo
@@ -324,39 +393,34 @@ impl BookingServiceInterface for BookingService {
 
         Self::ensure_owner_or_admin(&ctx, actor_user_id, booking.user_id)?;
 
-        let was_confirmed = booking.status == BookingStatus::Confirmed;
+        let holds_seats = matches!(
+            booking.status,
+            BookingStatus::Draft | BookingStatus::Confirmed
+        );
         let now = chrono::Utc::now().naive_utc();
         let reason = command
             .cancellation_reason
             .unwrap_or_else(|| "Booking cancelled".to_string());
 
+        // Read the seats before the cancel so they go back with it in one transaction.
+        let released_seats = if holds_seats {
+            let passengers = self
+                .passenger_repo
+                .list_passengers_by_booking(booking.id)
+                .await
+                .map_err(|e| UseCaseError::Unexpected(e.to_string()))?;
+            Passenger::occupied_seats(&passengers)
+        } else {
+            0
+        };
+
         booking.cancel(reason, Some(actor_user_id), now)?;
         let booking_expected_version = booking.version;
         self.booking_repo
-            .update_booking(&booking, booking_expected_version)
+            .cancel_booking(&booking, booking_expected_version, released_seats)
             .await
             .map_err(|e| UseCaseError::Unexpected(e.to_string()))?;
 
-        if was_confirmed {
-            let mut flight = self
-                .flight_repo
-                .find_flight_by_id(booking.flight_id)
-                .await
-                .map_err(|e| UseCaseError::Unexpected(e.to_string()))?
-                .ok_or_else(|| {
-                    UseCaseError::NotFound(format!("Flight with id {} not found", booking.flight_id))
-                })?;
-
-            if flight.available_seats < flight.total_seats {
-                let flight_expected_version = flight.version;
-                flight.available_seats += 1;
-                self.flight_repo
-                    .update_flight(&flight, flight_expected_version)
-                    .await
-                    .map_err(|e| UseCaseError::Unexpected(e.to_string()))?;
-            }
-        }
-
         self.invalidate_booking_cache(booking.id, booking.user_id).await;
 
         let _ = self
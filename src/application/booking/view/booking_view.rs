@@ -6,6 +6,8 @@ pub struct BookingView {
     pub booking_code: String,
     pub user_id: i64,
     pub flight_id: i64,
+    pub marketing_airline_code: String,
+    pub marketing_flight_number: String,
     pub status: String,
     pub cancellation_reason: Option<String>,
     pub base_amount: String,
@@ -14,6 +16,7 @@ pub struct BookingView {
     pub discount_amount: String,
     pub total_amount: String,
     pub currency: String,
+    pub fare_family: String,
     pub contact_email: String,
     pub contact_phone: Option<String>,
     pub contact_full_name: String,
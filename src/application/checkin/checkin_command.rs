@@ -10,8 +10,11 @@ pub struct CreateCheckinCommand {
     pub baggage_count: i32,
     pub baggage_weight_total: Decimal,
     pub baggage_weight_unit: String,
+    // One weight per bag, in `baggage_weight_unit`.
+    pub baggage_piece_weights: Vec<Decimal>,
     pub checkin_channel: String,
     pub checked_in_ip: Option<String>,
+    pub has_valid_visa: bool,
 }
 
 #[derive(Debug, Clone)]
@@ -20,6 +23,12 @@ pub struct UpdateCheckinCommand {
     pub seat_class: Option<String>,
     pub baggage_count: Option<i32>,
     pub baggage_weight_total: Option<Decimal>,
+    pub baggage_piece_weights: Option<Vec<Decimal>>,
+}
+
+#[derive(Debug, Clone)]
+pub struct PayExcessBaggageCommand {
+    pub payment_txn_id: String,
 }
 
 #[derive(Debug, Clone)]
@@ -4,6 +4,7 @@ use rust_decimal::Decimal;
 
 impl From<Checkin> for CheckinView {
     fn from(value: Checkin) -> Self {
+        let excess_baggage_payment_required = value.has_unpaid_excess_baggage();
         Self {
             id: value.id,
             booking_id: value.booking_id,
@@ -16,9 +17,30 @@ impl From<Checkin> for CheckinView {
                 .map(|v| v.to_string())
                 .unwrap_or_else(|| value.baggage_weight_total.to_string()),
             baggage_weight_unit: value.baggage_weight_unit,
+            baggage_piece_weights: value
+                .baggage_piece_weights
+                .iter()
+                .map(|w| {
+                    Decimal::from_f64_retain(*w)
+                        .map(|v| v.round_dp(3).to_string())
+                        .unwrap_or_else(|| w.to_string())
+                })
+                .collect(),
+            baggage_allowance: value.baggage_allowance,
+            excess_baggage_pieces: value.excess_baggage_pieces,
+            excess_baggage_weight_kg: value.excess_baggage_weight_kg.to_string(),
+            excess_baggage_fee: value.excess_baggage_fee.to_string(),
+            excess_baggage_currency: value.excess_baggage_currency,
+            excess_baggage_paid_at: value
+                .excess_baggage_paid_at
+                .map(|v| v.format("%Y-%m-%dT%H:%M:%S").to_string()),
+            excess_baggage_payment_required,
             checked_in_at: value
                 .checked_in_at
                 .map(|v| v.format("%Y-%m-%dT%H:%M:%S").to_string()),
+            boarded_at: value
+                .boarded_at
+                .map(|v| v.format("%Y-%m-%dT%H:%M:%S").to_string()),
             checkin_channel: checkin_channel_to_string(&value.checkin_channel),
             checked_in_ip: value.checked_in_ip,
             version: value.version,
@@ -26,16 +48,19 @@ impl From<Checkin> for CheckinView {
     }
 }
 
-fn checkin_status_to_string(value: &CheckinStatus) -> String {
+pub fn checkin_status_to_string(value: &CheckinStatus) -> String {
     match value {
         CheckinStatus::Pending => "PENDING",
         CheckinStatus::CheckedIn => "CHECKED_IN",
+        CheckinStatus::Boarded => "BOARDED",
+        CheckinStatus::Offloaded => "OFFLOADED",
         CheckinStatus::Cancelled => "CANCELLED",
+        CheckinStatus::Standby => "STANDBY",
     }
     .to_string()
 }
 
-fn seat_class_to_string(value: &SeatClass) -> String {
+pub fn seat_class_to_string(value: &SeatClass) -> String {
     match value {
         SeatClass::Economy => "ECONOMY",
         SeatClass::PremiumEconomy => "PREMIUM_ECONOMY",
@@ -1,5 +1,6 @@
+use crate::application::boarding_pass::boarding_pass_signer::BoardingPassSigner;
 use crate::application::checkin::checkin_command::{
-    CancelCheckinCommand, CreateCheckinCommand, UpdateCheckinCommand,
+    CancelCheckinCommand, CreateCheckinCommand, PayExcessBaggageCommand, UpdateCheckinCommand,
 };
 use crate::application::checkin::use_case::checkin_service_interface::CheckinServiceInterface;
 use crate::application::checkin::view::checkin_view::CheckinView;
@@ -8,23 +9,37 @@ use crate::application::common::cache_interface::CacheInterface;
 use crate::application::common::event_publisher::CheckinEventPublisher;
 use crate::application::common::use_case_error::{UseCaseError, UseCaseResult};
 use crate::core::context::request_context::RequestContext;
+use crate::domain::airport::airport_repository_interface::AirportRepositoryInterface;
+use crate::domain::airport::entity::Airport;
+use crate::domain::boarding_pass::bcbp::encoder;
+use crate::domain::boarding_pass::bcbp::factory::{BcbpLegSource, build_bcbp};
+use crate::domain::boarding_pass::boarding_group_policy::{
+    BoardingGroupInput, BoardingGroupPolicy,
+};
 use crate::domain::boarding_pass::boarding_pass_repository_interface::BoardingPassRepositoryInterface;
-use crate::domain::boarding_pass::entity::CreateBoardingPassProps;
+use crate::domain::boarding_pass::entity::{BoardingPass, CreateBoardingPassProps};
 use crate::domain::booking::booking_repository_interface::BookingRepositoryInterface;
-use crate::domain::booking::entity::BookingStatus;
+use crate::domain::booking::entity::{Booking, BookingStatus};
+use crate::domain::business_rule_interface::BusinessRuleInterface;
 use crate::domain::checkin;
+use crate::domain::checkin::baggage_allowance_policy::{BaggageAllowancePolicy, WeightUnit};
 use crate::domain::checkin::checkin_repository_interface::CheckinRepositoryInterface;
 use crate::domain::checkin::entity::{
-    CheckinChannel, CheckinStatus, CreateCheckinProps, SeatClass, UpdateCheckinProps,
+    Checkin, CheckinChannel, CheckinStatus, CreateCheckinProps, SeatClass, UpdateCheckinProps,
 };
+use crate::domain::checkin::error::CheckinDomainError;
 use crate::domain::checkin::events::checkin_cancelled::CheckinCancelledEvent;
 use crate::domain::checkin::events::checkin_created::CheckinCreatedEvent;
-use crate::domain::checkin::error::CheckinDomainError;
 use crate::domain::error::DomainError;
+use crate::domain::flight::entity::Flight;
 use crate::domain::flight::flight_repository_interface::FlightRepositoryInterface;
+use crate::domain::passenger::entity::Passenger;
+use crate::domain::passenger::error::PassengerDomainError;
 use crate::domain::passenger::passenger_repository_interface::PassengerRepositoryInterface;
+use crate::domain::passenger::rules::travel_documents_must_satisfy_requirements::TravelDocumentsMustSatisfyRequirements;
+use crate::domain::passenger::travel_document_policy::TravelDocumentPolicy;
+use rust_decimal::Decimal;
 use rust_decimal::prelude::ToPrimitive;
-use serde_json::json;
 use std::sync::Arc;
 use uuid::Uuid;
 use validator::Validate;
@@ -36,6 +51,11 @@ pub struct CheckinService {
     pub flight_repo: Arc<dyn FlightRepositoryInterface>,
     pub passenger_repo: Arc<dyn PassengerRepositoryInterface>,
     pub boarding_pass_repo: Arc<dyn BoardingPassRepositoryInterface>,
+    pub airport_repo: Arc<dyn AirportRepositoryInterface>,
+    pub document_policy: Arc<TravelDocumentPolicy>,
+    pub boarding_group_policy: Arc<BoardingGroupPolicy>,
+    pub baggage_allowance_policy: Arc<BaggageAllowancePolicy>,
+    pub boarding_pass_signer: Arc<dyn BoardingPassSigner>,
     pub event_publisher: Arc<dyn CheckinEventPublisher>,
 }
 
@@ -47,6 +67,11 @@ impl CheckinService {
         flight_repo: Arc<dyn FlightRepositoryInterface>,
         passenger_repo: Arc<dyn PassengerRepositoryInterface>,
         boarding_pass_repo: Arc<dyn BoardingPassRepositoryInterface>,
+        airport_repo: Arc<dyn AirportRepositoryInterface>,
+        document_policy: Arc<TravelDocumentPolicy>,
+        boarding_group_policy: Arc<BoardingGroupPolicy>,
+        baggage_allowance_policy: Arc<BaggageAllowancePolicy>,
+        boarding_pass_signer: Arc<dyn BoardingPassSigner>,
         event_publisher: Arc<dyn CheckinEventPublisher>,
     ) -> Self {
         Self {
@@ -56,6 +81,11 @@ impl CheckinService {
             flight_repo,
             passenger_repo,
             boarding_pass_repo,
+            airport_repo,
+            document_policy,
+            boarding_group_policy,
+            baggage_allowance_policy,
+            boarding_pass_signer,
             event_publisher,
         }
     }
@@ -94,17 +124,153 @@ impl CheckinService {
         }
     }
 
-    fn normalize_baggage_weight_unit(value: &str) -> UseCaseResult<String> {
-        let normalized = value.trim().to_uppercase();
-        match normalized.as_str() {
-            "KG" | "LB" => Ok(normalized),
-            _ => Err(UseCaseError::Domain(DomainError::Checkin(
-                CheckinDomainError::Validation {
-                    field: "baggage_weight_unit",
-                    message: format!("Invalid baggage_weight_unit: {value}"),
+    fn parse_piece_weights(values: &[Decimal]) -> UseCaseResult<Vec<f64>> {
+        values
+            .iter()
+            .map(|v| {
+                v.to_f64().ok_or_else(|| {
+                    UseCaseError::Domain(DomainError::Checkin(CheckinDomainError::Validation {
+                        field: "baggage_piece_weights",
+                        message: "Invalid bag weight".to_string(),
+                    }))
+                })
+            })
+            .collect()
+    }
+
+    fn assess_baggage(&self, checkin: &mut Checkin, booking: &Booking) -> UseCaseResult<()> {
+        let unit = WeightUnit::parse(&checkin.baggage_weight_unit)?;
+        let weight = Decimal::from_f64_retain(checkin.baggage_weight_total)
+            .map(|w| w.round_dp(3))
+            .ok_or_else(|| {
+                UseCaseError::Domain(DomainError::Checkin(CheckinDomainError::Validation {
+                    field: "baggage_weight_total",
+                    message: "Invalid baggage weight total".to_string(),
+                }))
+            })?;
+        let piece_weights: Vec<Decimal> = checkin
+            .baggage_piece_weights
+            .iter()
+            .filter_map(|w| Decimal::from_f64_retain(*w).map(|w| w.round_dp(3)))
+            .collect();
+
+        let assessment = self.baggage_allowance_policy.assess(
+            &checkin.seat_class,
+            booking.fare_family,
+            checkin.baggage_count,
+            weight,
+            &piece_weights,
+            unit,
+        )?;
+        checkin.apply_baggage_assessment(assessment)?;
+        Ok(())
+    }
+
+    // Checks the passenger in and builds a signed boarding pass for the check-in.
+    async fn prepare_boarding_pass(
+        &self,
+        booking: &Booking,
+        flight: &Flight,
+        passenger: &Passenger,
+        route: &(Airport, Airport),
+        checkin: &mut Checkin,
+        now: chrono::NaiveDateTime,
+    ) -> UseCaseResult<BoardingPass> {
+        let (origin, destination) = route;
+        let seat_no = checkin
+            .seat_no
+            .clone()
+            .unwrap_or_else(|| format!("AUTO-{}", passenger.id));
+        checkin.check_in(seat_no, now)?;
+
+        let uuid = Uuid::new_v4().to_string().replace('-', "").to_uppercase();
+        let boarding_pass_code = uuid[..10].to_string();
+        let mut boarding_pass = BoardingPass::new(
+            CreateBoardingPassProps {
+                // zero until the check-in row exists
+                checkin_id: checkin.id,
+                boarding_pass_code: boarding_pass_code.clone(),
+                barcode_format: "QR".to_string(),
+                barcode_payload: None,
+                issued_at: now,
+            },
+            checkin.status.clone(),
+        )?;
+        let sequence = self
+            .flight_repo
+            .next_boarding_sequence(flight.id)
+            .await
+            .map_err(|e| UseCaseError::Unexpected(e.to_string()))?;
+        let boarding_group = self.boarding_group_policy.group_for(&BoardingGroupInput {
+            seat_class: &checkin.seat_class,
+            fare_family: booking.fare_family,
+            operating_carrier: &flight.airline_code,
+            ff_airline_code: passenger.ff_airline_code.as_deref(),
+            ff_number: passenger.ff_number.as_deref(),
+            seat_no: checkin.seat_no.as_deref(),
+        });
+        boarding_pass.assign_boarding_position(boarding_group, sequence);
+
+        let mut bcbp = build_bcbp(
+            passenger,
+            &[BcbpLegSource {
+                booking_code: &booking.booking_code,
+                flight,
+                marketing_carrier: &booking.marketing_airline_code,
+                origin_iata_code: &origin.iata_code,
+                destination_iata_code: &destination.iata_code,
+                checkin,
+                boarding_pass: Some(&boarding_pass),
+            }],
+            &boarding_pass_code,
+            now,
+        )?;
+        self.boarding_pass_signer.sign(&mut bcbp)?;
+        boarding_pass.barcode_payload = Some(encoder::encode(&bcbp)?);
+
+        Ok(boarding_pass)
+    }
+
+    // Finishes a check-in held back by excess baggage once nothing is left to pay.
+    async fn complete_pending_checkin(
+        &self,
+        booking: &Booking,
+        checkin: &mut Checkin,
+        now: chrono::NaiveDateTime,
+    ) -> UseCaseResult<BoardingPass> {
+        if booking.status != BookingStatus::Confirmed {
+            return Err(UseCaseError::Domain(DomainError::Checkin(
+                CheckinDomainError::BusinessRule {
+                    message: "Booking must be CONFIRMED to check in".to_string(),
                 },
-            ))),
+            )));
         }
+
+        let flight = self
+            .flight_repo
+            .find_flight_by_id(booking.flight_id)
+            .await
+            .map_err(|e| UseCaseError::Unexpected(e.to_string()))?
+            .ok_or_else(|| {
+                UseCaseError::NotFound(format!("Flight with id {} not found", booking.flight_id))
+            })?;
+        Self::ensure_checkin_window(now, &flight)?;
+
+        let passenger = self
+            .passenger_repo
+            .find_passenger_by_id_and_booking(checkin.passenger_id, checkin.booking_id)
+            .await
+            .map_err(|e| UseCaseError::Unexpected(e.to_string()))?
+            .ok_or_else(|| {
+                UseCaseError::NotFound(format!(
+                    "Passenger {} not found in booking {}",
+                    checkin.passenger_id, checkin.booking_id
+                ))
+            })?;
+        let route = self.load_route_airports(&flight).await?;
+
+        self.prepare_boarding_pass(booking, &flight, &passenger, &route, checkin, now)
+            .await
     }
 
     fn ensure_owner_or_admin(
@@ -118,7 +284,73 @@ impl CheckinService {
         Ok(())
     }
 
-    fn ensure_checkin_window(now: chrono::NaiveDateTime, flight: &crate::domain::flight::entity::Flight) -> UseCaseResult<()> {
+    async fn load_route_airports(&self, flight: &Flight) -> UseCaseResult<(Airport, Airport)> {
+        let origin = self
+            .airport_repo
+            .find_airport_by_id(flight.origin_airport_id)
+            .await
+            .map_err(|e| UseCaseError::Unexpected(e.to_string()))?
+            .ok_or_else(|| {
+                UseCaseError::NotFound(format!(
+                    "Airport with id {} not found",
+                    flight.origin_airport_id
+                ))
+            })?;
+        let destination = self
+            .airport_repo
+            .find_airport_by_id(flight.destination_airport_id)
+            .await
+            .map_err(|e| UseCaseError::Unexpected(e.to_string()))?
+            .ok_or_else(|| {
+                UseCaseError::NotFound(format!(
+                    "Airport with id {} not found",
+                    flight.destination_airport_id
+                ))
+            })?;
+
+        Ok((origin, destination))
+    }
+
+    fn ensure_travel_documents(
+        &self,
+        flight: &Flight,
+        origin: &Airport,
+        destination: &Airport,
+        passenger: &Passenger,
+        has_valid_visa: bool,
+    ) -> UseCaseResult<()> {
+        let requirements = self.document_policy.requirements_for(
+            &passenger.nationality_code,
+            &origin.country_code,
+            &destination.country_code,
+            flight.departure_date,
+        );
+
+        TravelDocumentsMustSatisfyRequirements {
+            passenger,
+            requirements: &requirements,
+            has_valid_visa,
+        }
+        .check_broken()?;
+
+        Ok(())
+    }
+
+    // A visa is only as good as the agent who inspected it, so customers checking in
+    // online cannot vouch for their own.
+    fn visa_verified(ctx: &RequestContext, has_valid_visa: bool) -> UseCaseResult<bool> {
+        if has_valid_visa && !ctx.is_admin() && !ctx.is_staff() {
+            return Err(UseCaseError::Domain(DomainError::Passenger(
+                PassengerDomainError::Validation {
+                    field: "has_valid_visa",
+                    message: "A visa can only be confirmed by check-in staff".to_string(),
+                },
+            )));
+        }
+        Ok(has_valid_visa)
+    }
+
+    fn ensure_checkin_window(now: chrono::NaiveDateTime, flight: &Flight) -> UseCaseResult<()> {
         let open_at = flight.checkin_open_at.ok_or_else(|| {
             UseCaseError::Domain(DomainError::Checkin(CheckinDomainError::BusinessRule {
                 message: "Flight check-in window is not configured".to_string(),
@@ -203,6 +435,10 @@ impl CheckinServiceInterface for CheckinService {
                 ))
             })?;
 
+        let has_valid_visa = Self::visa_verified(&ctx, command.has_valid_visa)?;
+        let route = self.load_route_airports(&flight).await?;
+        self.ensure_travel_documents(&flight, &route.0, &route.1, &passenger, has_valid_visa)?;
+
         let existing = self
             .checkin_repo
             .find_checkin_by_booking_and_passenger(command.booking_id, command.passenger_id)
@@ -218,8 +454,9 @@ impl CheckinServiceInterface for CheckinService {
 
         let seat_class = Self::parse_seat_class(&command.seat_class)?;
         let checkin_channel = Self::parse_checkin_channel(&command.checkin_channel)?;
-        let baggage_weight_unit =
-            Self::normalize_baggage_weight_unit(&command.baggage_weight_unit)?;
+        let baggage_weight_unit = WeightUnit::parse(&command.baggage_weight_unit)?
+            .code()
+            .to_string();
         let baggage_weight_total = command.baggage_weight_total.to_f64().ok_or_else(|| {
             UseCaseError::Domain(DomainError::Checkin(CheckinDomainError::Validation {
                 field: "baggage_weight_total",
@@ -235,46 +472,45 @@ impl CheckinServiceInterface for CheckinService {
             baggage_count: command.baggage_count,
             baggage_weight_total,
             baggage_weight_unit,
+            baggage_piece_weights: Self::parse_piece_weights(&command.baggage_piece_weights)?,
             checkin_channel,
             checked_in_ip: command.checked_in_ip,
         };
 
         let mut checkin = checkin::entity::Checkin::new(props, now)?;
-        let seat_no = command
-            .seat_no
-            .unwrap_or_else(|| format!("AUTO-{}", passenger.id));
-        checkin.check_in(seat_no, now)?;
+        checkin.seat_no = command.seat_no;
+        self.assess_baggage(&mut checkin, &booking)?;
+
+        // Excess baggage must be paid before the passenger is checked in; until then the
+        // check-in stays PENDING without a boarding pass.
+        let boarding_pass = if checkin.has_unpaid_excess_baggage() {
+            None
+        } else {
+            Some(
+                self.prepare_boarding_pass(
+                    &booking,
+                    &flight,
+                    &passenger,
+                    &route,
+                    &mut checkin,
+                    now,
+                )
+                .await?,
+            )
+        };
 
         let new_id = self
             .checkin_repo
             .create_checkin(&checkin)
             .await
             .map_err(|e| UseCaseError::Unexpected(e.to_string()))?;
-
-        let uuid = Uuid::new_v4().to_string().replace('-', "").to_uppercase();
-        let boarding_pass_code = uuid[..10].to_string();
-        let barcode_payload = json!({
-            "booking_id": booking.id,
-            "passenger_id": passenger.id,
-            "checkin_id": new_id,
-            "flight_id": booking.flight_id,
-            "seat_no": checkin.seat_no,
-        })
-        .to_string();
-        let boarding_pass = crate::domain::boarding_pass::entity::BoardingPass::new(
-            CreateBoardingPassProps {
-                checkin_id: new_id,
-                boarding_pass_code,
-                barcode_format: "QR".to_string(),
-                barcode_payload: Some(barcode_payload),
-                issued_at: now,
-            },
-            checkin.status.clone(),
-        )?;
-        self.boarding_pass_repo
-            .create_boarding_pass(&boarding_pass)
-            .await
-            .map_err(|e| UseCaseError::Unexpected(e.to_string()))?;
+        if let Some(mut boarding_pass) = boarding_pass {
+            boarding_pass.checkin_id = new_id;
+            self.boarding_pass_repo
+                .create_boarding_pass(&boarding_pass)
+                .await
+                .map_err(|e| UseCaseError::Unexpected(e.to_string()))?;
+        }
 
         let cache_key = Self::checkin_cache_key(command.booking_id, command.passenger_id);
         if let Err(err) = self.cache.del(&cache_key).await {
@@ -338,17 +574,42 @@ impl CheckinServiceInterface for CheckinService {
             seat_no: command.seat_no,
             baggage_count: command.baggage_count,
             baggage_weight_total,
+            baggage_piece_weights: command
+                .baggage_piece_weights
+                .as_deref()
+                .map(Self::parse_piece_weights)
+                .transpose()?,
         };
         checkin.update_from(update_props)?;
 
         if let Some(seat_class) = command.seat_class {
             checkin.seat_class = Self::parse_seat_class(&seat_class)?;
         }
+        self.assess_baggage(&mut checkin, &booking)?;
+
+        // Dropping bags may clear the excess charge, which releases the held check-in.
+        let boarding_pass = if checkin.has_unpaid_excess_baggage() {
+            None
+        } else {
+            let now = chrono::Utc::now().naive_utc();
+            Some(
+                self.complete_pending_checkin(&booking, &mut checkin, now)
+                    .await?,
+            )
+        };
 
-        self.checkin_repo
-            .update_checkin(&checkin, expected_version)
-            .await
-            .map_err(|e| UseCaseError::Unexpected(e.to_string()))?;
+        match boarding_pass {
+            Some(boarding_pass) => self
+                .checkin_repo
+                .complete_checkin(&checkin, expected_version, &boarding_pass)
+                .await
+                .map_err(|e| UseCaseError::Unexpected(e.to_string()))?,
+            None => self
+                .checkin_repo
+                .update_checkin(&checkin, expected_version)
+                .await
+                .map_err(|e| UseCaseError::Unexpected(e.to_string()))?,
+        }
 
         let cache_key = Self::checkin_cache_key(checkin.booking_id, checkin.passenger_id);
         if let Err(err) = self.cache.del(&cache_key).await {
@@ -360,6 +621,63 @@ impl CheckinServiceInterface for CheckinService {
         Ok(true)
     }
 
+    async fn pay_excess_baggage(
+        &self,
+        ctx: RequestContext,
+        id: i64,
+        command: PayExcessBaggageCommand,
+    ) -> UseCaseResult<bool> {
+        // Only the desk or the payment provider's callback can confirm a payment; a passenger
+        // must not be able to clear their own fee with a made-up transaction id.
+        if !ctx.is_admin() && !ctx.is_staff() && !ctx.is_system() {
+            return Err(UseCaseError::PermissionDenied);
+        }
+
+        if command.payment_txn_id.trim().is_empty() {
+            return Err(UseCaseError::Domain(DomainError::Checkin(
+                CheckinDomainError::Validation {
+                    field: "payment_txn_id",
+                    message: "Payment transaction id is required".to_string(),
+                },
+            )));
+        }
+
+        let mut checkin = self
+            .checkin_repo
+            .find_checkin_by_id(id)
+            .await
+            .map_err(|e| UseCaseError::Unexpected(e.to_string()))?
+            .ok_or_else(|| UseCaseError::NotFound(format!("Checkin with id {} not found", id)))?;
+
+        let booking = self
+            .booking_repo
+            .find_booking_by_id(checkin.booking_id)
+            .await
+            .map_err(|e| UseCaseError::Unexpected(e.to_string()))?
+            .ok_or_else(|| {
+                UseCaseError::NotFound(format!("Booking with id {} not found", checkin.booking_id))
+            })?;
+
+        let expected_version = checkin.version;
+        let now = chrono::Utc::now().naive_utc();
+        checkin.pay_excess_baggage(command.payment_txn_id.trim().to_string(), now)?;
+        let boarding_pass = self
+            .complete_pending_checkin(&booking, &mut checkin, now)
+            .await?;
+
+        self.checkin_repo
+            .complete_checkin(&checkin, expected_version, &boarding_pass)
+            .await
+            .map_err(UseCaseError::Domain)?;
+
+        let cache_key = Self::checkin_cache_key(checkin.booking_id, checkin.passenger_id);
+        if let Err(err) = self.cache.del(&cache_key).await {
+            tracing::warn!("cache del failed key={}: {}", cache_key, err);
+        }
+
+        Ok(true)
+    }
+
     async fn cancel_checkin(
         &self,
         ctx: RequestContext,
@@ -391,6 +709,28 @@ impl CheckinServiceInterface for CheckinService {
             .await
             .map_err(|e| UseCaseError::Unexpected(e.to_string()))?;
 
+        // A cancelled check-in must not board; revoke the pass so gate verification rejects it.
+        let boarding_pass = self
+            .boarding_pass_repo
+            .find_boarding_pass_by_checkin(checkin.id)
+            .await
+            .map_err(|e| UseCaseError::Unexpected(e.to_string()))?;
+        if let Some(boarding_pass) = boarding_pass.filter(|bp| !bp.is_revoked()) {
+            self.boarding_pass_repo
+                .revoke_boarding_pass(boarding_pass.id, chrono::Utc::now().naive_utc())
+                .await
+                .map_err(|e| UseCaseError::Unexpected(e.to_string()))?;
+
+            for key in [
+                format!("boarding_pass:checkin:{}", checkin.id),
+                format!("boarding_pass:code:{}", boarding_pass.boarding_pass_code),
+            ] {
+                if let Err(err) = self.cache.del(&key).await {
+                    tracing::warn!("cache del failed key={}: {}", key, err);
+                }
+            }
+        }
+
         let cache_key = Self::checkin_cache_key(checkin.booking_id, checkin.passenger_id);
         if let Err(err) = self.cache.del(&cache_key).await {
             tracing::warn!("cache del failed key={}: {}", cache_key, err);
@@ -456,7 +796,9 @@ impl CheckinServiceInterface for CheckinService {
             .find_booking_by_id(booking_id)
             .await
             .map_err(|e| UseCaseError::Unexpected(e.to_string()))?
-            .ok_or_else(|| UseCaseError::NotFound(format!("Booking with id {} not found", booking_id)))?;
+            .ok_or_else(|| {
+                UseCaseError::NotFound(format!("Booking with id {} not found", booking_id))
+            })?;
         Self::ensure_owner_or_admin(&ctx, actor_user_id, booking.user_id)?;
 
         let passengers = self
@@ -468,7 +810,9 @@ impl CheckinServiceInterface for CheckinService {
         let mut results = Vec::new();
         for passenger in passengers {
             let cache_key = Self::checkin_cache_key(booking_id, passenger.id);
-            if let Ok(Some(cached)) = cache_get_json::<CheckinView>(self.cache.as_ref(), &cache_key).await {
+            if let Ok(Some(cached)) =
+                cache_get_json::<CheckinView>(self.cache.as_ref(), &cache_key).await
+            {
                 results.push(cached);
                 continue;
             }
@@ -491,3 +835,42 @@ impl CheckinServiceInterface for CheckinService {
         Ok(results)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::context::request_context::{Actor, UserRole};
+    use uuid::Uuid;
+
+    fn ctx(role: UserRole) -> RequestContext {
+        RequestContext {
+            request_id: "test".to_string(),
+            actor: Actor::User {
+                id: 1,
+                session_id: Uuid::nil(),
+                role,
+            },
+            ip_address: None,
+            user_agent: None,
+        }
+    }
+
+    #[test]
+    fn a_customer_cannot_self_attest_a_visa() {
+        let err = CheckinService::visa_verified(&ctx(UserRole::Customer), true).unwrap_err();
+        assert!(matches!(
+            err,
+            UseCaseError::Domain(DomainError::Passenger(PassengerDomainError::Validation {
+                field: "has_valid_visa",
+                ..
+            }))
+        ));
+        assert!(!CheckinService::visa_verified(&ctx(UserRole::Customer), false).unwrap());
+    }
+
+    #[test]
+    fn staff_and_admins_confirm_a_visa() {
+        assert!(CheckinService::visa_verified(&ctx(UserRole::Staff), true).unwrap());
+        assert!(CheckinService::visa_verified(&ctx(UserRole::Admin), true).unwrap());
+    }
+}
@@ -1,5 +1,5 @@
 use crate::application::checkin::checkin_command::{
-    CancelCheckinCommand, CreateCheckinCommand, UpdateCheckinCommand,
+    CancelCheckinCommand, CreateCheckinCommand, PayExcessBaggageCommand, UpdateCheckinCommand,
 };
 use crate::application::checkin::view::checkin_view::CheckinView;
 use crate::application::common::use_case_error::UseCaseResult;
@@ -20,6 +20,14 @@ pub trait CheckinServiceInterface: Send + Sync {
         command: UpdateCheckinCommand,
     ) -> UseCaseResult<bool>;
 
+    /// Records payment of the excess baggage fee and completes the held check-in.
+    async fn pay_excess_baggage(
+        &self,
+        ctx: RequestContext,
+        id: i64,
+        command: PayExcessBaggageCommand,
+    ) -> UseCaseResult<bool>;
+
     async fn cancel_checkin(
         &self,
         ctx: RequestContext,
@@ -11,7 +11,16 @@ pub struct CheckinView {
     pub baggage_count: i32,
     pub baggage_weight_total: String,
     pub baggage_weight_unit: String,
+    pub baggage_piece_weights: Vec<String>,
+    pub baggage_allowance: Option<String>,
+    pub excess_baggage_pieces: i32,
+    pub excess_baggage_weight_kg: String,
+    pub excess_baggage_fee: String,
+    pub excess_baggage_currency: Option<String>,
+    pub excess_baggage_paid_at: Option<String>,
+    pub excess_baggage_payment_required: bool,
     pub checked_in_at: Option<String>,
+    pub boarded_at: Option<String>,
     pub checkin_channel: String,
     pub checked_in_ip: Option<String>,
     pub version: i32,
@@ -6,4 +6,5 @@ pub trait CacheInterface: Send + Sync {
     async fn get(&self, key: &str) -> UseCaseResult<Option<String>>;
     async fn set_ex(&self, key: &str, value: &str, ttl_secs: u64) -> UseCaseResult<()>;
     async fn del(&self, key: &str) -> UseCaseResult<bool>;
+    async fn del_many(&self, keys: &[String]) -> UseCaseResult<u64>;
 }
@@ -2,19 +2,26 @@ use crate::application::common::use_case_error::UseCaseError;
 use crate::domain::address::events::address_created::AddressCreatedEvent;
 use crate::domain::address::events::address_deleted::AddressDeletedEvent;
 use crate::domain::address::events::address_updated::AddressUpdatedEvent;
+use crate::domain::airline::events::airline_created::AirlineCreatedEvent;
+use crate::domain::airline::events::airline_deactivated::AirlineDeactivatedEvent;
+use crate::domain::airline::events::airline_updated::AirlineUpdatedEvent;
 use crate::domain::airport::events::airport_created::AirportCreatedEvent;
 use crate::domain::airport::events::airport_deactivated::AirportDeactivatedEvent;
 use crate::domain::airport::events::airport_updated::AirportUpdatedEvent;
+use crate::domain::baggage::events::bag_status_changed::BagStatusChangedEvent;
 use crate::domain::boarding_pass::events::boarding_pass_issued::BoardingPassIssuedEvent;
 use crate::domain::booking::events::booking_cancelled::BookingCancelledEvent;
 use crate::domain::booking::events::booking_confirmed::BookingConfirmedEvent;
 use crate::domain::booking::events::booking_created::BookingCreatedEvent;
 use crate::domain::checkin::events::checkin_cancelled::CheckinCancelledEvent;
 use crate::domain::checkin::events::checkin_created::CheckinCreatedEvent;
+use crate::domain::compensation::events::compensation_claim_decided::CompensationClaimDecidedEvent;
+use crate::domain::compensation::events::compensation_claim_submitted::CompensationClaimSubmittedEvent;
 use crate::domain::flight::events::flight_cancelled::FlightCancelledEvent;
 use crate::domain::flight::events::flight_created::FlightCreatedEvent;
 use crate::domain::flight::events::flight_updated::FlightUpdatedEvent;
 use crate::domain::passenger::events::passenger_added::PassengerAddedEvent;
+use crate::domain::passenger::events::passenger_no_show::PassengerNoShowEvent;
 use crate::domain::passenger::events::passenger_removed::PassengerRemovedEvent;
 use crate::domain::passenger::events::passenger_updated::PassengerUpdatedEvent;
 use crate::domain::user::events::user_activated::UserActivatedEvent;
@@ -51,6 +58,16 @@ pub trait AirportEventPublisher: Send + Sync {
     -> Result<(), UseCaseError>;
 }
 
+#[async_trait::async_trait]
+pub trait AirlineEventPublisher: Send + Sync {
+    async fn publish_airline_created(&self, event: AirlineCreatedEvent)
+    -> Result<(), UseCaseError>;
+    async fn publish_airline_updated(&self, event: AirlineUpdatedEvent)
+    -> Result<(), UseCaseError>;
+    async fn publish_airline_deactivated(&self, event: AirlineDeactivatedEvent)
+    -> Result<(), UseCaseError>;
+}
+
 #[async_trait::async_trait]
 pub trait FlightEventPublisher: Send + Sync {
     async fn publish_flight_created(&self, event: FlightCreatedEvent)
@@ -79,6 +96,8 @@ pub trait PassengerEventPublisher: Send + Sync {
     -> Result<(), UseCaseError>;
     async fn publish_passenger_removed(&self, event: PassengerRemovedEvent)
     -> Result<(), UseCaseError>;
+    async fn publish_passenger_no_show(&self, event: PassengerNoShowEvent)
+    -> Result<(), UseCaseError>;
 }
 
 #[async_trait::async_trait]
@@ -94,3 +113,21 @@ pub trait BoardingPassEventPublisher: Send + Sync {
     async fn publish_boarding_pass_issued(&self, event: BoardingPassIssuedEvent)
     -> Result<(), UseCaseError>;
 }
+
+#[async_trait::async_trait]
+pub trait BaggageEventPublisher: Send + Sync {
+    async fn publish_bag_status_changed(&self, event: BagStatusChangedEvent)
+    -> Result<(), UseCaseError>;
+}
+
+#[async_trait::async_trait]
+pub trait CompensationEventPublisher: Send + Sync {
+    async fn publish_claim_submitted(
+        &self,
+        event: CompensationClaimSubmittedEvent,
+    ) -> Result<(), UseCaseError>;
+    async fn publish_claim_decided(
+        &self,
+        event: CompensationClaimDecidedEvent,
+    ) -> Result<(), UseCaseError>;
+}
@@ -0,0 +1,11 @@
+#[derive(Debug, Clone)]
+pub struct SubmitCompensationClaimCommand {
+    pub booking_code: String,
+    pub note: Option<String>,
+}
+
+/// Approval or rejection by customer service; rejections must carry a note.
+#[derive(Debug, Clone)]
+pub struct DecideCompensationClaimCommand {
+    pub note: Option<String>,
+}
@@ -0,0 +1,101 @@
+use crate::application::compensation::view::compensation_view::{
+    CompensationAssessmentView, CompensationClaimView,
+};
+use crate::application::flight::mapper::flight_mapper::flight_status_to_string;
+use crate::domain::compensation::compensation_policy::{
+    CompensationAssessment, CompensationReason, Ineligibility,
+};
+use crate::domain::compensation::entity::{CompensationClaim, CompensationClaimStatus};
+use crate::domain::flight::entity::Flight;
+use rust_decimal::Decimal;
+
+impl CompensationAssessmentView {
+    pub fn new(
+        booking_code: String,
+        flight: &Flight,
+        assessment: CompensationAssessment,
+        passenger_count: i32,
+        claim_id: Option<i64>,
+    ) -> Self {
+        Self {
+            booking_code,
+            flight_id: flight.id,
+            flight_key: flight.flight_key.clone(),
+            flight_status: flight_status_to_string(&flight.status),
+            eligible: assessment.is_eligible(),
+            reason: assessment
+                .reason
+                .as_ref()
+                .map(compensation_reason_to_string),
+            ineligibility: assessment
+                .ineligibility
+                .as_ref()
+                .map(ineligibility_to_string),
+            ineligibility_detail: assessment.ineligibility.map(|i| i.describe().to_string()),
+            distance_km: assessment.distance_km,
+            band_from_km: assessment.band_from_km,
+            band_to_km: assessment.band_to_km,
+            arrival_delay_minutes: assessment.arrival_delay_minutes,
+            reduced: assessment.reduced,
+            passenger_count,
+            amount_per_passenger: assessment.amount_per_passenger.to_string(),
+            total_amount: (assessment.amount_per_passenger * Decimal::from(passenger_count))
+                .to_string(),
+            currency: assessment.currency,
+            claim_id,
+        }
+    }
+}
+
+pub fn claim_to_view(claim: CompensationClaim) -> CompensationClaimView {
+    CompensationClaimView {
+        id: claim.id,
+        booking_id: claim.booking_id,
+        flight_id: claim.flight_id,
+        user_id: claim.user_id,
+        reason: compensation_reason_to_string(&claim.reason),
+        status: claim_status_to_string(&claim.status),
+        distance_km: claim.distance_km,
+        arrival_delay_minutes: claim.arrival_delay_minutes,
+        passenger_count: claim.passenger_count,
+        amount_per_passenger: claim.amount_per_passenger.to_string(),
+        total_amount: claim.total_amount.to_string(),
+        currency: claim.currency,
+        customer_note: claim.customer_note,
+        decision_note: claim.decision_note,
+        decided_by: claim.decided_by,
+        submitted_at: claim.submitted_at.format("%Y-%m-%dT%H:%M:%S").to_string(),
+        decided_at: claim
+            .decided_at
+            .map(|v| v.format("%Y-%m-%dT%H:%M:%S").to_string()),
+        version: claim.version,
+    }
+}
+
+pub fn compensation_reason_to_string(value: &CompensationReason) -> String {
+    match value {
+        CompensationReason::Delay => "DELAY",
+        CompensationReason::Cancellation => "CANCELLATION",
+    }
+    .to_string()
+}
+
+pub fn claim_status_to_string(value: &CompensationClaimStatus) -> String {
+    match value {
+        CompensationClaimStatus::Submitted => "SUBMITTED",
+        CompensationClaimStatus::Approved => "APPROVED",
+        CompensationClaimStatus::Rejected => "REJECTED",
+    }
+    .to_string()
+}
+
+fn ineligibility_to_string(value: &Ineligibility) -> String {
+    match value {
+        Ineligibility::NotCovered => "NOT_COVERED",
+        Ineligibility::NotArrived => "NOT_ARRIVED",
+        Ineligibility::BelowDelayThreshold => "BELOW_DELAY_THRESHOLD",
+        Ineligibility::CancellationNoticeGiven => "CANCELLATION_NOTICE_GIVEN",
+        Ineligibility::ExtraordinaryCircumstances => "EXTRAORDINARY_CIRCUMSTANCES",
+    }
+    .to_string()
+}
@@ -0,0 +1 @@
+pub mod compensation_mapper;
\ No newline at end of file
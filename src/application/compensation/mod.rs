@@ -0,0 +1,4 @@
+pub mod compensation_command;
+pub mod mapper;
+pub mod use_case;
+pub mod view;
\ No newline at end of file
@@ -0,0 +1,435 @@
+use crate::application::common::event_publisher::CompensationEventPublisher;
+use crate::application::common::use_case_error::{UseCaseError, UseCaseResult};
+use crate::application::compensation::compensation_command::{
+    DecideCompensationClaimCommand, SubmitCompensationClaimCommand,
+};
+use crate::application::compensation::mapper::compensation_mapper::{
+    claim_status_to_string, claim_to_view, compensation_reason_to_string,
+};
+use crate::application::compensation::use_case::compensation_service_interface::CompensationServiceInterface;
+use crate::application::compensation::view::compensation_view::{
+    CompensationAssessmentView, CompensationClaimView,
+};
+use crate::core::context::request_context::RequestContext;
+use crate::domain::airline::airline_repository_interface::AirlineRepositoryInterface;
+use crate::domain::airport::airport_repository_interface::AirportRepositoryInterface;
+use crate::domain::airport::entity::Airport;
+use crate::domain::booking::booking_repository_interface::BookingRepositoryInterface;
+use crate::domain::booking::entity::{Booking, BookingStatus};
+use crate::domain::compensation::compensation_claim_repository_interface::CompensationClaimRepositoryInterface;
+use crate::domain::compensation::compensation_policy::{
+    CompensationAssessment, CompensationPolicy, DisruptedFlight,
+};
+use crate::domain::compensation::entity::{
+    CompensationClaim, CompensationClaimStatus, SubmitCompensationClaimProps,
+};
+use crate::domain::compensation::error::CompensationDomainError;
+use crate::domain::compensation::events::compensation_claim_decided::CompensationClaimDecidedEvent;
+use crate::domain::compensation::events::compensation_claim_submitted::CompensationClaimSubmittedEvent;
+use crate::domain::error::DomainError;
+use crate::domain::flight::entity::{Flight, FlightStatus};
+use crate::domain::flight::flight_repository_interface::FlightRepositoryInterface;
+use crate::domain::passenger::entity::Passenger;
+use crate::domain::passenger::passenger_repository_interface::PassengerRepositoryInterface;
+use crate::domain::route::route_repository_interface::RouteRepositoryInterface;
+use std::sync::Arc;
+
+pub struct CompensationService {
+    pub claim_repo: Arc<dyn CompensationClaimRepositoryInterface>,
+    pub booking_repo: Arc<dyn BookingRepositoryInterface>,
+    pub flight_repo: Arc<dyn FlightRepositoryInterface>,
+    pub passenger_repo: Arc<dyn PassengerRepositoryInterface>,
+    pub airport_repo: Arc<dyn AirportRepositoryInterface>,
+    pub airline_repo: Arc<dyn AirlineRepositoryInterface>,
+    pub route_repo: Arc<dyn RouteRepositoryInterface>,
+    pub policy: Arc<CompensationPolicy>,
+    pub event_publisher: Arc<dyn CompensationEventPublisher>,
+}
+
+impl CompensationService {
+    pub fn new(
+        claim_repo: Arc<dyn CompensationClaimRepositoryInterface>,
+        booking_repo: Arc<dyn BookingRepositoryInterface>,
+        flight_repo: Arc<dyn FlightRepositoryInterface>,
+        passenger_repo: Arc<dyn PassengerRepositoryInterface>,
+        airport_repo: Arc<dyn AirportRepositoryInterface>,
+        airline_repo: Arc<dyn AirlineRepositoryInterface>,
+        route_repo: Arc<dyn RouteRepositoryInterface>,
+        policy: Arc<CompensationPolicy>,
+        event_publisher: Arc<dyn CompensationEventPublisher>,
+    ) -> Self {
+        Self {
+            claim_repo,
+            booking_repo,
+            flight_repo,
+            passenger_repo,
+            airport_repo,
+            airline_repo,
+            route_repo,
+            policy,
+            event_publisher,
+        }
+    }
+
+    // Claims are worked by customer service staff; admins can step in.
+    fn ensure_claims_agent(ctx: &RequestContext) -> UseCaseResult<i64> {
+        let user_id = ctx.user_id().ok_or(UseCaseError::PermissionDenied)?;
+        if !ctx.is_admin() && !ctx.is_staff() {
+            return Err(UseCaseError::PermissionDenied);
+        }
+        Ok(user_id)
+    }
+
+    fn ensure_booking_access(ctx: &RequestContext, booking: &Booking) -> UseCaseResult<()> {
+        let actor_user_id = ctx.user_id().ok_or(UseCaseError::PermissionDenied)?;
+        if !ctx.is_admin() && !ctx.is_staff() && actor_user_id != booking.user_id {
+            return Err(UseCaseError::PermissionDenied);
+        }
+        Ok(())
+    }
+
+    fn parse_status(value: &str) -> UseCaseResult<CompensationClaimStatus> {
+        match value.trim().to_uppercase().as_str() {
+            "SUBMITTED" => Ok(CompensationClaimStatus::Submitted),
+            "APPROVED" => Ok(CompensationClaimStatus::Approved),
+            "REJECTED" => Ok(CompensationClaimStatus::Rejected),
+            _ => Err(UseCaseError::Domain(DomainError::Compensation(
+                CompensationDomainError::Validation {
+                    field: "status",
+                    message: format!("Invalid status: {value}"),
+                },
+            ))),
+        }
+    }
+
+    fn normalize_note(note: Option<String>) -> Option<String> {
+        note.map(|v| v.trim().to_string()).filter(|v| !v.is_empty())
+    }
+
+    async fn load_booking_by_code(&self, booking_code: &str) -> UseCaseResult<Booking> {
+        let code = booking_code.trim().to_uppercase();
+        self.booking_repo
+            .find_booking_by_code(&code)
+            .await
+            .map_err(|e| UseCaseError::Unexpected(e.to_string()))?
+            .ok_or_else(|| UseCaseError::NotFound(format!("Booking with code {} not found", code)))
+    }
+
+    async fn load_claim(&self, id: i64) -> UseCaseResult<CompensationClaim> {
+        self.claim_repo
+            .find_claim_by_id(id)
+            .await
+            .map_err(|e| UseCaseError::Unexpected(e.to_string()))?
+            .ok_or_else(|| {
+                UseCaseError::Domain(DomainError::Compensation(
+                    CompensationDomainError::ClaimNotFound { claim_id: id },
+                ))
+            })
+    }
+
+    async fn load_airport(&self, airport_id: i64) -> UseCaseResult<Airport> {
+        self.airport_repo
+            .find_airport_by_id(airport_id)
+            .await
+            .map_err(|e| UseCaseError::Unexpected(e.to_string()))?
+            .ok_or_else(|| {
+                UseCaseError::NotFound(format!("Airport with id {} not found", airport_id))
+            })
+    }
+
+    // The route network holds the measured distance; older flights fall back to coordinates.
+    async fn distance_km(
+        &self,
+        flight: &Flight,
+        origin: &Airport,
+        destination: &Airport,
+    ) -> UseCaseResult<i32> {
+        if let Some(route_id) = flight.route_id {
+            let route = self
+                .route_repo
+                .find_route_by_id(route_id)
+                .await
+                .map_err(|e| UseCaseError::Unexpected(e.to_string()))?;
+            if let Some(route) = route {
+                return Ok(route.distance_km);
+            }
+        }
+
+        match (origin.location(), destination.location()) {
+            (Some(from), Some(to)) => Ok(from.distance_km(&to).round() as i32),
+            _ => Err(UseCaseError::BusinessRule(format!(
+                "Distance of flight {} is unknown: airport coordinates are missing",
+                flight.flight_key
+            ))),
+        }
+    }
+
+    /// Runs the compensation rules for a booking's flight; also returns the number of
+    /// passengers the amount is owed to.
+    async fn assess(
+        &self,
+        booking: &Booking,
+    ) -> UseCaseResult<(Flight, CompensationAssessment, i32)> {
+        let flight = self
+            .flight_repo
+            .find_flight_by_id(booking.flight_id)
+            .await
+            .map_err(|e| UseCaseError::Unexpected(e.to_string()))?
+            .ok_or_else(|| {
+                UseCaseError::NotFound(format!("Flight with id {} not found", booking.flight_id))
+            })?;
+        let origin = self.load_airport(flight.origin_airport_id).await?;
+        let destination = self.load_airport(flight.destination_airport_id).await?;
+        // The operating carrier owes compensation, whoever marketed the ticket.
+        let carrier = self
+            .airline_repo
+            .find_airline_by_iata_code(&flight.airline_code)
+            .await
+            .map_err(|e| UseCaseError::Unexpected(e.to_string()))?
+            .ok_or_else(|| {
+                UseCaseError::NotFound(format!("Airline {} not found", flight.airline_code))
+            })?;
+        let distance_km = self.distance_km(&flight, &origin, &destination).await?;
+
+        let events = self
+            .flight_repo
+            .list_status_events(flight.id)
+            .await
+            .map_err(|e| UseCaseError::Unexpected(e.to_string()))?;
+        let cancelled_at = events
+            .iter()
+            .rev()
+            .find(|e| e.to_status == FlightStatus::Cancelled)
+            .map(|e| e.recorded_at);
+        // Any cause reported along the way counts; one extraordinary cause is enough.
+        let mut delay_codes: Vec<String> = events
+            .iter()
+            .flat_map(|e| e.delay_codes.iter().cloned())
+            .collect();
+        delay_codes.sort();
+        delay_codes.dedup();
+
+        let times = &flight.operational_times;
+        let assessment = self.policy.assess(&DisruptedFlight {
+            status: flight.status.clone(),
+            distance_km,
+            origin_country: origin.country_code,
+            destination_country: destination.country_code,
+            carrier_country: carrier.country_code,
+            scheduled_departure: flight.departure_time,
+            scheduled_arrival: flight.arrival_time,
+            actual_arrival: times.actual_on_block.or(times.actual_landing),
+            cancelled_at,
+            delay_codes,
+        });
+
+        // Lap infants have no seat of their own and are not compensated separately.
+        let passengers = self
+            .passenger_repo
+            .list_passengers_by_booking(booking.id)
+            .await
+            .map_err(|e| UseCaseError::Unexpected(e.to_string()))?;
+        let passenger_count = Passenger::occupied_seats(&passengers);
+
+        Ok((flight, assessment, passenger_count))
+    }
+
+    async fn decide(
+        &self,
+        ctx: RequestContext,
+        id: i64,
+        command: DecideCompensationClaimCommand,
+        approve: bool,
+    ) -> UseCaseResult<CompensationClaimView> {
+        let agent_id = Self::ensure_claims_agent(&ctx)?;
+
+        let mut claim = self.load_claim(id).await?;
+        let note = Self::normalize_note(command.note);
+        let now = chrono::Utc::now().naive_utc();
+        let expected_version = claim.version;
+        if approve {
+            claim.approve(agent_id, note, now)?;
+        } else {
+            claim.reject(agent_id, note, now)?;
+        }
+
+        self.claim_repo
+            .update_claim(&claim, expected_version)
+            .await
+            .map_err(|e| match e {
+                DomainError::Compensation(CompensationDomainError::OptimisticLockConflict) => {
+                    UseCaseError::Domain(e)
+                }
+                _ => UseCaseError::Unexpected(e.to_string()),
+            })?;
+        claim.version = expected_version + 1;
+
+        if let Err(e) = self
+            .event_publisher
+            .publish_claim_decided(CompensationClaimDecidedEvent::new(
+                &claim,
+                claim_status_to_string(&claim.status),
+                now,
+            ))
+            .await
+        {
+            tracing::warn!(
+                "failed to publish compensation claim {} decision: {}",
+                claim.id,
+                e
+            );
+        }
+
+        Ok(claim_to_view(claim))
+    }
+}
+
+#[async_trait::async_trait]
+impl CompensationServiceInterface for CompensationService {
+    async fn assess_booking(
+        &self,
+        ctx: RequestContext,
+        booking_code: String,
+    ) -> UseCaseResult<CompensationAssessmentView> {
+        let booking = self.load_booking_by_code(&booking_code).await?;
+        Self::ensure_booking_access(&ctx, &booking)?;
+
+        let (flight, assessment, passenger_count) = self.assess(&booking).await?;
+        let claim_id = self
+            .claim_repo
+            .find_claim_by_booking(booking.id)
+            .await
+            .map_err(|e| UseCaseError::Unexpected(e.to_string()))?
+            .map(|claim| claim.id);
+
+        Ok(CompensationAssessmentView::new(
+            booking.booking_code,
+            &flight,
+            assessment,
+            passenger_count,
+            claim_id,
+        ))
+    }
+
+    async fn submit_claim(
+        &self,
+        ctx: RequestContext,
+        command: SubmitCompensationClaimCommand,
+    ) -> UseCaseResult<CompensationClaimView> {
+        let booking = self.load_booking_by_code(&command.booking_code).await?;
+        Self::ensure_booking_access(&ctx, &booking)?;
+
+        if booking.status != BookingStatus::Confirmed {
+            return Err(UseCaseError::BusinessRule(format!(
+                "Booking {} is not confirmed",
+                booking.booking_code
+            )));
+        }
+        if let Some(existing) = self
+            .claim_repo
+            .find_claim_by_booking(booking.id)
+            .await
+            .map_err(|e| UseCaseError::Unexpected(e.to_string()))?
+        {
+            return Err(UseCaseError::AlreadyExists(format!(
+                "Booking {} already has compensation claim {}",
+                booking.booking_code, existing.id
+            )));
+        }
+
+        let (flight, assessment, passenger_count) = self.assess(&booking).await?;
+        let now = chrono::Utc::now().naive_utc();
+        let mut claim = CompensationClaim::submit(
+            SubmitCompensationClaimProps {
+                booking_id: booking.id,
+                booking_code: booking.booking_code.clone(),
+                flight_id: flight.id,
+                user_id: booking.user_id,
+                passenger_count,
+                customer_note: Self::normalize_note(command.note),
+            },
+            &assessment,
+            now,
+        )?;
+
+        claim.id = self
+            .claim_repo
+            .create_claim(&claim)
+            .await
+            .map_err(|e| match e {
+                DomainError::Compensation(CompensationDomainError::Conflict { .. }) => {
+                    UseCaseError::Domain(e)
+                }
+                _ => UseCaseError::Unexpected(e.to_string()),
+            })?;
+
+        if let Err(e) = self
+            .event_publisher
+            .publish_claim_submitted(CompensationClaimSubmittedEvent::new(
+                &claim,
+                booking.booking_code,
+                compensation_reason_to_string(&claim.reason),
+                now,
+            ))
+            .await
+        {
+            tracing::warn!(
+                "failed to publish compensation claim {} submission: {}",
+                claim.id,
+                e
+            );
+        }
+
+        Ok(claim_to_view(claim))
+    }
+
+    async fn get_claim(
+        &self,
+        ctx: RequestContext,
+        id: i64,
+    ) -> UseCaseResult<CompensationClaimView> {
+        let actor_user_id = ctx.user_id().ok_or(UseCaseError::PermissionDenied)?;
+
+        let claim = self.load_claim(id).await?;
+        if !ctx.is_admin() && !ctx.is_staff() && actor_user_id != claim.user_id {
+            return Err(UseCaseError::PermissionDenied);
+        }
+
+        Ok(claim_to_view(claim))
+    }
+
+    async fn list_claims(
+        &self,
+        ctx: RequestContext,
+        status: Option<String>,
+    ) -> UseCaseResult<Vec<CompensationClaimView>> {
+        Self::ensure_claims_agent(&ctx)?;
+
+        let status = status.as_deref().map(Self::parse_status).transpose()?;
+        let claims = self
+            .claim_repo
+            .list_claims(status)
+            .await
+            .map_err(|e| UseCaseError::Unexpected(e.to_string()))?;
+
+        Ok(claims.into_iter().map(claim_to_view).collect())
+    }
+
+    async fn approve_claim(
+        &self,
+        ctx: RequestContext,
+        id: i64,
+        command: DecideCompensationClaimCommand,
+    ) -> UseCaseResult<CompensationClaimView> {
+        self.decide(ctx, id, command, true).await
+    }
+
+    async fn reject_claim(
+        &self,
+        ctx: RequestContext,
+        id: i64,
+        command: DecideCompensationClaimCommand,
+    ) -> UseCaseResult<CompensationClaimView> {
+        self.decide(ctx, id, command, false).await
+    }
+}
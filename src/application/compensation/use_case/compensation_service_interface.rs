@@ -0,0 +1,46 @@
+use crate::application::common::use_case_error::UseCaseResult;
+use crate::application::compensation::compensation_command::{
+    DecideCompensationClaimCommand, SubmitCompensationClaimCommand,
+};
+use crate::application::compensation::view::compensation_view::{
+    CompensationAssessmentView, CompensationClaimView,
+};
+use crate::core::context::request_context::RequestContext;
+
+#[async_trait::async_trait]
+pub trait CompensationServiceInterface: Send + Sync {
+    async fn assess_booking(
+        &self,
+        ctx: RequestContext,
+        booking_code: String,
+    ) -> UseCaseResult<CompensationAssessmentView>;
+
+    async fn submit_claim(
+        &self,
+        ctx: RequestContext,
+        command: SubmitCompensationClaimCommand,
+    ) -> UseCaseResult<CompensationClaimView>;
+
+    async fn get_claim(&self, ctx: RequestContext, id: i64)
+    -> UseCaseResult<CompensationClaimView>;
+
+    async fn list_claims(
+        &self,
+        ctx: RequestContext,
+        status: Option<String>,
+    ) -> UseCaseResult<Vec<CompensationClaimView>>;
+
+    async fn approve_claim(
+        &self,
+        ctx: RequestContext,
+        id: i64,
+        command: DecideCompensationClaimCommand,
+    ) -> UseCaseResult<CompensationClaimView>;
+
+    async fn reject_claim(
+        &self,
+        ctx: RequestContext,
+        id: i64,
+        command: DecideCompensationClaimCommand,
+    ) -> UseCaseResult<CompensationClaimView>;
+}
@@ -0,0 +1,2 @@
+pub mod compensation_service;
+pub mod compensation_service_interface;
\ No newline at end of file
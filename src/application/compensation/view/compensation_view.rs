@@ -0,0 +1,45 @@
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CompensationAssessmentView {
+    pub booking_code: String,
+    pub flight_id: i64,
+    pub flight_key: String,
+    pub flight_status: String,
+    pub eligible: bool,
+    pub reason: Option<String>,
+    pub ineligibility: Option<String>,
+    pub ineligibility_detail: Option<String>,
+    pub distance_km: i32,
+    pub band_from_km: i32,
+    pub band_to_km: Option<i32>,
+    pub arrival_delay_minutes: Option<i64>,
+    pub reduced: bool,
+    pub passenger_count: i32,
+    pub amount_per_passenger: String,
+    pub total_amount: String,
+    pub currency: String,
+    pub claim_id: Option<i64>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CompensationClaimView {
+    pub id: i64,
+    pub booking_id: i64,
+    pub flight_id: i64,
+    pub user_id: i64,
+    pub reason: String,
+    pub status: String,
+    pub distance_km: i32,
+    pub arrival_delay_minutes: Option<i32>,
+    pub passenger_count: i32,
+    pub amount_per_passenger: String,
+    pub total_amount: String,
+    pub currency: String,
+    pub customer_note: Option<String>,
+    pub decision_note: Option<String>,
+    pub decided_by: Option<i64>,
+    pub submitted_at: String,
+    pub decided_at: Option<String>,
+    pub version: i32,
+}
@@ -0,0 +1 @@
+pub mod compensation_view;
\ No newline at end of file
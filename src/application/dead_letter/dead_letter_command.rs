@@ -0,0 +1,26 @@
+use chrono::NaiveDateTime;
+use std::collections::BTreeMap;
+
+/// A message read from a consumer group's dead-letter topic.
+#[derive(Debug, Clone)]
+pub struct RecordDeadLetterCommand {
+    pub consumer_group: String,
+    pub handler: String,
+    pub original_topic: String,
+    pub original_partition: i32,
+    pub original_offset: i64,
+    pub message_key: Option<String>,
+    pub payload: Vec<u8>,
+    pub headers: BTreeMap<String, String>,
+    pub error: String,
+    pub attempts: i32,
+    pub dead_letter_topic: String,
+    pub dead_letter_partition: i32,
+    pub dead_letter_offset: i64,
+    pub failed_at: NaiveDateTime,
+}
+
+#[derive(Debug, Clone)]
+pub struct ResolveDeadLetterCommand {
+    pub note: Option<String>,
+}
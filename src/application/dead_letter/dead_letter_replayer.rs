@@ -0,0 +1,9 @@
+use crate::application::common::use_case_error::UseCaseResult;
+use crate::domain::dead_letter::entity::DeadLetter;
+
+/// Hands a dead letter back to the handler that gave up on it.
+#[async_trait::async_trait]
+pub trait DeadLetterReplayer: Send + Sync {
+    /// Resolves once the message is on its way; the handler runs asynchronously.
+    async fn replay(&self, dead_letter: &DeadLetter) -> UseCaseResult<()>;
+}
@@ -0,0 +1,41 @@
+use crate::application::dead_letter::view::dead_letter_view::DeadLetterView;
+use crate::domain::dead_letter::entity::{DeadLetter, DeadLetterStatus};
+
+pub fn dead_letter_to_view(dead_letter: DeadLetter) -> DeadLetterView {
+    DeadLetterView {
+        id: dead_letter.id,
+        consumer_group: dead_letter.consumer_group,
+        handler: dead_letter.handler,
+        original_topic: dead_letter.original_topic,
+        original_partition: dead_letter.original_partition,
+        original_offset: dead_letter.original_offset,
+        message_key: dead_letter.message_key,
+        payload: String::from_utf8_lossy(&dead_letter.payload).into_owned(),
+        headers: dead_letter.headers,
+        error: dead_letter.error,
+        attempts: dead_letter.attempts,
+        dead_letter_topic: dead_letter.dead_letter_topic,
+        dead_letter_partition: dead_letter.dead_letter_partition,
+        dead_letter_offset: dead_letter.dead_letter_offset,
+        status: dead_letter_status_to_string(&dead_letter.status),
+        failed_at: dead_letter
+            .failed_at
+            .format("%Y-%m-%dT%H:%M:%S")
+            .to_string(),
+        resolved_at: dead_letter
+            .resolved_at
+            .map(|v| v.format("%Y-%m-%dT%H:%M:%S").to_string()),
+        resolved_by: dead_letter.resolved_by,
+        resolution_note: dead_letter.resolution_note,
+        version: dead_letter.version,
+    }
+}
+
+pub fn dead_letter_status_to_string(value: &DeadLetterStatus) -> String {
+    match value {
+        DeadLetterStatus::Pending => "PENDING",
+        DeadLetterStatus::Replayed => "REPLAYED",
+        DeadLetterStatus::Discarded => "DISCARDED",
+    }
+    .to_string()
+}
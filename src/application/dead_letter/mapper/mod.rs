@@ -0,0 +1 @@
+pub mod dead_letter_mapper;
\ No newline at end of file
@@ -0,0 +1,5 @@
+pub mod dead_letter_command;
+pub mod dead_letter_replayer;
+pub mod mapper;
+pub mod use_case;
+pub mod view;
\ No newline at end of file
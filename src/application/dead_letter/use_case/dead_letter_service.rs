@@ -0,0 +1,196 @@
+use crate::application::common::use_case_error::{UseCaseError, UseCaseResult};
+use crate::application::dead_letter::dead_letter_command::{
+    RecordDeadLetterCommand, ResolveDeadLetterCommand,
+};
+use crate::application::dead_letter::dead_letter_replayer::DeadLetterReplayer;
+use crate::application::dead_letter::mapper::dead_letter_mapper::dead_letter_to_view;
+use crate::application::dead_letter::use_case::dead_letter_service_interface::DeadLetterServiceInterface;
+use crate::application::dead_letter::view::dead_letter_view::DeadLetterView;
+use crate::core::context::request_context::RequestContext;
+use crate::domain::dead_letter::dead_letter_repository_interface::DeadLetterRepositoryInterface;
+use crate::domain::dead_letter::entity::{DeadLetter, DeadLetterStatus, RecordDeadLetterProps};
+use crate::domain::dead_letter::error::DeadLetterDomainError;
+use crate::domain::error::DomainError;
+use std::sync::Arc;
+
+const LIST_LIMIT: u64 = 200;
+
+pub struct DeadLetterService {
+    pub dead_letter_repo: Arc<dyn DeadLetterRepositoryInterface>,
+    pub replayer: Arc<dyn DeadLetterReplayer>,
+}
+
+impl DeadLetterService {
+    pub fn new(
+        dead_letter_repo: Arc<dyn DeadLetterRepositoryInterface>,
+        replayer: Arc<dyn DeadLetterReplayer>,
+    ) -> Self {
+        Self {
+            dead_letter_repo,
+            replayer,
+        }
+    }
+
+    // Dead letters hold raw event payloads, personal data included.
+    fn ensure_admin(ctx: &RequestContext) -> UseCaseResult<i64> {
+        let user_id = ctx.user_id().ok_or(UseCaseError::PermissionDenied)?;
+        if !ctx.is_admin() {
+            return Err(UseCaseError::PermissionDenied);
+        }
+        Ok(user_id)
+    }
+
+    fn parse_status(value: &str) -> UseCaseResult<DeadLetterStatus> {
+        match value.trim().to_uppercase().as_str() {
+            "PENDING" => Ok(DeadLetterStatus::Pending),
+            "REPLAYED" => Ok(DeadLetterStatus::Replayed),
+            "DISCARDED" => Ok(DeadLetterStatus::Discarded),
+            _ => Err(UseCaseError::Domain(DomainError::DeadLetter(
+                DeadLetterDomainError::Validation {
+                    field: "status",
+                    message: format!("Invalid status: {value}"),
+                },
+            ))),
+        }
+    }
+
+    fn normalize_note(note: Option<String>) -> Option<String> {
+        note.map(|v| v.trim().to_string()).filter(|v| !v.is_empty())
+    }
+
+    async fn load_dead_letter(&self, id: i64) -> UseCaseResult<DeadLetter> {
+        self.dead_letter_repo
+            .find_dead_letter_by_id(id)
+            .await
+            .map_err(|e| UseCaseError::Unexpected(e.to_string()))?
+            .ok_or_else(|| {
+                UseCaseError::Domain(DomainError::DeadLetter(
+                    DeadLetterDomainError::DeadLetterNotFound { id },
+                ))
+            })
+    }
+
+    async fn save(&self, dead_letter: &mut DeadLetter, expected_version: i32) -> UseCaseResult<()> {
+        self.dead_letter_repo
+            .update_dead_letter(dead_letter, expected_version)
+            .await
+            .map_err(|e| match e {
+                DomainError::DeadLetter(DeadLetterDomainError::OptimisticLockConflict) => {
+                    UseCaseError::Domain(e)
+                }
+                _ => UseCaseError::Unexpected(e.to_string()),
+            })?;
+        dead_letter.version = expected_version + 1;
+        Ok(())
+    }
+}
+
+#[async_trait::async_trait]
+impl DeadLetterServiceInterface for DeadLetterService {
+    async fn record_dead_letter(&self, command: RecordDeadLetterCommand) -> UseCaseResult<()> {
+        let dead_letter = DeadLetter::record(RecordDeadLetterProps {
+            consumer_group: command.consumer_group,
+            handler: command.handler,
+            original_topic: command.original_topic,
+            original_partition: command.original_partition,
+            original_offset: command.original_offset,
+            message_key: command.message_key,
+            payload: command.payload,
+            headers: command.headers,
+            error: command.error,
+            attempts: command.attempts,
+            dead_letter_topic: command.dead_letter_topic,
+            dead_letter_partition: command.dead_letter_partition,
+            dead_letter_offset: command.dead_letter_offset,
+            failed_at: command.failed_at,
+        })?;
+
+        let created = self
+            .dead_letter_repo
+            .create_dead_letter(&dead_letter)
+            .await
+            .map_err(|e| UseCaseError::Unexpected(e.to_string()))?;
+        if let Some(id) = created {
+            tracing::info!(
+                "archived dead letter {} from {} ({})",
+                id,
+                dead_letter.consumer_group,
+                dead_letter.handler
+            );
+        }
+        Ok(())
+    }
+
+    async fn list_dead_letters(
+        &self,
+        ctx: RequestContext,
+        status: Option<String>,
+        consumer_group: Option<String>,
+    ) -> UseCaseResult<Vec<DeadLetterView>> {
+        Self::ensure_admin(&ctx)?;
+
+        let status = status.as_deref().map(Self::parse_status).transpose()?;
+        let consumer_group = consumer_group
+            .map(|v| v.trim().to_string())
+            .filter(|v| !v.is_empty());
+        Ok(self
+            .dead_letter_repo
+            .list_dead_letters(status, consumer_group, LIST_LIMIT)
+            .await
+            .map_err(|e| UseCaseError::Unexpected(e.to_string()))?
+            .into_iter()
+            .map(dead_letter_to_view)
+            .collect())
+    }
+
+    async fn get_dead_letter(&self, ctx: RequestContext, id: i64) -> UseCaseResult<DeadLetterView> {
+        Self::ensure_admin(&ctx)?;
+
+        Ok(dead_letter_to_view(self.load_dead_letter(id).await?))
+    }
+
+    async fn replay_dead_letter(
+        &self,
+        ctx: RequestContext,
+        id: i64,
+        command: ResolveDeadLetterCommand,
+    ) -> UseCaseResult<DeadLetterView> {
+        let admin_id = Self::ensure_admin(&ctx)?;
+
+        let mut dead_letter = self.load_dead_letter(id).await?;
+        dead_letter.ensure_replayable()?;
+        // Published before the row is marked, so a failed publish leaves it pending; a
+        // failed update after publishing at worst replays twice, which handlers tolerate.
+        self.replayer.replay(&dead_letter).await?;
+
+        let expected_version = dead_letter.version;
+        dead_letter.replay(
+            admin_id,
+            Self::normalize_note(command.note),
+            chrono::Utc::now().naive_utc(),
+        )?;
+        self.save(&mut dead_letter, expected_version).await?;
+
+        Ok(dead_letter_to_view(dead_letter))
+    }
+
+    async fn discard_dead_letter(
+        &self,
+        ctx: RequestContext,
+        id: i64,
+        command: ResolveDeadLetterCommand,
+    ) -> UseCaseResult<DeadLetterView> {
+        let admin_id = Self::ensure_admin(&ctx)?;
+
+        let mut dead_letter = self.load_dead_letter(id).await?;
+        let expected_version = dead_letter.version;
+        dead_letter.discard(
+            admin_id,
+            Self::normalize_note(command.note),
+            chrono::Utc::now().naive_utc(),
+        )?;
+        self.save(&mut dead_letter, expected_version).await?;
+
+        Ok(dead_letter_to_view(dead_letter))
+    }
+}
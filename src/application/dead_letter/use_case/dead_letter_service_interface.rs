@@ -0,0 +1,37 @@
+use crate::application::common::use_case_error::UseCaseResult;
+use crate::application::dead_letter::dead_letter_command::{
+    RecordDeadLetterCommand, ResolveDeadLetterCommand,
+};
+use crate::application::dead_letter::view::dead_letter_view::DeadLetterView;
+use crate::core::context::request_context::RequestContext;
+
+#[async_trait::async_trait]
+pub trait DeadLetterServiceInterface: Send + Sync {
+    /// Archives a message from a dead-letter topic; archiving the same message twice is a
+    /// no-op.
+    async fn record_dead_letter(&self, command: RecordDeadLetterCommand) -> UseCaseResult<()>;
+
+    async fn list_dead_letters(
+        &self,
+        ctx: RequestContext,
+        status: Option<String>,
+        consumer_group: Option<String>,
+    ) -> UseCaseResult<Vec<DeadLetterView>>;
+
+    async fn get_dead_letter(&self, ctx: RequestContext, id: i64) -> UseCaseResult<DeadLetterView>;
+
+    /// Sends the message back to the handler that failed it.
+    async fn replay_dead_letter(
+        &self,
+        ctx: RequestContext,
+        id: i64,
+        command: ResolveDeadLetterCommand,
+    ) -> UseCaseResult<DeadLetterView>;
+
+    async fn discard_dead_letter(
+        &self,
+        ctx: RequestContext,
+        id: i64,
+        command: ResolveDeadLetterCommand,
+    ) -> UseCaseResult<DeadLetterView>;
+}
@@ -0,0 +1,2 @@
+pub mod dead_letter_service;
+pub mod dead_letter_service_interface;
\ No newline at end of file
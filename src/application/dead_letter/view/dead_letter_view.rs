@@ -0,0 +1,27 @@
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct DeadLetterView {
+    pub id: i64,
+    pub consumer_group: String,
+    pub handler: String,
+    pub original_topic: String,
+    pub original_partition: i32,
+    pub original_offset: i64,
+    pub message_key: Option<String>,
+    // lossy UTF-8; event payloads are JSON
+    pub payload: String,
+    pub headers: BTreeMap<String, String>,
+    pub error: String,
+    pub attempts: i32,
+    pub dead_letter_topic: String,
+    pub dead_letter_partition: i32,
+    pub dead_letter_offset: i64,
+    pub status: String,
+    pub failed_at: String,
+    pub resolved_at: Option<String>,
+    pub resolved_by: Option<i64>,
+    pub resolution_note: Option<String>,
+    pub version: i32,
+}
@@ -0,0 +1 @@
+pub mod dead_letter_view;
\ No newline at end of file
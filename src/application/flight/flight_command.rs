@@ -34,6 +34,15 @@ pub struct UpdateFlightCommand {
     pub gate: Option<String>,
     pub departure_time: Option<NaiveDateTime>,
     pub arrival_time: Option<NaiveDateTime>,
+    pub estimated_departure: Option<NaiveDateTime>,
+    pub estimated_arrival: Option<NaiveDateTime>,
+    pub actual_off_block: Option<NaiveDateTime>,
+    pub actual_takeoff: Option<NaiveDateTime>,
+    pub actual_landing: Option<NaiveDateTime>,
+    pub actual_on_block: Option<NaiveDateTime>,
+    pub delay_codes: Option<Vec<String>>,
+    #[validate(length(max = 500))]
+    pub delay_reason: Option<String>,
 }
 
 #[derive(Debug, Clone)]
@@ -42,4 +51,13 @@ pub struct SearchFlightCommand {
     pub destination_airport_id: Option<i64>,
     pub departure_date: Option<NaiveDate>,
     pub status: Option<String>,
+    pub designator: Option<String>,
+}
+
+#[derive(Debug, Clone, Validate)]
+pub struct AddCodeshareCommand {
+    #[validate(length(min = 2, max = 3))]
+    pub airline_code: String,
+    #[validate(length(min = 1, max = 5))]
+    pub flight_number: String,
 }
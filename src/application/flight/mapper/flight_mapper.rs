@@ -1,8 +1,16 @@
-use crate::application::flight::view::flight_view::FlightView;
+use crate::application::flight::view::flight_timeline_view::{
+    FlightStatusEventView, FlightTimelineView,
+};
+use crate::application::flight::view::flight_view::{
+    FlightCodeshareView, FlightOperationalTimesView, FlightView,
+};
+use crate::domain::flight::codeshare::FlightCodeshare;
 use crate::domain::flight::entity::{Flight, FlightStatus};
+use crate::domain::flight::status_event::{FlightOperationalTimes, FlightStatusEvent};
 
 impl From<Flight> for FlightView {
     fn from(value: Flight) -> Self {
+        let delay_minutes = value.departure_delay_minutes();
         Self {
             id: value.id,
             airline_code: value.airline_code,
@@ -10,10 +18,13 @@ impl From<Flight> for FlightView {
             flight_key: value.flight_key,
             origin_airport_id: value.origin_airport_id,
             destination_airport_id: value.destination_airport_id,
+            route_id: value.route_id,
             departure_date: value.departure_date.format("%Y-%m-%d").to_string(),
             departure_time: value.departure_time.format("%Y-%m-%dT%H:%M:%S").to_string(),
             arrival_time: value.arrival_time.format("%Y-%m-%dT%H:%M:%S").to_string(),
             status: flight_status_to_string(&value.status),
+            operational_times: (&value.operational_times).into(),
+            delay_minutes,
             aircraft_type: value.aircraft_type,
             tail_number: value.tail_number,
             terminal_departure: value.terminal_departure,
@@ -31,6 +42,75 @@ impl From<Flight> for FlightView {
             total_seats: value.total_seats,
             available_seats: value.available_seats,
             version: value.version,
+            codeshares: Vec::new(),
+        }
+    }
+}
+
+impl From<FlightCodeshare> for FlightCodeshareView {
+    fn from(value: FlightCodeshare) -> Self {
+        Self {
+            airline_code: value.airline_code,
+            flight_number: value.flight_number,
+            flight_key: value.flight_key,
+        }
+    }
+}
+
+impl From<&FlightOperationalTimes> for FlightOperationalTimesView {
+    fn from(value: &FlightOperationalTimes) -> Self {
+        let format =
+            |v: Option<chrono::NaiveDateTime>| v.map(|v| v.format("%Y-%m-%dT%H:%M:%S").to_string());
+        Self {
+            estimated_departure: format(value.estimated_departure),
+            estimated_arrival: format(value.estimated_arrival),
+            actual_off_block: format(value.actual_off_block),
+            actual_takeoff: format(value.actual_takeoff),
+            actual_landing: format(value.actual_landing),
+            actual_on_block: format(value.actual_on_block),
+        }
+    }
+}
+
+impl From<FlightStatusEvent> for FlightStatusEventView {
+    fn from(value: FlightStatusEvent) -> Self {
+        Self {
+            id: value.id,
+            from_status: flight_status_to_string(&value.from_status),
+            to_status: flight_status_to_string(&value.to_status),
+            scheduled_departure: value
+                .scheduled_departure
+                .format("%Y-%m-%dT%H:%M:%S")
+                .to_string(),
+            scheduled_arrival: value
+                .scheduled_arrival
+                .format("%Y-%m-%dT%H:%M:%S")
+                .to_string(),
+            operational_times: (&value.times).into(),
+            delay_minutes: value.delay_minutes,
+            delay_codes: value.delay_codes,
+            reason: value.reason,
+            changed_by: value.changed_by,
+            recorded_at: value.recorded_at.format("%Y-%m-%dT%H:%M:%S").to_string(),
+        }
+    }
+}
+
+impl FlightTimelineView {
+    pub fn new(flight: &Flight, events: Vec<FlightStatusEvent>) -> Self {
+        Self {
+            flight_id: flight.id,
+            flight_key: flight.flight_key.clone(),
+            status: flight_status_to_string(&flight.status),
+            scheduled_departure: flight
+                .departure_time
+                .format("%Y-%m-%dT%H:%M:%S")
+                .to_string(),
+            scheduled_arrival: flight.arrival_time.format("%Y-%m-%dT%H:%M:%S").to_string(),
+            operational_times: (&flight.operational_times).into(),
+            departure_delay_minutes: flight.departure_delay_minutes(),
+            arrival_delay_minutes: flight.arrival_delay_minutes(),
+            events: events.into_iter().map(Into::into).collect(),
         }
     }
 }
@@ -3,20 +3,33 @@ use crate::application::common::cache_interface::CacheInterface;
 use crate::application::common::event_publisher::FlightEventPublisher;
 use crate::application::common::use_case_error::{UseCaseError, UseCaseResult};
 use crate::application::flight::flight_command::{
-    CreateFlightCommand, SearchFlightCommand, UpdateFlightCommand,
+    AddCodeshareCommand, CreateFlightCommand, SearchFlightCommand, UpdateFlightCommand,
 };
 use crate::application::flight::use_case::flight_service_interface::FlightServiceInterface;
+use crate::application::flight::view::flight_timeline_view::FlightTimelineView;
 use crate::application::flight::view::flight_view::FlightView;
 use crate::core::context::request_context::RequestContext;
+use crate::domain::aircraft::aircraft_repository_interface::AircraftRepositoryInterface;
+use crate::domain::aircraft::rules::aircraft_rotation_must_be_continuous::AircraftRotationMustBeContinuous;
+use crate::domain::airline::airline_repository_interface::AirlineRepositoryInterface;
+use crate::domain::airline::entity::Airline;
 use crate::domain::airport::airport_repository_interface::AirportRepositoryInterface;
+use crate::domain::airport::entity::Airport;
+use crate::domain::business_rule_interface::BusinessRuleInterface;
 use crate::domain::error::DomainError;
 use crate::domain::flight;
-use crate::domain::flight::entity::{CreateFlightProps, FlightStatus};
+use crate::domain::flight::codeshare::FlightCodeshare;
+use crate::domain::flight::entity::{CreateFlightProps, Flight, FlightStatus};
 use crate::domain::flight::events::flight_cancelled::FlightCancelledEvent;
 use crate::domain::flight::events::flight_created::FlightCreatedEvent;
 use crate::domain::flight::events::flight_updated::FlightUpdatedEvent;
 use crate::domain::flight::error::FlightDomainError;
 use crate::domain::flight::flight_repository_interface::FlightRepositoryInterface;
+use crate::domain::flight::status_event::{FlightOperationalTimes, FlightStatusChange};
+use crate::domain::route::entity::Route;
+use crate::domain::route::error::RouteDomainError;
+use crate::domain::route::route_repository_interface::RouteRepositoryInterface;
+use chrono::NaiveDate;
 use std::sync::Arc;
 use validator::Validate;
 
@@ -24,6 +37,9 @@ pub struct FlightService {
     pub cache: Arc<dyn CacheInterface>,
     pub flight_repo: Arc<dyn FlightRepositoryInterface>,
     pub airport_repo: Arc<dyn AirportRepositoryInterface>,
+    pub airline_repo: Arc<dyn AirlineRepositoryInterface>,
+    pub aircraft_repo: Arc<dyn AircraftRepositoryInterface>,
+    pub route_repo: Arc<dyn RouteRepositoryInterface>,
     pub event_publisher: Arc<dyn FlightEventPublisher>,
 }
 
@@ -32,12 +48,18 @@ impl FlightService {
         cache: Arc<dyn CacheInterface>,
         flight_repo: Arc<dyn FlightRepositoryInterface>,
         airport_repo: Arc<dyn AirportRepositoryInterface>,
+        airline_repo: Arc<dyn AirlineRepositoryInterface>,
+        aircraft_repo: Arc<dyn AircraftRepositoryInterface>,
+        route_repo: Arc<dyn RouteRepositoryInterface>,
         event_publisher: Arc<dyn FlightEventPublisher>,
     ) -> Self {
         Self {
             cache,
             flight_repo,
             airport_repo,
+            airline_repo,
+            aircraft_repo,
+            route_repo,
             event_publisher,
         }
     }
@@ -50,6 +72,83 @@ impl FlightService {
         format!("flights:search:{origin_airport_id}:{destination_airport_id}:{date}")
     }
 
+    fn normalize_designator(designator: Option<&str>) -> Option<String> {
+        designator
+            .map(|d| d.replace(' ', "").to_uppercase())
+            .filter(|d| !d.is_empty())
+    }
+
+    async fn invalidate_flight_cache(&self, flight: &Flight) {
+        let keys = [
+            Self::flight_id_cache_key(flight.id),
+            Self::search_cache_key(
+                flight.origin_airport_id,
+                flight.destination_airport_id,
+                &flight.departure_date.format("%Y-%m-%d").to_string(),
+            ),
+        ];
+        for key in keys {
+            if let Err(err) = self.cache.del(&key).await {
+                tracing::warn!("cache del failed key={}: {}", key, err);
+            }
+        }
+    }
+
+    async fn attach_codeshares(&self, views: &mut [FlightView]) -> UseCaseResult<()> {
+        let flight_ids: Vec<i64> = views.iter().map(|view| view.id).collect();
+        let codeshares = self
+            .flight_repo
+            .list_codeshares_by_flight_ids(&flight_ids)
+            .await
+            .map_err(|e| UseCaseError::Unexpected(e.to_string()))?;
+
+        for codeshare in codeshares {
+            if let Some(view) = views.iter_mut().find(|view| view.id == codeshare.flight_id) {
+                view.codeshares.push(codeshare.into());
+            }
+        }
+        Ok(())
+    }
+
+    /// Operating and codeshare designators both resolve to the operating flight.
+    async fn search_by_designator(
+        &self,
+        designator: &str,
+        departure_date: NaiveDate,
+        command: &SearchFlightCommand,
+        status: Option<&str>,
+    ) -> UseCaseResult<Vec<FlightView>> {
+        if let Some(status) = status {
+            let _ = Self::parse_status(status)?;
+        }
+
+        let flight_key = format!("{}_{}", designator, departure_date.format("%Y-%m-%d"));
+        let flight = self
+            .flight_repo
+            .find_flight_by_flight_key(&flight_key)
+            .await
+            .map_err(|e| UseCaseError::Unexpected(e.to_string()))?;
+
+        let mut views: Vec<FlightView> = flight
+            .into_iter()
+            .filter(|f| {
+                command
+                    .origin_airport_id
+                    .is_none_or(|id| id == f.origin_airport_id)
+                    && command
+                        .destination_airport_id
+                        .is_none_or(|id| id == f.destination_airport_id)
+            })
+            .map(Into::into)
+            .collect();
+        if let Some(status) = status {
+            views.retain(|f| f.status == status);
+        }
+        self.attach_codeshares(&mut views).await?;
+
+        Ok(views)
+    }
+
     fn parse_status(status: &str) -> UseCaseResult<FlightStatus> {
         match status.trim().to_uppercase().as_str() {
             "SCHEDULED" => Ok(FlightStatus::Scheduled),
@@ -66,6 +165,121 @@ impl FlightService {
         }
     }
 
+    /// Ties the flight to a registered aircraft: the tail must be in service with the
+    /// flight's airline and the leg must fit its rotation. The aircraft type is taken
+    /// from the registry.
+    async fn assign_aircraft(&self, flight: &mut Flight, airline: &Airline) -> UseCaseResult<()> {
+        let tail_number = match flight.tail_number.as_deref() {
+            Some(tail_number) => tail_number.trim().to_uppercase(),
+            None => return Ok(()),
+        };
+
+        let aircraft = self
+            .aircraft_repo
+            .find_aircraft_by_tail_number(&tail_number)
+            .await
+            .map_err(|e| UseCaseError::Unexpected(e.to_string()))?
+            .ok_or_else(|| {
+                UseCaseError::NotFound(format!(
+                    "Aircraft with tail_number {} not found",
+                    tail_number
+                ))
+            })?;
+        aircraft.ensure_available()?;
+        aircraft.ensure_operated_by(airline.id, &airline.iata_code)?;
+
+        let type_mismatch = flight
+            .aircraft_type
+            .as_deref()
+            .is_some_and(|t| !t.trim().eq_ignore_ascii_case(&aircraft.aircraft_type));
+        if type_mismatch {
+            return Err(UseCaseError::Domain(DomainError::Flight(
+                FlightDomainError::Validation {
+                    field: "aircraft_type",
+                    message: format!(
+                        "Aircraft {} is registered as {}",
+                        aircraft.tail_number, aircraft.aircraft_type
+                    ),
+                },
+            )));
+        }
+        flight.tail_number = Some(aircraft.tail_number.clone());
+        flight.aircraft_type = Some(aircraft.aircraft_type.clone());
+
+        let overlapping = self
+            .flight_repo
+            .list_flights_by_tail_number_between(
+                &aircraft.tail_number,
+                flight.departure_time,
+                flight.arrival_time,
+            )
+            .await
+            .map_err(|e| UseCaseError::Unexpected(e.to_string()))?;
+        let previous = self
+            .flight_repo
+            .find_previous_flight_by_tail_number(
+                &aircraft.tail_number,
+                flight.departure_time,
+                flight.id,
+            )
+            .await
+            .map_err(|e| UseCaseError::Unexpected(e.to_string()))?;
+        let next = self
+            .flight_repo
+            .find_next_flight_by_tail_number(&aircraft.tail_number, flight.arrival_time, flight.id)
+            .await
+            .map_err(|e| UseCaseError::Unexpected(e.to_string()))?;
+
+        AircraftRotationMustBeContinuous {
+            tail_number: &aircraft.tail_number,
+            flight,
+            overlapping: &overlapping,
+            previous: previous.as_ref(),
+            next: next.as_ref(),
+        }
+        .check_broken()?;
+
+        Ok(())
+    }
+
+    /// Links the flight to the route for its airport pair. The first flight on a pair
+    /// opens the route, taking its scheduled time as the typical block time.
+    async fn resolve_route(
+        &self,
+        origin: &Airport,
+        destination: &Airport,
+        flight: &Flight,
+    ) -> UseCaseResult<i64> {
+        let existing = self
+            .route_repo
+            .find_route_by_airports(origin.id, destination.id)
+            .await
+            .map_err(|e| UseCaseError::Unexpected(e.to_string()))?;
+        if let Some(route) = existing {
+            route.ensure_active(&origin.iata_code, &destination.iata_code)?;
+            return Ok(route.id);
+        }
+
+        let block_time_minutes = (flight.arrival_time - flight.departure_time).num_minutes() as i32;
+        let route = Route::between(origin, destination, block_time_minutes)?;
+        match self.route_repo.create_route(&route).await {
+            Ok(route_id) => Ok(route_id),
+            // Another flight opened the same route concurrently.
+            Err(DomainError::Route(RouteDomainError::Conflict { .. })) => self
+                .route_repo
+                .find_route_by_airports(origin.id, destination.id)
+                .await
+                .map_err(|e| UseCaseError::Unexpected(e.to_string()))?
+                .map(|route| route.id)
+                .ok_or_else(|| {
+                    UseCaseError::Unexpected(format!(
+                        "Route {}-{} not found after conflict",
+                        origin.iata_code, destination.iata_code
+                    ))
+                }),
+            Err(e) => Err(UseCaseError::Unexpected(e.to_string())),
+        }
+    }
 }
 
 #[async_trait::async_trait]
@@ -94,33 +308,41 @@ impl FlightServiceInterface for FlightService {
             ));
         }
 
-        let origin_exists = self
+        let origin = self
             .airport_repo
             .find_airport_by_id(command.origin_airport_id)
             .await
             .map_err(|e| UseCaseError::Unexpected(e.to_string()))?
-            .is_some();
-        if !origin_exists {
-            return Err(UseCaseError::NotFound(format!(
-                "Airport with id {} not found",
-                command.origin_airport_id
-            )));
-        }
-
-        let destination_exists = self
+            .ok_or_else(|| {
+                UseCaseError::NotFound(format!(
+                    "Airport with id {} not found",
+                    command.origin_airport_id
+                ))
+            })?;
+
+        let destination = self
             .airport_repo
             .find_airport_by_id(command.destination_airport_id)
             .await
             .map_err(|e| UseCaseError::Unexpected(e.to_string()))?
-            .is_some();
-        if !destination_exists {
-            return Err(UseCaseError::NotFound(format!(
-                "Airport with id {} not found",
-                command.destination_airport_id
-            )));
-        }
+            .ok_or_else(|| {
+                UseCaseError::NotFound(format!(
+                    "Airport with id {} not found",
+                    command.destination_airport_id
+                ))
+            })?;
 
         let airline_code = command.airline_code.trim().to_uppercase();
+        let airline = self
+            .airline_repo
+            .find_airline_by_iata_code(&airline_code)
+            .await
+            .map_err(|e| UseCaseError::Unexpected(e.to_string()))?
+            .ok_or_else(|| {
+                UseCaseError::NotFound(format!("Airline with iata_code {} not found", airline_code))
+            })?;
+        airline.ensure_active()?;
+
         let flight_number = command.flight_number.trim().to_uppercase();
         let departure_date = command.departure_date;
         let flight_key = format!(
@@ -163,6 +385,8 @@ impl FlightServiceInterface for FlightService {
 
         let mut flight = flight::entity::Flight::new(props)?;
         flight.flight_key = flight_key;
+        self.assign_aircraft(&mut flight, &airline).await?;
+        flight.route_id = Some(self.resolve_route(&origin, &destination, &flight).await?);
 
         let flight_id = self
             .flight_repo
@@ -218,17 +442,56 @@ impl FlightServiceInterface for FlightService {
             .map_err(|e| UseCaseError::Unexpected(e.to_string()))?
             .ok_or_else(|| UseCaseError::NotFound(format!("Flight with id {} not found", id)))?;
         let expected_version = flight.version;
-
-        if let Some(status) = command.status.as_ref() {
-            let next_status = Self::parse_status(status)?;
-            flight.change_status(next_status)?;
-        }
+        let reassigning_aircraft = command.tail_number.is_some()
+            || command.aircraft_type.is_some()
+            || command.departure_time.is_some()
+            || command.arrival_time.is_some();
+        let aircraft_type_given = command.aircraft_type.is_some();
+
+        let times = FlightOperationalTimes {
+            estimated_departure: command.estimated_departure,
+            estimated_arrival: command.estimated_arrival,
+            actual_off_block: command.actual_off_block,
+            actual_takeoff: command.actual_takeoff,
+            actual_landing: command.actual_landing,
+            actual_on_block: command.actual_on_block,
+        };
+        let status_change = match command.status.as_ref() {
+            Some(status) => Some(FlightStatusChange {
+                status: Self::parse_status(status)?,
+                times,
+                delay_codes: command
+                    .delay_codes
+                    .unwrap_or_default()
+                    .iter()
+                    .map(|code| code.trim().to_uppercase())
+                    .collect(),
+                reason: command.delay_reason,
+            }),
+            // Operational times belong to a status report so the timeline records them.
+            None if !times.is_empty()
+                || command.delay_codes.is_some()
+                || command.delay_reason.is_some() =>
+            {
+                return Err(UseCaseError::Domain(DomainError::Flight(
+                    FlightDomainError::Validation {
+                        field: "status",
+                        message: "Operational times and delay codes need a status".to_string(),
+                    },
+                )));
+            }
+            None => None,
+        };
 
         if let Some(v) = command.aircraft_type {
             flight.aircraft_type = Some(v);
         }
         if let Some(v) = command.tail_number {
             flight.tail_number = Some(v);
+            // A new tail brings its own registered type unless one is given.
+            if !aircraft_type_given {
+                flight.aircraft_type = None;
+            }
         }
         if let Some(v) = command.terminal_departure {
             flight.terminal_departure = Some(v);
@@ -255,6 +518,14 @@ impl FlightServiceInterface for FlightService {
             flight.arrival_time = v;
         }
 
+        // After the schedule edits so the event compares against the new schedule.
+        let status_event = match status_change {
+            Some(change) => {
+                Some(flight.change_status(change, ctx.user_id(), chrono::Utc::now().naive_utc())?)
+            }
+            None => None,
+        };
+
         let _ = CreateFlightProps {
             airline_code: flight.airline_code.clone(),
             flight_number: flight.flight_number.clone(),
@@ -275,8 +546,23 @@ impl FlightServiceInterface for FlightService {
         }
         .validate()?;
 
+        if reassigning_aircraft && flight.status != FlightStatus::Cancelled {
+            let airline = self
+                .airline_repo
+                .find_airline_by_iata_code(&flight.airline_code)
+                .await
+                .map_err(|e| UseCaseError::Unexpected(e.to_string()))?
+                .ok_or_else(|| {
+                    UseCaseError::NotFound(format!(
+                        "Airline with iata_code {} not found",
+                        flight.airline_code
+                    ))
+                })?;
+            self.assign_aircraft(&mut flight, &airline).await?;
+        }
+
         self.flight_repo
-            .update_flight(&flight, expected_version)
+            .update_flight(&flight, expected_version, status_event.as_ref())
             .await
             .map_err(|e| UseCaseError::Unexpected(e.to_string()))?;
 
@@ -322,7 +608,9 @@ impl FlightServiceInterface for FlightService {
             .map_err(|e| UseCaseError::Unexpected(e.to_string()))?
             .ok_or_else(|| UseCaseError::NotFound(format!("Flight with id {} not found", id)))?;
 
-        let view: FlightView = flight.into();
+        let mut view: FlightView = flight.into();
+        self.attach_codeshares(std::slice::from_mut(&mut view))
+            .await?;
         if let Err(err) = cache_set_json(self.cache.as_ref(), &cache_key, &view, 1800).await {
             tracing::warn!("cache set failed key={}: {}", cache_key, err);
         }
@@ -344,7 +632,9 @@ impl FlightServiceInterface for FlightService {
             .map_err(|e| UseCaseError::Unexpected(e.to_string()))?
             .ok_or_else(|| UseCaseError::NotFound(format!("Flight with key {} not found", flight_key)))?;
 
-        let view: FlightView = flight.into();
+        let mut view: FlightView = flight.into();
+        self.attach_codeshares(std::slice::from_mut(&mut view))
+            .await?;
         let id_key = Self::flight_id_cache_key(view.id);
         if let Err(err) = cache_set_json(self.cache.as_ref(), &id_key, &view, 1800).await {
             tracing::warn!("cache set failed key={}: {}", id_key, err);
@@ -353,6 +643,29 @@ impl FlightServiceInterface for FlightService {
         Ok(view)
     }
 
+    async fn get_flight_timeline(
+        &self,
+        ctx: RequestContext,
+        id: i64,
+    ) -> UseCaseResult<FlightTimelineView> {
+        let _ = ctx.user_id().ok_or(UseCaseError::PermissionDenied)?;
+
+        let flight = self
+            .flight_repo
+            .find_flight_by_id(id)
+            .await
+            .map_err(|e| UseCaseError::Unexpected(e.to_string()))?
+            .ok_or_else(|| UseCaseError::NotFound(format!("Flight with id {} not found", id)))?;
+
+        let events = self
+            .flight_repo
+            .list_status_events(flight.id)
+            .await
+            .map_err(|e| UseCaseError::Unexpected(e.to_string()))?;
+
+        Ok(FlightTimelineView::new(&flight, events))
+    }
+
     async fn search_flights(
         &self,
         ctx: RequestContext,
@@ -360,6 +673,24 @@ impl FlightServiceInterface for FlightService {
     ) -> UseCaseResult<Vec<FlightView>> {
         let _ = ctx.user_id().ok_or(UseCaseError::PermissionDenied)?;
 
+        if let Some(designator) = Self::normalize_designator(command.designator.as_deref()) {
+            let departure_date = command.departure_date.ok_or_else(|| {
+                UseCaseError::Domain(DomainError::Flight(FlightDomainError::Validation {
+                    field: "departure_date",
+                    message: "departure_date is required".to_string(),
+                }))
+            })?;
+            let status_filter = command.status.as_ref().map(|s| s.trim().to_uppercase());
+            return self
+                .search_by_designator(
+                    &designator,
+                    departure_date,
+                    &command,
+                    status_filter.as_deref(),
+                )
+                .await;
+        }
+
         let origin = command.origin_airport_id.ok_or_else(|| {
             UseCaseError::Domain(DomainError::Flight(FlightDomainError::Validation {
                 field: "origin_airport_id",
@@ -413,6 +744,7 @@ impl FlightServiceInterface for FlightService {
             let _ = Self::parse_status(status)?;
             views.retain(|f| f.status == status.as_str());
         }
+        self.attach_codeshares(&mut views).await?;
 
         if let Err(err) = cache_set_json(self.cache.as_ref(), &cache_key, &views, 300).await {
             tracing::warn!("cache set failed key={}: {}", cache_key, err);
@@ -448,9 +780,13 @@ impl FlightServiceInterface for FlightService {
         }
 
         let expected_version = flight.version;
-        flight.change_status(FlightStatus::Cancelled)?;
+        let status_event = flight.change_status(
+            FlightStatusChange::to(FlightStatus::Cancelled),
+            ctx.user_id(),
+            chrono::Utc::now().naive_utc(),
+        )?;
         self.flight_repo
-            .update_flight_status(flight.id, expected_version, FlightStatus::Cancelled)
+            .update_flight_status(&flight, expected_version, &status_event)
             .await
             .map_err(|e| UseCaseError::Unexpected(e.to_string()))?;
 
@@ -478,4 +814,131 @@ impl FlightServiceInterface for FlightService {
 
         Ok(true)
     }
+
+    async fn add_codeshare(
+        &self,
+        ctx: RequestContext,
+        flight_id: i64,
+        command: AddCodeshareCommand,
+    ) -> UseCaseResult<bool> {
+        let _ = ctx
+            .require_user()
+            .map_err(|_| UseCaseError::PermissionDenied)?;
+        if !ctx.is_admin() {
+            return Err(UseCaseError::PermissionDenied);
+        }
+
+        command.validate().map_err(|e| {
+            UseCaseError::Domain(DomainError::Flight(FlightDomainError::Validation {
+                field: "command",
+                message: e.to_string(),
+            }))
+        })?;
+
+        let flight = self
+            .flight_repo
+            .find_flight_by_id(flight_id)
+            .await
+            .map_err(|e| UseCaseError::Unexpected(e.to_string()))?
+            .ok_or_else(|| {
+                UseCaseError::NotFound(format!("Flight with id {} not found", flight_id))
+            })?;
+
+        let airline_code = command.airline_code.trim().to_uppercase();
+        let airline = self
+            .airline_repo
+            .find_airline_by_iata_code(&airline_code)
+            .await
+            .map_err(|e| UseCaseError::Unexpected(e.to_string()))?
+            .ok_or_else(|| {
+                UseCaseError::NotFound(format!("Airline with iata_code {} not found", airline_code))
+            })?;
+        airline.ensure_active()?;
+
+        let codeshare = FlightCodeshare::new(
+            &flight,
+            airline.iata_code,
+            command.flight_number.trim().to_uppercase(),
+        )?;
+
+        // Marketing keys share one namespace with operating keys.
+        let existing = self
+            .flight_repo
+            .find_flight_by_flight_key(&codeshare.flight_key)
+            .await
+            .map_err(|e| UseCaseError::Unexpected(e.to_string()))?;
+        if existing.is_some() {
+            return Err(UseCaseError::AlreadyExists(format!(
+                "Flight with key {} already exists",
+                codeshare.flight_key
+            )));
+        }
+
+        self.flight_repo
+            .create_codeshare(&codeshare)
+            .await
+            .map_err(|e| UseCaseError::Unexpected(e.to_string()))?;
+
+        self.invalidate_flight_cache(&flight).await;
+
+        let _ = self
+            .event_publisher
+            .publish_flight_updated(FlightUpdatedEvent::new(
+                flight.id,
+                flight.flight_key.clone(),
+                chrono::Utc::now().naive_utc(),
+            ))
+            .await;
+
+        Ok(true)
+    }
+
+    async fn remove_codeshare(
+        &self,
+        ctx: RequestContext,
+        flight_id: i64,
+        airline_code: String,
+    ) -> UseCaseResult<bool> {
+        let _ = ctx
+            .require_user()
+            .map_err(|_| UseCaseError::PermissionDenied)?;
+        if !ctx.is_admin() {
+            return Err(UseCaseError::PermissionDenied);
+        }
+
+        let flight = self
+            .flight_repo
+            .find_flight_by_id(flight_id)
+            .await
+            .map_err(|e| UseCaseError::Unexpected(e.to_string()))?
+            .ok_or_else(|| {
+                UseCaseError::NotFound(format!("Flight with id {} not found", flight_id))
+            })?;
+
+        let airline_code = airline_code.trim().to_uppercase();
+        let removed = self
+            .flight_repo
+            .delete_codeshare(flight.id, &airline_code)
+            .await
+            .map_err(|e| UseCaseError::Unexpected(e.to_string()))?;
+        if !removed {
+            return Err(UseCaseError::NotFound(format!(
+                "Flight {} has no codeshare for airline {}",
+                flight.flight_key, airline_code
+            )));
+        }
+
+        self.invalidate_flight_cache(&flight).await;
+
+        let _ = self
+            .event_publisher
+            .publish_flight_updated(FlightUpdatedEvent::new(
+                flight.id,
+                flight.flight_key.clone(),
+                chrono::Utc::now().naive_utc(),
+            ))
+            .await;
+
+        Ok(true)
+    }
 }
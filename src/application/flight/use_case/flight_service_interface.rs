@@ -1,7 +1,8 @@
 use crate::application::common::use_case_error::UseCaseResult;
 use crate::application::flight::flight_command::{
-    CreateFlightCommand, SearchFlightCommand, UpdateFlightCommand,
+    AddCodeshareCommand, CreateFlightCommand, SearchFlightCommand, UpdateFlightCommand,
 };
+use crate::application::flight::view::flight_timeline_view::FlightTimelineView;
 use crate::application::flight::view::flight_view::FlightView;
 use crate::core::context::request_context::RequestContext;
 
@@ -28,6 +29,12 @@ pub trait FlightServiceInterface: Send + Sync {
         flight_key: String,
     ) -> UseCaseResult<FlightView>;
 
+    async fn get_flight_timeline(
+        &self,
+        ctx: RequestContext,
+        id: i64,
+    ) -> UseCaseResult<FlightTimelineView>;
+
     async fn search_flights(
         &self,
         ctx: RequestContext,
@@ -35,4 +42,18 @@ pub trait FlightServiceInterface: Send + Sync {
     ) -> UseCaseResult<Vec<FlightView>>;
 
     async fn cancel_flight(&self, ctx: RequestContext, id: i64) -> UseCaseResult<bool>;
+
+    async fn add_codeshare(
+        &self,
+        ctx: RequestContext,
+        flight_id: i64,
+        command: AddCodeshareCommand,
+    ) -> UseCaseResult<bool>;
+
+    async fn remove_codeshare(
+        &self,
+        ctx: RequestContext,
+        flight_id: i64,
+        airline_code: String,
+    ) -> UseCaseResult<bool>;
 }
@@ -0,0 +1,31 @@
+use crate::application::flight::view::flight_view::FlightOperationalTimesView;
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct FlightTimelineView {
+    pub flight_id: i64,
+    pub flight_key: String,
+    pub status: String,
+    pub scheduled_departure: String,
+    pub scheduled_arrival: String,
+    pub operational_times: FlightOperationalTimesView,
+    pub departure_delay_minutes: Option<i32>,
+    pub arrival_delay_minutes: Option<i32>,
+    /// Status changes, oldest first.
+    pub events: Vec<FlightStatusEventView>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct FlightStatusEventView {
+    pub id: i64,
+    pub from_status: String,
+    pub to_status: String,
+    pub scheduled_departure: String,
+    pub scheduled_arrival: String,
+    pub operational_times: FlightOperationalTimesView,
+    pub delay_minutes: Option<i32>,
+    pub delay_codes: Vec<String>,
+    pub reason: Option<String>,
+    pub changed_by: Option<i64>,
+    pub recorded_at: String,
+}
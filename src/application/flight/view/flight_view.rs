@@ -8,10 +8,14 @@ pub struct FlightView {
     pub flight_key: String,
     pub origin_airport_id: i64,
     pub destination_airport_id: i64,
+    pub route_id: Option<i64>,
     pub departure_date: String,
     pub departure_time: String,
     pub arrival_time: String,
     pub status: String,
+    pub operational_times: FlightOperationalTimesView,
+    /// Minutes behind schedule at departure, from the best known departure time.
+    pub delay_minutes: Option<i32>,
     pub aircraft_type: Option<String>,
     pub tail_number: Option<String>,
     pub terminal_departure: Option<String>,
@@ -23,4 +27,23 @@ pub struct FlightView {
     pub total_seats: i32,
     pub available_seats: i32,
     pub version: i32,
+    /// Marketing designators partner airlines sell this flight under.
+    pub codeshares: Vec<FlightCodeshareView>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct FlightCodeshareView {
+    pub airline_code: String,
+    pub flight_number: String,
+    pub flight_key: String,
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct FlightOperationalTimesView {
+    pub estimated_departure: Option<String>,
+    pub estimated_arrival: Option<String>,
+    pub actual_off_block: Option<String>,
+    pub actual_takeoff: Option<String>,
+    pub actual_landing: Option<String>,
+    pub actual_on_block: Option<String>,
 }
@@ -1 +1,2 @@
-pub mod flight_view;
\ No newline at end of file
+pub mod flight_view;
+pub mod flight_timeline_view;
\ No newline at end of file
@@ -0,0 +1,6 @@
+/// Flights to follow, given directly or through the user's booking codes.
+#[derive(Debug, Clone, Default)]
+pub struct SubscribeFlightStatusCommand {
+    pub flight_ids: Vec<i64>,
+    pub booking_codes: Vec<String>,
+}
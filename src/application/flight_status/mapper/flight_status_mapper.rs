@@ -0,0 +1,27 @@
+use crate::application::flight::mapper::flight_mapper::flight_status_to_string;
+use crate::application::flight_status::view::flight_status_view::FlightStatusUpdateView;
+use crate::domain::flight::entity::Flight;
+use chrono::NaiveDateTime;
+
+impl FlightStatusUpdateView {
+    pub fn new(flight: &Flight, occurred_at: NaiveDateTime) -> Self {
+        Self {
+            flight_id: flight.id,
+            flight_key: flight.flight_key.clone(),
+            status: flight_status_to_string(&flight.status),
+            scheduled_departure: flight
+                .departure_time
+                .format("%Y-%m-%dT%H:%M:%S")
+                .to_string(),
+            scheduled_arrival: flight.arrival_time.format("%Y-%m-%dT%H:%M:%S").to_string(),
+            operational_times: (&flight.operational_times).into(),
+            delay_minutes: flight.departure_delay_minutes(),
+            terminal_departure: flight.terminal_departure.clone(),
+            gate: flight.gate.clone(),
+            boarding_time: flight
+                .boarding_time
+                .map(|v| v.format("%Y-%m-%dT%H:%M:%S").to_string()),
+            occurred_at: occurred_at.format("%Y-%m-%dT%H:%M:%S").to_string(),
+        }
+    }
+}
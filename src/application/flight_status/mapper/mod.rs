@@ -0,0 +1 @@
+pub mod flight_status_mapper;
\ No newline at end of file
@@ -0,0 +1,129 @@
+use crate::application::common::use_case_error::{UseCaseError, UseCaseResult};
+use crate::application::flight_status::flight_status_command::SubscribeFlightStatusCommand;
+use crate::application::flight_status::use_case::flight_status_service_interface::FlightStatusServiceInterface;
+use crate::application::flight_status::view::flight_status_view::FlightStatusUpdateView;
+use crate::core::context::request_context::RequestContext;
+use crate::domain::booking::booking_repository_interface::BookingRepositoryInterface;
+use crate::domain::error::DomainError;
+use crate::domain::flight::error::FlightDomainError;
+use crate::domain::flight::flight_repository_interface::FlightRepositoryInterface;
+use chrono::NaiveDateTime;
+use std::sync::Arc;
+use tokio::sync::broadcast;
+
+/// Most flights one connection may follow.
+pub const MAX_SUBSCRIBED_FLIGHTS: usize = 20;
+
+// Updates a slow subscriber may fall behind by before it starts missing them.
+const UPDATE_BUFFER: usize = 1024;
+
+pub struct FlightStatusService {
+    pub flight_repo: Arc<dyn FlightRepositoryInterface>,
+    pub booking_repo: Arc<dyn BookingRepositoryInterface>,
+    updates: broadcast::Sender<FlightStatusUpdateView>,
+}
+
+impl FlightStatusService {
+    pub fn new(
+        flight_repo: Arc<dyn FlightRepositoryInterface>,
+        booking_repo: Arc<dyn BookingRepositoryInterface>,
+    ) -> Self {
+        let (updates, _) = broadcast::channel(UPDATE_BUFFER);
+        Self {
+            flight_repo,
+            booking_repo,
+            updates,
+        }
+    }
+
+    fn validation_error(field: &'static str, message: String) -> UseCaseError {
+        UseCaseError::Domain(DomainError::Flight(FlightDomainError::Validation {
+            field,
+            message,
+        }))
+    }
+}
+
+#[async_trait::async_trait]
+impl FlightStatusServiceInterface for FlightStatusService {
+    async fn subscribe(
+        &self,
+        ctx: RequestContext,
+        command: SubscribeFlightStatusCommand,
+    ) -> UseCaseResult<Vec<FlightStatusUpdateView>> {
+        let actor_user_id = ctx.user_id().ok_or(UseCaseError::PermissionDenied)?;
+
+        if command.flight_ids.is_empty() && command.booking_codes.is_empty() {
+            return Err(Self::validation_error(
+                "flight_ids",
+                "Give at least one flight id or booking code".to_string(),
+            ));
+        }
+        if command.flight_ids.len() + command.booking_codes.len() > MAX_SUBSCRIBED_FLIGHTS {
+            return Err(Self::validation_error(
+                "flight_ids",
+                format!("At most {MAX_SUBSCRIBED_FLIGHTS} flights can be followed"),
+            ));
+        }
+
+        let mut flight_ids = command.flight_ids;
+        for code in command.booking_codes {
+            let code = code.trim().to_uppercase();
+            let booking = self
+                .booking_repo
+                .find_booking_by_code(&code)
+                .await
+                .map_err(|e| UseCaseError::Unexpected(e.to_string()))?
+                .ok_or_else(|| {
+                    UseCaseError::NotFound(format!("Booking with code {} not found", code))
+                })?;
+            if !ctx.is_admin() && booking.user_id != actor_user_id {
+                return Err(UseCaseError::PermissionDenied);
+            }
+            flight_ids.push(booking.flight_id);
+        }
+        flight_ids.sort_unstable();
+        flight_ids.dedup();
+
+        let now = chrono::Utc::now().naive_utc();
+        let mut views = Vec::with_capacity(flight_ids.len());
+        for id in flight_ids {
+            let flight = self
+                .flight_repo
+                .find_flight_by_id(id)
+                .await
+                .map_err(|e| UseCaseError::Unexpected(e.to_string()))?
+                .ok_or_else(|| {
+                    UseCaseError::NotFound(format!("Flight with id {} not found", id))
+                })?;
+            views.push(FlightStatusUpdateView::new(&flight, now));
+        }
+
+        Ok(views)
+    }
+
+    fn updates(&self) -> broadcast::Receiver<FlightStatusUpdateView> {
+        self.updates.subscribe()
+    }
+
+    async fn publish_flight_changed(
+        &self,
+        flight_id: i64,
+        occurred_at: NaiveDateTime,
+    ) -> UseCaseResult<()> {
+        let Some(flight) = self
+            .flight_repo
+            .find_flight_by_id(flight_id)
+            .await
+            .map_err(|e| UseCaseError::Unexpected(e.to_string()))?
+        else {
+            return Ok(());
+        };
+
+        // Sending only fails when nobody on this instance is listening.
+        let _ = self
+            .updates
+            .send(FlightStatusUpdateView::new(&flight, occurred_at));
+        Ok(())
+    }
+}
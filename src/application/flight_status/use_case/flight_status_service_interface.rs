@@ -0,0 +1,26 @@
+use crate::application::common::use_case_error::UseCaseResult;
+use crate::application::flight_status::flight_status_command::SubscribeFlightStatusCommand;
+use crate::application::flight_status::view::flight_status_view::FlightStatusUpdateView;
+use crate::core::context::request_context::RequestContext;
+use chrono::NaiveDateTime;
+use tokio::sync::broadcast;
+
+#[async_trait::async_trait]
+pub trait FlightStatusServiceInterface: Send + Sync {
+    /// Checks the caller may follow the flights and returns their current status.
+    async fn subscribe(
+        &self,
+        ctx: RequestContext,
+        command: SubscribeFlightStatusCommand,
+    ) -> UseCaseResult<Vec<FlightStatusUpdateView>>;
+
+    /// Updates of every flight changed from now on; receivers keep the ones they follow.
+    fn updates(&self) -> broadcast::Receiver<FlightStatusUpdateView>;
+
+    /// Reloads a changed flight and hands it to this instance's subscribers.
+    async fn publish_flight_changed(
+        &self,
+        flight_id: i64,
+        occurred_at: NaiveDateTime,
+    ) -> UseCaseResult<()>;
+}
@@ -0,0 +1,2 @@
+pub mod flight_status_service;
+pub mod flight_status_service_interface;
\ No newline at end of file
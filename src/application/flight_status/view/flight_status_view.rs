@@ -0,0 +1,18 @@
+use crate::application::flight::view::flight_view::FlightOperationalTimesView;
+use serde::{Deserialize, Serialize};
+
+/// What a status subscriber sees of a flight after each change.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct FlightStatusUpdateView {
+    pub flight_id: i64,
+    pub flight_key: String,
+    pub status: String,
+    pub scheduled_departure: String,
+    pub scheduled_arrival: String,
+    pub operational_times: FlightOperationalTimesView,
+    pub delay_minutes: Option<i32>,
+    pub terminal_departure: Option<String>,
+    pub gate: Option<String>,
+    pub boarding_time: Option<String>,
+    pub occurred_at: String,
+}
@@ -0,0 +1 @@
+pub mod flight_status_view;
\ No newline at end of file
@@ -0,0 +1,63 @@
+use crate::application::manifest::view::manifest_view::FlightManifestView;
+
+const MANIFEST_HEADER: [&str; 13] = [
+    "booking_code",
+    "passenger_id",
+    "passenger_type",
+    "last_name",
+    "first_name",
+    "dob",
+    "gender",
+    "nationality_code",
+    "passport_no",
+    "passport_issuing_country_code",
+    "passport_expiry_date",
+    "seat_no",
+    "checked_in",
+];
+
+pub fn manifest_to_csv(manifest: &FlightManifestView) -> String {
+    let mut out = String::new();
+    push_row(&mut out, MANIFEST_HEADER.iter().map(|h| h.to_string()));
+
+    for entry in &manifest.entries {
+        push_row(
+            &mut out,
+            [
+                entry.booking_code.clone(),
+                entry.passenger_id.to_string(),
+                entry.passenger_type.clone(),
+                entry.last_name.clone(),
+                entry.first_name.clone(),
+                entry.dob.clone(),
+                entry.gender.clone(),
+                entry.nationality_code.clone(),
+                entry.passport_no.clone().unwrap_or_default(),
+                entry
+                    .passport_issuing_country_code
+                    .clone()
+                    .unwrap_or_default(),
+                entry.passport_expiry_date.clone().unwrap_or_default(),
+                entry.seat_no.clone().unwrap_or_default(),
+                entry.checked_in.to_string(),
+            ],
+        );
+    }
+
+    out
+}
+
+fn push_row<I: IntoIterator<Item = String>>(out: &mut String, fields: I) {
+    let row: Vec<String> = fields.into_iter().map(|f| escape_field(&f)).collect();
+    out.push_str(&row.join(","));
+    out.push_str("\r\n");
+}
+
+// RFC 4180: quote fields containing separators, quotes or line breaks.
+fn escape_field(value: &str) -> String {
+    if value.contains([',', '"', '\n', '\r']) {
+        format!("\"{}\"", value.replace('"', "\"\""))
+    } else {
+        value.to_string()
+    }
+}
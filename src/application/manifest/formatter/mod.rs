@@ -0,0 +1,2 @@
+pub mod csv_formatter;
+pub mod paxlst_formatter;
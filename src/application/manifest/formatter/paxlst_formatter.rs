@@ -0,0 +1,143 @@
+use crate::application::manifest::view::manifest_view::{FlightManifestView, ManifestEntryView};
+
+const SERVICE_STRING_ADVICE: &str = "UNA:+.? '";
+const RECIPIENT_ID: &str = "APIS";
+
+/// Renders a UN/EDIFACT PAXLST (D05B, IATA profile) passenger list.
+///
+/// One message per flight; every passenger is emitted as an NAD+FL group followed by
+/// its attributes and travel document.
+pub fn manifest_to_paxlst(manifest: &FlightManifestView, interchange_ref: &str) -> String {
+    let generated = compact_digits(&manifest.generated_at);
+    let prepared_date = slice_or_empty(&generated, 2, 8);
+    let prepared_time = slice_or_empty(&generated, 8, 12);
+
+    let mut segments: Vec<String> = Vec::new();
+
+    segments.push(format!(
+        "UNH+1+PAXLST:D:05B:UN:IATA+{}",
+        escape(&format!(
+            "{}{}{}",
+            manifest.airline_code, manifest.flight_number, prepared_date
+        ))
+    ));
+    segments.push("BGM+745".to_string());
+    segments.push(format!("NAD+MS+++{}", escape(&manifest.airline_code)));
+    segments.push(format!(
+        "TDT+20+{}{}",
+        escape(&manifest.airline_code),
+        escape(&manifest.flight_number)
+    ));
+    segments.push(format!("LOC+125+{}", escape(&manifest.origin_iata_code)));
+    segments.push(format!(
+        "DTM+189:{}:201",
+        slice_or_empty(&compact_digits(&manifest.departure_time), 2, 12)
+    ));
+    segments.push(format!(
+        "LOC+87+{}",
+        escape(&manifest.destination_iata_code)
+    ));
+    segments.push(format!(
+        "DTM+232:{}:201",
+        slice_or_empty(&compact_digits(&manifest.arrival_time), 2, 12)
+    ));
+
+    for entry in &manifest.entries {
+        push_passenger(&mut segments, manifest, entry);
+    }
+
+    segments.push(format!("CNT+42:{}", manifest.entries.len()));
+
+    // UNT counts every segment of the message including UNH and UNT itself.
+    let message_segment_count = segments.len() + 1;
+    segments.push(format!("UNT+{message_segment_count}+1"));
+
+    let mut out = String::new();
+    out.push_str(SERVICE_STRING_ADVICE);
+    out.push('\n');
+    out.push_str(&format!(
+        "UNB+UNOA:4+{}+{}+{}:{}+{}'",
+        escape(&manifest.airline_code),
+        RECIPIENT_ID,
+        prepared_date,
+        prepared_time,
+        escape(interchange_ref)
+    ));
+    out.push('\n');
+    for segment in segments {
+        out.push_str(&segment);
+        out.push_str("'\n");
+    }
+    out.push_str(&format!("UNZ+1+{}'", escape(interchange_ref)));
+    out.push('\n');
+
+    out
+}
+
+fn push_passenger(
+    segments: &mut Vec<String>,
+    manifest: &FlightManifestView,
+    entry: &ManifestEntryView,
+) {
+    segments.push(format!(
+        "NAD+FL+++{}:{}",
+        escape(&entry.last_name.to_uppercase()),
+        escape(&entry.first_name.to_uppercase())
+    ));
+    segments.push(format!("ATT+2++{}", edifact_gender(&entry.gender)));
+    segments.push(format!(
+        "DTM+329:{}",
+        slice_or_empty(&compact_digits(&entry.dob), 2, 8)
+    ));
+    segments.push(format!("LOC+178+{}", escape(&manifest.origin_iata_code)));
+    segments.push(format!(
+        "LOC+179+{}",
+        escape(&manifest.destination_iata_code)
+    ));
+    segments.push(format!("NAT+2+{}", escape(&entry.nationality_code)));
+    segments.push(format!("RFF+AVF:{}", escape(&entry.booking_code)));
+    if let Some(seat_no) = &entry.seat_no {
+        segments.push(format!("RFF+SEA:{}", escape(seat_no)));
+    }
+
+    if let Some(passport_no) = &entry.passport_no {
+        segments.push(format!("DOC+P:110:111+{}", escape(passport_no)));
+        if let Some(expiry) = &entry.passport_expiry_date {
+            segments.push(format!(
+                "DTM+36:{}",
+                slice_or_empty(&compact_digits(expiry), 2, 8)
+            ));
+        }
+        if let Some(country) = &entry.passport_issuing_country_code {
+            segments.push(format!("LOC+91+{}", escape(country)));
+        }
+    }
+}
+
+fn edifact_gender(gender: &str) -> &'static str {
+    match gender.trim().to_uppercase().chars().next() {
+        Some('M') => "M",
+        Some('F') => "F",
+        _ => "U",
+    }
+}
+
+// Release character `?` must precede any of the service characters used as data.
+fn escape(value: &str) -> String {
+    let mut out = String::with_capacity(value.len());
+    for c in value.chars() {
+        if matches!(c, '?' | '+' | ':' | '\'') {
+            out.push('?');
+        }
+        out.push(c);
+    }
+    out
+}
+
+fn compact_digits(value: &str) -> String {
+    value.chars().filter(|c| c.is_ascii_digit()).collect()
+}
+
+fn slice_or_empty(value: &str, start: usize, end: usize) -> &str {
+    value.get(start..end.min(value.len())).unwrap_or("")
+}
@@ -0,0 +1,43 @@
+use crate::application::common::use_case_error::{UseCaseError, UseCaseResult};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ManifestFormat {
+    Csv,
+    Json,
+    Paxlst,
+}
+
+impl ManifestFormat {
+    pub fn parse(value: &str) -> UseCaseResult<Self> {
+        match value.trim().to_lowercase().as_str() {
+            "csv" => Ok(ManifestFormat::Csv),
+            "json" => Ok(ManifestFormat::Json),
+            "paxlst" | "edifact" => Ok(ManifestFormat::Paxlst),
+            _ => Err(UseCaseError::BusinessRule(format!(
+                "Invalid manifest format: {value}"
+            ))),
+        }
+    }
+
+    pub fn content_type(&self) -> &'static str {
+        match self {
+            ManifestFormat::Csv => "text/csv; charset=utf-8",
+            ManifestFormat::Json => "application/json",
+            ManifestFormat::Paxlst => "text/plain; charset=utf-8",
+        }
+    }
+
+    pub fn extension(&self) -> &'static str {
+        match self {
+            ManifestFormat::Csv => "csv",
+            ManifestFormat::Json => "json",
+            ManifestFormat::Paxlst => "edi",
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct ExportManifestCommand {
+    pub flight_id: i64,
+    pub format: ManifestFormat,
+}
@@ -0,0 +1,4 @@
+pub mod formatter;
+pub mod manifest_command;
+pub mod use_case;
+pub mod view;
@@ -0,0 +1,310 @@
+use crate::application::common::use_case_error::{UseCaseError, UseCaseResult};
+use crate::application::manifest::formatter::csv_formatter::manifest_to_csv;
+use crate::application::manifest::formatter::paxlst_formatter::manifest_to_paxlst;
+use crate::application::manifest::manifest_command::{ExportManifestCommand, ManifestFormat};
+use crate::application::manifest::use_case::manifest_service_interface::ManifestServiceInterface;
+use crate::application::manifest::view::manifest_view::{
+    FlightManifestView, ManifestEntryView, ManifestExportView, ManifestIssueView,
+};
+use crate::application::passenger::mapper::passenger_mapper::passenger_type_to_string;
+use crate::core::context::request_context::RequestContext;
+use crate::domain::airport::airport_repository_interface::AirportRepositoryInterface;
+use crate::domain::airport::entity::Airport;
+use crate::domain::booking::booking_repository_interface::BookingRepositoryInterface;
+use crate::domain::booking::entity::{Booking, BookingStatus};
+use crate::domain::checkin::checkin_repository_interface::CheckinRepositoryInterface;
+use crate::domain::checkin::entity::{Checkin, CheckinStatus};
+use crate::domain::flight::entity::Flight;
+use crate::domain::flight::flight_repository_interface::FlightRepositoryInterface;
+use crate::domain::passenger::entity::Passenger;
+use crate::domain::passenger::passenger_repository_interface::PassengerRepositoryInterface;
+use crate::domain::passenger::travel_document_policy::TravelDocumentPolicy;
+use std::collections::HashMap;
+use std::sync::Arc;
+
+pub struct ManifestService {
+    pub flight_repo: Arc<dyn FlightRepositoryInterface>,
+    pub airport_repo: Arc<dyn AirportRepositoryInterface>,
+    pub booking_repo: Arc<dyn BookingRepositoryInterface>,
+    pub passenger_repo: Arc<dyn PassengerRepositoryInterface>,
+    pub checkin_repo: Arc<dyn CheckinRepositoryInterface>,
+    pub document_policy: Arc<TravelDocumentPolicy>,
+}
+
+impl ManifestService {
+    pub fn new(
+        flight_repo: Arc<dyn FlightRepositoryInterface>,
+        airport_repo: Arc<dyn AirportRepositoryInterface>,
+        booking_repo: Arc<dyn BookingRepositoryInterface>,
+        passenger_repo: Arc<dyn PassengerRepositoryInterface>,
+        checkin_repo: Arc<dyn CheckinRepositoryInterface>,
+        document_policy: Arc<TravelDocumentPolicy>,
+    ) -> Self {
+        Self {
+            flight_repo,
+            airport_repo,
+            booking_repo,
+            passenger_repo,
+            checkin_repo,
+            document_policy,
+        }
+    }
+
+    fn ensure_manifest_access(ctx: &RequestContext) -> UseCaseResult<()> {
+        if !ctx.is_admin() && !ctx.is_staff() && !ctx.is_system() {
+            return Err(UseCaseError::PermissionDenied);
+        }
+        Ok(())
+    }
+
+    async fn load_airport(&self, airport_id: i64) -> UseCaseResult<Airport> {
+        self.airport_repo
+            .find_airport_by_id(airport_id)
+            .await
+            .map_err(|e| UseCaseError::Unexpected(e.to_string()))?
+            .ok_or_else(|| {
+                UseCaseError::NotFound(format!("Airport with id {} not found", airport_id))
+            })
+    }
+
+    fn build_entry(
+        booking: &Booking,
+        passenger: &Passenger,
+        checkin: Option<&Checkin>,
+    ) -> ManifestEntryView {
+        ManifestEntryView {
+            booking_code: booking.booking_code.clone(),
+            passenger_id: passenger.id,
+            passenger_type: passenger_type_to_string(&passenger.passenger_type),
+            first_name: passenger.first_name.clone(),
+            last_name: passenger.last_name.clone(),
+            dob: passenger.dob.format("%Y-%m-%d").to_string(),
+            gender: passenger.gender.clone(),
+            nationality_code: passenger.nationality_code.clone(),
+            passport_no: passenger.passport_no.clone(),
+            passport_issuing_country_code: passenger.passport_issuing_country_code.clone(),
+            passport_expiry_date: passenger
+                .passport_expiry_date
+                .map(|v| v.format("%Y-%m-%d").to_string()),
+            seat_no: checkin.and_then(|c| c.seat_no.clone()),
+            checked_in: checkin.is_some_and(|c| {
+                matches!(c.status, CheckinStatus::CheckedIn | CheckinStatus::Boarded)
+            }),
+        }
+    }
+
+    // Unlike check-in, which stops at the first broken rule, the manifest report lists
+    // every gap so the whole flight can be fixed in one pass before transmission.
+    fn collect_issues(
+        &self,
+        flight: &Flight,
+        origin: &Airport,
+        destination: &Airport,
+        booking: &Booking,
+        passenger: &Passenger,
+    ) -> Vec<ManifestIssueView> {
+        let mut issues = Vec::new();
+        let mut push = |field: &str, message: String| {
+            issues.push(ManifestIssueView {
+                passenger_id: passenger.id,
+                booking_code: booking.booking_code.clone(),
+                field: field.to_string(),
+                message,
+            });
+        };
+
+        if passenger.nationality_code.trim().is_empty() {
+            push("nationality_code", "Nationality is missing".to_string());
+        }
+
+        if !matches!(
+            passenger.gender.trim().to_uppercase().chars().next(),
+            Some('M') | Some('F')
+        ) {
+            push(
+                "gender",
+                format!("Gender '{}' cannot be reported as M or F", passenger.gender),
+            );
+        }
+
+        let requirements = self.document_policy.requirements_for(
+            &passenger.nationality_code,
+            &origin.country_code,
+            &destination.country_code,
+            flight.departure_date,
+        );
+        if !requirements.passport_required {
+            return issues;
+        }
+
+        if passenger
+            .passport_no
+            .as_deref()
+            .unwrap_or("")
+            .trim()
+            .is_empty()
+        {
+            push("passport_no", "Passport number is missing".to_string());
+        }
+        if passenger
+            .passport_issuing_country_code
+            .as_deref()
+            .unwrap_or("")
+            .trim()
+            .is_empty()
+        {
+            push(
+                "passport_issuing_country_code",
+                "Passport issuing country is missing".to_string(),
+            );
+        }
+        match (
+            passenger.passport_expiry_date,
+            requirements.passport_valid_until,
+        ) {
+            (None, _) => push(
+                "passport_expiry_date",
+                "Passport expiry date is missing".to_string(),
+            ),
+            (Some(expiry), Some(valid_until)) if expiry < valid_until => push(
+                "passport_expiry_date",
+                format!(
+                    "Passport expires on {expiry} but must be valid until at least {valid_until}"
+                ),
+            ),
+            _ => {}
+        }
+
+        issues
+    }
+}
+
+#[async_trait::async_trait]
+impl ManifestServiceInterface for ManifestService {
+    async fn get_flight_manifest(
+        &self,
+        ctx: RequestContext,
+        flight_id: i64,
+    ) -> UseCaseResult<FlightManifestView> {
+        Self::ensure_manifest_access(&ctx)?;
+
+        let flight = self
+            .flight_repo
+            .find_flight_by_id(flight_id)
+            .await
+            .map_err(|e| UseCaseError::Unexpected(e.to_string()))?
+            .ok_or_else(|| {
+                UseCaseError::NotFound(format!("Flight with id {} not found", flight_id))
+            })?;
+
+        let origin = self.load_airport(flight.origin_airport_id).await?;
+        let destination = self.load_airport(flight.destination_airport_id).await?;
+
+        let bookings = self
+            .booking_repo
+            .list_bookings_by_flight(flight.id)
+            .await
+            .map_err(|e| UseCaseError::Unexpected(e.to_string()))?;
+
+        let mut entries = Vec::new();
+        let mut issues = Vec::new();
+
+        for booking in bookings
+            .iter()
+            .filter(|b| b.status == BookingStatus::Confirmed)
+        {
+            let passengers = self
+                .passenger_repo
+                .list_passengers_by_booking(booking.id)
+                .await
+                .map_err(|e| UseCaseError::Unexpected(e.to_string()))?;
+
+            // One query per booking instead of one per passenger. Cancelled check-ins are
+            // dropped first so they cannot shadow the passenger's active one.
+            let checkins: HashMap<i64, Checkin> = self
+                .checkin_repo
+                .list_checkins_by_booking(booking.id)
+                .await
+                .map_err(|e| UseCaseError::Unexpected(e.to_string()))?
+                .into_iter()
+                .filter(|c| c.status != CheckinStatus::Cancelled)
+                .map(|c| (c.passenger_id, c))
+                .collect();
+
+            for passenger in &passengers {
+                entries.push(Self::build_entry(
+                    booking,
+                    passenger,
+                    checkins.get(&passenger.id),
+                ));
+                issues.extend(self.collect_issues(
+                    &flight,
+                    &origin,
+                    &destination,
+                    booking,
+                    passenger,
+                ));
+            }
+        }
+
+        entries.sort_by(|a, b| {
+            (a.last_name.to_uppercase(), a.first_name.to_uppercase())
+                .cmp(&(b.last_name.to_uppercase(), b.first_name.to_uppercase()))
+        });
+
+        Ok(FlightManifestView {
+            flight_id: flight.id,
+            airline_code: flight.airline_code.clone(),
+            flight_number: flight.flight_number.clone(),
+            departure_date: flight.departure_date.format("%Y-%m-%d").to_string(),
+            departure_time: flight
+                .departure_time
+                .format("%Y-%m-%dT%H:%M:%S")
+                .to_string(),
+            arrival_time: flight.arrival_time.format("%Y-%m-%dT%H:%M:%S").to_string(),
+            origin_iata_code: origin.iata_code.clone(),
+            origin_country_code: origin.country_code.clone(),
+            destination_iata_code: destination.iata_code.clone(),
+            destination_country_code: destination.country_code.clone(),
+            generated_at: chrono::Utc::now()
+                .naive_utc()
+                .format("%Y-%m-%dT%H:%M:%S")
+                .to_string(),
+            passenger_count: entries.len(),
+            entries,
+            issues,
+        })
+    }
+
+    async fn export_flight_manifest(
+        &self,
+        ctx: RequestContext,
+        command: ExportManifestCommand,
+    ) -> UseCaseResult<ManifestExportView> {
+        let manifest = self.get_flight_manifest(ctx, command.flight_id).await?;
+
+        let content = match command.format {
+            ManifestFormat::Csv => manifest_to_csv(&manifest),
+            ManifestFormat::Json => serde_json::to_string_pretty(&manifest)
+                .map_err(|e| UseCaseError::Unexpected(e.to_string()))?,
+            ManifestFormat::Paxlst => {
+                let interchange_ref =
+                    format!("{}{}", manifest.flight_id, chrono::Utc::now().timestamp());
+                manifest_to_paxlst(&manifest, &interchange_ref)
+            }
+        };
+
+        Ok(ManifestExportView {
+            file_name: format!(
+                "manifest_{}{}_{}.{}",
+                manifest.airline_code,
+                manifest.flight_number,
+                manifest.departure_date,
+                command.format.extension()
+            ),
+            content_type: command.format.content_type().to_string(),
+            content,
+            issue_count: manifest.issues.len(),
+        })
+    }
+}
@@ -0,0 +1,19 @@
+use crate::application::common::use_case_error::UseCaseResult;
+use crate::application::manifest::manifest_command::ExportManifestCommand;
+use crate::application::manifest::view::manifest_view::{FlightManifestView, ManifestExportView};
+use crate::core::context::request_context::RequestContext;
+
+#[async_trait::async_trait]
+pub trait ManifestServiceInterface: Send + Sync {
+    async fn get_flight_manifest(
+        &self,
+        ctx: RequestContext,
+        flight_id: i64,
+    ) -> UseCaseResult<FlightManifestView>;
+
+    async fn export_flight_manifest(
+        &self,
+        ctx: RequestContext,
+        command: ExportManifestCommand,
+    ) -> UseCaseResult<ManifestExportView>;
+}
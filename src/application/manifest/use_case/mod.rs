@@ -0,0 +1,2 @@
+pub mod manifest_service;
+pub mod manifest_service_interface;
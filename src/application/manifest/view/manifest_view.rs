@@ -0,0 +1,57 @@
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct FlightManifestView {
+    pub flight_id: i64,
+    pub airline_code: String,
+    pub flight_number: String,
+    pub departure_date: String,
+    pub departure_time: String,
+    pub arrival_time: String,
+
+    pub origin_iata_code: String,
+    pub origin_country_code: String,
+    pub destination_iata_code: String,
+    pub destination_country_code: String,
+
+    pub generated_at: String,
+    pub passenger_count: usize,
+    pub entries: Vec<ManifestEntryView>,
+    pub issues: Vec<ManifestIssueView>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ManifestEntryView {
+    pub booking_code: String,
+    pub passenger_id: i64,
+    pub passenger_type: String,
+
+    pub first_name: String,
+    pub last_name: String,
+    pub dob: String,
+    pub gender: String,
+    pub nationality_code: String,
+
+    pub passport_no: Option<String>,
+    pub passport_issuing_country_code: Option<String>,
+    pub passport_expiry_date: Option<String>,
+
+    pub seat_no: Option<String>,
+    pub checked_in: bool,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ManifestIssueView {
+    pub passenger_id: i64,
+    pub booking_code: String,
+    pub field: String,
+    pub message: String,
+}
+
+#[derive(Debug, Clone)]
+pub struct ManifestExportView {
+    pub file_name: String,
+    pub content_type: String,
+    pub content: String,
+    pub issue_count: usize,
+}
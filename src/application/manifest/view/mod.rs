@@ -0,0 +1 @@
+pub mod manifest_view;
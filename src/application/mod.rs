@@ -3,8 +3,20 @@ pub mod auth;
 pub mod common;
 pub mod user;
 pub mod airport;
+pub mod airline;
+pub mod aircraft;
+pub mod route;
+pub mod boarding;
 pub mod boarding_pass;
 pub mod booking;
 pub mod checkin;
 pub mod flight;
 pub mod passenger;
+pub mod manifest;
+
+pub mod no_show;
+pub mod baggage;
+pub mod flight_status;
+pub mod compensation;
+pub mod notification;
+pub mod dead_letter;
\ No newline at end of file
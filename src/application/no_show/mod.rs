@@ -0,0 +1,2 @@
+pub mod use_case;
+pub mod view;
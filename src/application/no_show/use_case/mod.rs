@@ -0,0 +1,2 @@
+pub mod no_show_service;
+pub mod no_show_service_interface;
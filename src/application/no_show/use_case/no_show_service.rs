@@ -0,0 +1,373 @@
+use crate::application::booking::mapper::booking_mapper::fare_family_to_string;
+use crate::application::common::cache_interface::CacheInterface;
+use crate::application::common::event_publisher::PassengerEventPublisher;
+use crate::application::common::use_case_error::{UseCaseError, UseCaseResult};
+use crate::application::no_show::use_case::no_show_service_interface::NoShowServiceInterface;
+use crate::application::no_show::view::no_show_view::{NoShowProcessingView, NoShowView};
+use crate::core::context::request_context::RequestContext;
+use crate::domain::booking::booking_repository_interface::BookingRepositoryInterface;
+use crate::domain::booking::entity::{Booking, BookingStatus};
+use crate::domain::booking::no_show::NoShow;
+use crate::domain::booking::no_show_policy::NoShowPenaltyPolicy;
+use crate::domain::booking::no_show_repository_interface::NoShowRepositoryInterface;
+use crate::domain::checkin::checkin_repository_interface::CheckinRepositoryInterface;
+use crate::domain::checkin::entity::CheckinStatus;
+use crate::domain::error::DomainError;
+use crate::domain::flight::entity::{Flight, FlightStatus};
+use crate::domain::flight::error::FlightDomainError;
+use crate::domain::flight::flight_repository_interface::FlightRepositoryInterface;
+use crate::domain::passenger::entity::Passenger;
+use crate::domain::passenger::events::passenger_no_show::PassengerNoShowEvent;
+use crate::domain::passenger::passenger_repository_interface::PassengerRepositoryInterface;
+use chrono::NaiveDateTime;
+use std::collections::{HashMap, HashSet};
+use std::sync::Arc;
+
+pub struct NoShowService {
+    pub cache: Arc<dyn CacheInterface>,
+    pub flight_repo: Arc<dyn FlightRepositoryInterface>,
+    pub booking_repo: Arc<dyn BookingRepositoryInterface>,
+    pub passenger_repo: Arc<dyn PassengerRepositoryInterface>,
+    pub checkin_repo: Arc<dyn CheckinRepositoryInterface>,
+    pub no_show_repo: Arc<dyn NoShowRepositoryInterface>,
+    pub penalty_policy: Arc<NoShowPenaltyPolicy>,
+    pub event_publisher: Arc<dyn PassengerEventPublisher>,
+}
+
+impl NoShowService {
+    pub fn new(
+        cache: Arc<dyn CacheInterface>,
+        flight_repo: Arc<dyn FlightRepositoryInterface>,
+        booking_repo: Arc<dyn BookingRepositoryInterface>,
+        passenger_repo: Arc<dyn PassengerRepositoryInterface>,
+        checkin_repo: Arc<dyn CheckinRepositoryInterface>,
+        no_show_repo: Arc<dyn NoShowRepositoryInterface>,
+        penalty_policy: Arc<NoShowPenaltyPolicy>,
+        event_publisher: Arc<dyn PassengerEventPublisher>,
+    ) -> Self {
+        Self {
+            cache,
+            flight_repo,
+            booking_repo,
+            passenger_repo,
+            checkin_repo,
+            no_show_repo,
+            penalty_policy,
+            event_publisher,
+        }
+    }
+
+    fn booking_cache_key(id: i64) -> String {
+        format!("booking:id:{id}")
+    }
+
+    fn bookings_user_cache_key(user_id: i64) -> String {
+        format!("bookings:user:{user_id}")
+    }
+
+    fn ensure_no_show_access(ctx: &RequestContext) -> UseCaseResult<()> {
+        if !ctx.is_admin() && !ctx.is_system() {
+            return Err(UseCaseError::PermissionDenied);
+        }
+        Ok(())
+    }
+
+    fn passenger_name(passenger: &Passenger) -> String {
+        format!(
+            "{}/{}",
+            passenger.last_name.trim(),
+            passenger.first_name.trim()
+        )
+    }
+
+    async fn load_flight(&self, flight_id: i64) -> UseCaseResult<Flight> {
+        self.flight_repo
+            .find_flight_by_id(flight_id)
+            .await
+            .map_err(|e| UseCaseError::Unexpected(e.to_string()))?
+            .ok_or_else(|| {
+                UseCaseError::NotFound(format!("Flight with id {} not found", flight_id))
+            })
+    }
+
+    async fn invalidate_booking_cache(&self, booking: &Booking) {
+        let keys = [
+            Self::booking_cache_key(booking.id),
+            Self::bookings_user_cache_key(booking.user_id),
+        ];
+
+        for key in keys {
+            if let Err(err) = self.cache.del(&key).await {
+                tracing::warn!("cache del failed key={}: {}", key, err);
+            }
+        }
+    }
+
+    // Boarded passengers flew. Offloaded passengers were taken off by the airline and
+    // standby passengers were at the gate without a seat, so the no-show penalty does not
+    // apply to them.
+    async fn no_show_exempt(
+        &self,
+        booking_id: i64,
+        passenger_id: i64,
+    ) -> UseCaseResult<(bool, Option<i64>)> {
+        let checkin = self
+            .checkin_repo
+            .find_checkin_by_booking_and_passenger(booking_id, passenger_id)
+            .await
+            .map_err(|e| UseCaseError::Unexpected(e.to_string()))?;
+
+        Ok(match checkin {
+            Some(c) => (
+                matches!(
+                    c.status,
+                    CheckinStatus::Boarded | CheckinStatus::Offloaded | CheckinStatus::Standby
+                ),
+                Some(c.id),
+            ),
+            None => (false, None),
+        })
+    }
+
+    // A booking covers a single flight, so once nobody on it flew there is nothing
+    // onward left to keep open: the booking itself is closed as NO_SHOW.
+    async fn process_flight(
+        &self,
+        flight: &Flight,
+        now: NaiveDateTime,
+    ) -> UseCaseResult<NoShowProcessingView> {
+        if !matches!(
+            flight.status,
+            FlightStatus::Departed | FlightStatus::Arrived
+        ) {
+            return Err(UseCaseError::Domain(DomainError::Flight(
+                FlightDomainError::InvalidOperationForStatus {
+                    status: flight.status.clone(),
+                },
+            )));
+        }
+
+        let already_recorded: HashSet<i64> = self
+            .no_show_repo
+            .list_no_shows_by_flight(flight.id)
+            .await
+            .map_err(|e| UseCaseError::Unexpected(e.to_string()))?
+            .into_iter()
+            .map(|n| n.passenger_id)
+            .collect();
+
+        let bookings = self
+            .booking_repo
+            .list_bookings_by_flight(flight.id)
+            .await
+            .map_err(|e| UseCaseError::Unexpected(e.to_string()))?;
+
+        let mut no_shows = Vec::new();
+        let mut bookings_closed = 0;
+
+        for mut booking in bookings
+            .into_iter()
+            .filter(|b| b.status == BookingStatus::Confirmed)
+        {
+            let passengers = self
+                .passenger_repo
+                .list_passengers_by_booking(booking.id)
+                .await
+                .map_err(|e| UseCaseError::Unexpected(e.to_string()))?;
+            if passengers.is_empty() {
+                continue;
+            }
+
+            let fare_amount = NoShowPenaltyPolicy::fare_share(&booking, passengers.len());
+            let mut unflown = 0;
+
+            for passenger in &passengers {
+                if already_recorded.contains(&passenger.id) {
+                    unflown += 1;
+                    continue;
+                }
+
+                let (exempt, checkin_id) = self.no_show_exempt(booking.id, passenger.id).await?;
+                if exempt {
+                    continue;
+                }
+                unflown += 1;
+
+                let penalty_amount = self
+                    .penalty_policy
+                    .penalty_for(booking.fare_family, fare_amount);
+                let mut no_show = NoShow::record(
+                    &booking,
+                    passenger.id,
+                    checkin_id,
+                    fare_amount,
+                    penalty_amount,
+                    now,
+                );
+                no_show.id = self
+                    .no_show_repo
+                    .create_no_show(&no_show)
+                    .await
+                    .map_err(|e| UseCaseError::Unexpected(e.to_string()))?;
+
+                let fare_family = fare_family_to_string(&no_show.fare_family);
+                let _ = self
+                    .event_publisher
+                    .publish_passenger_no_show(PassengerNoShowEvent::new(
+                        &no_show,
+                        booking.booking_code.clone(),
+                        fare_family.clone(),
+                    ))
+                    .await;
+
+                no_shows.push(NoShowView {
+                    id: no_show.id,
+                    flight_id: no_show.flight_id,
+                    booking_id: no_show.booking_id,
+                    booking_code: booking.booking_code.clone(),
+                    passenger_id: passenger.id,
+                    passenger_name: Self::passenger_name(passenger),
+                    checkin_id: no_show.checkin_id,
+                    fare_family,
+                    fare_amount: no_show.fare_amount.to_string(),
+                    penalty_amount: no_show.penalty_amount.to_string(),
+                    currency: no_show.currency,
+                    recorded_at: no_show.recorded_at.format("%Y-%m-%dT%H:%M:%S").to_string(),
+                });
+            }
+
+            if unflown == passengers.len() {
+                let expected_version = booking.version;
+                booking.mark_no_show()?;
+                self.booking_repo
+                    .update_booking(&booking, expected_version)
+                    .await
+                    .map_err(UseCaseError::Domain)?;
+                self.invalidate_booking_cache(&booking).await;
+                bookings_closed += 1;
+            }
+        }
+
+        self.flight_repo
+            .mark_no_shows_processed(flight.id, now)
+            .await
+            .map_err(|e| UseCaseError::Unexpected(e.to_string()))?;
+
+        Ok(NoShowProcessingView {
+            flight_id: flight.id,
+            airline_code: flight.airline_code.clone(),
+            flight_number: flight.flight_number.clone(),
+            departure_time: flight
+                .departure_time
+                .format("%Y-%m-%dT%H:%M:%S")
+                .to_string(),
+            no_shows,
+            bookings_closed,
+            processed_at: now.format("%Y-%m-%dT%H:%M:%S").to_string(),
+        })
+    }
+}
+
+#[async_trait::async_trait]
+impl NoShowServiceInterface for NoShowService {
+    async fn process_flight_no_shows(
+        &self,
+        ctx: RequestContext,
+        flight_id: i64,
+    ) -> UseCaseResult<NoShowProcessingView> {
+        Self::ensure_no_show_access(&ctx)?;
+
+        let flight = self.load_flight(flight_id).await?;
+        self.process_flight(&flight, chrono::Utc::now().naive_utc())
+            .await
+    }
+
+    async fn process_departed_flights(
+        &self,
+        ctx: RequestContext,
+    ) -> UseCaseResult<Vec<NoShowProcessingView>> {
+        Self::ensure_no_show_access(&ctx)?;
+
+        let flights = self
+            .flight_repo
+            .list_flights_pending_no_shows()
+            .await
+            .map_err(|e| UseCaseError::Unexpected(e.to_string()))?;
+
+        // One failing flight must not hold back the rest; it stays pending for the next run.
+        let mut results = Vec::with_capacity(flights.len());
+        for flight in flights {
+            match self
+                .process_flight(&flight, chrono::Utc::now().naive_utc())
+                .await
+            {
+                Ok(view) => results.push(view),
+                Err(err) => {
+                    tracing::error!("no-show processing failed flight_id={}: {}", flight.id, err)
+                }
+            }
+        }
+
+        Ok(results)
+    }
+
+    async fn list_flight_no_shows(
+        &self,
+        ctx: RequestContext,
+        flight_id: i64,
+    ) -> UseCaseResult<Vec<NoShowView>> {
+        Self::ensure_no_show_access(&ctx)?;
+        let _ = self.load_flight(flight_id).await?;
+
+        let records = self
+            .no_show_repo
+            .list_no_shows_by_flight(flight_id)
+            .await
+            .map_err(|e| UseCaseError::Unexpected(e.to_string()))?;
+
+        // Several passengers usually share a booking; look each booking code up once.
+        let mut booking_codes: HashMap<i64, String> = HashMap::new();
+        let mut views = Vec::with_capacity(records.len());
+        for no_show in records {
+            let booking_code = match booking_codes.get(&no_show.booking_id) {
+                Some(code) => code.clone(),
+                None => {
+                    let code = self
+                        .booking_repo
+                        .find_booking_by_id(no_show.booking_id)
+                        .await
+                        .map_err(|e| UseCaseError::Unexpected(e.to_string()))?
+                        .map(|b| b.booking_code)
+                        .unwrap_or_default();
+                    booking_codes.insert(no_show.booking_id, code.clone());
+                    code
+                }
+            };
+
+            let passenger_name = self
+                .passenger_repo
+                .find_passenger_by_id(no_show.passenger_id)
+                .await
+                .map_err(|e| UseCaseError::Unexpected(e.to_string()))?
+                .map(|p| Self::passenger_name(&p))
+                .unwrap_or_default();
+
+            views.push(NoShowView {
+                id: no_show.id,
+                flight_id: no_show.flight_id,
+                booking_id: no_show.booking_id,
+                booking_code,
+                passenger_id: no_show.passenger_id,
+                passenger_name,
+                checkin_id: no_show.checkin_id,
+                fare_family: fare_family_to_string(&no_show.fare_family),
+                fare_amount: no_show.fare_amount.to_string(),
+                penalty_amount: no_show.penalty_amount.to_string(),
+                currency: no_show.currency,
+                recorded_at: no_show.recorded_at.format("%Y-%m-%dT%H:%M:%S").to_string(),
+            });
+        }
+
+        Ok(views)
+    }
+}
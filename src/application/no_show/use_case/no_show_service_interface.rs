@@ -0,0 +1,24 @@
+use crate::application::common::use_case_error::UseCaseResult;
+use crate::application::no_show::view::no_show_view::{NoShowProcessingView, NoShowView};
+use crate::core::context::request_context::RequestContext;
+
+#[async_trait::async_trait]
+pub trait NoShowServiceInterface: Send + Sync {
+    async fn process_flight_no_shows(
+        &self,
+        ctx: RequestContext,
+        flight_id: i64,
+    ) -> UseCaseResult<NoShowProcessingView>;
+
+    /// Runs no-show processing for every departed flight that has not been processed yet.
+    async fn process_departed_flights(
+        &self,
+        ctx: RequestContext,
+    ) -> UseCaseResult<Vec<NoShowProcessingView>>;
+
+    async fn list_flight_no_shows(
+        &self,
+        ctx: RequestContext,
+        flight_id: i64,
+    ) -> UseCaseResult<Vec<NoShowView>>;
+}
@@ -0,0 +1,30 @@
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct NoShowView {
+    pub id: i64,
+    pub flight_id: i64,
+    pub booking_id: i64,
+    pub booking_code: String,
+    pub passenger_id: i64,
+    pub passenger_name: String,
+    pub checkin_id: Option<i64>,
+    pub fare_family: String,
+    pub fare_amount: String,
+    pub penalty_amount: String,
+    pub currency: String,
+    pub recorded_at: String,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct NoShowProcessingView {
+    pub flight_id: i64,
+    pub airline_code: String,
+    pub flight_number: String,
+    pub departure_time: String,
+    /// No-shows recorded by this run; passengers recorded earlier are not repeated.
+    pub no_shows: Vec<NoShowView>,
+    /// Bookings moved to `NO_SHOW` because none of their passengers flew.
+    pub bookings_closed: usize,
+    pub processed_at: String,
+}
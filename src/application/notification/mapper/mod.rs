@@ -0,0 +1 @@
+pub mod notification_mapper;
\ No newline at end of file
@@ -0,0 +1,76 @@
+use crate::application::notification::view::notification_view::{
+    NotificationPreferenceView, NotificationView,
+};
+use crate::domain::notification::entity::{
+    Notification, NotificationChannelKind, NotificationKind, NotificationStatus,
+};
+use crate::domain::notification::preference::NotificationPreference;
+
+pub fn notification_to_view(notification: Notification) -> NotificationView {
+    NotificationView {
+        id: notification.id,
+        kind: notification_kind_to_string(&notification.kind),
+        channel: notification_channel_to_string(&notification.channel),
+        status: notification_status_to_string(&notification.status),
+        user_id: notification.user_id,
+        booking_id: notification.booking_id,
+        flight_id: notification.flight_id,
+        recipient: notification.recipient,
+        locale: notification.locale,
+        subject: notification.subject,
+        body: notification.body,
+        attempts: notification.attempts,
+        last_error: notification.last_error,
+        scheduled_at: notification
+            .scheduled_at
+            .format("%Y-%m-%dT%H:%M:%S")
+            .to_string(),
+        sent_at: notification
+            .sent_at
+            .map(|v| v.format("%Y-%m-%dT%H:%M:%S").to_string()),
+    }
+}
+
+pub fn preference_to_view(
+    preference: NotificationPreference,
+    effective_locale: String,
+) -> NotificationPreferenceView {
+    NotificationPreferenceView {
+        user_id: preference.user_id,
+        locale: preference.locale,
+        effective_locale,
+        email_enabled: preference.email_enabled,
+        sms_enabled: preference.sms_enabled,
+        push_enabled: preference.push_enabled,
+        push_token: preference.push_token,
+    }
+}
+
+pub fn notification_kind_to_string(value: &NotificationKind) -> String {
+    match value {
+        NotificationKind::EmailVerification => "EMAIL_VERIFICATION",
+        NotificationKind::BookingConfirmation => "BOOKING_CONFIRMATION",
+        NotificationKind::CheckinReminder => "CHECKIN_REMINDER",
+        NotificationKind::FlightDisruption => "FLIGHT_DISRUPTION",
+    }
+    .to_string()
+}
+
+pub fn notification_channel_to_string(value: &NotificationChannelKind) -> String {
+    match value {
+        NotificationChannelKind::Email => "EMAIL",
+        NotificationChannelKind::Sms => "SMS",
+        NotificationChannelKind::Push => "PUSH",
+    }
+    .to_string()
+}
+
+pub fn notification_status_to_string(value: &NotificationStatus) -> String {
+    match value {
+        NotificationStatus::Pending => "PENDING",
+        NotificationStatus::Sent => "SENT",
+        NotificationStatus::Failed => "FAILED",
+        NotificationStatus::Cancelled => "CANCELLED",
+    }
+    .to_string()
+}
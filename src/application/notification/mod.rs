@@ -0,0 +1,5 @@
+pub mod mapper;
+pub mod notification_channel;
+pub mod notification_command;
+pub mod use_case;
+pub mod view;
\ No newline at end of file
@@ -0,0 +1,57 @@
+use std::sync::Arc;
+
+use crate::domain::notification::entity::{Notification, NotificationChannelKind};
+
+pub struct DeliveryReceipt {
+    /// Message id returned by the provider, when it gives one.
+    pub provider_message_id: Option<String>,
+}
+
+#[derive(Debug, thiserror::Error)]
+#[error("{message}")]
+pub struct DeliveryError {
+    pub message: String,
+    /// The provider refused the message itself (bad address, rejected content); retrying
+    /// will not help.
+    pub permanent: bool,
+}
+
+impl DeliveryError {
+    pub fn transient(message: impl Into<String>) -> Self {
+        Self {
+            message: message.into(),
+            permanent: false,
+        }
+    }
+
+    pub fn permanent(message: impl Into<String>) -> Self {
+        Self {
+            message: message.into(),
+            permanent: true,
+        }
+    }
+}
+
+/// Delivers a rendered notification to its recipient: an email address, a phone number or
+/// a device push token, depending on the channel.
+#[async_trait::async_trait]
+pub trait NotificationChannel: Send + Sync {
+    async fn send(&self, notification: &Notification) -> Result<DeliveryReceipt, DeliveryError>;
+}
+
+#[derive(Clone)]
+pub struct NotificationChannels {
+    pub email: Arc<dyn NotificationChannel>,
+    pub sms: Arc<dyn NotificationChannel>,
+    pub push: Arc<dyn NotificationChannel>,
+}
+
+impl NotificationChannels {
+    pub fn get(&self, kind: NotificationChannelKind) -> &dyn NotificationChannel {
+        match kind {
+            NotificationChannelKind::Email => self.email.as_ref(),
+            NotificationChannelKind::Sms => self.sms.as_ref(),
+            NotificationChannelKind::Push => self.push.as_ref(),
+        }
+    }
+}
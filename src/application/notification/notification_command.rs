@@ -0,0 +1,8 @@
+#[derive(Debug, Clone)]
+pub struct UpdateNotificationPreferenceCommand {
+    pub locale: Option<String>,
+    pub email_enabled: bool,
+    pub sms_enabled: bool,
+    pub push_enabled: bool,
+    pub push_token: Option<String>,
+}
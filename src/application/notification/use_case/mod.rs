@@ -0,0 +1,2 @@
+pub mod notification_service;
+pub mod notification_service_interface;
@@ -0,0 +1,585 @@
+use crate::application::common::use_case_error::{UseCaseError, UseCaseResult};
+use crate::application::flight::mapper::flight_mapper::flight_status_to_string;
+use crate::application::notification::mapper::notification_mapper::{
+    notification_channel_to_string, notification_to_view, preference_to_view,
+};
+use crate::application::notification::notification_channel::NotificationChannels;
+use crate::application::notification::notification_command::UpdateNotificationPreferenceCommand;
+use crate::application::notification::use_case::notification_service_interface::NotificationServiceInterface;
+use crate::application::notification::view::notification_view::{
+    NotificationPreferenceView, NotificationView,
+};
+use crate::core::context::request_context::RequestContext;
+use crate::domain::airport::airport_repository_interface::AirportRepositoryInterface;
+use crate::domain::airport::entity::Airport;
+use crate::domain::booking::booking_repository_interface::BookingRepositoryInterface;
+use crate::domain::booking::entity::{Booking, BookingStatus};
+use crate::domain::booking::events::booking_cancelled::BookingCancelledEvent;
+use crate::domain::booking::events::booking_confirmed::BookingConfirmedEvent;
+use crate::domain::error::DomainError;
+use crate::domain::flight::entity::{Flight, FlightStatus};
+use crate::domain::flight::flight_repository_interface::FlightRepositoryInterface;
+use crate::domain::notification::entity::{
+    Notification, NotificationChannelKind, NotificationKind, NotificationRetryPolicy,
+    NotificationStatus, QueueNotificationProps,
+};
+use crate::domain::notification::error::NotificationDomainError;
+use crate::domain::notification::notification_preference_repository_interface::NotificationPreferenceRepositoryInterface;
+use crate::domain::notification::notification_repository_interface::NotificationRepositoryInterface;
+use crate::domain::notification::preference::{
+    NotificationPreference, UpdateNotificationPreferenceProps,
+};
+use crate::domain::notification::template::{NotificationTemplates, TemplateVars};
+use crate::domain::user::events::user_registered::UserRegisteredEvent;
+use chrono::{Duration, NaiveDateTime, TimeZone, Utc};
+use chrono_tz::Tz;
+use std::sync::Arc;
+
+// Notifications sent per dispatch round, and how long a round may hold them.
+const DISPATCH_BATCH_SIZE: u64 = 50;
+const DISPATCH_LEASE_MINUTES: i64 = 5;
+const LIST_LIMIT: u64 = 200;
+
+pub struct NotificationService {
+    pub notification_repo: Arc<dyn NotificationRepositoryInterface>,
+    pub preference_repo: Arc<dyn NotificationPreferenceRepositoryInterface>,
+    pub booking_repo: Arc<dyn BookingRepositoryInterface>,
+    pub flight_repo: Arc<dyn FlightRepositoryInterface>,
+    pub airport_repo: Arc<dyn AirportRepositoryInterface>,
+    pub templates: Arc<NotificationTemplates>,
+    pub channels: NotificationChannels,
+    pub retry_policy: NotificationRetryPolicy,
+    pub verification_url: String,
+}
+
+/// What booking notifications say about the trip.
+struct Itinerary {
+    booking: Booking,
+    flight: Flight,
+    origin: Airport,
+    destination: Airport,
+}
+
+impl NotificationService {
+    pub fn new(
+        notification_repo: Arc<dyn NotificationRepositoryInterface>,
+        preference_repo: Arc<dyn NotificationPreferenceRepositoryInterface>,
+        booking_repo: Arc<dyn BookingRepositoryInterface>,
+        flight_repo: Arc<dyn FlightRepositoryInterface>,
+        airport_repo: Arc<dyn AirportRepositoryInterface>,
+        templates: Arc<NotificationTemplates>,
+        channels: NotificationChannels,
+        retry_policy: NotificationRetryPolicy,
+        verification_url: String,
+    ) -> Self {
+        Self {
+            notification_repo,
+            preference_repo,
+            booking_repo,
+            flight_repo,
+            airport_repo,
+            templates,
+            channels,
+            retry_policy,
+            verification_url,
+        }
+    }
+
+    fn parse_status(value: &str) -> UseCaseResult<NotificationStatus> {
+        match value.trim().to_uppercase().as_str() {
+            "PENDING" => Ok(NotificationStatus::Pending),
+            "SENT" => Ok(NotificationStatus::Sent),
+            "FAILED" => Ok(NotificationStatus::Failed),
+            "CANCELLED" => Ok(NotificationStatus::Cancelled),
+            _ => Err(UseCaseError::Domain(DomainError::Notification(
+                NotificationDomainError::Validation {
+                    field: "status",
+                    message: format!("Invalid status: {value}"),
+                },
+            ))),
+        }
+    }
+
+    // Falls back to UTC, labelled as such, when the airport has no usable time zone.
+    fn local_time(utc: NaiveDateTime, time_zone: &str) -> String {
+        let pattern = "%Y-%m-%d %H:%M";
+        match time_zone.trim().parse::<Tz>() {
+            Ok(tz) => Utc
+                .from_utc_datetime(&utc)
+                .with_timezone(&tz)
+                .format(pattern)
+                .to_string(),
+            Err(_) => format!("{} UTC", utc.format(pattern)),
+        }
+    }
+
+    async fn load_preference(&self, user_id: i64) -> UseCaseResult<NotificationPreference> {
+        Ok(self
+            .preference_repo
+            .find_preference(user_id)
+            .await
+            .map_err(|e| UseCaseError::Unexpected(e.to_string()))?
+            .unwrap_or_else(|| NotificationPreference::default_for(user_id)))
+    }
+
+    async fn load_airport(&self, airport_id: i64) -> UseCaseResult<Airport> {
+        self.airport_repo
+            .find_airport_by_id(airport_id)
+            .await
+            .map_err(|e| UseCaseError::Unexpected(e.to_string()))?
+            .ok_or_else(|| {
+                UseCaseError::NotFound(format!("Airport with id {} not found", airport_id))
+            })
+    }
+
+    async fn load_flight(&self, flight_id: i64) -> UseCaseResult<Flight> {
+        self.flight_repo
+            .find_flight_by_id(flight_id)
+            .await
+            .map_err(|e| UseCaseError::Unexpected(e.to_string()))?
+            .ok_or_else(|| {
+                UseCaseError::NotFound(format!("Flight with id {} not found", flight_id))
+            })
+    }
+
+    async fn load_itinerary(&self, booking: Booking, flight: Flight) -> UseCaseResult<Itinerary> {
+        let origin = self.load_airport(flight.origin_airport_id).await?;
+        let destination = self.load_airport(flight.destination_airport_id).await?;
+        Ok(Itinerary {
+            booking,
+            flight,
+            origin,
+            destination,
+        })
+    }
+
+    /// Variables every booking notification shares. The flight is shown under the
+    /// designator the booking was sold with.
+    fn itinerary_vars(itinerary: &Itinerary) -> TemplateVars {
+        let Itinerary {
+            booking,
+            flight,
+            origin,
+            destination,
+        } = itinerary;
+
+        TemplateVars::from([
+            ("contact_name", booking.contact_full_name.clone()),
+            ("booking_code", booking.booking_code.clone()),
+            (
+                "flight",
+                format!(
+                    "{}{}",
+                    booking.marketing_airline_code, booking.marketing_flight_number
+                ),
+            ),
+            ("origin", format!("{} ({})", origin.city, origin.iata_code)),
+            (
+                "destination",
+                format!("{} ({})", destination.city, destination.iata_code),
+            ),
+            (
+                "departure_time",
+                Self::local_time(flight.departure_time, &origin.time_zone),
+            ),
+        ])
+    }
+
+    /// Stores the notification unless one with the same dedup key exists.
+    async fn queue(&self, props: QueueNotificationProps) -> UseCaseResult<bool> {
+        let notification = Notification::queue(props)?;
+        let created = self
+            .notification_repo
+            .create_notification(&notification)
+            .await
+            .map_err(|e| UseCaseError::Unexpected(e.to_string()))?;
+        Ok(created.is_some())
+    }
+
+    /// Queues the message on every channel the booking owner allows and can be reached
+    /// on: the booking contact's email and phone, and the owner's device.
+    async fn queue_for_booking(
+        &self,
+        kind: NotificationKind,
+        itinerary: &Itinerary,
+        vars: &TemplateVars,
+        occasion: &str,
+        scheduled_at: NaiveDateTime,
+    ) -> UseCaseResult<()> {
+        let booking = &itinerary.booking;
+        let preference = self.load_preference(booking.user_id).await?;
+        let locale = self
+            .templates
+            .resolve_locale(preference.locale.as_deref())
+            .to_string();
+
+        for channel in [
+            NotificationChannelKind::Email,
+            NotificationChannelKind::Sms,
+            NotificationChannelKind::Push,
+        ] {
+            if !preference.allows(channel) {
+                continue;
+            }
+            let recipient = match channel {
+                NotificationChannelKind::Email => Some(booking.contact_email.clone()),
+                NotificationChannelKind::Sms => booking.contact_phone.clone(),
+                NotificationChannelKind::Push => preference.push_token.clone(),
+            };
+            let Some(recipient) = recipient else {
+                continue;
+            };
+            let Some(message) = self.templates.render(kind, channel, &locale, vars) else {
+                continue;
+            };
+
+            self.queue(QueueNotificationProps {
+                kind,
+                channel,
+                user_id: Some(booking.user_id),
+                booking_id: Some(booking.id),
+                flight_id: Some(itinerary.flight.id),
+                recipient,
+                locale: locale.clone(),
+                message,
+                dedup_key: format!("{occasion}:{}", notification_channel_to_string(&channel)),
+                scheduled_at,
+            })
+            .await?;
+        }
+        Ok(())
+    }
+
+    async fn send(&self, mut notification: Notification) -> UseCaseResult<bool> {
+        let result = self
+            .channels
+            .get(notification.channel)
+            .send(&notification)
+            .await;
+        let now = Utc::now().naive_utc();
+
+        let delivered = match result {
+            Ok(receipt) => {
+                notification.mark_sent(receipt.provider_message_id, now);
+                true
+            }
+            Err(err) => {
+                tracing::warn!(
+                    "notification {} ({:?} to {}) failed: {}",
+                    notification.id,
+                    notification.channel,
+                    notification.recipient,
+                    err
+                );
+                notification.mark_failed(err.message, err.permanent, &self.retry_policy, now);
+                false
+            }
+        };
+
+        self.notification_repo
+            .update_notification(&notification, notification.version)
+            .await
+            .map_err(|e| UseCaseError::Unexpected(e.to_string()))?;
+        Ok(delivered)
+    }
+}
+
+#[async_trait::async_trait]
+impl NotificationServiceInterface for NotificationService {
+    async fn notify_user_registered(&self, event: UserRegisteredEvent) -> UseCaseResult<()> {
+        let preference = self.load_preference(event.user_id).await?;
+        let locale = self
+            .templates
+            .resolve_locale(preference.locale.as_deref())
+            .to_string();
+        let vars = TemplateVars::from([
+            ("full_name", event.full_name.clone()),
+            (
+                "verification_url",
+                self.verification_url
+                    .replace("{token}", &event.verification_token),
+            ),
+        ]);
+        let Some(message) = self.templates.render(
+            NotificationKind::EmailVerification,
+            NotificationChannelKind::Email,
+            &locale,
+            &vars,
+        ) else {
+            return Ok(());
+        };
+
+        // Verification ignores the email opt-out: the account cannot be used without it.
+        self.queue(QueueNotificationProps {
+            kind: NotificationKind::EmailVerification,
+            channel: NotificationChannelKind::Email,
+            user_id: Some(event.user_id),
+            booking_id: None,
+            flight_id: None,
+            recipient: event.email,
+            locale,
+            message,
+            dedup_key: format!(
+                "email_verification:{}:{}",
+                event.user_id,
+                event.created_at.and_utc().timestamp()
+            ),
+            scheduled_at: Utc::now().naive_utc(),
+        })
+        .await?;
+        Ok(())
+    }
+
+    async fn notify_booking_confirmed(&self, event: BookingConfirmedEvent) -> UseCaseResult<()> {
+        let booking = self
+            .booking_repo
+            .find_booking_by_id(event.booking_id)
+            .await
+            .map_err(|e| UseCaseError::Unexpected(e.to_string()))?
+            .ok_or_else(|| {
+                UseCaseError::NotFound(format!("Booking with id {} not found", event.booking_id))
+            })?;
+        if booking.status != BookingStatus::Confirmed {
+            return Ok(());
+        }
+        let flight = self.load_flight(booking.flight_id).await?;
+        let itinerary = self.load_itinerary(booking, flight).await?;
+        let now = Utc::now().naive_utc();
+
+        let vars = Self::itinerary_vars(&itinerary);
+        self.queue_for_booking(
+            NotificationKind::BookingConfirmation,
+            &itinerary,
+            &vars,
+            &format!("booking_confirmation:{}", itinerary.booking.id),
+            now,
+        )
+        .await?;
+
+        // The reminder goes out when check-in opens, or right away if it already has.
+        let flight = &itinerary.flight;
+        let Some(open_at) = flight.checkin_open_at else {
+            return Ok(());
+        };
+        let close_at = flight.checkin_close_at.unwrap_or(flight.departure_time);
+        if close_at <= now || flight.status == FlightStatus::Cancelled {
+            return Ok(());
+        }
+
+        let mut vars = vars;
+        vars.insert(
+            "checkin_close_time",
+            Self::local_time(close_at, &itinerary.origin.time_zone),
+        );
+        self.queue_for_booking(
+            NotificationKind::CheckinReminder,
+            &itinerary,
+            &vars,
+            &format!("checkin_reminder:{}", itinerary.booking.id),
+            open_at.max(now),
+        )
+        .await
+    }
+
+    async fn notify_booking_cancelled(&self, event: BookingCancelledEvent) -> UseCaseResult<()> {
+        self.notification_repo
+            .cancel_pending_for_booking(event.booking_id, None)
+            .await
+            .map_err(|e| UseCaseError::Unexpected(e.to_string()))?;
+        Ok(())
+    }
+
+    async fn notify_flight_changed(&self, flight_id: i64) -> UseCaseResult<()> {
+        let flight = self.load_flight(flight_id).await?;
+        if !matches!(
+            flight.status,
+            FlightStatus::Delayed | FlightStatus::Cancelled
+        ) {
+            return Ok(());
+        }
+        let status = flight_status_to_string(&flight.status);
+
+        let last_event = self
+            .flight_repo
+            .list_status_events(flight_id)
+            .await
+            .map_err(|e| UseCaseError::Unexpected(e.to_string()))?
+            .pop();
+        let reason = last_event.and_then(|e| e.reason).unwrap_or_default();
+        let estimated_departure = flight.operational_times.estimated_departure;
+
+        let bookings = self
+            .booking_repo
+            .list_bookings_by_flight(flight_id)
+            .await
+            .map_err(|e| UseCaseError::Unexpected(e.to_string()))?;
+        let origin = self.load_airport(flight.origin_airport_id).await?;
+        let destination = self.load_airport(flight.destination_airport_id).await?;
+        let now = Utc::now().naive_utc();
+
+        // A new estimate for a delayed flight is a new notice; a repeated update is not.
+        let occasion = format!(
+            "flight_disruption:{}:{}:{}",
+            flight_id,
+            status,
+            estimated_departure.map_or(0, |t| t.and_utc().timestamp())
+        );
+
+        for booking in bookings
+            .into_iter()
+            .filter(|b| b.status == BookingStatus::Confirmed)
+        {
+            let booking_id = booking.id;
+            let itinerary = Itinerary {
+                booking,
+                flight: flight.clone(),
+                origin: origin.clone(),
+                destination: destination.clone(),
+            };
+
+            let mut vars = Self::itinerary_vars(&itinerary);
+            vars.insert("status", status.clone());
+            vars.insert(
+                "new_departure_time",
+                estimated_departure
+                    .map(|t| Self::local_time(t, &origin.time_zone))
+                    .unwrap_or_default(),
+            );
+            vars.insert("reason", reason.clone());
+
+            self.queue_for_booking(
+                NotificationKind::FlightDisruption,
+                &itinerary,
+                &vars,
+                &format!("{occasion}:{booking_id}"),
+                now,
+            )
+            .await?;
+
+            if flight.status == FlightStatus::Cancelled {
+                self.notification_repo
+                    .cancel_pending_for_booking(booking_id, Some(NotificationKind::CheckinReminder))
+                    .await
+                    .map_err(|e| UseCaseError::Unexpected(e.to_string()))?;
+            }
+        }
+        Ok(())
+    }
+
+    async fn dispatch_due(&self) -> UseCaseResult<usize> {
+        let now = Utc::now().naive_utc();
+        let due = self
+            .notification_repo
+            .lease_due_notifications(
+                now,
+                now + Duration::minutes(DISPATCH_LEASE_MINUTES),
+                DISPATCH_BATCH_SIZE,
+            )
+            .await
+            .map_err(|e| UseCaseError::Unexpected(e.to_string()))?;
+
+        // An outcome that cannot be recorded stays leased and is retried after the lease.
+        let mut delivered = 0;
+        for notification in due {
+            let id = notification.id;
+            match self.send(notification).await {
+                Ok(true) => delivered += 1,
+                Ok(false) => {}
+                Err(err) => tracing::warn!("notification {} outcome not saved: {}", id, err),
+            }
+        }
+        Ok(delivered)
+    }
+
+    async fn list_notifications(
+        &self,
+        ctx: RequestContext,
+        user_id: Option<i64>,
+        status: Option<String>,
+    ) -> UseCaseResult<Vec<NotificationView>> {
+        let actor_user_id = ctx.user_id().ok_or(UseCaseError::PermissionDenied)?;
+        // Customers see their own notifications; staff and admins may look up anyone's.
+        let user_id = if ctx.is_admin() || ctx.is_staff() {
+            user_id
+        } else {
+            if user_id.is_some_and(|id| id != actor_user_id) {
+                return Err(UseCaseError::PermissionDenied);
+            }
+            Some(actor_user_id)
+        };
+        let status = status.as_deref().map(Self::parse_status).transpose()?;
+
+        Ok(self
+            .notification_repo
+            .list_notifications(user_id, status, LIST_LIMIT)
+            .await
+            .map_err(|e| UseCaseError::Unexpected(e.to_string()))?
+            .into_iter()
+            .map(notification_to_view)
+            .collect())
+    }
+
+    async fn get_preference(
+        &self,
+        ctx: RequestContext,
+    ) -> UseCaseResult<NotificationPreferenceView> {
+        let user_id = ctx.user_id().ok_or(UseCaseError::PermissionDenied)?;
+        let preference = self.load_preference(user_id).await?;
+        let effective_locale = self
+            .templates
+            .resolve_locale(preference.locale.as_deref())
+            .to_string();
+
+        Ok(preference_to_view(preference, effective_locale))
+    }
+
+    async fn update_preference(
+        &self,
+        ctx: RequestContext,
+        command: UpdateNotificationPreferenceCommand,
+    ) -> UseCaseResult<NotificationPreferenceView> {
+        let user_id = ctx.user_id().ok_or(UseCaseError::PermissionDenied)?;
+        let mut preference = self.load_preference(user_id).await?;
+        let expected_version = preference.version;
+
+        preference.update(UpdateNotificationPreferenceProps {
+            locale: command.locale,
+            email_enabled: command.email_enabled,
+            sms_enabled: command.sms_enabled,
+            push_enabled: command.push_enabled,
+            push_token: command.push_token,
+        })?;
+
+        if preference.is_new() {
+            self.preference_repo
+                .create_preference(&preference)
+                .await
+                .map_err(|e| match e {
+                    DomainError::Notification(NotificationDomainError::Conflict { .. }) => {
+                        UseCaseError::Domain(DomainError::Notification(
+                            NotificationDomainError::OptimisticLockConflict,
+                        ))
+                    }
+                    other => UseCaseError::Unexpected(other.to_string()),
+                })?;
+            preference.version = 1;
+        } else {
+            self.preference_repo
+                .update_preference(&preference, expected_version)
+                .await
+                .map_err(|e| match e {
+                    DomainError::Notification(NotificationDomainError::OptimisticLockConflict) => {
+                        UseCaseError::Domain(e)
+                    }
+                    other => UseCaseError::Unexpected(other.to_string()),
+                })?;
+            preference.version += 1;
+        }
+
+        let effective_locale = self
+            .templates
+            .resolve_locale(preference.locale.as_deref())
+            .to_string();
+        Ok(preference_to_view(preference, effective_locale))
+    }
+}
@@ -0,0 +1,47 @@
+use crate::application::common::use_case_error::UseCaseResult;
+use crate::application::notification::notification_command::UpdateNotificationPreferenceCommand;
+use crate::application::notification::view::notification_view::{
+    NotificationPreferenceView, NotificationView,
+};
+use crate::core::context::request_context::RequestContext;
+use crate::domain::booking::events::booking_cancelled::BookingCancelledEvent;
+use crate::domain::booking::events::booking_confirmed::BookingConfirmedEvent;
+use crate::domain::user::events::user_registered::UserRegisteredEvent;
+
+#[async_trait::async_trait]
+pub trait NotificationServiceInterface: Send + Sync {
+    /// Queues the verification email; sent again for every resend.
+    async fn notify_user_registered(&self, event: UserRegisteredEvent) -> UseCaseResult<()>;
+
+    /// Queues the booking confirmation and the check-in reminder for when check-in opens.
+    async fn notify_booking_confirmed(&self, event: BookingConfirmedEvent) -> UseCaseResult<()>;
+
+    /// Drops the booking's notifications that have not gone out yet.
+    async fn notify_booking_cancelled(&self, event: BookingCancelledEvent) -> UseCaseResult<()>;
+
+    /// Queues a disruption notice for every confirmed booking when the flight is delayed
+    /// or cancelled; once per status and departure estimate.
+    async fn notify_flight_changed(&self, flight_id: i64) -> UseCaseResult<()>;
+
+    /// Sends the notifications that are due and records the outcome; returns how many
+    /// were delivered.
+    async fn dispatch_due(&self) -> UseCaseResult<usize>;
+
+    async fn list_notifications(
+        &self,
+        ctx: RequestContext,
+        user_id: Option<i64>,
+        status: Option<String>,
+    ) -> UseCaseResult<Vec<NotificationView>>;
+
+    async fn get_preference(
+        &self,
+        ctx: RequestContext,
+    ) -> UseCaseResult<NotificationPreferenceView>;
+
+    async fn update_preference(
+        &self,
+        ctx: RequestContext,
+        command: UpdateNotificationPreferenceCommand,
+    ) -> UseCaseResult<NotificationPreferenceView>;
+}
@@ -0,0 +1 @@
+pub mod notification_view;
\ No newline at end of file
@@ -0,0 +1,30 @@
+#[derive(Debug, Clone)]
+pub struct NotificationView {
+    pub id: i64,
+    pub kind: String,
+    pub channel: String,
+    pub status: String,
+    pub user_id: Option<i64>,
+    pub booking_id: Option<i64>,
+    pub flight_id: Option<i64>,
+    pub recipient: String,
+    pub locale: String,
+    pub subject: Option<String>,
+    pub body: String,
+    pub attempts: i32,
+    pub last_error: Option<String>,
+    pub scheduled_at: String,
+    pub sent_at: Option<String>,
+}
+
+#[derive(Debug, Clone)]
+pub struct NotificationPreferenceView {
+    pub user_id: i64,
+    pub locale: Option<String>,
+    /// Locale the templates are actually rendered in.
+    pub effective_locale: String,
+    pub email_enabled: bool,
+    pub sms_enabled: bool,
+    pub push_enabled: bool,
+    pub push_token: Option<String>,
+}
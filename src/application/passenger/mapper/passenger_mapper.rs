@@ -22,12 +22,13 @@ impl From<Passenger> for PassengerView {
             phone_number: value.phone_number,
             ff_airline_code: value.ff_airline_code,
             ff_number: value.ff_number,
+            accompanying_adult_id: value.accompanying_adult_id,
             version: value.version,
         }
     }
 }
 
-fn passenger_type_to_string(value: &PassengerType) -> String {
+pub fn passenger_type_to_string(value: &PassengerType) -> String {
     match value {
         PassengerType::Adult => "ADT",
         PassengerType::Child => "CHD",
@@ -20,6 +20,7 @@ pub struct CreatePassengerCommand {
     pub phone_number: Option<String>,
     pub ff_airline_code: Option<String>,
     pub ff_number: Option<String>,
+    pub accompanying_adult_id: Option<i64>,
 }
 
 #[derive(Debug, Clone, Validate)]
@@ -37,4 +38,6 @@ pub struct UpdatePassengerCommand {
     pub phone_number: Option<String>,
     pub ff_airline_code: Option<String>,
     pub ff_number: Option<String>,
+    // `Some(None)` unlinks the accompanying adult
+    pub accompanying_adult_id: Option<Option<i64>>,
 }
@@ -10,16 +10,21 @@ use crate::application::passenger::view::passenger_view::PassengerView;
 use crate::core::context::request_context::RequestContext;
 use crate::domain::booking::booking_repository_interface::BookingRepositoryInterface;
 use crate::domain::booking::entity::BookingStatus;
+use crate::domain::business_rule_interface::BusinessRuleInterface;
 use crate::domain::error::DomainError;
+use crate::domain::flight::flight_repository_interface::FlightRepositoryInterface;
 use crate::domain::passenger;
 use crate::domain::passenger::entity::{
-    CreatePassengerProps, PassengerType, UpdatePassengerProps,
+    CreatePassengerProps, Passenger, PassengerType, UpdatePassengerProps,
 };
 use crate::domain::passenger::events::passenger_added::PassengerAddedEvent;
 use crate::domain::passenger::events::passenger_removed::PassengerRemovedEvent;
 use crate::domain::passenger::events::passenger_updated::PassengerUpdatedEvent;
 use crate::domain::passenger::error::PassengerDomainError;
 use crate::domain::passenger::passenger_repository_interface::PassengerRepositoryInterface;
+use crate::domain::passenger::rules::infant_must_reference_distinct_adult::InfantMustReferenceDistinctAdult;
+use crate::domain::passenger::rules::minors_must_be_accompanied::MinorsMustBeAccompanied;
+use crate::domain::passenger::rules::passenger_count_must_not_exceed_limit::PassengerCountMustNotExceedLimit;
 use std::sync::Arc;
 use validator::Validate;
 
@@ -27,7 +32,9 @@ pub struct PassengerService {
     pub cache: Arc<dyn CacheInterface>,
     pub passenger_repo: Arc<dyn PassengerRepositoryInterface>,
     pub booking_repo: Arc<dyn BookingRepositoryInterface>,
+    pub flight_repo: Arc<dyn FlightRepositoryInterface>,
     pub event_publisher: Arc<dyn PassengerEventPublisher>,
+    pub max_passengers_per_booking: usize,
 }
 
 impl PassengerService {
@@ -35,13 +42,17 @@ impl PassengerService {
         cache: Arc<dyn CacheInterface>,
         passenger_repo: Arc<dyn PassengerRepositoryInterface>,
         booking_repo: Arc<dyn BookingRepositoryInterface>,
+        flight_repo: Arc<dyn FlightRepositoryInterface>,
         event_publisher: Arc<dyn PassengerEventPublisher>,
+        max_passengers_per_booking: usize,
     ) -> Self {
         Self {
             cache,
             passenger_repo,
             booking_repo,
+            flight_repo,
             event_publisher,
+            max_passengers_per_booking,
         }
     }
 
@@ -116,6 +127,15 @@ impl PassengerServiceInterface for PassengerService {
         Self::ensure_owner_or_admin(&ctx, actor_user_id, booking.user_id)?;
         Self::ensure_booking_editable(booking.status)?;
 
+        let flight = self
+            .flight_repo
+            .find_flight_by_id(booking.flight_id)
+            .await
+            .map_err(|e| UseCaseError::Unexpected(e.to_string()))?
+            .ok_or_else(|| {
+                UseCaseError::NotFound(format!("Flight with id {} not found", booking.flight_id))
+            })?;
+
         let passenger_type = Self::parse_passenger_type(&command.passenger_type)?;
         let props = CreatePassengerProps {
             booking_id: command.booking_id,
@@ -133,15 +153,52 @@ impl PassengerServiceInterface for PassengerService {
             phone_number: command.phone_number,
             ff_airline_code: command.ff_airline_code,
             ff_number: command.ff_number,
+            accompanying_adult_id: command.accompanying_adult_id,
         };
 
         let today = chrono::Utc::now().date_naive();
-        let passenger = passenger::entity::Passenger::new(props, today)?;
-        let passenger_id = self
+        let passenger = passenger::entity::Passenger::new(props, today, flight.departure_date)?;
+
+        let existing = self
             .passenger_repo
-            .create_passenger(&passenger)
+            .list_passengers_by_booking(booking.id)
             .await
             .map_err(|e| UseCaseError::Unexpected(e.to_string()))?;
+        PassengerCountMustNotExceedLimit {
+            current_count: existing.len(),
+            max_count: self.max_passengers_per_booking,
+        }
+        .check_broken()?;
+
+        InfantMustReferenceDistinctAdult {
+            passenger_id: passenger.id,
+            passenger_type: &passenger.passenger_type,
+            accompanying_adult_id: passenger.accompanying_adult_id,
+            booking_passengers: &existing,
+        }
+        .check_broken()?;
+
+        // Checked again at confirmation; catching it here means adults are added first.
+        let mut with_new = existing.clone();
+        with_new.push(passenger.clone());
+        MinorsMustBeAccompanied {
+            passengers: &with_new,
+        }
+        .check_broken()?;
+
+        let seat_flight_id = if passenger.passenger_type.occupies_seat() {
+            flight.validate_seat_reservation()?;
+            Some(booking.flight_id)
+        } else {
+            None
+        };
+
+        // Conditional decrement and insert share one transaction, so a failed insert
+        // never leaks a seat.
+        let passenger_id = self
+            .passenger_repo
+            .add_passenger(&passenger, seat_flight_id)
+            .await?;
 
         let cache_key = Self::passengers_booking_cache_key(booking.id);
         if let Err(err) = self.cache.del(&cache_key).await {
@@ -206,6 +263,7 @@ impl PassengerServiceInterface for PassengerService {
             passport_issuing_country_code: command.passport_issuing_country_code,
             ff_airline_code: command.ff_airline_code,
             ff_number: command.ff_number,
+            accompanying_adult_id: command.accompanying_adult_id,
         };
         passenger.update_from(update_props)?;
 
@@ -225,6 +283,15 @@ impl PassengerServiceInterface for PassengerService {
             passenger.nationality_code = nationality_code;
         }
 
+        let flight = self
+            .flight_repo
+            .find_flight_by_id(booking.flight_id)
+            .await
+            .map_err(|e| UseCaseError::Unexpected(e.to_string()))?
+            .ok_or_else(|| {
+                UseCaseError::NotFound(format!("Flight with id {} not found", booking.flight_id))
+            })?;
+
         let today = chrono::Utc::now().date_naive();
         let validate_props = CreatePassengerProps {
             booking_id: passenger.booking_id,
@@ -242,8 +309,37 @@ impl PassengerServiceInterface for PassengerService {
             phone_number: passenger.phone_number.clone(),
             ff_airline_code: passenger.ff_airline_code.clone(),
             ff_number: passenger.ff_number.clone(),
+            accompanying_adult_id: passenger.accompanying_adult_id,
         };
-        validate_props.validate(&today)?;
+        validate_props.validate(&today, &flight.departure_date)?;
+
+        let booking_passengers = self
+            .passenger_repo
+            .list_passengers_by_booking(booking.id)
+            .await
+            .map_err(|e| UseCaseError::Unexpected(e.to_string()))?;
+        InfantMustReferenceDistinctAdult {
+            passenger_id: passenger.id,
+            passenger_type: &passenger.passenger_type,
+            accompanying_adult_id: passenger.accompanying_adult_id,
+            booking_passengers: &booking_passengers,
+        }
+        .check_broken()?;
+
+        let updated_passengers: Vec<Passenger> = booking_passengers
+            .iter()
+            .map(|p| {
+                if p.id == passenger.id {
+                    passenger.clone()
+                } else {
+                    p.clone()
+                }
+            })
+            .collect();
+        MinorsMustBeAccompanied {
+            passengers: &updated_passengers,
+        }
+        .check_broken()?;
 
         self.passenger_repo
             .update_passenger(&passenger, expected_version)
@@ -291,8 +387,37 @@ impl PassengerServiceInterface for PassengerService {
         Self::ensure_owner_or_admin(&ctx, actor_user_id, booking.user_id)?;
         Self::ensure_booking_editable(booking.status)?;
 
+        if passenger.passenger_type == PassengerType::Adult {
+            let remaining: Vec<Passenger> = self
+                .passenger_repo
+                .list_passengers_by_booking(booking.id)
+                .await
+                .map_err(|e| UseCaseError::Unexpected(e.to_string()))?
+                .into_iter()
+                .filter(|p| p.id != passenger.id)
+                .collect();
+            if remaining
+                .iter()
+                .any(|p| p.accompanying_adult_id == Some(passenger.id))
+            {
+                return Err(UseCaseError::Domain(DomainError::Passenger(
+                    PassengerDomainError::BusinessRule {
+                        message: "Cannot remove an adult who accompanies an infant".to_string(),
+                    },
+                )));
+            }
+            MinorsMustBeAccompanied {
+                passengers: &remaining,
+            }
+            .check_broken()?;
+        }
+
+        let seat_flight_id = passenger
+            .passenger_type
+            .occupies_seat()
+            .then_some(booking.flight_id);
         self.passenger_repo
-            .delete_passenger_by_id(id)
+            .remove_passenger(id, seat_flight_id)
             .await
             .map_err(|e| UseCaseError::Unexpected(e.to_string()))?;
 
@@ -18,5 +18,6 @@ pub struct PassengerView {
     pub phone_number: Option<String>,
     pub ff_airline_code: Option<String>,
     pub ff_number: Option<String>,
+    pub accompanying_adult_id: Option<i64>,
     pub version: i32,
 }
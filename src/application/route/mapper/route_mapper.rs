@@ -0,0 +1,16 @@
+use crate::application::route::view::route_view::RouteView;
+use crate::domain::route::entity::Route;
+
+impl From<Route> for RouteView {
+    fn from(route: Route) -> Self {
+        Self {
+            id: route.id,
+            origin_airport_id: route.origin_airport_id,
+            destination_airport_id: route.destination_airport_id,
+            distance_km: route.distance_km,
+            block_time_minutes: route.block_time_minutes,
+            is_domestic: route.is_domestic,
+            is_active: route.is_active,
+        }
+    }
+}
@@ -0,0 +1,18 @@
+use validator::Validate;
+
+#[derive(Debug, Clone, Validate)]
+pub struct CreateRouteCommand {
+    #[validate(length(equal = 3))]
+    pub origin_iata_code: String,
+    #[validate(length(equal = 3))]
+    pub destination_iata_code: String,
+    #[validate(range(min = 1, max = 1440))]
+    pub block_time_minutes: i32,
+}
+
+#[derive(Debug, Clone, Validate)]
+pub struct UpdateRouteCommand {
+    #[validate(range(min = 1, max = 1440))]
+    pub block_time_minutes: Option<i32>,
+    pub is_active: Option<bool>,
+}
@@ -0,0 +1,2 @@
+pub mod route_service;
+pub mod route_service_interface;
@@ -0,0 +1,207 @@
+use crate::application::common::cache_helper::{cache_get_json, cache_set_json};
+use crate::application::common::cache_interface::CacheInterface;
+use crate::application::common::use_case_error::{UseCaseError, UseCaseResult};
+use crate::application::route::route_command::{CreateRouteCommand, UpdateRouteCommand};
+use crate::application::route::use_case::route_service_interface::RouteServiceInterface;
+use crate::application::route::view::route_view::RouteView;
+use crate::core::context::request_context::RequestContext;
+use crate::domain::airport::airport_repository_interface::AirportRepositoryInterface;
+use crate::domain::airport::entity::Airport;
+use crate::domain::error::DomainError;
+use crate::domain::route::entity::{Route, UpdateRouteProps};
+use crate::domain::route::error::RouteDomainError;
+use crate::domain::route::route_repository_interface::RouteRepositoryInterface;
+use std::sync::Arc;
+use validator::Validate;
+
+pub struct RouteService {
+    pub cache: Arc<dyn CacheInterface>,
+    pub route_repo: Arc<dyn RouteRepositoryInterface>,
+    pub airport_repo: Arc<dyn AirportRepositoryInterface>,
+}
+
+impl RouteService {
+    pub fn new(
+        cache: Arc<dyn CacheInterface>,
+        route_repo: Arc<dyn RouteRepositoryInterface>,
+        airport_repo: Arc<dyn AirportRepositoryInterface>,
+    ) -> Self {
+        Self {
+            cache,
+            route_repo,
+            airport_repo,
+        }
+    }
+
+    fn route_id_cache_key(id: i64) -> String {
+        format!("route:id:{id}")
+    }
+
+    fn validation_error(field: &'static str, message: String) -> UseCaseError {
+        UseCaseError::Domain(DomainError::Route(RouteDomainError::Validation {
+            field,
+            message,
+        }))
+    }
+
+    async fn load_route(&self, id: i64) -> UseCaseResult<Route> {
+        self.route_repo
+            .find_route_by_id(id)
+            .await
+            .map_err(|e| UseCaseError::Unexpected(e.to_string()))?
+            .ok_or_else(|| UseCaseError::NotFound(format!("Route with id {} not found", id)))
+    }
+
+    async fn load_airport_by_iata_code(&self, iata_code: &str) -> UseCaseResult<Airport> {
+        let iata_code = iata_code.trim().to_uppercase();
+        self.airport_repo
+            .find_airport_by_iata_code(&iata_code)
+            .await
+            .map_err(|e| UseCaseError::Unexpected(e.to_string()))?
+            .ok_or_else(|| {
+                UseCaseError::NotFound(format!("Airport with iata_code {} not found", iata_code))
+            })
+    }
+
+    async fn load_airport_by_id(&self, id: i64) -> UseCaseResult<Airport> {
+        self.airport_repo
+            .find_airport_by_id(id)
+            .await
+            .map_err(|e| UseCaseError::Unexpected(e.to_string()))?
+            .ok_or_else(|| UseCaseError::NotFound(format!("Airport with id {} not found", id)))
+    }
+}
+
+#[async_trait::async_trait]
+impl RouteServiceInterface for RouteService {
+    async fn create_route(
+        &self,
+        ctx: RequestContext,
+        command: CreateRouteCommand,
+    ) -> UseCaseResult<bool> {
+        if !ctx.is_admin() {
+            return Err(UseCaseError::PermissionDenied);
+        }
+        let _ = ctx
+            .require_user()
+            .map_err(|_| UseCaseError::PermissionDenied)?;
+
+        command
+            .validate()
+            .map_err(|e| Self::validation_error("command", e.to_string()))?;
+
+        let origin = self
+            .load_airport_by_iata_code(&command.origin_iata_code)
+            .await?;
+        let destination = self
+            .load_airport_by_iata_code(&command.destination_iata_code)
+            .await?;
+
+        let existing = self
+            .route_repo
+            .find_route_by_airports(origin.id, destination.id)
+            .await
+            .map_err(|e| UseCaseError::Unexpected(e.to_string()))?;
+        if existing.is_some() {
+            return Err(UseCaseError::AlreadyExists(format!(
+                "Route {}-{} already exists",
+                origin.iata_code, destination.iata_code
+            )));
+        }
+
+        let route = Route::between(&origin, &destination, command.block_time_minutes)?;
+        self.route_repo
+            .create_route(&route)
+            .await
+            .map_err(|e| UseCaseError::Unexpected(e.to_string()))?;
+
+        Ok(true)
+    }
+
+    async fn update_route(
+        &self,
+        ctx: RequestContext,
+        id: i64,
+        command: UpdateRouteCommand,
+    ) -> UseCaseResult<bool> {
+        if !ctx.is_admin() {
+            return Err(UseCaseError::PermissionDenied);
+        }
+        let _ = ctx
+            .require_user()
+            .map_err(|_| UseCaseError::PermissionDenied)?;
+
+        command
+            .validate()
+            .map_err(|e| Self::validation_error("command", e.to_string()))?;
+
+        let mut route = self.load_route(id).await?;
+        let origin = self.load_airport_by_id(route.origin_airport_id).await?;
+        let destination = self
+            .load_airport_by_id(route.destination_airport_id)
+            .await?;
+
+        route.update(UpdateRouteProps {
+            block_time_minutes: command.block_time_minutes,
+            is_active: command.is_active,
+        })?;
+        route.remeasure(&origin, &destination)?;
+
+        self.route_repo
+            .update_route(&route)
+            .await
+            .map_err(|e| UseCaseError::Unexpected(e.to_string()))?;
+
+        let key = Self::route_id_cache_key(id);
+        if let Err(err) = self.cache.del(&key).await {
+            tracing::warn!("cache del failed key={}: {}", key, err);
+        }
+
+        Ok(true)
+    }
+
+    async fn get_route_by_id(&self, ctx: RequestContext, id: i64) -> UseCaseResult<RouteView> {
+        let _ = ctx.user_id().ok_or(UseCaseError::PermissionDenied)?;
+
+        let cache_key = Self::route_id_cache_key(id);
+        match cache_get_json::<RouteView>(self.cache.as_ref(), &cache_key).await {
+            Ok(Some(cached)) => return Ok(cached),
+            Ok(None) => {}
+            Err(err) => tracing::warn!("cache get route failed key={}: {}", cache_key, err),
+        }
+
+        let view: RouteView = self.load_route(id).await?.into();
+        if let Err(err) = cache_set_json(self.cache.as_ref(), &cache_key, &view, 86400).await {
+            tracing::warn!("cache set failed key={}: {}", cache_key, err);
+        }
+
+        Ok(view)
+    }
+
+    async fn list_routes(
+        &self,
+        ctx: RequestContext,
+        origin_iata_code: Option<String>,
+        destination_iata_code: Option<String>,
+        active_only: bool,
+    ) -> UseCaseResult<Vec<RouteView>> {
+        let _ = ctx.user_id().ok_or(UseCaseError::PermissionDenied)?;
+
+        let origin_airport_id = match origin_iata_code.as_deref() {
+            Some(code) => Some(self.load_airport_by_iata_code(code).await?.id),
+            None => None,
+        };
+        let destination_airport_id = match destination_iata_code.as_deref() {
+            Some(code) => Some(self.load_airport_by_iata_code(code).await?.id),
+            None => None,
+        };
+
+        let routes = self
+            .route_repo
+            .list_routes(origin_airport_id, destination_airport_id, active_only)
+            .await
+            .map_err(|e| UseCaseError::Unexpected(e.to_string()))?;
+
+        Ok(routes.into_iter().map(Into::into).collect())
+    }
+}
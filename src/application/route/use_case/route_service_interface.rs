@@ -0,0 +1,32 @@
+use crate::application::common::use_case_error::UseCaseResult;
+use crate::application::route::route_command::{CreateRouteCommand, UpdateRouteCommand};
+use crate::application::route::view::route_view::RouteView;
+use crate::core::context::request_context::RequestContext;
+
+#[async_trait::async_trait]
+pub trait RouteServiceInterface: Send + Sync {
+    async fn create_route(
+        &self,
+        ctx: RequestContext,
+        command: CreateRouteCommand,
+    ) -> UseCaseResult<bool>;
+
+    /// Also re-measures the route from the airports' current coordinates.
+    async fn update_route(
+        &self,
+        ctx: RequestContext,
+        id: i64,
+        command: UpdateRouteCommand,
+    ) -> UseCaseResult<bool>;
+
+    async fn get_route_by_id(&self, ctx: RequestContext, id: i64) -> UseCaseResult<RouteView>;
+
+    /// Routes filtered by origin and/or destination IATA code.
+    async fn list_routes(
+        &self,
+        ctx: RequestContext,
+        origin_iata_code: Option<String>,
+        destination_iata_code: Option<String>,
+        active_only: bool,
+    ) -> UseCaseResult<Vec<RouteView>>;
+}
@@ -0,0 +1,12 @@
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RouteView {
+    pub id: i64,
+    pub origin_airport_id: i64,
+    pub destination_airport_id: i64,
+    pub distance_km: i32,
+    pub block_time_minutes: i32,
+    pub is_domestic: bool,
+    pub is_active: bool,
+}
@@ -1,7 +1,12 @@
 use anyhow::Result;
 use flight_booking::infrastructure::bootstrap::app_state_builder::AppStateBuilder;
+use flight_booking::infrastructure::cli;
 use flight_booking::infrastructure::http::server::AppServer;
+use flight_booking::infrastructure::runtime::shutdown;
 use log::{LevelFilter, error, info};
+use std::time::Duration;
+
+const BACKGROUND_DRAIN_TIMEOUT: Duration = Duration::from_secs(30);
 
 #[tokio::main]
 async fn main() -> Result<()> {
@@ -11,6 +16,43 @@ async fn main() -> Result<()> {
         .init();
 
     info!("The initialization of Tracing was successful!");
+
+    let args: Vec<String> = std::env::args().skip(1).collect();
+    if let Some(command) = args.first() {
+        match command.as_str() {
+            "airports-import" => {
+                if let Err(err) = cli::airport_import::run(&args[1..]).await {
+                    error!("{err}");
+                    std::process::exit(1);
+                }
+                return Ok(());
+            }
+            "manifest" => {
+                if let Err(err) = cli::manifest_export::run(&args[1..]).await {
+                    error!("{err}");
+                    std::process::exit(1);
+                }
+                return Ok(());
+            }
+            "no-shows" => {
+                if let Err(err) = cli::no_show_processing::run(&args[1..]).await {
+                    error!("{err}");
+                    std::process::exit(1);
+                }
+                return Ok(());
+            }
+            other => {
+                error!(
+                    "Unknown command: {other}\n{}\n{}\n{}",
+                    cli::airport_import::USAGE,
+                    cli::manifest_export::USAGE,
+                    cli::no_show_processing::USAGE
+                );
+                std::process::exit(2);
+            }
+        }
+    }
+
     let (state, addr) = AppStateBuilder::build().await?;
 
     let server = AppServer::build(state, addr).await?;
@@ -21,6 +63,9 @@ async fn main() -> Result<()> {
         std::process::exit(1);
     }
 
+    info!("Server stopped, waiting for background workers...");
+    shutdown::drain(BACKGROUND_DRAIN_TIMEOUT).await;
+
     /*let server_task = tokio::spawn(async {
         if let Err(e) = server.run().await {
             error!("HTTP Server error: {:?}", e);
@@ -1,11 +1,22 @@
 use crate::application::address::use_case::address_service_interface::AddressServiceInterface;
+use crate::application::aircraft::use_case::aircraft_service_interface::AircraftServiceInterface;
+use crate::application::airline::use_case::airline_service_interface::AirlineServiceInterface;
 use crate::application::airport::use_case::airport_service_interface::AirportServiceInterface;
 use crate::application::auth::use_case::auth_service_interface::AuthServiceInterface;
+use crate::application::baggage::use_case::baggage_service_interface::BaggageServiceInterface;
+use crate::application::boarding::use_case::boarding_service_interface::BoardingServiceInterface;
 use crate::application::boarding_pass::use_case::boarding_pass_service_interface::BoardingPassServiceInterface;
 use crate::application::booking::use_case::booking_service_interface::BookingServiceInterface;
 use crate::application::checkin::use_case::checkin_service_interface::CheckinServiceInterface;
+use crate::application::compensation::use_case::compensation_service_interface::CompensationServiceInterface;
+use crate::application::dead_letter::use_case::dead_letter_service_interface::DeadLetterServiceInterface;
 use crate::application::flight::use_case::flight_service_interface::FlightServiceInterface;
+use crate::application::flight_status::use_case::flight_status_service_interface::FlightStatusServiceInterface;
+use crate::application::manifest::use_case::manifest_service_interface::ManifestServiceInterface;
+use crate::application::no_show::use_case::no_show_service_interface::NoShowServiceInterface;
+use crate::application::notification::use_case::notification_service_interface::NotificationServiceInterface;
 use crate::application::passenger::use_case::passenger_service_interface::PassengerServiceInterface;
+use crate::application::route::use_case::route_service_interface::RouteServiceInterface;
 use crate::application::user::use_case::user_service_interface::UserServiceInterface;
 use crate::core::configure::deploy_mode::DeployMode;
 use crate::core::context::request_context_provider::RequestContextProvider;
@@ -26,11 +37,22 @@ pub struct AppState {
     pub auth_service: Arc<dyn AuthServiceInterface>,
     pub address_service: Arc<dyn AddressServiceInterface>,
     pub airport_service: Arc<dyn AirportServiceInterface>,
+    pub airline_service: Arc<dyn AirlineServiceInterface>,
+    pub aircraft_service: Arc<dyn AircraftServiceInterface>,
+    pub route_service: Arc<dyn RouteServiceInterface>,
     pub flight_service: Arc<dyn FlightServiceInterface>,
+    pub flight_status_service: Arc<dyn FlightStatusServiceInterface>,
     pub booking_service: Arc<dyn BookingServiceInterface>,
     pub passenger_service: Arc<dyn PassengerServiceInterface>,
     pub checkin_service: Arc<dyn CheckinServiceInterface>,
     pub boarding_pass_service: Arc<dyn BoardingPassServiceInterface>,
+    pub boarding_service: Arc<dyn BoardingServiceInterface>,
+    pub manifest_service: Arc<dyn ManifestServiceInterface>,
+    pub no_show_service: Arc<dyn NoShowServiceInterface>,
+    pub baggage_service: Arc<dyn BaggageServiceInterface>,
+    pub compensation_service: Arc<dyn CompensationServiceInterface>,
+    pub notification_service: Arc<dyn NotificationServiceInterface>,
+    pub dead_letter_service: Arc<dyn DeadLetterServiceInterface>,
 }
 
 impl AppState {
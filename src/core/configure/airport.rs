@@ -0,0 +1,15 @@
+use serde::Deserialize;
+use std::collections::HashMap;
+
+#[derive(Debug, Deserialize, Clone, Default)]
+pub struct AirportConfig {
+    #[serde(default)]
+    pub import: AirportImportConfig,
+}
+
+#[derive(Debug, Deserialize, Clone, Default)]
+pub struct AirportImportConfig {
+    /// IANA zone by ISO country code, for countries that keep a single zone.
+    #[serde(default)]
+    pub country_time_zones: HashMap<String, String>,
+}
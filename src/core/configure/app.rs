@@ -1,12 +1,19 @@
+use crate::core::configure::airport::AirportConfig;
+use crate::core::configure::boarding_pass::BoardingPassConfig;
+use crate::core::configure::booking::BookingConfig;
+use crate::core::configure::checkin::CheckinConfig;
+use crate::core::configure::compensation::CompensationConfig;
 use crate::core::configure::db::DatabaseConfig;
 use crate::core::configure::deploy_mode::DeployMode;
 use crate::core::configure::env::get_env_source;
 use crate::core::configure::gateway::GatewayConfig;
 use crate::core::configure::http::HttpClientConfig;
 use crate::core::configure::kafka::KafkaConfig;
+use crate::core::configure::notification::NotificationConfig;
 use crate::core::configure::redis::RedisConfig;
 use crate::core::configure::secret::SecretConfig;
 use crate::core::configure::server::ServerConfig;
+use crate::core::configure::travel_document::TravelDocumentConfig;
 use config::{ConfigError, Environment};
 use serde::{Deserialize, Serialize};
 use utils::dir::get_project_root;
@@ -23,6 +30,20 @@ pub struct AppConfig {
     pub http: HttpClientConfig,
     pub kafka: KafkaConfig,
     pub gateway: GatewayConfig,
+    #[serde(default)]
+    pub booking: BookingConfig,
+    #[serde(default)]
+    pub travel_document: TravelDocumentConfig,
+    #[serde(default)]
+    pub boarding_pass: BoardingPassConfig,
+    #[serde(default)]
+    pub checkin: CheckinConfig,
+    #[serde(default)]
+    pub airport: AirportConfig,
+    #[serde(default)]
+    pub compensation: CompensationConfig,
+    #[serde(default)]
+    pub notification: NotificationConfig,
 }
 
 impl AppConfig {
@@ -0,0 +1,77 @@
+use std::collections::HashMap;
+use std::path::PathBuf;
+
+use serde::Deserialize;
+
+#[derive(Debug, Deserialize, Clone)]
+pub struct BoardingPassConfig {
+    /// Printed in the header of rendered boarding passes.
+    pub brand_name: String,
+    /// Header colour as `#RRGGBB`.
+    pub brand_color: String,
+    /// Wallet (`.pkpass`) generation is disabled when not set.
+    #[serde(default)]
+    pub wallet: Option<WalletPassConfig>,
+    #[serde(default)]
+    pub groups: BoardingGroupConfig,
+}
+
+#[derive(Debug, Deserialize, Clone)]
+pub struct WalletPassConfig {
+    pub pass_type_identifier: String,
+    pub team_identifier: String,
+    /// Pass Type ID certificate and its private key, PEM encoded.
+    pub certificate: PathBuf,
+    pub private_key: PathBuf,
+    /// Apple WWDR intermediate certificate; left out for self-signed test certificates.
+    #[serde(default)]
+    pub wwdr_certificate: Option<PathBuf>,
+}
+
+/// Boarding group policy; the lowest matching group wins.
+#[derive(Debug, Deserialize, Clone)]
+pub struct BoardingGroupConfig {
+    /// Seat class (`FIRST`, `BUSINESS`, ...) to group.
+    #[serde(default)]
+    pub cabins: HashMap<String, u8>,
+    /// Fare family (`BASIC`, `STANDARD`, `FLEX`) to group.
+    #[serde(default)]
+    pub fare_families: HashMap<String, u8>,
+    #[serde(default)]
+    pub frequent_flyer: Option<u8>,
+    /// Programmes honoured besides the operating carrier's own.
+    #[serde(default)]
+    pub frequent_flyer_airlines: Vec<String>,
+    /// First row of each seat zone, rearmost first.
+    #[serde(default)]
+    pub zone_row_starts: Vec<u32>,
+    pub first_zone_group: u8,
+}
+
+impl Default for BoardingGroupConfig {
+    fn default() -> Self {
+        Self {
+            cabins: HashMap::from([
+                ("FIRST".to_string(), 1),
+                ("BUSINESS".to_string(), 1),
+                ("PREMIUM_ECONOMY".to_string(), 2),
+            ]),
+            fare_families: HashMap::from([("FLEX".to_string(), 3)]),
+            frequent_flyer: Some(2),
+            frequent_flyer_airlines: Vec::new(),
+            zone_row_starts: vec![30, 20],
+            first_zone_group: 4,
+        }
+    }
+}
+
+impl Default for BoardingPassConfig {
+    fn default() -> Self {
+        Self {
+            brand_name: "Flight Booking".to_string(),
+            brand_color: "#0B3D91".to_string(),
+            wallet: None,
+            groups: BoardingGroupConfig::default(),
+        }
+    }
+}
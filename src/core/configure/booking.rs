@@ -0,0 +1,38 @@
+use std::collections::HashMap;
+
+use serde::Deserialize;
+
+#[derive(Debug, Deserialize, Clone)]
+pub struct BookingConfig {
+    pub max_passengers_per_booking: usize,
+    #[serde(default)]
+    pub no_show: NoShowConfig,
+}
+
+/// No-show fare rule applied after departure.
+#[derive(Debug, Deserialize, Clone)]
+pub struct NoShowConfig {
+    /// Fare family (`BASIC`, `STANDARD`, `FLEX`) to the percentage of the fare forfeited.
+    pub penalty_percent: HashMap<String, u8>,
+}
+
+impl Default for NoShowConfig {
+    fn default() -> Self {
+        Self {
+            penalty_percent: HashMap::from([
+                ("BASIC".to_string(), 100),
+                ("STANDARD".to_string(), 50),
+                ("FLEX".to_string(), 0),
+            ]),
+        }
+    }
+}
+
+impl Default for BookingConfig {
+    fn default() -> Self {
+        Self {
+            max_passengers_per_booking: 9,
+            no_show: NoShowConfig::default(),
+        }
+    }
+}
@@ -0,0 +1,79 @@
+use std::collections::HashMap;
+
+use serde::Deserialize;
+
+#[derive(Debug, Deserialize, Clone, Default)]
+pub struct CheckinConfig {
+    #[serde(default)]
+    pub baggage: BaggageConfig,
+}
+
+/// Free checked baggage and excess pricing; fees are decimal strings in `currency`.
+#[derive(Debug, Deserialize, Clone)]
+pub struct BaggageConfig {
+    pub currency: String,
+    pub excess_piece_fee: String,
+    /// Charged per started kilogram above the allowance.
+    pub excess_weight_fee_per_kg: String,
+    #[serde(default)]
+    pub allowances: Vec<BaggageAllowanceConfig>,
+    /// Three-digit IATA issuer code per airline code, printed on bag tags.
+    #[serde(default)]
+    pub tag_issuer_codes: HashMap<String, String>,
+}
+
+#[derive(Debug, Deserialize, Clone)]
+pub struct BaggageAllowanceConfig {
+    /// `ECONOMY`, `PREMIUM_ECONOMY`, `BUSINESS` or `FIRST`.
+    pub seat_class: String,
+    /// Applies to every fare family of the cabin when not set.
+    #[serde(default)]
+    pub fare_family: Option<String>,
+    /// `PIECE` (uses `pieces` and `max_piece_weight_kg`) or `WEIGHT` (uses `total_weight_kg`).
+    pub concept: String,
+    #[serde(default)]
+    pub pieces: Option<u32>,
+    #[serde(default)]
+    pub max_piece_weight_kg: Option<u32>,
+    #[serde(default)]
+    pub total_weight_kg: Option<u32>,
+}
+
+impl Default for BaggageConfig {
+    fn default() -> Self {
+        let piece = |seat_class: &str, fare_family: Option<&str>, pieces, max_piece_weight_kg| {
+            BaggageAllowanceConfig {
+                seat_class: seat_class.to_string(),
+                fare_family: fare_family.map(str::to_string),
+                concept: "PIECE".to_string(),
+                pieces: Some(pieces),
+                max_piece_weight_kg: Some(max_piece_weight_kg),
+                total_weight_kg: None,
+            }
+        };
+
+        let weight = |seat_class: &str, total_weight_kg| BaggageAllowanceConfig {
+            seat_class: seat_class.to_string(),
+            fare_family: None,
+            concept: "WEIGHT".to_string(),
+            pieces: None,
+            max_piece_weight_kg: None,
+            total_weight_kg: Some(total_weight_kg),
+        };
+
+        Self {
+            currency: "USD".to_string(),
+            excess_piece_fee: "75.00".to_string(),
+            excess_weight_fee_per_kg: "15.00".to_string(),
+            allowances: vec![
+                piece("ECONOMY", Some("BASIC"), 0, 23),
+                piece("ECONOMY", Some("STANDARD"), 1, 23),
+                piece("ECONOMY", Some("FLEX"), 2, 23),
+                piece("PREMIUM_ECONOMY", None, 2, 23),
+                weight("BUSINESS", 40),
+                weight("FIRST", 50),
+            ],
+            tag_issuer_codes: HashMap::new(),
+        }
+    }
+}
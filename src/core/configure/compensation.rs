@@ -0,0 +1,75 @@
+use serde::Deserialize;
+
+/// Passenger compensation for long delays and cancellations (EU261-style); amounts are
+/// decimal strings in `currency`.
+#[derive(Debug, Deserialize, Clone)]
+pub struct CompensationConfig {
+    pub currency: String,
+    /// ISO country codes where the regulation applies: every departure from them, and
+    /// arrivals into them on a carrier based in one of them.
+    pub covered_countries: Vec<String>,
+    /// Delay at arrival, in minutes, from which a delayed flight is compensated.
+    pub delay_threshold_minutes: i64,
+    /// Cancellations announced at least this many days before departure are not compensated.
+    pub cancellation_notice_days: i64,
+    /// IATA delay codes that count as extraordinary circumstances (weather, ATC, security).
+    pub extraordinary_delay_codes: Vec<String>,
+    /// Distance bands in ascending order; the last one must be open-ended.
+    pub bands: Vec<CompensationBandConfig>,
+    /// Flights between two covered countries never go past the band holding this distance.
+    #[serde(default)]
+    pub intra_region_distance_cap_km: Option<i32>,
+    /// Share of the amount paid when a delay stays below a band's reduction limit.
+    pub reduced_percent: u8,
+}
+
+#[derive(Debug, Deserialize, Clone)]
+pub struct CompensationBandConfig {
+    /// Upper bound of the band, inclusive; open-ended when not set.
+    #[serde(default)]
+    pub max_distance_km: Option<i32>,
+    pub amount: String,
+    /// Delays shorter than this (but above the threshold) are paid at `reduced_percent`.
+    #[serde(default)]
+    pub reduced_below_delay_minutes: Option<i64>,
+}
+
+impl Default for CompensationConfig {
+    fn default() -> Self {
+        let band =
+            |max_distance_km, amount: &str, reduced_below_delay_minutes| CompensationBandConfig {
+                max_distance_km,
+                amount: amount.to_string(),
+                reduced_below_delay_minutes,
+            };
+
+        Self {
+            currency: "EUR".to_string(),
+            // EU member states plus the EEA and Switzerland.
+            covered_countries: [
+                "AT", "BE", "BG", "HR", "CY", "CZ", "DK", "EE", "FI", "FR", "DE", "GR", "HU", "IE",
+                "IT", "LV", "LT", "LU", "MT", "NL", "PL", "PT", "RO", "SK", "SI", "ES", "SE", "IS",
+                "LI", "NO", "CH",
+            ]
+            .into_iter()
+            .map(str::to_string)
+            .collect(),
+            delay_threshold_minutes: 180,
+            cancellation_notice_days: 14,
+            extraordinary_delay_codes: [
+                "71", "72", "73", "75", "76", "77", "81", "82", "83", "84", "85", "86", "87", "88",
+                "89", "97",
+            ]
+            .into_iter()
+            .map(str::to_string)
+            .collect(),
+            bands: vec![
+                band(Some(1500), "250", None),
+                band(Some(3500), "400", None),
+                band(None, "600", Some(240)),
+            ],
+            intra_region_distance_cap_km: Some(3500),
+            reduced_percent: 50,
+        }
+    }
+}
@@ -1,4 +1,5 @@
 pub mod app;
+pub mod booking;
 pub mod db;
 pub mod env;
 pub mod http;
@@ -6,5 +7,12 @@ pub mod kafka;
 pub mod redis;
 pub mod secret;
 pub mod server;
+pub mod travel_document;
 pub mod gateway;
 pub mod deploy_mode;
+pub mod boarding_pass;
+pub mod checkin;
+pub mod compensation;
+pub mod notification;
+
+pub mod airport;
\ No newline at end of file
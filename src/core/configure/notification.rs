@@ -0,0 +1,80 @@
+use std::path::PathBuf;
+
+use serde::Deserialize;
+
+/// Customer notifications. A channel without a provider section writes to `file_sink_dir`
+/// when set and to the log otherwise, which is enough for local development.
+#[derive(Debug, Deserialize, Clone)]
+pub struct NotificationConfig {
+    /// Locale of users without a preference; its template file must cover every kind.
+    pub default_locale: String,
+    /// Directory with one `<locale>.toml` template file per locale.
+    pub templates_dir: PathBuf,
+    /// Link put in verification emails; `{token}` is replaced with the verification token.
+    pub verification_url: String,
+    /// Delivery attempts before a notification is marked `FAILED`.
+    pub max_attempts: i32,
+    /// Wait before the first retry; doubles with every further attempt.
+    pub retry_delay_seconds: i64,
+    /// How often due and retried notifications are picked up.
+    pub dispatch_interval_seconds: u64,
+    #[serde(default)]
+    pub email: Option<SmtpConfig>,
+    #[serde(default)]
+    pub sms: Option<SmsGatewayConfig>,
+    #[serde(default)]
+    pub push: Option<PushGatewayConfig>,
+    #[serde(default)]
+    pub file_sink_dir: Option<PathBuf>,
+}
+
+#[derive(Debug, Deserialize, Clone)]
+pub struct SmtpConfig {
+    pub host: String,
+    pub port: u16,
+    #[serde(default)]
+    pub username: Option<String>,
+    #[serde(default)]
+    pub password: Option<String>,
+    /// Sender mailbox, e.g. `Flight Booking <no-reply@example.com>`.
+    pub from: String,
+    /// Upgrade the connection with STARTTLS; turn off only for a local mail catcher.
+    #[serde(default = "default_true")]
+    pub starttls: bool,
+}
+
+/// HTTP SMS gateway: messages are POSTed as JSON `{from, to, text}` with a bearer key.
+#[derive(Debug, Deserialize, Clone)]
+pub struct SmsGatewayConfig {
+    pub url: String,
+    pub api_key: String,
+    pub sender: String,
+}
+
+/// HTTP push gateway: messages are POSTed as JSON `{token, title, body}` with a bearer key.
+#[derive(Debug, Deserialize, Clone)]
+pub struct PushGatewayConfig {
+    pub url: String,
+    pub api_key: String,
+}
+
+fn default_true() -> bool {
+    true
+}
+
+impl Default for NotificationConfig {
+    fn default() -> Self {
+        Self {
+            default_locale: "en".to_string(),
+            templates_dir: PathBuf::from("./static/notification_templates"),
+            verification_url: "http://localhost:3000/verify-email?token={token}".to_string(),
+            max_attempts: 5,
+            retry_delay_seconds: 60,
+            dispatch_interval_seconds: 10,
+            email: None,
+            sms: None,
+            push: None,
+            file_sink_dir: None,
+        }
+    }
+}
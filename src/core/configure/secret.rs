@@ -7,4 +7,19 @@ pub struct SecretConfig {
     pub public_access_key: PathBuf,
     pub private_refresh_key: PathBuf,
     pub public_refresh_key: PathBuf,
+
+    // Ed25519 keys for boarding pass signatures. Retired keys keep only `public_key`
+    // so passes issued before a rotation still verify.
+    #[serde(default)]
+    pub boarding_pass_keys: Vec<BoardingPassKeyConfig>,
+    #[serde(default)]
+    pub boarding_pass_active_key_id: Option<String>,
+}
+
+#[derive(Debug, Deserialize, Clone)]
+pub struct BoardingPassKeyConfig {
+    /// Single character, carried in the BCBP security type field.
+    pub key_id: String,
+    pub private_key: Option<PathBuf>,
+    pub public_key: PathBuf,
 }
\ No newline at end of file
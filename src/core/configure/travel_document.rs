@@ -0,0 +1,26 @@
+use serde::Deserialize;
+
+#[derive(Debug, Deserialize, Clone)]
+pub struct TravelDocumentConfig {
+    pub passport_min_validity_months: u32,
+    #[serde(default)]
+    pub passport_free_zones: Vec<Vec<String>>,
+    #[serde(default)]
+    pub visa_requirements: Vec<VisaRequirementConfig>,
+}
+
+#[derive(Debug, Deserialize, Clone)]
+pub struct VisaRequirementConfig {
+    pub nationality_code: String,
+    pub destination_country_code: String,
+}
+
+impl Default for TravelDocumentConfig {
+    fn default() -> Self {
+        Self {
+            passport_min_validity_months: 6,
+            passport_free_zones: Vec::new(),
+            visa_requirements: Vec::new(),
+        }
+    }
+}
@@ -66,6 +66,20 @@ impl RequestContext {
         )
     }
 
+    pub fn is_staff(&self) -> bool {
+        matches!(
+            self.actor,
+            Actor::User {
+                role: UserRole::Staff,
+                ..
+            }
+        )
+    }
+
+    pub fn is_system(&self) -> bool {
+        matches!(self.actor, Actor::System)
+    }
+
     /// Helper for controller: required login
     pub fn require_user(&self) -> Result<(i64, Uuid), RequestContextError> {
         match &self.actor {
@@ -85,6 +99,18 @@ impl RequestContext {
             _ => Err(RequestContextError::Forbidden),
         }
     }
+
+    /// Helper for gate operations: required staff
+    pub fn require_staff(&self) -> Result<i64, RequestContextError> {
+        match &self.actor {
+            Actor::User {
+                id,
+                role: UserRole::Staff,
+                ..
+            } => Ok(*id),
+            _ => Err(RequestContextError::Forbidden),
+        }
+    }
 }
 impl From<UserRoleView> for UserRole {
     fn from(value: UserRoleView) -> Self {
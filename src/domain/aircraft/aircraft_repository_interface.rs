@@ -0,0 +1,15 @@
+use crate::domain::aircraft::entity::Aircraft;
+use crate::domain::error::DomainError;
+
+#[async_trait::async_trait]
+pub trait AircraftRepositoryInterface: Send + Sync {
+    async fn create_aircraft(&self, aircraft: &Aircraft) -> Result<i64, DomainError>;
+    async fn update_aircraft(&self, aircraft: &Aircraft) -> Result<(), DomainError>;
+    async fn find_aircraft_by_id(&self, id: i64) -> Result<Option<Aircraft>, DomainError>;
+    async fn find_aircraft_by_tail_number(
+        &self,
+        tail_number: &str,
+    ) -> Result<Option<Aircraft>, DomainError>;
+    async fn tail_number_exists(&self, tail_number: &str) -> Result<bool, DomainError>;
+    async fn list_aircraft(&self, airline_id: Option<i64>) -> Result<Vec<Aircraft>, DomainError>;
+}
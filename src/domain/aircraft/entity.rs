@@ -0,0 +1,114 @@
+use crate::domain::aircraft::error::AircraftDomainError;
+use crate::domain::aircraft::rules::aircraft_identifiers_must_be_valid::AircraftIdentifiersMustBeValid;
+use crate::domain::business_rule_interface::BusinessRuleInterface;
+use crate::domain::error::DomainError;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum AircraftStatus {
+    Active,
+    Maintenance,
+}
+
+#[derive(Debug, Clone)]
+pub struct CreateAircraftProps {
+    pub tail_number: String,
+    pub aircraft_type: String,
+    pub seat_configuration: String,
+    pub airline_id: i64,
+}
+
+#[derive(Debug, Clone)]
+pub struct UpdateAircraftProps {
+    pub aircraft_type: Option<String>,
+    pub seat_configuration: Option<String>,
+    pub airline_id: Option<i64>,
+    pub status: Option<AircraftStatus>,
+}
+
+impl CreateAircraftProps {
+    pub fn validate(&self) -> Result<(), DomainError> {
+        AircraftIdentifiersMustBeValid {
+            tail_number: self.tail_number.as_str(),
+            aircraft_type: Some(self.aircraft_type.as_str()),
+        }
+        .check_broken()?;
+        Ok(())
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct Aircraft {
+    pub id: i64,
+    pub tail_number: String,
+    pub aircraft_type: String,
+    // Reference to the cabin layout, e.g. `C16Y168`.
+    pub seat_configuration: String,
+    // Operator airline.
+    pub airline_id: i64,
+    pub status: AircraftStatus,
+}
+
+impl Aircraft {
+    pub fn new(props: CreateAircraftProps) -> Result<Self, DomainError> {
+        props.validate()?;
+
+        Ok(Self {
+            id: 0,
+            tail_number: props.tail_number,
+            aircraft_type: props.aircraft_type,
+            seat_configuration: props.seat_configuration,
+            airline_id: props.airline_id,
+            status: AircraftStatus::Active,
+        })
+    }
+
+    pub fn update(&mut self, props: UpdateAircraftProps) -> Result<(), DomainError> {
+        if let Some(aircraft_type) = props.aircraft_type {
+            AircraftIdentifiersMustBeValid {
+                tail_number: self.tail_number.as_str(),
+                aircraft_type: Some(aircraft_type.as_str()),
+            }
+            .check_broken()?;
+            self.aircraft_type = aircraft_type;
+        }
+        if let Some(seat_configuration) = props.seat_configuration {
+            self.seat_configuration = seat_configuration;
+        }
+        if let Some(airline_id) = props.airline_id {
+            self.airline_id = airline_id;
+        }
+        if let Some(status) = props.status {
+            self.status = status;
+        }
+
+        Ok(())
+    }
+
+    /// Aircraft in maintenance cannot be assigned to flights.
+    pub fn ensure_available(&self) -> Result<(), DomainError> {
+        if self.status == AircraftStatus::Maintenance {
+            return Err(AircraftDomainError::AircraftInMaintenance {
+                tail_number: self.tail_number.clone(),
+            }
+            .into());
+        }
+        Ok(())
+    }
+
+    pub fn ensure_operated_by(
+        &self,
+        airline_id: i64,
+        airline_code: &str,
+    ) -> Result<(), DomainError> {
+        if self.airline_id != airline_id {
+            return Err(AircraftDomainError::BusinessRule {
+                message: format!(
+                    "Aircraft {} is not operated by airline {}",
+                    self.tail_number, airline_code
+                ),
+            }
+            .into());
+        }
+        Ok(())
+    }
+}
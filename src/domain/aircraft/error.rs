@@ -0,0 +1,37 @@
+use thiserror::Error;
+
+#[derive(Debug, Error)]
+pub enum AircraftDomainError {
+    #[error("validation error: {field} - {message}")]
+    Validation {
+        field: &'static str,
+        message: String,
+    },
+
+    #[error("business rule violated: {message}")]
+    BusinessRule { message: String },
+
+    #[error("unauthorized: {message}")]
+    Unauthorized { message: String },
+
+    #[error("conflict: {field} - {message}")]
+    Conflict {
+        field: &'static str,
+        message: String,
+    },
+
+    #[error("aircraft is in maintenance: {tail_number}")]
+    AircraftInMaintenance { tail_number: String },
+
+    #[error("aircraft rotation conflict for {tail_number}: {message}")]
+    RotationConflict {
+        tail_number: String,
+        message: String,
+    },
+
+    #[error("internal error: {0}")]
+    Internal(String),
+
+    #[error("Entity not found: {detail}")]
+    NotFound { detail: String },
+}
@@ -0,0 +1,4 @@
+pub mod rules;
+pub mod entity;
+pub mod aircraft_repository_interface;
+pub mod error;
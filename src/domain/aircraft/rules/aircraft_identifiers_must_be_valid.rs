@@ -0,0 +1,46 @@
+use crate::domain::aircraft::error::AircraftDomainError;
+use crate::domain::business_rule_interface::BusinessRuleInterface;
+use crate::domain::error::DomainError;
+
+/// Tail numbers are national registrations such as `VN-A321` or `N12345`;
+/// aircraft types are ICAO type designators such as `A321` or `B789`.
+pub struct AircraftIdentifiersMustBeValid<'a> {
+    pub tail_number: &'a str,
+    pub aircraft_type: Option<&'a str>,
+}
+
+impl<'a> BusinessRuleInterface for AircraftIdentifiersMustBeValid<'a> {
+    fn check_broken(&self) -> Result<(), DomainError> {
+        let tail_valid = (2..=10).contains(&self.tail_number.len())
+            && self
+                .tail_number
+                .chars()
+                .all(|c| c.is_ascii_uppercase() || c.is_ascii_digit() || c == '-')
+            && !self.tail_number.starts_with('-')
+            && !self.tail_number.ends_with('-');
+        if !tail_valid {
+            return Err(AircraftDomainError::Validation {
+                field: "tail_number",
+                message: "Must be 2-10 uppercase letters, digits or inner hyphens".to_string(),
+            }
+            .into());
+        }
+
+        if let Some(aircraft_type) = self.aircraft_type {
+            let type_valid = (2..=4).contains(&aircraft_type.len())
+                && aircraft_type.starts_with(|c: char| c.is_ascii_uppercase())
+                && aircraft_type
+                    .chars()
+                    .all(|c| c.is_ascii_uppercase() || c.is_ascii_digit());
+            if !type_valid {
+                return Err(AircraftDomainError::Validation {
+                    field: "aircraft_type",
+                    message: "Must be an ICAO type designator, e.g. A321".to_string(),
+                }
+                .into());
+            }
+        }
+
+        Ok(())
+    }
+}
@@ -0,0 +1,62 @@
+use crate::domain::aircraft::error::AircraftDomainError;
+use crate::domain::business_rule_interface::BusinessRuleInterface;
+use crate::domain::error::DomainError;
+use crate::domain::flight::entity::Flight;
+
+/// An aircraft flies one leg at a time, departs from where it last arrived and arrives
+/// where its next leg departs.
+///
+/// `overlapping` holds the other flights on the tail whose block times intersect
+/// this one; `previous` is the last flight on the tail to arrive before it departs
+/// and `next` the first to depart after it arrives.
+pub struct AircraftRotationMustBeContinuous<'a> {
+    pub tail_number: &'a str,
+    pub flight: &'a Flight,
+    pub overlapping: &'a [Flight],
+    pub previous: Option<&'a Flight>,
+    pub next: Option<&'a Flight>,
+}
+
+impl<'a> BusinessRuleInterface for AircraftRotationMustBeContinuous<'a> {
+    fn check_broken(&self) -> Result<(), DomainError> {
+        if let Some(other) = self.overlapping.iter().find(|f| f.id != self.flight.id) {
+            return Err(AircraftDomainError::RotationConflict {
+                tail_number: self.tail_number.to_string(),
+                message: format!("Overlaps flight {}", other.flight_key),
+            }
+            .into());
+        }
+
+        let misplaced = self
+            .previous
+            .filter(|p| p.destination_airport_id != self.flight.origin_airport_id);
+        if let Some(previous) = misplaced {
+            return Err(AircraftDomainError::RotationConflict {
+                tail_number: self.tail_number.to_string(),
+                message: format!(
+                    "Previous flight {} arrives at airport {}, not at origin airport {}",
+                    previous.flight_key,
+                    previous.destination_airport_id,
+                    self.flight.origin_airport_id
+                ),
+            }
+            .into());
+        }
+
+        let stranded = self
+            .next
+            .filter(|n| n.origin_airport_id != self.flight.destination_airport_id);
+        if let Some(next) = stranded {
+            return Err(AircraftDomainError::RotationConflict {
+                tail_number: self.tail_number.to_string(),
+                message: format!(
+                    "Next flight {} departs from airport {}, not from destination airport {}",
+                    next.flight_key, next.origin_airport_id, self.flight.destination_airport_id
+                ),
+            }
+            .into());
+        }
+
+        Ok(())
+    }
+}
@@ -0,0 +1,2 @@
+pub mod aircraft_identifiers_must_be_valid;
+pub mod aircraft_rotation_must_be_continuous;
@@ -0,0 +1,16 @@
+use crate::domain::airline::entity::Airline;
+use crate::domain::error::DomainError;
+
+#[async_trait::async_trait]
+pub trait AirlineRepositoryInterface: Send + Sync {
+    async fn create_airline(&self, airline: &Airline) -> Result<i64, DomainError>;
+    async fn update_airline(&self, airline: &Airline) -> Result<(), DomainError>;
+    async fn find_airline_by_id(&self, id: i64) -> Result<Option<Airline>, DomainError>;
+    /// Inactive airlines are returned too, so callers can tell them apart from unknown codes.
+    async fn find_airline_by_iata_code(
+        &self,
+        iata_code: &str,
+    ) -> Result<Option<Airline>, DomainError>;
+    async fn iata_code_exists(&self, iata_code: &str) -> Result<bool, DomainError>;
+    async fn list_airlines(&self, active_only: bool) -> Result<Vec<Airline>, DomainError>;
+}
@@ -0,0 +1,103 @@
+use crate::domain::airline::error::AirlineDomainError;
+use crate::domain::airline::rules::airline_codes_must_be_valid::AirlineCodesMustBeValid;
+use crate::domain::business_rule_interface::BusinessRuleInterface;
+use crate::domain::error::DomainError;
+
+#[derive(Debug, Clone)]
+pub struct CreateAirlineProps {
+    pub iata_code: String,
+    pub icao_code: Option<String>,
+    pub name: String,
+    pub callsign: Option<String>,
+    pub country_code: String,
+    pub logo_url: Option<String>,
+}
+
+#[derive(Debug, Clone)]
+pub struct UpdateAirlineProps {
+    pub icao_code: Option<String>,
+    pub name: Option<String>,
+    pub callsign: Option<String>,
+    pub country_code: Option<String>,
+    pub logo_url: Option<String>,
+}
+
+impl CreateAirlineProps {
+    pub fn validate(&self) -> Result<(), DomainError> {
+        AirlineCodesMustBeValid {
+            iata_code: self.iata_code.as_str(),
+            icao_code: self.icao_code.as_deref(),
+        }
+        .check_broken()?;
+        Ok(())
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct Airline {
+    pub id: i64,
+    pub iata_code: String,
+    pub icao_code: Option<String>,
+    pub name: String,
+    pub callsign: Option<String>,
+    pub country_code: String,
+    pub logo_url: Option<String>,
+    pub is_active: bool,
+}
+
+impl Airline {
+    pub fn new(props: CreateAirlineProps) -> Result<Self, DomainError> {
+        props.validate()?;
+
+        Ok(Self {
+            id: 0,
+            iata_code: props.iata_code,
+            icao_code: props.icao_code,
+            name: props.name,
+            callsign: props.callsign,
+            country_code: props.country_code,
+            logo_url: props.logo_url,
+            is_active: false,
+        })
+    }
+
+    pub fn update(&mut self, props: UpdateAirlineProps) -> Result<(), DomainError> {
+        if let Some(icao_code) = props.icao_code {
+            AirlineCodesMustBeValid {
+                iata_code: self.iata_code.as_str(),
+                icao_code: Some(icao_code.as_str()),
+            }
+            .check_broken()?;
+            self.icao_code = Some(icao_code);
+        }
+        if let Some(name) = props.name {
+            self.name = name;
+        }
+        if let Some(callsign) = props.callsign {
+            self.callsign = Some(callsign);
+        }
+        if let Some(country_code) = props.country_code {
+            self.country_code = country_code;
+        }
+        if let Some(logo_url) = props.logo_url {
+            self.logo_url = Some(logo_url);
+        }
+
+        Ok(())
+    }
+
+    pub fn deactivate(&mut self) {
+        self.is_active = false;
+    }
+
+    /// Only active airlines can operate new flights.
+    pub fn ensure_active(&self) -> Result<(), DomainError> {
+        if !self.is_active {
+            return Err(AirlineDomainError::AirlineInactive {
+                iata_code: self.iata_code.clone(),
+            }
+            .into());
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,31 @@
+use thiserror::Error;
+
+#[derive(Debug, Error)]
+pub enum AirlineDomainError {
+    #[error("validation error: {field} - {message}")]
+    Validation {
+        field: &'static str,
+        message: String,
+    },
+
+    #[error("business rule violated: {message}")]
+    BusinessRule { message: String },
+
+    #[error("unauthorized: {message}")]
+    Unauthorized { message: String },
+
+    #[error("conflict: {field} - {message}")]
+    Conflict {
+        field: &'static str,
+        message: String,
+    },
+
+    #[error("airline is not active: {iata_code}")]
+    AirlineInactive { iata_code: String },
+
+    #[error("internal error: {0}")]
+    Internal(String),
+
+    #[error("Entity not found: {detail}")]
+    NotFound { detail: String },
+}
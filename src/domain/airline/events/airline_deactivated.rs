@@ -0,0 +1,23 @@
+use chrono::NaiveDateTime;
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct AirlineDeactivatedEvent {
+    pub airline_id: i64,
+    pub iata_code: String,
+    pub occurred_at: NaiveDateTime,
+}
+
+impl AirlineDeactivatedEvent {
+    pub fn new(airline_id: i64, iata_code: String, occurred_at: NaiveDateTime) -> Self {
+        Self {
+            airline_id,
+            iata_code,
+            occurred_at,
+        }
+    }
+
+    pub fn topic_name() -> &'static str {
+        "airline.deactivated"
+    }
+}
@@ -0,0 +1,3 @@
+pub mod airline_created;
+pub mod airline_deactivated;
+pub mod airline_updated;
@@ -0,0 +1,5 @@
+pub mod events;
+pub mod rules;
+pub mod entity;
+pub mod airline_repository_interface;
+pub mod error;
@@ -0,0 +1,42 @@
+use crate::domain::airline::error::AirlineDomainError;
+use crate::domain::business_rule_interface::BusinessRuleInterface;
+use crate::domain::error::DomainError;
+
+/// IATA designators are two letters or digits (never two digits, e.g. `VN`, `U2`);
+/// ICAO designators are three letters.
+pub struct AirlineCodesMustBeValid<'a> {
+    pub iata_code: &'a str,
+    pub icao_code: Option<&'a str>,
+}
+
+impl<'a> BusinessRuleInterface for AirlineCodesMustBeValid<'a> {
+    fn check_broken(&self) -> Result<(), DomainError> {
+        let iata_valid = self.iata_code.len() == 2
+            && self
+                .iata_code
+                .chars()
+                .all(|c| c.is_ascii_uppercase() || c.is_ascii_digit())
+            && self.iata_code.chars().any(|c| c.is_ascii_uppercase());
+        if !iata_valid {
+            return Err(AirlineDomainError::Validation {
+                field: "iata_code",
+                message: "Must be 2 uppercase letters or digits, at least one letter".to_string(),
+            }
+            .into());
+        }
+
+        if let Some(icao_code) = self.icao_code {
+            let icao_valid =
+                icao_code.len() == 3 && icao_code.chars().all(|c| c.is_ascii_uppercase());
+            if !icao_valid {
+                return Err(AirlineDomainError::Validation {
+                    field: "icao_code",
+                    message: "Must be 3 uppercase letters".to_string(),
+                }
+                .into());
+            }
+        }
+
+        Ok(())
+    }
+}
@@ -0,0 +1 @@
+pub mod airline_codes_must_be_valid;
@@ -1,4 +1,5 @@
 use crate::domain::airport::entity::Airport;
+use crate::domain::airport::geo::BoundingBox;
 use crate::domain::error::DomainError;
 
 #[async_trait::async_trait]
@@ -13,4 +14,23 @@ pub trait AirportRepositoryInterface: Send + Sync {
     async fn iata_code_exists(&self, iata_code: &str) -> Result<bool, DomainError>;
     async fn delete_airport(&self, id: i64) -> Result<Option<Airport>, DomainError>;
     async fn list_airports(&self, active_only: bool) -> Result<Vec<Airport>, DomainError>;
+    /// Active airports that may match an autocomplete query: IATA/ICAO prefixes, name or
+    /// city substrings and trigram-similar names or cities. Unranked beyond trigram
+    /// similarity; callers rank with `search::match_score`.
+    async fn search_airport_candidates(
+        &self,
+        query: &str,
+        limit: u64,
+    ) -> Result<Vec<Airport>, DomainError>;
+    /// Active airports with coordinates inside the box.
+    async fn list_airports_in_bounding_box(
+        &self,
+        bounds: &BoundingBox,
+    ) -> Result<Vec<Airport>, DomainError>;
+    /// Writes a bulk import in one transaction, so a failure leaves no partial import.
+    async fn import_airports(
+        &self,
+        inserts: &[Airport],
+        updates: &[Airport],
+    ) -> Result<(), DomainError>;
 }
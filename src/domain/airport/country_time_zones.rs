@@ -0,0 +1,16 @@
+/// IANA time zones for countries that keep a single one, used when an airport source
+/// carries no per-airport zone.
+#[derive(Debug, Clone, Default)]
+pub struct CountryTimeZones {
+    // ISO country code to IANA zone, e.g. `("VN", "Asia/Ho_Chi_Minh")`.
+    pub zones: Vec<(String, String)>,
+}
+
+impl CountryTimeZones {
+    pub fn time_zone_for(&self, country_code: &str) -> Option<&str> {
+        self.zones
+            .iter()
+            .find(|(country, _)| country.eq_ignore_ascii_case(country_code.trim()))
+            .map(|(_, zone)| zone.as_str())
+    }
+}
@@ -1,7 +1,9 @@
+use crate::domain::airport::geo::GeoPoint;
 use crate::domain::airport::rules::iata_code_must_be_valid::IAtaCodeMustBeValid;
 use crate::domain::business_rule_interface::BusinessRuleInterface;
 use crate::domain::error::DomainError;
 use rust_decimal::Decimal;
+use rust_decimal::prelude::ToPrimitive;
 
 #[derive(Debug, Clone)]
 pub struct CreateAirportProps {
@@ -37,7 +39,7 @@ impl CreateAirportProps {
     }
 }
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, PartialEq)]
 pub struct Airport {
     pub id: i64,
     pub iata_code: String,
@@ -106,4 +108,14 @@ impl Airport {
     pub fn deactivate(&mut self) {
         self.is_active = false;
     }
+
+    // Airports imported or created without coordinates have no location.
+    pub fn location(&self) -> Option<GeoPoint> {
+        let latitude = self.latitude?.to_f64()?;
+        let longitude = self.longitude?.to_f64()?;
+        Some(GeoPoint {
+            latitude,
+            longitude,
+        })
+    }
 }
@@ -0,0 +1,96 @@
+use crate::domain::airport::error::AirportDomainError;
+use crate::domain::error::DomainError;
+
+/// Mean Earth radius (IUGG), in kilometres.
+pub const EARTH_RADIUS_KM: f64 = 6371.0088;
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct GeoPoint {
+    pub latitude: f64,
+    pub longitude: f64,
+}
+
+impl GeoPoint {
+    pub fn new(latitude: f64, longitude: f64) -> Result<Self, DomainError> {
+        if !(-90.0..=90.0).contains(&latitude) {
+            return Err(AirportDomainError::Validation {
+                field: "latitude",
+                message: format!("Latitude must be between -90 and 90, got {latitude}"),
+            }
+            .into());
+        }
+        if !(-180.0..=180.0).contains(&longitude) {
+            return Err(AirportDomainError::Validation {
+                field: "longitude",
+                message: format!("Longitude must be between -180 and 180, got {longitude}"),
+            }
+            .into());
+        }
+
+        Ok(Self {
+            latitude,
+            longitude,
+        })
+    }
+
+    /// Great-circle distance by the haversine formula.
+    pub fn distance_km(&self, other: &GeoPoint) -> f64 {
+        let lat1 = self.latitude.to_radians();
+        let lat2 = other.latitude.to_radians();
+        let d_lat = lat2 - lat1;
+        let d_lon = (other.longitude - self.longitude).to_radians();
+
+        let a = (d_lat / 2.0).sin().powi(2) + lat1.cos() * lat2.cos() * (d_lon / 2.0).sin().powi(2);
+        2.0 * EARTH_RADIUS_KM * a.sqrt().min(1.0).asin()
+    }
+}
+
+/// Latitude/longitude box around a search circle, so storage can prefilter by plain
+/// range comparisons before distances are computed. A box crossing the antimeridian is
+/// split into two longitude ranges.
+#[derive(Debug, Clone, PartialEq)]
+pub struct BoundingBox {
+    pub min_latitude: f64,
+    pub max_latitude: f64,
+    pub longitude_ranges: Vec<(f64, f64)>,
+}
+
+impl BoundingBox {
+    pub fn around(center: GeoPoint, radius_km: f64) -> Self {
+        let angular_radius = radius_km / EARTH_RADIUS_KM;
+        let lat_delta = angular_radius.to_degrees();
+        let min_latitude = center.latitude - lat_delta;
+        let max_latitude = center.latitude + lat_delta;
+
+        // A circle reaching a pole covers every longitude.
+        if min_latitude <= -90.0 || max_latitude >= 90.0 {
+            return Self {
+                min_latitude: min_latitude.max(-90.0),
+                max_latitude: max_latitude.min(90.0),
+                longitude_ranges: vec![(-180.0, 180.0)],
+            };
+        }
+
+        let lon_delta = (angular_radius.sin() / center.latitude.to_radians().cos())
+            .asin()
+            .to_degrees();
+        let min_longitude = center.longitude - lon_delta;
+        let max_longitude = center.longitude + lon_delta;
+
+        let longitude_ranges = if lon_delta.is_nan() || lon_delta >= 180.0 {
+            vec![(-180.0, 180.0)]
+        } else if min_longitude < -180.0 {
+            vec![(min_longitude + 360.0, 180.0), (-180.0, max_longitude)]
+        } else if max_longitude > 180.0 {
+            vec![(min_longitude, 180.0), (-180.0, max_longitude - 360.0)]
+        } else {
+            vec![(min_longitude, max_longitude)]
+        };
+
+        Self {
+            min_latitude,
+            max_latitude,
+            longitude_ranges,
+        }
+    }
+}
@@ -2,4 +2,7 @@ pub mod events;
 pub mod rules;
 pub mod entity;
 pub mod airport_repository_interface;
-pub mod error;
\ No newline at end of file
+pub mod error;
+pub mod country_time_zones;
+pub mod geo;
+pub mod search;
\ No newline at end of file
@@ -0,0 +1,89 @@
+use crate::domain::airport::entity::Airport;
+
+/// Ranks an airport against an autocomplete query; higher scores rank first and `None`
+/// means no match. Codes beat names, prefixes beat substrings, and a near miss on a name
+/// or city word (a typo) ranks last.
+pub fn match_score(airport: &Airport, query: &str) -> Option<u32> {
+    let query = query.trim().to_lowercase();
+    if query.is_empty() {
+        return None;
+    }
+
+    let iata_code = airport.iata_code.to_lowercase();
+    let icao_code = airport
+        .icao_code
+        .as_deref()
+        .unwrap_or_default()
+        .to_lowercase();
+    let name = airport.name.to_lowercase();
+    let city = airport.city.to_lowercase();
+
+    let score = if iata_code == query {
+        1000
+    } else if icao_code == query {
+        950
+    } else if iata_code.starts_with(&query) {
+        900
+    } else if !icao_code.is_empty() && icao_code.starts_with(&query) {
+        850
+    } else if city == query {
+        800
+    } else if city.starts_with(&query) {
+        750
+    } else if name.starts_with(&query) {
+        700
+    } else if words(&city)
+        .chain(words(&name))
+        .any(|w| w.starts_with(&query))
+    {
+        650
+    } else if city.contains(&query) || name.contains(&query) {
+        500
+    } else {
+        return fuzzy_score(&query, &city, &name);
+    };
+
+    Some(score)
+}
+
+fn words(value: &str) -> impl Iterator<Item = &str> {
+    value
+        .split(|c: char| !c.is_alphanumeric())
+        .filter(|w| !w.is_empty())
+}
+
+// One typo allowed up to five characters, two beyond; short queries are too ambiguous.
+fn fuzzy_score(query: &str, city: &str, name: &str) -> Option<u32> {
+    let query_len = query.chars().count();
+    if query_len < 4 {
+        return None;
+    }
+    let max_distance = if query_len <= 5 { 1 } else { 2 };
+
+    words(city)
+        .chain(words(name))
+        .map(|word| {
+            // Compare against the word's prefix too, so a typo while still typing matches.
+            let prefix: String = word.chars().take(query_len).collect();
+            edit_distance(query, word).min(edit_distance(query, &prefix))
+        })
+        .min()
+        .filter(|distance| *distance <= max_distance)
+        .map(|distance| 300 - 100 * distance as u32)
+}
+
+fn edit_distance(a: &str, b: &str) -> usize {
+    let b: Vec<char> = b.chars().collect();
+    let mut previous: Vec<usize> = (0..=b.len()).collect();
+
+    for (i, ca) in a.chars().enumerate() {
+        let mut current = vec![i + 1; b.len() + 1];
+        for (j, cb) in b.iter().enumerate() {
+            let substitution = previous[j] + usize::from(ca != *cb);
+            current[j + 1] = substitution.min(previous[j + 1] + 1).min(current[j] + 1);
+        }
+        previous = current;
+    }
+
+    previous[b.len()]
+}
@@ -0,0 +1,26 @@
+use crate::domain::baggage::entity::{Bag, BagStatusEntry};
+use crate::domain::error::DomainError;
+
+#[async_trait::async_trait]
+pub trait BagRepositoryInterface: Send + Sync {
+    /// Next license plate serial; wraps after 999999.
+    async fn next_tag_serial(&self) -> Result<i64, DomainError>;
+
+    async fn create_bag(&self, bag: &Bag, entry: &BagStatusEntry) -> Result<i64, DomainError>;
+
+    async fn update_bag_status(
+        &self,
+        bag: &Bag,
+        expected_version: i32,
+        entry: &BagStatusEntry,
+    ) -> Result<(), DomainError>;
+
+    async fn find_bag_by_tag(&self, tag_number: &str) -> Result<Option<Bag>, DomainError>;
+
+    async fn list_bags_by_checkin(&self, checkin_id: i64) -> Result<Vec<Bag>, DomainError>;
+
+    async fn list_bags_by_booking(&self, booking_id: i64) -> Result<Vec<Bag>, DomainError>;
+
+    async fn list_status_history(&self, bag_ids: &[i64])
+    -> Result<Vec<BagStatusEntry>, DomainError>;
+}
@@ -0,0 +1,25 @@
+use crate::domain::baggage::error::BaggageDomainError;
+use crate::domain::error::DomainError;
+
+/// Builds IATA license plates: a leading `0` for tags printed by the carrier, the
+/// carrier's 3-digit accounting code, then a 6-digit serial.
+#[derive(Debug, Clone)]
+pub struct BagTagIssuer {
+    // IATA airline code to 3-digit accounting code, e.g. `("VN", "738")`.
+    pub issuer_codes: Vec<(String, String)>,
+}
+
+impl BagTagIssuer {
+    pub fn tag_number(&self, airline_code: &str, serial: i64) -> Result<String, DomainError> {
+        let issuer_code = self
+            .issuer_codes
+            .iter()
+            .find(|(airline, _)| airline.eq_ignore_ascii_case(airline_code.trim()))
+            .map(|(_, code)| code)
+            .ok_or_else(|| BaggageDomainError::TagIssuerNotConfigured {
+                airline_code: airline_code.to_string(),
+            })?;
+
+        Ok(format!("0{issuer_code}{:06}", serial.rem_euclid(1_000_000)))
+    }
+}
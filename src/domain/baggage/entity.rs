@@ -0,0 +1,160 @@
+use crate::domain::baggage::error::BaggageDomainError;
+use crate::domain::error::DomainError;
+use chrono::NaiveDateTime;
+use rust_decimal::Decimal;
+
+#[derive(Debug, Clone)]
+pub struct CreateBagProps {
+    pub tag_number: String,
+    pub checkin_id: i64,
+    pub booking_id: i64,
+    pub passenger_id: i64,
+    pub flight_id: i64,
+    pub destination_airport_id: i64,
+    pub weight_kg: Decimal,
+    // where the bag was accepted, usually the origin airport
+    pub location: String,
+}
+
+impl CreateBagProps {
+    pub fn validate(&self) -> Result<(), DomainError> {
+        if self.tag_number.len() != 10 || !self.tag_number.chars().all(|c| c.is_ascii_digit()) {
+            return Err(BaggageDomainError::Validation {
+                field: "tag_number",
+                message: "Bag tag number must be 10 digits".to_string(),
+            }
+            .into());
+        }
+        if self.weight_kg <= Decimal::ZERO {
+            return Err(BaggageDomainError::Validation {
+                field: "weight",
+                message: "Bag weight must be positive".to_string(),
+            }
+            .into());
+        }
+        Ok(())
+    }
+}
+
+/// A checked bag, identified by its IATA license plate (10-digit tag number).
+#[derive(Debug, Clone)]
+pub struct Bag {
+    pub id: i64,
+    pub tag_number: String,
+    pub checkin_id: i64,
+    pub booking_id: i64,
+    pub passenger_id: i64,
+    pub flight_id: i64,
+    pub destination_airport_id: i64,
+
+    pub weight_kg: Decimal,
+    pub status: BagStatus,
+    pub last_location: Option<String>,
+
+    // for Optimistic locking
+    pub version: i32,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum BagStatus {
+    Checked,
+    Loaded,
+    Transferred,
+    Arrived,
+    Delayed,
+}
+
+/// One step of a bag's journey; the history is append-only.
+#[derive(Debug, Clone)]
+pub struct BagStatusEntry {
+    pub id: i64,
+    pub bag_id: i64,
+    pub status: BagStatus,
+    pub location: Option<String>,
+    pub note: Option<String>,
+    pub recorded_at: NaiveDateTime,
+}
+
+impl Bag {
+    /// Creates a bag accepted at check-in along with its first history entry.
+    pub fn new(
+        props: CreateBagProps,
+        now: NaiveDateTime,
+    ) -> Result<(Self, BagStatusEntry), DomainError> {
+        props.validate()?;
+
+        let bag = Self {
+            id: 0,
+            tag_number: props.tag_number,
+            checkin_id: props.checkin_id,
+            booking_id: props.booking_id,
+            passenger_id: props.passenger_id,
+            flight_id: props.flight_id,
+            destination_airport_id: props.destination_airport_id,
+
+            weight_kg: props.weight_kg,
+            status: BagStatus::Checked,
+            last_location: Some(props.location.clone()),
+
+            version: 1,
+        };
+        let entry = BagStatusEntry {
+            id: 0,
+            bag_id: 0,
+            status: BagStatus::Checked,
+            location: Some(props.location),
+            note: None,
+            recorded_at: now,
+        };
+
+        Ok((bag, entry))
+    }
+
+    /// Moves the bag along its journey and returns the history entry to record.
+    pub fn update_status(
+        &mut self,
+        status: BagStatus,
+        location: Option<String>,
+        note: Option<String>,
+        now: NaiveDateTime,
+    ) -> Result<BagStatusEntry, DomainError> {
+        let allowed = match self.status {
+            BagStatus::Checked => matches!(status, BagStatus::Loaded | BagStatus::Delayed),
+            BagStatus::Loaded => matches!(
+                status,
+                BagStatus::Transferred | BagStatus::Arrived | BagStatus::Delayed
+            ),
+            BagStatus::Transferred => matches!(
+                status,
+                BagStatus::Loaded | BagStatus::Arrived | BagStatus::Delayed
+            ),
+            // a delayed bag is found again and put back on its way
+            BagStatus::Delayed => matches!(
+                status,
+                BagStatus::Loaded | BagStatus::Transferred | BagStatus::Arrived
+            ),
+            BagStatus::Arrived => false,
+        };
+        if !allowed {
+            return Err(BaggageDomainError::InvalidStatusTransition {
+                from: self.status,
+                to: status,
+            }
+            .into());
+        }
+
+        self.status = status;
+        if location.is_some() {
+            self.last_location = location.clone();
+        }
+
+        Ok(BagStatusEntry {
+            id: 0,
+            bag_id: self.id,
+            status,
+            location,
+            note,
+            recorded_at: now,
+        })
+    }
+}
@@ -0,0 +1,45 @@
+use crate::domain::baggage::entity::BagStatus;
+use thiserror::Error;
+
+#[derive(Debug, Error)]
+pub enum BaggageDomainError {
+    // ===== Validation =====
+    #[error("validation error: {field} - {message}")]
+    Validation {
+        field: &'static str,
+        message: String,
+    },
+
+    // ===== Business rule =====
+    #[error("business rule violated: {message}")]
+    BusinessRule { message: String },
+
+    // ===== State =====
+    #[error("invalid bag status transition: {from:?} -> {to:?}")]
+    InvalidStatusTransition { from: BagStatus, to: BagStatus },
+
+    // ===== Tagging =====
+    #[error("no bag tag issuer code configured for carrier: {airline_code}")]
+    TagIssuerNotConfigured { airline_code: String },
+
+    // ===== Conflict =====
+    #[error("conflict: {field} - {message}")]
+    Conflict {
+        field: &'static str,
+        message: String,
+    },
+
+    #[error("optimistic lock conflict")]
+    OptimisticLockConflict,
+
+    // ===== Not found =====
+    #[error("bag not found: {tag_number}")]
+    BagNotFound { tag_number: String },
+
+    #[error("entity not found: {detail}")]
+    NotFound { detail: String },
+
+    // ===== Internal =====
+    #[error("internal error: {0}")]
+    Internal(String),
+}
@@ -0,0 +1,41 @@
+use crate::domain::baggage::entity::Bag;
+use chrono::NaiveDateTime;
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct BagStatusChangedEvent {
+    pub bag_id: i64,
+    pub tag_number: String,
+    pub booking_id: i64,
+    pub passenger_id: i64,
+    pub flight_id: i64,
+    pub previous_status: Option<String>,
+    pub status: String,
+    pub location: Option<String>,
+    pub occurred_at: NaiveDateTime,
+}
+
+impl BagStatusChangedEvent {
+    pub fn new(
+        bag: &Bag,
+        previous_status: Option<String>,
+        status: String,
+        occurred_at: NaiveDateTime,
+    ) -> Self {
+        Self {
+            bag_id: bag.id,
+            tag_number: bag.tag_number.clone(),
+            booking_id: bag.booking_id,
+            passenger_id: bag.passenger_id,
+            flight_id: bag.flight_id,
+            previous_status,
+            status,
+            location: bag.last_location.clone(),
+            occurred_at,
+        }
+    }
+
+    pub fn topic_name() -> &'static str {
+        "baggage.status_changed"
+    }
+}
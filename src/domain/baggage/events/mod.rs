@@ -0,0 +1 @@
+pub mod bag_status_changed;
\ No newline at end of file
@@ -0,0 +1,5 @@
+pub mod events;
+pub mod entity;
+pub mod bag_repository_interface;
+pub mod error;
+pub mod bag_tag_issuer;
\ No newline at end of file
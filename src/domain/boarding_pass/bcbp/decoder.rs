@@ -0,0 +1,327 @@
+use crate::domain::boarding_pass::bcbp::model::{
+    AIRPORT_LEN, BAG_TAG_LEN, BcbpLeg, BcbpLegConditional, BcbpPass, BcbpSecurity,
+    BcbpUniqueConditional, CARRIER_LEN, FLIGHT_NUMBER_LEN, JULIAN_DATE_LEN, NAME_LEN, PNR_LEN,
+    REPEATED_FIELD_WIDTHS, SEAT_LEN, SEQUENCE_LEN, UNIQUE_FIELD_WIDTHS,
+};
+use crate::domain::boarding_pass::bcbp::{BCBP_FORMAT_CODE, BCBP_MAX_LEGS};
+use crate::domain::boarding_pass::error::BoardingPassDomainError;
+use crate::domain::error::DomainError;
+
+pub fn decode(value: &str) -> Result<BcbpPass, DomainError> {
+    if !value.is_ascii() {
+        return Err(invalid("BCBP data must be ASCII".to_string()));
+    }
+
+    let mut cursor = Cursor::new(value);
+
+    if cursor.take(1, "format_code")? != BCBP_FORMAT_CODE.to_string() {
+        return Err(invalid(format!(
+            "Unsupported BCBP format code, expected {BCBP_FORMAT_CODE}"
+        )));
+    }
+
+    let leg_count = cursor
+        .take(1, "number_of_legs")?
+        .parse::<usize>()
+        .ok()
+        .filter(|n| (1..=BCBP_MAX_LEGS).contains(n))
+        .ok_or_else(|| invalid("Invalid number of legs encoded".to_string()))?;
+
+    let passenger_name = cursor.take(NAME_LEN, "passenger_name")?.trim().to_string();
+    let electronic_ticket = cursor.take(1, "electronic_ticket_indicator")? == "E";
+
+    let mut legs = Vec::with_capacity(leg_count);
+    let mut unique = None;
+
+    for index in 0..leg_count {
+        let mut leg = decode_mandatory_leg(&mut cursor)?;
+
+        let variable_size = parse_hex(cursor.take(2, "variable_size")?)?;
+        let mut variable = Cursor::new(cursor.take(variable_size, "variable_section")?);
+
+        if index == 0 && variable.peek() == Some('>') {
+            variable.take(1, "version_marker")?;
+            let version = variable.take(1, "version_number")?.to_string();
+            let unique_size = parse_hex(variable.take(2, "unique_conditional_size")?)?;
+            let block = variable.take(unique_size, "unique_conditional")?;
+            unique = Some(decode_unique(version, block));
+        }
+
+        if variable.remaining() >= 2 {
+            let repeated_size = parse_hex(variable.take(2, "repeated_conditional_size")?)?;
+            if repeated_size > 0 {
+                let block = variable.take(repeated_size, "repeated_conditional")?;
+                leg.conditional = Some(decode_repeated(block));
+            }
+        }
+        leg.airline_data = variable.rest().to_string();
+
+        legs.push(leg);
+    }
+
+    let security = if cursor.peek() == Some('^') {
+        cursor.take(1, "security_marker")?;
+        let security_type = cursor.take(1, "security_type")?.trim().to_string();
+        let length = parse_hex(cursor.take(2, "security_length")?)?;
+        let data = cursor.take(length, "security_data")?.to_string();
+        Some(BcbpSecurity {
+            security_type,
+            data,
+        })
+    } else {
+        None
+    };
+
+    if cursor.remaining() > 0 {
+        return Err(invalid(format!(
+            "Unexpected trailing data in BCBP: {}",
+            cursor.rest()
+        )));
+    }
+
+    Ok(BcbpPass {
+        passenger_name,
+        electronic_ticket,
+        legs,
+        unique,
+        security,
+    })
+}
+
+fn decode_mandatory_leg(cursor: &mut Cursor<'_>) -> Result<BcbpLeg, DomainError> {
+    let pnr = cursor.take(PNR_LEN, "pnr")?.trim().to_string();
+    let from_airport = cursor.take(AIRPORT_LEN, "from_airport")?.trim().to_string();
+    let to_airport = cursor.take(AIRPORT_LEN, "to_airport")?.trim().to_string();
+    let operating_carrier = cursor
+        .take(CARRIER_LEN, "operating_carrier")?
+        .trim()
+        .to_string();
+
+    let raw_flight = cursor.take(FLIGHT_NUMBER_LEN, "flight_number")?;
+    let (digits, suffix) = raw_flight.split_at(4);
+    let digits = digits.trim();
+    // `0834` -> `834`, but `0000` keeps one digit.
+    let digits = match digits.trim_start_matches('0') {
+        "" => &digits[digits.len().saturating_sub(1)..],
+        stripped => stripped,
+    };
+    let flight_number = format!("{}{}", digits, suffix.trim());
+
+    let flight_day_of_year = cursor
+        .take(JULIAN_DATE_LEN, "flight_date")?
+        .trim()
+        .parse::<u16>()
+        .map_err(|_| invalid("Invalid Julian flight date".to_string()))?;
+
+    let compartment = cursor.take(1, "compartment")?.trim().to_string();
+    let seat = cursor.take(SEAT_LEN, "seat")?.trim().to_string();
+    let sequence_no = cursor.take(SEQUENCE_LEN, "sequence_no")?.trim().to_string();
+    let passenger_status = cursor.take(1, "passenger_status")?.trim().to_string();
+
+    Ok(BcbpLeg {
+        pnr,
+        from_airport,
+        to_airport,
+        operating_carrier,
+        flight_number,
+        flight_day_of_year,
+        compartment,
+        seat,
+        sequence_no,
+        passenger_status,
+        conditional: None,
+        airline_data: String::new(),
+    })
+}
+
+// Conditional blocks may be truncated after any field, so missing trailing items decode as empty.
+fn decode_unique(version: String, block: &str) -> BcbpUniqueConditional {
+    let mut fields = split_fields(block, &UNIQUE_FIELD_WIDTHS);
+    let rest = &block[UNIQUE_FIELD_WIDTHS.iter().sum::<usize>().min(block.len())..];
+
+    let bag_tags = rest
+        .as_bytes()
+        .chunks(BAG_TAG_LEN)
+        .map(|chunk| String::from_utf8_lossy(chunk).trim().to_string())
+        .filter(|tag| !tag.is_empty())
+        .collect();
+
+    BcbpUniqueConditional {
+        version,
+        passenger_description: fields.remove(0),
+        checkin_source: fields.remove(0),
+        issuance_source: fields.remove(0),
+        issue_date: fields.remove(0),
+        document_type: fields.remove(0),
+        issuer_designator: fields.remove(0),
+        bag_tags,
+    }
+}
+
+fn decode_repeated(block: &str) -> BcbpLegConditional {
+    let mut fields = split_fields(block, &REPEATED_FIELD_WIDTHS).into_iter();
+    let mut next = || fields.next().unwrap_or_default();
+
+    BcbpLegConditional {
+        airline_numeric_code: next(),
+        document_serial: next(),
+        selectee: next(),
+        document_verification: next(),
+        marketing_carrier: next(),
+        frequent_flyer_airline: next(),
+        frequent_flyer_number: next(),
+        id_ad_indicator: next(),
+        free_baggage: next(),
+        fast_track: next(),
+    }
+}
+
+fn split_fields(block: &str, widths: &[usize]) -> Vec<String> {
+    let mut cursor = Cursor::new(block);
+    widths
+        .iter()
+        .map(|width| {
+            let width = (*width).min(cursor.remaining());
+            cursor
+                .take(width, "conditional_item")
+                .map(|v| v.trim().to_string())
+                .unwrap_or_default()
+        })
+        .collect()
+}
+
+fn parse_hex(value: &str) -> Result<usize, DomainError> {
+    usize::from_str_radix(value, 16)
+        .map_err(|_| invalid(format!("Invalid BCBP field size: {value}")))
+}
+
+fn invalid(message: String) -> DomainError {
+    BoardingPassDomainError::Validation {
+        field: "barcode_payload",
+        message,
+    }
+    .into()
+}
+
+struct Cursor<'a> {
+    data: &'a str,
+    pos: usize,
+}
+
+impl<'a> Cursor<'a> {
+    fn new(data: &'a str) -> Self {
+        Self { data, pos: 0 }
+    }
+
+    fn take(&mut self, len: usize, field: &str) -> Result<&'a str, DomainError> {
+        if self.remaining() < len {
+            return Err(invalid(format!(
+                "BCBP data too short while reading {field}"
+            )));
+        }
+        let value = &self.data[self.pos..self.pos + len];
+        self.pos += len;
+        Ok(value)
+    }
+
+    fn peek(&self) -> Option<char> {
+        self.data[self.pos..].chars().next()
+    }
+
+    fn remaining(&self) -> usize {
+        self.data.len() - self.pos
+    }
+
+    fn rest(&mut self) -> &'a str {
+        let value = &self.data[self.pos..];
+        self.pos = self.data.len();
+        value
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::decode;
+    use crate::domain::boarding_pass::bcbp::encoder::encode;
+
+    // Resolution 792 attachment examples: mandatory items only, and two legs with
+    // conditional items, airline data and a security section.
+    const MANDATORY_ONLY: &str = "M1DESMARAIS/LUC       EABC123 YULFRAAC 0834 326J001A0025 100";
+    const TWO_LEGS: &str = concat!(
+        "M2DESMARAIS/LUC       EABC123 YULFRAAC 0834 326J001A0025 14D>6181WW6225BAC 00141234560032A",
+        "0141234567890 1AC AC 1234567890123    20KYLX58ZDEF456 FRAGVALH 3664 327C012C0002 12E2A0141",
+        "234567890 1AC AC 1234567890123    2PCNWQ^164GIWVC5EH7JNT684FVNJ91W2QA4DVN5J8K4F0L0GEQ3DF5TGB",
+        "N8709HKT5D3DW3GBHFCVHMY7J5T6HFR41W2QA4DVN5J8K4F0L0GE",
+    );
+
+    #[test]
+    fn mandatory_only_sample_round_trips() {
+        let pass = decode(MANDATORY_ONLY).unwrap();
+
+        assert_eq!(pass.passenger_name, "DESMARAIS/LUC");
+        assert!(pass.electronic_ticket);
+        assert_eq!(pass.legs.len(), 1);
+        let leg = &pass.legs[0];
+        assert_eq!(leg.pnr, "ABC123");
+        assert_eq!(leg.from_airport, "YUL");
+        assert_eq!(leg.to_airport, "FRA");
+        assert_eq!(leg.operating_carrier, "AC");
+        assert_eq!(leg.flight_number, "834");
+        assert_eq!(leg.flight_day_of_year, 326);
+        assert_eq!(leg.compartment, "J");
+        assert_eq!(leg.seat, "001A");
+        assert_eq!(leg.sequence_no, "0025");
+        assert_eq!(leg.passenger_status, "1");
+        assert!(leg.conditional.is_none());
+        assert!(pass.unique.is_none());
+        assert!(pass.security.is_none());
+
+        assert_eq!(encode(&pass).unwrap(), MANDATORY_ONLY);
+    }
+
+    #[test]
+    fn two_leg_sample_round_trips() {
+        let pass = decode(TWO_LEGS).unwrap();
+
+        assert_eq!(pass.legs.len(), 2);
+
+        let unique = pass.unique.as_ref().unwrap();
+        assert_eq!(unique.version, "6");
+        assert_eq!(unique.passenger_description, "1");
+        assert_eq!(unique.issue_date, "6225");
+        assert_eq!(unique.issuer_designator, "AC");
+        assert_eq!(unique.bag_tags, vec!["0014123456003".to_string()]);
+
+        let first = &pass.legs[0];
+        let conditional = first.conditional.as_ref().unwrap();
+        assert_eq!(conditional.airline_numeric_code, "014");
+        assert_eq!(conditional.document_serial, "1234567890");
+        assert_eq!(conditional.marketing_carrier, "AC");
+        assert_eq!(conditional.frequent_flyer_number, "1234567890123");
+        assert_eq!(conditional.free_baggage, "20K");
+        assert_eq!(conditional.fast_track, "Y");
+        assert_eq!(first.airline_data, "LX58Z");
+
+        let second = &pass.legs[1];
+        assert_eq!(second.pnr, "DEF456");
+        assert_eq!(second.operating_carrier, "LH");
+        assert_eq!(second.flight_number, "3664");
+        assert_eq!(second.seat, "012C");
+        assert_eq!(second.airline_data, "WQ");
+
+        let security = pass.security.as_ref().unwrap();
+        assert_eq!(security.security_type, "1");
+        assert_eq!(security.data.len(), 0x64);
+
+        assert_eq!(encode(&pass).unwrap(), TWO_LEGS);
+    }
+
+    #[test]
+    fn all_zero_flight_number_keeps_one_digit() {
+        let raw = MANDATORY_ONLY.replace("AC 0834 ", "AC 0000 ");
+        let pass = decode(&raw).unwrap();
+
+        assert_eq!(pass.legs[0].flight_number, "0");
+        assert_eq!(encode(&pass).unwrap(), raw);
+    }
+}
@@ -0,0 +1,197 @@
+use crate::domain::boarding_pass::bcbp::model::{
+    AIRPORT_LEN, BAG_TAG_LEN, BcbpLeg, BcbpLegConditional, BcbpPass, BcbpUniqueConditional,
+    CARRIER_LEN, FLIGHT_NUMBER_LEN, JULIAN_DATE_LEN, NAME_LEN, PNR_LEN, SEAT_LEN, SEQUENCE_LEN,
+};
+use crate::domain::boarding_pass::bcbp::{BCBP_FORMAT_CODE, BCBP_MAX_LEGS, BCBP_VERSION};
+use crate::domain::boarding_pass::error::BoardingPassDomainError;
+use crate::domain::error::DomainError;
+
+pub fn encode(pass: &BcbpPass) -> Result<String, DomainError> {
+    if pass.legs.is_empty() || pass.legs.len() > BCBP_MAX_LEGS {
+        return Err(invalid(
+            "legs",
+            format!(
+                "BCBP supports 1 to {BCBP_MAX_LEGS} legs, got {}",
+                pass.legs.len()
+            ),
+        ));
+    }
+
+    let mut out = String::new();
+    out.push(BCBP_FORMAT_CODE);
+    out.push_str(&pass.legs.len().to_string());
+    out.push_str(&alpha(&pass.passenger_name, NAME_LEN));
+    out.push(if pass.electronic_ticket { 'E' } else { ' ' });
+
+    for (index, leg) in pass.legs.iter().enumerate() {
+        out.push_str(&encode_mandatory_leg(leg)?);
+
+        let unique = if index == 0 {
+            pass.unique.as_ref()
+        } else {
+            None
+        };
+        let variable = encode_variable_leg(leg, unique)?;
+        out.push_str(&hex_size(variable.len(), "variable_size")?);
+        out.push_str(&variable);
+    }
+
+    if let Some(security) = &pass.security {
+        out.push('^');
+        out.push_str(&alpha(&security.security_type, 1));
+        out.push_str(&hex_size(security.data.len(), "security_data")?);
+        out.push_str(&security.data);
+    }
+
+    Ok(out)
+}
+
+fn encode_mandatory_leg(leg: &BcbpLeg) -> Result<String, DomainError> {
+    if leg.flight_day_of_year == 0 || leg.flight_day_of_year > 366 {
+        return Err(invalid(
+            "flight_day_of_year",
+            format!("Invalid Julian date: {}", leg.flight_day_of_year),
+        ));
+    }
+
+    let mut out = String::new();
+    out.push_str(&alpha(&leg.pnr, PNR_LEN));
+    out.push_str(&alpha(&leg.from_airport, AIRPORT_LEN));
+    out.push_str(&alpha(&leg.to_airport, AIRPORT_LEN));
+    out.push_str(&alpha(&leg.operating_carrier, CARRIER_LEN));
+    out.push_str(&flight_number(&leg.flight_number)?);
+    out.push_str(&format!(
+        "{:0width$}",
+        leg.flight_day_of_year,
+        width = JULIAN_DATE_LEN
+    ));
+    out.push_str(&alpha(&leg.compartment, 1));
+    out.push_str(&seat(&leg.seat));
+    out.push_str(&sequence_no(&leg.sequence_no));
+    out.push_str(&alpha(&leg.passenger_status, 1));
+    Ok(out)
+}
+
+fn encode_variable_leg(
+    leg: &BcbpLeg,
+    unique: Option<&BcbpUniqueConditional>,
+) -> Result<String, DomainError> {
+    let mut out = String::new();
+
+    if let Some(unique) = unique {
+        let block = encode_unique(unique);
+        out.push('>');
+        out.push(unique.version.chars().next().unwrap_or(BCBP_VERSION));
+        out.push_str(&hex_size(block.len(), "unique_conditional")?);
+        out.push_str(&block);
+    }
+
+    match &leg.conditional {
+        Some(conditional) => {
+            let block = encode_repeated(conditional);
+            out.push_str(&hex_size(block.len(), "repeated_conditional")?);
+            out.push_str(&block);
+        }
+        // The size field is still needed as a separator when airline data follows.
+        None if unique.is_some() || !leg.airline_data.is_empty() => out.push_str("00"),
+        None => {}
+    }
+
+    out.push_str(&leg.airline_data);
+    Ok(out)
+}
+
+fn encode_unique(unique: &BcbpUniqueConditional) -> String {
+    let mut out = String::new();
+    out.push_str(&alpha(&unique.passenger_description, 1));
+    out.push_str(&alpha(&unique.checkin_source, 1));
+    out.push_str(&alpha(&unique.issuance_source, 1));
+    out.push_str(&alpha(&unique.issue_date, 4));
+    out.push_str(&alpha(&unique.document_type, 1));
+    out.push_str(&alpha(&unique.issuer_designator, CARRIER_LEN));
+    for tag in unique.bag_tags.iter().take(3) {
+        out.push_str(&alpha(tag, BAG_TAG_LEN));
+    }
+    out
+}
+
+fn encode_repeated(conditional: &BcbpLegConditional) -> String {
+    let mut out = String::new();
+    out.push_str(&alpha(&conditional.airline_numeric_code, 3));
+    out.push_str(&alpha(&conditional.document_serial, 10));
+    out.push_str(&alpha(&conditional.selectee, 1));
+    out.push_str(&alpha(&conditional.document_verification, 1));
+    out.push_str(&alpha(&conditional.marketing_carrier, CARRIER_LEN));
+    out.push_str(&alpha(&conditional.frequent_flyer_airline, CARRIER_LEN));
+    out.push_str(&alpha(&conditional.frequent_flyer_number, 16));
+    out.push_str(&alpha(&conditional.id_ad_indicator, 1));
+    out.push_str(&alpha(&conditional.free_baggage, 3));
+    out.push_str(&alpha(&conditional.fast_track, 1));
+    out
+}
+
+// Left-justified, blank-padded, truncated to width.
+fn alpha(value: &str, width: usize) -> String {
+    let value: String = value.chars().take(width).collect();
+    format!("{value:<width$}")
+}
+
+// `834` -> `0834 `, `1234A` -> `1234A`
+fn flight_number(value: &str) -> Result<String, DomainError> {
+    let value = value.trim();
+    let digits: String = value.chars().take_while(|c| c.is_ascii_digit()).collect();
+    let suffix = &value[digits.len()..];
+
+    if digits.is_empty() || digits.len() > 4 || suffix.len() > 1 {
+        return Err(invalid(
+            "flight_number",
+            format!("Flight number {value} cannot be encoded in BCBP"),
+        ));
+    }
+
+    Ok(format!(
+        "{:0>4}{}",
+        digits,
+        alpha(suffix, FLIGHT_NUMBER_LEN - 4)
+    ))
+}
+
+// `1A` -> `001A`; infants travel without a seat and carry `INF`.
+fn seat(value: &str) -> String {
+    let value = value.trim().to_uppercase();
+    let digits: String = value.chars().take_while(|c| c.is_ascii_digit()).collect();
+    let rest = &value[digits.len()..];
+
+    if !digits.is_empty() && digits.len() <= 3 && rest.len() == 1 {
+        format!("{digits:0>3}{rest}")
+    } else if value == "INF" {
+        alpha(&value, SEAT_LEN)
+    } else {
+        // Unassigned or placeholder seats are left blank.
+        alpha("", SEAT_LEN)
+    }
+}
+
+// `25` -> `0025 `
+fn sequence_no(value: &str) -> String {
+    let value = value.trim();
+    let digits: String = value.chars().take_while(|c| c.is_ascii_digit()).collect();
+    let rest = &value[digits.len()..];
+
+    if !digits.is_empty() && digits.len() <= 4 && rest.len() <= 1 {
+        format!("{digits:0>4}{}", alpha(rest, 1))
+    } else {
+        alpha(value, SEQUENCE_LEN)
+    }
+}
+
+fn hex_size(len: usize, field: &'static str) -> Result<String, DomainError> {
+    if len > 0xFF {
+        return Err(invalid(field, format!("Section too long for BCBP: {len}")));
+    }
+    Ok(format!("{len:02X}"))
+}
+
+fn invalid(field: &'static str, message: String) -> DomainError {
+    BoardingPassDomainError::Validation { field, message }.into()
+}
@@ -0,0 +1,241 @@
+use chrono::{Datelike, NaiveDateTime};
+use unicode_normalization::UnicodeNormalization;
+use unicode_normalization::char::is_combining_mark;
+
+use crate::domain::boarding_pass::bcbp::model::{
+    BcbpLeg, BcbpLegConditional, BcbpPass, BcbpUniqueConditional, NAME_LEN,
+};
+use crate::domain::boarding_pass::bcbp::{BCBP_MAX_LEGS, BCBP_VERSION};
+use crate::domain::boarding_pass::entity::BoardingPass;
+use crate::domain::boarding_pass::error::BoardingPassDomainError;
+use crate::domain::checkin::entity::{Checkin, CheckinChannel, CheckinStatus, SeatClass};
+use crate::domain::error::DomainError;
+use crate::domain::flight::entity::Flight;
+use crate::domain::passenger::entity::{Passenger, PassengerType};
+
+/// Everything needed to encode one flight segment of a boarding pass.
+pub struct BcbpLegSource<'a> {
+    pub booking_code: &'a str,
+    pub flight: &'a Flight,
+    // Carrier the booking was sold under; the operating carrier unless it is a codeshare.
+    pub marketing_carrier: &'a str,
+    pub origin_iata_code: &'a str,
+    pub destination_iata_code: &'a str,
+    pub checkin: &'a Checkin,
+    // Present once the pass is issued; supplies the boarding sequence number.
+    pub boarding_pass: Option<&'a BoardingPass>,
+}
+
+/// The boarding pass code travels in the first leg's airline-use field so a scanned
+/// pass can be matched back to its record.
+pub fn build_bcbp(
+    passenger: &Passenger,
+    legs: &[BcbpLegSource<'_>],
+    boarding_pass_code: &str,
+    issued_at: NaiveDateTime,
+) -> Result<BcbpPass, DomainError> {
+    let first = legs.first().ok_or_else(|| -> DomainError {
+        BoardingPassDomainError::Validation {
+            field: "legs",
+            message: "A boarding pass needs at least one leg".to_string(),
+        }
+        .into()
+    })?;
+    if legs.len() > BCBP_MAX_LEGS {
+        return Err(BoardingPassDomainError::Validation {
+            field: "legs",
+            message: format!("A boarding pass can hold at most {BCBP_MAX_LEGS} legs"),
+        }
+        .into());
+    }
+
+    let source = checkin_source(&first.checkin.checkin_channel).to_string();
+
+    let mut bcbp_legs: Vec<BcbpLeg> = legs.iter().map(|leg| build_leg(passenger, leg)).collect();
+    bcbp_legs[0].airline_data = boarding_pass_code.to_string();
+
+    Ok(BcbpPass {
+        passenger_name: passenger_name(passenger),
+        electronic_ticket: true,
+        legs: bcbp_legs,
+        unique: Some(BcbpUniqueConditional {
+            version: BCBP_VERSION.to_string(),
+            passenger_description: passenger_description(passenger).to_string(),
+            checkin_source: source.clone(),
+            issuance_source: source,
+            issue_date: format!(
+                "{}{:03}",
+                issued_at.year().rem_euclid(10),
+                issued_at.ordinal()
+            ),
+            document_type: "B".to_string(),
+            issuer_designator: first.flight.airline_code.clone(),
+            bag_tags: Vec::new(),
+        }),
+        security: None,
+    })
+}
+
+fn build_leg(passenger: &Passenger, source: &BcbpLegSource<'_>) -> BcbpLeg {
+    let seat = if passenger.passenger_type.occupies_seat() {
+        source.checkin.seat_no.clone().unwrap_or_default()
+    } else {
+        "INF".to_string()
+    };
+
+    // Flight numbers are sometimes stored with the carrier prefix (`VN123`).
+    let carrier = source.flight.airline_code.trim().to_uppercase();
+    let flight_number = source.flight.flight_number.trim().to_uppercase();
+    let flight_number = flight_number
+        .strip_prefix(carrier.as_str())
+        .unwrap_or(flight_number.as_str())
+        .trim()
+        .to_string();
+
+    BcbpLeg {
+        pnr: source.booking_code.to_uppercase(),
+        from_airport: source.origin_iata_code.to_uppercase(),
+        to_airport: source.destination_iata_code.to_uppercase(),
+        operating_carrier: carrier,
+        flight_number,
+        flight_day_of_year: source.flight.departure_date.ordinal() as u16,
+        compartment: compartment_code(&source.checkin.seat_class).to_string(),
+        seat,
+        sequence_no: source
+            .boarding_pass
+            .and_then(|bp| bp.sequence_no.clone())
+            .unwrap_or_default(),
+        passenger_status: if source.checkin.status == CheckinStatus::CheckedIn {
+            "1".to_string()
+        } else {
+            "0".to_string()
+        },
+        conditional: Some(BcbpLegConditional {
+            selectee: "0".to_string(),
+            // 2 = documents verified; check-in enforces travel documents when a passport is held.
+            document_verification: if passenger.passport_no.is_some() {
+                "2".to_string()
+            } else {
+                "0".to_string()
+            },
+            marketing_carrier: source.marketing_carrier.trim().to_uppercase(),
+            frequent_flyer_airline: passenger
+                .ff_airline_code
+                .clone()
+                .unwrap_or_default()
+                .to_uppercase(),
+            frequent_flyer_number: passenger.ff_number.clone().unwrap_or_default(),
+            ..Default::default()
+        }),
+        airline_data: String::new(),
+    }
+}
+
+// `SURNAME/GIVEN`, ASCII upper case, cut to the 20-character field.
+fn passenger_name(passenger: &Passenger) -> String {
+    format_name(&passenger.last_name, &passenger.first_name)
+}
+
+fn format_name(last_name: &str, first_name: &str) -> String {
+    printable_name(last_name, first_name)
+        .chars()
+        .take(NAME_LEN)
+        .collect()
+}
+
+/// `LAST/FIRST` in upper-case ASCII, the way the barcode spells it but without the field
+/// length limit; printed documents use it so their fonts never meet a letter they lack.
+pub fn printable_name(last_name: &str, first_name: &str) -> String {
+    let clean = |value: &str| -> String {
+        transliterate(value)
+            .to_uppercase()
+            .chars()
+            .filter(|c| c.is_ascii_alphabetic() || *c == ' ' || *c == '-')
+            .collect::<String>()
+            .trim()
+            .to_string()
+    };
+
+    format!("{}/{}", clean(last_name), clean(first_name))
+}
+
+// `Nguyễn` -> `Nguyen`: drop the combining marks left by canonical decomposition.
+// Letters with no decomposition are mapped by hand.
+pub fn transliterate(value: &str) -> String {
+    let mut out = String::with_capacity(value.len());
+    for c in value.nfd().filter(|c| !is_combining_mark(*c)) {
+        match c {
+            'Đ' | 'Ð' => out.push('D'),
+            'đ' | 'ð' => out.push('d'),
+            'Ø' => out.push('O'),
+            'ø' => out.push('o'),
+            'Ł' => out.push('L'),
+            'ł' => out.push('l'),
+            'Æ' => out.push_str("AE"),
+            'æ' => out.push_str("ae"),
+            'Œ' => out.push_str("OE"),
+            'œ' => out.push_str("oe"),
+            'ß' => out.push_str("ss"),
+            'Þ' => out.push_str("TH"),
+            'þ' => out.push_str("th"),
+            other => out.push(other),
+        }
+    }
+    out
+}
+
+fn passenger_description(passenger: &Passenger) -> &'static str {
+    match passenger.passenger_type {
+        PassengerType::Infant => "4",
+        PassengerType::Child => "3",
+        PassengerType::Adult => match passenger.gender.trim().to_uppercase().chars().next() {
+            Some('M') => "1",
+            Some('F') => "2",
+            _ => "0",
+        },
+    }
+}
+
+fn checkin_source(channel: &CheckinChannel) -> &'static str {
+    match channel {
+        CheckinChannel::Web => "W",
+        CheckinChannel::Mobile => "M",
+        CheckinChannel::Kiosk => "K",
+        CheckinChannel::Counter => "O",
+    }
+}
+
+fn compartment_code(seat_class: &SeatClass) -> &'static str {
+    match seat_class {
+        SeatClass::Economy => "Y",
+        SeatClass::PremiumEconomy => "W",
+        SeatClass::Business => "J",
+        SeatClass::First => "F",
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{format_name, printable_name, transliterate};
+
+    #[test]
+    fn name_strips_diacritics() {
+        assert_eq!(format_name("Nguyễn", "Thị Minh"), "NGUYEN/THI MINH");
+        assert_eq!(format_name("Müller", "José"), "MULLER/JOSE");
+        assert_eq!(format_name("Trần", "Đức"), "TRAN/DUC");
+        assert_eq!(format_name("Strauß", "Søren"), "STRAUSS/SOREN");
+    }
+
+    #[test]
+    fn printed_name_keeps_the_full_name_without_diacritics() {
+        assert_eq!(
+            printable_name("Nguyễn", "Thị Minh Khai Phương"),
+            "NGUYEN/THI MINH KHAI PHUONG"
+        );
+        assert_eq!(
+            format_name("Nguyễn", "Thị Minh Khai Phương"),
+            "NGUYEN/THI MINH KHAI"
+        );
+        assert_eq!(transliterate("Đà Nẵng"), "Da Nang");
+    }
+}
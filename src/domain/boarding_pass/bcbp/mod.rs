@@ -0,0 +1,10 @@
+// IATA Resolution 792 Bar Coded Boarding Pass (BCBP), format code `M`, version 6.
+
+pub mod decoder;
+pub mod encoder;
+pub mod factory;
+pub mod model;
+
+pub const BCBP_FORMAT_CODE: char = 'M';
+pub const BCBP_VERSION: char = '6';
+pub const BCBP_MAX_LEGS: usize = 4;
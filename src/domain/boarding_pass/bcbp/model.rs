@@ -0,0 +1,78 @@
+/// Decoded/encodable BCBP. Text fields hold the trimmed value; the encoder pads
+/// them back to the fixed widths of the spec.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct BcbpPass {
+    /// `SURNAME/GIVEN NAME`, max 20 characters.
+    pub passenger_name: String,
+    pub electronic_ticket: bool,
+    pub legs: Vec<BcbpLeg>,
+    /// Conditional items that appear once, carried in the first leg.
+    pub unique: Option<BcbpUniqueConditional>,
+    pub security: Option<BcbpSecurity>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct BcbpLeg {
+    pub pnr: String,
+    pub from_airport: String,
+    pub to_airport: String,
+    pub operating_carrier: String,
+    /// Numeric part plus optional suffix, e.g. `834` or `1234A`.
+    pub flight_number: String,
+    /// Day of year of the flight date (Julian date, 1..=366).
+    pub flight_day_of_year: u16,
+    pub compartment: String,
+    pub seat: String,
+    pub sequence_no: String,
+    pub passenger_status: String,
+    pub conditional: Option<BcbpLegConditional>,
+    /// "For individual airline use" free text.
+    pub airline_data: String,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct BcbpUniqueConditional {
+    pub version: String,
+    pub passenger_description: String,
+    pub checkin_source: String,
+    pub issuance_source: String,
+    /// `YDDD`: last digit of the year followed by the day of year.
+    pub issue_date: String,
+    pub document_type: String,
+    pub issuer_designator: String,
+    pub bag_tags: Vec<String>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct BcbpLegConditional {
+    pub airline_numeric_code: String,
+    pub document_serial: String,
+    pub selectee: String,
+    pub document_verification: String,
+    pub marketing_carrier: String,
+    pub frequent_flyer_airline: String,
+    pub frequent_flyer_number: String,
+    pub id_ad_indicator: String,
+    pub free_baggage: String,
+    pub fast_track: String,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct BcbpSecurity {
+    pub security_type: String,
+    pub data: String,
+}
+
+// Field widths (Resolution 792, attachment C).
+pub(crate) const NAME_LEN: usize = 20;
+pub(crate) const PNR_LEN: usize = 7;
+pub(crate) const AIRPORT_LEN: usize = 3;
+pub(crate) const CARRIER_LEN: usize = 3;
+pub(crate) const FLIGHT_NUMBER_LEN: usize = 5;
+pub(crate) const JULIAN_DATE_LEN: usize = 3;
+pub(crate) const SEAT_LEN: usize = 4;
+pub(crate) const SEQUENCE_LEN: usize = 5;
+pub(crate) const BAG_TAG_LEN: usize = 13;
+
+pub(crate) const UNIQUE_FIELD_WIDTHS: [usize; 6] = [1, 1, 1, 4, 1, 3];
+pub(crate) const REPEATED_FIELD_WIDTHS: [usize; 10] = [3, 10, 1, 1, 3, 3, 16, 1, 3, 1];
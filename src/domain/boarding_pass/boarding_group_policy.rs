@@ -0,0 +1,103 @@
+use crate::domain::booking::entity::FareFamily;
+use crate::domain::checkin::entity::SeatClass;
+
+/// Decides the boarding group printed on a pass. Priority groups (cabin, fare family,
+/// frequent flyer) win when they board earlier; everyone else boards by seat zone,
+/// back to front.
+#[derive(Debug, Clone)]
+pub struct BoardingGroupPolicy {
+    pub cabin_groups: Vec<(SeatClass, u8)>,
+    pub fare_family_groups: Vec<(FareFamily, u8)>,
+    pub frequent_flyer_group: Option<u8>,
+    // Programmes honoured besides the operating carrier's own, e.g. alliance partners.
+    pub frequent_flyer_airlines: Vec<String>,
+    // First row of each zone, rearmost zone first: `[30, 20]` boards rows 30+, then 20-29,
+    // then the rest.
+    pub zone_row_starts: Vec<u32>,
+    pub first_zone_group: u8,
+}
+
+#[derive(Debug, Clone)]
+pub struct BoardingGroupInput<'a> {
+    pub seat_class: &'a SeatClass,
+    pub fare_family: FareFamily,
+    pub operating_carrier: &'a str,
+    pub ff_airline_code: Option<&'a str>,
+    pub ff_number: Option<&'a str>,
+    pub seat_no: Option<&'a str>,
+}
+
+impl Default for BoardingGroupPolicy {
+    fn default() -> Self {
+        Self {
+            cabin_groups: vec![
+                (SeatClass::First, 1),
+                (SeatClass::Business, 1),
+                (SeatClass::PremiumEconomy, 2),
+            ],
+            fare_family_groups: vec![(FareFamily::Flex, 3)],
+            frequent_flyer_group: Some(2),
+            frequent_flyer_airlines: Vec::new(),
+            zone_row_starts: vec![30, 20],
+            first_zone_group: 4,
+        }
+    }
+}
+
+impl BoardingGroupPolicy {
+    pub fn group_for(&self, input: &BoardingGroupInput<'_>) -> String {
+        let priority = [
+            self.cabin_groups
+                .iter()
+                .find(|(cabin, _)| cabin == input.seat_class)
+                .map(|(_, group)| *group),
+            self.fare_family_groups
+                .iter()
+                .find(|(fare_family, _)| *fare_family == input.fare_family)
+                .map(|(_, group)| *group),
+            self.frequent_flyer_group
+                .filter(|_| self.is_frequent_flyer(input)),
+        ]
+        .into_iter()
+        .flatten()
+        .min();
+
+        let zone = self.zone_group(input.seat_no);
+        priority.map_or(zone, |group| group.min(zone)).to_string()
+    }
+
+    fn is_frequent_flyer(&self, input: &BoardingGroupInput<'_>) -> bool {
+        let has_number = input.ff_number.is_some_and(|n| !n.trim().is_empty());
+        let airline = input.ff_airline_code.unwrap_or("").trim();
+
+        has_number
+            && !airline.is_empty()
+            && (airline.eq_ignore_ascii_case(input.operating_carrier.trim())
+                || self
+                    .frequent_flyer_airlines
+                    .iter()
+                    .any(|a| a.eq_ignore_ascii_case(airline)))
+    }
+
+    // Passengers without a numbered seat yet board with the front zone.
+    fn zone_group(&self, seat_no: Option<&str>) -> u8 {
+        let last_zone = self.zone_row_starts.len();
+        let zone = seat_no
+            .and_then(seat_row)
+            .and_then(|row| self.zone_row_starts.iter().position(|start| row >= *start))
+            .unwrap_or(last_zone);
+
+        self.first_zone_group
+            .saturating_add(u8::try_from(zone).unwrap_or(u8::MAX))
+    }
+}
+
+// `32A` -> 32; placeholder seats such as `AUTO-...` have no row.
+fn seat_row(seat_no: &str) -> Option<u32> {
+    let digits: String = seat_no
+        .trim()
+        .chars()
+        .take_while(|c| c.is_ascii_digit())
+        .collect();
+    digits.parse().ok()
+}
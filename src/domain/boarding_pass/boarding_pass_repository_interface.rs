@@ -1,5 +1,6 @@
 use crate::domain::boarding_pass::entity::BoardingPass;
 use crate::domain::error::DomainError;
+use chrono::NaiveDateTime;
 
 #[async_trait::async_trait]
 pub trait BoardingPassRepositoryInterface: Send + Sync {
@@ -20,4 +21,17 @@ pub trait BoardingPassRepositoryInterface: Send + Sync {
     async fn exists_by_checkin(&self, checkin_id: i64) -> Result<bool, DomainError>;
 
     async fn exists_by_code(&self, code: &str) -> Result<bool, DomainError>;
+
+    async fn revoke_boarding_pass(
+        &self,
+        id: i64,
+        revoked_at: NaiveDateTime,
+    ) -> Result<(), DomainError>;
+
+    /// Returns `false` when another request moved the wallet pass version first.
+    async fn update_wallet_pass(
+        &self,
+        boarding_pass: &BoardingPass,
+        expected_version: i32,
+    ) -> Result<bool, DomainError>;
 }
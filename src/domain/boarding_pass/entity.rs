@@ -38,6 +38,13 @@ pub struct BoardingPass {
     pub barcode_format: String,
     pub barcode_payload: Option<String>,
 
+    pub revoked_at: Option<NaiveDateTime>,
+
+    // Wallet passes are regenerated on request; the version moves on whenever the
+    // gate or boarding time printed on them changes.
+    pub wallet_pass_version: i32,
+    pub wallet_pass_fingerprint: Option<String>,
+
     // audit
     pub issued_at: NaiveDateTime,
 }
@@ -65,9 +72,38 @@ impl BoardingPass {
 
             barcode_format: props.barcode_format,
             barcode_payload: props.barcode_payload,
+
+            revoked_at: None,
+
+            wallet_pass_version: 1,
+            wallet_pass_fingerprint: None,
         })
     }
 
+    pub fn is_revoked(&self) -> bool {
+        self.revoked_at.is_some()
+    }
+
+    /// Boarding group and sequence number are fixed when the pass is issued.
+    pub fn assign_boarding_position(&mut self, boarding_group: String, sequence: i32) {
+        self.boarding_group = Some(boarding_group);
+        self.sequence_no = Some(format!("{sequence:04}"));
+    }
+
+    /// Records the gate/boarding time state a wallet pass is generated from and returns
+    /// `true` when it differs from the last generated one. The first generation keeps
+    /// version 1.
+    pub fn refresh_wallet_pass(&mut self, fingerprint: String) -> bool {
+        if self.wallet_pass_fingerprint.as_deref() == Some(fingerprint.as_str()) {
+            return false;
+        }
+        if self.wallet_pass_fingerprint.is_some() {
+            self.wallet_pass_version += 1;
+        }
+        self.wallet_pass_fingerprint = Some(fingerprint);
+        true
+    }
+
     pub fn update_from(
         &mut self,
         props: UpdateBoardingPassProps,
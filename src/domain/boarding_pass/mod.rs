@@ -1,5 +1,7 @@
 pub mod events;
 pub mod rules;
 pub mod entity;
+pub mod bcbp;
 pub mod boarding_pass_repository_interface;
-pub mod error;
\ No newline at end of file
+pub mod error;
+pub mod boarding_group_policy;
@@ -9,6 +9,14 @@ pub trait BookingRepositoryInterface: Send + Sync {
         booking: &Booking,
         expected_version: i32,
     ) -> Result<(), DomainError>;
+    /// Saves the cancelled booking and returns `released_seats` to its flight in one
+    /// transaction.
+    async fn cancel_booking(
+        &self,
+        booking: &Booking,
+        expected_version: i32,
+        released_seats: i32,
+    ) -> Result<(), DomainError>;
 
     async fn find_booking_by_id(&self, id: i64) -> Result<Option<Booking>, DomainError>;
     async fn find_booking_by_code(
@@ -23,5 +31,7 @@ pub trait BookingRepositoryInterface: Send + Sync {
         page_size: u64,
     ) -> Result<Vec<Booking>, DomainError>;
 
+    async fn list_bookings_by_flight(&self, flight_id: i64) -> Result<Vec<Booking>, DomainError>;
+
     async fn booking_code_exists(&self, booking_code: &str) -> Result<bool, DomainError>;
 }
@@ -16,12 +16,15 @@ pub struct CreateBookingProps {
     pub booking_code: String,
     pub user_id: i64,
     pub flight_id: i64,
+    pub marketing_airline_code: String,
+    pub marketing_flight_number: String,
 
     pub base_amount: Decimal,
     pub taxes_amount: Decimal,
     pub fees_amount: Decimal,
     pub discount_amount: Decimal,
     pub currency: String,
+    pub fare_family: FareFamily,
 
     pub contact_email: String,
     pub contact_full_name: String,
@@ -89,6 +92,9 @@ pub struct Booking {
 
     pub user_id: i64,
     pub flight_id: i64,
+    // Designator the booking was sold under: the operating one or a codeshare.
+    pub marketing_airline_code: String,
+    pub marketing_flight_number: String,
 
     pub status: BookingStatus,
     pub cancellation_reason: Option<String>,
@@ -100,6 +106,7 @@ pub struct Booking {
     pub discount_amount: Decimal,
     pub total_amount: Decimal,
     pub currency: String,
+    pub fare_family: FareFamily,
 
     // contact
     pub contact_email: String,
@@ -126,6 +133,7 @@ pub enum BookingStatus {
     Confirmed,
     Cancelled,
     Expired,
+    NoShow,
 }
 
 #[derive(Debug, Clone, PartialEq, Eq)]
@@ -142,6 +150,14 @@ pub enum PaymentMethod {
     BankTransfer,
     Wallet,
 }
+
+/// Fare brand the booking was sold under; drives boarding priority and fare rules.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum FareFamily {
+    Basic,
+    Standard,
+    Flex,
+}
 impl Booking {
     pub fn new(props: CreateBookingProps) -> Result<Self, DomainError> {
         let total = props.validate()?;
@@ -151,6 +167,8 @@ impl Booking {
             booking_code: props.booking_code,
             user_id: props.user_id,
             flight_id: props.flight_id,
+            marketing_airline_code: props.marketing_airline_code,
+            marketing_flight_number: props.marketing_flight_number,
 
             status: BookingStatus::Draft,
             cancellation_reason: None,
@@ -161,6 +179,7 @@ impl Booking {
             discount_amount: props.discount_amount,
             total_amount: total,
             currency: props.currency,
+            fare_family: props.fare_family,
 
             contact_email: props.contact_email,
             contact_phone: props.contact_phone,
@@ -212,6 +231,12 @@ impl Booking {
             }
             .into());
         }
+        if self.status == BookingStatus::NoShow {
+            return Err(BookingDomainError::InvalidOperationForStatus {
+                status: self.status,
+            }
+            .into());
+        }
 
         self.status = BookingStatus::Cancelled;
         self.cancellation_reason = Some(reason);
@@ -220,6 +245,20 @@ impl Booking {
         Ok(())
     }
 
+    // Closes a confirmed booking whose passengers all failed to board the departed flight.
+    pub fn mark_no_show(&mut self) -> Result<(), DomainError> {
+        if self.status != BookingStatus::Confirmed {
+            return Err(BookingDomainError::InvalidStatusTransition {
+                from: self.status,
+                to: BookingStatus::NoShow,
+            }
+            .into());
+        }
+
+        self.status = BookingStatus::NoShow;
+        Ok(())
+    }
+
     pub fn mark_paid(
         &mut self,
         method: PaymentMethod,
@@ -2,4 +2,7 @@ pub mod events;
 pub mod rules;
 pub mod entity;
 pub mod booking_repository_interface;
-pub mod error;
\ No newline at end of file
+pub mod error;
+pub mod no_show;
+pub mod no_show_policy;
+pub mod no_show_repository_interface;
\ No newline at end of file
@@ -0,0 +1,44 @@
+use crate::domain::booking::entity::{Booking, FareFamily};
+use chrono::NaiveDateTime;
+use rust_decimal::Decimal;
+
+/// A passenger who held a confirmed seat on a departed flight but never boarded.
+#[derive(Debug, Clone)]
+pub struct NoShow {
+    pub id: i64,
+    pub flight_id: i64,
+    pub booking_id: i64,
+    pub passenger_id: i64,
+    pub checkin_id: Option<i64>,
+
+    pub fare_family: FareFamily,
+    pub fare_amount: Decimal,
+    pub penalty_amount: Decimal,
+    pub currency: String,
+
+    pub recorded_at: NaiveDateTime,
+}
+
+impl NoShow {
+    pub fn record(
+        booking: &Booking,
+        passenger_id: i64,
+        checkin_id: Option<i64>,
+        fare_amount: Decimal,
+        penalty_amount: Decimal,
+        now: NaiveDateTime,
+    ) -> Self {
+        Self {
+            id: 0,
+            flight_id: booking.flight_id,
+            booking_id: booking.id,
+            passenger_id,
+            checkin_id,
+            fare_family: booking.fare_family,
+            fare_amount,
+            penalty_amount,
+            currency: booking.currency.clone(),
+            recorded_at: now,
+        }
+    }
+}
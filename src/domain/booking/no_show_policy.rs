@@ -0,0 +1,41 @@
+use crate::domain::booking::entity::{Booking, FareFamily};
+use rust_decimal::Decimal;
+
+/// Fare rule for passengers who do not turn up: the share of their fare that is forfeited,
+/// by fare family. Families without a rule forfeit nothing.
+#[derive(Debug, Clone)]
+pub struct NoShowPenaltyPolicy {
+    pub penalty_percent: Vec<(FareFamily, u8)>,
+}
+
+impl Default for NoShowPenaltyPolicy {
+    fn default() -> Self {
+        Self {
+            penalty_percent: vec![
+                (FareFamily::Basic, 100),
+                (FareFamily::Standard, 50),
+                (FareFamily::Flex, 0),
+            ],
+        }
+    }
+}
+
+impl NoShowPenaltyPolicy {
+    // Only the base fare is split across passengers; unused taxes stay refundable.
+    pub fn fare_share(booking: &Booking, passenger_count: usize) -> Decimal {
+        if passenger_count == 0 {
+            return Decimal::ZERO;
+        }
+        (booking.base_amount / Decimal::from(passenger_count)).round_dp(2)
+    }
+
+    pub fn penalty_for(&self, fare_family: FareFamily, fare_amount: Decimal) -> Decimal {
+        let percent = self
+            .penalty_percent
+            .iter()
+            .find(|(family, _)| *family == fare_family)
+            .map_or(0, |(_, percent)| (*percent).min(100));
+
+        (fare_amount * Decimal::from(percent) / Decimal::from(100)).round_dp(2)
+    }
+}
@@ -0,0 +1,9 @@
+use crate::domain::booking::no_show::NoShow;
+use crate::domain::error::DomainError;
+
+#[async_trait::async_trait]
+pub trait NoShowRepositoryInterface: Send + Sync {
+    async fn create_no_show(&self, no_show: &NoShow) -> Result<i64, DomainError>;
+
+    async fn list_no_shows_by_flight(&self, flight_id: i64) -> Result<Vec<NoShow>, DomainError>;
+}
@@ -0,0 +1,226 @@
+use crate::domain::booking::entity::FareFamily;
+use crate::domain::checkin::entity::SeatClass;
+use crate::domain::checkin::error::CheckinDomainError;
+use crate::domain::error::DomainError;
+use rust_decimal::Decimal;
+
+// International avoirdupois pound.
+const KG_PER_LB: Decimal = Decimal::from_parts(45359237, 0, 0, false, 8);
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum WeightUnit {
+    Kg,
+    Lb,
+}
+
+impl WeightUnit {
+    pub fn parse(value: &str) -> Result<Self, DomainError> {
+        match value.trim().to_uppercase().as_str() {
+            "KG" => Ok(WeightUnit::Kg),
+            "LB" => Ok(WeightUnit::Lb),
+            _ => Err(CheckinDomainError::Validation {
+                field: "baggage_weight_unit",
+                message: format!("Invalid baggage_weight_unit: {value}"),
+            }
+            .into()),
+        }
+    }
+
+    pub fn code(&self) -> &'static str {
+        match self {
+            WeightUnit::Kg => "KG",
+            WeightUnit::Lb => "LB",
+        }
+    }
+
+    pub fn to_kg(&self, weight: Decimal) -> Decimal {
+        match self {
+            WeightUnit::Kg => weight,
+            WeightUnit::Lb => weight * KG_PER_LB,
+        }
+    }
+}
+
+/// How much checked baggage a passenger travels with for free.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum AllowanceConcept {
+    /// A number of bags, each up to a weight limit.
+    Piece {
+        pieces: u32,
+        max_piece_weight_kg: u32,
+    },
+    /// A combined weight, any number of bags.
+    Weight { total_weight_kg: u32 },
+}
+
+impl AllowanceConcept {
+    /// Short code shown to the passenger, e.g. `2PC` or `30KG`.
+    pub fn code(&self) -> String {
+        match self {
+            AllowanceConcept::Piece { pieces, .. } => format!("{pieces}PC"),
+            AllowanceConcept::Weight { total_weight_kg } => format!("{total_weight_kg}KG"),
+        }
+    }
+}
+
+/// Allowance for a cabin; a rule without a fare family covers every family in that cabin.
+#[derive(Debug, Clone)]
+pub struct BaggageAllowance {
+    pub seat_class: SeatClass,
+    pub fare_family: Option<FareFamily>,
+    pub concept: AllowanceConcept,
+}
+
+#[derive(Debug, Clone)]
+pub struct BaggageAssessment {
+    pub allowance: AllowanceConcept,
+    pub excess_pieces: i32,
+    pub excess_weight_kg: Decimal,
+    pub excess_fee: Decimal,
+    pub currency: String,
+}
+
+/// Free baggage allowance by cabin and fare family, and the price of anything above it.
+#[derive(Debug, Clone)]
+pub struct BaggageAllowancePolicy {
+    pub allowances: Vec<BaggageAllowance>,
+    pub excess_piece_fee: Decimal,
+    // Charged per started kilogram.
+    pub excess_weight_fee_per_kg: Decimal,
+    pub currency: String,
+}
+
+impl Default for BaggageAllowancePolicy {
+    fn default() -> Self {
+        let piece = |pieces, max_piece_weight_kg| AllowanceConcept::Piece {
+            pieces,
+            max_piece_weight_kg,
+        };
+
+        Self {
+            allowances: vec![
+                BaggageAllowance {
+                    seat_class: SeatClass::Economy,
+                    fare_family: Some(FareFamily::Basic),
+                    concept: piece(0, 23),
+                },
+                BaggageAllowance {
+                    seat_class: SeatClass::Economy,
+                    fare_family: Some(FareFamily::Standard),
+                    concept: piece(1, 23),
+                },
+                BaggageAllowance {
+                    seat_class: SeatClass::Economy,
+                    fare_family: Some(FareFamily::Flex),
+                    concept: piece(2, 23),
+                },
+                BaggageAllowance {
+                    seat_class: SeatClass::PremiumEconomy,
+                    fare_family: None,
+                    concept: piece(2, 23),
+                },
+                BaggageAllowance {
+                    seat_class: SeatClass::Business,
+                    fare_family: None,
+                    concept: AllowanceConcept::Weight {
+                        total_weight_kg: 40,
+                    },
+                },
+                BaggageAllowance {
+                    seat_class: SeatClass::First,
+                    fare_family: None,
+                    concept: AllowanceConcept::Weight {
+                        total_weight_kg: 50,
+                    },
+                },
+            ],
+            excess_piece_fee: Decimal::new(75, 0),
+            excess_weight_fee_per_kg: Decimal::new(15, 0),
+            currency: "USD".to_string(),
+        }
+    }
+}
+
+impl BaggageAllowancePolicy {
+    pub fn allowance_for(
+        &self,
+        seat_class: &SeatClass,
+        fare_family: FareFamily,
+    ) -> Option<&AllowanceConcept> {
+        let for_cabin = || {
+            self.allowances
+                .iter()
+                .filter(|a| &a.seat_class == seat_class)
+        };
+
+        for_cabin()
+            .find(|a| a.fare_family == Some(fare_family))
+            .or_else(|| for_cabin().find(|a| a.fare_family.is_none()))
+            .map(|a| &a.concept)
+    }
+
+    pub fn assess(
+        &self,
+        seat_class: &SeatClass,
+        fare_family: FareFamily,
+        baggage_count: i32,
+        baggage_weight: Decimal,
+        piece_weights: &[Decimal],
+        unit: WeightUnit,
+    ) -> Result<BaggageAssessment, DomainError> {
+        let allowance = self
+            .allowance_for(seat_class, fare_family)
+            .ok_or_else(|| CheckinDomainError::BusinessRule {
+                message: format!(
+                    "No baggage allowance configured for {seat_class:?} / {fare_family:?}"
+                ),
+            })?
+            .clone();
+
+        let weight_kg = unit.to_kg(baggage_weight);
+        let (excess_pieces, excess_weight_kg) = match &allowance {
+            AllowanceConcept::Piece {
+                pieces,
+                max_piece_weight_kg,
+            } => {
+                let excess_pieces = (baggage_count - *pieces as i32).max(0);
+                // The limit applies to every bag checked, paid-for pieces included. A single
+                // bag weighs the declared total; several need their own weights.
+                let piece_weights = match (baggage_count, piece_weights.is_empty()) {
+                    (0, _) => Vec::new(),
+                    (1, true) => vec![baggage_weight],
+                    (_, false) => piece_weights.to_vec(),
+                    (_, true) => {
+                        return Err(CheckinDomainError::Validation {
+                            field: "baggage_piece_weights",
+                            message: "Declare the weight of each bag for a piece allowance"
+                                .to_string(),
+                        }
+                        .into());
+                    }
+                };
+                let limit = Decimal::from(*max_piece_weight_kg);
+                let overweight: Decimal = piece_weights
+                    .iter()
+                    .map(|w| (unit.to_kg(*w) - limit).max(Decimal::ZERO))
+                    .sum();
+                (excess_pieces, overweight)
+            }
+            AllowanceConcept::Weight { total_weight_kg } => (
+                0,
+                (weight_kg - Decimal::from(*total_weight_kg)).max(Decimal::ZERO),
+            ),
+        };
+
+        let excess_fee = self.excess_piece_fee * Decimal::from(excess_pieces)
+            + self.excess_weight_fee_per_kg * excess_weight_kg.ceil();
+
+        Ok(BaggageAssessment {
+            allowance,
+            excess_pieces,
+            excess_weight_kg: excess_weight_kg.round_dp(2),
+            excess_fee: excess_fee.round_dp(2),
+            currency: self.currency.clone(),
+        })
+    }
+}
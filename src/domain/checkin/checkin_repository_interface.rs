@@ -1,3 +1,4 @@
+use crate::domain::boarding_pass::entity::BoardingPass;
 use crate::domain::checkin::entity::{Checkin, CheckinStatus};
 use crate::domain::error::DomainError;
 
@@ -9,6 +10,13 @@ pub trait CheckinRepositoryInterface: Send + Sync {
         checkin: &Checkin,
         expected_version: i32,
     ) -> Result<(), DomainError>;
+    /// Saves the checked-in record and issues its boarding pass in the same transaction.
+    async fn complete_checkin(
+        &self,
+        checkin: &Checkin,
+        expected_version: i32,
+        boarding_pass: &BoardingPass,
+    ) -> Result<(), DomainError>;
 
     async fn find_checkin_by_id(&self, id: i64) -> Result<Option<Checkin>, DomainError>;
 
@@ -18,6 +26,8 @@ pub trait CheckinRepositoryInterface: Send + Sync {
         passenger_id: i64,
     ) -> Result<Option<Checkin>, DomainError>;
 
+    async fn list_checkins_by_booking(&self, booking_id: i64) -> Result<Vec<Checkin>, DomainError>;
+
     async fn update_checkin_status(
         &self,
         checkin_id: i64,
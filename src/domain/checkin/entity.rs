@@ -1,9 +1,12 @@
 use crate::domain::business_rule_interface::BusinessRuleInterface;
+use crate::domain::checkin::baggage_allowance_policy::BaggageAssessment;
 use crate::domain::checkin::error::CheckinDomainError;
 use crate::domain::checkin::rules::baggage_must_be_valid::BaggageMustBeValid;
 use crate::domain::checkin::rules::checkin_must_be_pending::CheckinMustBePending;
+use crate::domain::checkin::rules::excess_baggage_must_be_paid::ExcessBaggageMustBePaid;
 use crate::domain::error::DomainError;
 use chrono::NaiveDateTime;
+use rust_decimal::Decimal;
 
 #[derive(Debug, Clone)]
 pub struct CreateCheckinProps {
@@ -15,6 +18,7 @@ pub struct CreateCheckinProps {
     pub baggage_count: i32,
     pub baggage_weight_total: f64,
     pub baggage_weight_unit: String,
+    pub baggage_piece_weights: Vec<f64>,
 
     pub checkin_channel: CheckinChannel,
     pub checked_in_ip: Option<String>,
@@ -25,6 +29,7 @@ pub struct UpdateCheckinProps {
     pub seat_no: Option<String>,
     pub baggage_count: Option<i32>,
     pub baggage_weight_total: Option<f64>,
+    pub baggage_piece_weights: Option<Vec<f64>>,
 }
 
 impl CreateCheckinProps {
@@ -32,6 +37,7 @@ impl CreateCheckinProps {
         BaggageMustBeValid {
             count: self.baggage_count,
             weight: self.baggage_weight_total,
+            piece_weights: &self.baggage_piece_weights,
         }
         .check_broken()?;
 
@@ -51,12 +57,24 @@ pub struct Checkin {
     pub baggage_count: i32,
     pub baggage_weight_total: f64,
     pub baggage_weight_unit: String,
+    // Weight of each bag in `baggage_weight_unit`; empty when only the total was declared.
+    pub baggage_piece_weights: Vec<f64>,
+
+    // excess baggage, priced against the cabin and fare family allowance
+    pub baggage_allowance: Option<String>,
+    pub excess_baggage_pieces: i32,
+    pub excess_baggage_weight_kg: Decimal,
+    pub excess_baggage_fee: Decimal,
+    pub excess_baggage_currency: Option<String>,
+    pub excess_baggage_paid_at: Option<NaiveDateTime>,
+    pub excess_baggage_payment_txn_id: Option<String>,
 
     pub checkin_channel: CheckinChannel,
     pub checked_in_ip: Option<String>,
 
     //audit
     pub checked_in_at: Option<NaiveDateTime>,
+    pub boarded_at: Option<NaiveDateTime>,
 
     // for Optimistic locking
     pub version: i32,
@@ -66,7 +84,11 @@ pub struct Checkin {
 pub enum CheckinStatus {
     Pending,
     CheckedIn,
+    Boarded,
+    Offloaded,
     Cancelled,
+    // at the gate without a seat, waiting to be cleared
+    Standby,
 }
 
 #[derive(Debug, Clone, PartialEq, Eq)]
@@ -101,8 +123,18 @@ impl Checkin {
             baggage_count: props.baggage_count,
             baggage_weight_total: props.baggage_weight_total,
             baggage_weight_unit: props.baggage_weight_unit,
+            baggage_piece_weights: props.baggage_piece_weights,
+
+            baggage_allowance: None,
+            excess_baggage_pieces: 0,
+            excess_baggage_weight_kg: Decimal::ZERO,
+            excess_baggage_fee: Decimal::ZERO,
+            excess_baggage_currency: None,
+            excess_baggage_paid_at: None,
+            excess_baggage_payment_txn_id: None,
 
             checked_in_at: None,
+            boarded_at: None,
             checkin_channel: props.checkin_channel,
             checked_in_ip: props.checked_in_ip,
             version: 1,
@@ -113,6 +145,12 @@ impl Checkin {
         if self.status != CheckinStatus::Pending {
             return Err(CheckinDomainError::AlreadyCheckedIn.into());
         }
+        ExcessBaggageMustBePaid {
+            fee: self.excess_baggage_fee,
+            currency: self.excess_baggage_currency.as_deref(),
+            paid_at: self.excess_baggage_paid_at,
+        }
+        .check_broken()?;
 
         self.seat_no = Some(seat_no);
         self.status = CheckinStatus::CheckedIn;
@@ -120,6 +158,144 @@ impl Checkin {
         Ok(())
     }
 
+    /// Records the allowance and excess charge for the declared baggage; replaces any earlier
+    /// assessment while the check-in is still pending.
+    pub fn apply_baggage_assessment(
+        &mut self,
+        assessment: BaggageAssessment,
+    ) -> Result<(), DomainError> {
+        CheckinMustBePending {
+            status: self.status.clone(),
+        }
+        .check_broken()?;
+        if self.excess_baggage_paid_at.is_some() {
+            return Err(CheckinDomainError::BusinessRule {
+                message: "Excess baggage already paid".to_string(),
+            }
+            .into());
+        }
+
+        self.baggage_allowance = Some(assessment.allowance.code());
+        self.excess_baggage_pieces = assessment.excess_pieces;
+        self.excess_baggage_weight_kg = assessment.excess_weight_kg;
+        self.excess_baggage_fee = assessment.excess_fee;
+        self.excess_baggage_currency = Some(assessment.currency);
+        Ok(())
+    }
+
+    pub fn has_unpaid_excess_baggage(&self) -> bool {
+        self.excess_baggage_fee > Decimal::ZERO && self.excess_baggage_paid_at.is_none()
+    }
+
+    pub fn pay_excess_baggage(
+        &mut self,
+        txn_id: String,
+        now: NaiveDateTime,
+    ) -> Result<(), DomainError> {
+        CheckinMustBePending {
+            status: self.status.clone(),
+        }
+        .check_broken()?;
+        if !self.has_unpaid_excess_baggage() {
+            return Err(CheckinDomainError::BusinessRule {
+                message: "No excess baggage fee is due".to_string(),
+            }
+            .into());
+        }
+
+        self.excess_baggage_paid_at = Some(now);
+        self.excess_baggage_payment_txn_id = Some(txn_id);
+        Ok(())
+    }
+
+    pub fn board(&mut self, now: NaiveDateTime) -> Result<(), DomainError> {
+        match self.status {
+            CheckinStatus::CheckedIn => {}
+            CheckinStatus::Boarded => return Err(CheckinDomainError::AlreadyBoarded.into()),
+            _ => {
+                return Err(CheckinDomainError::InvalidStatusTransition {
+                    from: self.status.clone(),
+                    to: CheckinStatus::Boarded,
+                }
+                .into());
+            }
+        }
+
+        self.status = CheckinStatus::Boarded;
+        self.boarded_at = Some(now);
+        Ok(())
+    }
+
+    /// Lists a passenger who reached the gate without a seat on the flight's standby list.
+    pub fn list_on_standby(&mut self, now: NaiveDateTime) -> Result<(), DomainError> {
+        if self.status != CheckinStatus::Pending {
+            return Err(CheckinDomainError::InvalidStatusTransition {
+                from: self.status.clone(),
+                to: CheckinStatus::Standby,
+            }
+            .into());
+        }
+        ExcessBaggageMustBePaid {
+            fee: self.excess_baggage_fee,
+            currency: self.excess_baggage_currency.as_deref(),
+            paid_at: self.excess_baggage_paid_at,
+        }
+        .check_broken()?;
+
+        self.seat_no = None;
+        self.status = CheckinStatus::Standby;
+        self.checked_in_at = Some(now);
+        Ok(())
+    }
+
+    /// Clears a standby passenger into `seat_no`; from then on they board like anyone checked in.
+    pub fn clear_standby(&mut self, seat_no: String) -> Result<(), DomainError> {
+        if self.status != CheckinStatus::Standby {
+            return Err(CheckinDomainError::InvalidStatusTransition {
+                from: self.status.clone(),
+                to: CheckinStatus::CheckedIn,
+            }
+            .into());
+        }
+
+        self.seat_no = Some(seat_no);
+        self.status = CheckinStatus::CheckedIn;
+        Ok(())
+    }
+
+    /// Reverts a boarding scan made in error; the passenger is back to checked in.
+    pub fn undo_boarding(&mut self) -> Result<(), DomainError> {
+        if self.status != CheckinStatus::Boarded {
+            return Err(CheckinDomainError::InvalidStatusTransition {
+                from: self.status.clone(),
+                to: CheckinStatus::CheckedIn,
+            }
+            .into());
+        }
+
+        self.status = CheckinStatus::CheckedIn;
+        self.boarded_at = None;
+        Ok(())
+    }
+
+    /// Removes a checked-in, boarded or standby passenger from the flight at the gate.
+    pub fn offload(&mut self) -> Result<(), DomainError> {
+        if !matches!(
+            self.status,
+            CheckinStatus::CheckedIn | CheckinStatus::Boarded | CheckinStatus::Standby
+        ) {
+            return Err(CheckinDomainError::InvalidStatusTransition {
+                from: self.status.clone(),
+                to: CheckinStatus::Offloaded,
+            }
+            .into());
+        }
+
+        self.status = CheckinStatus::Offloaded;
+        self.boarded_at = None;
+        Ok(())
+    }
+
     pub fn update_from(&mut self, props: UpdateCheckinProps) -> Result<(), DomainError> {
         CheckinMustBePending {
             status: self.status.clone(),
@@ -129,12 +305,25 @@ impl Checkin {
         if let Some(seat) = props.seat_no {
             self.seat_no = Some(seat);
         }
+        // Bag weights declared for the old bags no longer apply once count or total change.
+        if props.baggage_count.is_some() || props.baggage_weight_total.is_some() {
+            self.baggage_piece_weights.clear();
+        }
         if let Some(count) = props.baggage_count {
             self.baggage_count = count;
         }
         if let Some(weight) = props.baggage_weight_total {
             self.baggage_weight_total = weight;
         }
+        if let Some(piece_weights) = props.baggage_piece_weights {
+            self.baggage_piece_weights = piece_weights;
+        }
+        BaggageMustBeValid {
+            count: self.baggage_count,
+            weight: self.baggage_weight_total,
+            piece_weights: &self.baggage_piece_weights,
+        }
+        .check_broken()?;
 
         Ok(())
     }
@@ -1,4 +1,5 @@
 use crate::domain::checkin::entity::{CheckinStatus, SeatClass};
+use rust_decimal::Decimal;
 use thiserror::Error;
 
 #[derive(Debug, Error)]
@@ -37,6 +38,10 @@ pub enum CheckinDomainError {
     #[error("check-in cancelled")]
     CheckinCancelled,
 
+    // ===== Boarding =====
+    #[error("passenger already boarded")]
+    AlreadyBoarded,
+
     // ===== Seat =====
     #[error("seat already assigned")]
     SeatAlreadyAssigned,
@@ -54,6 +59,9 @@ pub enum CheckinDomainError {
     #[error("invalid baggage weight: {weight}")]
     InvalidBaggageWeight { weight: f64 },
 
+    #[error("excess baggage fee unpaid: {fee} {currency}")]
+    ExcessBaggageFeeUnpaid { fee: Decimal, currency: String },
+
     // ===== Timing =====
     #[error("check-in not open yet")]
     CheckinNotOpenYet,
@@ -2,4 +2,5 @@ pub mod events;
 pub mod rules;
 pub mod entity;
 pub mod checkin_repository_interface;
-pub mod error;
\ No newline at end of file
+pub mod error;
+pub mod baggage_allowance_policy;
\ No newline at end of file
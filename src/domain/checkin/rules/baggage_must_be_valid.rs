@@ -2,12 +2,14 @@ use crate::domain::business_rule_interface::BusinessRuleInterface;
 use crate::domain::checkin::error::CheckinDomainError;
 use crate::domain::error::DomainError;
 
-pub struct BaggageMustBeValid {
+pub struct BaggageMustBeValid<'a> {
     pub count: i32,
     pub weight: f64,
+    // Per-bag weights, when declared; they must add up to `weight`.
+    pub piece_weights: &'a [f64],
 }
 
-impl BusinessRuleInterface for BaggageMustBeValid {
+impl BusinessRuleInterface for BaggageMustBeValid<'_> {
     fn check_broken(&self) -> Result<(), DomainError> {
         if self.count < 0 || self.weight < 0.0 {
             return Err(CheckinDomainError::Validation {
@@ -16,6 +18,45 @@ impl BusinessRuleInterface for BaggageMustBeValid {
             }
             .into());
         }
+        if self.count == 0 && self.weight > 0.0 {
+            return Err(CheckinDomainError::Validation {
+                field: "baggage",
+                message: "Baggage weight declared without any bags".to_string(),
+            }
+            .into());
+        }
+        if self.piece_weights.is_empty() {
+            return Ok(());
+        }
+        if self.piece_weights.len() != self.count as usize {
+            return Err(CheckinDomainError::Validation {
+                field: "baggage_piece_weights",
+                message: format!(
+                    "{} bag weights declared for {} bags",
+                    self.piece_weights.len(),
+                    self.count
+                ),
+            }
+            .into());
+        }
+        if self.piece_weights.iter().any(|w| *w <= 0.0) {
+            return Err(CheckinDomainError::Validation {
+                field: "baggage_piece_weights",
+                message: "Each bag weight must be positive".to_string(),
+            }
+            .into());
+        }
+        let sum: f64 = self.piece_weights.iter().sum();
+        if (sum - self.weight).abs() > 0.01 {
+            return Err(CheckinDomainError::Validation {
+                field: "baggage_piece_weights",
+                message: format!(
+                    "Bag weights add up to {sum} but the declared total is {}",
+                    self.weight
+                ),
+            }
+            .into());
+        }
         Ok(())
     }
 }
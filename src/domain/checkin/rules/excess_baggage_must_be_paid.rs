@@ -0,0 +1,24 @@
+use crate::domain::business_rule_interface::BusinessRuleInterface;
+use crate::domain::checkin::error::CheckinDomainError;
+use crate::domain::error::DomainError;
+use chrono::NaiveDateTime;
+use rust_decimal::Decimal;
+
+pub struct ExcessBaggageMustBePaid<'a> {
+    pub fee: Decimal,
+    pub currency: Option<&'a str>,
+    pub paid_at: Option<NaiveDateTime>,
+}
+
+impl BusinessRuleInterface for ExcessBaggageMustBePaid<'_> {
+    fn check_broken(&self) -> Result<(), DomainError> {
+        if self.fee > Decimal::ZERO && self.paid_at.is_none() {
+            return Err(CheckinDomainError::ExcessBaggageFeeUnpaid {
+                fee: self.fee,
+                currency: self.currency.unwrap_or_default().to_string(),
+            }
+            .into());
+        }
+        Ok(())
+    }
+}
@@ -1,2 +1,3 @@
 pub mod checkin_must_be_pending;
-pub mod baggage_must_be_valid;
\ No newline at end of file
+pub mod baggage_must_be_valid;
+pub mod excess_baggage_must_be_paid;
\ No newline at end of file
@@ -0,0 +1,26 @@
+use crate::domain::compensation::entity::{CompensationClaim, CompensationClaimStatus};
+use crate::domain::error::DomainError;
+
+#[async_trait::async_trait]
+pub trait CompensationClaimRepositoryInterface: Send + Sync {
+    async fn create_claim(&self, claim: &CompensationClaim) -> Result<i64, DomainError>;
+
+    async fn update_claim(
+        &self,
+        claim: &CompensationClaim,
+        expected_version: i32,
+    ) -> Result<(), DomainError>;
+
+    async fn find_claim_by_id(&self, id: i64) -> Result<Option<CompensationClaim>, DomainError>;
+
+    async fn find_claim_by_booking(
+        &self,
+        booking_id: i64,
+    ) -> Result<Option<CompensationClaim>, DomainError>;
+
+    /// Oldest first, so the review queue is worked in order.
+    async fn list_claims(
+        &self,
+        status: Option<CompensationClaimStatus>,
+    ) -> Result<Vec<CompensationClaim>, DomainError>;
+}
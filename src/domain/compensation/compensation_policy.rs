@@ -0,0 +1,237 @@
+use crate::domain::flight::entity::FlightStatus;
+use chrono::NaiveDateTime;
+use rust_decimal::Decimal;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CompensationReason {
+    Delay,
+    Cancellation,
+}
+
+/// Why a disrupted (or undisrupted) flight earns nothing.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Ineligibility {
+    /// Neither departs from the covered region nor flies into it on a carrier based there.
+    NotCovered,
+    /// Not cancelled and not yet arrived, so the delay at arrival is unknown.
+    NotArrived,
+    BelowDelayThreshold,
+    CancellationNoticeGiven,
+    ExtraordinaryCircumstances,
+}
+
+impl Ineligibility {
+    pub fn describe(&self) -> &'static str {
+        match self {
+            Ineligibility::NotCovered => "flight is not covered by the compensation rules",
+            Ineligibility::NotArrived => "flight has not arrived yet",
+            Ineligibility::BelowDelayThreshold => "delay at arrival is below the threshold",
+            Ineligibility::CancellationNoticeGiven => "cancellation was announced in time",
+            Ineligibility::ExtraordinaryCircumstances => {
+                "disruption was caused by extraordinary circumstances"
+            }
+        }
+    }
+}
+
+/// Fixed amount per passenger for flights up to `max_distance_km` (open-ended when `None`).
+#[derive(Debug, Clone)]
+pub struct CompensationBand {
+    pub max_distance_km: Option<i32>,
+    pub amount: Decimal,
+    pub reduced_below_delay_minutes: Option<i64>,
+}
+
+/// What is known about a flight when a passenger asks for compensation.
+#[derive(Debug, Clone)]
+pub struct DisruptedFlight {
+    pub status: FlightStatus,
+    pub distance_km: i32,
+    pub origin_country: String,
+    pub destination_country: String,
+    /// Home country of the operating carrier.
+    pub carrier_country: String,
+    pub scheduled_departure: NaiveDateTime,
+    pub scheduled_arrival: NaiveDateTime,
+    /// On-block, or landing when on-block was not reported; estimates do not count.
+    pub actual_arrival: Option<NaiveDateTime>,
+    pub cancelled_at: Option<NaiveDateTime>,
+    pub delay_codes: Vec<String>,
+}
+
+#[derive(Debug, Clone)]
+pub struct CompensationAssessment {
+    pub reason: Option<CompensationReason>,
+    pub ineligibility: Option<Ineligibility>,
+    pub distance_km: i32,
+    pub arrival_delay_minutes: Option<i64>,
+    /// Bounds of the distance band the flight falls in, whether or not it is eligible.
+    pub band_from_km: i32,
+    pub band_to_km: Option<i32>,
+    pub reduced: bool,
+    /// Zero unless eligible.
+    pub amount_per_passenger: Decimal,
+    pub currency: String,
+}
+
+impl CompensationAssessment {
+    pub fn is_eligible(&self) -> bool {
+        self.ineligibility.is_none()
+    }
+}
+
+/// EU member states plus the EEA and Switzerland, where EU261 applies.
+pub const EU261_COVERED_COUNTRIES: &[&str] = &[
+    "AT", "BE", "BG", "HR", "CY", "CZ", "DK", "EE", "FI", "FR", "DE", "GR", "HU", "IE", "IT", "LV",
+    "LT", "LU", "MT", "NL", "PL", "PT", "RO", "SK", "SI", "ES", "SE", "IS", "LI", "NO", "CH",
+];
+
+/// IATA delay codes for weather, air traffic control and security: extraordinary
+/// circumstances that release the carrier.
+pub const EXTRAORDINARY_DELAY_CODES: &[&str] = &[
+    "71", "72", "73", "75", "76", "77", "81", "82", "83", "84", "85", "86", "87", "88", "89", "97",
+];
+
+/// Delay and cancellation compensation rules (EU261-style): fixed amounts by distance band,
+/// owed for delays at arrival above a threshold and for late-notice cancellations, unless
+/// caused by extraordinary circumstances.
+#[derive(Debug, Clone)]
+pub struct CompensationPolicy {
+    pub currency: String,
+    pub covered_countries: Vec<String>,
+    pub delay_threshold_minutes: i64,
+    pub cancellation_notice_days: i64,
+    pub extraordinary_delay_codes: Vec<String>,
+    /// Ascending by distance; the last band is open-ended.
+    pub bands: Vec<CompensationBand>,
+    pub intra_region_distance_cap_km: Option<i32>,
+    pub reduced_percent: u8,
+}
+
+impl Default for CompensationPolicy {
+    fn default() -> Self {
+        let band = |max_distance_km, amount: i64, reduced_below_delay_minutes| CompensationBand {
+            max_distance_km,
+            amount: Decimal::from(amount),
+            reduced_below_delay_minutes,
+        };
+
+        Self {
+            currency: "EUR".to_string(),
+            covered_countries: EU261_COVERED_COUNTRIES
+                .iter()
+                .map(|c| c.to_string())
+                .collect(),
+            delay_threshold_minutes: 180,
+            cancellation_notice_days: 14,
+            extraordinary_delay_codes: EXTRAORDINARY_DELAY_CODES
+                .iter()
+                .map(|c| c.to_string())
+                .collect(),
+            bands: vec![
+                band(Some(1500), 250, None),
+                band(Some(3500), 400, None),
+                band(None, 600, Some(240)),
+            ],
+            intra_region_distance_cap_km: Some(3500),
+            reduced_percent: 50,
+        }
+    }
+}
+
+impl CompensationPolicy {
+    fn is_covered_country(&self, country_code: &str) -> bool {
+        self.covered_countries
+            .iter()
+            .any(|c| c.eq_ignore_ascii_case(country_code))
+    }
+
+    pub fn assess(&self, flight: &DisruptedFlight) -> CompensationAssessment {
+        let departs_covered = self.is_covered_country(&flight.origin_country);
+        let arrives_covered = self.is_covered_country(&flight.destination_country);
+        let covered = departs_covered
+            || (arrives_covered && self.is_covered_country(&flight.carrier_country));
+
+        // Within the region long flights are paid as medium-haul.
+        let band_distance = match self.intra_region_distance_cap_km {
+            Some(cap) if departs_covered && arrives_covered => flight.distance_km.min(cap),
+            _ => flight.distance_km,
+        };
+        let (band_from_km, band) = self.band_for(band_distance);
+
+        let arrival_delay_minutes = flight
+            .actual_arrival
+            .map(|arrival| (arrival - flight.scheduled_arrival).num_minutes());
+        let reason = match flight.status {
+            FlightStatus::Cancelled => Some(CompensationReason::Cancellation),
+            _ if arrival_delay_minutes.is_some_and(|m| m > 0) => Some(CompensationReason::Delay),
+            _ => None,
+        };
+
+        let extraordinary = flight
+            .delay_codes
+            .iter()
+            .any(|code| self.extraordinary_delay_codes.contains(code));
+        let ineligibility = if !covered {
+            Some(Ineligibility::NotCovered)
+        } else {
+            match reason {
+                Some(CompensationReason::Cancellation) => {
+                    let notice_given = flight.cancelled_at.is_some_and(|at| {
+                        (flight.scheduled_departure - at).num_days()
+                            >= self.cancellation_notice_days
+                    });
+                    notice_given.then_some(Ineligibility::CancellationNoticeGiven)
+                }
+                Some(CompensationReason::Delay) => arrival_delay_minutes
+                    .filter(|m| *m < self.delay_threshold_minutes)
+                    .map(|_| Ineligibility::BelowDelayThreshold),
+                None if arrival_delay_minutes.is_some() => Some(Ineligibility::BelowDelayThreshold),
+                None => Some(Ineligibility::NotArrived),
+            }
+        };
+        let ineligibility =
+            ineligibility.or(extraordinary.then_some(Ineligibility::ExtraordinaryCircumstances));
+
+        let reduced = reason == Some(CompensationReason::Delay)
+            && band
+                .and_then(|b| b.reduced_below_delay_minutes)
+                .zip(arrival_delay_minutes)
+                .is_some_and(|(limit, delay)| delay < limit);
+
+        let amount_per_passenger = match band {
+            Some(band) if ineligibility.is_none() => {
+                let percent = if reduced {
+                    Decimal::from(self.reduced_percent.min(100))
+                } else {
+                    Decimal::from(100)
+                };
+                (band.amount * percent / Decimal::from(100)).round_dp(2)
+            }
+            _ => Decimal::ZERO,
+        };
+
+        CompensationAssessment {
+            reason,
+            ineligibility,
+            distance_km: flight.distance_km,
+            arrival_delay_minutes,
+            band_from_km,
+            band_to_km: band.and_then(|b| b.max_distance_km),
+            reduced,
+            amount_per_passenger,
+            currency: self.currency.clone(),
+        }
+    }
+
+    fn band_for(&self, distance_km: i32) -> (i32, Option<&CompensationBand>) {
+        let mut from_km = 0;
+        for band in &self.bands {
+            match band.max_distance_km {
+                Some(max) if distance_km > max => from_km = max,
+                _ => return (from_km, Some(band)),
+            }
+        }
+        (from_km, None)
+    }
+}
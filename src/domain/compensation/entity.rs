@@ -0,0 +1,155 @@
+use crate::domain::compensation::compensation_policy::{
+    CompensationAssessment, CompensationReason,
+};
+use crate::domain::compensation::error::CompensationDomainError;
+use crate::domain::error::DomainError;
+use chrono::NaiveDateTime;
+use rust_decimal::Decimal;
+
+#[derive(Debug, Clone)]
+pub struct SubmitCompensationClaimProps {
+    pub booking_id: i64,
+    pub booking_code: String,
+    pub flight_id: i64,
+    pub user_id: i64,
+    pub passenger_count: i32,
+    pub customer_note: Option<String>,
+}
+
+/// A claim for delay or cancellation compensation on one booking, paid per passenger.
+#[derive(Debug, Clone)]
+pub struct CompensationClaim {
+    pub id: i64,
+    pub booking_id: i64,
+    pub flight_id: i64,
+    // the booking owner, even when staff files the claim for them
+    pub user_id: i64,
+
+    pub reason: CompensationReason,
+    pub status: CompensationClaimStatus,
+
+    pub distance_km: i32,
+    pub arrival_delay_minutes: Option<i32>,
+    pub passenger_count: i32,
+    pub amount_per_passenger: Decimal,
+    pub total_amount: Decimal,
+    pub currency: String,
+
+    pub customer_note: Option<String>,
+    pub decision_note: Option<String>,
+    pub decided_by: Option<i64>,
+
+    pub submitted_at: NaiveDateTime,
+    pub decided_at: Option<NaiveDateTime>,
+
+    // for Optimistic locking
+    pub version: i32,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CompensationClaimStatus {
+    Submitted,
+    Approved,
+    Rejected,
+}
+
+impl CompensationClaim {
+    /// Files a claim for the amount the assessment found owed; ineligible bookings are refused.
+    pub fn submit(
+        props: SubmitCompensationClaimProps,
+        assessment: &CompensationAssessment,
+        now: NaiveDateTime,
+    ) -> Result<Self, DomainError> {
+        let reason = match (assessment.reason, assessment.ineligibility) {
+            (Some(reason), None) => reason,
+            (_, ineligibility) => {
+                return Err(CompensationDomainError::NotEligible {
+                    booking_code: props.booking_code,
+                    reason: ineligibility
+                        .map_or("flight was not disrupted", |i| i.describe())
+                        .to_string(),
+                }
+                .into());
+            }
+        };
+        if props.passenger_count <= 0 {
+            return Err(CompensationDomainError::BusinessRule {
+                message: format!("Booking {} has no passengers", props.booking_code),
+            }
+            .into());
+        }
+
+        Ok(Self {
+            id: 0,
+            booking_id: props.booking_id,
+            flight_id: props.flight_id,
+            user_id: props.user_id,
+
+            reason,
+            status: CompensationClaimStatus::Submitted,
+
+            distance_km: assessment.distance_km,
+            arrival_delay_minutes: assessment.arrival_delay_minutes.map(|m| m as i32),
+            passenger_count: props.passenger_count,
+            amount_per_passenger: assessment.amount_per_passenger,
+            total_amount: assessment.amount_per_passenger * Decimal::from(props.passenger_count),
+            currency: assessment.currency.clone(),
+
+            customer_note: props.customer_note,
+            decision_note: None,
+            decided_by: None,
+
+            submitted_at: now,
+            decided_at: None,
+
+            version: 1,
+        })
+    }
+
+    pub fn approve(
+        &mut self,
+        decided_by: i64,
+        note: Option<String>,
+        now: NaiveDateTime,
+    ) -> Result<(), DomainError> {
+        self.decide(CompensationClaimStatus::Approved, decided_by, note, now)
+    }
+
+    /// Rejections must say why; the customer sees the note.
+    pub fn reject(
+        &mut self,
+        decided_by: i64,
+        note: Option<String>,
+        now: NaiveDateTime,
+    ) -> Result<(), DomainError> {
+        if note.is_none() {
+            return Err(CompensationDomainError::Validation {
+                field: "note",
+                message: "A rejection needs a note for the customer".to_string(),
+            }
+            .into());
+        }
+        self.decide(CompensationClaimStatus::Rejected, decided_by, note, now)
+    }
+
+    fn decide(
+        &mut self,
+        status: CompensationClaimStatus,
+        decided_by: i64,
+        note: Option<String>,
+        now: NaiveDateTime,
+    ) -> Result<(), DomainError> {
+        if self.status != CompensationClaimStatus::Submitted {
+            return Err(CompensationDomainError::ClaimAlreadyDecided {
+                status: self.status,
+            }
+            .into());
+        }
+
+        self.status = status;
+        self.decision_note = note;
+        self.decided_by = Some(decided_by);
+        self.decided_at = Some(now);
+        Ok(())
+    }
+}
@@ -0,0 +1,47 @@
+use crate::domain::compensation::entity::CompensationClaimStatus;
+use thiserror::Error;
+
+#[derive(Debug, Error)]
+pub enum CompensationDomainError {
+    // ===== Validation =====
+    #[error("validation error: {field} - {message}")]
+    Validation {
+        field: &'static str,
+        message: String,
+    },
+
+    // ===== Business rule =====
+    #[error("business rule violated: {message}")]
+    BusinessRule { message: String },
+
+    #[error("booking {booking_code} is not eligible for compensation: {reason}")]
+    NotEligible {
+        booking_code: String,
+        reason: String,
+    },
+
+    // ===== State =====
+    #[error("compensation claim already decided: {status:?}")]
+    ClaimAlreadyDecided { status: CompensationClaimStatus },
+
+    // ===== Conflict =====
+    #[error("conflict: {field} - {message}")]
+    Conflict {
+        field: &'static str,
+        message: String,
+    },
+
+    #[error("optimistic lock conflict")]
+    OptimisticLockConflict,
+
+    // ===== Not found =====
+    #[error("compensation claim not found: {claim_id}")]
+    ClaimNotFound { claim_id: i64 },
+
+    #[error("entity not found: {detail}")]
+    NotFound { detail: String },
+
+    // ===== Internal =====
+    #[error("internal error: {0}")]
+    Internal(String),
+}
@@ -0,0 +1,36 @@
+use crate::domain::compensation::entity::CompensationClaim;
+use chrono::NaiveDateTime;
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CompensationClaimDecidedEvent {
+    pub claim_id: i64,
+    pub booking_id: i64,
+    pub user_id: i64,
+    pub status: String,
+    pub total_amount: String,
+    pub currency: String,
+    pub decision_note: Option<String>,
+    pub decided_by: Option<i64>,
+    pub occurred_at: NaiveDateTime,
+}
+
+impl CompensationClaimDecidedEvent {
+    pub fn new(claim: &CompensationClaim, status: String, occurred_at: NaiveDateTime) -> Self {
+        Self {
+            claim_id: claim.id,
+            booking_id: claim.booking_id,
+            user_id: claim.user_id,
+            status,
+            total_amount: claim.total_amount.to_string(),
+            currency: claim.currency.clone(),
+            decision_note: claim.decision_note.clone(),
+            decided_by: claim.decided_by,
+            occurred_at,
+        }
+    }
+
+    pub fn topic_name() -> &'static str {
+        "compensation.claim_decided"
+    }
+}
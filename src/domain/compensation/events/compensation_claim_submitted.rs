@@ -0,0 +1,41 @@
+use crate::domain::compensation::entity::CompensationClaim;
+use chrono::NaiveDateTime;
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CompensationClaimSubmittedEvent {
+    pub claim_id: i64,
+    pub booking_id: i64,
+    pub booking_code: String,
+    pub flight_id: i64,
+    pub user_id: i64,
+    pub reason: String,
+    pub total_amount: String,
+    pub currency: String,
+    pub occurred_at: NaiveDateTime,
+}
+
+impl CompensationClaimSubmittedEvent {
+    pub fn new(
+        claim: &CompensationClaim,
+        booking_code: String,
+        reason: String,
+        occurred_at: NaiveDateTime,
+    ) -> Self {
+        Self {
+            claim_id: claim.id,
+            booking_id: claim.booking_id,
+            booking_code,
+            flight_id: claim.flight_id,
+            user_id: claim.user_id,
+            reason,
+            total_amount: claim.total_amount.to_string(),
+            currency: claim.currency.clone(),
+            occurred_at,
+        }
+    }
+
+    pub fn topic_name() -> &'static str {
+        "compensation.claim_submitted"
+    }
+}
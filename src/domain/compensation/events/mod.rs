@@ -0,0 +1,2 @@
+pub mod compensation_claim_submitted;
+pub mod compensation_claim_decided;
\ No newline at end of file
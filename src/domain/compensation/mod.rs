@@ -0,0 +1,5 @@
+pub mod events;
+pub mod entity;
+pub mod compensation_claim_repository_interface;
+pub mod compensation_policy;
+pub mod error;
\ No newline at end of file
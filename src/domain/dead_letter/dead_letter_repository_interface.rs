@@ -0,0 +1,27 @@
+use crate::domain::dead_letter::entity::{DeadLetter, DeadLetterStatus};
+use crate::domain::error::DomainError;
+
+#[async_trait::async_trait]
+pub trait DeadLetterRepositoryInterface: Send + Sync {
+    /// `None` when the dead-letter topic message was already archived.
+    async fn create_dead_letter(
+        &self,
+        dead_letter: &DeadLetter,
+    ) -> Result<Option<i64>, DomainError>;
+
+    async fn update_dead_letter(
+        &self,
+        dead_letter: &DeadLetter,
+        expected_version: i32,
+    ) -> Result<(), DomainError>;
+
+    async fn find_dead_letter_by_id(&self, id: i64) -> Result<Option<DeadLetter>, DomainError>;
+
+    /// Newest first.
+    async fn list_dead_letters(
+        &self,
+        status: Option<DeadLetterStatus>,
+        consumer_group: Option<String>,
+        limit: u64,
+    ) -> Result<Vec<DeadLetter>, DomainError>;
+}
@@ -0,0 +1,164 @@
+use crate::domain::dead_letter::error::DeadLetterDomainError;
+use crate::domain::error::DomainError;
+use chrono::NaiveDateTime;
+use std::collections::BTreeMap;
+
+#[derive(Debug, Clone)]
+pub struct RecordDeadLetterProps {
+    pub consumer_group: String,
+    pub handler: String,
+    pub original_topic: String,
+    pub original_partition: i32,
+    pub original_offset: i64,
+    pub message_key: Option<String>,
+    pub payload: Vec<u8>,
+    pub headers: BTreeMap<String, String>,
+    pub error: String,
+    pub attempts: i32,
+    pub dead_letter_topic: String,
+    pub dead_letter_partition: i32,
+    pub dead_letter_offset: i64,
+    pub failed_at: NaiveDateTime,
+}
+
+/// A consumed message its handler gave up on, archived from the dead-letter topic until
+/// an admin replays or discards it.
+#[derive(Debug, Clone)]
+pub struct DeadLetter {
+    pub id: i64,
+    pub consumer_group: String,
+    // the route that failed, as `Handler<Event>`
+    pub handler: String,
+
+    // where the message was first consumed from
+    pub original_topic: String,
+    pub original_partition: i32,
+    pub original_offset: i64,
+
+    pub message_key: Option<String>,
+    pub payload: Vec<u8>,
+    pub headers: BTreeMap<String, String>,
+
+    pub error: String,
+    pub attempts: i32,
+
+    // where the archiver read it from; identifies the dead letter
+    pub dead_letter_topic: String,
+    pub dead_letter_partition: i32,
+    pub dead_letter_offset: i64,
+
+    pub status: DeadLetterStatus,
+    pub failed_at: NaiveDateTime,
+    pub resolved_at: Option<NaiveDateTime>,
+    pub resolved_by: Option<i64>,
+    pub resolution_note: Option<String>,
+
+    // for Optimistic locking
+    pub version: i32,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DeadLetterStatus {
+    Pending,
+    Replayed,
+    Discarded,
+}
+
+impl DeadLetter {
+    pub fn record(props: RecordDeadLetterProps) -> Result<Self, DomainError> {
+        if props.consumer_group.trim().is_empty() {
+            return Err(DeadLetterDomainError::Validation {
+                field: "consumer_group",
+                message: "Consumer group must not be empty".to_string(),
+            }
+            .into());
+        }
+
+        Ok(Self {
+            id: 0,
+            consumer_group: props.consumer_group,
+            handler: props.handler,
+
+            original_topic: props.original_topic,
+            original_partition: props.original_partition,
+            original_offset: props.original_offset,
+
+            message_key: props.message_key,
+            payload: props.payload,
+            headers: props.headers,
+
+            error: props.error,
+            attempts: props.attempts,
+
+            dead_letter_topic: props.dead_letter_topic,
+            dead_letter_partition: props.dead_letter_partition,
+            dead_letter_offset: props.dead_letter_offset,
+
+            status: DeadLetterStatus::Pending,
+            failed_at: props.failed_at,
+            resolved_at: None,
+            resolved_by: None,
+            resolution_note: None,
+
+            version: 1,
+        })
+    }
+
+    /// Marks the message as handed back to its handler; a failing replay dead-letters it
+    /// again as a new entry.
+    pub fn replay(
+        &mut self,
+        resolved_by: i64,
+        note: Option<String>,
+        now: NaiveDateTime,
+    ) -> Result<(), DomainError> {
+        self.resolve(DeadLetterStatus::Replayed, resolved_by, note, now)
+    }
+
+    pub fn discard(
+        &mut self,
+        resolved_by: i64,
+        note: Option<String>,
+        now: NaiveDateTime,
+    ) -> Result<(), DomainError> {
+        self.resolve(DeadLetterStatus::Discarded, resolved_by, note, now)
+    }
+
+    /// Replays must name the handler they go back to.
+    pub fn ensure_replayable(&self) -> Result<(), DomainError> {
+        self.ensure_pending()?;
+        if self.handler.is_empty() {
+            return Err(DeadLetterDomainError::BusinessRule {
+                message: format!("Dead letter {} has no handler to replay to", self.id),
+            }
+            .into());
+        }
+        Ok(())
+    }
+
+    fn ensure_pending(&self) -> Result<(), DomainError> {
+        if self.status != DeadLetterStatus::Pending {
+            return Err(DeadLetterDomainError::AlreadyResolved {
+                status: self.status,
+            }
+            .into());
+        }
+        Ok(())
+    }
+
+    fn resolve(
+        &mut self,
+        status: DeadLetterStatus,
+        resolved_by: i64,
+        note: Option<String>,
+        now: NaiveDateTime,
+    ) -> Result<(), DomainError> {
+        self.ensure_pending()?;
+
+        self.status = status;
+        self.resolution_note = note;
+        self.resolved_by = Some(resolved_by);
+        self.resolved_at = Some(now);
+        Ok(())
+    }
+}
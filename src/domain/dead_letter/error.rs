@@ -0,0 +1,41 @@
+use crate::domain::dead_letter::entity::DeadLetterStatus;
+use thiserror::Error;
+
+#[derive(Debug, Error)]
+pub enum DeadLetterDomainError {
+    // ===== Validation =====
+    #[error("validation error: {field} - {message}")]
+    Validation {
+        field: &'static str,
+        message: String,
+    },
+
+    // ===== Business rule =====
+    #[error("business rule violated: {message}")]
+    BusinessRule { message: String },
+
+    // ===== State =====
+    #[error("dead letter already resolved: {status:?}")]
+    AlreadyResolved { status: DeadLetterStatus },
+
+    // ===== Conflict =====
+    #[error("conflict: {field} - {message}")]
+    Conflict {
+        field: &'static str,
+        message: String,
+    },
+
+    #[error("optimistic lock conflict")]
+    OptimisticLockConflict,
+
+    // ===== Not found =====
+    #[error("dead letter not found: {id}")]
+    DeadLetterNotFound { id: i64 },
+
+    #[error("entity not found: {detail}")]
+    NotFound { detail: String },
+
+    // ===== Internal =====
+    #[error("internal error: {0}")]
+    Internal(String),
+}
@@ -0,0 +1,3 @@
+pub mod dead_letter_repository_interface;
+pub mod entity;
+pub mod error;
\ No newline at end of file
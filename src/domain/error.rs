@@ -1,12 +1,19 @@
 use thiserror::Error;
 
 use crate::domain::address::error::AddressDomainError;
+use crate::domain::aircraft::error::AircraftDomainError;
+use crate::domain::airline::error::AirlineDomainError;
 use crate::domain::airport::error::AirportDomainError;
+use crate::domain::baggage::error::BaggageDomainError;
 use crate::domain::boarding_pass::error::BoardingPassDomainError;
 use crate::domain::booking::error::BookingDomainError;
 use crate::domain::checkin::error::CheckinDomainError;
+use crate::domain::compensation::error::CompensationDomainError;
+use crate::domain::dead_letter::error::DeadLetterDomainError;
 use crate::domain::flight::error::FlightDomainError;
+use crate::domain::notification::error::NotificationDomainError;
 use crate::domain::passenger::error::PassengerDomainError;
+use crate::domain::route::error::RouteDomainError;
 use crate::domain::user::errors::UserDomainError;
 
 #[derive(Debug, Error)]
@@ -20,6 +27,15 @@ pub enum DomainError {
     #[error(transparent)]
     Airport(#[from] AirportDomainError),
 
+    #[error(transparent)]
+    Airline(#[from] AirlineDomainError),
+
+    #[error(transparent)]
+    Aircraft(#[from] AircraftDomainError),
+
+    #[error(transparent)]
+    Route(#[from] RouteDomainError),
+
     #[error(transparent)]
     Flight(#[from] FlightDomainError),
 
@@ -34,4 +50,16 @@ pub enum DomainError {
 
     #[error(transparent)]
     BoardingPass(#[from] BoardingPassDomainError),
+
+    #[error(transparent)]
+    Baggage(#[from] BaggageDomainError),
+
+    #[error(transparent)]
+    Compensation(#[from] CompensationDomainError),
+
+    #[error(transparent)]
+    Notification(#[from] NotificationDomainError),
+
+    #[error(transparent)]
+    DeadLetter(#[from] DeadLetterDomainError),
 }
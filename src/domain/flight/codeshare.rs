@@ -0,0 +1,59 @@
+use crate::domain::business_rule_interface::BusinessRuleInterface;
+use crate::domain::error::DomainError;
+use crate::domain::flight::entity::Flight;
+use crate::domain::flight::error::FlightDomainError;
+use crate::domain::flight::rules::flight_number_must_be_valid::FlightNumberMustBeValid;
+
+/// A partner airline's designator on an operating flight. The partner sells seats under
+/// its own flight number; inventory, status and check-in stay with the operating flight.
+#[derive(Debug, Clone)]
+pub struct FlightCodeshare {
+    pub id: i64,
+    pub flight_id: i64,
+
+    pub airline_code: String,
+    pub flight_number: String,
+    /// Same `<airline><number>_<date>` form as the operating flight key.
+    pub flight_key: String,
+}
+
+impl FlightCodeshare {
+    pub fn new(
+        flight: &Flight,
+        airline_code: String,
+        flight_number: String,
+    ) -> Result<Self, DomainError> {
+        FlightNumberMustBeValid {
+            flight_number: &flight_number,
+        }
+        .check_broken()?;
+
+        if airline_code == flight.airline_code {
+            return Err(FlightDomainError::BusinessRule {
+                message: "The operating carrier cannot market its own flight as a codeshare"
+                    .to_string(),
+            }
+            .into());
+        }
+        flight.ensure_open_for_sale()?;
+
+        let flight_key = format!(
+            "{}{}_{}",
+            airline_code,
+            flight_number,
+            flight.departure_date.format("%Y-%m-%d")
+        );
+
+        Ok(Self {
+            id: 0,
+            flight_id: flight.id,
+            airline_code,
+            flight_number,
+            flight_key,
+        })
+    }
+
+    pub fn designator(&self) -> String {
+        format!("{}{}", self.airline_code, self.flight_number)
+    }
+}
@@ -3,8 +3,14 @@ use crate::domain::error::DomainError;
 use crate::domain::flight::error::FlightDomainError;
 use crate::domain::flight::rules::arrival_time_must_be_after_departure_time::ArrivalTimeMustBeAfterDepartureTime;
 use crate::domain::flight::rules::available_seats_must_not_exceed_total_seats::AvailableSeatsMustNotExceedTotalSeats;
+use crate::domain::flight::rules::delay_codes_must_be_valid::DelayCodesMustBeValid;
 use crate::domain::flight::rules::flight_checkin_window_must_be_valid::FlightCheckinWindowMustBeValid;
+use crate::domain::flight::rules::flight_number_must_be_valid::FlightNumberMustBeValid;
 use crate::domain::flight::rules::origin_must_not_equal_destination::FlightMustHaveDifferentAirports;
+use crate::domain::flight::rules::status_change_must_report_times::StatusChangeMustReportTimes;
+use crate::domain::flight::status_event::{
+    FlightOperationalTimes, FlightStatusChange, FlightStatusEvent,
+};
 use chrono::{NaiveDate, NaiveDateTime};
 
 #[derive(Debug, Clone, PartialEq, Eq)]
@@ -26,12 +32,15 @@ pub struct Flight {
 
     pub origin_airport_id: i64,
     pub destination_airport_id: i64,
+    // Flights created before the route network existed may have none.
+    pub route_id: Option<i64>,
 
     pub departure_date: NaiveDate,
     pub departure_time: NaiveDateTime,
     pub arrival_time: NaiveDateTime,
 
     pub status: FlightStatus,
+    pub operational_times: FlightOperationalTimes,
 
     pub aircraft_type: Option<String>,
     pub tail_number: Option<String>,
@@ -79,6 +88,11 @@ pub struct CreateFlightProps {
 
 impl CreateFlightProps {
     pub fn validate(&self) -> Result<(), DomainError> {
+        FlightNumberMustBeValid {
+            flight_number: &self.flight_number,
+        }
+        .check_broken()?;
+
         ArrivalTimeMustBeAfterDepartureTime {
             departure_time: &self.departure_time,
             arrival_time: &self.arrival_time,
@@ -119,12 +133,14 @@ impl Flight {
 
             origin_airport_id: props.origin_airport_id,
             destination_airport_id: props.destination_airport_id,
+            route_id: None,
 
             departure_date: props.departure_date,
             departure_time: props.departure_time,
             arrival_time: props.arrival_time,
 
             status: FlightStatus::Scheduled,
+            operational_times: FlightOperationalTimes::default(),
 
             aircraft_type: props.aircraft_type,
             tail_number: props.tail_number,
@@ -143,15 +159,28 @@ impl Flight {
         })
     }
 
-    pub fn change_status(&mut self, new_status: FlightStatus) -> Result<(), DomainError> {
+    /// Operating designator, e.g. `LH400`.
+    pub fn designator(&self) -> String {
+        format!("{}{}", self.airline_code, self.flight_number)
+    }
+
+    /// Applies an operational status update and returns the timeline event to record.
+    /// A delayed flight may be delayed again to publish a revised estimate.
+    pub fn change_status(
+        &mut self,
+        change: FlightStatusChange,
+        changed_by: Option<i64>,
+        now: NaiveDateTime,
+    ) -> Result<FlightStatusEvent, DomainError> {
         use FlightStatus::*;
 
         let from = self.status.clone();
-        let to = new_status.clone();
+        let to = change.status.clone();
 
         let valid = matches!(
             (from.clone(), to.clone()),
             (Scheduled, Delayed)
+                | (Delayed, Delayed)
                 | (Scheduled, Departed)
                 | (Delayed, Departed)
                 | (Departed, Arrived)
@@ -162,16 +191,77 @@ impl Flight {
             return Err(FlightDomainError::InvalidStatusTransition { from, to }.into());
         }
 
-        self.status = new_status;
-        Ok(())
+        StatusChangeMustReportTimes {
+            status: &to,
+            times: &change.times,
+        }
+        .check_broken()?;
+
+        DelayCodesMustBeValid {
+            delay_codes: &change.delay_codes,
+        }
+        .check_broken()?;
+
+        let mut times = self.operational_times.clone();
+        times.merge(&change.times);
+
+        self.status = to.clone();
+        self.operational_times = times;
+
+        Ok(FlightStatusEvent {
+            id: 0,
+            flight_id: self.id,
+            from_status: from,
+            to_status: to,
+            scheduled_departure: self.departure_time,
+            scheduled_arrival: self.arrival_time,
+            times: self.operational_times.clone(),
+            delay_minutes: self.departure_delay_minutes(),
+            delay_codes: change.delay_codes,
+            reason: change.reason,
+            changed_by,
+            recorded_at: now,
+        })
     }
 
-    pub fn reserve_seat(&mut self) -> Result<(), DomainError> {
-        self.available_seats -= 1;
-        Ok(())
+    /// Best known departure, falling back to the schedule until operations report one.
+    pub fn expected_departure(&self) -> NaiveDateTime {
+        self.operational_times
+            .departure()
+            .unwrap_or(self.departure_time)
+    }
+
+    /// Whether the flight is expected to have left by `now`, which closes boarding. A delay
+    /// keeps the gate open past the scheduled time.
+    pub fn has_departed_by(&self, now: NaiveDateTime) -> bool {
+        now > self.expected_departure()
+    }
+
+    /// Minutes between scheduled and best known departure; `None` until one is reported.
+    pub fn departure_delay_minutes(&self) -> Option<i32> {
+        self.operational_times
+            .departure()
+            .map(|at| (at - self.departure_time).num_minutes() as i32)
+    }
+
+    /// Minutes between scheduled and best known arrival; `None` until one is reported.
+    pub fn arrival_delay_minutes(&self) -> Option<i32> {
+        self.operational_times
+            .arrival()
+            .map(|at| (at - self.arrival_time).num_minutes() as i32)
     }
 
     pub fn validate_seat_reservation(&self) -> Result<(), DomainError> {
+        self.ensure_open_for_sale()?;
+
+        if self.available_seats <= 0 {
+            return Err(FlightDomainError::NoSeatsAvailable.into());
+        }
+
+        Ok(())
+    }
+
+    pub fn ensure_open_for_sale(&self) -> Result<(), DomainError> {
         match self.status {
             FlightStatus::Cancelled => {
                 return Err(FlightDomainError::FlightAlreadyCancelled.into());
@@ -188,10 +278,71 @@ impl Flight {
             _ => {}
         }
 
-        if self.available_seats <= 0 {
-            return Err(FlightDomainError::NoSeatsAvailable.into());
-        }
-
         Ok(())
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::Duration;
+
+    fn scheduled_flight() -> Flight {
+        let departure_time = NaiveDate::from_ymd_opt(2026, 10, 20)
+            .unwrap()
+            .and_hms_opt(8, 0, 0)
+            .unwrap();
+        Flight::new(CreateFlightProps {
+            airline_code: "VN".to_string(),
+            flight_number: "123".to_string(),
+            origin_airport_id: 1,
+            destination_airport_id: 2,
+            departure_date: departure_time.date(),
+            departure_time,
+            arrival_time: departure_time + Duration::hours(2),
+            aircraft_type: None,
+            tail_number: None,
+            terminal_departure: None,
+            terminal_arrival: None,
+            checkin_open_at: None,
+            checkin_close_at: None,
+            boarding_time: None,
+            gate: None,
+            total_seats: 180,
+        })
+        .unwrap()
+    }
+
+    #[test]
+    fn a_delayed_flight_boards_after_its_scheduled_departure() {
+        let mut flight = scheduled_flight();
+        let scheduled = flight.departure_time;
+        flight
+            .change_status(
+                FlightStatusChange {
+                    status: FlightStatus::Delayed,
+                    times: FlightOperationalTimes {
+                        estimated_departure: Some(scheduled + Duration::hours(2)),
+                        ..Default::default()
+                    },
+                    delay_codes: Vec::new(),
+                    reason: None,
+                },
+                None,
+                scheduled - Duration::hours(1),
+            )
+            .unwrap();
+
+        assert_eq!(flight.departure_time, scheduled);
+        assert!(!flight.has_departed_by(scheduled + Duration::minutes(30)));
+        assert!(flight.has_departed_by(scheduled + Duration::hours(2) + Duration::minutes(1)));
+    }
+
+    #[test]
+    fn an_on_time_flight_closes_boarding_at_its_scheduled_departure() {
+        let flight = scheduled_flight();
+
+        assert!(!flight.has_departed_by(flight.departure_time));
+        assert!(flight.has_departed_by(flight.departure_time + Duration::minutes(1)));
+    }
+}
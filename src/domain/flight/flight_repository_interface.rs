@@ -1,22 +1,41 @@
 use crate::domain::error::DomainError;
-use crate::domain::flight::entity::{Flight, FlightStatus};
-use chrono::NaiveDate;
+use crate::domain::flight::codeshare::FlightCodeshare;
+use crate::domain::flight::entity::Flight;
+use crate::domain::flight::status_event::FlightStatusEvent;
+use chrono::{NaiveDate, NaiveDateTime};
 
 #[async_trait::async_trait]
 pub trait FlightRepositoryInterface: Send + Sync {
     async fn create_flight(&self, flight: &Flight) -> Result<i64, DomainError>;
+    /// Writes the flight and, when its status changed, the timeline event in one transaction.
     async fn update_flight(
         &self,
         flight: &Flight,
         expected_version: i32,
+        status_event: Option<&FlightStatusEvent>,
     ) -> Result<(), DomainError>;
 
     async fn find_flight_by_id(&self, id: i64) -> Result<Option<Flight>, DomainError>;
+    /// Resolves operating and codeshare keys alike to the operating flight.
     async fn find_flight_by_flight_key(
         &self,
         flight_key: &str,
     ) -> Result<Option<Flight>, DomainError>;
 
+    async fn create_codeshare(&self, codeshare: &FlightCodeshare) -> Result<i64, DomainError>;
+
+    /// Returns `false` when the flight carries no designator of that airline.
+    async fn delete_codeshare(
+        &self,
+        flight_id: i64,
+        airline_code: &str,
+    ) -> Result<bool, DomainError>;
+
+    async fn list_codeshares_by_flight_ids(
+        &self,
+        flight_ids: &[i64],
+    ) -> Result<Vec<FlightCodeshare>, DomainError>;
+
     async fn search_flights(
         &self,
         origin_airport_id: i32,
@@ -24,13 +43,59 @@ pub trait FlightRepositoryInterface: Send + Sync {
         departure_date: NaiveDate,
     ) -> Result<Vec<Flight>, DomainError>;
 
+    /// Writes the status and operational times only, together with the timeline event.
     async fn update_flight_status(
         &self,
-        flight_id: i64,
+        flight: &Flight,
         expected_version: i32,
-        status: FlightStatus,
+        status_event: &FlightStatusEvent,
     ) -> Result<(), DomainError>;
 
+    /// Status history of a flight, oldest first.
+    async fn list_status_events(
+        &self,
+        flight_id: i64,
+    ) -> Result<Vec<FlightStatusEvent>, DomainError>;
+
     async fn decrease_available_seats(&self, flight_id: i64, seats: i32)
     -> Result<(), DomainError>;
+
+    async fn increase_available_seats(&self, flight_id: i64, seats: i32)
+    -> Result<(), DomainError>;
+
+    /// Hands out the next boarding sequence number on the flight, starting at 1.
+    async fn next_boarding_sequence(&self, flight_id: i64) -> Result<i32, DomainError>;
+
+    /// Non-cancelled flights on the tail whose block times intersect `departure..arrival`.
+    async fn list_flights_by_tail_number_between(
+        &self,
+        tail_number: &str,
+        departure: NaiveDateTime,
+        arrival: NaiveDateTime,
+    ) -> Result<Vec<Flight>, DomainError>;
+
+    /// The last non-cancelled flight on the tail arriving by `before`, skipping `exclude_flight_id`.
+    async fn find_previous_flight_by_tail_number(
+        &self,
+        tail_number: &str,
+        before: NaiveDateTime,
+        exclude_flight_id: i64,
+    ) -> Result<Option<Flight>, DomainError>;
+
+    /// The first non-cancelled flight on the tail departing at or after `after`, skipping `exclude_flight_id`.
+    async fn find_next_flight_by_tail_number(
+        &self,
+        tail_number: &str,
+        after: NaiveDateTime,
+        exclude_flight_id: i64,
+    ) -> Result<Option<Flight>, DomainError>;
+
+    /// Departed or arrived flights the no-show job has not processed yet.
+    async fn list_flights_pending_no_shows(&self) -> Result<Vec<Flight>, DomainError>;
+
+    async fn mark_no_shows_processed(
+        &self,
+        flight_id: i64,
+        processed_at: NaiveDateTime,
+    ) -> Result<(), DomainError>;
 }
@@ -1,5 +1,7 @@
 pub mod events;
 pub mod rules;
 pub mod entity;
+pub mod codeshare;
+pub mod status_event;
 pub mod flight_repository_interface;
 pub mod error;
\ No newline at end of file
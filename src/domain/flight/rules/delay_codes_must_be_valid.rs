@@ -0,0 +1,34 @@
+use crate::domain::business_rule_interface::BusinessRuleInterface;
+use crate::domain::error::DomainError;
+use crate::domain::flight::error::FlightDomainError;
+
+/// IATA delay codes are two digits (`93`) or two letters (`RA`); at most five are kept.
+pub struct DelayCodesMustBeValid<'a> {
+    pub delay_codes: &'a [String],
+}
+
+impl<'a> BusinessRuleInterface for DelayCodesMustBeValid<'a> {
+    fn check_broken(&self) -> Result<(), DomainError> {
+        if self.delay_codes.len() > 5 {
+            return Err(FlightDomainError::Validation {
+                field: "delay_codes",
+                message: "At most 5 delay codes can be given".to_string(),
+            }
+            .into());
+        }
+
+        let invalid = self.delay_codes.iter().find(|code| {
+            code.len() != 2
+                || !(code.chars().all(|c| c.is_ascii_digit())
+                    || code.chars().all(|c| c.is_ascii_uppercase()))
+        });
+        if let Some(code) = invalid {
+            return Err(FlightDomainError::Validation {
+                field: "delay_codes",
+                message: format!("Invalid delay code: {code}"),
+            }
+            .into());
+        }
+        Ok(())
+    }
+}
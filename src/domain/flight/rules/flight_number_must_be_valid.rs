@@ -0,0 +1,32 @@
+use crate::domain::business_rule_interface::BusinessRuleInterface;
+use crate::domain::error::DomainError;
+use crate::domain::flight::error::FlightDomainError;
+
+/// 1-4 digits plus an optional operational suffix letter, e.g. `123` or `1234A`.
+/// The airline designator is kept separately in `airline_code`.
+pub struct FlightNumberMustBeValid<'a> {
+    pub flight_number: &'a str,
+}
+
+impl<'a> BusinessRuleInterface for FlightNumberMustBeValid<'a> {
+    fn check_broken(&self) -> Result<(), DomainError> {
+        let digits = self
+            .flight_number
+            .chars()
+            .take_while(|c| c.is_ascii_digit())
+            .count();
+        let suffix = &self.flight_number[digits..];
+
+        let valid = (1..=4).contains(&digits)
+            && (suffix.is_empty()
+                || (suffix.len() == 1 && suffix.chars().all(|c| c.is_ascii_uppercase())));
+        if !valid {
+            return Err(FlightDomainError::Validation {
+                field: "flight_number",
+                message: "Must be 1-4 digits with an optional letter suffix".to_string(),
+            }
+            .into());
+        }
+        Ok(())
+    }
+}
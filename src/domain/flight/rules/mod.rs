@@ -1,4 +1,7 @@
 pub mod origin_must_not_equal_destination;
 pub mod available_seats_must_not_exceed_total_seats;
 pub mod arrival_time_must_be_after_departure_time;
-pub mod flight_checkin_window_must_be_valid;
\ No newline at end of file
+pub mod flight_checkin_window_must_be_valid;
+pub mod flight_number_must_be_valid;
+pub mod delay_codes_must_be_valid;
+pub mod status_change_must_report_times;
\ No newline at end of file
@@ -0,0 +1,36 @@
+use crate::domain::business_rule_interface::BusinessRuleInterface;
+use crate::domain::error::DomainError;
+use crate::domain::flight::entity::FlightStatus;
+use crate::domain::flight::error::FlightDomainError;
+use crate::domain::flight::status_event::FlightOperationalTimes;
+
+/// A delay needs a new estimated departure and a departure needs an actual off-block
+/// or takeoff time, so the timeline can tell how late the flight was.
+pub struct StatusChangeMustReportTimes<'a> {
+    pub status: &'a FlightStatus,
+    pub times: &'a FlightOperationalTimes,
+}
+
+impl<'a> BusinessRuleInterface for StatusChangeMustReportTimes<'a> {
+    fn check_broken(&self) -> Result<(), DomainError> {
+        match self.status {
+            FlightStatus::Delayed if self.times.estimated_departure.is_none() => {
+                Err(FlightDomainError::Validation {
+                    field: "estimated_departure",
+                    message: "A delay needs an estimated departure time".to_string(),
+                }
+                .into())
+            }
+            FlightStatus::Departed
+                if self.times.actual_off_block.is_none() && self.times.actual_takeoff.is_none() =>
+            {
+                Err(FlightDomainError::Validation {
+                    field: "actual_off_block",
+                    message: "A departure needs an actual off-block or takeoff time".to_string(),
+                }
+                .into())
+            }
+            _ => Ok(()),
+        }
+    }
+}
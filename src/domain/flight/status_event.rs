@@ -0,0 +1,94 @@
+use crate::domain::flight::entity::FlightStatus;
+use chrono::NaiveDateTime;
+
+/// Estimated and actual movement times, naive UTC. Off-block and on-block are the gate
+/// times; takeoff and landing are wheels up and wheels down.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct FlightOperationalTimes {
+    pub estimated_departure: Option<NaiveDateTime>,
+    pub estimated_arrival: Option<NaiveDateTime>,
+    pub actual_off_block: Option<NaiveDateTime>,
+    pub actual_takeoff: Option<NaiveDateTime>,
+    pub actual_landing: Option<NaiveDateTime>,
+    pub actual_on_block: Option<NaiveDateTime>,
+}
+
+impl FlightOperationalTimes {
+    pub fn is_empty(&self) -> bool {
+        *self == Self::default()
+    }
+
+    /// Takes every time `reported` carries, keeping the known ones it leaves out.
+    pub fn merge(&mut self, reported: &FlightOperationalTimes) {
+        let merge = |known: &mut Option<NaiveDateTime>, reported: Option<NaiveDateTime>| {
+            if reported.is_some() {
+                *known = reported;
+            }
+        };
+        merge(&mut self.estimated_departure, reported.estimated_departure);
+        merge(&mut self.estimated_arrival, reported.estimated_arrival);
+        merge(&mut self.actual_off_block, reported.actual_off_block);
+        merge(&mut self.actual_takeoff, reported.actual_takeoff);
+        merge(&mut self.actual_landing, reported.actual_landing);
+        merge(&mut self.actual_on_block, reported.actual_on_block);
+    }
+
+    /// Best known departure: off-block, then takeoff, then the estimate.
+    pub fn departure(&self) -> Option<NaiveDateTime> {
+        self.actual_off_block
+            .or(self.actual_takeoff)
+            .or(self.estimated_departure)
+    }
+
+    /// Best known arrival: on-block, then landing, then the estimate.
+    pub fn arrival(&self) -> Option<NaiveDateTime> {
+        self.actual_on_block
+            .or(self.actual_landing)
+            .or(self.estimated_arrival)
+    }
+}
+
+/// A status update as reported by operations.
+#[derive(Debug, Clone)]
+pub struct FlightStatusChange {
+    pub status: FlightStatus,
+    pub times: FlightOperationalTimes,
+    /// IATA delay codes (AHM 730), most significant first.
+    pub delay_codes: Vec<String>,
+    pub reason: Option<String>,
+}
+
+impl FlightStatusChange {
+    pub fn to(status: FlightStatus) -> Self {
+        Self {
+            status,
+            times: FlightOperationalTimes::default(),
+            delay_codes: Vec::new(),
+            reason: None,
+        }
+    }
+}
+
+/// One step of a flight's operational timeline; the history is append-only.
+#[derive(Debug, Clone)]
+pub struct FlightStatusEvent {
+    pub id: i64,
+    pub flight_id: i64,
+
+    pub from_status: FlightStatus,
+    pub to_status: FlightStatus,
+
+    pub scheduled_departure: NaiveDateTime,
+    pub scheduled_arrival: NaiveDateTime,
+    // Times known after this change, not only the ones reported with it.
+    pub times: FlightOperationalTimes,
+
+    /// Departure delay against the schedule, from the best known departure time.
+    pub delay_minutes: Option<i32>,
+    pub delay_codes: Vec<String>,
+    pub reason: Option<String>,
+
+    /// User who made the change; `None` for system jobs.
+    pub changed_by: Option<i64>,
+    pub recorded_at: NaiveDateTime,
+}
@@ -1,10 +1,17 @@
 pub mod user;
 pub mod address;
 pub mod airport;
+pub mod airline;
+pub mod aircraft;
+pub mod route;
 pub mod flight;
 pub mod booking;
 pub mod passenger;
 pub mod checkin;
 pub mod boarding_pass;
+pub mod baggage;
+pub mod compensation;
+pub mod notification;
+pub mod dead_letter;
 pub mod business_rule_interface;
 pub mod error;
\ No newline at end of file
@@ -0,0 +1,219 @@
+use crate::domain::error::DomainError;
+use crate::domain::notification::error::NotificationDomainError;
+use crate::domain::notification::template::RenderedMessage;
+use chrono::{Duration, NaiveDateTime};
+
+/// What a notification is about; each kind has its own template and variables.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum NotificationKind {
+    EmailVerification,
+    BookingConfirmation,
+    CheckinReminder,
+    FlightDisruption,
+}
+
+impl NotificationKind {
+    pub const ALL: [NotificationKind; 4] = [
+        NotificationKind::EmailVerification,
+        NotificationKind::BookingConfirmation,
+        NotificationKind::CheckinReminder,
+        NotificationKind::FlightDisruption,
+    ];
+
+    /// Section name in the template files.
+    pub fn key(&self) -> &'static str {
+        match self {
+            NotificationKind::EmailVerification => "email_verification",
+            NotificationKind::BookingConfirmation => "booking_confirmation",
+            NotificationKind::CheckinReminder => "checkin_reminder",
+            NotificationKind::FlightDisruption => "flight_disruption",
+        }
+    }
+
+    pub fn from_key(key: &str) -> Option<Self> {
+        Self::ALL.into_iter().find(|kind| kind.key() == key)
+    }
+
+    /// Placeholders a template of this kind may use.
+    pub fn variables(&self) -> &'static [&'static str] {
+        match self {
+            NotificationKind::EmailVerification => &["full_name", "verification_url"],
+            NotificationKind::BookingConfirmation => &[
+                "contact_name",
+                "booking_code",
+                "flight",
+                "origin",
+                "destination",
+                "departure_time",
+            ],
+            NotificationKind::CheckinReminder => &[
+                "contact_name",
+                "booking_code",
+                "flight",
+                "origin",
+                "destination",
+                "departure_time",
+                "checkin_close_time",
+            ],
+            NotificationKind::FlightDisruption => &[
+                "contact_name",
+                "booking_code",
+                "flight",
+                "origin",
+                "destination",
+                "departure_time",
+                "status",
+                "new_departure_time",
+                "reason",
+            ],
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum NotificationChannelKind {
+    Email,
+    Sms,
+    Push,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum NotificationStatus {
+    Pending,
+    Sent,
+    Failed,
+    Cancelled,
+}
+
+#[derive(Debug, Clone)]
+pub struct QueueNotificationProps {
+    pub kind: NotificationKind,
+    pub channel: NotificationChannelKind,
+    pub user_id: Option<i64>,
+    pub booking_id: Option<i64>,
+    pub flight_id: Option<i64>,
+    pub recipient: String,
+    pub locale: String,
+    pub message: RenderedMessage,
+    /// Identifies the occasion; a second notification with the same key is never queued.
+    pub dedup_key: String,
+    pub scheduled_at: NaiveDateTime,
+}
+
+/// One message to one recipient over one channel, kept with its delivery state.
+#[derive(Debug, Clone)]
+pub struct Notification {
+    pub id: i64,
+    pub kind: NotificationKind,
+    pub channel: NotificationChannelKind,
+    pub status: NotificationStatus,
+
+    pub user_id: Option<i64>,
+    pub booking_id: Option<i64>,
+    pub flight_id: Option<i64>,
+
+    pub recipient: String,
+    pub locale: String,
+    pub subject: Option<String>,
+    pub body: String,
+    pub dedup_key: String,
+
+    pub attempts: i32,
+    pub last_error: Option<String>,
+    pub provider_message_id: Option<String>,
+
+    // next delivery attempt while pending
+    pub scheduled_at: NaiveDateTime,
+    pub sent_at: Option<NaiveDateTime>,
+
+    // for Optimistic locking
+    pub version: i32,
+}
+
+/// Failed deliveries are retried after `base_delay`, doubling each time, until
+/// `max_attempts` have been made.
+#[derive(Debug, Clone, Copy)]
+pub struct NotificationRetryPolicy {
+    pub max_attempts: i32,
+    pub base_delay: Duration,
+}
+
+impl NotificationRetryPolicy {
+    /// `None` once `attempts` deliveries have failed and no retry is left.
+    pub fn next_attempt_at(&self, attempts: i32, now: NaiveDateTime) -> Option<NaiveDateTime> {
+        if attempts >= self.max_attempts {
+            return None;
+        }
+        let factor = 1i32 << (attempts - 1).clamp(0, 16);
+        Some(now + self.base_delay * factor)
+    }
+}
+
+impl Notification {
+    pub fn queue(props: QueueNotificationProps) -> Result<Self, DomainError> {
+        if props.recipient.trim().is_empty() {
+            return Err(NotificationDomainError::Validation {
+                field: "recipient",
+                message: "Recipient must not be empty".to_string(),
+            }
+            .into());
+        }
+
+        Ok(Self {
+            id: 0,
+            kind: props.kind,
+            channel: props.channel,
+            status: NotificationStatus::Pending,
+
+            user_id: props.user_id,
+            booking_id: props.booking_id,
+            flight_id: props.flight_id,
+
+            recipient: props.recipient.trim().to_string(),
+            locale: props.locale,
+            subject: props.message.subject,
+            body: props.message.body,
+            dedup_key: props.dedup_key,
+
+            attempts: 0,
+            last_error: None,
+            provider_message_id: None,
+
+            scheduled_at: props.scheduled_at,
+            sent_at: None,
+
+            version: 1,
+        })
+    }
+
+    pub fn mark_sent(&mut self, provider_message_id: Option<String>, now: NaiveDateTime) {
+        self.attempts += 1;
+        self.status = NotificationStatus::Sent;
+        self.provider_message_id = provider_message_id;
+        self.last_error = None;
+        self.sent_at = Some(now);
+    }
+
+    /// Reschedules the notification, or fails it for good when the error is permanent or
+    /// the policy has no retry left.
+    pub fn mark_failed(
+        &mut self,
+        error: String,
+        permanent: bool,
+        policy: &NotificationRetryPolicy,
+        now: NaiveDateTime,
+    ) {
+        self.attempts += 1;
+        self.last_error = Some(error);
+
+        let next_attempt = if permanent {
+            None
+        } else {
+            policy.next_attempt_at(self.attempts, now)
+        };
+        match next_attempt {
+            Some(at) => self.scheduled_at = at,
+            None => self.status = NotificationStatus::Failed,
+        }
+    }
+}
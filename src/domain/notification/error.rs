@@ -0,0 +1,36 @@
+use thiserror::Error;
+
+#[derive(Debug, Error)]
+pub enum NotificationDomainError {
+    // ===== Validation =====
+    #[error("validation error: {field} - {message}")]
+    Validation {
+        field: &'static str,
+        message: String,
+    },
+
+    #[error("invalid notification template: {message}")]
+    Template { message: String },
+
+    // ===== Business rule =====
+    #[error("business rule violated: {message}")]
+    BusinessRule { message: String },
+
+    // ===== Conflict =====
+    #[error("conflict: {field} - {message}")]
+    Conflict {
+        field: &'static str,
+        message: String,
+    },
+
+    #[error("optimistic lock conflict")]
+    OptimisticLockConflict,
+
+    // ===== Not found =====
+    #[error("entity not found: {detail}")]
+    NotFound { detail: String },
+
+    // ===== Internal =====
+    #[error("internal error: {0}")]
+    Internal(String),
+}
@@ -0,0 +1,6 @@
+pub mod entity;
+pub mod error;
+pub mod notification_preference_repository_interface;
+pub mod notification_repository_interface;
+pub mod preference;
+pub mod template;
\ No newline at end of file
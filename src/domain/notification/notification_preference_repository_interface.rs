@@ -0,0 +1,21 @@
+use crate::domain::error::DomainError;
+use crate::domain::notification::preference::NotificationPreference;
+
+#[async_trait::async_trait]
+pub trait NotificationPreferenceRepositoryInterface: Send + Sync {
+    async fn create_preference(
+        &self,
+        preference: &NotificationPreference,
+    ) -> Result<(), DomainError>;
+
+    async fn update_preference(
+        &self,
+        preference: &NotificationPreference,
+        expected_version: i32,
+    ) -> Result<(), DomainError>;
+
+    async fn find_preference(
+        &self,
+        user_id: i64,
+    ) -> Result<Option<NotificationPreference>, DomainError>;
+}
@@ -0,0 +1,44 @@
+use chrono::NaiveDateTime;
+
+use crate::domain::error::DomainError;
+use crate::domain::notification::entity::{Notification, NotificationKind, NotificationStatus};
+
+#[async_trait::async_trait]
+pub trait NotificationRepositoryInterface: Send + Sync {
+    /// `None` when a notification with the same dedup key already exists.
+    async fn create_notification(
+        &self,
+        notification: &Notification,
+    ) -> Result<Option<i64>, DomainError>;
+
+    async fn update_notification(
+        &self,
+        notification: &Notification,
+        expected_version: i32,
+    ) -> Result<(), DomainError>;
+
+    /// Takes up to `limit` pending notifications due at `now` and pushes their schedule to
+    /// `lease_until`, so other instances skip them while they are being sent and a crashed
+    /// sender's batch comes back once the lease runs out.
+    async fn lease_due_notifications(
+        &self,
+        now: NaiveDateTime,
+        lease_until: NaiveDateTime,
+        limit: u64,
+    ) -> Result<Vec<Notification>, DomainError>;
+
+    /// Cancels the booking's pending notifications, only those of `kind` when given.
+    async fn cancel_pending_for_booking(
+        &self,
+        booking_id: i64,
+        kind: Option<NotificationKind>,
+    ) -> Result<u64, DomainError>;
+
+    /// Newest first.
+    async fn list_notifications(
+        &self,
+        user_id: Option<i64>,
+        status: Option<NotificationStatus>,
+        limit: u64,
+    ) -> Result<Vec<Notification>, DomainError>;
+}
@@ -0,0 +1,99 @@
+use crate::domain::error::DomainError;
+use crate::domain::notification::entity::NotificationChannelKind;
+use crate::domain::notification::error::NotificationDomainError;
+
+#[derive(Debug, Clone)]
+pub struct UpdateNotificationPreferenceProps {
+    pub locale: Option<String>,
+    pub email_enabled: bool,
+    pub sms_enabled: bool,
+    pub push_enabled: bool,
+    pub push_token: Option<String>,
+}
+
+/// How a user wants to be notified. Users without a stored preference get `default_for`:
+/// email only, in the default locale.
+#[derive(Debug, Clone)]
+pub struct NotificationPreference {
+    pub user_id: i64,
+    /// BCP 47 language tag such as `en` or `vi-VN`.
+    pub locale: Option<String>,
+    pub email_enabled: bool,
+    pub sms_enabled: bool,
+    pub push_enabled: bool,
+    pub push_token: Option<String>,
+
+    // for Optimistic locking
+    pub version: i32,
+}
+
+impl NotificationPreference {
+    pub fn default_for(user_id: i64) -> Self {
+        Self {
+            user_id,
+            locale: None,
+            email_enabled: true,
+            sms_enabled: false,
+            push_enabled: false,
+            push_token: None,
+            version: 0,
+        }
+    }
+
+    /// Not stored yet; `version` is only set once the row exists.
+    pub fn is_new(&self) -> bool {
+        self.version == 0
+    }
+
+    pub fn update(&mut self, props: UpdateNotificationPreferenceProps) -> Result<(), DomainError> {
+        let locale = props
+            .locale
+            .map(|l| l.trim().to_string())
+            .filter(|l| !l.is_empty());
+        if let Some(locale) = locale.as_ref().filter(|l| !is_language_tag(l)) {
+            return Err(NotificationDomainError::Validation {
+                field: "locale",
+                message: format!("{locale} is not a language tag such as en or vi-VN"),
+            }
+            .into());
+        }
+
+        let push_token = props
+            .push_token
+            .map(|t| t.trim().to_string())
+            .filter(|t| !t.is_empty());
+        if props.push_enabled && push_token.is_none() {
+            return Err(NotificationDomainError::Validation {
+                field: "push_token",
+                message: "Push notifications need a device push token".to_string(),
+            }
+            .into());
+        }
+
+        self.locale = locale;
+        self.email_enabled = props.email_enabled;
+        self.sms_enabled = props.sms_enabled;
+        self.push_enabled = props.push_enabled;
+        self.push_token = push_token;
+        Ok(())
+    }
+
+    pub fn allows(&self, channel: NotificationChannelKind) -> bool {
+        match channel {
+            NotificationChannelKind::Email => self.email_enabled,
+            NotificationChannelKind::Sms => self.sms_enabled,
+            NotificationChannelKind::Push => self.push_enabled && self.push_token.is_some(),
+        }
+    }
+}
+
+// `ll` or `ll-RR`: a two or three letter language, optionally with a two letter region.
+fn is_language_tag(tag: &str) -> bool {
+    let mut parts = tag.split('-');
+    let language = parts.next().unwrap_or_default();
+    let region = parts.next();
+    parts.next().is_none()
+        && (2..=3).contains(&language.len())
+        && language.chars().all(|c| c.is_ascii_lowercase())
+        && region.is_none_or(|r| r.len() == 2 && r.chars().all(|c| c.is_ascii_uppercase()))
+}
@@ -0,0 +1,176 @@
+use std::collections::{BTreeMap, HashMap};
+
+use crate::domain::notification::entity::{NotificationChannelKind, NotificationKind};
+use crate::domain::notification::error::NotificationDomainError;
+
+/// Values for a template's `{{ name }}` placeholders.
+pub type TemplateVars = BTreeMap<&'static str, String>;
+
+/// Texts for one kind in one locale. Email uses `subject` and `body`; SMS and push are only
+/// sent when their text is given, push using `subject` as its title.
+#[derive(Debug, Clone)]
+pub struct NotificationTemplate {
+    pub subject: String,
+    pub body: String,
+    pub sms: Option<String>,
+    pub push: Option<String>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RenderedMessage {
+    pub subject: Option<String>,
+    pub body: String,
+}
+
+/// Templates per locale. The default locale must cover every kind; other locales may
+/// leave kinds out and fall back to it.
+#[derive(Debug, Clone)]
+pub struct NotificationTemplates {
+    default_locale: String,
+    templates: HashMap<String, HashMap<NotificationKind, NotificationTemplate>>,
+}
+
+impl NotificationTemplates {
+    pub fn new(
+        default_locale: String,
+        templates: HashMap<String, HashMap<NotificationKind, NotificationTemplate>>,
+    ) -> Result<Self, NotificationDomainError> {
+        let Some(defaults) = templates.get(&default_locale) else {
+            return Err(NotificationDomainError::Template {
+                message: format!("no templates for the default locale {default_locale}"),
+            });
+        };
+        if let Some(kind) = NotificationKind::ALL
+            .iter()
+            .find(|kind| !defaults.contains_key(kind))
+        {
+            return Err(NotificationDomainError::Template {
+                message: format!(
+                    "default locale {default_locale} has no {} template",
+                    kind.key()
+                ),
+            });
+        }
+
+        for (locale, by_kind) in &templates {
+            for (kind, template) in by_kind {
+                let texts = [
+                    Some(&template.subject),
+                    Some(&template.body),
+                    template.sms.as_ref(),
+                    template.push.as_ref(),
+                ];
+                for text in texts.into_iter().flatten() {
+                    check_placeholders(text, kind.variables()).map_err(|name| {
+                        NotificationDomainError::Template {
+                            message: format!(
+                                "{locale}/{}: unknown placeholder {{{{{name}}}}}",
+                                kind.key()
+                            ),
+                        }
+                    })?;
+                }
+            }
+        }
+
+        Ok(Self {
+            default_locale,
+            templates,
+        })
+    }
+
+    pub fn default_locale(&self) -> &str {
+        &self.default_locale
+    }
+
+    /// The locale messages for `requested` are written in: the exact tag, its language
+    /// (`pt` for `pt-BR`), or the default.
+    pub fn resolve_locale(&self, requested: Option<&str>) -> &str {
+        let Some(requested) = requested else {
+            return &self.default_locale;
+        };
+        let language = requested.split('-').next().unwrap_or(requested);
+        [requested, language]
+            .into_iter()
+            .find_map(|tag| {
+                self.templates
+                    .get_key_value(tag)
+                    .map(|(key, _)| key.as_str())
+            })
+            .unwrap_or(self.default_locale.as_str())
+    }
+
+    /// `None` when the kind has no text for the channel (SMS and push are optional).
+    pub fn render(
+        &self,
+        kind: NotificationKind,
+        channel: NotificationChannelKind,
+        locale: &str,
+        vars: &TemplateVars,
+    ) -> Option<RenderedMessage> {
+        let template = self
+            .templates
+            .get(locale)
+            .and_then(|by_kind| by_kind.get(&kind))
+            .or_else(|| {
+                self.templates
+                    .get(&self.default_locale)
+                    .and_then(|by_kind| by_kind.get(&kind))
+            })?;
+
+        match channel {
+            NotificationChannelKind::Email => Some(RenderedMessage {
+                subject: Some(substitute(&template.subject, vars)),
+                body: substitute(&template.body, vars),
+            }),
+            NotificationChannelKind::Sms => template.sms.as_ref().map(|text| RenderedMessage {
+                subject: None,
+                body: substitute(text, vars),
+            }),
+            NotificationChannelKind::Push => template.push.as_ref().map(|text| RenderedMessage {
+                subject: Some(substitute(&template.subject, vars)),
+                body: substitute(text, vars),
+            }),
+        }
+    }
+}
+
+/// Splits `text` into literal parts and placeholder names.
+fn tokens(text: &str) -> Vec<(&str, Option<&str>)> {
+    let mut tokens = Vec::new();
+    let mut rest = text;
+    while let Some(start) = rest.find("{{") {
+        let Some(len) = rest[start + 2..].find("}}") else {
+            break;
+        };
+        let name = rest[start + 2..start + 2 + len].trim();
+        tokens.push((&rest[..start], Some(name)));
+        rest = &rest[start + 2 + len + 2..];
+    }
+    tokens.push((rest, None));
+    tokens
+}
+
+fn check_placeholders<'a>(text: &'a str, allowed: &[&str]) -> Result<(), &'a str> {
+    match tokens(text)
+        .into_iter()
+        .filter_map(|(_, name)| name)
+        .find(|name| !allowed.contains(name))
+    {
+        Some(name) => Err(name),
+        None => Ok(()),
+    }
+}
+
+// Placeholders were checked when the templates were loaded; a value the caller left out
+// renders as an empty string.
+fn substitute(text: &str, vars: &TemplateVars) -> String {
+    let mut out = String::with_capacity(text.len());
+    for (literal, name) in tokens(text) {
+        out.push_str(literal);
+        if let Some(value) = name.and_then(|name| vars.get(name)) {
+            out.push_str(value);
+        }
+    }
+    out
+}
@@ -29,6 +29,8 @@ pub struct CreatePassengerProps {
 
     pub ff_airline_code: Option<String>,
     pub ff_number: Option<String>,
+
+    pub accompanying_adult_id: Option<i64>,
 }
 
 #[derive(Debug, Clone)]
@@ -43,10 +45,13 @@ pub struct UpdatePassengerProps {
 
     pub ff_airline_code: Option<String>,
     pub ff_number: Option<String>,
+
+    // `Some(None)` unlinks the accompanying adult
+    pub accompanying_adult_id: Option<Option<i64>>,
 }
 
 impl CreatePassengerProps {
-    pub fn validate(&self, today: &NaiveDate) -> Result<(), DomainError> {
+    pub fn validate(&self, today: &NaiveDate, travel_date: &NaiveDate) -> Result<(), DomainError> {
         if let Some(phone) = &self.phone_number {
             PhoneMustBeValid {
                 phone: phone.as_str(),
@@ -75,7 +80,7 @@ impl CreatePassengerProps {
 
         PassengerMustBeValidAge {
             dob: &self.dob,
-            today,
+            travel_date,
             passenger_type: self.passenger_type.clone(),
         }
         .check_broken()?;
@@ -108,6 +113,9 @@ pub struct Passenger {
     pub ff_airline_code: Option<String>,
     pub ff_number: Option<String>,
 
+    // infant on lap -> adult passenger on the same booking
+    pub accompanying_adult_id: Option<i64>,
+
     // for Optimistic locking
     pub version: i32,
 }
@@ -119,9 +127,28 @@ pub enum PassengerType {
     Infant,
 }
 
+impl PassengerType {
+    /// Infants travel on an adult's lap and do not take a seat from inventory.
+    pub fn occupies_seat(&self) -> bool {
+        !matches!(self, PassengerType::Infant)
+    }
+}
+
 impl Passenger {
-    pub fn new(props: CreatePassengerProps, today: NaiveDate) -> Result<Self, DomainError> {
-        props.validate(&today)?;
+    /// Seats a booking's passengers take from the flight's inventory.
+    pub fn occupied_seats(passengers: &[Passenger]) -> i32 {
+        passengers
+            .iter()
+            .filter(|p| p.passenger_type.occupies_seat())
+            .count() as i32
+    }
+
+    pub fn new(
+        props: CreatePassengerProps,
+        today: NaiveDate,
+        travel_date: NaiveDate,
+    ) -> Result<Self, DomainError> {
+        props.validate(&today, &travel_date)?;
 
         Ok(Self {
             id: 0,
@@ -141,6 +168,7 @@ impl Passenger {
             phone_number: props.phone_number,
             ff_airline_code: props.ff_airline_code,
             ff_number: props.ff_number,
+            accompanying_adult_id: props.accompanying_adult_id,
             title: props.title,
             version: 1,
         })
@@ -177,6 +205,62 @@ impl Passenger {
             self.ff_number = Some(ff_number);
         }
 
+        if let Some(adult_id) = props.accompanying_adult_id {
+            self.accompanying_adult_id = adult_id;
+        }
+
         Ok(())
     }
 }
+
+#[cfg(test)]
+pub(crate) mod tests {
+    use super::*;
+
+    // Also used by the passenger rule tests.
+    pub(crate) fn passenger(id: i64, passenger_type: PassengerType) -> Passenger {
+        Passenger {
+            id,
+            booking_id: 1,
+            passenger_type,
+            title: None,
+            first_name: "An".to_string(),
+            last_name: "Nguyen".to_string(),
+            dob: NaiveDate::from_ymd_opt(1990, 1, 1).unwrap(),
+            gender: "F".to_string(),
+            nationality_code: "VN".to_string(),
+            passport_no: None,
+            passport_expiry_date: None,
+            passport_issuing_country_code: None,
+            email: None,
+            phone_number: None,
+            ff_airline_code: None,
+            ff_number: None,
+            accompanying_adult_id: None,
+            version: 1,
+        }
+    }
+
+    #[test]
+    fn lap_infants_do_not_take_a_seat() {
+        assert!(PassengerType::Adult.occupies_seat());
+        assert!(PassengerType::Child.occupies_seat());
+        assert!(!PassengerType::Infant.occupies_seat());
+    }
+
+    #[test]
+    fn a_booking_holds_one_seat_per_adult_and_child() {
+        let passengers = [
+            passenger(1, PassengerType::Adult),
+            passenger(2, PassengerType::Adult),
+            passenger(3, PassengerType::Child),
+            passenger(4, PassengerType::Infant),
+        ];
+
+        assert_eq!(Passenger::occupied_seats(&passengers), 3);
+        assert_eq!(
+            Passenger::occupied_seats(&[passenger(1, PassengerType::Infant)]),
+            0
+        );
+    }
+}
@@ -45,6 +45,10 @@ pub enum PassengerDomainError {
         message: String,
     },
 
+    // unique violation that cannot be traced to a field
+    #[error("duplicate passenger: {message}")]
+    Duplicate { message: String },
+
     // ===== Not found =====
     #[error("passenger not found: {passenger_id}")]
     PassengerNotFound { passenger_id: i64 },
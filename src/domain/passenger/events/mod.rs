@@ -1,3 +1,4 @@
 pub mod passenger_added;
 pub mod passenger_removed;
 pub mod passenger_updated;
+pub mod passenger_no_show;
@@ -0,0 +1,39 @@
+use crate::domain::booking::no_show::NoShow;
+use chrono::NaiveDateTime;
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PassengerNoShowEvent {
+    pub no_show_id: i64,
+    pub passenger_id: i64,
+    pub booking_id: i64,
+    pub booking_code: String,
+    pub flight_id: i64,
+    pub fare_family: String,
+    // Decimal amounts as strings so revenue accounting never sees a rounded float.
+    pub fare_amount: String,
+    pub penalty_amount: String,
+    pub currency: String,
+    pub occurred_at: NaiveDateTime,
+}
+
+impl PassengerNoShowEvent {
+    pub fn new(no_show: &NoShow, booking_code: String, fare_family: String) -> Self {
+        Self {
+            no_show_id: no_show.id,
+            passenger_id: no_show.passenger_id,
+            booking_id: no_show.booking_id,
+            booking_code,
+            flight_id: no_show.flight_id,
+            fare_family,
+            fare_amount: no_show.fare_amount.to_string(),
+            penalty_amount: no_show.penalty_amount.to_string(),
+            currency: no_show.currency.clone(),
+            occurred_at: no_show.recorded_at,
+        }
+    }
+
+    pub fn topic_name() -> &'static str {
+        "passenger.no_show"
+    }
+}
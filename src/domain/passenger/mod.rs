@@ -2,4 +2,5 @@ pub mod events;
 pub mod rules;
 pub mod entity;
 pub mod passenger_repository_interface;
-pub mod error;
\ No newline at end of file
+pub mod error;
+pub mod travel_document_policy;
\ No newline at end of file
@@ -4,6 +4,13 @@ use crate::domain::passenger::entity::Passenger;
 #[async_trait::async_trait]
 pub trait PassengerRepositoryInterface: Send + Sync {
     async fn create_passenger(&self, passenger: &Passenger) -> Result<i64, DomainError>;
+    /// Inserts the passenger and, when `seat_flight_id` is set, takes one seat on that flight
+    /// in the same transaction.
+    async fn add_passenger(
+        &self,
+        passenger: &Passenger,
+        seat_flight_id: Option<i64>,
+    ) -> Result<i64, DomainError>;
     async fn create_passengers(&self, passengers: &[Passenger]) -> Result<Vec<i64>, DomainError>;
     async fn update_passenger(
         &self,
@@ -21,6 +28,13 @@ pub trait PassengerRepositoryInterface: Send + Sync {
         booking_id: i64,
     ) -> Result<Vec<Passenger>, DomainError>;
     async fn delete_passenger_by_id(&self, id: i64) -> Result<(), DomainError>;
+    /// Deletes the passenger and, when `seat_flight_id` is set, releases its seat on that flight
+    /// in the same transaction.
+    async fn remove_passenger(
+        &self,
+        id: i64,
+        seat_flight_id: Option<i64>,
+    ) -> Result<(), DomainError>;
 
     async fn delete_passengers_by_booking(&self, booking_id: i64) -> Result<(), DomainError>;
 }
@@ -0,0 +1,118 @@
+use crate::domain::business_rule_interface::BusinessRuleInterface;
+use crate::domain::error::DomainError;
+use crate::domain::passenger::entity::{Passenger, PassengerType};
+use crate::domain::passenger::error::PassengerDomainError;
+
+pub struct InfantMustReferenceDistinctAdult<'a> {
+    // 0 when the passenger is not persisted yet
+    pub passenger_id: i64,
+    pub passenger_type: &'a PassengerType,
+    pub accompanying_adult_id: Option<i64>,
+    pub booking_passengers: &'a [Passenger],
+}
+
+impl<'a> BusinessRuleInterface for InfantMustReferenceDistinctAdult<'a> {
+    fn check_broken(&self) -> Result<(), DomainError> {
+        if *self.passenger_type != PassengerType::Infant {
+            if self.accompanying_adult_id.is_some() {
+                return Err(PassengerDomainError::Validation {
+                    field: "accompanying_adult_id",
+                    message: "Only infants can reference an accompanying adult".to_string(),
+                }
+                .into());
+            }
+            return Ok(());
+        }
+
+        let adult_id = self.accompanying_adult_id.ok_or_else(|| {
+            PassengerDomainError::Validation {
+                field: "accompanying_adult_id",
+                message: "Infant must reference an accompanying adult".to_string(),
+            }
+        })?;
+
+        let adult_on_booking = self
+            .booking_passengers
+            .iter()
+            .any(|p| p.id == adult_id && p.passenger_type == PassengerType::Adult);
+        if !adult_on_booking {
+            return Err(PassengerDomainError::Validation {
+                field: "accompanying_adult_id",
+                message: "Accompanying adult must be an adult passenger on the same booking"
+                    .to_string(),
+            }
+            .into());
+        }
+
+        let already_taken = self.booking_passengers.iter().any(|p| {
+            p.id != self.passenger_id
+                && p.passenger_type == PassengerType::Infant
+                && p.accompanying_adult_id == Some(adult_id)
+        });
+        if already_taken {
+            return Err(PassengerDomainError::BusinessRule {
+                message: format!("Adult passenger {adult_id} already accompanies another infant"),
+            }
+            .into());
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::passenger::entity::tests::passenger;
+
+    fn infant_of(id: i64, adult_id: i64) -> Passenger {
+        let mut infant = passenger(id, PassengerType::Infant);
+        infant.accompanying_adult_id = Some(adult_id);
+        infant
+    }
+
+    fn check(
+        passenger_id: i64,
+        passenger_type: &PassengerType,
+        accompanying_adult_id: Option<i64>,
+        booking_passengers: &[Passenger],
+    ) -> Result<(), DomainError> {
+        InfantMustReferenceDistinctAdult {
+            passenger_id,
+            passenger_type,
+            accompanying_adult_id,
+            booking_passengers,
+        }
+        .check_broken()
+    }
+
+    #[test]
+    fn an_infant_sits_on_an_adult_of_the_same_booking() {
+        let booking = [
+            passenger(1, PassengerType::Adult),
+            passenger(2, PassengerType::Child),
+        ];
+
+        assert!(check(0, &PassengerType::Infant, Some(1), &booking).is_ok());
+        assert!(check(0, &PassengerType::Infant, None, &booking).is_err());
+        assert!(check(0, &PassengerType::Infant, Some(2), &booking).is_err());
+        assert!(check(0, &PassengerType::Infant, Some(9), &booking).is_err());
+    }
+
+    #[test]
+    fn an_adult_accompanies_one_infant() {
+        let booking = [passenger(1, PassengerType::Adult), infant_of(2, 1)];
+
+        assert!(check(0, &PassengerType::Infant, Some(1), &booking).is_err());
+        // re-validating the infant already linked is not a clash with itself
+        assert!(check(2, &PassengerType::Infant, Some(1), &booking).is_ok());
+    }
+
+    #[test]
+    fn only_infants_reference_an_adult() {
+        let booking = [passenger(1, PassengerType::Adult)];
+
+        assert!(check(0, &PassengerType::Child, Some(1), &booking).is_err());
+        assert!(check(0, &PassengerType::Child, None, &booking).is_ok());
+    }
+}
@@ -0,0 +1,70 @@
+use crate::domain::business_rule_interface::BusinessRuleInterface;
+use crate::domain::error::DomainError;
+use crate::domain::passenger::entity::{Passenger, PassengerType};
+use crate::domain::passenger::error::PassengerDomainError;
+
+pub struct MinorsMustBeAccompanied<'a> {
+    pub passengers: &'a [Passenger],
+}
+
+impl<'a> BusinessRuleInterface for MinorsMustBeAccompanied<'a> {
+    fn check_broken(&self) -> Result<(), DomainError> {
+        let has_adult = self
+            .passengers
+            .iter()
+            .any(|p| p.passenger_type == PassengerType::Adult);
+        let has_minor = self
+            .passengers
+            .iter()
+            .any(|p| p.passenger_type != PassengerType::Adult);
+
+        if has_minor && !has_adult {
+            return Err(PassengerDomainError::BusinessRule {
+                message: "Unaccompanied minors are not accepted; booking requires at least one adult"
+                    .to_string(),
+            }
+            .into());
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::passenger::entity::tests::passenger;
+
+    fn check(types: &[PassengerType]) -> Result<(), DomainError> {
+        let passengers: Vec<Passenger> = types
+            .iter()
+            .enumerate()
+            .map(|(i, t)| passenger(i as i64 + 1, t.clone()))
+            .collect();
+        MinorsMustBeAccompanied {
+            passengers: &passengers,
+        }
+        .check_broken()
+    }
+
+    #[test]
+    fn minors_travel_with_an_adult() {
+        assert!(check(&[PassengerType::Adult]).is_ok());
+        assert!(check(&[PassengerType::Adult, PassengerType::Child]).is_ok());
+        assert!(
+            check(&[
+                PassengerType::Adult,
+                PassengerType::Child,
+                PassengerType::Infant
+            ])
+            .is_ok()
+        );
+        assert!(check(&[]).is_ok());
+    }
+
+    #[test]
+    fn minors_without_an_adult_are_rejected() {
+        assert!(check(&[PassengerType::Child]).is_err());
+        assert!(check(&[PassengerType::Infant]).is_err());
+        assert!(check(&[PassengerType::Child, PassengerType::Infant]).is_err());
+    }
+}
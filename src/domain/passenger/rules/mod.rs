@@ -2,4 +2,8 @@ pub mod passenger_must_be_valid_age;
 pub mod phone_must_be_valid;
 pub mod email_must_be_valid;
 pub mod passenger_name_must_be_valid;
-pub mod passenger_dob_must_not_be_future;
\ No newline at end of file
+pub mod passenger_dob_must_not_be_future;
+pub mod passenger_count_must_not_exceed_limit;
+pub mod infant_must_reference_distinct_adult;
+pub mod minors_must_be_accompanied;
+pub mod travel_documents_must_satisfy_requirements;
\ No newline at end of file
@@ -0,0 +1,23 @@
+use crate::domain::business_rule_interface::BusinessRuleInterface;
+use crate::domain::error::DomainError;
+use crate::domain::passenger::error::PassengerDomainError;
+
+pub struct PassengerCountMustNotExceedLimit {
+    pub current_count: usize,
+    pub max_count: usize,
+}
+
+impl BusinessRuleInterface for PassengerCountMustNotExceedLimit {
+    fn check_broken(&self) -> Result<(), DomainError> {
+        if self.current_count >= self.max_count {
+            return Err(PassengerDomainError::BusinessRule {
+                message: format!(
+                    "Booking cannot have more than {} passengers",
+                    self.max_count
+                ),
+            }
+            .into());
+        }
+        Ok(())
+    }
+}
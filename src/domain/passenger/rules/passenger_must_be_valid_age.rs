@@ -4,15 +4,16 @@ use crate::domain::passenger::entity::PassengerType;
 use crate::domain::passenger::error::PassengerDomainError;
 use chrono::NaiveDate;
 
+/// Age bands are evaluated on the travel date, not on the booking date.
 pub struct PassengerMustBeValidAge<'a> {
     pub dob: &'a NaiveDate,
-    pub today: &'a NaiveDate,
+    pub travel_date: &'a NaiveDate,
     pub passenger_type: PassengerType,
 }
 
 impl<'a> BusinessRuleInterface for PassengerMustBeValidAge<'a> {
     fn check_broken(&self) -> Result<(), DomainError> {
-        let age = (*self.today - *self.dob).num_days() / 365;
+        let age = self.travel_date.years_since(*self.dob).unwrap_or(0);
 
         match self.passenger_type {
             PassengerType::Adult if age < 12 => Err(PassengerDomainError::BusinessRule {
@@ -0,0 +1,72 @@
+use crate::domain::business_rule_interface::BusinessRuleInterface;
+use crate::domain::error::DomainError;
+use crate::domain::passenger::entity::Passenger;
+use crate::domain::passenger::error::PassengerDomainError;
+use crate::domain::passenger::travel_document_policy::DocumentRequirements;
+
+pub struct TravelDocumentsMustSatisfyRequirements<'a> {
+    pub passenger: &'a Passenger,
+    pub requirements: &'a DocumentRequirements,
+    pub has_valid_visa: bool,
+}
+
+impl<'a> BusinessRuleInterface for TravelDocumentsMustSatisfyRequirements<'a> {
+    fn check_broken(&self) -> Result<(), DomainError> {
+        if self.requirements.passport_required {
+            let passport_no = self.passenger.passport_no.as_deref().unwrap_or("").trim();
+            if passport_no.is_empty() {
+                return Err(PassengerDomainError::Validation {
+                    field: "passport_no",
+                    message: "Passport is required for this route".to_string(),
+                }
+                .into());
+            }
+
+            let issuing_country = self
+                .passenger
+                .passport_issuing_country_code
+                .as_deref()
+                .unwrap_or("")
+                .trim();
+            if issuing_country.is_empty() {
+                return Err(PassengerDomainError::Validation {
+                    field: "passport_issuing_country_code",
+                    message: "Passport issuing country is required for this route".to_string(),
+                }
+                .into());
+            }
+
+            let expiry_date = self.passenger.passport_expiry_date.ok_or_else(|| {
+                PassengerDomainError::Validation {
+                    field: "passport_expiry_date",
+                    message: "Passport expiry date is required for this route".to_string(),
+                }
+            })?;
+
+            if let Some(valid_until) = self.requirements.passport_valid_until {
+                if expiry_date < valid_until {
+                    return Err(PassengerDomainError::Validation {
+                        field: "passport_expiry_date",
+                        message: format!(
+                            "Passport expires on {expiry_date} but must be valid until at least {valid_until}"
+                        ),
+                    }
+                    .into());
+                }
+            }
+        }
+
+        if self.requirements.visa_required && !self.has_valid_visa {
+            return Err(PassengerDomainError::Validation {
+                field: "has_valid_visa",
+                message: format!(
+                    "A visa is required for nationality {} on this route; check in at the airport so staff can verify it",
+                    self.passenger.nationality_code
+                ),
+            }
+            .into());
+        }
+
+        Ok(())
+    }
+}
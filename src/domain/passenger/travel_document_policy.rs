@@ -0,0 +1,96 @@
+use chrono::{Months, NaiveDate};
+
+#[derive(Debug, Clone)]
+pub struct VisaRequirement {
+    pub nationality_code: String,
+    pub destination_country_code: String,
+}
+
+#[derive(Debug, Clone)]
+pub struct TravelDocumentPolicy {
+    pub passport_min_validity_months: u32,
+    // countries whose nationals can travel between each other without a passport
+    pub passport_free_zones: Vec<Vec<String>>,
+    pub visa_requirements: Vec<VisaRequirement>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DocumentRequirements {
+    pub passport_required: bool,
+    pub passport_valid_until: Option<NaiveDate>,
+    pub visa_required: bool,
+}
+
+impl Default for TravelDocumentPolicy {
+    fn default() -> Self {
+        Self {
+            passport_min_validity_months: 6,
+            passport_free_zones: Vec::new(),
+            visa_requirements: Vec::new(),
+        }
+    }
+}
+
+impl TravelDocumentPolicy {
+    pub fn requirements_for(
+        &self,
+        nationality_code: &str,
+        origin_country_code: &str,
+        destination_country_code: &str,
+        travel_date: NaiveDate,
+    ) -> DocumentRequirements {
+        let nationality = nationality_code.trim().to_uppercase();
+        let origin = origin_country_code.trim().to_uppercase();
+        let destination = destination_country_code.trim().to_uppercase();
+
+        let domestic = origin == destination;
+
+        // Nationals flying domestically travel on a national ID.
+        if domestic && nationality == origin {
+            return DocumentRequirements {
+                passport_required: false,
+                passport_valid_until: None,
+                visa_required: false,
+            };
+        }
+
+        if self.same_passport_free_zone(&[&nationality, &origin, &destination]) {
+            return DocumentRequirements {
+                passport_required: false,
+                passport_valid_until: None,
+                visa_required: false,
+            };
+        }
+
+        // Foreign nationals on a domestic leg only need an unexpired passport as ID;
+        // the validity margin applies to border crossings.
+        let passport_valid_until = if domestic {
+            travel_date
+        } else {
+            travel_date
+                .checked_add_months(Months::new(self.passport_min_validity_months))
+                .unwrap_or(travel_date)
+        };
+
+        let visa_required = !domestic
+            && nationality != destination
+            && self.visa_requirements.iter().any(|v| {
+                v.nationality_code.eq_ignore_ascii_case(&nationality)
+                    && v.destination_country_code.eq_ignore_ascii_case(&destination)
+            });
+
+        DocumentRequirements {
+            passport_required: true,
+            passport_valid_until: Some(passport_valid_until),
+            visa_required,
+        }
+    }
+
+    fn same_passport_free_zone(&self, country_codes: &[&str]) -> bool {
+        self.passport_free_zones.iter().any(|zone| {
+            country_codes
+                .iter()
+                .all(|code| zone.iter().any(|c| c.eq_ignore_ascii_case(code)))
+        })
+    }
+}
@@ -0,0 +1,127 @@
+use crate::domain::airport::entity::Airport;
+use crate::domain::business_rule_interface::BusinessRuleInterface;
+use crate::domain::error::DomainError;
+use crate::domain::route::error::RouteDomainError;
+use crate::domain::route::rules::block_time_must_be_valid::BlockTimeMustBeValid;
+use crate::domain::route::rules::route_must_connect_distinct_airports::RouteMustConnectDistinctAirports;
+
+#[derive(Debug, Clone)]
+pub struct CreateRouteProps {
+    pub origin_airport_id: i64,
+    pub destination_airport_id: i64,
+    pub distance_km: i32,
+    pub block_time_minutes: i32,
+    pub is_domestic: bool,
+}
+
+#[derive(Debug, Clone)]
+pub struct UpdateRouteProps {
+    pub block_time_minutes: Option<i32>,
+    pub is_active: Option<bool>,
+}
+
+impl CreateRouteProps {
+    pub fn validate(&self) -> Result<(), DomainError> {
+        RouteMustConnectDistinctAirports {
+            origin_airport_id: self.origin_airport_id,
+            destination_airport_id: self.destination_airport_id,
+        }
+        .check_broken()?;
+        BlockTimeMustBeValid {
+            block_time_minutes: self.block_time_minutes,
+        }
+        .check_broken()?;
+        Ok(())
+    }
+}
+
+/// A directional airport pair; the return leg is its own route, since block times
+/// differ by direction.
+#[derive(Debug, Clone)]
+pub struct Route {
+    pub id: i64,
+    pub origin_airport_id: i64,
+    pub destination_airport_id: i64,
+    // Great-circle distance, rounded to the kilometre.
+    pub distance_km: i32,
+    pub block_time_minutes: i32,
+    pub is_domestic: bool,
+    pub is_active: bool,
+}
+
+impl Route {
+    pub fn new(props: CreateRouteProps) -> Result<Self, DomainError> {
+        props.validate()?;
+
+        Ok(Self {
+            id: 0,
+            origin_airport_id: props.origin_airport_id,
+            destination_airport_id: props.destination_airport_id,
+            distance_km: props.distance_km,
+            block_time_minutes: props.block_time_minutes,
+            is_domestic: props.is_domestic,
+            is_active: true,
+        })
+    }
+
+    /// Opens a route between two airports, measuring it from their coordinates.
+    pub fn between(
+        origin: &Airport,
+        destination: &Airport,
+        block_time_minutes: i32,
+    ) -> Result<Self, DomainError> {
+        Self::new(CreateRouteProps {
+            origin_airport_id: origin.id,
+            destination_airport_id: destination.id,
+            distance_km: Self::measure(origin, destination)?,
+            block_time_minutes,
+            is_domestic: origin.country_code == destination.country_code,
+        })
+    }
+
+    pub fn update(&mut self, props: UpdateRouteProps) -> Result<(), DomainError> {
+        if let Some(block_time_minutes) = props.block_time_minutes {
+            BlockTimeMustBeValid { block_time_minutes }.check_broken()?;
+            self.block_time_minutes = block_time_minutes;
+        }
+        if let Some(is_active) = props.is_active {
+            self.is_active = is_active;
+        }
+        Ok(())
+    }
+
+    /// Re-derives distance and the domestic flag after airport data changed.
+    pub fn remeasure(
+        &mut self,
+        origin: &Airport,
+        destination: &Airport,
+    ) -> Result<(), DomainError> {
+        self.distance_km = Self::measure(origin, destination)?;
+        self.is_domestic = origin.country_code == destination.country_code;
+        Ok(())
+    }
+
+    /// Only active routes take new flights.
+    pub fn ensure_active(&self, origin: &str, destination: &str) -> Result<(), DomainError> {
+        if !self.is_active {
+            return Err(RouteDomainError::RouteInactive {
+                origin: origin.to_string(),
+                destination: destination.to_string(),
+            }
+            .into());
+        }
+        Ok(())
+    }
+
+    fn measure(origin: &Airport, destination: &Airport) -> Result<i32, DomainError> {
+        let location = |airport: &Airport| {
+            airport.location().ok_or_else(|| {
+                DomainError::from(RouteDomainError::AirportWithoutCoordinates {
+                    iata_code: airport.iata_code.clone(),
+                })
+            })
+        };
+        let distance_km = location(origin)?.distance_km(&location(destination)?);
+        Ok(distance_km.round() as i32)
+    }
+}
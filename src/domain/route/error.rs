@@ -0,0 +1,34 @@
+use thiserror::Error;
+
+#[derive(Debug, Error)]
+pub enum RouteDomainError {
+    #[error("validation error: {field} - {message}")]
+    Validation {
+        field: &'static str,
+        message: String,
+    },
+
+    #[error("business rule violated: {message}")]
+    BusinessRule { message: String },
+
+    #[error("unauthorized: {message}")]
+    Unauthorized { message: String },
+
+    #[error("conflict: {field} - {message}")]
+    Conflict {
+        field: &'static str,
+        message: String,
+    },
+
+    #[error("route is not active: {origin}-{destination}")]
+    RouteInactive { origin: String, destination: String },
+
+    #[error("airport has no coordinates: {iata_code}")]
+    AirportWithoutCoordinates { iata_code: String },
+
+    #[error("internal error: {0}")]
+    Internal(String),
+
+    #[error("Entity not found: {detail}")]
+    NotFound { detail: String },
+}
@@ -0,0 +1,4 @@
+pub mod rules;
+pub mod entity;
+pub mod route_repository_interface;
+pub mod error;
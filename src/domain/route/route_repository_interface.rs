@@ -0,0 +1,20 @@
+use crate::domain::error::DomainError;
+use crate::domain::route::entity::Route;
+
+#[async_trait::async_trait]
+pub trait RouteRepositoryInterface: Send + Sync {
+    async fn create_route(&self, route: &Route) -> Result<i64, DomainError>;
+    async fn update_route(&self, route: &Route) -> Result<(), DomainError>;
+    async fn find_route_by_id(&self, id: i64) -> Result<Option<Route>, DomainError>;
+    async fn find_route_by_airports(
+        &self,
+        origin_airport_id: i64,
+        destination_airport_id: i64,
+    ) -> Result<Option<Route>, DomainError>;
+    async fn list_routes(
+        &self,
+        origin_airport_id: Option<i64>,
+        destination_airport_id: Option<i64>,
+        active_only: bool,
+    ) -> Result<Vec<Route>, DomainError>;
+}
@@ -0,0 +1,24 @@
+use crate::domain::business_rule_interface::BusinessRuleInterface;
+use crate::domain::error::DomainError;
+use crate::domain::route::error::RouteDomainError;
+
+// Longer than any scheduled nonstop.
+const MAX_BLOCK_TIME_MINUTES: i32 = 24 * 60;
+
+/// Block time is gate to gate, in whole minutes.
+pub struct BlockTimeMustBeValid {
+    pub block_time_minutes: i32,
+}
+
+impl BusinessRuleInterface for BlockTimeMustBeValid {
+    fn check_broken(&self) -> Result<(), DomainError> {
+        if !(1..=MAX_BLOCK_TIME_MINUTES).contains(&self.block_time_minutes) {
+            return Err(RouteDomainError::Validation {
+                field: "block_time_minutes",
+                message: format!("Must be between 1 and {MAX_BLOCK_TIME_MINUTES} minutes"),
+            }
+            .into());
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,2 @@
+pub mod block_time_must_be_valid;
+pub mod route_must_connect_distinct_airports;
@@ -0,0 +1,21 @@
+use crate::domain::business_rule_interface::BusinessRuleInterface;
+use crate::domain::error::DomainError;
+use crate::domain::route::error::RouteDomainError;
+
+pub struct RouteMustConnectDistinctAirports {
+    pub origin_airport_id: i64,
+    pub destination_airport_id: i64,
+}
+
+impl BusinessRuleInterface for RouteMustConnectDistinctAirports {
+    fn check_broken(&self) -> Result<(), DomainError> {
+        if self.origin_airport_id == self.destination_airport_id {
+            return Err(RouteDomainError::Validation {
+                field: "destination_airport_id",
+                message: "Origin and destination must be different airports".to_string(),
+            }
+            .into());
+        }
+        Ok(())
+    }
+}
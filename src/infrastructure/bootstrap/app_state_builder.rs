@@ -9,22 +9,49 @@ use crate::infrastructure::runtime::gateway_registry::build_gateway_registry;
 
 // Application services
 use crate::application::address::use_case::address_service::AddressService;
+use crate::application::aircraft::use_case::aircraft_service::AircraftService;
+use crate::application::airline::use_case::airline_service::AirlineService;
 use crate::application::airport::use_case::airport_service::AirportService;
 use crate::application::auth::use_case::auth_service::AuthService;
+use crate::application::baggage::use_case::baggage_service::BaggageService;
+use crate::application::boarding::use_case::boarding_service::BoardingService;
 use crate::application::boarding_pass::use_case::boarding_pass_service::BoardingPassService;
 use crate::application::booking::use_case::booking_service::BookingService;
 use crate::application::checkin::use_case::checkin_service::CheckinService;
+use crate::application::compensation::use_case::compensation_service::CompensationService;
+use crate::application::dead_letter::use_case::dead_letter_service::DeadLetterService;
 use crate::application::flight::use_case::flight_service::FlightService;
+use crate::application::flight_status::use_case::flight_status_service::FlightStatusService;
+use crate::application::manifest::use_case::manifest_service::ManifestService;
+use crate::application::no_show::use_case::no_show_service::NoShowService;
+use crate::application::notification::use_case::notification_service::NotificationService;
 use crate::application::passenger::use_case::passenger_service::PassengerService;
+use crate::application::route::use_case::route_service::RouteService;
 use crate::application::user::use_case::user_service::UserService;
 
 // Infrastructure
+use crate::infrastructure::bootstrap::dead_letter::start_dead_letter_archiver;
+use crate::infrastructure::bootstrap::flight_status::{
+    build_flight_event_publisher, start_flight_status_listener,
+};
+use crate::infrastructure::bootstrap::kafka::build_kafka_broker;
+use crate::infrastructure::bootstrap::notification::{
+    build_notification_channels, build_notification_retry_policy, build_notification_templates,
+    notification_consumer_group, start_notification_workers,
+};
 use crate::infrastructure::bootstrap::{
-    cache::build_cache, database::build_database, event_publishers::build_event_publishers,
-    jwt::build_token_service, kafka::build_kafka_producer, redis::build_redis,
-    repositories::build_repositories,
+    bag_tag_issuer::build_bag_tag_issuer, baggage_allowance_policy::build_baggage_allowance_policy,
+    boarding_group_policy::build_boarding_group_policy,
+    boarding_pass_renderer::build_boarding_pass_renderer,
+    boarding_pass_signer::build_boarding_pass_signer, cache::build_cache,
+    compensation_policy::build_compensation_policy, country_time_zones::build_country_time_zones,
+    database::build_database, event_publishers::build_event_publishers, jwt::build_token_service,
+    kafka::build_kafka_producer, no_show_policy::build_no_show_penalty_policy, redis::build_redis,
+    repositories::build_repositories, travel_document::build_travel_document_policy,
+    wallet_pass_generator::build_wallet_pass_generator,
 };
 use crate::infrastructure::context::axum_request_context_provider::AxumRequestContextProvider;
+use crate::infrastructure::messaging::kafka::consumer::dead_letter_replayer::BrokerDeadLetterReplayer;
 use crate::infrastructure::runtime::config::CONFIG;
 use crate::infrastructure::security::argon2_password_hasher::Argon2PasswordHasher;
 
@@ -50,6 +77,20 @@ impl AppStateBuilder {
         let token_service = build_token_service(&config)?;
         let password_hasher = Arc::new(Argon2PasswordHasher);
         let events = build_event_publishers(kafka_producer.clone());
+        let kafka_broker = build_kafka_broker(&config, kafka_producer.clone());
+        let travel_document_policy = build_travel_document_policy(&config);
+        let boarding_group_policy = build_boarding_group_policy(&config)?;
+        let no_show_penalty_policy = build_no_show_penalty_policy(&config)?;
+        let baggage_allowance_policy = build_baggage_allowance_policy(&config)?;
+        let bag_tag_issuer = build_bag_tag_issuer(&config)?;
+        let boarding_pass_signer = build_boarding_pass_signer(&config)?;
+        let boarding_pass_renderer = build_boarding_pass_renderer(&config)?;
+        let wallet_pass_generator = build_wallet_pass_generator(&config)?;
+        let country_time_zones = build_country_time_zones(&config)?;
+        let compensation_policy = build_compensation_policy(&config)?;
+        let notification_templates = build_notification_templates(&config)?;
+        let notification_channels = build_notification_channels(&config)?;
+        let notification_retry_policy = build_notification_retry_policy(&config)?;
 
         // -------- application services --------
         let auth_service = Arc::new(AuthService::new(
@@ -77,20 +118,49 @@ impl AppStateBuilder {
         let airport_service = Arc::new(AirportService::new(
             cache.clone(),
             repos.airport.clone(),
+            country_time_zones,
             events.airport.clone(),
         ));
 
+        let airline_service = Arc::new(AirlineService::new(
+            cache.clone(),
+            repos.airline.clone(),
+            events.airline.clone(),
+        ));
+
+        let aircraft_service = Arc::new(AircraftService::new(
+            cache.clone(),
+            repos.aircraft.clone(),
+            repos.airline.clone(),
+        ));
+
+        let route_service = Arc::new(RouteService::new(
+            cache.clone(),
+            repos.route.clone(),
+            repos.airport.clone(),
+        ));
+
         let flight_service = Arc::new(FlightService::new(
             cache.clone(),
             repos.flight.clone(),
             repos.airport.clone(),
-            events.flight.clone(),
+            repos.airline.clone(),
+            repos.aircraft.clone(),
+            repos.route.clone(),
+            build_flight_event_publisher(events.flight.clone(), redis.clone()),
+        ));
+
+        let flight_status_service = Arc::new(FlightStatusService::new(
+            repos.flight.clone(),
+            repos.booking.clone(),
         ));
+        start_flight_status_listener(&config, flight_status_service.clone())?;
 
         let booking_service = Arc::new(BookingService::new(
             cache.clone(),
             repos.booking.clone(),
             repos.flight.clone(),
+            repos.passenger.clone(),
             repos.user.clone(),
             events.booking.clone(),
         ));
@@ -99,7 +169,9 @@ impl AppStateBuilder {
             cache.clone(),
             repos.passenger.clone(),
             repos.booking.clone(),
+            repos.flight.clone(),
             events.passenger.clone(),
+            config.booking.max_passengers_per_booking,
         ));
 
         let checkin_service = Arc::new(CheckinService::new(
@@ -109,18 +181,105 @@ impl AppStateBuilder {
             repos.flight.clone(),
             repos.passenger.clone(),
             repos.boarding_pass.clone(),
+            repos.airport.clone(),
+            travel_document_policy.clone(),
+            boarding_group_policy.clone(),
+            baggage_allowance_policy.clone(),
+            boarding_pass_signer.clone(),
             events.checkin.clone(),
         ));
 
+        let boarding_service = Arc::new(BoardingService::new(
+            cache.clone(),
+            repos.flight.clone(),
+            repos.booking.clone(),
+            repos.passenger.clone(),
+            repos.checkin.clone(),
+            repos.boarding_pass.clone(),
+            boarding_pass_signer.clone(),
+        ));
+
+        let no_show_service = Arc::new(NoShowService::new(
+            cache.clone(),
+            repos.flight.clone(),
+            repos.booking.clone(),
+            repos.passenger.clone(),
+            repos.checkin.clone(),
+            repos.no_show.clone(),
+            no_show_penalty_policy,
+            events.passenger.clone(),
+        ));
+
         let boarding_pass_service = Arc::new(BoardingPassService::new(
             cache,
             repos.boarding_pass.clone(),
             repos.checkin.clone(),
             repos.booking.clone(),
             repos.passenger.clone(),
+            repos.flight.clone(),
+            repos.airport.clone(),
+            boarding_group_policy,
+            boarding_pass_signer,
+            boarding_pass_renderer,
+            wallet_pass_generator,
             events.boarding_pass.clone(),
         ));
 
+        let manifest_service = Arc::new(ManifestService::new(
+            repos.flight.clone(),
+            repos.airport.clone(),
+            repos.booking.clone(),
+            repos.passenger.clone(),
+            repos.checkin.clone(),
+            travel_document_policy,
+        ));
+
+        let baggage_service = Arc::new(BaggageService::new(
+            repos.bag.clone(),
+            repos.checkin.clone(),
+            repos.booking.clone(),
+            repos.flight.clone(),
+            repos.airport.clone(),
+            bag_tag_issuer,
+            events.baggage.clone(),
+        ));
+
+        let compensation_service = Arc::new(CompensationService::new(
+            repos.compensation_claim.clone(),
+            repos.booking.clone(),
+            repos.flight.clone(),
+            repos.passenger.clone(),
+            repos.airport.clone(),
+            repos.airline.clone(),
+            repos.route.clone(),
+            compensation_policy,
+            events.compensation.clone(),
+        ));
+
+        let notification_service = Arc::new(NotificationService::new(
+            repos.notification.clone(),
+            repos.notification_preference.clone(),
+            repos.booking.clone(),
+            repos.flight.clone(),
+            repos.airport.clone(),
+            notification_templates,
+            notification_channels,
+            notification_retry_policy,
+            config.notification.verification_url.clone(),
+        ));
+        start_notification_workers(&config, kafka_broker.as_ref(), notification_service.clone())?;
+
+        let dead_letter_service = Arc::new(DeadLetterService::new(
+            repos.dead_letter.clone(),
+            Arc::new(BrokerDeadLetterReplayer::new(kafka_broker.sink())),
+        ));
+        start_dead_letter_archiver(
+            &config,
+            kafka_broker.as_ref(),
+            &[notification_consumer_group(&config)],
+            dead_letter_service.clone(),
+        )?;
+
         let state = AppState {
             db,
             deploy_mode,
@@ -130,11 +289,22 @@ impl AppStateBuilder {
             auth_service,
             address_service,
             airport_service,
+            airline_service,
+            aircraft_service,
+            route_service,
             flight_service,
+            flight_status_service,
             booking_service,
             passenger_service,
             checkin_service,
             boarding_pass_service,
+            boarding_service,
+            manifest_service,
+            no_show_service,
+            baggage_service,
+            compensation_service,
+            notification_service,
+            dead_letter_service,
         };
         let addr = config.server.get_socket_addr()?;
 
@@ -0,0 +1,33 @@
+use std::sync::Arc;
+
+use crate::core::configure::app::AppConfig;
+use crate::domain::baggage::bag_tag_issuer::BagTagIssuer;
+use crate::infrastructure::error::{TechnicalError, TechnicalResult};
+
+pub fn build_bag_tag_issuer(config: &AppConfig) -> TechnicalResult<Arc<BagTagIssuer>> {
+    let cfg = &config.checkin.baggage;
+
+    let issuer_codes = cfg
+        .tag_issuer_codes
+        .iter()
+        .map(|(airline, code)| {
+            let airline = airline.trim().to_uppercase();
+            if airline.len() != 2 || !airline.chars().all(|c| c.is_ascii_alphanumeric()) {
+                return Err(TechnicalError::InvalidConfig(format!(
+                    "checkin.baggage.tag_issuer_codes: invalid airline code {airline}"
+                )));
+            }
+
+            let code = code.trim().to_string();
+            if code.len() != 3 || !code.chars().all(|c| c.is_ascii_digit()) {
+                return Err(TechnicalError::InvalidConfig(format!(
+                    "checkin.baggage.tag_issuer_codes.{airline}: issuer code must be 3 digits"
+                )));
+            }
+
+            Ok((airline, code))
+        })
+        .collect::<TechnicalResult<Vec<_>>>()?;
+
+    Ok(Arc::new(BagTagIssuer { issuer_codes }))
+}
@@ -0,0 +1,132 @@
+use std::str::FromStr;
+use std::sync::Arc;
+
+use rust_decimal::Decimal;
+
+use crate::core::configure::app::AppConfig;
+use crate::core::configure::checkin::BaggageAllowanceConfig;
+use crate::domain::booking::entity::FareFamily;
+use crate::domain::checkin::baggage_allowance_policy::{
+    AllowanceConcept, BaggageAllowance, BaggageAllowancePolicy,
+};
+use crate::domain::checkin::entity::SeatClass;
+use crate::infrastructure::error::{TechnicalError, TechnicalResult};
+
+const SEAT_CLASSES: [SeatClass; 4] = [
+    SeatClass::Economy,
+    SeatClass::PremiumEconomy,
+    SeatClass::Business,
+    SeatClass::First,
+];
+const FARE_FAMILIES: [FareFamily; 3] = [FareFamily::Basic, FareFamily::Standard, FareFamily::Flex];
+
+pub fn build_baggage_allowance_policy(
+    config: &AppConfig,
+) -> TechnicalResult<Arc<BaggageAllowancePolicy>> {
+    let cfg = &config.checkin.baggage;
+
+    let parse_fee = |name: &str, value: &str| {
+        Decimal::from_str(value.trim())
+            .ok()
+            .filter(|fee| !fee.is_sign_negative())
+            .ok_or_else(|| {
+                TechnicalError::InvalidConfig(format!(
+                    "checkin.baggage.{name}: invalid amount {value}"
+                ))
+            })
+    };
+    let excess_piece_fee = parse_fee("excess_piece_fee", &cfg.excess_piece_fee)?;
+    let excess_weight_fee_per_kg =
+        parse_fee("excess_weight_fee_per_kg", &cfg.excess_weight_fee_per_kg)?;
+
+    let currency = cfg.currency.trim().to_uppercase();
+    if currency.len() != 3 || !currency.chars().all(|c| c.is_ascii_alphabetic()) {
+        return Err(TechnicalError::InvalidConfig(format!(
+            "checkin.baggage.currency: invalid currency {}",
+            cfg.currency
+        )));
+    }
+
+    let allowances = cfg
+        .allowances
+        .iter()
+        .map(build_allowance)
+        .collect::<TechnicalResult<Vec<_>>>()?;
+
+    let policy = BaggageAllowancePolicy {
+        allowances,
+        excess_piece_fee,
+        excess_weight_fee_per_kg,
+        currency,
+    };
+
+    // Every cabin and fare family sold must resolve to an allowance, or check-in would fail.
+    for seat_class in &SEAT_CLASSES {
+        for fare_family in FARE_FAMILIES {
+            if policy.allowance_for(seat_class, fare_family).is_none() {
+                return Err(TechnicalError::InvalidConfig(format!(
+                    "checkin.baggage.allowances: no allowance for {seat_class:?} / {fare_family:?}"
+                )));
+            }
+        }
+    }
+
+    Ok(Arc::new(policy))
+}
+
+fn build_allowance(cfg: &BaggageAllowanceConfig) -> TechnicalResult<BaggageAllowance> {
+    let invalid = |message: String| {
+        TechnicalError::InvalidConfig(format!("checkin.baggage.allowances: {message}"))
+    };
+
+    let seat_class = match cfg.seat_class.trim().to_uppercase().as_str() {
+        "ECONOMY" => SeatClass::Economy,
+        "PREMIUM_ECONOMY" => SeatClass::PremiumEconomy,
+        "BUSINESS" => SeatClass::Business,
+        "FIRST" => SeatClass::First,
+        _ => return Err(invalid(format!("unknown seat class {}", cfg.seat_class))),
+    };
+
+    let fare_family = match cfg.fare_family.as_deref().map(|f| f.trim().to_uppercase()) {
+        None => None,
+        Some(f) => Some(match f.as_str() {
+            "BASIC" => FareFamily::Basic,
+            "STANDARD" => FareFamily::Standard,
+            "FLEX" => FareFamily::Flex,
+            _ => return Err(invalid(format!("unknown fare family {f}"))),
+        }),
+    };
+
+    let concept = match cfg.concept.trim().to_uppercase().as_str() {
+        "PIECE" => match (cfg.pieces, cfg.max_piece_weight_kg) {
+            (Some(pieces), Some(max_piece_weight_kg)) if max_piece_weight_kg > 0 => {
+                AllowanceConcept::Piece {
+                    pieces,
+                    max_piece_weight_kg,
+                }
+            }
+            _ => {
+                return Err(invalid(format!(
+                    "{} PIECE allowance needs pieces and a positive max_piece_weight_kg",
+                    cfg.seat_class
+                )));
+            }
+        },
+        "WEIGHT" => match cfg.total_weight_kg {
+            Some(total_weight_kg) => AllowanceConcept::Weight { total_weight_kg },
+            None => {
+                return Err(invalid(format!(
+                    "{} WEIGHT allowance needs total_weight_kg",
+                    cfg.seat_class
+                )));
+            }
+        },
+        _ => return Err(invalid(format!("unknown concept {}", cfg.concept))),
+    };
+
+    Ok(BaggageAllowance {
+        seat_class,
+        fare_family,
+        concept,
+    })
+}
@@ -0,0 +1,66 @@
+use std::sync::Arc;
+
+use crate::core::configure::app::AppConfig;
+use crate::domain::boarding_pass::boarding_group_policy::BoardingGroupPolicy;
+use crate::domain::booking::entity::FareFamily;
+use crate::domain::checkin::entity::SeatClass;
+use crate::infrastructure::error::{TechnicalError, TechnicalResult};
+
+pub fn build_boarding_group_policy(
+    config: &AppConfig,
+) -> TechnicalResult<Arc<BoardingGroupPolicy>> {
+    let cfg = &config.boarding_pass.groups;
+
+    let cabin_groups = cfg
+        .cabins
+        .iter()
+        .map(|(cabin, group)| {
+            let seat_class = match cabin.trim().to_uppercase().as_str() {
+                "ECONOMY" => SeatClass::Economy,
+                "PREMIUM_ECONOMY" => SeatClass::PremiumEconomy,
+                "BUSINESS" => SeatClass::Business,
+                "FIRST" => SeatClass::First,
+                _ => {
+                    return Err(TechnicalError::InvalidConfig(format!(
+                        "boarding_pass.groups.cabins: unknown seat class {cabin}"
+                    )));
+                }
+            };
+            Ok((seat_class, *group))
+        })
+        .collect::<TechnicalResult<Vec<_>>>()?;
+
+    let fare_family_groups = cfg
+        .fare_families
+        .iter()
+        .map(|(fare_family, group)| {
+            let fare_family = match fare_family.trim().to_uppercase().as_str() {
+                "BASIC" => FareFamily::Basic,
+                "STANDARD" => FareFamily::Standard,
+                "FLEX" => FareFamily::Flex,
+                _ => {
+                    return Err(TechnicalError::InvalidConfig(format!(
+                        "boarding_pass.groups.fare_families: unknown fare family {fare_family}"
+                    )));
+                }
+            };
+            Ok((fare_family, *group))
+        })
+        .collect::<TechnicalResult<Vec<_>>>()?;
+
+    // Zones must be listed rearmost first for back-to-front boarding.
+    if cfg.zone_row_starts.windows(2).any(|w| w[0] <= w[1]) {
+        return Err(TechnicalError::InvalidConfig(
+            "boarding_pass.groups.zone_row_starts must be in descending order".to_string(),
+        ));
+    }
+
+    Ok(Arc::new(BoardingGroupPolicy {
+        cabin_groups,
+        fare_family_groups,
+        frequent_flyer_group: cfg.frequent_flyer,
+        frequent_flyer_airlines: cfg.frequent_flyer_airlines.clone(),
+        zone_row_starts: cfg.zone_row_starts.clone(),
+        first_zone_group: cfg.first_zone_group,
+    }))
+}
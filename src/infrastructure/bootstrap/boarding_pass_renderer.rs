@@ -0,0 +1,24 @@
+use std::sync::Arc;
+
+use crate::application::boarding_pass::boarding_pass_renderer::BoardingPassRenderer;
+use crate::core::configure::app::AppConfig;
+use crate::infrastructure::document::boarding_pass_document_renderer::BoardingPassDocumentRenderer;
+use crate::infrastructure::document::boarding_pass_layout::{Branding, Rgb};
+use crate::infrastructure::error::{TechnicalError, TechnicalResult};
+
+pub fn build_boarding_pass_renderer(
+    config: &AppConfig,
+) -> TechnicalResult<Arc<dyn BoardingPassRenderer>> {
+    let cfg = &config.boarding_pass;
+    let color = Rgb::from_hex(&cfg.brand_color).ok_or_else(|| {
+        TechnicalError::InvalidConfig(format!(
+            "boarding_pass.brand_color must be #RRGGBB: {}",
+            cfg.brand_color
+        ))
+    })?;
+
+    Ok(Arc::new(BoardingPassDocumentRenderer::new(Branding {
+        name: cfg.brand_name.clone(),
+        color,
+    })))
+}
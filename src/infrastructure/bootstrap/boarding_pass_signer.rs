@@ -0,0 +1,66 @@
+use std::collections::HashMap;
+use std::sync::Arc;
+
+use jsonwebtoken::{DecodingKey, EncodingKey};
+
+use crate::application::boarding_pass::boarding_pass_signer::BoardingPassSigner;
+use crate::core::configure::app::AppConfig;
+use crate::infrastructure::error::{TechnicalError, TechnicalResult};
+use crate::infrastructure::security::ed25519_boarding_pass_signer::Ed25519BoardingPassSigner;
+
+pub fn build_boarding_pass_signer(
+    config: &AppConfig,
+) -> TechnicalResult<Arc<dyn BoardingPassSigner>> {
+    let secret = &config.secret;
+    let active_key_id = secret.boarding_pass_active_key_id.clone().ok_or_else(|| {
+        TechnicalError::InvalidConfig("secret.boarding_pass_active_key_id is not set".to_string())
+    })?;
+
+    let mut signing_key = None;
+    let mut verifying_keys = HashMap::new();
+
+    for key in &secret.boarding_pass_keys {
+        // BCBP reserves a single character for the security type.
+        if key.key_id.chars().count() != 1 {
+            return Err(TechnicalError::InvalidConfig(format!(
+                "boarding pass key_id must be a single character: {}",
+                key.key_id
+            )));
+        }
+
+        let public = std::fs::read(&key.public_key)
+            .map_err(|e| TechnicalError::InvalidConfig(e.to_string()))?;
+        verifying_keys.insert(
+            key.key_id.clone(),
+            DecodingKey::from_ed_pem(&public)
+                .map_err(|e| TechnicalError::InvalidConfig(e.to_string()))?,
+        );
+
+        if key.key_id == active_key_id {
+            let path = key.private_key.as_ref().ok_or_else(|| {
+                TechnicalError::InvalidConfig(format!(
+                    "active boarding pass key {} has no private_key",
+                    key.key_id
+                ))
+            })?;
+            let private =
+                std::fs::read(path).map_err(|e| TechnicalError::InvalidConfig(e.to_string()))?;
+            signing_key = Some(
+                EncodingKey::from_ed_pem(&private)
+                    .map_err(|e| TechnicalError::InvalidConfig(e.to_string()))?,
+            );
+        }
+    }
+
+    let signing_key = signing_key.ok_or_else(|| {
+        TechnicalError::InvalidConfig(format!(
+            "boarding pass key {active_key_id} is not configured"
+        ))
+    })?;
+
+    Ok(Arc::new(Ed25519BoardingPassSigner {
+        active_key_id,
+        signing_key,
+        verifying_keys,
+    }))
+}
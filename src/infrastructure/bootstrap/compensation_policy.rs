@@ -0,0 +1,110 @@
+use std::str::FromStr;
+use std::sync::Arc;
+
+use rust_decimal::Decimal;
+
+use crate::core::configure::app::AppConfig;
+use crate::domain::compensation::compensation_policy::{CompensationBand, CompensationPolicy};
+use crate::infrastructure::error::{TechnicalError, TechnicalResult};
+
+pub fn build_compensation_policy(config: &AppConfig) -> TechnicalResult<Arc<CompensationPolicy>> {
+    let cfg = &config.compensation;
+
+    let currency = cfg.currency.trim().to_uppercase();
+    if currency.len() != 3 || !currency.chars().all(|c| c.is_ascii_alphabetic()) {
+        return Err(TechnicalError::InvalidConfig(format!(
+            "compensation.currency: invalid currency {}",
+            cfg.currency
+        )));
+    }
+
+    let covered_countries = cfg
+        .covered_countries
+        .iter()
+        .map(|code| {
+            let code = code.trim().to_uppercase();
+            if code.len() != 2 || !code.chars().all(|c| c.is_ascii_alphabetic()) {
+                return Err(TechnicalError::InvalidConfig(format!(
+                    "compensation.covered_countries: invalid country code {code}"
+                )));
+            }
+            Ok(code)
+        })
+        .collect::<TechnicalResult<Vec<_>>>()?;
+    // With no covered country every claim would be turned down as not covered.
+    if covered_countries.is_empty() {
+        return Err(TechnicalError::InvalidConfig(
+            "compensation.covered_countries is empty; list the countries where the regulation applies"
+                .to_string(),
+        ));
+    }
+
+    let mut bands = Vec::with_capacity(cfg.bands.len());
+    let mut previous_max = 0;
+    for (index, band) in cfg.bands.iter().enumerate() {
+        let is_last = index + 1 == cfg.bands.len();
+        match band.max_distance_km {
+            Some(max) if max <= previous_max => {
+                return Err(TechnicalError::InvalidConfig(format!(
+                    "compensation.bands: max_distance_km {max} is not above {previous_max}"
+                )));
+            }
+            Some(max) if !is_last => previous_max = max,
+            None if is_last => {}
+            _ => {
+                return Err(TechnicalError::InvalidConfig(
+                    "compensation.bands: only the last band must be open-ended".to_string(),
+                ));
+            }
+        }
+
+        let amount = Decimal::from_str(band.amount.trim())
+            .ok()
+            .filter(|amount| !amount.is_sign_negative())
+            .ok_or_else(|| {
+                TechnicalError::InvalidConfig(format!(
+                    "compensation.bands: invalid amount {}",
+                    band.amount
+                ))
+            })?;
+
+        bands.push(CompensationBand {
+            max_distance_km: band.max_distance_km,
+            amount,
+            reduced_below_delay_minutes: band.reduced_below_delay_minutes,
+        });
+    }
+    if bands.is_empty() {
+        return Err(TechnicalError::InvalidConfig(
+            "compensation.bands: at least one band is required".to_string(),
+        ));
+    }
+
+    if cfg.reduced_percent > 100 {
+        return Err(TechnicalError::InvalidConfig(format!(
+            "compensation.reduced_percent: {} is above 100",
+            cfg.reduced_percent
+        )));
+    }
+    if cfg.delay_threshold_minutes <= 0 || cfg.cancellation_notice_days < 0 {
+        return Err(TechnicalError::InvalidConfig(
+            "compensation: delay_threshold_minutes must be positive and cancellation_notice_days non-negative"
+                .to_string(),
+        ));
+    }
+
+    Ok(Arc::new(CompensationPolicy {
+        currency,
+        covered_countries,
+        delay_threshold_minutes: cfg.delay_threshold_minutes,
+        cancellation_notice_days: cfg.cancellation_notice_days,
+        extraordinary_delay_codes: cfg
+            .extraordinary_delay_codes
+            .iter()
+            .map(|code| code.trim().to_uppercase())
+            .collect(),
+        bands,
+        intra_region_distance_cap_km: cfg.intra_region_distance_cap_km,
+        reduced_percent: cfg.reduced_percent,
+    }))
+}
@@ -0,0 +1,35 @@
+use std::sync::Arc;
+
+use chrono_tz::Tz;
+
+use crate::core::configure::app::AppConfig;
+use crate::domain::airport::country_time_zones::CountryTimeZones;
+use crate::infrastructure::error::{TechnicalError, TechnicalResult};
+
+pub fn build_country_time_zones(config: &AppConfig) -> TechnicalResult<Arc<CountryTimeZones>> {
+    let cfg = &config.airport.import;
+
+    let zones = cfg
+        .country_time_zones
+        .iter()
+        .map(|(country, zone)| {
+            let country = country.trim().to_uppercase();
+            if country.len() != 2 || !country.chars().all(|c| c.is_ascii_uppercase()) {
+                return Err(TechnicalError::InvalidConfig(format!(
+                    "airport.import.country_time_zones: invalid country code {country}"
+                )));
+            }
+
+            let zone = zone.trim().to_string();
+            if zone.parse::<Tz>().is_err() {
+                return Err(TechnicalError::InvalidConfig(format!(
+                    "airport.import.country_time_zones.{country}: unknown time zone {zone}"
+                )));
+            }
+
+            Ok((country, zone))
+        })
+        .collect::<TechnicalResult<Vec<_>>>()?;
+
+    Ok(Arc::new(CountryTimeZones { zones }))
+}
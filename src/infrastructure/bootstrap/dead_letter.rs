@@ -0,0 +1,22 @@
+use std::sync::Arc;
+
+use crate::application::dead_letter::use_case::dead_letter_service_interface::DeadLetterServiceInterface;
+use crate::core::configure::app::AppConfig;
+use crate::infrastructure::error::TechnicalResult;
+use crate::infrastructure::messaging::kafka::consumer::broker::MessageBroker;
+use crate::infrastructure::messaging::kafka::consumer::dead_letter_archiver::DeadLetterArchiver;
+use crate::infrastructure::runtime::shutdown;
+
+/// Archives the dead letters of `consumer_groups`; stops with the process.
+pub fn start_dead_letter_archiver(
+    config: &AppConfig,
+    broker: &dyn MessageBroker,
+    consumer_groups: &[String],
+    service: Arc<dyn DeadLetterServiceInterface>,
+) -> TechnicalResult<()> {
+    let group_id = format!("{}.dead-letters", config.kafka.group_id);
+    let archiver = DeadLetterArchiver::new(broker, &group_id, consumer_groups, service)?;
+
+    shutdown::track(archiver.spawn());
+    Ok(())
+}
@@ -0,0 +1,29 @@
+use std::sync::Arc;
+
+use utils::redis_client::RedisConnectionPool;
+
+use crate::application::common::event_publisher::FlightEventPublisher;
+use crate::application::flight_status::use_case::flight_status_service_interface::FlightStatusServiceInterface;
+use crate::core::configure::app::AppConfig;
+use crate::infrastructure::cache::redis::client::create_redis_client;
+use crate::infrastructure::error::{TechnicalError, TechnicalResult};
+use crate::infrastructure::messaging::redis::flight_status_listener::spawn_flight_status_listener;
+use crate::infrastructure::messaging::redis::redis_flight_event_publisher::RedisFlightEventPublisher;
+
+/// Flight events go to Kafka and, for live status pushes, to the Redis status channel.
+pub fn build_flight_event_publisher(
+    inner: Arc<dyn FlightEventPublisher>,
+    redis: Arc<RedisConnectionPool>,
+) -> Arc<dyn FlightEventPublisher> {
+    Arc::new(RedisFlightEventPublisher::new(inner, redis))
+}
+
+pub fn start_flight_status_listener(
+    config: &AppConfig,
+    service: Arc<dyn FlightStatusServiceInterface>,
+) -> TechnicalResult<()> {
+    let client =
+        create_redis_client(&config.redis).map_err(|e| TechnicalError::Network(e.to_string()))?;
+    spawn_flight_status_listener(client, service);
+    Ok(())
+}
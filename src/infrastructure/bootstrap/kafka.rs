@@ -4,8 +4,15 @@ use rdkafka::producer::FutureProducer;
 
 use crate::core::configure::app::AppConfig;
 use crate::infrastructure::error::TechnicalResult;
+use crate::infrastructure::messaging::kafka::consumer::kafka_broker::KafkaBroker;
 use crate::infrastructure::messaging::kafka::factory::create_kafka_producer;
 
 pub fn build_kafka_producer(config: &AppConfig) -> TechnicalResult<Arc<FutureProducer>> {
     Ok(Arc::new(create_kafka_producer(&config.kafka)?))
 }
+
+/// Event consumers read through this and publish their retries and dead letters with
+/// the shared producer.
+pub fn build_kafka_broker(config: &AppConfig, producer: Arc<FutureProducer>) -> Arc<KafkaBroker> {
+    Arc::new(KafkaBroker::new(config.kafka.clone(), producer))
+}
@@ -5,4 +5,18 @@ pub mod kafka;
 pub mod jwt;
 pub mod cache;
 pub mod repositories;
-pub mod event_publishers;
\ No newline at end of file
+pub mod event_publishers;
+pub mod travel_document;
+pub mod boarding_pass_signer;
+pub mod boarding_pass_renderer;
+pub mod wallet_pass_generator;
+pub mod boarding_group_policy;
+
+pub mod no_show_policy;
+pub mod baggage_allowance_policy;
+pub mod bag_tag_issuer;
+pub mod country_time_zones;
+pub mod flight_status;
+pub mod compensation_policy;
+pub mod notification;
+pub mod dead_letter;
\ No newline at end of file
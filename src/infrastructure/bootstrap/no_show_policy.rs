@@ -0,0 +1,37 @@
+use std::sync::Arc;
+
+use crate::core::configure::app::AppConfig;
+use crate::domain::booking::entity::FareFamily;
+use crate::domain::booking::no_show_policy::NoShowPenaltyPolicy;
+use crate::infrastructure::error::{TechnicalError, TechnicalResult};
+
+pub fn build_no_show_penalty_policy(
+    config: &AppConfig,
+) -> TechnicalResult<Arc<NoShowPenaltyPolicy>> {
+    let penalty_percent = config
+        .booking
+        .no_show
+        .penalty_percent
+        .iter()
+        .map(|(fare_family, percent)| {
+            let fare_family = match fare_family.trim().to_uppercase().as_str() {
+                "BASIC" => FareFamily::Basic,
+                "STANDARD" => FareFamily::Standard,
+                "FLEX" => FareFamily::Flex,
+                _ => {
+                    return Err(TechnicalError::InvalidConfig(format!(
+                        "booking.no_show.penalty_percent: unknown fare family {fare_family}"
+                    )));
+                }
+            };
+            if *percent > 100 {
+                return Err(TechnicalError::InvalidConfig(format!(
+                    "booking.no_show.penalty_percent: {percent} is above 100 for {fare_family:?}"
+                )));
+            }
+            Ok((fare_family, *percent))
+        })
+        .collect::<TechnicalResult<Vec<_>>>()?;
+
+    Ok(Arc::new(NoShowPenaltyPolicy { penalty_percent }))
+}
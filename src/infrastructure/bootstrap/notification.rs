@@ -0,0 +1,186 @@
+use std::collections::HashMap;
+use std::sync::Arc;
+use std::time::Duration;
+
+use serde::Deserialize;
+
+use crate::application::notification::notification_channel::{
+    NotificationChannel, NotificationChannels,
+};
+use crate::application::notification::use_case::notification_service_interface::NotificationServiceInterface;
+use crate::core::configure::app::AppConfig;
+use crate::domain::booking::events::booking_cancelled::BookingCancelledEvent;
+use crate::domain::booking::events::booking_confirmed::BookingConfirmedEvent;
+use crate::domain::flight::events::flight_cancelled::FlightCancelledEvent;
+use crate::domain::flight::events::flight_updated::FlightUpdatedEvent;
+use crate::domain::notification::entity::{NotificationKind, NotificationRetryPolicy};
+use crate::domain::notification::template::{NotificationTemplate, NotificationTemplates};
+use crate::domain::user::events::user_registered::UserRegisteredEvent;
+use crate::infrastructure::error::{TechnicalError, TechnicalResult};
+use crate::infrastructure::messaging::kafka::consumer::broker::MessageBroker;
+use crate::infrastructure::messaging::kafka::consumer::event_consumer::EventConsumerBuilder;
+use crate::infrastructure::messaging::kafka::consumer::event_handler::ErrorPolicy;
+use crate::infrastructure::messaging::kafka::consumer::notification_event_handler::NotificationEventHandler;
+use crate::infrastructure::notification::http_gateway_channel::{HttpPushChannel, HttpSmsChannel};
+use crate::infrastructure::notification::local_sink_channel::{
+    FileNotificationChannel, LogNotificationChannel,
+};
+use crate::infrastructure::notification::notification_dispatcher::spawn_notification_dispatcher;
+use crate::infrastructure::notification::smtp_email_channel::SmtpEmailChannel;
+use crate::infrastructure::runtime::shutdown;
+
+const NOTIFICATION_HANDLER_ATTEMPTS: u32 = 3;
+
+#[derive(Deserialize)]
+struct TemplateFileEntry {
+    subject: String,
+    body: String,
+    #[serde(default)]
+    sms: Option<String>,
+    #[serde(default)]
+    push: Option<String>,
+}
+
+/// Loads `<templates_dir>/<locale>.toml`; each file has one table per notification kind.
+pub fn build_notification_templates(
+    config: &AppConfig,
+) -> TechnicalResult<Arc<NotificationTemplates>> {
+    let cfg = &config.notification;
+    let entries = std::fs::read_dir(&cfg.templates_dir).map_err(|e| {
+        TechnicalError::InvalidConfig(format!(
+            "notification.templates_dir {}: {e}",
+            cfg.templates_dir.display()
+        ))
+    })?;
+
+    let mut templates = HashMap::new();
+    for entry in entries {
+        let path = entry?.path();
+        if path.extension().and_then(|ext| ext.to_str()) != Some("toml") {
+            continue;
+        }
+        let Some(locale) = path.file_stem().and_then(|stem| stem.to_str()) else {
+            continue;
+        };
+
+        let file: HashMap<String, TemplateFileEntry> = config::Config::builder()
+            .add_source(config::File::from(path.as_path()))
+            .build()
+            .and_then(|c| c.try_deserialize())
+            .map_err(|e| TechnicalError::InvalidConfig(format!("{}: {e}", path.display())))?;
+
+        let by_kind = file
+            .into_iter()
+            .map(|(key, entry)| {
+                let kind = NotificationKind::from_key(&key).ok_or_else(|| {
+                    TechnicalError::InvalidConfig(format!(
+                        "{}: unknown notification kind {key}",
+                        path.display()
+                    ))
+                })?;
+                Ok((
+                    kind,
+                    NotificationTemplate {
+                        subject: entry.subject,
+                        body: entry.body,
+                        sms: entry.sms,
+                        push: entry.push,
+                    },
+                ))
+            })
+            .collect::<TechnicalResult<HashMap<_, _>>>()?;
+        templates.insert(locale.to_string(), by_kind);
+    }
+
+    NotificationTemplates::new(cfg.default_locale.clone(), templates)
+        .map(Arc::new)
+        .map_err(|e| TechnicalError::InvalidConfig(format!("notification templates: {e}")))
+}
+
+/// A channel without a provider section writes to `file_sink_dir` when set and to the
+/// log otherwise.
+pub fn build_notification_channels(config: &AppConfig) -> TechnicalResult<NotificationChannels> {
+    let cfg = &config.notification;
+    let fallback: Arc<dyn NotificationChannel> = match &cfg.file_sink_dir {
+        Some(dir) => Arc::new(FileNotificationChannel::new(dir.clone())),
+        None => Arc::new(LogNotificationChannel),
+    };
+    let client = reqwest::Client::new();
+
+    let email: Arc<dyn NotificationChannel> = match &cfg.email {
+        Some(smtp) => Arc::new(SmtpEmailChannel::new(smtp).map_err(TechnicalError::InvalidConfig)?),
+        None => fallback.clone(),
+    };
+    let sms: Arc<dyn NotificationChannel> = match &cfg.sms {
+        Some(gateway) => Arc::new(HttpSmsChannel::new(client.clone(), gateway.clone())),
+        None => fallback.clone(),
+    };
+    let push: Arc<dyn NotificationChannel> = match &cfg.push {
+        Some(gateway) => Arc::new(HttpPushChannel::new(client, gateway.clone())),
+        None => fallback,
+    };
+
+    Ok(NotificationChannels { email, sms, push })
+}
+
+pub fn build_notification_retry_policy(
+    config: &AppConfig,
+) -> TechnicalResult<NotificationRetryPolicy> {
+    let cfg = &config.notification;
+    if cfg.max_attempts < 1 {
+        return Err(TechnicalError::InvalidConfig(format!(
+            "notification.max_attempts must be at least 1: {}",
+            cfg.max_attempts
+        )));
+    }
+    if cfg.retry_delay_seconds <= 0 {
+        return Err(TechnicalError::InvalidConfig(format!(
+            "notification.retry_delay_seconds must be positive: {}",
+            cfg.retry_delay_seconds
+        )));
+    }
+
+    Ok(NotificationRetryPolicy {
+        max_attempts: cfg.max_attempts,
+        base_delay: chrono::Duration::seconds(cfg.retry_delay_seconds),
+    })
+}
+
+/// The notification consumer's own group, so it sees every event; also names its retry,
+/// replay and dead-letter topics.
+pub fn notification_consumer_group(config: &AppConfig) -> String {
+    format!("{}.notifications", config.kafka.group_id)
+}
+
+/// Starts the event consumer and the periodic dispatcher; both stop with the process.
+pub fn start_notification_workers(
+    config: &AppConfig,
+    broker: &dyn MessageBroker,
+    service: Arc<dyn NotificationServiceInterface>,
+) -> TechnicalResult<()> {
+    let cfg = &config.notification;
+    if cfg.dispatch_interval_seconds == 0 {
+        return Err(TechnicalError::InvalidConfig(
+            "notification.dispatch_interval_seconds must be positive".to_string(),
+        ));
+    }
+
+    let handler = Arc::new(NotificationEventHandler::new(service.clone()));
+    // queueing only touches the database, so failures are worth a few quick retries
+    // before the event is dead-lettered
+    let policy = ErrorPolicy::retry(NOTIFICATION_HANDLER_ATTEMPTS, Duration::from_secs(2));
+    let consumer = EventConsumerBuilder::new(notification_consumer_group(config))
+        .on::<UserRegisteredEvent, _>(handler.clone(), policy)
+        .on::<BookingConfirmedEvent, _>(handler.clone(), policy)
+        .on::<BookingCancelledEvent, _>(handler.clone(), policy)
+        .on::<FlightUpdatedEvent, _>(handler.clone(), policy)
+        .on::<FlightCancelledEvent, _>(handler, policy)
+        .build(broker)?;
+
+    shutdown::track(consumer.spawn());
+    shutdown::track(spawn_notification_dispatcher(
+        service,
+        Duration::from_secs(cfg.dispatch_interval_seconds),
+    ));
+    Ok(())
+}
@@ -2,12 +2,21 @@ use crate::core::context::request_context_provider::RequestContextProvider;
 use crate::infrastructure::persistence::postgres::DatabaseClient;
 use crate::infrastructure::persistence::seaorm::repositories::{
     address_repository::SeaOrmAddressRepository,
+    aircraft_repository::SeaOrmAircraftRepository,
+    airline_repository::SeaOrmAirlineRepository,
     airport_repository::SeaOrmAirportRepository,
+    bag_repository::SeaOrmBagRepository,
     boarding_pass_repository::SeaOrmBoardingPassRepository,
     booking_repository::SeaOrmBookingRepository,
     checkin_repository::SeaOrmCheckinRepository,
+    compensation_claim_repository::SeaOrmCompensationClaimRepository,
+    dead_letter_repository::SeaOrmDeadLetterRepository,
     flight_repository::SeaOrmFlightRepository,
+    no_show_repository::SeaOrmNoShowRepository,
+    notification_preference_repository::SeaOrmNotificationPreferenceRepository,
+    notification_repository::SeaOrmNotificationRepository,
     passenger_repository::SeaOrmPassengerRepository,
+    route_repository::SeaOrmRouteRepository,
     user_repository::SeaOrmUserRepository,
 };
 use std::sync::Arc;
@@ -16,11 +25,20 @@ pub struct Repositories {
     pub user: Arc<SeaOrmUserRepository>,
     pub address: Arc<SeaOrmAddressRepository>,
     pub airport: Arc<SeaOrmAirportRepository>,
+    pub airline: Arc<SeaOrmAirlineRepository>,
+    pub aircraft: Arc<SeaOrmAircraftRepository>,
+    pub route: Arc<SeaOrmRouteRepository>,
     pub flight: Arc<SeaOrmFlightRepository>,
     pub booking: Arc<SeaOrmBookingRepository>,
     pub passenger: Arc<SeaOrmPassengerRepository>,
     pub checkin: Arc<SeaOrmCheckinRepository>,
     pub boarding_pass: Arc<SeaOrmBoardingPassRepository>,
+    pub no_show: Arc<SeaOrmNoShowRepository>,
+    pub bag: Arc<SeaOrmBagRepository>,
+    pub compensation_claim: Arc<SeaOrmCompensationClaimRepository>,
+    pub notification: Arc<SeaOrmNotificationRepository>,
+    pub notification_preference: Arc<SeaOrmNotificationPreferenceRepository>,
+    pub dead_letter: Arc<SeaOrmDeadLetterRepository>,
 }
 
 pub fn build_repositories(
@@ -31,10 +49,31 @@ pub fn build_repositories(
         user: Arc::new(SeaOrmUserRepository::new(db.clone(), ctx_provider.clone())),
         address: Arc::new(SeaOrmAddressRepository::new(db.clone(), ctx_provider.clone())),
         airport: Arc::new(SeaOrmAirportRepository::new(db.clone())),
+        airline: Arc::new(SeaOrmAirlineRepository::new(db.clone())),
+        aircraft: Arc::new(SeaOrmAircraftRepository::new(db.clone())),
+        route: Arc::new(SeaOrmRouteRepository::new(db.clone())),
         flight: Arc::new(SeaOrmFlightRepository::new(db.clone(), ctx_provider.clone())),
         booking: Arc::new(SeaOrmBookingRepository::new(db.clone(), ctx_provider.clone())),
         passenger: Arc::new(SeaOrmPassengerRepository::new(db.clone(), ctx_provider.clone())),
         checkin: Arc::new(SeaOrmCheckinRepository::new(db.clone(), ctx_provider.clone())),
-        boarding_pass: Arc::new(SeaOrmBoardingPassRepository::new(db, ctx_provider)),
+        boarding_pass: Arc::new(SeaOrmBoardingPassRepository::new(
+            db.clone(),
+            ctx_provider.clone(),
+        )),
+        no_show: Arc::new(SeaOrmNoShowRepository::new(db.clone(), ctx_provider.clone())),
+        bag: Arc::new(SeaOrmBagRepository::new(db.clone(), ctx_provider.clone())),
+        compensation_claim: Arc::new(SeaOrmCompensationClaimRepository::new(
+            db.clone(),
+            ctx_provider.clone(),
+        )),
+        notification: Arc::new(SeaOrmNotificationRepository::new(
+            db.clone(),
+            ctx_provider.clone(),
+        )),
+        notification_preference: Arc::new(SeaOrmNotificationPreferenceRepository::new(
+            db.clone(),
+            ctx_provider.clone(),
+        )),
+        dead_letter: Arc::new(SeaOrmDeadLetterRepository::new(db, ctx_provider)),
     }
 }
@@ -0,0 +1,21 @@
+use std::sync::Arc;
+
+use crate::core::configure::app::AppConfig;
+use crate::domain::passenger::travel_document_policy::{TravelDocumentPolicy, VisaRequirement};
+
+pub fn build_travel_document_policy(config: &AppConfig) -> Arc<TravelDocumentPolicy> {
+    let cfg = &config.travel_document;
+
+    Arc::new(TravelDocumentPolicy {
+        passport_min_validity_months: cfg.passport_min_validity_months,
+        passport_free_zones: cfg.passport_free_zones.clone(),
+        visa_requirements: cfg
+            .visa_requirements
+            .iter()
+            .map(|v| VisaRequirement {
+                nationality_code: v.nationality_code.clone(),
+                destination_country_code: v.destination_country_code.clone(),
+            })
+            .collect(),
+    })
+}
@@ -0,0 +1,53 @@
+use std::path::Path;
+use std::sync::Arc;
+
+use openssl::pkey::PKey;
+use openssl::x509::X509;
+
+use crate::application::boarding_pass::wallet_pass_generator::WalletPassGenerator;
+use crate::core::configure::app::AppConfig;
+use crate::infrastructure::document::boarding_pass_layout::{Branding, Rgb};
+use crate::infrastructure::document::pkpass_generator::PkpassGenerator;
+use crate::infrastructure::error::{TechnicalError, TechnicalResult};
+
+/// `None` when `boarding_pass.wallet` is not configured.
+pub fn build_wallet_pass_generator(
+    config: &AppConfig,
+) -> TechnicalResult<Option<Arc<dyn WalletPassGenerator>>> {
+    let cfg = &config.boarding_pass;
+    let Some(wallet) = &cfg.wallet else {
+        return Ok(None);
+    };
+
+    let read = |path: &Path| {
+        std::fs::read(path).map_err(|e| {
+            TechnicalError::InvalidConfig(format!("cannot read {}: {e}", path.display()))
+        })
+    };
+    let invalid = |e: openssl::error::ErrorStack| TechnicalError::InvalidConfig(e.to_string());
+
+    let certificate = X509::from_pem(&read(&wallet.certificate)?).map_err(invalid)?;
+    let private_key = PKey::private_key_from_pem(&read(&wallet.private_key)?).map_err(invalid)?;
+    let intermediates = match &wallet.wwdr_certificate {
+        Some(path) => vec![X509::from_pem(&read(path)?).map_err(invalid)?],
+        None => Vec::new(),
+    };
+    let color = Rgb::from_hex(&cfg.brand_color).ok_or_else(|| {
+        TechnicalError::InvalidConfig(format!(
+            "boarding_pass.brand_color must be #RRGGBB: {}",
+            cfg.brand_color
+        ))
+    })?;
+
+    Ok(Some(Arc::new(PkpassGenerator {
+        pass_type_identifier: wallet.pass_type_identifier.clone(),
+        team_identifier: wallet.team_identifier.clone(),
+        branding: Branding {
+            name: cfg.brand_name.clone(),
+            color,
+        },
+        certificate,
+        private_key,
+        intermediates,
+    })))
+}
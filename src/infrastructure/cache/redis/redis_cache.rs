@@ -40,4 +40,11 @@ impl CacheInterface for RedisCacheAdapter {
             .await
             .map_err(|e| UseCaseError::Unexpected(format!("redis del error: {e}")))
     }
+
+    async fn del_many(&self, keys: &[String]) -> UseCaseResult<u64> {
+        self.redis
+            .delete_keys(keys)
+            .await
+            .map_err(|e| UseCaseError::Unexpected(format!("redis del error: {e}")))
+    }
 }
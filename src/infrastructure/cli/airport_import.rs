@@ -0,0 +1,135 @@
+use std::sync::Arc;
+
+use log::{info, warn};
+
+use crate::application::airport::airport_command::ImportAirportsCommand;
+use crate::application::airport::use_case::airport_service::AirportService;
+use crate::application::airport::use_case::airport_service_interface::AirportServiceInterface;
+use crate::application::airport::view::airport_import_view::AirportImportReportView;
+use crate::core::context::request_context::RequestContext;
+use crate::infrastructure::bootstrap::{
+    cache::build_cache, country_time_zones::build_country_time_zones, database::build_database,
+    event_publishers::build_event_publishers, kafka::build_kafka_producer, redis::build_redis,
+    repositories::build_repositories,
+};
+use crate::infrastructure::context::axum_request_context_provider::AxumRequestContextProvider;
+use crate::infrastructure::error::{TechnicalError, TechnicalResult};
+use crate::infrastructure::runtime::config::CONFIG;
+
+pub const USAGE: &str =
+    "usage: flight-booking airports-import --airports <PATH> --countries <PATH> [--apply]";
+
+struct AirportImportArgs {
+    airports: String,
+    countries: String,
+    apply: bool,
+}
+
+fn parse_args(args: &[String]) -> TechnicalResult<AirportImportArgs> {
+    let mut airports = None;
+    let mut countries = None;
+    let mut apply = false;
+
+    let mut iter = args.iter();
+    while let Some(arg) = iter.next() {
+        let mut value = || {
+            iter.next()
+                .cloned()
+                .ok_or_else(|| TechnicalError::InvalidArgument(format!("{arg} requires a value")))
+        };
+        match arg.as_str() {
+            "--airports" => airports = Some(value()?),
+            "--countries" => countries = Some(value()?),
+            "--apply" => apply = true,
+            other => {
+                return Err(TechnicalError::InvalidArgument(format!(
+                    "Unknown argument: {other}\n{USAGE}"
+                )));
+            }
+        }
+    }
+
+    let required =
+        |name: &str| TechnicalError::InvalidArgument(format!("--{name} is required\n{USAGE}"));
+    Ok(AirportImportArgs {
+        airports: airports.ok_or_else(|| required("airports"))?,
+        countries: countries.ok_or_else(|| required("countries"))?,
+        apply,
+    })
+}
+
+fn log_report(report: &AirportImportReportView) {
+    for issue in &report.conflicts {
+        warn!(
+            "Conflict on line {} ({} {}): {}",
+            issue.line, issue.ident, issue.iata_code, issue.reason
+        );
+    }
+    for issue in &report.skipped {
+        info!(
+            "Skipped line {} ({} {}): {}",
+            issue.line, issue.ident, issue.iata_code, issue.reason
+        );
+    }
+
+    let verb = if report.dry_run { "would be" } else { "were" };
+    info!(
+        "{} airport(s) {verb} inserted, {} {verb} updated, {} unchanged; {} skipped, {} conflict(s), {} row(s) without IATA code",
+        report.inserts.len(),
+        report.updates.len(),
+        report.unchanged,
+        report.skipped.len(),
+        report.conflicts.len(),
+        report.without_iata_code
+    );
+    if report.dry_run {
+        info!("Dry run only; re-run with --apply to write the changes");
+    }
+}
+
+/// `airports-import` subcommand: loads an OurAirports export from disk. Dry run unless
+/// `--apply` is given.
+pub async fn run(args: &[String]) -> TechnicalResult<()> {
+    let args = parse_args(args)?;
+    let config = Arc::new(CONFIG.clone());
+
+    let read = |path: String| async move {
+        tokio::fs::read_to_string(&path)
+            .await
+            .map_err(|e| TechnicalError::Io(format!("{path}: {e}")))
+    };
+    let airports_csv = read(args.airports).await?;
+    let countries_csv = read(args.countries).await?;
+
+    let db = build_database(&config).await?;
+    let redis = build_redis(&config).await?;
+    let kafka_producer = build_kafka_producer(&config)?;
+    let ctx_provider = Arc::new(AxumRequestContextProvider::new(|| {
+        crate::infrastructure::runtime::request_context::get()
+    }));
+    let repos = build_repositories(db, ctx_provider);
+    let events = build_event_publishers(kafka_producer);
+
+    let airport_service = AirportService::new(
+        build_cache(redis),
+        repos.airport.clone(),
+        build_country_time_zones(&config)?,
+        events.airport.clone(),
+    );
+
+    let report = airport_service
+        .import_airports(
+            RequestContext::system(),
+            ImportAirportsCommand {
+                airports_csv,
+                countries_csv,
+                dry_run: !args.apply,
+            },
+        )
+        .await
+        .map_err(|e| TechnicalError::Unexpected(e.to_string()))?;
+
+    log_report(&report);
+
+    Ok(())
+}
@@ -0,0 +1,118 @@
+use std::sync::Arc;
+
+use log::{info, warn};
+
+use crate::application::manifest::manifest_command::{ExportManifestCommand, ManifestFormat};
+use crate::application::manifest::use_case::manifest_service::ManifestService;
+use crate::application::manifest::use_case::manifest_service_interface::ManifestServiceInterface;
+use crate::core::context::request_context::RequestContext;
+use crate::infrastructure::bootstrap::{
+    database::build_database, repositories::build_repositories,
+    travel_document::build_travel_document_policy,
+};
+use crate::infrastructure::context::axum_request_context_provider::AxumRequestContextProvider;
+use crate::infrastructure::error::{TechnicalError, TechnicalResult};
+use crate::infrastructure::runtime::config::CONFIG;
+
+pub const USAGE: &str =
+    "usage: flight-booking manifest --flight-id <ID> [--format csv|json|paxlst] [--output <PATH>]";
+
+struct ManifestExportArgs {
+    flight_id: i64,
+    format: ManifestFormat,
+    output: Option<String>,
+}
+
+fn parse_args(args: &[String]) -> TechnicalResult<ManifestExportArgs> {
+    let mut flight_id = None;
+    let mut format = ManifestFormat::Csv;
+    let mut output = None;
+
+    let mut iter = args.iter();
+    while let Some(arg) = iter.next() {
+        let mut value = || {
+            iter.next()
+                .cloned()
+                .ok_or_else(|| TechnicalError::InvalidArgument(format!("{arg} requires a value")))
+        };
+        match arg.as_str() {
+            "--flight-id" => {
+                let raw = value()?;
+                flight_id = Some(raw.parse::<i64>().map_err(|_| {
+                    TechnicalError::InvalidArgument(format!("Invalid flight id: {raw}"))
+                })?);
+            }
+            "--format" => {
+                format = ManifestFormat::parse(&value()?)
+                    .map_err(|e| TechnicalError::InvalidArgument(e.to_string()))?;
+            }
+            "--output" => output = Some(value()?),
+            other => {
+                return Err(TechnicalError::InvalidArgument(format!(
+                    "Unknown argument: {other}\n{USAGE}"
+                )));
+            }
+        }
+    }
+
+    let flight_id = flight_id.ok_or_else(|| {
+        TechnicalError::InvalidArgument(format!("--flight-id is required\n{USAGE}"))
+    })?;
+
+    Ok(ManifestExportArgs {
+        flight_id,
+        format,
+        output,
+    })
+}
+
+/// `manifest` subcommand: builds the APIS manifest for a flight as the system actor.
+pub async fn run(args: &[String]) -> TechnicalResult<()> {
+    let args = parse_args(args)?;
+    let config = Arc::new(CONFIG.clone());
+
+    let db = build_database(&config).await?;
+    let ctx_provider = Arc::new(AxumRequestContextProvider::new(|| {
+        crate::infrastructure::runtime::request_context::get()
+    }));
+    let repos = build_repositories(db, ctx_provider);
+
+    let manifest_service = ManifestService::new(
+        repos.flight.clone(),
+        repos.airport.clone(),
+        repos.booking.clone(),
+        repos.passenger.clone(),
+        repos.checkin.clone(),
+        build_travel_document_policy(&config),
+    );
+
+    let export = manifest_service
+        .export_flight_manifest(
+            RequestContext::system(),
+            ExportManifestCommand {
+                flight_id: args.flight_id,
+                format: args.format,
+            },
+        )
+        .await
+        .map_err(|e| TechnicalError::Unexpected(e.to_string()))?;
+
+    if export.issue_count > 0 {
+        warn!(
+            "Manifest for flight {} has {} validation issue(s); export with --format json for details",
+            args.flight_id, export.issue_count
+        );
+    }
+
+    match args.output {
+        Some(path) => {
+            tokio::fs::write(&path, export.content.as_bytes())
+                .await
+                .map_err(|e| TechnicalError::Io(e.to_string()))?;
+            info!("Manifest written to {path}");
+        }
+        None => print!("{}", export.content),
+    }
+
+    Ok(())
+}
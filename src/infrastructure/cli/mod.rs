@@ -0,0 +1,3 @@
+pub mod airport_import;
+pub mod manifest_export;
+pub mod no_show_processing;
@@ -0,0 +1,103 @@
+use std::sync::Arc;
+
+use log::info;
+
+use crate::application::no_show::use_case::no_show_service::NoShowService;
+use crate::application::no_show::use_case::no_show_service_interface::NoShowServiceInterface;
+use crate::application::no_show::view::no_show_view::NoShowProcessingView;
+use crate::core::context::request_context::RequestContext;
+use crate::infrastructure::bootstrap::{
+    cache::build_cache, database::build_database, event_publishers::build_event_publishers,
+    kafka::build_kafka_producer, no_show_policy::build_no_show_penalty_policy, redis::build_redis,
+    repositories::build_repositories,
+};
+use crate::infrastructure::context::axum_request_context_provider::AxumRequestContextProvider;
+use crate::infrastructure::error::{TechnicalError, TechnicalResult};
+use crate::infrastructure::runtime::config::CONFIG;
+
+pub const USAGE: &str = "usage: flight-booking no-shows [--flight-id <ID>]";
+
+fn parse_args(args: &[String]) -> TechnicalResult<Option<i64>> {
+    let mut flight_id = None;
+
+    let mut iter = args.iter();
+    while let Some(arg) = iter.next() {
+        match arg.as_str() {
+            "--flight-id" => {
+                let raw = iter.next().ok_or_else(|| {
+                    TechnicalError::InvalidArgument(format!("{arg} requires a value"))
+                })?;
+                flight_id = Some(raw.parse::<i64>().map_err(|_| {
+                    TechnicalError::InvalidArgument(format!("Invalid flight id: {raw}"))
+                })?);
+            }
+            other => {
+                return Err(TechnicalError::InvalidArgument(format!(
+                    "Unknown argument: {other}\n{USAGE}"
+                )));
+            }
+        }
+    }
+
+    Ok(flight_id)
+}
+
+fn log_result(view: &NoShowProcessingView) {
+    info!(
+        "Flight {} {}{} departed {}: {} no-show(s) recorded, {} booking(s) closed",
+        view.flight_id,
+        view.airline_code,
+        view.flight_number,
+        view.departure_time,
+        view.no_shows.len(),
+        view.bookings_closed
+    );
+}
+
+/// `no-shows` subcommand: the post-departure job. Without `--flight-id` it processes every
+/// departed flight not handled yet, so it is safe to run from cron.
+pub async fn run(args: &[String]) -> TechnicalResult<()> {
+    let flight_id = parse_args(args)?;
+    let config = Arc::new(CONFIG.clone());
+
+    let db = build_database(&config).await?;
+    let redis = build_redis(&config).await?;
+    let kafka_producer = build_kafka_producer(&config)?;
+    let ctx_provider = Arc::new(AxumRequestContextProvider::new(|| {
+        crate::infrastructure::runtime::request_context::get()
+    }));
+    let repos = build_repositories(db, ctx_provider);
+    let events = build_event_publishers(kafka_producer);
+
+    let no_show_service = NoShowService::new(
+        build_cache(redis),
+        repos.flight.clone(),
+        repos.booking.clone(),
+        repos.passenger.clone(),
+        repos.checkin.clone(),
+        repos.no_show.clone(),
+        build_no_show_penalty_policy(&config)?,
+        events.passenger.clone(),
+    );
+
+    let ctx = RequestContext::system();
+    match flight_id {
+        Some(flight_id) => {
+            let view = no_show_service
+                .process_flight_no_shows(ctx, flight_id)
+                .await
+                .map_err(|e| TechnicalError::Unexpected(e.to_string()))?;
+            log_result(&view);
+        }
+        None => {
+            let views = no_show_service
+                .process_departed_flights(ctx)
+                .await
+                .map_err(|e| TechnicalError::Unexpected(e.to_string()))?;
+            views.iter().for_each(log_result);
+            info!("No-show processing finished for {} flight(s)", views.len());
+        }
+    }
+
+    Ok(())
+}
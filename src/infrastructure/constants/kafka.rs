@@ -0,0 +1,18 @@
+// Kafka consumer retry / dead-letter topics, suffixed to the consumer group id
+
+pub const KAFKA_RETRY_TOPIC_SUFFIX: &str = "retry";
+pub const KAFKA_REPLAY_TOPIC_SUFFIX: &str = "replay";
+pub const KAFKA_DEAD_LETTER_TOPIC_SUFFIX: &str = "dlq";
+
+// Headers added to retried and dead-lettered messages
+
+pub const KAFKA_HEADER_ORIGINAL_TOPIC: &str = "x-original-topic";
+pub const KAFKA_HEADER_ORIGINAL_PARTITION: &str = "x-original-partition";
+pub const KAFKA_HEADER_ORIGINAL_OFFSET: &str = "x-original-offset";
+pub const KAFKA_HEADER_CONSUMER_GROUP: &str = "x-consumer-group";
+pub const KAFKA_HEADER_HANDLER: &str = "x-handler";
+pub const KAFKA_HEADER_ATTEMPT: &str = "x-attempt";
+pub const KAFKA_HEADER_RETRY_AT: &str = "x-retry-at";
+pub const KAFKA_HEADER_ERROR: &str = "x-error";
+pub const KAFKA_HEADER_FAILED_AT: &str = "x-failed-at";
+pub const KAFKA_HEADER_REPLAY_OF: &str = "x-replay-of";
@@ -1,4 +1,5 @@
 pub mod app;
 pub mod http;
 pub mod auth;
-pub mod redis;
\ No newline at end of file
+pub mod redis;
+pub mod kafka;
\ No newline at end of file
@@ -30,3 +30,7 @@ pub const REDIS_TTL_SEARCH_RESULT: i64 = 120;
 pub const REDIS_TTL_STATISTICS: i64 = 300;
 
 pub const REDIS_TTL_DEFAULT: i64 = 3600;
+
+// Redis pub/sub channels
+
+pub const REDIS_CHANNEL_FLIGHT_STATUS: &str = "flight.status";
@@ -0,0 +1,72 @@
+use rxing::{BarcodeFormat, MultiFormatWriter, Writer};
+
+use crate::application::common::use_case_error::{UseCaseError, UseCaseResult};
+
+/// Dark/light modules of a 2D barcode, cropped to the symbol itself. Renderers add
+/// their own quiet zone.
+#[derive(Debug, Clone)]
+pub struct BarcodeMatrix {
+    pub width: usize,
+    pub height: usize,
+    modules: Vec<bool>,
+}
+
+impl BarcodeMatrix {
+    pub fn is_dark(&self, x: usize, y: usize) -> bool {
+        self.modules[y * self.width + x]
+    }
+}
+
+/// Accepts the `barcode_format` values stored on a boarding pass (`QR`, `PDF417`, `AZTEC`).
+pub fn encode_barcode(format: &str, payload: &str) -> UseCaseResult<BarcodeMatrix> {
+    let barcode_format = match format
+        .trim()
+        .to_uppercase()
+        .replace(['_', '-'], "")
+        .as_str()
+    {
+        "QR" | "QRCODE" => BarcodeFormat::QR_CODE,
+        "PDF417" => BarcodeFormat::PDF_417,
+        "AZTEC" => BarcodeFormat::AZTEC,
+        other => {
+            return Err(UseCaseError::BusinessRule(format!(
+                "Unsupported barcode format: {other}"
+            )));
+        }
+    };
+
+    let bits = MultiFormatWriter
+        .encode(payload, &barcode_format, 0, 0)
+        .map_err(|e| UseCaseError::Unexpected(format!("barcode encoding failed: {e}")))?;
+
+    let (width, height) = (bits.get_width() as usize, bits.get_height() as usize);
+    let dark = |x: usize, y: usize| bits.get(x as u32, y as u32);
+
+    // Writers pad the symbol with format-specific margins; keep only the dark area.
+    let rows: Vec<usize> = (0..height)
+        .filter(|&y| (0..width).any(|x| dark(x, y)))
+        .collect();
+    let cols: Vec<usize> = (0..width)
+        .filter(|&x| (0..height).any(|y| dark(x, y)))
+        .collect();
+    let (Some(&top), Some(&bottom), Some(&left), Some(&right)) =
+        (rows.first(), rows.last(), cols.first(), cols.last())
+    else {
+        return Err(UseCaseError::Unexpected(
+            "barcode encoding produced an empty symbol".to_string(),
+        ));
+    };
+
+    let mut modules = Vec::with_capacity((right - left + 1) * (bottom - top + 1));
+    for y in top..=bottom {
+        for x in left..=right {
+            modules.push(dark(x, y));
+        }
+    }
+
+    Ok(BarcodeMatrix {
+        width: right - left + 1,
+        height: bottom - top + 1,
+        modules,
+    })
+}
@@ -0,0 +1,40 @@
+use crate::application::boarding_pass::boarding_pass_renderer::{
+    BoardingPassDocument, BoardingPassRenderer,
+};
+use crate::application::common::use_case_error::UseCaseResult;
+use crate::infrastructure::document::barcode::encode_barcode;
+use crate::infrastructure::document::boarding_pass_layout::{
+    Branding, PAGE_HEIGHT, PAGE_WIDTH, Primitive, layout,
+};
+use crate::infrastructure::document::pdf::render_pdf;
+use crate::infrastructure::document::raster::render_png;
+
+// Pixels per point for PNG output (1200x600 for the default page).
+const PNG_SCALE: u32 = 2;
+
+pub struct BoardingPassDocumentRenderer {
+    pub branding: Branding,
+}
+
+impl BoardingPassDocumentRenderer {
+    pub fn new(branding: Branding) -> Self {
+        Self { branding }
+    }
+
+    fn page(&self, document: &BoardingPassDocument) -> UseCaseResult<Vec<Primitive>> {
+        let barcode = encode_barcode(&document.barcode_format, &document.barcode_payload)?;
+        Ok(layout(document, barcode, &self.branding))
+    }
+}
+
+impl BoardingPassRenderer for BoardingPassDocumentRenderer {
+    fn render_pdf(&self, document: &BoardingPassDocument) -> UseCaseResult<Vec<u8>> {
+        let page = self.page(document)?;
+        Ok(render_pdf(&page, PAGE_WIDTH, PAGE_HEIGHT))
+    }
+
+    fn render_png(&self, document: &BoardingPassDocument) -> UseCaseResult<Vec<u8>> {
+        let page = self.page(document)?;
+        render_png(&page, PAGE_WIDTH, PAGE_HEIGHT, PNG_SCALE)
+    }
+}
@@ -0,0 +1,307 @@
+use crate::application::boarding_pass::boarding_pass_renderer::BoardingPassDocument;
+use crate::infrastructure::document::barcode::BarcodeMatrix;
+
+/// Page size in points (1/72 inch).
+pub const PAGE_WIDTH: f32 = 600.0;
+pub const PAGE_HEIGHT: f32 = 300.0;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Rgb(pub u8, pub u8, pub u8);
+
+impl Rgb {
+    pub const WHITE: Rgb = Rgb(0xFF, 0xFF, 0xFF);
+    pub const BLACK: Rgb = Rgb(0x00, 0x00, 0x00);
+    pub const INK: Rgb = Rgb(0x1F, 0x29, 0x37);
+    pub const MUTED: Rgb = Rgb(0x6B, 0x72, 0x80);
+    pub const RULE: Rgb = Rgb(0xD1, 0xD5, 0xDB);
+
+    /// Parses `#RRGGBB`.
+    pub fn from_hex(value: &str) -> Option<Rgb> {
+        let hex = value.trim().strip_prefix('#')?;
+        if hex.len() != 6 || !hex.is_ascii() {
+            return None;
+        }
+        let channel = |i: usize| u8::from_str_radix(&hex[i..i + 2], 16).ok();
+        Some(Rgb(channel(0)?, channel(2)?, channel(4)?))
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct Branding {
+    pub name: String,
+    pub color: Rgb,
+}
+
+/// Drawing operations shared by the PDF and PNG backends. Coordinates are points with
+/// the origin at the top-left corner; text `y` is the top of the capital letters.
+#[derive(Debug, Clone)]
+pub enum Primitive {
+    Rect {
+        x: f32,
+        y: f32,
+        width: f32,
+        height: f32,
+        color: Rgb,
+    },
+    Text {
+        x: f32,
+        y: f32,
+        size: f32,
+        bold: bool,
+        color: Rgb,
+        text: String,
+    },
+    /// Scaled to fit the box and centred in it, keeping modules square.
+    Barcode {
+        x: f32,
+        y: f32,
+        width: f32,
+        height: f32,
+        matrix: BarcodeMatrix,
+    },
+}
+
+pub fn layout(
+    document: &BoardingPassDocument,
+    barcode: BarcodeMatrix,
+    branding: &Branding,
+) -> Vec<Primitive> {
+    let mut page = Vec::new();
+
+    page.push(rect(0.0, 0.0, PAGE_WIDTH, PAGE_HEIGHT, Rgb::WHITE));
+    page.push(rect(0.0, 0.0, PAGE_WIDTH, 48.0, branding.color));
+    page.push(text(20.0, 16.0, 18.0, true, Rgb::WHITE, &branding.name));
+    page.push(text(440.0, 20.0, 12.0, true, Rgb::WHITE, "Boarding pass"));
+
+    field(
+        &mut page,
+        20.0,
+        62.0,
+        "Passenger",
+        &document.passenger_name,
+        13.0,
+    );
+    field(
+        &mut page,
+        250.0,
+        62.0,
+        "Booking ref",
+        &document.booking_code,
+        13.0,
+    );
+
+    field(
+        &mut page,
+        20.0,
+        100.0,
+        "From",
+        &document.origin_iata_code,
+        24.0,
+    );
+    page.push(text(
+        20.0,
+        142.0,
+        8.0,
+        false,
+        Rgb::MUTED,
+        &document.origin_city,
+    ));
+    page.push(text(105.0, 116.0, 16.0, true, Rgb::MUTED, ">"));
+    field(
+        &mut page,
+        140.0,
+        100.0,
+        "To",
+        &document.destination_iata_code,
+        24.0,
+    );
+    page.push(text(
+        140.0,
+        142.0,
+        8.0,
+        false,
+        Rgb::MUTED,
+        &document.destination_city,
+    ));
+    field(&mut page, 250.0, 100.0, "Cabin", &document.cabin, 12.0);
+
+    field(&mut page, 20.0, 160.0, "Flight", &document.flight, 12.0);
+    if let Some(operating) = &document.operated_by {
+        page.push(text(
+            20.0,
+            187.0,
+            6.0,
+            false,
+            Rgb::MUTED,
+            &format!("Operated by {operating}"),
+        ));
+    }
+    field(
+        &mut page,
+        90.0,
+        160.0,
+        "Date",
+        &document.departure_date,
+        12.0,
+    );
+    field(
+        &mut page,
+        180.0,
+        160.0,
+        "Departs",
+        &document.departure_time,
+        12.0,
+    );
+    field(
+        &mut page,
+        280.0,
+        160.0,
+        "Arrives",
+        &document.arrival_time,
+        12.0,
+    );
+
+    let optional = |value: &Option<String>| value.clone().unwrap_or_else(|| "-".to_string());
+    field(
+        &mut page,
+        20.0,
+        198.0,
+        "Boarding",
+        &optional(&document.boarding_time),
+        12.0,
+    );
+    field(
+        &mut page,
+        90.0,
+        198.0,
+        "Terminal",
+        &optional(&document.terminal),
+        12.0,
+    );
+    field(
+        &mut page,
+        150.0,
+        198.0,
+        "Gate",
+        &optional(&document.gate),
+        12.0,
+    );
+    field(
+        &mut page,
+        205.0,
+        198.0,
+        "Group",
+        &optional(&document.boarding_group),
+        12.0,
+    );
+    field(
+        &mut page,
+        255.0,
+        198.0,
+        "Seq",
+        &optional(&document.sequence_no),
+        12.0,
+    );
+    field(
+        &mut page,
+        305.0,
+        198.0,
+        "Seat",
+        &optional(&document.seat),
+        12.0,
+    );
+
+    let note = "Times are local to each airport";
+    // PDF417 is several times wider than tall and gets a strip along the bottom;
+    // square symbologies (QR, Aztec) sit in the right-hand panel.
+    if barcode.width > barcode.height * 2 {
+        page.push(rect(20.0, 232.0, PAGE_WIDTH - 40.0, 0.75, Rgb::RULE));
+        page.push(Primitive::Barcode {
+            x: 20.0,
+            y: 240.0,
+            width: PAGE_WIDTH - 40.0,
+            height: 50.0,
+            matrix: barcode,
+        });
+        field(
+            &mut page,
+            400.0,
+            62.0,
+            "Pass",
+            &document.boarding_pass_code,
+            12.0,
+        );
+        page.push(text(400.0, 100.0, 7.0, false, Rgb::MUTED, note));
+    } else {
+        page.push(rect(388.0, 58.0, 0.75, PAGE_HEIGHT - 70.0, Rgb::RULE));
+        page.push(Primitive::Barcode {
+            x: 400.0,
+            y: 62.0,
+            width: 180.0,
+            height: 180.0,
+            matrix: barcode,
+        });
+        page.push(text(
+            400.0,
+            254.0,
+            9.0,
+            true,
+            Rgb::INK,
+            &document.boarding_pass_code,
+        ));
+        page.push(text(20.0, 278.0, 7.0, false, Rgb::MUTED, note));
+    }
+
+    page
+}
+
+/// Square app icon: the first letter of the brand on the brand colour.
+pub fn icon(branding: &Branding, size: f32) -> Vec<Primitive> {
+    let initial = branding
+        .name
+        .trim()
+        .chars()
+        .next()
+        .unwrap_or(' ')
+        .to_string();
+    let letter = size * 0.6;
+
+    vec![
+        rect(0.0, 0.0, size, size, branding.color),
+        text(
+            (size - letter * 0.7) / 2.0,
+            (size - letter * 0.72) / 2.0,
+            letter,
+            true,
+            Rgb::WHITE,
+            &initial,
+        ),
+    ]
+}
+
+fn field(page: &mut Vec<Primitive>, x: f32, y: f32, label: &str, value: &str, size: f32) {
+    page.push(text(x, y, 7.0, false, Rgb::MUTED, label));
+    page.push(text(x, y + 12.0, size, true, Rgb::INK, value));
+}
+
+fn rect(x: f32, y: f32, width: f32, height: f32, color: Rgb) -> Primitive {
+    Primitive::Rect {
+        x,
+        y,
+        width,
+        height,
+        color,
+    }
+}
+
+// Everything is printed in capitals, as on airport-issued passes.
+fn text(x: f32, y: f32, size: f32, bold: bool, color: Rgb, value: &str) -> Primitive {
+    Primitive::Text {
+        x,
+        y,
+        size,
+        bold,
+        color,
+        text: value.to_uppercase(),
+    }
+}
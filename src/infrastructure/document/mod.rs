@@ -0,0 +1,7 @@
+pub mod barcode;
+pub mod bitmap_font;
+pub mod boarding_pass_document_renderer;
+pub mod boarding_pass_layout;
+pub mod pdf;
+pub mod pkpass_generator;
+pub mod raster;
@@ -0,0 +1,159 @@
+use crate::infrastructure::document::boarding_pass_layout::{Primitive, Rgb};
+
+// Helvetica cap height as a fraction of the font size.
+const CAP_HEIGHT: f32 = 0.718;
+
+/// Writes a single-page PDF using the standard Helvetica fonts, so no font is embedded.
+pub fn render_pdf(primitives: &[Primitive], width: f32, height: f32) -> Vec<u8> {
+    let mut content = String::new();
+
+    for primitive in primitives {
+        match primitive {
+            Primitive::Rect {
+                x,
+                y,
+                width: w,
+                height: h,
+                color,
+            } => {
+                content.push_str(&fill_color(*color));
+                content.push_str(&rect(*x, height - y - h, *w, *h));
+            }
+            Primitive::Text {
+                x,
+                y,
+                size,
+                bold,
+                color,
+                text,
+            } => {
+                content.push_str(&format!(
+                    "BT /{} {} Tf {} {} {} Td ({}) Tj ET\n",
+                    if *bold { "F2" } else { "F1" },
+                    num(*size),
+                    fill_color(*color).trim_end(),
+                    num(*x),
+                    num(height - y - size * CAP_HEIGHT),
+                    escape_text(text)
+                ));
+            }
+            Primitive::Barcode {
+                x,
+                y,
+                width: w,
+                height: h,
+                matrix,
+            } => {
+                let module = (w / matrix.width as f32).min(h / matrix.height as f32);
+                let left = x + (w - module * matrix.width as f32) / 2.0;
+                let top = y + (h - module * matrix.height as f32) / 2.0;
+
+                content.push_str(&fill_color(Rgb::BLACK));
+                for row in 0..matrix.height {
+                    let mut col = 0;
+                    // One rectangle per horizontal run of dark modules.
+                    while col < matrix.width {
+                        if !matrix.is_dark(col, row) {
+                            col += 1;
+                            continue;
+                        }
+                        let start = col;
+                        while col < matrix.width && matrix.is_dark(col, row) {
+                            col += 1;
+                        }
+                        content.push_str(&rect(
+                            left + start as f32 * module,
+                            height - top - (row + 1) as f32 * module,
+                            (col - start) as f32 * module,
+                            module,
+                        ));
+                    }
+                }
+            }
+        }
+    }
+
+    let objects = [
+        "<< /Type /Catalog /Pages 2 0 R >>".to_string(),
+        "<< /Type /Pages /Kids [3 0 R] /Count 1 >>".to_string(),
+        format!(
+            "<< /Type /Page /Parent 2 0 R /MediaBox [0 0 {} {}] \
+             /Resources << /Font << /F1 4 0 R /F2 5 0 R >> >> /Contents 6 0 R >>",
+            num(width),
+            num(height)
+        ),
+        "<< /Type /Font /Subtype /Type1 /BaseFont /Helvetica /Encoding /WinAnsiEncoding >>"
+            .to_string(),
+        "<< /Type /Font /Subtype /Type1 /BaseFont /Helvetica-Bold /Encoding /WinAnsiEncoding >>"
+            .to_string(),
+        format!(
+            "<< /Length {} >>\nstream\n{}endstream",
+            content.len(),
+            content
+        ),
+    ];
+
+    let mut out = String::from("%PDF-1.4\n");
+    let mut offsets = Vec::with_capacity(objects.len());
+    for (index, object) in objects.iter().enumerate() {
+        offsets.push(out.len());
+        out.push_str(&format!("{} 0 obj\n{}\nendobj\n", index + 1, object));
+    }
+
+    let xref_offset = out.len();
+    out.push_str(&format!(
+        "xref\n0 {}\n0000000000 65535 f \n",
+        objects.len() + 1
+    ));
+    for offset in offsets {
+        out.push_str(&format!("{offset:010} 00000 n \n"));
+    }
+    out.push_str(&format!(
+        "trailer\n<< /Size {} /Root 1 0 R >>\nstartxref\n{}\n%%EOF\n",
+        objects.len() + 1,
+        xref_offset
+    ));
+
+    out.into_bytes()
+}
+
+fn rect(x: f32, y: f32, width: f32, height: f32) -> String {
+    format!(
+        "{} {} {} {} re f\n",
+        num(x),
+        num(y),
+        num(width),
+        num(height)
+    )
+}
+
+fn fill_color(color: Rgb) -> String {
+    let channel = |v: u8| format!("{:.3}", v as f32 / 255.0);
+    format!(
+        "{} {} {} rg\n",
+        channel(color.0),
+        channel(color.1),
+        channel(color.2)
+    )
+}
+
+fn num(value: f32) -> String {
+    format!("{value:.2}")
+}
+
+// Literal strings need `\`, `(` and `)` escaped. The content stream is kept ASCII
+// so byte offsets in the xref table match the string length.
+fn escape_text(value: &str) -> String {
+    let mut out = String::with_capacity(value.len());
+    for c in value.chars() {
+        match c {
+            '\\' | '(' | ')' => {
+                out.push('\\');
+                out.push(c);
+            }
+            ' '..='~' => out.push(c),
+            _ => out.push('?'),
+        }
+    }
+    out
+}
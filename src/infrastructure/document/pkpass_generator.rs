@@ -0,0 +1,346 @@
+use std::io::{Cursor, Write};
+
+use openssl::pkcs7::{Pkcs7, Pkcs7Flags};
+use openssl::pkey::{PKey, Private};
+use openssl::stack::Stack;
+use openssl::x509::X509;
+use serde_json::{Value, json};
+use zip::ZipWriter;
+use zip::write::SimpleFileOptions;
+
+use crate::application::boarding_pass::boarding_pass_renderer::BoardingPassDocument;
+use crate::application::boarding_pass::wallet_pass_generator::WalletPassGenerator;
+use crate::application::common::use_case_error::{UseCaseError, UseCaseResult};
+use crate::infrastructure::document::boarding_pass_layout::{Branding, icon};
+use crate::infrastructure::document::raster::render_png;
+
+// Wallet icon size in points; the @2x/@3x variants are rendered from the same layout.
+const ICON_SIZE: f32 = 29.0;
+
+pub struct PkpassGenerator {
+    pub pass_type_identifier: String,
+    pub team_identifier: String,
+    pub branding: Branding,
+    pub certificate: X509,
+    pub private_key: PKey<Private>,
+    pub intermediates: Vec<X509>,
+}
+
+impl PkpassGenerator {
+    fn pass_json(&self, document: &BoardingPassDocument, version: i32) -> Value {
+        let optional = |value: &Option<String>| value.clone().unwrap_or_else(|| "-".to_string());
+        let field = |key: &str, label: &str, value: String| json!({ "key": key, "label": label, "value": value });
+        let barcode = json!({
+            "format": barcode_format(&document.barcode_format),
+            "message": document.barcode_payload,
+            "messageEncoding": "iso-8859-1",
+            "altText": document.boarding_pass_code,
+        });
+        let color = self.branding.color;
+
+        json!({
+            "formatVersion": 1,
+            "passTypeIdentifier": self.pass_type_identifier,
+            "teamIdentifier": self.team_identifier,
+            "serialNumber": document.boarding_pass_code,
+            "organizationName": self.branding.name,
+            "description": format!(
+                "Boarding pass {} {}-{}",
+                document.flight, document.origin_iata_code, document.destination_iata_code
+            ),
+            "logoText": self.branding.name,
+            "backgroundColor": format!("rgb({}, {}, {})", color.0, color.1, color.2),
+            "foregroundColor": "rgb(255, 255, 255)",
+            "labelColor": "rgb(209, 213, 219)",
+            "relevantDate": document.departure_at,
+            "barcodes": [barcode.clone()],
+            "barcode": barcode,
+            "boardingPass": {
+                "transitType": "PKTransitTypeAir",
+                "headerFields": [{
+                    "key": "gate",
+                    "label": "GATE",
+                    "value": optional(&document.gate),
+                    "changeMessage": "Gate changed to %@",
+                }],
+                "primaryFields": [
+                    field("origin", &document.origin_city, document.origin_iata_code.clone()),
+                    field(
+                        "destination",
+                        &document.destination_city,
+                        document.destination_iata_code.clone(),
+                    ),
+                ],
+                "secondaryFields": [
+                    field("passenger", "PASSENGER", document.passenger_name.clone()),
+                    field("seat", "SEAT", optional(&document.seat)),
+                ],
+                "auxiliaryFields": [
+                    field("flight", "FLIGHT", document.flight.clone()),
+                    {
+                        "key": "boarding",
+                        "label": "BOARDING",
+                        "value": optional(&document.boarding_time),
+                        "changeMessage": "Boarding time changed to %@",
+                    },
+                    field("departs", "DEPARTS", document.departure_time.clone()),
+                    field("group", "GROUP", optional(&document.boarding_group)),
+                ],
+                "backFields": [
+                    field("date", "DATE", document.departure_date.clone()),
+                    field("operatedBy", "OPERATED BY", optional(&document.operated_by)),
+                    field("arrives", "ARRIVES", document.arrival_time.clone()),
+                    field("terminal", "TERMINAL", optional(&document.terminal)),
+                    field("sequence", "SEQUENCE", optional(&document.sequence_no)),
+                    field("cabin", "CABIN", document.cabin.clone()),
+                    field("booking", "BOOKING REF", document.booking_code.clone()),
+                    field("code", "BOARDING PASS", document.boarding_pass_code.clone()),
+                    field("version", "PASS VERSION", version.to_string()),
+                    field("times", "NOTE", "Times are local to each airport".to_string()),
+                ],
+            },
+            "userInfo": { "version": version },
+        })
+    }
+
+    // Detached PKCS#7 over manifest.json, as Wallet expects in the `signature` file.
+    fn sign(&self, manifest: &[u8]) -> UseCaseResult<Vec<u8>> {
+        let mut chain = Stack::new().map_err(signing_error)?;
+        for certificate in &self.intermediates {
+            chain.push(certificate.clone()).map_err(signing_error)?;
+        }
+
+        Pkcs7::sign(
+            &self.certificate,
+            &self.private_key,
+            &chain,
+            manifest,
+            Pkcs7Flags::DETACHED | Pkcs7Flags::BINARY,
+        )
+        .and_then(|pkcs7| pkcs7.to_der())
+        .map_err(signing_error)
+    }
+}
+
+impl WalletPassGenerator for PkpassGenerator {
+    fn generate(&self, document: &BoardingPassDocument, version: i32) -> UseCaseResult<Vec<u8>> {
+        let pass_json = serde_json::to_vec_pretty(&self.pass_json(document, version))
+            .map_err(|e| UseCaseError::Unexpected(e.to_string()))?;
+
+        let mut files = vec![("pass.json".to_string(), pass_json)];
+        for scale in 1..=3 {
+            let name = match scale {
+                1 => "icon.png".to_string(),
+                _ => format!("icon@{scale}x.png"),
+            };
+            let page = icon(&self.branding, ICON_SIZE);
+            files.push((name, render_png(&page, ICON_SIZE, ICON_SIZE, scale)?));
+        }
+
+        let manifest: serde_json::Map<String, Value> = files
+            .iter()
+            .map(|(name, content)| (name.clone(), Value::String(sha1_hex(content))))
+            .collect();
+        let manifest = serde_json::to_vec_pretty(&manifest)
+            .map_err(|e| UseCaseError::Unexpected(e.to_string()))?;
+        let signature = self.sign(&manifest)?;
+
+        files.push(("manifest.json".to_string(), manifest));
+        files.push(("signature".to_string(), signature));
+
+        let mut zip = ZipWriter::new(Cursor::new(Vec::new()));
+        for (name, content) in &files {
+            zip.start_file(name.as_str(), SimpleFileOptions::default())
+                .map_err(packaging_error)?;
+            zip.write_all(content)
+                .map_err(|e| UseCaseError::Unexpected(format!("pkpass packaging failed: {e}")))?;
+        }
+        let bundle = zip.finish().map_err(packaging_error)?;
+
+        Ok(bundle.into_inner())
+    }
+}
+
+fn barcode_format(format: &str) -> &'static str {
+    match format
+        .trim()
+        .to_uppercase()
+        .replace(['_', '-'], "")
+        .as_str()
+    {
+        "PDF417" => "PKBarcodeFormatPDF417",
+        "AZTEC" => "PKBarcodeFormatAztec",
+        _ => "PKBarcodeFormatQR",
+    }
+}
+
+fn sha1_hex(content: &[u8]) -> String {
+    openssl::sha::sha1(content)
+        .iter()
+        .map(|b| format!("{b:02x}"))
+        .collect()
+}
+
+fn signing_error(e: openssl::error::ErrorStack) -> UseCaseError {
+    UseCaseError::Unexpected(format!("pkpass signing failed: {e}"))
+}
+
+fn packaging_error(e: zip::result::ZipError) -> UseCaseError {
+    UseCaseError::Unexpected(format!("pkpass packaging failed: {e}"))
+}
+
+#[cfg(test)]
+mod tests {
+    use std::io::{Cursor, Read};
+
+    use openssl::asn1::Asn1Time;
+    use openssl::bn::BigNum;
+    use openssl::hash::MessageDigest;
+    use openssl::pkcs7::{Pkcs7, Pkcs7Flags};
+    use openssl::pkey::{PKey, Private};
+    use openssl::rsa::Rsa;
+    use openssl::stack::Stack;
+    use openssl::x509::store::X509StoreBuilder;
+    use openssl::x509::{X509, X509NameBuilder};
+    use serde_json::{Map, Value};
+    use zip::ZipArchive;
+
+    use super::{PkpassGenerator, sha1_hex};
+    use crate::application::boarding_pass::boarding_pass_renderer::BoardingPassDocument;
+    use crate::application::boarding_pass::wallet_pass_generator::WalletPassGenerator;
+    use crate::infrastructure::document::boarding_pass_layout::{Branding, Rgb};
+
+    fn self_signed_certificate() -> (X509, PKey<Private>) {
+        let key = PKey::from_rsa(Rsa::generate(2048).unwrap()).unwrap();
+
+        let mut name = X509NameBuilder::new().unwrap();
+        name.append_entry_by_text("CN", "pass.com.example.boarding")
+            .unwrap();
+        let name = name.build();
+
+        let mut builder = X509::builder().unwrap();
+        builder.set_version(2).unwrap();
+        let serial = BigNum::from_u32(1).unwrap().to_asn1_integer().unwrap();
+        builder.set_serial_number(&serial).unwrap();
+        builder.set_subject_name(&name).unwrap();
+        builder.set_issuer_name(&name).unwrap();
+        builder.set_pubkey(&key).unwrap();
+        builder
+            .set_not_before(&Asn1Time::days_from_now(0).unwrap())
+            .unwrap();
+        builder
+            .set_not_after(&Asn1Time::days_from_now(1).unwrap())
+            .unwrap();
+        builder.sign(&key, MessageDigest::sha256()).unwrap();
+
+        (builder.build(), key)
+    }
+
+    fn document() -> BoardingPassDocument {
+        BoardingPassDocument {
+            boarding_pass_code: "BP7K2Q9X".to_string(),
+            booking_code: "ABC123".to_string(),
+            passenger_name: "NGUYEN/VAN AN".to_string(),
+            flight: "VN 245".to_string(),
+            operated_by: None,
+            cabin: "Economy".to_string(),
+            origin_iata_code: "HAN".to_string(),
+            origin_city: "Hanoi".to_string(),
+            destination_iata_code: "SGN".to_string(),
+            destination_city: "Ho Chi Minh City".to_string(),
+            departure_at: "2026-10-20T08:00:00+07:00".to_string(),
+            departure_date: "20 Oct 2026".to_string(),
+            departure_time: "08:00".to_string(),
+            arrival_time: "10:10".to_string(),
+            boarding_time: Some("07:20".to_string()),
+            terminal: Some("1".to_string()),
+            gate: Some("12".to_string()),
+            boarding_group: Some("3".to_string()),
+            sequence_no: Some("0042".to_string()),
+            seat: Some("23A".to_string()),
+            barcode_format: "QR".to_string(),
+            barcode_payload: "M1NGUYEN/VAN AN       EABC123 HANSGNVN 0245 293Y023A0042 100"
+                .to_string(),
+        }
+    }
+
+    fn read_entry(archive: &mut ZipArchive<Cursor<Vec<u8>>>, name: &str) -> Vec<u8> {
+        let mut content = Vec::new();
+        archive
+            .by_name(name)
+            .unwrap()
+            .read_to_end(&mut content)
+            .unwrap();
+        content
+    }
+
+    #[test]
+    fn pkpass_manifest_and_signature_are_valid() {
+        let (certificate, private_key) = self_signed_certificate();
+        let generator = PkpassGenerator {
+            pass_type_identifier: "pass.com.example.boarding".to_string(),
+            team_identifier: "TEAM123456".to_string(),
+            branding: Branding {
+                name: "Example Air".to_string(),
+                color: Rgb::from_hex("#0B3D91").unwrap(),
+            },
+            certificate: certificate.clone(),
+            private_key,
+            intermediates: Vec::new(),
+        };
+
+        let bundle = generator.generate(&document(), 3).unwrap();
+        let mut archive = ZipArchive::new(Cursor::new(bundle)).unwrap();
+
+        let pass: Value = serde_json::from_slice(&read_entry(&mut archive, "pass.json")).unwrap();
+        assert_eq!(pass["serialNumber"], "BP7K2Q9X");
+        assert_eq!(pass["userInfo"]["version"], 3);
+
+        // Every file except the manifest and signature is listed with its SHA-1.
+        let manifest_bytes = read_entry(&mut archive, "manifest.json");
+        let manifest: Map<String, Value> = serde_json::from_slice(&manifest_bytes).unwrap();
+        let mut listed: Vec<String> = manifest.keys().cloned().collect();
+        let mut packaged: Vec<String> = archive
+            .file_names()
+            .filter(|name| *name != "manifest.json" && *name != "signature")
+            .map(str::to_string)
+            .collect();
+        listed.sort();
+        packaged.sort();
+        assert_eq!(listed, packaged);
+        for (name, digest) in &manifest {
+            let content = read_entry(&mut archive, name);
+            assert_eq!(digest.as_str(), Some(sha1_hex(&content).as_str()), "{name}");
+        }
+
+        // Detached signature: verifies against manifest.json only when it is supplied.
+        let signature = Pkcs7::from_der(&read_entry(&mut archive, "signature")).unwrap();
+        let mut store = X509StoreBuilder::new().unwrap();
+        store.add_cert(certificate).unwrap();
+        let store = store.build();
+        let certs: Stack<X509> = Stack::new().unwrap();
+
+        signature
+            .verify(
+                &certs,
+                &store,
+                Some(&manifest_bytes),
+                None,
+                Pkcs7Flags::BINARY,
+            )
+            .unwrap();
+        assert!(
+            signature
+                .verify(&certs, &store, None, None, Pkcs7Flags::BINARY)
+                .is_err()
+        );
+
+        let mut tampered = manifest_bytes.clone();
+        tampered.extend_from_slice(b" ");
+        assert!(
+            signature
+                .verify(&certs, &store, Some(&tampered), None, Pkcs7Flags::BINARY)
+                .is_err()
+        );
+    }
+}
@@ -0,0 +1,136 @@
+use crate::application::common::use_case_error::{UseCaseError, UseCaseResult};
+use crate::infrastructure::document::bitmap_font::{
+    GLYPH_ADVANCE, GLYPH_HEIGHT, GLYPH_WIDTH, glyph,
+};
+use crate::infrastructure::document::boarding_pass_layout::{Primitive, Rgb};
+
+struct Canvas {
+    width: usize,
+    height: usize,
+    pixels: Vec<u8>,
+}
+
+impl Canvas {
+    fn new(width: usize, height: usize) -> Self {
+        Self {
+            width,
+            height,
+            pixels: vec![0xFF; width * height * 3],
+        }
+    }
+
+    fn fill(&mut self, x: i64, y: i64, width: i64, height: i64, color: Rgb) {
+        let x0 = x.clamp(0, self.width as i64) as usize;
+        let y0 = y.clamp(0, self.height as i64) as usize;
+        let x1 = (x + width).clamp(0, self.width as i64) as usize;
+        let y1 = (y + height).clamp(0, self.height as i64) as usize;
+
+        for row in y0..y1 {
+            for col in x0..x1 {
+                let i = (row * self.width + col) * 3;
+                self.pixels[i..i + 3].copy_from_slice(&[color.0, color.1, color.2]);
+            }
+        }
+    }
+}
+
+/// Rasterises the page at `scale` pixels per point and encodes it as an RGB PNG.
+pub fn render_png(
+    primitives: &[Primitive],
+    width: f32,
+    height: f32,
+    scale: u32,
+) -> UseCaseResult<Vec<u8>> {
+    let k = scale as f32;
+    let px = |v: f32| (v * k).round() as i64;
+    let mut canvas = Canvas::new(px(width) as usize, px(height) as usize);
+
+    for primitive in primitives {
+        match primitive {
+            Primitive::Rect {
+                x,
+                y,
+                width: w,
+                height: h,
+                color,
+            } => {
+                // Hairlines stay visible at low scales.
+                canvas.fill(px(*x), px(*y), px(*w).max(1), px(*h).max(1), *color);
+            }
+            Primitive::Text {
+                x,
+                y,
+                size,
+                bold,
+                color,
+                text,
+            } => {
+                // The 7-unit glyph height approximates the cap height of the PDF font.
+                let unit = ((size * k * 0.72) / GLYPH_HEIGHT as f32).round().max(1.0) as i64;
+                let dot_width = if *bold { unit + (unit + 1) / 2 } else { unit };
+                let (left, top) = (px(*x), px(*y));
+
+                for (index, c) in text.chars().enumerate() {
+                    let origin = left + (index * GLYPH_ADVANCE) as i64 * unit;
+                    for (row, bits) in glyph(c).iter().enumerate() {
+                        for col in 0..GLYPH_WIDTH {
+                            if bits & (0x10 >> col) != 0 {
+                                canvas.fill(
+                                    origin + col as i64 * unit,
+                                    top + row as i64 * unit,
+                                    dot_width,
+                                    unit,
+                                    *color,
+                                );
+                            }
+                        }
+                    }
+                }
+            }
+            Primitive::Barcode {
+                x,
+                y,
+                width: w,
+                height: h,
+                matrix,
+            } => {
+                // Whole-pixel modules keep the symbol sharp for scanners.
+                let module = (px(*w) / matrix.width as i64)
+                    .min(px(*h) / matrix.height as i64)
+                    .max(1);
+                let left = px(*x) + (px(*w) - module * matrix.width as i64) / 2;
+                let top = px(*y) + (px(*h) - module * matrix.height as i64) / 2;
+
+                for row in 0..matrix.height {
+                    for col in 0..matrix.width {
+                        if matrix.is_dark(col, row) {
+                            canvas.fill(
+                                left + col as i64 * module,
+                                top + row as i64 * module,
+                                module,
+                                module,
+                                Rgb::BLACK,
+                            );
+                        }
+                    }
+                }
+            }
+        }
+    }
+
+    let mut out = Vec::new();
+    let mut encoder = png::Encoder::new(&mut out, canvas.width as u32, canvas.height as u32);
+    encoder.set_color(png::ColorType::Rgb);
+    encoder.set_depth(png::BitDepth::Eight);
+    let mut writer = encoder
+        .write_header()
+        .map_err(|e| UseCaseError::Unexpected(format!("png encoding failed: {e}")))?;
+    writer
+        .write_image_data(&canvas.pixels)
+        .map_err(|e| UseCaseError::Unexpected(format!("png encoding failed: {e}")))?;
+    writer
+        .finish()
+        .map_err(|e| UseCaseError::Unexpected(format!("png encoding failed: {e}")))?;
+
+    Ok(out)
+}
@@ -2,6 +2,7 @@ use crate::core::app_state::AppState;
 use std::net::SocketAddr;
 
 use crate::infrastructure::http::app::build_app;
+use crate::infrastructure::runtime::shutdown;
 use anyhow::Result;
 use tokio::net::TcpListener;
 
@@ -21,7 +22,9 @@ impl AppServer {
     pub async fn run(self) -> Result<()> {
         let app = build_app(self.state);
 
-        axum::serve(self.tcp, app).await?;
+        axum::serve(self.tcp, app)
+            .with_graceful_shutdown(shutdown::wait_for_signal())
+            .await?;
 
         Ok(())
     }
@@ -0,0 +1,64 @@
+use std::sync::Arc;
+
+/// A message as read from a topic, detached from the client that read it.
+#[derive(Debug, Clone)]
+pub struct ConsumedMessage {
+    pub topic: String,
+    pub partition: i32,
+    pub offset: i64,
+    pub key: Option<Vec<u8>>,
+    pub payload: Option<Vec<u8>>,
+    pub headers: Vec<(String, Vec<u8>)>,
+}
+
+impl ConsumedMessage {
+    /// Last value of header `name`, when it is valid UTF-8.
+    pub fn header(&self, name: &str) -> Option<&str> {
+        self.headers
+            .iter()
+            .rev()
+            .find(|(key, _)| key == name)
+            .and_then(|(_, value)| std::str::from_utf8(value).ok())
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct OutgoingMessage {
+    pub topic: String,
+    pub key: Option<Vec<u8>>,
+    pub payload: Option<Vec<u8>>,
+    pub headers: Vec<(String, Vec<u8>)>,
+}
+
+#[derive(Debug, thiserror::Error)]
+#[error("{0}")]
+pub struct BrokerError(pub String);
+
+/// Messages of one consumer group, read in order per partition.
+#[async_trait::async_trait]
+pub trait MessageSource: Send + Sync {
+    async fn recv(&self) -> Result<ConsumedMessage, BrokerError>;
+
+    /// Marks `message` and everything before it on its partition as processed for the group.
+    fn commit(&self, message: &ConsumedMessage) -> Result<(), BrokerError>;
+
+    fn close(&self);
+}
+
+#[async_trait::async_trait]
+pub trait MessageSink: Send + Sync {
+    /// Resolves once the broker has accepted the message.
+    async fn send(&self, message: OutgoingMessage) -> Result<(), BrokerError>;
+}
+
+/// Where consumers read from and write retries and dead letters to: Kafka in the
+/// application, [`InMemoryBroker`](super::in_memory_broker::InMemoryBroker) in tests.
+pub trait MessageBroker: Send + Sync {
+    fn subscribe(
+        &self,
+        group_id: &str,
+        topics: &[String],
+    ) -> Result<Box<dyn MessageSource>, BrokerError>;
+
+    fn sink(&self) -> Arc<dyn MessageSink>;
+}
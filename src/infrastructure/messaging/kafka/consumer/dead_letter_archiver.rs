@@ -0,0 +1,150 @@
+use std::collections::BTreeMap;
+use std::sync::Arc;
+use std::time::Duration;
+
+use crate::application::dead_letter::dead_letter_command::RecordDeadLetterCommand;
+use crate::application::dead_letter::use_case::dead_letter_service_interface::DeadLetterServiceInterface;
+use crate::infrastructure::constants::kafka::{
+    KAFKA_HEADER_ATTEMPT, KAFKA_HEADER_CONSUMER_GROUP, KAFKA_HEADER_ERROR, KAFKA_HEADER_FAILED_AT,
+    KAFKA_HEADER_HANDLER, KAFKA_HEADER_ORIGINAL_OFFSET, KAFKA_HEADER_ORIGINAL_PARTITION,
+    KAFKA_HEADER_ORIGINAL_TOPIC,
+};
+use crate::infrastructure::error::{TechnicalError, TechnicalResult};
+use crate::infrastructure::messaging::kafka::consumer::broker::{
+    ConsumedMessage, MessageBroker, MessageSource,
+};
+use crate::infrastructure::messaging::kafka::consumer::event_consumer::{dead_letter_topic, pause};
+use crate::infrastructure::runtime::shutdown;
+
+const ARCHIVE_RETRY_DELAY: Duration = Duration::from_secs(5);
+
+/// Copies the dead-letter topics of the given consumer groups into the database, where
+/// admins inspect, replay or discard them. An offset is committed only once its message
+/// is stored, so nothing is lost while the database is down.
+pub struct DeadLetterArchiver {
+    source: Box<dyn MessageSource>,
+    service: Arc<dyn DeadLetterServiceInterface>,
+}
+
+impl DeadLetterArchiver {
+    pub fn new(
+        broker: &dyn MessageBroker,
+        group_id: &str,
+        consumer_groups: &[String],
+        service: Arc<dyn DeadLetterServiceInterface>,
+    ) -> TechnicalResult<Self> {
+        let topics: Vec<String> = consumer_groups
+            .iter()
+            .map(|group| dead_letter_topic(group))
+            .collect();
+        let source = broker.subscribe(group_id, &topics).map_err(|e| {
+            TechnicalError::InvalidConfig(format!("dead-letter archiver {group_id}: {e}"))
+        })?;
+        Ok(Self { source, service })
+    }
+
+    pub fn spawn(self) -> tokio::task::JoinHandle<()> {
+        tokio::spawn(self.run())
+    }
+
+    async fn run(self) {
+        tracing::info!("Dead-letter archiver started");
+
+        'consume: loop {
+            let received = tokio::select! {
+                biased;
+                _ = shutdown::requested() => break,
+                received = self.source.recv() => received,
+            };
+            let message = match received {
+                Ok(message) => message,
+                Err(err) => {
+                    tracing::warn!("Dead-letter archiver broker error: {}", err);
+                    if !pause(ARCHIVE_RETRY_DELAY).await {
+                        break;
+                    }
+                    continue;
+                }
+            };
+
+            let command = to_record_command(&message);
+            while let Err(err) = self.service.record_dead_letter(command.clone()).await {
+                tracing::warn!(
+                    "Cannot archive dead letter {}@{}:{}: {}",
+                    message.topic,
+                    message.partition,
+                    message.offset,
+                    err
+                );
+                if !pause(ARCHIVE_RETRY_DELAY).await {
+                    break 'consume;
+                }
+            }
+
+            let _ = self
+                .source
+                .commit(&message)
+                .inspect_err(|e| tracing::warn!("Error while committing offset: {}", e));
+        }
+
+        self.source.close();
+        tracing::info!("Dead-letter archiver stopped");
+    }
+}
+
+fn to_record_command(message: &ConsumedMessage) -> RecordDeadLetterCommand {
+    let header = |name: &str| message.header(name).unwrap_or_default().to_string();
+    let failed_at = message
+        .header(KAFKA_HEADER_FAILED_AT)
+        .and_then(|value| value.parse::<i64>().ok())
+        .and_then(chrono::DateTime::from_timestamp_millis)
+        .map(|at| at.naive_utc())
+        .unwrap_or_else(|| chrono::Utc::now().naive_utc());
+
+    RecordDeadLetterCommand {
+        // the topic name is derived from the group, should the header be missing
+        consumer_group: message
+            .header(KAFKA_HEADER_CONSUMER_GROUP)
+            .map(str::to_string)
+            .unwrap_or_else(|| consumer_group_of(&message.topic)),
+        handler: header(KAFKA_HEADER_HANDLER),
+        original_topic: header(KAFKA_HEADER_ORIGINAL_TOPIC),
+        original_partition: parse_header(message, KAFKA_HEADER_ORIGINAL_PARTITION),
+        original_offset: parse_header(message, KAFKA_HEADER_ORIGINAL_OFFSET),
+        message_key: message
+            .key
+            .as_deref()
+            .map(|key| String::from_utf8_lossy(key).into_owned()),
+        payload: message.payload.clone().unwrap_or_default(),
+        headers: headers_of(message),
+        error: header(KAFKA_HEADER_ERROR),
+        attempts: parse_header(message, KAFKA_HEADER_ATTEMPT),
+        dead_letter_topic: message.topic.clone(),
+        dead_letter_partition: message.partition,
+        dead_letter_offset: message.offset,
+        failed_at,
+    }
+}
+
+fn parse_header<T: std::str::FromStr + Default>(message: &ConsumedMessage, name: &str) -> T {
+    message
+        .header(name)
+        .and_then(|value| value.parse().ok())
+        .unwrap_or_default()
+}
+
+/// Headers as text; a header repeated on the message keeps its last value.
+fn headers_of(message: &ConsumedMessage) -> BTreeMap<String, String> {
+    message
+        .headers
+        .iter()
+        .map(|(key, value)| (key.clone(), String::from_utf8_lossy(value).into_owned()))
+        .collect()
+}
+
+fn consumer_group_of(topic: &str) -> String {
+    topic
+        .rsplit_once('.')
+        .map_or(topic, |(group, _)| group)
+        .to_string()
+}
@@ -0,0 +1,62 @@
+use std::sync::Arc;
+
+use crate::application::common::use_case_error::{UseCaseError, UseCaseResult};
+use crate::application::dead_letter::dead_letter_replayer::DeadLetterReplayer;
+use crate::domain::dead_letter::entity::DeadLetter;
+use crate::infrastructure::constants::kafka::{
+    KAFKA_HEADER_ATTEMPT, KAFKA_HEADER_HANDLER, KAFKA_HEADER_REPLAY_OF, KAFKA_HEADER_RETRY_AT,
+};
+use crate::infrastructure::messaging::kafka::consumer::broker::{MessageSink, OutgoingMessage};
+use crate::infrastructure::messaging::kafka::consumer::event_consumer::replay_topic;
+
+/// Publishes a dead letter to its consumer group's replay topic, which runs the named
+/// handler once more with a fresh set of attempts.
+pub struct BrokerDeadLetterReplayer {
+    sink: Arc<dyn MessageSink>,
+}
+
+impl BrokerDeadLetterReplayer {
+    pub fn new(sink: Arc<dyn MessageSink>) -> Self {
+        Self { sink }
+    }
+}
+
+#[async_trait::async_trait]
+impl DeadLetterReplayer for BrokerDeadLetterReplayer {
+    async fn replay(&self, dead_letter: &DeadLetter) -> UseCaseResult<()> {
+        // The archived headers still carry the original position and the last error; the
+        // consumer replaces its own headers when it retries or dead-letters again.
+        let mut headers: Vec<(String, Vec<u8>)> = dead_letter
+            .headers
+            .iter()
+            .map(|(key, value)| (key.clone(), value.clone().into_bytes()))
+            .collect();
+        headers.extend(
+            [
+                (KAFKA_HEADER_HANDLER, dead_letter.handler.clone()),
+                (KAFKA_HEADER_ATTEMPT, "1".to_string()),
+                (
+                    KAFKA_HEADER_RETRY_AT,
+                    chrono::Utc::now().timestamp_millis().to_string(),
+                ),
+                (KAFKA_HEADER_REPLAY_OF, dead_letter.id.to_string()),
+            ]
+            .map(|(key, value)| (key.to_string(), value.into_bytes())),
+        );
+
+        self.sink
+            .send(OutgoingMessage {
+                topic: replay_topic(&dead_letter.consumer_group),
+                key: dead_letter.message_key.clone().map(String::into_bytes),
+                payload: Some(dead_letter.payload.clone()),
+                headers,
+            })
+            .await
+            .map_err(|e| {
+                UseCaseError::Unexpected(format!(
+                    "cannot replay dead letter {}: {e}",
+                    dead_letter.id
+                ))
+            })
+    }
+}
@@ -0,0 +1,489 @@
+use std::collections::HashSet;
+use std::marker::PhantomData;
+use std::sync::Arc;
+use std::time::Duration;
+
+use crate::application::common::use_case_error::UseCaseError;
+use crate::infrastructure::constants::kafka::{
+    KAFKA_DEAD_LETTER_TOPIC_SUFFIX, KAFKA_HEADER_ATTEMPT, KAFKA_HEADER_CONSUMER_GROUP,
+    KAFKA_HEADER_ERROR, KAFKA_HEADER_FAILED_AT, KAFKA_HEADER_HANDLER, KAFKA_HEADER_ORIGINAL_OFFSET,
+    KAFKA_HEADER_ORIGINAL_PARTITION, KAFKA_HEADER_ORIGINAL_TOPIC, KAFKA_HEADER_REPLAY_OF,
+    KAFKA_HEADER_RETRY_AT, KAFKA_REPLAY_TOPIC_SUFFIX, KAFKA_RETRY_TOPIC_SUFFIX,
+};
+use crate::infrastructure::error::{TechnicalError, TechnicalResult};
+use crate::infrastructure::messaging::kafka::consumer::broker::{
+    ConsumedMessage, MessageBroker, MessageSink, MessageSource, OutgoingMessage,
+};
+use crate::infrastructure::messaging::kafka::consumer::event_handler::{ErrorPolicy, EventHandler};
+use crate::infrastructure::messaging::kafka::consumer::kafka_event::KafkaEvent;
+use crate::infrastructure::runtime::shutdown;
+
+const BROKER_ERROR_DELAY: Duration = Duration::from_secs(5);
+
+// Headers this consumer sets; dropped from the original headers before they are set again.
+const CONSUMER_HEADERS: [&str; 10] = [
+    KAFKA_HEADER_ORIGINAL_TOPIC,
+    KAFKA_HEADER_ORIGINAL_PARTITION,
+    KAFKA_HEADER_ORIGINAL_OFFSET,
+    KAFKA_HEADER_CONSUMER_GROUP,
+    KAFKA_HEADER_HANDLER,
+    KAFKA_HEADER_ATTEMPT,
+    KAFKA_HEADER_RETRY_AT,
+    KAFKA_HEADER_ERROR,
+    KAFKA_HEADER_FAILED_AT,
+    KAFKA_HEADER_REPLAY_OF,
+];
+
+pub fn retry_topic(group_id: &str, tier: u32) -> String {
+    format!("{group_id}.{KAFKA_RETRY_TOPIC_SUFFIX}.{tier}")
+}
+
+pub fn replay_topic(group_id: &str) -> String {
+    format!("{group_id}.{KAFKA_REPLAY_TOPIC_SUFFIX}")
+}
+
+pub fn dead_letter_topic(group_id: &str) -> String {
+    format!("{group_id}.{KAFKA_DEAD_LETTER_TOPIC_SUFFIX}")
+}
+
+enum HandlingError {
+    /// The payload is not the registered event; retrying will not help.
+    Malformed(String),
+    Failed(UseCaseError),
+}
+
+#[async_trait::async_trait]
+trait RawHandler: Send + Sync {
+    async fn handle(&self, payload: &[u8]) -> Result<(), HandlingError>;
+}
+
+struct TypedHandler<E, H: ?Sized> {
+    handler: Arc<H>,
+    _event: PhantomData<fn() -> E>,
+}
+
+#[async_trait::async_trait]
+impl<E, H> RawHandler for TypedHandler<E, H>
+where
+    E: KafkaEvent,
+    H: EventHandler<E> + ?Sized,
+{
+    async fn handle(&self, payload: &[u8]) -> Result<(), HandlingError> {
+        let event: E =
+            serde_json::from_slice(payload).map_err(|e| HandlingError::Malformed(e.to_string()))?;
+        self.handler
+            .handle(event)
+            .await
+            .map_err(HandlingError::Failed)
+    }
+}
+
+struct Route {
+    /// `Handler<Event>`; names the handler on retried and dead-lettered messages.
+    id: String,
+    topic: &'static str,
+    policy: ErrorPolicy,
+    handler: Box<dyn RawHandler>,
+}
+
+#[derive(Debug, Clone, Copy)]
+enum Stage {
+    /// The event topics: every handler of the topic runs.
+    Events,
+    /// A retry or replay topic: only the handler named on the message runs, once it is due.
+    Redelivery,
+}
+
+enum Delivery {
+    Done,
+    Stop,
+}
+
+/// Collects the handlers of one consumer group, keyed by the topic of their event type.
+pub struct EventConsumerBuilder {
+    group_id: String,
+    routes: Vec<Route>,
+}
+
+impl EventConsumerBuilder {
+    pub fn new(group_id: impl Into<String>) -> Self {
+        Self {
+            group_id: group_id.into(),
+            routes: Vec::new(),
+        }
+    }
+
+    /// Routes every `E` published on `E::topic()` to `handler`. A topic may have several
+    /// handlers; they run in registration order.
+    pub fn on<E, H>(mut self, handler: Arc<H>, policy: ErrorPolicy) -> Self
+    where
+        E: KafkaEvent,
+        H: EventHandler<E> + ?Sized + 'static,
+    {
+        self.routes.push(Route {
+            id: format!("{}<{}>", short_type_name::<H>(), short_type_name::<E>()),
+            topic: E::topic(),
+            policy,
+            handler: Box::new(TypedHandler {
+                handler,
+                _event: PhantomData,
+            }),
+        });
+        self
+    }
+
+    /// Subscribes to the event topics, one retry topic per retry attempt the policies
+    /// allow, and the replay topic. Each runs in its own consumer group derived from
+    /// `group_id`, so a message waiting for its retry time does not hold up new events.
+    pub fn build(self, broker: &dyn MessageBroker) -> TechnicalResult<EventConsumer> {
+        if self.routes.is_empty() {
+            return Err(TechnicalError::InvalidConfig(format!(
+                "event consumer {} has no handlers",
+                self.group_id
+            )));
+        }
+        let mut ids = HashSet::new();
+        if let Some(route) = self.routes.iter().find(|route| !ids.insert(&route.id)) {
+            return Err(TechnicalError::InvalidConfig(format!(
+                "event consumer {} registers {} twice",
+                self.group_id, route.id
+            )));
+        }
+
+        let mut topics: Vec<String> = Vec::new();
+        for route in &self.routes {
+            if !topics.iter().any(|topic| topic == route.topic) {
+                topics.push(route.topic.to_string());
+            }
+        }
+        let retry_tiers = self
+            .routes
+            .iter()
+            .filter_map(|route| match route.policy {
+                ErrorPolicy::Retry { max_attempts, .. } => Some(max_attempts.saturating_sub(1)),
+                _ => None,
+            })
+            .max()
+            .unwrap_or(0);
+
+        let subscribe = |group_id: &str, topics: &[String]| {
+            broker.subscribe(group_id, topics).map_err(|e| {
+                TechnicalError::InvalidConfig(format!("event consumer {group_id}: {e}"))
+            })
+        };
+        let mut sources = vec![(Stage::Events, subscribe(&self.group_id, &topics)?)];
+        for tier in 1..=retry_tiers {
+            let topic = retry_topic(&self.group_id, tier);
+            sources.push((Stage::Redelivery, subscribe(&topic, &[topic.clone()])?));
+        }
+        let topic = replay_topic(&self.group_id);
+        sources.push((Stage::Redelivery, subscribe(&topic, &[topic.clone()])?));
+
+        Ok(EventConsumer {
+            core: Arc::new(ConsumerCore {
+                group_id: self.group_id,
+                routes: self.routes,
+                sink: broker.sink(),
+            }),
+            sources,
+        })
+    }
+}
+
+/// Feeds messages to the registered handlers one at a time and commits each offset once
+/// every handler has succeeded or its message has been handed to a retry or dead-letter
+/// topic. Stops on process shutdown after the message in hand.
+pub struct EventConsumer {
+    core: Arc<ConsumerCore>,
+    sources: Vec<(Stage, Box<dyn MessageSource>)>,
+}
+
+struct ConsumerCore {
+    group_id: String,
+    routes: Vec<Route>,
+    sink: Arc<dyn MessageSink>,
+}
+
+impl EventConsumer {
+    pub fn spawn(self) -> tokio::task::JoinHandle<()> {
+        let core = self.core;
+        let loops = self
+            .sources
+            .into_iter()
+            .map(|(stage, source)| core.clone().run(stage, source));
+        tokio::spawn(async move {
+            futures::future::join_all(loops).await;
+        })
+    }
+}
+
+impl ConsumerCore {
+    async fn run(self: Arc<Self>, stage: Stage, source: Box<dyn MessageSource>) {
+        tracing::info!("Event consumer {} started ({:?})", self.group_id, stage);
+
+        loop {
+            let received = tokio::select! {
+                biased;
+                _ = shutdown::requested() => break,
+                received = source.recv() => received,
+            };
+            let message = match received {
+                Ok(message) => message,
+                Err(err) => {
+                    tracing::warn!("Event consumer {} broker error: {}", self.group_id, err);
+                    if !pause(BROKER_ERROR_DELAY).await {
+                        break;
+                    }
+                    continue;
+                }
+            };
+
+            let delivery = match stage {
+                Stage::Events => self.process_event(&message).await,
+                Stage::Redelivery => self.process_redelivery(&message).await,
+            };
+            if let Delivery::Stop = delivery {
+                break;
+            }
+            let _ = source
+                .commit(&message)
+                .inspect_err(|e| tracing::warn!("Error while committing offset: {}", e));
+        }
+
+        source.close();
+        tracing::info!("Event consumer {} stopped ({:?})", self.group_id, stage);
+    }
+
+    async fn process_event(&self, message: &ConsumedMessage) -> Delivery {
+        for route in self.routes.iter().filter(|r| r.topic == message.topic) {
+            if let Delivery::Stop = self.deliver(route, message, 1).await {
+                return Delivery::Stop;
+            }
+        }
+        Delivery::Done
+    }
+
+    async fn process_redelivery(&self, message: &ConsumedMessage) -> Delivery {
+        let handler = message.header(KAFKA_HEADER_HANDLER).unwrap_or_default();
+        let attempt = message
+            .header(KAFKA_HEADER_ATTEMPT)
+            .and_then(|value| value.parse().ok())
+            .unwrap_or(1);
+        let Some(route) = self.routes.iter().find(|route| route.id == handler) else {
+            return self
+                .dead_letter(
+                    handler,
+                    message,
+                    attempt,
+                    "no handler of that name in this consumer group",
+                )
+                .await;
+        };
+
+        let due_in = message
+            .header(KAFKA_HEADER_RETRY_AT)
+            .and_then(|value| value.parse::<i64>().ok())
+            .map(|retry_at| retry_at - chrono::Utc::now().timestamp_millis())
+            .filter(|millis| *millis > 0)
+            .map(|millis| Duration::from_millis(millis as u64))
+            .unwrap_or_default();
+        if !due_in.is_zero() && !pause(due_in).await {
+            return Delivery::Stop;
+        }
+
+        self.deliver(route, message, attempt).await
+    }
+
+    async fn deliver(&self, route: &Route, message: &ConsumedMessage, attempt: u32) -> Delivery {
+        let payload = message.payload.as_deref().unwrap_or_default();
+        let error = match route.handler.handle(payload).await {
+            Ok(()) => return Delivery::Done,
+            Err(HandlingError::Malformed(err)) => {
+                return self
+                    .dead_letter(
+                        &route.id,
+                        message,
+                        attempt,
+                        &format!("malformed payload: {err}"),
+                    )
+                    .await;
+            }
+            Err(HandlingError::Failed(err)) => err.to_string(),
+        };
+
+        match route.policy {
+            ErrorPolicy::Skip => {
+                tracing::error!(
+                    "{} skipped {} {}: {}",
+                    self.group_id,
+                    route.id,
+                    describe(message),
+                    error
+                );
+                Delivery::Done
+            }
+            ErrorPolicy::Stop => {
+                tracing::error!(
+                    "{} stopped on {} {}: {}",
+                    self.group_id,
+                    route.id,
+                    describe(message),
+                    error
+                );
+                Delivery::Stop
+            }
+            ErrorPolicy::DeadLetter => self.dead_letter(&route.id, message, attempt, &error).await,
+            ErrorPolicy::Retry { .. } => match route.policy.next_delay(attempt) {
+                Some(delay) => self.retry(route, message, attempt, delay, &error).await,
+                None => self.dead_letter(&route.id, message, attempt, &error).await,
+            },
+        }
+    }
+
+    async fn retry(
+        &self,
+        route: &Route,
+        message: &ConsumedMessage,
+        attempt: u32,
+        delay: Duration,
+        error: &str,
+    ) -> Delivery {
+        tracing::warn!(
+            "{} failed {} (attempt {}) {}, retrying in {:?}: {}",
+            self.group_id,
+            route.id,
+            attempt,
+            describe(message),
+            delay,
+            error
+        );
+        let retry_at = chrono::Utc::now().timestamp_millis() + delay.as_millis() as i64;
+        let mut headers = origin_headers(message);
+        headers.extend([
+            header(KAFKA_HEADER_CONSUMER_GROUP, &self.group_id),
+            header(KAFKA_HEADER_HANDLER, &route.id),
+            header(KAFKA_HEADER_ATTEMPT, &(attempt + 1).to_string()),
+            header(KAFKA_HEADER_RETRY_AT, &retry_at.to_string()),
+            header(KAFKA_HEADER_ERROR, error),
+        ]);
+
+        self.publish(OutgoingMessage {
+            topic: retry_topic(&self.group_id, attempt),
+            key: message.key.clone(),
+            payload: message.payload.clone(),
+            headers,
+        })
+        .await
+    }
+
+    async fn dead_letter(
+        &self,
+        handler: &str,
+        message: &ConsumedMessage,
+        attempts: u32,
+        error: &str,
+    ) -> Delivery {
+        tracing::error!(
+            "{} dead-lettered {} after {} attempt(s) {}: {}",
+            self.group_id,
+            handler,
+            attempts,
+            describe(message),
+            error
+        );
+        let mut headers = origin_headers(message);
+        headers.extend([
+            header(KAFKA_HEADER_CONSUMER_GROUP, &self.group_id),
+            header(KAFKA_HEADER_HANDLER, handler),
+            header(KAFKA_HEADER_ATTEMPT, &attempts.to_string()),
+            header(KAFKA_HEADER_ERROR, error),
+            header(
+                KAFKA_HEADER_FAILED_AT,
+                &chrono::Utc::now().timestamp_millis().to_string(),
+            ),
+        ]);
+
+        self.publish(OutgoingMessage {
+            topic: dead_letter_topic(&self.group_id),
+            key: message.key.clone(),
+            payload: message.payload.clone(),
+            headers,
+        })
+        .await
+    }
+
+    // The source message is only committed once its retry or dead letter is on the
+    // broker, so publishing is retried until it succeeds or the process stops.
+    async fn publish(&self, message: OutgoingMessage) -> Delivery {
+        loop {
+            match self.sink.send(message.clone()).await {
+                Ok(()) => return Delivery::Done,
+                Err(err) => {
+                    tracing::warn!(
+                        "{} cannot publish to {}: {}",
+                        self.group_id,
+                        message.topic,
+                        err
+                    );
+                    if !pause(BROKER_ERROR_DELAY).await {
+                        return Delivery::Stop;
+                    }
+                }
+            }
+        }
+    }
+}
+
+/// The message's own headers plus where it was first consumed from, which a retried
+/// message already carries.
+fn origin_headers(message: &ConsumedMessage) -> Vec<(String, Vec<u8>)> {
+    let topic = message
+        .header(KAFKA_HEADER_ORIGINAL_TOPIC)
+        .unwrap_or(&message.topic)
+        .to_string();
+    let partition = message
+        .header(KAFKA_HEADER_ORIGINAL_PARTITION)
+        .map(str::to_string)
+        .unwrap_or_else(|| message.partition.to_string());
+    let offset = message
+        .header(KAFKA_HEADER_ORIGINAL_OFFSET)
+        .map(str::to_string)
+        .unwrap_or_else(|| message.offset.to_string());
+
+    let mut headers: Vec<(String, Vec<u8>)> = message
+        .headers
+        .iter()
+        .filter(|(key, _)| !CONSUMER_HEADERS.contains(&key.as_str()))
+        .cloned()
+        .collect();
+    headers.extend([
+        header(KAFKA_HEADER_ORIGINAL_TOPIC, &topic),
+        header(KAFKA_HEADER_ORIGINAL_PARTITION, &partition),
+        header(KAFKA_HEADER_ORIGINAL_OFFSET, &offset),
+    ]);
+    headers
+}
+
+fn header(key: &str, value: &str) -> (String, Vec<u8>) {
+    (key.to_string(), value.as_bytes().to_vec())
+}
+
+fn describe(message: &ConsumedMessage) -> String {
+    format!(
+        "topic={} partition={} offset={}",
+        message.topic, message.partition, message.offset
+    )
+}
+
+fn short_type_name<T: ?Sized>() -> &'static str {
+    let name = std::any::type_name::<T>();
+    name.rsplit("::").next().unwrap_or(name)
+}
+
+/// Sleeps for `delay`; `false` when shutdown was requested meanwhile.
+pub(crate) async fn pause(delay: Duration) -> bool {
+    tokio::select! {
+        _ = shutdown::requested() => false,
+        _ = tokio::time::sleep(delay) => true,
+    }
+}
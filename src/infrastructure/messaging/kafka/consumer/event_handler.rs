@@ -0,0 +1,52 @@
+use std::time::Duration;
+
+use crate::application::common::use_case_error::UseCaseResult;
+use crate::infrastructure::messaging::kafka::consumer::kafka_event::KafkaEvent;
+
+/// Reacts to one event type. Handlers must be idempotent: a message is redelivered when
+/// the process stops before its offset is committed.
+#[async_trait::async_trait]
+pub trait EventHandler<E: KafkaEvent>: Send + Sync {
+    async fn handle(&self, event: E) -> UseCaseResult<()>;
+}
+
+/// What the consumer does when a handler fails. Payloads that do not deserialize into the
+/// event are dead-lettered whatever the policy.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ErrorPolicy {
+    /// Log the failure and move on to the next message.
+    Skip,
+    /// Publish the message to the group's dead-letter topic right away.
+    DeadLetter,
+    /// Try again up to `max_attempts` times in all through the group's retry topics,
+    /// waiting `backoff` before the first retry and doubling it each time; dead-letter the
+    /// message after the last attempt.
+    Retry {
+        max_attempts: u32,
+        backoff: Duration,
+    },
+    /// Stop consuming without committing, so the message is redelivered after a restart.
+    Stop,
+}
+
+impl ErrorPolicy {
+    pub fn retry(max_attempts: u32, backoff: Duration) -> Self {
+        ErrorPolicy::Retry {
+            max_attempts,
+            backoff,
+        }
+    }
+
+    /// Wait before attempt `attempt + 1`, or `None` when no attempt is left.
+    pub fn next_delay(&self, attempt: u32) -> Option<Duration> {
+        match self {
+            ErrorPolicy::Retry {
+                max_attempts,
+                backoff,
+            } if attempt < *max_attempts => {
+                Some(backoff.saturating_mul(2u32.saturating_pow(attempt - 1)))
+            }
+            _ => None,
+        }
+    }
+}
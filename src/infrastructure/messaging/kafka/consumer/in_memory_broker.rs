@@ -0,0 +1,453 @@
+use std::collections::HashMap;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::{Arc, Mutex, MutexGuard};
+
+use tokio::sync::Notify;
+
+use crate::infrastructure::messaging::kafka::consumer::broker::{
+    BrokerError, ConsumedMessage, MessageBroker, MessageSink, MessageSource, OutgoingMessage,
+};
+
+/// Kafka stand-in for tests and local runs: each topic is a single in-memory partition and
+/// each group remembers its committed offset per topic. A new source resumes after the
+/// group's last commit, so redelivery after a "restart" behaves as it does on Kafka.
+#[derive(Default)]
+pub struct InMemoryBroker {
+    state: Mutex<BrokerState>,
+    appended: Notify,
+}
+
+#[derive(Default)]
+struct BrokerState {
+    topics: HashMap<String, Vec<ConsumedMessage>>,
+    // (group, topic) -> next offset to read
+    committed: HashMap<(String, String), i64>,
+}
+
+pub struct InMemoryMessageSource {
+    broker: Arc<InMemoryBroker>,
+    group_id: String,
+    topics: Vec<String>,
+    positions: Mutex<HashMap<String, i64>>,
+    closed: AtomicBool,
+}
+
+impl InMemoryBroker {
+    fn state(&self) -> MutexGuard<'_, BrokerState> {
+        self.state
+            .lock()
+            .unwrap_or_else(|poisoned| poisoned.into_inner())
+    }
+
+    fn append(&self, message: OutgoingMessage) {
+        let mut state = self.state();
+        let log = state.topics.entry(message.topic.clone()).or_default();
+        log.push(ConsumedMessage {
+            offset: log.len() as i64,
+            topic: message.topic,
+            partition: 0,
+            key: message.key,
+            payload: message.payload,
+            headers: message.headers,
+        });
+        drop(state);
+        self.appended.notify_waiters();
+    }
+
+    /// Everything published to `topic` so far, oldest first.
+    pub fn messages(&self, topic: &str) -> Vec<ConsumedMessage> {
+        self.state().topics.get(topic).cloned().unwrap_or_default()
+    }
+
+    /// Next offset `group_id` will read on `topic`, if it has committed anything.
+    pub fn committed_offset(&self, group_id: &str, topic: &str) -> Option<i64> {
+        self.state()
+            .committed
+            .get(&(group_id.to_string(), topic.to_string()))
+            .copied()
+    }
+
+    pub fn source(self: &Arc<Self>, group_id: &str, topics: &[String]) -> InMemoryMessageSource {
+        InMemoryMessageSource {
+            broker: self.clone(),
+            group_id: group_id.to_string(),
+            topics: topics.to_vec(),
+            positions: Mutex::new(HashMap::new()),
+            closed: AtomicBool::new(false),
+        }
+    }
+}
+
+impl InMemoryMessageSource {
+    fn next_message(&self) -> Option<ConsumedMessage> {
+        let state = self.broker.state();
+        let mut positions = self
+            .positions
+            .lock()
+            .unwrap_or_else(|poisoned| poisoned.into_inner());
+        for topic in &self.topics {
+            let position = *positions.entry(topic.clone()).or_insert_with(|| {
+                state
+                    .committed
+                    .get(&(self.group_id.clone(), topic.clone()))
+                    .copied()
+                    .unwrap_or(0)
+            });
+            let next = state
+                .topics
+                .get(topic)
+                .and_then(|log| log.get(position as usize));
+            if let Some(message) = next {
+                positions.insert(topic.clone(), position + 1);
+                return Some(message.clone());
+            }
+        }
+        None
+    }
+}
+
+#[async_trait::async_trait]
+impl MessageSource for InMemoryMessageSource {
+    async fn recv(&self) -> Result<ConsumedMessage, BrokerError> {
+        loop {
+            // registered before looking, so an append in between is not missed
+            let appended = self.broker.appended.notified();
+            if self.closed.load(Ordering::Acquire) {
+                return Err(BrokerError("source closed".to_string()));
+            }
+            if let Some(message) = self.next_message() {
+                return Ok(message);
+            }
+            appended.await;
+        }
+    }
+
+    fn commit(&self, message: &ConsumedMessage) -> Result<(), BrokerError> {
+        let mut state = self.broker.state();
+        let next = state
+            .committed
+            .entry((self.group_id.clone(), message.topic.clone()))
+            .or_insert(0);
+        *next = (*next).max(message.offset + 1);
+        Ok(())
+    }
+
+    fn close(&self) {
+        self.closed.store(true, Ordering::Release);
+        self.broker.appended.notify_waiters();
+    }
+}
+
+#[async_trait::async_trait]
+impl MessageSink for InMemoryBroker {
+    async fn send(&self, message: OutgoingMessage) -> Result<(), BrokerError> {
+        self.append(message);
+        Ok(())
+    }
+}
+
+// Implemented on the `Arc` so sources can keep the broker alive.
+impl MessageBroker for Arc<InMemoryBroker> {
+    fn subscribe(
+        &self,
+        group_id: &str,
+        topics: &[String],
+    ) -> Result<Box<dyn MessageSource>, BrokerError> {
+        Ok(Box::new(self.source(group_id, topics)))
+    }
+
+    fn sink(&self) -> Arc<dyn MessageSink> {
+        self.clone()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::{Arc, Mutex};
+    use std::time::{Duration, Instant};
+
+    use serde::Deserialize;
+
+    use super::InMemoryBroker;
+    use crate::application::common::use_case_error::{UseCaseError, UseCaseResult};
+    use crate::application::dead_letter::dead_letter_replayer::DeadLetterReplayer;
+    use crate::domain::dead_letter::entity::{DeadLetter, RecordDeadLetterProps};
+    use crate::infrastructure::constants::kafka::{
+        KAFKA_HEADER_ATTEMPT, KAFKA_HEADER_ERROR, KAFKA_HEADER_HANDLER,
+        KAFKA_HEADER_ORIGINAL_OFFSET, KAFKA_HEADER_ORIGINAL_TOPIC,
+    };
+    use crate::infrastructure::messaging::kafka::consumer::broker::{
+        ConsumedMessage, MessageSink, OutgoingMessage,
+    };
+    use crate::infrastructure::messaging::kafka::consumer::dead_letter_replayer::BrokerDeadLetterReplayer;
+    use crate::infrastructure::messaging::kafka::consumer::event_consumer::{
+        EventConsumerBuilder, dead_letter_topic, replay_topic, retry_topic,
+    };
+    use crate::infrastructure::messaging::kafka::consumer::event_handler::{
+        ErrorPolicy, EventHandler,
+    };
+    use crate::infrastructure::messaging::kafka::consumer::kafka_event::KafkaEvent;
+
+    const GROUP: &str = "test-consumer";
+    const TOPIC: &str = "test.pings";
+    const BACKOFF: Duration = Duration::from_millis(10);
+
+    #[derive(Debug, Deserialize)]
+    struct Ping {
+        id: i64,
+    }
+
+    impl KafkaEvent for Ping {
+        fn topic() -> &'static str {
+            TOPIC
+        }
+    }
+
+    /// Fails its first `failures` calls, then succeeds.
+    struct Flaky {
+        failures: usize,
+        calls: Mutex<Vec<i64>>,
+    }
+
+    impl Flaky {
+        fn new(failures: usize) -> Arc<Self> {
+            Arc::new(Self {
+                failures,
+                calls: Mutex::new(Vec::new()),
+            })
+        }
+
+        fn calls(&self) -> usize {
+            self.calls.lock().unwrap().len()
+        }
+    }
+
+    #[async_trait::async_trait]
+    impl EventHandler<Ping> for Flaky {
+        async fn handle(&self, event: Ping) -> UseCaseResult<()> {
+            let mut calls = self.calls.lock().unwrap();
+            calls.push(event.id);
+            if calls.len() <= self.failures {
+                return Err(UseCaseError::Unexpected(format!(
+                    "ping {} failed",
+                    event.id
+                )));
+            }
+            Ok(())
+        }
+    }
+
+    async fn publish(broker: &InMemoryBroker, topic: &str, payload: &[u8]) {
+        broker
+            .send(OutgoingMessage {
+                topic: topic.to_string(),
+                key: Some(b"key".to_vec()),
+                payload: Some(payload.to_vec()),
+                headers: Vec::new(),
+            })
+            .await
+            .unwrap();
+    }
+
+    async fn wait_until(what: &str, done: impl Fn() -> bool) {
+        let deadline = Instant::now() + Duration::from_secs(5);
+        while !done() {
+            assert!(Instant::now() < deadline, "timed out waiting for {what}");
+            tokio::time::sleep(Duration::from_millis(5)).await;
+        }
+    }
+
+    fn start(
+        broker: &Arc<InMemoryBroker>,
+        handler: &Arc<Flaky>,
+        policy: ErrorPolicy,
+    ) -> tokio::task::JoinHandle<()> {
+        EventConsumerBuilder::new(GROUP)
+            .on::<Ping, Flaky>(handler.clone(), policy)
+            .build(broker)
+            .unwrap()
+            .spawn()
+    }
+
+    fn header<'a>(message: &'a ConsumedMessage, name: &str) -> &'a str {
+        message.header(name).unwrap_or_default()
+    }
+
+    #[tokio::test]
+    async fn retries_through_each_tier_until_the_handler_succeeds() {
+        let broker = Arc::new(InMemoryBroker::default());
+        let handler = Flaky::new(2);
+        let consumer = start(&broker, &handler, ErrorPolicy::retry(3, BACKOFF));
+
+        publish(&broker, TOPIC, br#"{"id":7}"#).await;
+        let last_tier = retry_topic(GROUP, 2);
+        wait_until("the third attempt to be committed", || {
+            broker.committed_offset(&last_tier, &last_tier) == Some(1)
+        })
+        .await;
+        consumer.abort();
+
+        assert_eq!(*handler.calls.lock().unwrap(), vec![7, 7, 7]);
+        for (tier, attempt) in [(1, "2"), (2, "3")] {
+            let retried = broker.messages(&retry_topic(GROUP, tier));
+            assert_eq!(retried.len(), 1, "retry tier {tier}");
+            assert_eq!(header(&retried[0], KAFKA_HEADER_ATTEMPT), attempt);
+            assert_eq!(header(&retried[0], KAFKA_HEADER_HANDLER), "Flaky<Ping>");
+            assert_eq!(header(&retried[0], KAFKA_HEADER_ORIGINAL_TOPIC), TOPIC);
+            assert_eq!(header(&retried[0], KAFKA_HEADER_ORIGINAL_OFFSET), "0");
+        }
+        assert!(broker.messages(&dead_letter_topic(GROUP)).is_empty());
+        assert_eq!(broker.committed_offset(GROUP, TOPIC), Some(1));
+    }
+
+    #[tokio::test]
+    async fn dead_letters_after_max_attempts() {
+        let broker = Arc::new(InMemoryBroker::default());
+        let handler = Flaky::new(usize::MAX);
+        let consumer = start(&broker, &handler, ErrorPolicy::retry(2, BACKOFF));
+
+        publish(&broker, TOPIC, br#"{"id":1}"#).await;
+        let dead_letters = dead_letter_topic(GROUP);
+        wait_until("the dead letter", || {
+            !broker.messages(&dead_letters).is_empty()
+        })
+        .await;
+        let tier = retry_topic(GROUP, 1);
+        wait_until("the retry to be committed", || {
+            broker.committed_offset(&tier, &tier) == Some(1)
+        })
+        .await;
+        consumer.abort();
+
+        assert_eq!(handler.calls(), 2);
+        assert!(broker.messages(&retry_topic(GROUP, 2)).is_empty());
+        let dead = broker.messages(&dead_letters);
+        assert_eq!(dead.len(), 1);
+        assert_eq!(header(&dead[0], KAFKA_HEADER_ATTEMPT), "2");
+        assert_eq!(
+            header(&dead[0], KAFKA_HEADER_ERROR),
+            "unexpected error: ping 1 failed"
+        );
+        assert_eq!(header(&dead[0], KAFKA_HEADER_ORIGINAL_TOPIC), TOPIC);
+        assert_eq!(dead[0].payload.as_deref(), Some(&br#"{"id":1}"#[..]));
+    }
+
+    #[tokio::test]
+    async fn dead_letters_malformed_payloads_without_retrying() {
+        let broker = Arc::new(InMemoryBroker::default());
+        let handler = Flaky::new(0);
+        let consumer = start(&broker, &handler, ErrorPolicy::retry(3, BACKOFF));
+
+        publish(&broker, TOPIC, b"not json").await;
+        wait_until("the malformed message to be committed", || {
+            broker.committed_offset(GROUP, TOPIC) == Some(1)
+        })
+        .await;
+        consumer.abort();
+
+        assert_eq!(handler.calls(), 0);
+        assert!(broker.messages(&retry_topic(GROUP, 1)).is_empty());
+        let dead = broker.messages(&dead_letter_topic(GROUP));
+        assert_eq!(dead.len(), 1);
+        assert_eq!(header(&dead[0], KAFKA_HEADER_ATTEMPT), "1");
+        assert!(header(&dead[0], KAFKA_HEADER_ERROR).starts_with("malformed payload"));
+    }
+
+    #[tokio::test]
+    async fn replays_a_dead_letter_to_its_handler() {
+        let broker = Arc::new(InMemoryBroker::default());
+        let handler = Flaky::new(1);
+        let consumer = start(&broker, &handler, ErrorPolicy::DeadLetter);
+
+        publish(&broker, TOPIC, br#"{"id":3}"#).await;
+        let dead_letters = dead_letter_topic(GROUP);
+        wait_until("the dead letter", || {
+            !broker.messages(&dead_letters).is_empty()
+        })
+        .await;
+
+        let message = broker.messages(&dead_letters).remove(0);
+        let dead_letter = DeadLetter::record(RecordDeadLetterProps {
+            consumer_group: GROUP.to_string(),
+            handler: header(&message, KAFKA_HEADER_HANDLER).to_string(),
+            original_topic: TOPIC.to_string(),
+            original_partition: 0,
+            original_offset: 0,
+            message_key: Some("key".to_string()),
+            payload: message.payload.clone().unwrap_or_default(),
+            headers: message
+                .headers
+                .iter()
+                .map(|(k, v)| (k.clone(), String::from_utf8_lossy(v).into_owned()))
+                .collect(),
+            error: header(&message, KAFKA_HEADER_ERROR).to_string(),
+            attempts: 1,
+            dead_letter_topic: dead_letters.clone(),
+            dead_letter_partition: message.partition,
+            dead_letter_offset: message.offset,
+            failed_at: chrono::Utc::now().naive_utc(),
+        })
+        .unwrap();
+        BrokerDeadLetterReplayer::new(broker.clone())
+            .replay(&dead_letter)
+            .await
+            .unwrap();
+
+        let replay = replay_topic(GROUP);
+        wait_until("the replay to be committed", || {
+            broker.committed_offset(&replay, &replay) == Some(1)
+        })
+        .await;
+        consumer.abort();
+
+        assert_eq!(*handler.calls.lock().unwrap(), vec![3, 3]);
+        assert_eq!(broker.messages(&dead_letters).len(), 1);
+    }
+
+    #[tokio::test]
+    async fn commits_handled_offsets_and_resumes_after_them() {
+        let broker = Arc::new(InMemoryBroker::default());
+        let handler = Flaky::new(1);
+        let consumer = start(&broker, &handler, ErrorPolicy::Skip);
+
+        for id in 1..=3 {
+            publish(&broker, TOPIC, format!(r#"{{"id":{id}}}"#).as_bytes()).await;
+        }
+        wait_until("all three offsets to be committed", || {
+            broker.committed_offset(GROUP, TOPIC) == Some(3)
+        })
+        .await;
+        consumer.abort();
+        assert_eq!(*handler.calls.lock().unwrap(), vec![1, 2, 3]);
+
+        // a restarted consumer only sees what was published after the last commit
+        publish(&broker, TOPIC, br#"{"id":4}"#).await;
+        let restarted = start(&broker, &handler, ErrorPolicy::Skip);
+        wait_until("the new offset to be committed", || {
+            broker.committed_offset(GROUP, TOPIC) == Some(4)
+        })
+        .await;
+        restarted.abort();
+        assert_eq!(*handler.calls.lock().unwrap(), vec![1, 2, 3, 4]);
+    }
+
+    #[tokio::test]
+    async fn stop_policy_leaves_the_offset_uncommitted() {
+        let broker = Arc::new(InMemoryBroker::default());
+        let handler = Flaky::new(1);
+        let consumer = start(&broker, &handler, ErrorPolicy::Stop);
+
+        publish(&broker, TOPIC, br#"{"id":5}"#).await;
+        wait_until("the first attempt", || handler.calls() == 1).await;
+        consumer.abort();
+        assert_eq!(broker.committed_offset(GROUP, TOPIC), None);
+
+        let restarted = start(&broker, &handler, ErrorPolicy::Stop);
+        wait_until("the redelivery to be committed", || {
+            broker.committed_offset(GROUP, TOPIC) == Some(1)
+        })
+        .await;
+        restarted.abort();
+        assert_eq!(*handler.calls.lock().unwrap(), vec![5, 5]);
+    }
+}
@@ -0,0 +1,139 @@
+use std::sync::Arc;
+use std::time::Duration;
+
+use rdkafka::consumer::{CommitMode, Consumer, StreamConsumer};
+use rdkafka::message::{Header, Headers, OwnedHeaders};
+use rdkafka::producer::{FutureProducer, FutureRecord};
+use rdkafka::{Message, Offset, TopicPartitionList};
+
+use crate::core::configure::kafka::KafkaConfig;
+use crate::infrastructure::messaging::kafka::consumer::broker::{
+    BrokerError, ConsumedMessage, MessageBroker, MessageSink, MessageSource, OutgoingMessage,
+};
+use crate::infrastructure::messaging::kafka::factory::create_kafka_event_consumer;
+
+pub struct KafkaBroker {
+    config: KafkaConfig,
+    sink: Arc<KafkaMessageSink>,
+}
+
+pub struct KafkaMessageSource {
+    consumer: StreamConsumer,
+}
+
+pub struct KafkaMessageSink {
+    producer: Arc<FutureProducer>,
+    timeout: Duration,
+}
+
+impl KafkaBroker {
+    pub fn new(config: KafkaConfig, producer: Arc<FutureProducer>) -> Self {
+        Self {
+            config,
+            sink: Arc::new(KafkaMessageSink {
+                producer,
+                timeout: Duration::from_secs(5),
+            }),
+        }
+    }
+}
+
+impl MessageBroker for KafkaBroker {
+    fn subscribe(
+        &self,
+        group_id: &str,
+        topics: &[String],
+    ) -> Result<Box<dyn MessageSource>, BrokerError> {
+        let consumer = create_kafka_event_consumer(&self.config, group_id)
+            .map_err(|e| BrokerError(e.to_string()))?;
+        let topics: Vec<&str> = topics.iter().map(String::as_str).collect();
+        consumer
+            .subscribe(&topics)
+            .map_err(|e| BrokerError(format!("cannot subscribe to {topics:?}: {e}")))?;
+        Ok(Box::new(KafkaMessageSource { consumer }))
+    }
+
+    fn sink(&self) -> Arc<dyn MessageSink> {
+        self.sink.clone()
+    }
+}
+
+#[async_trait::async_trait]
+impl MessageSource for KafkaMessageSource {
+    async fn recv(&self) -> Result<ConsumedMessage, BrokerError> {
+        let message = self
+            .consumer
+            .recv()
+            .await
+            .map_err(|e| BrokerError(e.to_string()))?;
+        let headers = message
+            .headers()
+            .map(|headers| {
+                headers
+                    .iter()
+                    .map(|header| {
+                        (
+                            header.key.to_string(),
+                            header.value.map(<[u8]>::to_vec).unwrap_or_default(),
+                        )
+                    })
+                    .collect()
+            })
+            .unwrap_or_default();
+
+        Ok(ConsumedMessage {
+            topic: message.topic().to_string(),
+            partition: message.partition(),
+            offset: message.offset(),
+            key: message.key().map(<[u8]>::to_vec),
+            payload: message.payload().map(<[u8]>::to_vec),
+            headers,
+        })
+    }
+
+    fn commit(&self, message: &ConsumedMessage) -> Result<(), BrokerError> {
+        let mut offsets = TopicPartitionList::new();
+        offsets
+            .add_partition_offset(
+                &message.topic,
+                message.partition,
+                Offset::Offset(message.offset + 1),
+            )
+            .map_err(|e| BrokerError(e.to_string()))?;
+        self.consumer
+            .commit(&offsets, CommitMode::Async)
+            .map_err(|e| BrokerError(e.to_string()))
+    }
+
+    fn close(&self) {
+        self.consumer.unsubscribe();
+    }
+}
+
+#[async_trait::async_trait]
+impl MessageSink for KafkaMessageSink {
+    async fn send(&self, message: OutgoingMessage) -> Result<(), BrokerError> {
+        let headers = message
+            .headers
+            .iter()
+            .fold(OwnedHeaders::new(), |headers, (key, value)| {
+                headers.insert(Header {
+                    key: key.as_str(),
+                    value: Some(value.as_slice()),
+                })
+            });
+        let mut record = FutureRecord::<[u8], [u8]>::to(&message.topic).headers(headers);
+        if let Some(key) = &message.key {
+            record = record.key(key.as_slice());
+        }
+        if let Some(payload) = &message.payload {
+            record = record.payload(payload.as_slice());
+        }
+
+        self.producer
+            .send(record, self.timeout)
+            .await
+            .map(|_| ())
+            .map_err(|(e, _)| BrokerError(e.to_string()))
+    }
+}
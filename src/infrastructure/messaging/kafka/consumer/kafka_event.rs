@@ -0,0 +1,79 @@
+use serde::de::DeserializeOwned;
+
+use crate::domain::address::events::address_created::AddressCreatedEvent;
+use crate::domain::address::events::address_deleted::AddressDeletedEvent;
+use crate::domain::address::events::address_updated::AddressUpdatedEvent;
+use crate::domain::airline::events::airline_created::AirlineCreatedEvent;
+use crate::domain::airline::events::airline_deactivated::AirlineDeactivatedEvent;
+use crate::domain::airline::events::airline_updated::AirlineUpdatedEvent;
+use crate::domain::airport::events::airport_created::AirportCreatedEvent;
+use crate::domain::airport::events::airport_deactivated::AirportDeactivatedEvent;
+use crate::domain::airport::events::airport_updated::AirportUpdatedEvent;
+use crate::domain::baggage::events::bag_status_changed::BagStatusChangedEvent;
+use crate::domain::boarding_pass::events::boarding_pass_issued::BoardingPassIssuedEvent;
+use crate::domain::booking::events::booking_cancelled::BookingCancelledEvent;
+use crate::domain::booking::events::booking_confirmed::BookingConfirmedEvent;
+use crate::domain::booking::events::booking_created::BookingCreatedEvent;
+use crate::domain::checkin::events::checkin_cancelled::CheckinCancelledEvent;
+use crate::domain::checkin::events::checkin_created::CheckinCreatedEvent;
+use crate::domain::compensation::events::compensation_claim_decided::CompensationClaimDecidedEvent;
+use crate::domain::compensation::events::compensation_claim_submitted::CompensationClaimSubmittedEvent;
+use crate::domain::flight::events::flight_cancelled::FlightCancelledEvent;
+use crate::domain::flight::events::flight_created::FlightCreatedEvent;
+use crate::domain::flight::events::flight_updated::FlightUpdatedEvent;
+use crate::domain::passenger::events::passenger_added::PassengerAddedEvent;
+use crate::domain::passenger::events::passenger_no_show::PassengerNoShowEvent;
+use crate::domain::passenger::events::passenger_removed::PassengerRemovedEvent;
+use crate::domain::passenger::events::passenger_updated::PassengerUpdatedEvent;
+use crate::domain::user::events::user_activated::UserActivatedEvent;
+use crate::domain::user::events::user_logged_in::UserLoggedInEvent;
+use crate::domain::user::events::user_registered::UserRegisteredEvent;
+
+/// A domain event published by the `kafka_*_event_publisher` modules, read back from the
+/// topic it was published to as JSON.
+pub trait KafkaEvent: DeserializeOwned + Send + 'static {
+    fn topic() -> &'static str;
+}
+
+macro_rules! impl_kafka_event {
+    ($($event:ty),* $(,)?) => {
+        $(
+            impl KafkaEvent for $event {
+                fn topic() -> &'static str {
+                    <$event>::topic_name()
+                }
+            }
+        )*
+    };
+}
+
+impl_kafka_event!(
+    AddressCreatedEvent,
+    AddressUpdatedEvent,
+    AddressDeletedEvent,
+    AirlineCreatedEvent,
+    AirlineUpdatedEvent,
+    AirlineDeactivatedEvent,
+    AirportCreatedEvent,
+    AirportUpdatedEvent,
+    AirportDeactivatedEvent,
+    BagStatusChangedEvent,
+    BoardingPassIssuedEvent,
+    BookingCreatedEvent,
+    BookingConfirmedEvent,
+    BookingCancelledEvent,
+    CheckinCreatedEvent,
+    CheckinCancelledEvent,
+    CompensationClaimSubmittedEvent,
+    CompensationClaimDecidedEvent,
+    FlightCreatedEvent,
+    FlightUpdatedEvent,
+    FlightCancelledEvent,
+    PassengerAddedEvent,
+    PassengerUpdatedEvent,
+    PassengerRemovedEvent,
+    PassengerNoShowEvent,
+    UserRegisteredEvent,
+    UserActivatedEvent,
+    UserLoggedInEvent,
+);
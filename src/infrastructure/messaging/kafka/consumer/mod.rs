@@ -0,0 +1,9 @@
+pub mod broker;
+pub mod dead_letter_archiver;
+pub mod dead_letter_replayer;
+pub mod event_consumer;
+pub mod event_handler;
+pub mod in_memory_broker;
+pub mod kafka_broker;
+pub mod kafka_event;
+pub mod notification_event_handler;
\ No newline at end of file
@@ -0,0 +1,57 @@
+use std::sync::Arc;
+
+use crate::application::common::use_case_error::UseCaseResult;
+use crate::application::notification::use_case::notification_service_interface::NotificationServiceInterface;
+use crate::domain::booking::events::booking_cancelled::BookingCancelledEvent;
+use crate::domain::booking::events::booking_confirmed::BookingConfirmedEvent;
+use crate::domain::flight::events::flight_cancelled::FlightCancelledEvent;
+use crate::domain::flight::events::flight_updated::FlightUpdatedEvent;
+use crate::domain::user::events::user_registered::UserRegisteredEvent;
+use crate::infrastructure::messaging::kafka::consumer::event_handler::EventHandler;
+
+/// Queues customer notifications for the events that call for one. Queueing is
+/// deduplicated, so redelivered events are harmless.
+pub struct NotificationEventHandler {
+    service: Arc<dyn NotificationServiceInterface>,
+}
+
+impl NotificationEventHandler {
+    pub fn new(service: Arc<dyn NotificationServiceInterface>) -> Self {
+        Self { service }
+    }
+}
+
+#[async_trait::async_trait]
+impl EventHandler<UserRegisteredEvent> for NotificationEventHandler {
+    async fn handle(&self, event: UserRegisteredEvent) -> UseCaseResult<()> {
+        self.service.notify_user_registered(event).await
+    }
+}
+
+#[async_trait::async_trait]
+impl EventHandler<BookingConfirmedEvent> for NotificationEventHandler {
+    async fn handle(&self, event: BookingConfirmedEvent) -> UseCaseResult<()> {
+        self.service.notify_booking_confirmed(event).await
+    }
+}
+
+#[async_trait::async_trait]
+impl EventHandler<BookingCancelledEvent> for NotificationEventHandler {
+    async fn handle(&self, event: BookingCancelledEvent) -> UseCaseResult<()> {
+        self.service.notify_booking_cancelled(event).await
+    }
+}
+
+#[async_trait::async_trait]
+impl EventHandler<FlightUpdatedEvent> for NotificationEventHandler {
+    async fn handle(&self, event: FlightUpdatedEvent) -> UseCaseResult<()> {
+        self.service.notify_flight_changed(event.flight_id).await
+    }
+}
+
+#[async_trait::async_trait]
+impl EventHandler<FlightCancelledEvent> for NotificationEventHandler {
+    async fn handle(&self, event: FlightCancelledEvent) -> UseCaseResult<()> {
+        self.service.notify_flight_changed(event.flight_id).await
+    }
+}
@@ -0,0 +1,81 @@
+use std::sync::Arc;
+use std::time::Duration;
+
+use async_trait::async_trait;
+use rdkafka::producer::{FutureProducer, FutureRecord};
+use serde::Serialize;
+
+use crate::application::common::event_publisher::AirlineEventPublisher;
+use crate::application::common::use_case_error::UseCaseError;
+use crate::domain::airline::events::airline_created::AirlineCreatedEvent;
+use crate::domain::airline::events::airline_deactivated::AirlineDeactivatedEvent;
+use crate::domain::airline::events::airline_updated::AirlineUpdatedEvent;
+
+pub struct KafkaAirlineEventPublisher {
+    producer: Arc<FutureProducer>,
+    timeout: Duration,
+}
+
+impl KafkaAirlineEventPublisher {
+    pub fn new(producer: Arc<FutureProducer>) -> Self {
+        Self {
+            producer,
+            timeout: Duration::from_secs(5),
+        }
+    }
+
+    async fn publish<E>(&self, topic: &str, key: String, event: &E) -> Result<(), UseCaseError>
+    where
+        E: Serialize + Sync,
+    {
+        let payload =
+            serde_json::to_string(event).map_err(|e| UseCaseError::Unexpected(e.to_string()))?;
+        self.producer
+            .send(
+                FutureRecord::to(topic).payload(&payload).key(&key),
+                self.timeout,
+            )
+            .await
+            .map(|_| ())
+            .map_err(|(e, _)| UseCaseError::Unexpected(e.to_string()))
+    }
+}
+
+#[async_trait]
+impl AirlineEventPublisher for KafkaAirlineEventPublisher {
+    async fn publish_airline_created(
+        &self,
+        event: AirlineCreatedEvent,
+    ) -> Result<(), UseCaseError> {
+        self.publish(
+            AirlineCreatedEvent::topic_name(),
+            event.airline_id.to_string(),
+            &event,
+        )
+        .await
+    }
+
+    async fn publish_airline_updated(
+        &self,
+        event: AirlineUpdatedEvent,
+    ) -> Result<(), UseCaseError> {
+        self.publish(
+            AirlineUpdatedEvent::topic_name(),
+            event.airline_id.to_string(),
+            &event,
+        )
+        .await
+    }
+
+    async fn publish_airline_deactivated(
+        &self,
+        event: AirlineDeactivatedEvent,
+    ) -> Result<(), UseCaseError> {
+        self.publish(
+            AirlineDeactivatedEvent::topic_name(),
+            event.airline_id.to_string(),
+            &event,
+        )
+        .await
+    }
+}
@@ -0,0 +1,55 @@
+use std::sync::Arc;
+use std::time::Duration;
+
+use async_trait::async_trait;
+use rdkafka::producer::{FutureProducer, FutureRecord};
+use serde::Serialize;
+
+use crate::application::common::event_publisher::BaggageEventPublisher;
+use crate::application::common::use_case_error::UseCaseError;
+use crate::domain::baggage::events::bag_status_changed::BagStatusChangedEvent;
+
+pub struct KafkaBaggageEventPublisher {
+    producer: Arc<FutureProducer>,
+    timeout: Duration,
+}
+
+impl KafkaBaggageEventPublisher {
+    pub fn new(producer: Arc<FutureProducer>) -> Self {
+        Self {
+            producer,
+            timeout: Duration::from_secs(5),
+        }
+    }
+
+    async fn publish<E>(&self, topic: &str, key: String, event: &E) -> Result<(), UseCaseError>
+    where
+        E: Serialize + Sync,
+    {
+        let payload =
+            serde_json::to_string(event).map_err(|e| UseCaseError::Unexpected(e.to_string()))?;
+        self.producer
+            .send(
+                FutureRecord::to(topic).payload(&payload).key(&key),
+                self.timeout,
+            )
+            .await
+            .map(|_| ())
+            .map_err(|(e, _)| UseCaseError::Unexpected(e.to_string()))
+    }
+}
+
+#[async_trait]
+impl BaggageEventPublisher for KafkaBaggageEventPublisher {
+    async fn publish_bag_status_changed(
+        &self,
+        event: BagStatusChangedEvent,
+    ) -> Result<(), UseCaseError> {
+        self.publish(
+            BagStatusChangedEvent::topic_name(),
+            event.tag_number.clone(),
+            &event,
+        )
+        .await
+    }
+}
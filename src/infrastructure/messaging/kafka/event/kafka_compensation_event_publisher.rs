@@ -0,0 +1,68 @@
+use std::sync::Arc;
+use std::time::Duration;
+
+use async_trait::async_trait;
+use rdkafka::producer::{FutureProducer, FutureRecord};
+use serde::Serialize;
+
+use crate::application::common::event_publisher::CompensationEventPublisher;
+use crate::application::common::use_case_error::UseCaseError;
+use crate::domain::compensation::events::compensation_claim_decided::CompensationClaimDecidedEvent;
+use crate::domain::compensation::events::compensation_claim_submitted::CompensationClaimSubmittedEvent;
+
+pub struct KafkaCompensationEventPublisher {
+    producer: Arc<FutureProducer>,
+    timeout: Duration,
+}
+
+impl KafkaCompensationEventPublisher {
+    pub fn new(producer: Arc<FutureProducer>) -> Self {
+        Self {
+            producer,
+            timeout: Duration::from_secs(5),
+        }
+    }
+
+    async fn publish<E>(&self, topic: &str, key: String, event: &E) -> Result<(), UseCaseError>
+    where
+        E: Serialize + Sync,
+    {
+        let payload =
+            serde_json::to_string(event).map_err(|e| UseCaseError::Unexpected(e.to_string()))?;
+        self.producer
+            .send(
+                FutureRecord::to(topic).payload(&payload).key(&key),
+                self.timeout,
+            )
+            .await
+            .map(|_| ())
+            .map_err(|(e, _)| UseCaseError::Unexpected(e.to_string()))
+    }
+}
+
+#[async_trait]
+impl CompensationEventPublisher for KafkaCompensationEventPublisher {
+    async fn publish_claim_submitted(
+        &self,
+        event: CompensationClaimSubmittedEvent,
+    ) -> Result<(), UseCaseError> {
+        self.publish(
+            CompensationClaimSubmittedEvent::topic_name(),
+            event.claim_id.to_string(),
+            &event,
+        )
+        .await
+    }
+
+    async fn publish_claim_decided(
+        &self,
+        event: CompensationClaimDecidedEvent,
+    ) -> Result<(), UseCaseError> {
+        self.publish(
+            CompensationClaimDecidedEvent::topic_name(),
+            event.claim_id.to_string(),
+            &event,
+        )
+        .await
+    }
+}
@@ -8,6 +8,7 @@ use serde::Serialize;
 use crate::application::common::event_publisher::PassengerEventPublisher;
 use crate::application::common::use_case_error::UseCaseError;
 use crate::domain::passenger::events::passenger_added::PassengerAddedEvent;
+use crate::domain::passenger::events::passenger_no_show::PassengerNoShowEvent;
 use crate::domain::passenger::events::passenger_removed::PassengerRemovedEvent;
 use crate::domain::passenger::events::passenger_updated::PassengerUpdatedEvent;
 
@@ -72,4 +73,16 @@ impl PassengerEventPublisher for KafkaPassengerEventPublisher {
         )
         .await
     }
+
+    async fn publish_passenger_no_show(
+        &self,
+        event: PassengerNoShowEvent,
+    ) -> Result<(), UseCaseError> {
+        self.publish(
+            PassengerNoShowEvent::topic_name(),
+            event.passenger_id.to_string(),
+            &event,
+        )
+        .await
+    }
 }
@@ -5,4 +5,7 @@ pub mod kafka_flight_event_publisher;
 pub mod kafka_booking_event_publisher;
 pub mod kafka_passenger_event_publisher;
 pub mod kafka_checkin_event_publisher;
-pub mod kafka_boarding_pass_event_publisher;
\ No newline at end of file
+pub mod kafka_boarding_pass_event_publisher;
+pub mod kafka_baggage_event_publisher;
+pub mod kafka_airline_event_publisher;
+pub mod kafka_compensation_event_publisher;
\ No newline at end of file
@@ -30,3 +30,21 @@ pub fn create_kafka_consumer(cfg: &KafkaConfig) -> TechnicalResult<StreamConsume
         .create()
         .map_err(|e| TechnicalError::InvalidConfig(format!("Consumer creation failed: {e}")))
 }
+
+/// Consumer for the typed event framework: its own group, offsets committed by hand once a
+/// message has been handled.
+pub fn create_kafka_event_consumer(
+    cfg: &KafkaConfig,
+    group_id: &str,
+) -> TechnicalResult<StreamConsumer> {
+    ClientConfig::new()
+        .set("group.id", group_id)
+        .set("bootstrap.servers", cfg.server_url.to_owned())
+        .set("enable.partition.eof", "false")
+        .set("session.timeout.ms", cfg.timeout_ms.to_owned())
+        .set("enable.auto.commit", "false")
+        .set("auto.offset.reset", "earliest")
+        .set_log_level(RDKafkaLogLevel::Debug)
+        .create()
+        .map_err(|e| TechnicalError::InvalidConfig(format!("Consumer creation failed: {e}")))
+}
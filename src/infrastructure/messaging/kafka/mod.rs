@@ -1,4 +1,3 @@
 pub mod factory;
-pub mod consumer_task;
-pub mod message;
+pub mod consumer;
 pub mod event;
\ No newline at end of file
@@ -1 +1,2 @@
-pub mod kafka;
\ No newline at end of file
+pub mod kafka;
+pub mod redis;
\ No newline at end of file
@@ -0,0 +1,76 @@
+use std::sync::Arc;
+use std::time::Duration;
+
+use futures::StreamExt;
+use redis::Client;
+
+use crate::application::flight_status::use_case::flight_status_service_interface::FlightStatusServiceInterface;
+use crate::infrastructure::constants::redis::REDIS_CHANNEL_FLIGHT_STATUS;
+use crate::infrastructure::messaging::redis::flight_status_message::FlightStatusMessage;
+
+const MAX_RECONNECT_DELAY: Duration = Duration::from_secs(30);
+
+/// Follows the Redis status channel for the life of the process and feeds this instance's
+/// status subscribers. Reconnects with backoff; updates sent while disconnected are lost.
+pub fn spawn_flight_status_listener(
+    client: Client,
+    service: Arc<dyn FlightStatusServiceInterface>,
+) -> tokio::task::JoinHandle<()> {
+    tokio::spawn(async move {
+        let mut delay = Duration::from_secs(1);
+        loop {
+            match listen(&client, service.as_ref()).await {
+                Ok(()) => {
+                    tracing::warn!("flight status channel closed, reconnecting");
+                    delay = Duration::from_secs(1);
+                }
+                Err(err) => tracing::warn!("flight status channel failed: {}", err),
+            }
+            tokio::time::sleep(delay).await;
+            delay = (delay * 2).min(MAX_RECONNECT_DELAY);
+        }
+    })
+}
+
+async fn listen(
+    client: &Client,
+    service: &dyn FlightStatusServiceInterface,
+) -> redis::RedisResult<()> {
+    let mut pubsub = client.get_async_pubsub().await?;
+    pubsub.subscribe(REDIS_CHANNEL_FLIGHT_STATUS).await?;
+    tracing::info!(
+        "Listening for flight status on {}",
+        REDIS_CHANNEL_FLIGHT_STATUS
+    );
+
+    let mut messages = pubsub.on_message();
+    while let Some(msg) = messages.next().await {
+        let payload: String = match msg.get_payload() {
+            Ok(payload) => payload,
+            Err(err) => {
+                tracing::warn!("flight status payload unreadable: {}", err);
+                continue;
+            }
+        };
+        let message: FlightStatusMessage = match serde_json::from_str(&payload) {
+            Ok(message) => message,
+            Err(err) => {
+                tracing::warn!("flight status message malformed: {}", err);
+                continue;
+            }
+        };
+
+        if let Err(err) = service
+            .publish_flight_changed(message.flight_id(), message.occurred_at())
+            .await
+        {
+            tracing::warn!(
+                "flight status push failed flight_id={}: {}",
+                message.flight_id(),
+                err
+            );
+        }
+    }
+
+    Ok(())
+}
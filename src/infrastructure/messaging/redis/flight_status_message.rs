@@ -0,0 +1,28 @@
+use serde::{Deserialize, Serialize};
+
+use crate::domain::flight::events::flight_cancelled::FlightCancelledEvent;
+use crate::domain::flight::events::flight_updated::FlightUpdatedEvent;
+
+/// Flight events relayed on the Redis status channel so every instance can push them.
+#[derive(Debug, Serialize, Deserialize)]
+#[serde(tag = "event", rename_all = "snake_case")]
+pub enum FlightStatusMessage {
+    Updated(FlightUpdatedEvent),
+    Cancelled(FlightCancelledEvent),
+}
+
+impl FlightStatusMessage {
+    pub fn flight_id(&self) -> i64 {
+        match self {
+            FlightStatusMessage::Updated(event) => event.flight_id,
+            FlightStatusMessage::Cancelled(event) => event.flight_id,
+        }
+    }
+
+    pub fn occurred_at(&self) -> chrono::NaiveDateTime {
+        match self {
+            FlightStatusMessage::Updated(event) => event.occurred_at,
+            FlightStatusMessage::Cancelled(event) => event.occurred_at,
+        }
+    }
+}
@@ -0,0 +1,3 @@
+pub mod flight_status_message;
+pub mod redis_flight_event_publisher;
+pub mod flight_status_listener;
\ No newline at end of file
@@ -0,0 +1,68 @@
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use utils::redis_client::RedisConnectionPool;
+
+use crate::application::common::event_publisher::FlightEventPublisher;
+use crate::application::common::use_case_error::UseCaseError;
+use crate::domain::flight::events::flight_cancelled::FlightCancelledEvent;
+use crate::domain::flight::events::flight_created::FlightCreatedEvent;
+use crate::domain::flight::events::flight_updated::FlightUpdatedEvent;
+use crate::infrastructure::constants::redis::REDIS_CHANNEL_FLIGHT_STATUS;
+use crate::infrastructure::messaging::redis::flight_status_message::FlightStatusMessage;
+
+/// Publishes flight events as usual and relays updates and cancellations to the Redis
+/// status channel. The relay is best effort: a Redis failure only costs live pushes.
+pub struct RedisFlightEventPublisher {
+    inner: Arc<dyn FlightEventPublisher>,
+    redis: Arc<RedisConnectionPool>,
+}
+
+impl RedisFlightEventPublisher {
+    pub fn new(inner: Arc<dyn FlightEventPublisher>, redis: Arc<RedisConnectionPool>) -> Self {
+        Self { inner, redis }
+    }
+
+    async fn relay(&self, message: FlightStatusMessage) {
+        let payload = match serde_json::to_string(&message) {
+            Ok(payload) => payload,
+            Err(err) => {
+                tracing::warn!("flight status relay serialize failed: {}", err);
+                return;
+            }
+        };
+        if let Err(err) = self
+            .redis
+            .publish(REDIS_CHANNEL_FLIGHT_STATUS, &payload)
+            .await
+        {
+            tracing::warn!(
+                "flight status relay failed flight_id={}: {}",
+                message.flight_id(),
+                err
+            );
+        }
+    }
+}
+
+#[async_trait]
+impl FlightEventPublisher for RedisFlightEventPublisher {
+    async fn publish_flight_created(&self, event: FlightCreatedEvent) -> Result<(), UseCaseError> {
+        self.inner.publish_flight_created(event).await
+    }
+
+    async fn publish_flight_updated(&self, event: FlightUpdatedEvent) -> Result<(), UseCaseError> {
+        self.relay(FlightStatusMessage::Updated(event.clone()))
+            .await;
+        self.inner.publish_flight_updated(event).await
+    }
+
+    async fn publish_flight_cancelled(
+        &self,
+        event: FlightCancelledEvent,
+    ) -> Result<(), UseCaseError> {
+        self.relay(FlightStatusMessage::Cancelled(event.clone()))
+            .await;
+        self.inner.publish_flight_cancelled(event).await
+    }
+}
@@ -10,7 +10,11 @@ pub mod gateway;
 pub mod config;
 pub mod error;
 pub mod bootstrap;
+pub mod cli;
 pub mod http;
 pub mod middleware;
 pub mod context;
-pub mod third_party;
\ No newline at end of file
+pub mod third_party;
+pub mod document;
+
+pub mod notification;
\ No newline at end of file
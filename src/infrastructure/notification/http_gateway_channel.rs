@@ -0,0 +1,120 @@
+use std::time::Duration;
+
+use reqwest::{Client, StatusCode};
+use serde::Serialize;
+use serde_json::Value;
+
+use crate::application::notification::notification_channel::{
+    DeliveryError, DeliveryReceipt, NotificationChannel,
+};
+use crate::core::configure::notification::{PushGatewayConfig, SmsGatewayConfig};
+use crate::domain::notification::entity::Notification;
+
+const GATEWAY_TIMEOUT: Duration = Duration::from_secs(10);
+
+/// SMS through an HTTP gateway.
+pub struct HttpSmsChannel {
+    client: Client,
+    cfg: SmsGatewayConfig,
+}
+
+/// Push notifications through an HTTP gateway that holds the platform credentials.
+pub struct HttpPushChannel {
+    client: Client,
+    cfg: PushGatewayConfig,
+}
+
+#[derive(Serialize)]
+struct SmsPayload<'a> {
+    from: &'a str,
+    to: &'a str,
+    text: &'a str,
+}
+
+#[derive(Serialize)]
+struct PushPayload<'a> {
+    token: &'a str,
+    title: &'a str,
+    body: &'a str,
+}
+
+impl HttpSmsChannel {
+    pub fn new(client: Client, cfg: SmsGatewayConfig) -> Self {
+        Self { client, cfg }
+    }
+}
+
+impl HttpPushChannel {
+    pub fn new(client: Client, cfg: PushGatewayConfig) -> Self {
+        Self { client, cfg }
+    }
+}
+
+#[async_trait::async_trait]
+impl NotificationChannel for HttpSmsChannel {
+    async fn send(&self, notification: &Notification) -> Result<DeliveryReceipt, DeliveryError> {
+        let payload = SmsPayload {
+            from: &self.cfg.sender,
+            to: &notification.recipient,
+            text: &notification.body,
+        };
+        post(&self.client, &self.cfg.url, &self.cfg.api_key, &payload).await
+    }
+}
+
+#[async_trait::async_trait]
+impl NotificationChannel for HttpPushChannel {
+    async fn send(&self, notification: &Notification) -> Result<DeliveryReceipt, DeliveryError> {
+        let payload = PushPayload {
+            token: &notification.recipient,
+            title: notification.subject.as_deref().unwrap_or_default(),
+            body: &notification.body,
+        };
+        post(&self.client, &self.cfg.url, &self.cfg.api_key, &payload).await
+    }
+}
+
+// A 4xx other than 408/429 means the gateway refused the message itself; anything else
+// may go through on a later attempt. The message id is read from `id` or `message_id`.
+async fn post<T: Serialize + Sync>(
+    client: &Client,
+    url: &str,
+    api_key: &str,
+    payload: &T,
+) -> Result<DeliveryReceipt, DeliveryError> {
+    let response = client
+        .post(url)
+        .bearer_auth(api_key)
+        .timeout(GATEWAY_TIMEOUT)
+        .json(payload)
+        .send()
+        .await
+        .map_err(|e| DeliveryError::transient(e.to_string()))?;
+
+    let status = response.status();
+    let body = response.text().await.unwrap_or_default();
+    if !status.is_success() {
+        let message = format!("gateway returned {status}: {body}");
+        let retryable = status.is_server_error()
+            || status == StatusCode::REQUEST_TIMEOUT
+            || status == StatusCode::TOO_MANY_REQUESTS;
+        return Err(if retryable {
+            DeliveryError::transient(message)
+        } else {
+            DeliveryError::permanent(message)
+        });
+    }
+
+    let provider_message_id = serde_json::from_str::<Value>(&body).ok().and_then(|json| {
+        ["id", "message_id"]
+            .into_iter()
+            .find_map(|key| match &json[key] {
+                Value::String(id) => Some(id.clone()),
+                Value::Number(id) => Some(id.to_string()),
+                _ => None,
+            })
+    });
+    Ok(DeliveryReceipt {
+        provider_message_id,
+    })
+}
@@ -0,0 +1,74 @@
+use std::path::PathBuf;
+
+use crate::application::notification::mapper::notification_mapper::{
+    notification_channel_to_string, notification_kind_to_string,
+};
+use crate::application::notification::notification_channel::{
+    DeliveryError, DeliveryReceipt, NotificationChannel,
+};
+use crate::domain::notification::entity::Notification;
+
+/// Writes each notification to `<dir>/<id>-<kind>-<channel>.txt` instead of sending it.
+pub struct FileNotificationChannel {
+    dir: PathBuf,
+}
+
+/// Logs each notification instead of sending it.
+pub struct LogNotificationChannel;
+
+impl FileNotificationChannel {
+    pub fn new(dir: PathBuf) -> Self {
+        Self { dir }
+    }
+}
+
+fn render(notification: &Notification) -> String {
+    format!(
+        "To: {}\nChannel: {}\nLocale: {}\nSubject: {}\n\n{}\n",
+        notification.recipient,
+        notification_channel_to_string(&notification.channel),
+        notification.locale,
+        notification.subject.as_deref().unwrap_or_default(),
+        notification.body
+    )
+}
+
+#[async_trait::async_trait]
+impl NotificationChannel for FileNotificationChannel {
+    async fn send(&self, notification: &Notification) -> Result<DeliveryReceipt, DeliveryError> {
+        let name = format!(
+            "{}-{}-{}.txt",
+            notification.id,
+            notification_kind_to_string(&notification.kind),
+            notification_channel_to_string(&notification.channel)
+        )
+        .to_lowercase();
+        let path = self.dir.join(name);
+
+        tokio::fs::create_dir_all(&self.dir)
+            .await
+            .map_err(|e| DeliveryError::transient(format!("{}: {e}", self.dir.display())))?;
+        tokio::fs::write(&path, render(notification))
+            .await
+            .map_err(|e| DeliveryError::transient(format!("{}: {e}", path.display())))?;
+
+        Ok(DeliveryReceipt {
+            provider_message_id: Some(path.display().to_string()),
+        })
+    }
+}
+
+#[async_trait::async_trait]
+impl NotificationChannel for LogNotificationChannel {
+    async fn send(&self, notification: &Notification) -> Result<DeliveryReceipt, DeliveryError> {
+        tracing::info!(
+            "notification {} ({})\n{}",
+            notification.id,
+            notification_kind_to_string(&notification.kind),
+            render(notification)
+        );
+        Ok(DeliveryReceipt {
+            provider_message_id: None,
+        })
+    }
+}
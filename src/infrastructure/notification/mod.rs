@@ -0,0 +1,4 @@
+pub mod http_gateway_channel;
+pub mod local_sink_channel;
+pub mod notification_dispatcher;
+pub mod smtp_email_channel;
\ No newline at end of file
@@ -0,0 +1,28 @@
+use std::sync::Arc;
+use std::time::Duration;
+
+use crate::application::notification::use_case::notification_service_interface::NotificationServiceInterface;
+use crate::infrastructure::runtime::shutdown;
+
+/// Sends due notifications (new ones, scheduled reminders and retries) every `interval`
+/// until shutdown; a batch in progress is finished first.
+pub fn spawn_notification_dispatcher(
+    service: Arc<dyn NotificationServiceInterface>,
+    interval: Duration,
+) -> tokio::task::JoinHandle<()> {
+    tokio::spawn(async move {
+        let mut ticker = tokio::time::interval(interval);
+        ticker.set_missed_tick_behavior(tokio::time::MissedTickBehavior::Delay);
+        loop {
+            tokio::select! {
+                _ = shutdown::requested() => break,
+                _ = ticker.tick() => {}
+            }
+            match service.dispatch_due().await {
+                Ok(0) => {}
+                Ok(sent) => tracing::info!("{} notification(s) sent", sent),
+                Err(err) => tracing::warn!("notification dispatch failed: {}", err),
+            }
+        }
+    })
+}
@@ -0,0 +1,69 @@
+use lettre::message::Mailbox;
+use lettre::message::header::ContentType;
+use lettre::transport::smtp::authentication::Credentials;
+use lettre::{AsyncSmtpTransport, AsyncTransport, Message, Tokio1Executor};
+
+use crate::application::notification::notification_channel::{
+    DeliveryError, DeliveryReceipt, NotificationChannel,
+};
+use crate::core::configure::notification::SmtpConfig;
+use crate::domain::notification::entity::Notification;
+
+/// Plain-text email over SMTP.
+pub struct SmtpEmailChannel {
+    transport: AsyncSmtpTransport<Tokio1Executor>,
+    from: Mailbox,
+}
+
+impl SmtpEmailChannel {
+    pub fn new(cfg: &SmtpConfig) -> Result<Self, String> {
+        let from = cfg
+            .from
+            .parse::<Mailbox>()
+            .map_err(|e| format!("notification.email.from {}: {e}", cfg.from))?;
+
+        let mut builder = if cfg.starttls {
+            AsyncSmtpTransport::<Tokio1Executor>::starttls_relay(&cfg.host)
+                .map_err(|e| format!("notification.email.host {}: {e}", cfg.host))?
+        } else {
+            AsyncSmtpTransport::<Tokio1Executor>::builder_dangerous(&cfg.host)
+        }
+        .port(cfg.port);
+        if let (Some(username), Some(password)) = (&cfg.username, &cfg.password) {
+            builder = builder.credentials(Credentials::new(username.clone(), password.clone()));
+        }
+
+        Ok(Self {
+            transport: builder.build(),
+            from,
+        })
+    }
+}
+
+#[async_trait::async_trait]
+impl NotificationChannel for SmtpEmailChannel {
+    async fn send(&self, notification: &Notification) -> Result<DeliveryReceipt, DeliveryError> {
+        let to = notification.recipient.parse::<Mailbox>().map_err(|e| {
+            DeliveryError::permanent(format!("invalid address {}: {e}", notification.recipient))
+        })?;
+        let message = Message::builder()
+            .from(self.from.clone())
+            .to(to)
+            .subject(notification.subject.clone().unwrap_or_default())
+            .header(ContentType::TEXT_PLAIN)
+            .body(notification.body.clone())
+            .map_err(|e| DeliveryError::permanent(e.to_string()))?;
+
+        let response = self.transport.send(message).await.map_err(|e| {
+            if e.is_permanent() {
+                DeliveryError::permanent(e.to_string())
+            } else {
+                DeliveryError::transient(e.to_string())
+            }
+        })?;
+
+        Ok(DeliveryReceipt {
+            provider_message_id: response.message().next().map(str::to_string),
+        })
+    }
+}
@@ -0,0 +1,44 @@
+use super::airline;
+use sea_orm::entity::prelude::*;
+use sea_orm::sea_query::StringLen;
+use serde::{Deserialize, Serialize};
+
+#[derive(Clone, Debug, PartialEq, DeriveEntityModel, Serialize, Deserialize)]
+#[sea_orm(table_name = "aircraft")]
+pub struct Model {
+    #[sea_orm(primary_key)]
+    pub id: i64,
+    pub tail_number: String,
+    pub aircraft_type: String,
+    pub seat_configuration: String,
+    pub airline_id: i64,
+    pub status: AircraftStatus,
+}
+
+#[derive(EnumIter, DeriveActiveEnum, Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[sea_orm(rs_type = "String", db_type = "String(StringLen::N(20))")]
+pub enum AircraftStatus {
+    #[sea_orm(string_value = "ACTIVE")]
+    Active,
+    #[sea_orm(string_value = "MAINTENANCE")]
+    Maintenance,
+}
+
+#[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
+pub enum Relation {
+    #[sea_orm(
+        belongs_to = "airline::Entity",
+        from = "Column::AirlineId",
+        to = "airline::Column::Id",
+        on_delete = "Restrict"
+    )]
+    Airline,
+}
+
+impl Related<airline::Entity> for Entity {
+    fn to() -> RelationDef {
+        Relation::Airline.def()
+    }
+}
+
+impl ActiveModelBehavior for ActiveModel {}
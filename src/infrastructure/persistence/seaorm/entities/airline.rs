@@ -0,0 +1,21 @@
+use sea_orm::entity::prelude::*;
+use serde::{Deserialize, Serialize};
+
+#[derive(Clone, Debug, PartialEq, DeriveEntityModel, Serialize, Deserialize)]
+#[sea_orm(table_name = "airlines")]
+pub struct Model {
+    #[sea_orm(primary_key)]
+    pub id: i64,
+    pub iata_code: String,
+    pub icao_code: Option<String>,
+    pub name: String,
+    pub callsign: Option<String>,
+    pub country_code: String,
+    pub logo_url: Option<String>,
+    pub is_active: bool,
+}
+
+#[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
+pub enum Relation {}
+
+impl ActiveModelBehavior for ActiveModel {}
@@ -0,0 +1,128 @@
+use super::{airport, bag_status_event, booking, checkin, flight, passenger};
+use crate::impl_audit_for_entity;
+use chrono::NaiveDateTime;
+use sea_orm::entity::prelude::*;
+use sea_orm::sea_query::StringLen;
+use serde::{Deserialize, Serialize};
+
+#[derive(Clone, Debug, PartialEq, DeriveEntityModel, Serialize, Deserialize)]
+#[sea_orm(table_name = "bags")]
+pub struct Model {
+    #[sea_orm(primary_key)]
+    pub id: i64,
+
+    pub tag_number: String,
+
+    pub checkin_id: i64,
+    pub booking_id: i64,
+    pub passenger_id: i64,
+    pub flight_id: i64,
+    pub destination_airport_id: i64,
+
+    pub weight_kg: Decimal,
+    pub status: BagStatus,
+    pub last_location: Option<String>,
+
+    pub created_at: NaiveDateTime,
+    pub updated_at: NaiveDateTime,
+
+    pub created_by: Option<i64>,
+    pub updated_by: Option<i64>,
+
+    // for Optimistic locking
+    pub version: i32,
+}
+
+#[derive(EnumIter, DeriveActiveEnum, Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[sea_orm(rs_type = "String", db_type = "String(StringLen::N(20))")]
+pub enum BagStatus {
+    #[sea_orm(string_value = "CHECKED")]
+    Checked,
+    #[sea_orm(string_value = "LOADED")]
+    Loaded,
+    #[sea_orm(string_value = "TRANSFERRED")]
+    Transferred,
+    #[sea_orm(string_value = "ARRIVED")]
+    Arrived,
+    #[sea_orm(string_value = "DELAYED")]
+    Delayed,
+}
+
+#[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
+pub enum Relation {
+    #[sea_orm(
+        belongs_to = "checkin::Entity",
+        from = "Column::CheckinId",
+        to = "checkin::Column::Id",
+        on_delete = "Cascade"
+    )]
+    Checkin,
+
+    #[sea_orm(
+        belongs_to = "booking::Entity",
+        from = "Column::BookingId",
+        to = "booking::Column::Id",
+        on_delete = "Cascade"
+    )]
+    Booking,
+
+    #[sea_orm(
+        belongs_to = "passenger::Entity",
+        from = "Column::PassengerId",
+        to = "passenger::Column::Id",
+        on_delete = "Cascade"
+    )]
+    Passenger,
+
+    #[sea_orm(
+        belongs_to = "flight::Entity",
+        from = "Column::FlightId",
+        to = "flight::Column::Id",
+        on_delete = "Restrict"
+    )]
+    Flight,
+
+    #[sea_orm(
+        belongs_to = "airport::Entity",
+        from = "Column::DestinationAirportId",
+        to = "airport::Column::Id",
+        on_delete = "Restrict"
+    )]
+    DestinationAirport,
+
+    #[sea_orm(has_many = "bag_status_event::Entity")]
+    StatusEvents,
+}
+
+impl Related<checkin::Entity> for Entity {
+    fn to() -> RelationDef {
+        Relation::Checkin.def()
+    }
+}
+
+impl Related<booking::Entity> for Entity {
+    fn to() -> RelationDef {
+        Relation::Booking.def()
+    }
+}
+
+impl Related<passenger::Entity> for Entity {
+    fn to() -> RelationDef {
+        Relation::Passenger.def()
+    }
+}
+
+impl Related<flight::Entity> for Entity {
+    fn to() -> RelationDef {
+        Relation::Flight.def()
+    }
+}
+
+impl Related<bag_status_event::Entity> for Entity {
+    fn to() -> RelationDef {
+        Relation::StatusEvents.def()
+    }
+}
+
+impl ActiveModelBehavior for ActiveModel {}
+impl_audit_for_entity!(super::bag::ActiveModel);
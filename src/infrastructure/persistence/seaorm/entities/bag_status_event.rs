@@ -0,0 +1,44 @@
+use super::bag::{self, BagStatus};
+use crate::impl_audit_for_entity;
+use chrono::NaiveDateTime;
+use sea_orm::entity::prelude::*;
+use serde::{Deserialize, Serialize};
+
+#[derive(Clone, Debug, PartialEq, DeriveEntityModel, Serialize, Deserialize)]
+#[sea_orm(table_name = "bag_status_events")]
+pub struct Model {
+    #[sea_orm(primary_key)]
+    pub id: i64,
+
+    pub bag_id: i64,
+    pub status: BagStatus,
+    pub location: Option<String>,
+    pub note: Option<String>,
+    pub recorded_at: NaiveDateTime,
+
+    pub created_at: NaiveDateTime,
+    pub updated_at: NaiveDateTime,
+
+    pub created_by: Option<i64>,
+    pub updated_by: Option<i64>,
+}
+
+#[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
+pub enum Relation {
+    #[sea_orm(
+        belongs_to = "bag::Entity",
+        from = "Column::BagId",
+        to = "bag::Column::Id",
+        on_delete = "Cascade"
+    )]
+    Bag,
+}
+
+impl Related<bag::Entity> for Entity {
+    fn to() -> RelationDef {
+        Relation::Bag.def()
+    }
+}
+
+impl ActiveModelBehavior for ActiveModel {}
+impl_audit_for_entity!(super::bag_status_event::ActiveModel);
@@ -26,6 +26,11 @@ pub struct Model {
     pub barcode_format: String,
     pub barcode_payload: Option<String>,
 
+    pub revoked_at: Option<NaiveDateTime>,
+
+    pub wallet_pass_version: i32,
+    pub wallet_pass_fingerprint: Option<String>,
+
     pub created_at: NaiveDateTime,
     pub updated_at: NaiveDateTime,
 
@@ -15,6 +15,8 @@ pub struct Model {
 
     pub user_id: i64,
     pub flight_id: i64,
+    pub marketing_airline_code: String,
+    pub marketing_flight_number: String,
 
     pub status: BookingStatus,
     pub cancellation_reason: Option<String>,
@@ -26,6 +28,7 @@ pub struct Model {
     pub total_amount: Decimal,
 
     pub currency: String,
+    pub fare_family: FareFamily,
 
     pub contact_email: String,
     pub contact_phone: Option<String>,
@@ -60,6 +63,8 @@ pub enum BookingStatus {
     Cancelled,
     #[sea_orm(string_value = "EXPIRED")]
     Expired,
+    #[sea_orm(string_value = "NO_SHOW")]
+    NoShow,
 }
 
 #[derive(EnumIter, DeriveActiveEnum, Clone, Debug, PartialEq, Serialize, Deserialize)]
@@ -85,6 +90,17 @@ pub enum PaymentMethod {
     #[sea_orm(string_value = "WALLET")]
     Wallet,
 }
+
+#[derive(EnumIter, DeriveActiveEnum, Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[sea_orm(rs_type = "String", db_type = "String(StringLen::N(20))")]
+pub enum FareFamily {
+    #[sea_orm(string_value = "BASIC")]
+    Basic,
+    #[sea_orm(string_value = "STANDARD")]
+    Standard,
+    #[sea_orm(string_value = "FLEX")]
+    Flex,
+}
 #[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
 pub enum Relation {
     #[sea_orm(
@@ -22,8 +22,19 @@ pub struct Model {
     pub baggage_count: i32,
     pub baggage_weight_total: f64,
     pub baggage_weight_unit: String,
+    #[sea_orm(column_type = "JsonBinary")]
+    pub baggage_piece_weights: Json,
+
+    pub baggage_allowance: Option<String>,
+    pub excess_baggage_pieces: i32,
+    pub excess_baggage_weight_kg: Decimal,
+    pub excess_baggage_fee: Decimal,
+    pub excess_baggage_currency: Option<String>,
+    pub excess_baggage_paid_at: Option<NaiveDateTime>,
+    pub excess_baggage_payment_txn_id: Option<String>,
 
     pub checked_in_at: Option<NaiveDateTime>,
+    pub boarded_at: Option<NaiveDateTime>,
 
     pub checkin_channel: CheckinChannel,
     pub checked_in_ip: Option<String>,
@@ -44,8 +55,14 @@ pub enum CheckinStatus {
     Pending,
     #[sea_orm(string_value = "CHECKED_IN")]
     CheckedIn,
+    #[sea_orm(string_value = "BOARDED")]
+    Boarded,
+    #[sea_orm(string_value = "OFFLOADED")]
+    Offloaded,
     #[sea_orm(string_value = "CANCELLED")]
     Cancelled,
+    #[sea_orm(string_value = "STANDBY")]
+    Standby,
 }
 
 #[derive(EnumIter, DeriveActiveEnum, Clone, Debug, PartialEq, Serialize, Deserialize)]
@@ -0,0 +1,97 @@
+use super::{booking, flight};
+use crate::impl_audit_for_entity;
+use chrono::NaiveDateTime;
+use sea_orm::entity::prelude::*;
+use sea_orm::sea_query::StringLen;
+use serde::{Deserialize, Serialize};
+
+#[derive(Clone, Debug, PartialEq, DeriveEntityModel, Serialize, Deserialize)]
+#[sea_orm(table_name = "compensation_claims")]
+pub struct Model {
+    #[sea_orm(primary_key)]
+    pub id: i64,
+
+    pub booking_id: i64,
+    pub flight_id: i64,
+    pub user_id: i64,
+
+    pub reason: CompensationReason,
+    pub status: CompensationClaimStatus,
+
+    pub distance_km: i32,
+    pub arrival_delay_minutes: Option<i32>,
+    pub passenger_count: i32,
+    pub amount_per_passenger: Decimal,
+    pub total_amount: Decimal,
+    pub currency: String,
+
+    pub customer_note: Option<String>,
+    pub decision_note: Option<String>,
+    pub decided_by: Option<i64>,
+
+    pub submitted_at: NaiveDateTime,
+    pub decided_at: Option<NaiveDateTime>,
+
+    pub created_at: NaiveDateTime,
+    pub updated_at: NaiveDateTime,
+
+    pub created_by: Option<i64>,
+    pub updated_by: Option<i64>,
+
+    // for Optimistic locking
+    pub version: i32,
+}
+
+#[derive(EnumIter, DeriveActiveEnum, Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[sea_orm(rs_type = "String", db_type = "String(StringLen::N(20))")]
+pub enum CompensationReason {
+    #[sea_orm(string_value = "DELAY")]
+    Delay,
+    #[sea_orm(string_value = "CANCELLATION")]
+    Cancellation,
+}
+
+#[derive(EnumIter, DeriveActiveEnum, Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[sea_orm(rs_type = "String", db_type = "String(StringLen::N(20))")]
+pub enum CompensationClaimStatus {
+    #[sea_orm(string_value = "SUBMITTED")]
+    Submitted,
+    #[sea_orm(string_value = "APPROVED")]
+    Approved,
+    #[sea_orm(string_value = "REJECTED")]
+    Rejected,
+}
+
+#[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
+pub enum Relation {
+    #[sea_orm(
+        belongs_to = "booking::Entity",
+        from = "Column::BookingId",
+        to = "booking::Column::Id",
+        on_delete = "Cascade"
+    )]
+    Booking,
+
+    #[sea_orm(
+        belongs_to = "flight::Entity",
+        from = "Column::FlightId",
+        to = "flight::Column::Id",
+        on_delete = "Restrict"
+    )]
+    Flight,
+}
+
+impl Related<booking::Entity> for Entity {
+    fn to() -> RelationDef {
+        Relation::Booking.def()
+    }
+}
+
+impl Related<flight::Entity> for Entity {
+    fn to() -> RelationDef {
+        Relation::Flight.def()
+    }
+}
+
+impl ActiveModelBehavior for ActiveModel {}
+impl_audit_for_entity!(super::compensation_claim::ActiveModel);
@@ -0,0 +1,64 @@
+use crate::impl_audit_for_entity;
+use chrono::NaiveDateTime;
+use sea_orm::entity::prelude::*;
+use sea_orm::sea_query::StringLen;
+use serde::{Deserialize, Serialize};
+
+#[derive(Clone, Debug, PartialEq, DeriveEntityModel, Serialize, Deserialize)]
+#[sea_orm(table_name = "dead_letters")]
+pub struct Model {
+    #[sea_orm(primary_key)]
+    pub id: i64,
+
+    pub consumer_group: String,
+    pub handler: String,
+
+    pub original_topic: String,
+    pub original_partition: i32,
+    pub original_offset: i64,
+
+    pub message_key: Option<String>,
+    #[sea_orm(column_type = "Blob")]
+    pub payload: Vec<u8>,
+    #[sea_orm(column_type = "JsonBinary")]
+    pub headers: Json,
+
+    pub error: String,
+    pub attempts: i32,
+
+    pub dead_letter_topic: String,
+    pub dead_letter_partition: i32,
+    pub dead_letter_offset: i64,
+
+    pub status: DeadLetterStatus,
+    pub failed_at: NaiveDateTime,
+    pub resolved_at: Option<NaiveDateTime>,
+    pub resolved_by: Option<i64>,
+    pub resolution_note: Option<String>,
+
+    pub created_at: NaiveDateTime,
+    pub updated_at: NaiveDateTime,
+
+    pub created_by: Option<i64>,
+    pub updated_by: Option<i64>,
+
+    // for Optimistic locking
+    pub version: i32,
+}
+
+#[derive(EnumIter, DeriveActiveEnum, Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[sea_orm(rs_type = "String", db_type = "String(StringLen::N(20))")]
+pub enum DeadLetterStatus {
+    #[sea_orm(string_value = "PENDING")]
+    Pending,
+    #[sea_orm(string_value = "REPLAYED")]
+    Replayed,
+    #[sea_orm(string_value = "DISCARDED")]
+    Discarded,
+}
+
+#[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
+pub enum Relation {}
+
+impl ActiveModelBehavior for ActiveModel {}
+impl_audit_for_entity!(super::dead_letter::ActiveModel);
@@ -4,6 +4,7 @@ use sea_orm::sea_query::StringLen;
 use serde::{Deserialize, Serialize};
 use crate::impl_audit_for_entity;
 use super::airport;
+use super::route;
 
 #[derive(Clone, Debug, PartialEq, DeriveEntityModel, Serialize, Deserialize)]
 #[sea_orm(table_name = "flights")]
@@ -17,6 +18,7 @@ pub struct Model {
 
     pub origin_airport_id: i64,
     pub destination_airport_id: i64,
+    pub route_id: Option<i64>,
 
     pub departure_date: NaiveDate,
     pub departure_time: NaiveDateTime,
@@ -24,6 +26,13 @@ pub struct Model {
 
     pub status: FlightStatus,
 
+    pub estimated_departure: Option<NaiveDateTime>,
+    pub estimated_arrival: Option<NaiveDateTime>,
+    pub actual_off_block: Option<NaiveDateTime>,
+    pub actual_takeoff: Option<NaiveDateTime>,
+    pub actual_landing: Option<NaiveDateTime>,
+    pub actual_on_block: Option<NaiveDateTime>,
+
     pub aircraft_type: Option<String>,
     pub tail_number: Option<String>,
     pub terminal_departure: Option<String>,
@@ -38,6 +47,12 @@ pub struct Model {
     pub total_seats: i32,
     pub available_seats: i32,
 
+    // Boarding sequence counter; only ever moved by `next_boarding_sequence`.
+    pub boarding_sequence: i32,
+
+    // Set once the post-departure no-show job has run for the flight.
+    pub no_shows_processed_at: Option<NaiveDateTime>,
+
     pub created_at: NaiveDateTime,
     pub updated_at: NaiveDateTime,
 
@@ -78,6 +93,14 @@ pub enum Relation {
         on_delete = "Restrict"
     )]
     DestinationAirport,
+
+    #[sea_orm(
+        belongs_to = "route::Entity",
+        from = "Column::RouteId",
+        to = "route::Column::Id",
+        on_delete = "Restrict"
+    )]
+    Route,
 }
 
 impl Related<airport::Entity> for Entity {
@@ -86,5 +109,11 @@ impl Related<airport::Entity> for Entity {
     }
 }
 
+impl Related<route::Entity> for Entity {
+    fn to() -> RelationDef {
+        Relation::Route.def()
+    }
+}
+
 impl ActiveModelBehavior for ActiveModel {}
 impl_audit_for_entity!(super::flight::ActiveModel);
\ No newline at end of file
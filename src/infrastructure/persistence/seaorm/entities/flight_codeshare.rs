@@ -0,0 +1,43 @@
+use super::flight;
+use crate::impl_audit_for_entity;
+use chrono::NaiveDateTime;
+use sea_orm::entity::prelude::*;
+use serde::{Deserialize, Serialize};
+
+#[derive(Clone, Debug, PartialEq, DeriveEntityModel, Serialize, Deserialize)]
+#[sea_orm(table_name = "flight_codeshares")]
+pub struct Model {
+    #[sea_orm(primary_key)]
+    pub id: i64,
+
+    pub flight_id: i64,
+
+    pub airline_code: String,
+    pub flight_number: String,
+    pub flight_key: String,
+
+    pub created_at: NaiveDateTime,
+    pub updated_at: NaiveDateTime,
+
+    pub created_by: Option<i64>,
+    pub updated_by: Option<i64>,
+}
+#[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
+pub enum Relation {
+    #[sea_orm(
+        belongs_to = "flight::Entity",
+        from = "Column::FlightId",
+        to = "flight::Column::Id",
+        on_delete = "Cascade"
+    )]
+    Flight,
+}
+
+impl Related<flight::Entity> for Entity {
+    fn to() -> RelationDef {
+        Relation::Flight.def()
+    }
+}
+
+impl ActiveModelBehavior for ActiveModel {}
+impl_audit_for_entity!(super::flight_codeshare::ActiveModel);
@@ -0,0 +1,60 @@
+use super::flight::{self, FlightStatus};
+use crate::impl_audit_for_entity;
+use chrono::NaiveDateTime;
+use sea_orm::entity::prelude::*;
+use serde::{Deserialize, Serialize};
+
+#[derive(Clone, Debug, PartialEq, DeriveEntityModel, Serialize, Deserialize)]
+#[sea_orm(table_name = "flight_status_events")]
+pub struct Model {
+    #[sea_orm(primary_key)]
+    pub id: i64,
+
+    pub flight_id: i64,
+    pub from_status: FlightStatus,
+    pub to_status: FlightStatus,
+
+    pub scheduled_departure: NaiveDateTime,
+    pub scheduled_arrival: NaiveDateTime,
+
+    pub estimated_departure: Option<NaiveDateTime>,
+    pub estimated_arrival: Option<NaiveDateTime>,
+    pub actual_off_block: Option<NaiveDateTime>,
+    pub actual_takeoff: Option<NaiveDateTime>,
+    pub actual_landing: Option<NaiveDateTime>,
+    pub actual_on_block: Option<NaiveDateTime>,
+
+    pub delay_minutes: Option<i32>,
+    // Comma separated, most significant first.
+    pub delay_codes: Option<String>,
+    pub reason: Option<String>,
+
+    pub changed_by: Option<i64>,
+    pub recorded_at: NaiveDateTime,
+
+    pub created_at: NaiveDateTime,
+    pub updated_at: NaiveDateTime,
+
+    pub created_by: Option<i64>,
+    pub updated_by: Option<i64>,
+}
+
+#[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
+pub enum Relation {
+    #[sea_orm(
+        belongs_to = "flight::Entity",
+        from = "Column::FlightId",
+        to = "flight::Column::Id",
+        on_delete = "Cascade"
+    )]
+    Flight,
+}
+
+impl Related<flight::Entity> for Entity {
+    fn to() -> RelationDef {
+        Relation::Flight.def()
+    }
+}
+
+impl ActiveModelBehavior for ActiveModel {}
+impl_audit_for_entity!(super::flight_status_event::ActiveModel);
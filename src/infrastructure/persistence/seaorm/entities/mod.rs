@@ -5,4 +5,16 @@ pub mod flight;
 pub mod booking;
 pub mod passenger;
 pub mod checkin;
-pub mod boarding_pass;
\ No newline at end of file
+pub mod boarding_pass;
+pub mod no_show;
+pub mod bag;
+pub mod bag_status_event;
+pub mod airline;
+pub mod aircraft;
+pub mod route;
+pub mod flight_codeshare;
+pub mod flight_status_event;
+pub mod compensation_claim;
+pub mod notification;
+pub mod notification_preference;
+pub mod dead_letter;
\ No newline at end of file
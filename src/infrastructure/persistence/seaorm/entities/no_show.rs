@@ -0,0 +1,78 @@
+use super::booking::{self, FareFamily};
+use super::{flight, passenger};
+use crate::impl_audit_for_entity;
+use chrono::NaiveDateTime;
+use sea_orm::entity::prelude::*;
+use serde::{Deserialize, Serialize};
+
+#[derive(Clone, Debug, PartialEq, DeriveEntityModel, Serialize, Deserialize)]
+#[sea_orm(table_name = "no_shows")]
+pub struct Model {
+    #[sea_orm(primary_key)]
+    pub id: i64,
+
+    pub flight_id: i64,
+    pub booking_id: i64,
+    pub passenger_id: i64,
+    pub checkin_id: Option<i64>,
+
+    pub fare_family: FareFamily,
+    pub fare_amount: Decimal,
+    pub penalty_amount: Decimal,
+    pub currency: String,
+
+    pub recorded_at: NaiveDateTime,
+
+    pub created_at: NaiveDateTime,
+    pub updated_at: NaiveDateTime,
+
+    pub created_by: Option<i64>,
+    pub updated_by: Option<i64>,
+}
+#[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
+pub enum Relation {
+    #[sea_orm(
+        belongs_to = "flight::Entity",
+        from = "Column::FlightId",
+        to = "flight::Column::Id",
+        on_delete = "Restrict"
+    )]
+    Flight,
+
+    #[sea_orm(
+        belongs_to = "booking::Entity",
+        from = "Column::BookingId",
+        to = "booking::Column::Id",
+        on_delete = "Cascade"
+    )]
+    Booking,
+
+    #[sea_orm(
+        belongs_to = "passenger::Entity",
+        from = "Column::PassengerId",
+        to = "passenger::Column::Id",
+        on_delete = "Cascade"
+    )]
+    Passenger,
+}
+
+impl Related<flight::Entity> for Entity {
+    fn to() -> RelationDef {
+        Relation::Flight.def()
+    }
+}
+
+impl Related<booking::Entity> for Entity {
+    fn to() -> RelationDef {
+        Relation::Booking.def()
+    }
+}
+
+impl Related<passenger::Entity> for Entity {
+    fn to() -> RelationDef {
+        Relation::Passenger.def()
+    }
+}
+
+impl ActiveModelBehavior for ActiveModel {}
+impl_audit_for_entity!(super::no_show::ActiveModel);
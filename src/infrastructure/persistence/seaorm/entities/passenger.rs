@@ -34,6 +34,8 @@ pub struct Model {
     pub ff_airline_code: Option<String>,
     pub ff_number: Option<String>,
 
+    pub accompanying_adult_id: Option<i64>,
+
     pub created_at: NaiveDateTime,
     pub updated_at: NaiveDateTime,
 
@@ -0,0 +1,43 @@
+use super::airport;
+use sea_orm::entity::prelude::*;
+use serde::{Deserialize, Serialize};
+
+#[derive(Clone, Debug, PartialEq, DeriveEntityModel, Serialize, Deserialize)]
+#[sea_orm(table_name = "routes")]
+pub struct Model {
+    #[sea_orm(primary_key)]
+    pub id: i64,
+    pub origin_airport_id: i64,
+    pub destination_airport_id: i64,
+    pub distance_km: i32,
+    pub block_time_minutes: i32,
+    pub is_domestic: bool,
+    pub is_active: bool,
+}
+
+#[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
+pub enum Relation {
+    #[sea_orm(
+        belongs_to = "airport::Entity",
+        from = "Column::OriginAirportId",
+        to = "airport::Column::Id",
+        on_delete = "Restrict"
+    )]
+    OriginAirport,
+
+    #[sea_orm(
+        belongs_to = "airport::Entity",
+        from = "Column::DestinationAirportId",
+        to = "airport::Column::Id",
+        on_delete = "Restrict"
+    )]
+    DestinationAirport,
+}
+
+impl Related<airport::Entity> for Entity {
+    fn to() -> RelationDef {
+        Relation::OriginAirport.def()
+    }
+}
+
+impl ActiveModelBehavior for ActiveModel {}
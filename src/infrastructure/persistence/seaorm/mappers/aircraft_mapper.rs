@@ -0,0 +1,65 @@
+use crate::domain::aircraft::entity::{Aircraft, AircraftStatus};
+use crate::infrastructure::persistence::seaorm::entities::aircraft::{
+    self as aircraft_orm, ActiveModel, Model,
+};
+use sea_orm::{NotSet, Set};
+
+impl From<AircraftStatus> for aircraft_orm::AircraftStatus {
+    fn from(s: AircraftStatus) -> Self {
+        match s {
+            AircraftStatus::Active => aircraft_orm::AircraftStatus::Active,
+            AircraftStatus::Maintenance => aircraft_orm::AircraftStatus::Maintenance,
+        }
+    }
+}
+
+impl From<aircraft_orm::AircraftStatus> for AircraftStatus {
+    fn from(s: aircraft_orm::AircraftStatus) -> Self {
+        match s {
+            aircraft_orm::AircraftStatus::Active => AircraftStatus::Active,
+            aircraft_orm::AircraftStatus::Maintenance => AircraftStatus::Maintenance,
+        }
+    }
+}
+
+pub struct AircraftMapper;
+
+impl AircraftMapper {
+    /// SeaORM Model -> Domain Entity
+    pub fn model_to_domain(model: Model) -> Aircraft {
+        Aircraft {
+            id: model.id,
+            tail_number: model.tail_number,
+            aircraft_type: model.aircraft_type,
+            seat_configuration: model.seat_configuration,
+            airline_id: model.airline_id,
+            status: model.status.into(),
+        }
+    }
+
+    /// Domain Entity -> SeaORM ActiveModel (for INSERT)
+    pub fn domain_to_active_model_for_create(entity: &Aircraft) -> ActiveModel {
+        ActiveModel {
+            id: NotSet,
+            tail_number: Set(entity.tail_number.clone()),
+            aircraft_type: Set(entity.aircraft_type.clone()),
+            seat_configuration: Set(entity.seat_configuration.clone()),
+            airline_id: Set(entity.airline_id),
+            status: Set(entity.status.into()),
+        }
+    }
+
+    /// Domain Entity -> SeaORM ActiveModel (for UPDATE)
+    ///
+    /// Flights reference aircraft by tail number, so it is never rewritten.
+    pub fn domain_to_active_model_for_update(entity: &Aircraft) -> ActiveModel {
+        ActiveModel {
+            id: Set(entity.id),
+            tail_number: NotSet,
+            aircraft_type: Set(entity.aircraft_type.clone()),
+            seat_configuration: Set(entity.seat_configuration.clone()),
+            airline_id: Set(entity.airline_id),
+            status: Set(entity.status.into()),
+        }
+    }
+}
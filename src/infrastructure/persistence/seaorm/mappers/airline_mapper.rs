@@ -0,0 +1,51 @@
+use crate::domain::airline::entity::Airline;
+use crate::infrastructure::persistence::seaorm::entities::airline::{ActiveModel, Model};
+use sea_orm::{NotSet, Set};
+
+pub struct AirlineMapper;
+
+impl AirlineMapper {
+    /// SeaORM Model -> Domain Entity
+    pub fn model_to_domain(model: Model) -> Airline {
+        Airline {
+            id: model.id,
+            iata_code: model.iata_code,
+            icao_code: model.icao_code,
+            name: model.name,
+            callsign: model.callsign,
+            country_code: model.country_code,
+            logo_url: model.logo_url,
+            is_active: model.is_active,
+        }
+    }
+
+    /// Domain Entity -> SeaORM ActiveModel (for INSERT)
+    pub fn domain_to_active_model_for_create(entity: &Airline) -> ActiveModel {
+        ActiveModel {
+            id: NotSet,
+            iata_code: Set(entity.iata_code.clone()),
+            icao_code: Set(entity.icao_code.clone()),
+            name: Set(entity.name.clone()),
+            callsign: Set(entity.callsign.clone()),
+            country_code: Set(entity.country_code.clone()),
+            logo_url: Set(entity.logo_url.clone()),
+            is_active: Set(entity.is_active),
+        }
+    }
+
+    /// Domain Entity -> SeaORM ActiveModel (for UPDATE)
+    ///
+    /// The IATA code is the airline's identity and is never rewritten.
+    pub fn domain_to_active_model_for_update(entity: &Airline) -> ActiveModel {
+        ActiveModel {
+            id: Set(entity.id),
+            iata_code: NotSet,
+            icao_code: Set(entity.icao_code.clone()),
+            name: Set(entity.name.clone()),
+            callsign: Set(entity.callsign.clone()),
+            country_code: Set(entity.country_code.clone()),
+            logo_url: Set(entity.logo_url.clone()),
+            is_active: Set(entity.is_active),
+        }
+    }
+}
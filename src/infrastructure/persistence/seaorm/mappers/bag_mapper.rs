@@ -0,0 +1,113 @@
+use crate::domain::baggage::entity::{Bag, BagStatus, BagStatusEntry};
+use crate::infrastructure::persistence::seaorm::entities::bag as bag_orm;
+use crate::infrastructure::persistence::seaorm::entities::bag_status_event as bag_status_event_orm;
+use sea_orm::ActiveValue::{NotSet, Set};
+
+pub struct BagMapper;
+
+/* ---------- ENUM ---------- */
+
+impl From<BagStatus> for bag_orm::BagStatus {
+    fn from(s: BagStatus) -> Self {
+        match s {
+            BagStatus::Checked => bag_orm::BagStatus::Checked,
+            BagStatus::Loaded => bag_orm::BagStatus::Loaded,
+            BagStatus::Transferred => bag_orm::BagStatus::Transferred,
+            BagStatus::Arrived => bag_orm::BagStatus::Arrived,
+            BagStatus::Delayed => bag_orm::BagStatus::Delayed,
+        }
+    }
+}
+
+impl From<bag_orm::BagStatus> for BagStatus {
+    fn from(s: bag_orm::BagStatus) -> Self {
+        match s {
+            bag_orm::BagStatus::Checked => BagStatus::Checked,
+            bag_orm::BagStatus::Loaded => BagStatus::Loaded,
+            bag_orm::BagStatus::Transferred => BagStatus::Transferred,
+            bag_orm::BagStatus::Arrived => BagStatus::Arrived,
+            bag_orm::BagStatus::Delayed => BagStatus::Delayed,
+        }
+    }
+}
+
+/* ---------- MODEL <-> DOMAIN ---------- */
+
+impl BagMapper {
+    pub fn domain_to_active_model_create(bag: &Bag) -> bag_orm::ActiveModel {
+        bag_orm::ActiveModel {
+            id: NotSet,
+            tag_number: Set(bag.tag_number.clone()),
+
+            checkin_id: Set(bag.checkin_id),
+            booking_id: Set(bag.booking_id),
+            passenger_id: Set(bag.passenger_id),
+            flight_id: Set(bag.flight_id),
+            destination_airport_id: Set(bag.destination_airport_id),
+
+            weight_kg: Set(bag.weight_kg),
+            status: Set(bag.status.into()),
+            last_location: Set(bag.last_location.clone()),
+
+            version: Set(bag.version),
+            ..Default::default()
+        }
+    }
+
+    pub fn domain_to_active_model_update(bag: &Bag) -> bag_orm::ActiveModel {
+        let mut active = bag_orm::ActiveModel {
+            id: Set(bag.id),
+            ..Default::default()
+        };
+
+        active.status = Set(bag.status.into());
+        active.last_location = Set(bag.last_location.clone());
+
+        active
+    }
+
+    pub fn entry_to_active_model_create(
+        bag_id: i64,
+        entry: &BagStatusEntry,
+    ) -> bag_status_event_orm::ActiveModel {
+        bag_status_event_orm::ActiveModel {
+            id: NotSet,
+            bag_id: Set(bag_id),
+            status: Set(entry.status.into()),
+            location: Set(entry.location.clone()),
+            note: Set(entry.note.clone()),
+            recorded_at: Set(entry.recorded_at),
+            ..Default::default()
+        }
+    }
+
+    pub fn model_to_domain(model: bag_orm::Model) -> Bag {
+        Bag {
+            id: model.id,
+            tag_number: model.tag_number,
+
+            checkin_id: model.checkin_id,
+            booking_id: model.booking_id,
+            passenger_id: model.passenger_id,
+            flight_id: model.flight_id,
+            destination_airport_id: model.destination_airport_id,
+
+            weight_kg: model.weight_kg,
+            status: model.status.into(),
+            last_location: model.last_location,
+
+            version: model.version,
+        }
+    }
+
+    pub fn entry_model_to_domain(model: bag_status_event_orm::Model) -> BagStatusEntry {
+        BagStatusEntry {
+            id: model.id,
+            bag_id: model.bag_id,
+            status: model.status.into(),
+            location: model.location,
+            note: model.note,
+            recorded_at: model.recorded_at,
+        }
+    }
+}
@@ -24,6 +24,11 @@ impl BoardingPassMapper {
             barcode_format: Set(boarding_pass.barcode_format.clone()),
             barcode_payload: Set(boarding_pass.barcode_payload.clone()),
 
+            revoked_at: Set(boarding_pass.revoked_at),
+
+            wallet_pass_version: Set(boarding_pass.wallet_pass_version),
+            wallet_pass_fingerprint: Set(boarding_pass.wallet_pass_fingerprint.clone()),
+
             ..Default::default()
         }
     }
@@ -46,6 +51,11 @@ impl BoardingPassMapper {
 
             barcode_format: model.barcode_format,
             barcode_payload: model.barcode_payload,
+
+            revoked_at: model.revoked_at,
+
+            wallet_pass_version: model.wallet_pass_version,
+            wallet_pass_fingerprint: model.wallet_pass_fingerprint,
         }
     }
 }
@@ -1,4 +1,6 @@
-use crate::domain::booking::entity::{Booking, BookingStatus, PaymentMethod, PaymentStatus};
+use crate::domain::booking::entity::{
+    Booking, BookingStatus, FareFamily, PaymentMethod, PaymentStatus,
+};
 use crate::infrastructure::persistence::seaorm::entities::booking as booking_orm;
 use sea_orm::ActiveValue::{NotSet, Set};
 
@@ -13,6 +15,7 @@ impl From<BookingStatus> for booking_orm::BookingStatus {
             BookingStatus::Confirmed => booking_orm::BookingStatus::Confirmed,
             BookingStatus::Cancelled => booking_orm::BookingStatus::Cancelled,
             BookingStatus::Expired => booking_orm::BookingStatus::Expired,
+            BookingStatus::NoShow => booking_orm::BookingStatus::NoShow,
         }
     }
 }
@@ -24,6 +27,7 @@ impl From<booking_orm::BookingStatus> for BookingStatus {
             booking_orm::BookingStatus::Confirmed => BookingStatus::Confirmed,
             booking_orm::BookingStatus::Cancelled => BookingStatus::Cancelled,
             booking_orm::BookingStatus::Expired => BookingStatus::Expired,
+            booking_orm::BookingStatus::NoShow => BookingStatus::NoShow,
         }
     }
 }
@@ -70,6 +74,26 @@ impl From<booking_orm::PaymentMethod> for PaymentMethod {
     }
 }
 
+impl From<FareFamily> for booking_orm::FareFamily {
+    fn from(fare_family: FareFamily) -> Self {
+        match fare_family {
+            FareFamily::Basic => booking_orm::FareFamily::Basic,
+            FareFamily::Standard => booking_orm::FareFamily::Standard,
+            FareFamily::Flex => booking_orm::FareFamily::Flex,
+        }
+    }
+}
+
+impl From<booking_orm::FareFamily> for FareFamily {
+    fn from(fare_family: booking_orm::FareFamily) -> Self {
+        match fare_family {
+            booking_orm::FareFamily::Basic => FareFamily::Basic,
+            booking_orm::FareFamily::Standard => FareFamily::Standard,
+            booking_orm::FareFamily::Flex => FareFamily::Flex,
+        }
+    }
+}
+
 /* ---------- MODEL <-> DOMAIN ---------- */
 
 impl BookingMapper {
@@ -79,6 +103,8 @@ impl BookingMapper {
             booking_code: Set(booking.booking_code.clone()),
             user_id: Set(booking.user_id),
             flight_id: Set(booking.flight_id),
+            marketing_airline_code: Set(booking.marketing_airline_code.clone()),
+            marketing_flight_number: Set(booking.marketing_flight_number.clone()),
 
             status: Set(booking.status.clone().into()),
             cancellation_reason: Set(booking.cancellation_reason.clone()),
@@ -90,6 +116,7 @@ impl BookingMapper {
             total_amount: Set(booking.total_amount),
 
             currency: Set(booking.currency.clone()),
+            fare_family: Set(booking.fare_family.into()),
 
             contact_email: Set(booking.contact_email.clone()),
             contact_phone: Set(booking.contact_phone.clone()),
@@ -132,6 +159,8 @@ impl BookingMapper {
             booking_code: model.booking_code,
             user_id: model.user_id,
             flight_id: model.flight_id,
+            marketing_airline_code: model.marketing_airline_code,
+            marketing_flight_number: model.marketing_flight_number,
 
             status: model.status.into(),
             cancellation_reason: model.cancellation_reason,
@@ -143,6 +172,7 @@ impl BookingMapper {
             total_amount: model.total_amount,
 
             currency: model.currency,
+            fare_family: model.fare_family.into(),
 
             contact_email: model.contact_email,
             contact_phone: model.contact_phone,
@@ -11,7 +11,10 @@ impl From<CheckinStatus> for checkin_orm::CheckinStatus {
         match s {
             CheckinStatus::Pending => checkin_orm::CheckinStatus::Pending,
             CheckinStatus::CheckedIn => checkin_orm::CheckinStatus::CheckedIn,
+            CheckinStatus::Boarded => checkin_orm::CheckinStatus::Boarded,
+            CheckinStatus::Offloaded => checkin_orm::CheckinStatus::Offloaded,
             CheckinStatus::Cancelled => checkin_orm::CheckinStatus::Cancelled,
+            CheckinStatus::Standby => checkin_orm::CheckinStatus::Standby,
         }
     }
 }
@@ -21,7 +24,10 @@ impl From<checkin_orm::CheckinStatus> for CheckinStatus {
         match s {
             checkin_orm::CheckinStatus::Pending => CheckinStatus::Pending,
             checkin_orm::CheckinStatus::CheckedIn => CheckinStatus::CheckedIn,
+            checkin_orm::CheckinStatus::Boarded => CheckinStatus::Boarded,
+            checkin_orm::CheckinStatus::Offloaded => CheckinStatus::Offloaded,
             checkin_orm::CheckinStatus::Cancelled => CheckinStatus::Cancelled,
+            checkin_orm::CheckinStatus::Standby => CheckinStatus::Standby,
         }
     }
 }
@@ -87,8 +93,18 @@ impl CheckinMapper {
             baggage_count: Set(checkin.baggage_count),
             baggage_weight_total: Set(checkin.baggage_weight_total),
             baggage_weight_unit: Set(checkin.baggage_weight_unit.clone()),
+            baggage_piece_weights: Set(serde_json::json!(checkin.baggage_piece_weights)),
+
+            baggage_allowance: Set(checkin.baggage_allowance.clone()),
+            excess_baggage_pieces: Set(checkin.excess_baggage_pieces),
+            excess_baggage_weight_kg: Set(checkin.excess_baggage_weight_kg),
+            excess_baggage_fee: Set(checkin.excess_baggage_fee),
+            excess_baggage_currency: Set(checkin.excess_baggage_currency.clone()),
+            excess_baggage_paid_at: Set(checkin.excess_baggage_paid_at),
+            excess_baggage_payment_txn_id: Set(checkin.excess_baggage_payment_txn_id.clone()),
 
             checked_in_at: Set(checkin.checked_in_at),
+            boarded_at: Set(checkin.boarded_at),
 
             checkin_channel: Set(checkin.checkin_channel.clone().into()),
             checked_in_ip: Set(checkin.checked_in_ip.clone()),
@@ -107,7 +123,17 @@ impl CheckinMapper {
         active.seat_no = Set(checkin.seat_no.clone());
         active.baggage_count = Set(checkin.baggage_count);
         active.baggage_weight_total = Set(checkin.baggage_weight_total);
+        active.baggage_piece_weights = Set(serde_json::json!(checkin.baggage_piece_weights));
+        active.seat_class = Set(checkin.seat_class.clone().into());
+        active.baggage_allowance = Set(checkin.baggage_allowance.clone());
+        active.excess_baggage_pieces = Set(checkin.excess_baggage_pieces);
+        active.excess_baggage_weight_kg = Set(checkin.excess_baggage_weight_kg);
+        active.excess_baggage_fee = Set(checkin.excess_baggage_fee);
+        active.excess_baggage_currency = Set(checkin.excess_baggage_currency.clone());
+        active.excess_baggage_paid_at = Set(checkin.excess_baggage_paid_at);
+        active.excess_baggage_payment_txn_id = Set(checkin.excess_baggage_payment_txn_id.clone());
         active.checked_in_at = Set(checkin.checked_in_at);
+        active.boarded_at = Set(checkin.boarded_at);
 
         active
     }
@@ -126,8 +152,19 @@ impl CheckinMapper {
             baggage_count: model.baggage_count,
             baggage_weight_total: model.baggage_weight_total,
             baggage_weight_unit: model.baggage_weight_unit,
+            baggage_piece_weights: serde_json::from_value(model.baggage_piece_weights)
+                .unwrap_or_default(),
+
+            baggage_allowance: model.baggage_allowance,
+            excess_baggage_pieces: model.excess_baggage_pieces,
+            excess_baggage_weight_kg: model.excess_baggage_weight_kg,
+            excess_baggage_fee: model.excess_baggage_fee,
+            excess_baggage_currency: model.excess_baggage_currency,
+            excess_baggage_paid_at: model.excess_baggage_paid_at,
+            excess_baggage_payment_txn_id: model.excess_baggage_payment_txn_id,
 
             checked_in_at: model.checked_in_at,
+            boarded_at: model.boarded_at,
             checkin_channel: model.checkin_channel.into(),
             checked_in_ip: model.checked_in_ip,
             version: model.version,
@@ -0,0 +1,122 @@
+use crate::domain::compensation::compensation_policy::CompensationReason;
+use crate::domain::compensation::entity::{CompensationClaim, CompensationClaimStatus};
+use crate::infrastructure::persistence::seaorm::entities::compensation_claim as claim_orm;
+use sea_orm::ActiveValue::{NotSet, Set};
+
+pub struct CompensationClaimMapper;
+
+/* ---------- ENUM ---------- */
+
+impl From<CompensationReason> for claim_orm::CompensationReason {
+    fn from(r: CompensationReason) -> Self {
+        match r {
+            CompensationReason::Delay => claim_orm::CompensationReason::Delay,
+            CompensationReason::Cancellation => claim_orm::CompensationReason::Cancellation,
+        }
+    }
+}
+
+impl From<claim_orm::CompensationReason> for CompensationReason {
+    fn from(r: claim_orm::CompensationReason) -> Self {
+        match r {
+            claim_orm::CompensationReason::Delay => CompensationReason::Delay,
+            claim_orm::CompensationReason::Cancellation => CompensationReason::Cancellation,
+        }
+    }
+}
+
+impl From<CompensationClaimStatus> for claim_orm::CompensationClaimStatus {
+    fn from(s: CompensationClaimStatus) -> Self {
+        match s {
+            CompensationClaimStatus::Submitted => claim_orm::CompensationClaimStatus::Submitted,
+            CompensationClaimStatus::Approved => claim_orm::CompensationClaimStatus::Approved,
+            CompensationClaimStatus::Rejected => claim_orm::CompensationClaimStatus::Rejected,
+        }
+    }
+}
+
+impl From<claim_orm::CompensationClaimStatus> for CompensationClaimStatus {
+    fn from(s: claim_orm::CompensationClaimStatus) -> Self {
+        match s {
+            claim_orm::CompensationClaimStatus::Submitted => CompensationClaimStatus::Submitted,
+            claim_orm::CompensationClaimStatus::Approved => CompensationClaimStatus::Approved,
+            claim_orm::CompensationClaimStatus::Rejected => CompensationClaimStatus::Rejected,
+        }
+    }
+}
+
+/* ---------- MODEL <-> DOMAIN ---------- */
+
+impl CompensationClaimMapper {
+    pub fn domain_to_active_model_create(claim: &CompensationClaim) -> claim_orm::ActiveModel {
+        claim_orm::ActiveModel {
+            id: NotSet,
+            booking_id: Set(claim.booking_id),
+            flight_id: Set(claim.flight_id),
+            user_id: Set(claim.user_id),
+
+            reason: Set(claim.reason.into()),
+            status: Set(claim.status.into()),
+
+            distance_km: Set(claim.distance_km),
+            arrival_delay_minutes: Set(claim.arrival_delay_minutes),
+            passenger_count: Set(claim.passenger_count),
+            amount_per_passenger: Set(claim.amount_per_passenger),
+            total_amount: Set(claim.total_amount),
+            currency: Set(claim.currency.clone()),
+
+            customer_note: Set(claim.customer_note.clone()),
+            decision_note: Set(claim.decision_note.clone()),
+            decided_by: Set(claim.decided_by),
+
+            submitted_at: Set(claim.submitted_at),
+            decided_at: Set(claim.decided_at),
+
+            version: Set(claim.version),
+            ..Default::default()
+        }
+    }
+
+    /// Only the decision changes after submission; the assessed amounts are kept as filed.
+    pub fn domain_to_active_model_update(claim: &CompensationClaim) -> claim_orm::ActiveModel {
+        let mut active = claim_orm::ActiveModel {
+            id: Set(claim.id),
+            ..Default::default()
+        };
+
+        active.status = Set(claim.status.into());
+        active.decision_note = Set(claim.decision_note.clone());
+        active.decided_by = Set(claim.decided_by);
+        active.decided_at = Set(claim.decided_at);
+
+        active
+    }
+
+    pub fn model_to_domain(model: claim_orm::Model) -> CompensationClaim {
+        CompensationClaim {
+            id: model.id,
+            booking_id: model.booking_id,
+            flight_id: model.flight_id,
+            user_id: model.user_id,
+
+            reason: model.reason.into(),
+            status: model.status.into(),
+
+            distance_km: model.distance_km,
+            arrival_delay_minutes: model.arrival_delay_minutes,
+            passenger_count: model.passenger_count,
+            amount_per_passenger: model.amount_per_passenger,
+            total_amount: model.total_amount,
+            currency: model.currency,
+
+            customer_note: model.customer_note,
+            decision_note: model.decision_note,
+            decided_by: model.decided_by,
+
+            submitted_at: model.submitted_at,
+            decided_at: model.decided_at,
+
+            version: model.version,
+        }
+    }
+}
@@ -0,0 +1,109 @@
+use crate::domain::dead_letter::entity::{DeadLetter, DeadLetterStatus};
+use crate::infrastructure::persistence::seaorm::entities::dead_letter as dead_letter_orm;
+use sea_orm::ActiveValue::{NotSet, Set};
+
+pub struct DeadLetterMapper;
+
+/* ---------- ENUM ---------- */
+
+impl From<DeadLetterStatus> for dead_letter_orm::DeadLetterStatus {
+    fn from(s: DeadLetterStatus) -> Self {
+        match s {
+            DeadLetterStatus::Pending => dead_letter_orm::DeadLetterStatus::Pending,
+            DeadLetterStatus::Replayed => dead_letter_orm::DeadLetterStatus::Replayed,
+            DeadLetterStatus::Discarded => dead_letter_orm::DeadLetterStatus::Discarded,
+        }
+    }
+}
+
+impl From<dead_letter_orm::DeadLetterStatus> for DeadLetterStatus {
+    fn from(s: dead_letter_orm::DeadLetterStatus) -> Self {
+        match s {
+            dead_letter_orm::DeadLetterStatus::Pending => DeadLetterStatus::Pending,
+            dead_letter_orm::DeadLetterStatus::Replayed => DeadLetterStatus::Replayed,
+            dead_letter_orm::DeadLetterStatus::Discarded => DeadLetterStatus::Discarded,
+        }
+    }
+}
+
+/* ---------- MODEL <-> DOMAIN ---------- */
+
+impl DeadLetterMapper {
+    pub fn domain_to_active_model_create(dead_letter: &DeadLetter) -> dead_letter_orm::ActiveModel {
+        dead_letter_orm::ActiveModel {
+            id: NotSet,
+            consumer_group: Set(dead_letter.consumer_group.clone()),
+            handler: Set(dead_letter.handler.clone()),
+
+            original_topic: Set(dead_letter.original_topic.clone()),
+            original_partition: Set(dead_letter.original_partition),
+            original_offset: Set(dead_letter.original_offset),
+
+            message_key: Set(dead_letter.message_key.clone()),
+            payload: Set(dead_letter.payload.clone()),
+            headers: Set(serde_json::json!(dead_letter.headers)),
+
+            error: Set(dead_letter.error.clone()),
+            attempts: Set(dead_letter.attempts),
+
+            dead_letter_topic: Set(dead_letter.dead_letter_topic.clone()),
+            dead_letter_partition: Set(dead_letter.dead_letter_partition),
+            dead_letter_offset: Set(dead_letter.dead_letter_offset),
+
+            status: Set(dead_letter.status.into()),
+            failed_at: Set(dead_letter.failed_at),
+            resolved_at: Set(dead_letter.resolved_at),
+            resolved_by: Set(dead_letter.resolved_by),
+            resolution_note: Set(dead_letter.resolution_note.clone()),
+
+            version: Set(dead_letter.version),
+            ..Default::default()
+        }
+    }
+
+    /// The message itself is kept as archived; only its resolution changes.
+    pub fn domain_to_active_model_update(dead_letter: &DeadLetter) -> dead_letter_orm::ActiveModel {
+        let mut active = dead_letter_orm::ActiveModel {
+            id: Set(dead_letter.id),
+            ..Default::default()
+        };
+
+        active.status = Set(dead_letter.status.into());
+        active.resolved_at = Set(dead_letter.resolved_at);
+        active.resolved_by = Set(dead_letter.resolved_by);
+        active.resolution_note = Set(dead_letter.resolution_note.clone());
+
+        active
+    }
+
+    pub fn model_to_domain(model: dead_letter_orm::Model) -> DeadLetter {
+        DeadLetter {
+            id: model.id,
+            consumer_group: model.consumer_group,
+            handler: model.handler,
+
+            original_topic: model.original_topic,
+            original_partition: model.original_partition,
+            original_offset: model.original_offset,
+
+            message_key: model.message_key,
+            payload: model.payload,
+            headers: serde_json::from_value(model.headers).unwrap_or_default(),
+
+            error: model.error,
+            attempts: model.attempts,
+
+            dead_letter_topic: model.dead_letter_topic,
+            dead_letter_partition: model.dead_letter_partition,
+            dead_letter_offset: model.dead_letter_offset,
+
+            status: model.status.into(),
+            failed_at: model.failed_at,
+            resolved_at: model.resolved_at,
+            resolved_by: model.resolved_by,
+            resolution_note: model.resolution_note,
+
+            version: model.version,
+        }
+    }
+}
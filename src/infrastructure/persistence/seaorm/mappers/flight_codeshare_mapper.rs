@@ -0,0 +1,30 @@
+use crate::domain::flight::codeshare::FlightCodeshare;
+use crate::infrastructure::persistence::seaorm::entities::flight_codeshare as codeshare_orm;
+use sea_orm::ActiveValue::{NotSet, Set};
+
+pub struct FlightCodeshareMapper;
+
+impl FlightCodeshareMapper {
+    pub fn domain_to_active_model_create(
+        codeshare: &FlightCodeshare,
+    ) -> codeshare_orm::ActiveModel {
+        codeshare_orm::ActiveModel {
+            id: NotSet,
+            flight_id: Set(codeshare.flight_id),
+            airline_code: Set(codeshare.airline_code.clone()),
+            flight_number: Set(codeshare.flight_number.clone()),
+            flight_key: Set(codeshare.flight_key.clone()),
+            ..Default::default()
+        }
+    }
+
+    pub fn model_to_domain(model: codeshare_orm::Model) -> FlightCodeshare {
+        FlightCodeshare {
+            id: model.id,
+            flight_id: model.flight_id,
+            airline_code: model.airline_code,
+            flight_number: model.flight_number,
+            flight_key: model.flight_key,
+        }
+    }
+}
@@ -1,5 +1,7 @@
 use crate::domain::flight::entity::{Flight, FlightStatus};
+use crate::domain::flight::status_event::{FlightOperationalTimes, FlightStatusEvent};
 use crate::infrastructure::persistence::seaorm::entities::flight as flight_orm;
+use crate::infrastructure::persistence::seaorm::entities::flight_status_event as flight_status_event_orm;
 use sea_orm::ActiveValue::{NotSet, Set};
 
 pub struct FlightMapper;
@@ -43,6 +45,7 @@ impl FlightMapper {
 
             origin_airport_id: Set(flight.origin_airport_id),
             destination_airport_id: Set(flight.destination_airport_id),
+            route_id: Set(flight.route_id),
 
             departure_date: Set(flight.departure_date),
             departure_time: Set(flight.departure_time),
@@ -50,6 +53,13 @@ impl FlightMapper {
 
             status: Set(flight.status.clone().into()),
 
+            estimated_departure: Set(flight.operational_times.estimated_departure),
+            estimated_arrival: Set(flight.operational_times.estimated_arrival),
+            actual_off_block: Set(flight.operational_times.actual_off_block),
+            actual_takeoff: Set(flight.operational_times.actual_takeoff),
+            actual_landing: Set(flight.operational_times.actual_landing),
+            actual_on_block: Set(flight.operational_times.actual_on_block),
+
             aircraft_type: Set(flight.aircraft_type.clone()),
             tail_number: Set(flight.tail_number.clone()),
             terminal_departure: Set(flight.terminal_departure.clone()),
@@ -75,6 +85,18 @@ impl FlightMapper {
         };
 
         active.status = Set(flight.status.clone().into());
+        active.estimated_departure = Set(flight.operational_times.estimated_departure);
+        active.estimated_arrival = Set(flight.operational_times.estimated_arrival);
+        active.actual_off_block = Set(flight.operational_times.actual_off_block);
+        active.actual_takeoff = Set(flight.operational_times.actual_takeoff);
+        active.actual_landing = Set(flight.operational_times.actual_landing);
+        active.actual_on_block = Set(flight.operational_times.actual_on_block);
+        active.departure_time = Set(flight.departure_time);
+        active.arrival_time = Set(flight.arrival_time);
+        active.aircraft_type = Set(flight.aircraft_type.clone());
+        active.tail_number = Set(flight.tail_number.clone());
+        active.terminal_departure = Set(flight.terminal_departure.clone());
+        active.terminal_arrival = Set(flight.terminal_arrival.clone());
         active.available_seats = Set(flight.available_seats);
         active.checkin_open_at = Set(flight.checkin_open_at);
         active.checkin_close_at = Set(flight.checkin_close_at);
@@ -93,12 +115,21 @@ impl FlightMapper {
 
             origin_airport_id: model.origin_airport_id,
             destination_airport_id: model.destination_airport_id,
+            route_id: model.route_id,
 
             departure_date: model.departure_date,
             departure_time: model.departure_time,
             arrival_time: model.arrival_time,
 
             status: model.status.into(),
+            operational_times: FlightOperationalTimes {
+                estimated_departure: model.estimated_departure,
+                estimated_arrival: model.estimated_arrival,
+                actual_off_block: model.actual_off_block,
+                actual_takeoff: model.actual_takeoff,
+                actual_landing: model.actual_landing,
+                actual_on_block: model.actual_on_block,
+            },
 
             aircraft_type: model.aircraft_type,
             tail_number: model.tail_number,
@@ -116,4 +147,65 @@ impl FlightMapper {
             version: model.version,
         }
     }
+
+    pub fn status_event_to_active_model_create(
+        event: &FlightStatusEvent,
+    ) -> flight_status_event_orm::ActiveModel {
+        flight_status_event_orm::ActiveModel {
+            id: NotSet,
+            flight_id: Set(event.flight_id),
+            from_status: Set(event.from_status.clone().into()),
+            to_status: Set(event.to_status.clone().into()),
+
+            scheduled_departure: Set(event.scheduled_departure),
+            scheduled_arrival: Set(event.scheduled_arrival),
+
+            estimated_departure: Set(event.times.estimated_departure),
+            estimated_arrival: Set(event.times.estimated_arrival),
+            actual_off_block: Set(event.times.actual_off_block),
+            actual_takeoff: Set(event.times.actual_takeoff),
+            actual_landing: Set(event.times.actual_landing),
+            actual_on_block: Set(event.times.actual_on_block),
+
+            delay_minutes: Set(event.delay_minutes),
+            delay_codes: Set((!event.delay_codes.is_empty()).then(|| event.delay_codes.join(","))),
+            reason: Set(event.reason.clone()),
+
+            changed_by: Set(event.changed_by),
+            recorded_at: Set(event.recorded_at),
+            ..Default::default()
+        }
+    }
+
+    pub fn status_event_model_to_domain(
+        model: flight_status_event_orm::Model,
+    ) -> FlightStatusEvent {
+        FlightStatusEvent {
+            id: model.id,
+            flight_id: model.flight_id,
+            from_status: model.from_status.into(),
+            to_status: model.to_status.into(),
+
+            scheduled_departure: model.scheduled_departure,
+            scheduled_arrival: model.scheduled_arrival,
+            times: FlightOperationalTimes {
+                estimated_departure: model.estimated_departure,
+                estimated_arrival: model.estimated_arrival,
+                actual_off_block: model.actual_off_block,
+                actual_takeoff: model.actual_takeoff,
+                actual_landing: model.actual_landing,
+                actual_on_block: model.actual_on_block,
+            },
+
+            delay_minutes: model.delay_minutes,
+            delay_codes: model
+                .delay_codes
+                .map(|codes| codes.split(',').map(str::to_string).collect())
+                .unwrap_or_default(),
+            reason: model.reason,
+
+            changed_by: model.changed_by,
+            recorded_at: model.recorded_at,
+        }
+    }
 }
@@ -5,4 +5,13 @@ pub mod booking_mapper;
 pub mod passenger_mapper;
 pub mod flight_mapper;
 pub mod checkin_mapper;
-pub mod boarding_pass_mapper;
\ No newline at end of file
+pub mod boarding_pass_mapper;
+pub mod no_show_mapper;
+pub mod bag_mapper;
+pub mod airline_mapper;
+pub mod aircraft_mapper;
+pub mod route_mapper;
+pub mod flight_codeshare_mapper;
+pub mod compensation_claim_mapper;
+pub mod notification_mapper;
+pub mod dead_letter_mapper;
\ No newline at end of file
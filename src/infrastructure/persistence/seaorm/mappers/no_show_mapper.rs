@@ -0,0 +1,43 @@
+use crate::domain::booking::no_show::NoShow;
+use crate::infrastructure::persistence::seaorm::entities::no_show as no_show_orm;
+use sea_orm::ActiveValue::{NotSet, Set};
+
+pub struct NoShowMapper;
+
+impl NoShowMapper {
+    pub fn domain_to_active_model_create(no_show: &NoShow) -> no_show_orm::ActiveModel {
+        no_show_orm::ActiveModel {
+            id: NotSet,
+            flight_id: Set(no_show.flight_id),
+            booking_id: Set(no_show.booking_id),
+            passenger_id: Set(no_show.passenger_id),
+            checkin_id: Set(no_show.checkin_id),
+
+            fare_family: Set(no_show.fare_family.into()),
+            fare_amount: Set(no_show.fare_amount),
+            penalty_amount: Set(no_show.penalty_amount),
+            currency: Set(no_show.currency.clone()),
+
+            recorded_at: Set(no_show.recorded_at),
+
+            ..Default::default()
+        }
+    }
+
+    pub fn model_to_domain(model: no_show_orm::Model) -> NoShow {
+        NoShow {
+            id: model.id,
+            flight_id: model.flight_id,
+            booking_id: model.booking_id,
+            passenger_id: model.passenger_id,
+            checkin_id: model.checkin_id,
+
+            fare_family: model.fare_family.into(),
+            fare_amount: model.fare_amount,
+            penalty_amount: model.penalty_amount,
+            currency: model.currency,
+
+            recorded_at: model.recorded_at,
+        }
+    }
+}
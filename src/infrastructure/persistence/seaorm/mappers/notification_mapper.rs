@@ -0,0 +1,224 @@
+use crate::domain::notification::entity::{
+    Notification, NotificationChannelKind, NotificationKind, NotificationStatus,
+};
+use crate::domain::notification::preference::NotificationPreference;
+use crate::infrastructure::persistence::seaorm::entities::{
+    notification as notification_orm, notification_preference as preference_orm,
+};
+use sea_orm::ActiveValue::{NotSet, Set};
+
+pub struct NotificationMapper;
+
+pub struct NotificationPreferenceMapper;
+
+/* ---------- ENUM ---------- */
+
+impl From<NotificationKind> for notification_orm::NotificationKind {
+    fn from(k: NotificationKind) -> Self {
+        match k {
+            NotificationKind::EmailVerification => {
+                notification_orm::NotificationKind::EmailVerification
+            }
+            NotificationKind::BookingConfirmation => {
+                notification_orm::NotificationKind::BookingConfirmation
+            }
+            NotificationKind::CheckinReminder => {
+                notification_orm::NotificationKind::CheckinReminder
+            }
+            NotificationKind::FlightDisruption => {
+                notification_orm::NotificationKind::FlightDisruption
+            }
+        }
+    }
+}
+
+impl From<notification_orm::NotificationKind> for NotificationKind {
+    fn from(k: notification_orm::NotificationKind) -> Self {
+        match k {
+            notification_orm::NotificationKind::EmailVerification => {
+                NotificationKind::EmailVerification
+            }
+            notification_orm::NotificationKind::BookingConfirmation => {
+                NotificationKind::BookingConfirmation
+            }
+            notification_orm::NotificationKind::CheckinReminder => {
+                NotificationKind::CheckinReminder
+            }
+            notification_orm::NotificationKind::FlightDisruption => {
+                NotificationKind::FlightDisruption
+            }
+        }
+    }
+}
+
+impl From<NotificationChannelKind> for notification_orm::NotificationChannel {
+    fn from(c: NotificationChannelKind) -> Self {
+        match c {
+            NotificationChannelKind::Email => notification_orm::NotificationChannel::Email,
+            NotificationChannelKind::Sms => notification_orm::NotificationChannel::Sms,
+            NotificationChannelKind::Push => notification_orm::NotificationChannel::Push,
+        }
+    }
+}
+
+impl From<notification_orm::NotificationChannel> for NotificationChannelKind {
+    fn from(c: notification_orm::NotificationChannel) -> Self {
+        match c {
+            notification_orm::NotificationChannel::Email => NotificationChannelKind::Email,
+            notification_orm::NotificationChannel::Sms => NotificationChannelKind::Sms,
+            notification_orm::NotificationChannel::Push => NotificationChannelKind::Push,
+        }
+    }
+}
+
+impl From<NotificationStatus> for notification_orm::NotificationStatus {
+    fn from(s: NotificationStatus) -> Self {
+        match s {
+            NotificationStatus::Pending => notification_orm::NotificationStatus::Pending,
+            NotificationStatus::Sent => notification_orm::NotificationStatus::Sent,
+            NotificationStatus::Failed => notification_orm::NotificationStatus::Failed,
+            NotificationStatus::Cancelled => notification_orm::NotificationStatus::Cancelled,
+        }
+    }
+}
+
+impl From<notification_orm::NotificationStatus> for NotificationStatus {
+    fn from(s: notification_orm::NotificationStatus) -> Self {
+        match s {
+            notification_orm::NotificationStatus::Pending => NotificationStatus::Pending,
+            notification_orm::NotificationStatus::Sent => NotificationStatus::Sent,
+            notification_orm::NotificationStatus::Failed => NotificationStatus::Failed,
+            notification_orm::NotificationStatus::Cancelled => NotificationStatus::Cancelled,
+        }
+    }
+}
+
+/* ---------- MODEL <-> DOMAIN ---------- */
+
+impl NotificationMapper {
+    pub fn domain_to_active_model_create(
+        notification: &Notification,
+    ) -> notification_orm::ActiveModel {
+        notification_orm::ActiveModel {
+            id: NotSet,
+            kind: Set(notification.kind.into()),
+            channel: Set(notification.channel.into()),
+            status: Set(notification.status.into()),
+
+            user_id: Set(notification.user_id),
+            booking_id: Set(notification.booking_id),
+            flight_id: Set(notification.flight_id),
+
+            recipient: Set(notification.recipient.clone()),
+            locale: Set(notification.locale.clone()),
+            subject: Set(notification.subject.clone()),
+            body: Set(notification.body.clone()),
+            dedup_key: Set(notification.dedup_key.clone()),
+
+            attempts: Set(notification.attempts),
+            last_error: Set(notification.last_error.clone()),
+            provider_message_id: Set(notification.provider_message_id.clone()),
+
+            scheduled_at: Set(notification.scheduled_at),
+            sent_at: Set(notification.sent_at),
+
+            version: Set(notification.version),
+            ..Default::default()
+        }
+    }
+
+    /// Only the delivery state changes once a notification is queued.
+    pub fn domain_to_active_model_update(
+        notification: &Notification,
+    ) -> notification_orm::ActiveModel {
+        let mut active = notification_orm::ActiveModel {
+            id: Set(notification.id),
+            ..Default::default()
+        };
+
+        active.status = Set(notification.status.into());
+        active.attempts = Set(notification.attempts);
+        active.last_error = Set(notification.last_error.clone());
+        active.provider_message_id = Set(notification.provider_message_id.clone());
+        active.scheduled_at = Set(notification.scheduled_at);
+        active.sent_at = Set(notification.sent_at);
+
+        active
+    }
+
+    pub fn model_to_domain(model: notification_orm::Model) -> Notification {
+        Notification {
+            id: model.id,
+            kind: model.kind.into(),
+            channel: model.channel.into(),
+            status: model.status.into(),
+
+            user_id: model.user_id,
+            booking_id: model.booking_id,
+            flight_id: model.flight_id,
+
+            recipient: model.recipient,
+            locale: model.locale,
+            subject: model.subject,
+            body: model.body,
+            dedup_key: model.dedup_key,
+
+            attempts: model.attempts,
+            last_error: model.last_error,
+            provider_message_id: model.provider_message_id,
+
+            scheduled_at: model.scheduled_at,
+            sent_at: model.sent_at,
+
+            version: model.version,
+        }
+    }
+}
+
+impl NotificationPreferenceMapper {
+    pub fn domain_to_active_model_create(
+        preference: &NotificationPreference,
+    ) -> preference_orm::ActiveModel {
+        preference_orm::ActiveModel {
+            user_id: Set(preference.user_id),
+            locale: Set(preference.locale.clone()),
+            email_enabled: Set(preference.email_enabled),
+            sms_enabled: Set(preference.sms_enabled),
+            push_enabled: Set(preference.push_enabled),
+            push_token: Set(preference.push_token.clone()),
+
+            version: Set(1),
+            ..Default::default()
+        }
+    }
+
+    pub fn domain_to_active_model_update(
+        preference: &NotificationPreference,
+    ) -> preference_orm::ActiveModel {
+        let mut active = preference_orm::ActiveModel {
+            user_id: Set(preference.user_id),
+            ..Default::default()
+        };
+
+        active.locale = Set(preference.locale.clone());
+        active.email_enabled = Set(preference.email_enabled);
+        active.sms_enabled = Set(preference.sms_enabled);
+        active.push_enabled = Set(preference.push_enabled);
+        active.push_token = Set(preference.push_token.clone());
+
+        active
+    }
+
+    pub fn model_to_domain(model: preference_orm::Model) -> NotificationPreference {
+        NotificationPreference {
+            user_id: model.user_id,
+            locale: model.locale,
+            email_enabled: model.email_enabled,
+            sms_enabled: model.sms_enabled,
+            push_enabled: model.push_enabled,
+            push_token: model.push_token,
+
+            version: model.version,
+        }
+    }
+}
@@ -52,6 +52,7 @@ impl PassengerMapper {
 
             ff_airline_code: Set(passenger.ff_airline_code.clone()),
             ff_number: Set(passenger.ff_number.clone()),
+            accompanying_adult_id: Set(passenger.accompanying_adult_id),
             version: Set(passenger.version),
             ..Default::default()
         }
@@ -71,6 +72,7 @@ impl PassengerMapper {
 
             ff_airline_code: Set(passenger.ff_airline_code.clone()),
             ff_number: Set(passenger.ff_number.clone()),
+            accompanying_adult_id: Set(passenger.accompanying_adult_id),
 
             version: NotSet,
 
@@ -104,6 +106,7 @@ impl PassengerMapper {
 
             ff_airline_code: model.ff_airline_code,
             ff_number: model.ff_number,
+            accompanying_adult_id: model.accompanying_adult_id,
             version: model.version,
         }
     }
@@ -0,0 +1,48 @@
+use crate::domain::route::entity::Route;
+use crate::infrastructure::persistence::seaorm::entities::route::{ActiveModel, Model};
+use sea_orm::{NotSet, Set};
+
+pub struct RouteMapper;
+
+impl RouteMapper {
+    /// SeaORM Model -> Domain Entity
+    pub fn model_to_domain(model: Model) -> Route {
+        Route {
+            id: model.id,
+            origin_airport_id: model.origin_airport_id,
+            destination_airport_id: model.destination_airport_id,
+            distance_km: model.distance_km,
+            block_time_minutes: model.block_time_minutes,
+            is_domestic: model.is_domestic,
+            is_active: model.is_active,
+        }
+    }
+
+    /// Domain Entity -> SeaORM ActiveModel (for INSERT)
+    pub fn domain_to_active_model_for_create(entity: &Route) -> ActiveModel {
+        ActiveModel {
+            id: NotSet,
+            origin_airport_id: Set(entity.origin_airport_id),
+            destination_airport_id: Set(entity.destination_airport_id),
+            distance_km: Set(entity.distance_km),
+            block_time_minutes: Set(entity.block_time_minutes),
+            is_domestic: Set(entity.is_domestic),
+            is_active: Set(entity.is_active),
+        }
+    }
+
+    /// Domain Entity -> SeaORM ActiveModel (for UPDATE)
+    ///
+    /// The airport pair identifies the route and is never rewritten.
+    pub fn domain_to_active_model_for_update(entity: &Route) -> ActiveModel {
+        ActiveModel {
+            id: Set(entity.id),
+            origin_airport_id: NotSet,
+            destination_airport_id: NotSet,
+            distance_km: Set(entity.distance_km),
+            block_time_minutes: Set(entity.block_time_minutes),
+            is_domestic: Set(entity.is_domestic),
+            is_active: Set(entity.is_active),
+        }
+    }
+}
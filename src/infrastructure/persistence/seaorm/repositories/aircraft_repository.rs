@@ -0,0 +1,145 @@
+use crate::domain::aircraft::aircraft_repository_interface::AircraftRepositoryInterface;
+use crate::domain::aircraft::entity::Aircraft as DomainAircraft;
+use crate::domain::aircraft::error::AircraftDomainError;
+use crate::domain::error::DomainError;
+use crate::infrastructure::persistence::seaorm::entities::aircraft::{Column, Entity};
+use crate::infrastructure::persistence::seaorm::mappers::aircraft_mapper::AircraftMapper;
+use async_trait::async_trait;
+use sea_orm::{
+    ActiveModelTrait, ColumnTrait, DatabaseConnection, EntityTrait, PaginatorTrait, QueryFilter,
+    QueryOrder,
+};
+use std::sync::Arc;
+
+pub struct SeaOrmAircraftRepository {
+    pub db: Arc<DatabaseConnection>,
+}
+
+impl SeaOrmAircraftRepository {
+    pub fn new(db: Arc<DatabaseConnection>) -> Self {
+        Self { db }
+    }
+
+    fn map_db_err(e: sea_orm::DbErr) -> DomainError {
+        let aircraft_err = match e {
+            sea_orm::DbErr::RecordNotFound(detail) => AircraftDomainError::NotFound { detail },
+
+            sea_orm::DbErr::Query(err) => {
+                let msg = err.to_string().to_lowercase();
+
+                if msg.contains("duplicate key")
+                    || msg.contains("unique constraint")
+                    || msg.contains("duplicate entry")
+                {
+                    let field = map_conflict_field(&msg);
+                    return AircraftDomainError::Conflict {
+                        field,
+                        message: err.to_string(),
+                    }
+                    .into();
+                }
+
+                if msg.contains("foreign key") {
+                    return AircraftDomainError::BusinessRule {
+                        message: err.to_string(),
+                    }
+                    .into();
+                }
+
+                AircraftDomainError::Internal(err.to_string())
+            }
+
+            sea_orm::DbErr::Conn(err) => AircraftDomainError::Internal(err.to_string()),
+            sea_orm::DbErr::Exec(err) => AircraftDomainError::Internal(err.to_string()),
+            sea_orm::DbErr::Type(err) => AircraftDomainError::Internal(err.to_string()),
+            sea_orm::DbErr::Json(err) => AircraftDomainError::Internal(err.to_string()),
+            sea_orm::DbErr::Migration(err) => AircraftDomainError::Internal(err.to_string()),
+
+            other => AircraftDomainError::Internal(other.to_string()),
+        };
+
+        aircraft_err.into()
+    }
+}
+
+fn map_conflict_field(msg: &str) -> &'static str {
+    let msg = msg.to_lowercase();
+
+    if msg.contains("tail_number") {
+        "tail_number"
+    } else {
+        "unknown"
+    }
+}
+
+#[async_trait]
+impl AircraftRepositoryInterface for SeaOrmAircraftRepository {
+    async fn create_aircraft(&self, aircraft: &DomainAircraft) -> Result<i64, DomainError> {
+        let active_model = AircraftMapper::domain_to_active_model_for_create(aircraft);
+        let res = active_model
+            .insert(self.db.as_ref())
+            .await
+            .map_err(Self::map_db_err)?;
+        Ok(res.id)
+    }
+
+    async fn update_aircraft(&self, aircraft: &DomainAircraft) -> Result<(), DomainError> {
+        let active_model = AircraftMapper::domain_to_active_model_for_update(aircraft);
+        active_model
+            .update(self.db.as_ref())
+            .await
+            .map_err(Self::map_db_err)?;
+        Ok(())
+    }
+
+    async fn find_aircraft_by_id(&self, id: i64) -> Result<Option<DomainAircraft>, DomainError> {
+        let model = Entity::find_by_id(id)
+            .one(self.db.as_ref())
+            .await
+            .map_err(Self::map_db_err)?;
+
+        Ok(model.map(AircraftMapper::model_to_domain))
+    }
+
+    async fn find_aircraft_by_tail_number(
+        &self,
+        tail_number: &str,
+    ) -> Result<Option<DomainAircraft>, DomainError> {
+        Ok(Entity::find()
+            .filter(Column::TailNumber.eq(tail_number))
+            .one(self.db.as_ref())
+            .await
+            .map_err(Self::map_db_err)?
+            .map(AircraftMapper::model_to_domain))
+    }
+
+    async fn tail_number_exists(&self, tail_number: &str) -> Result<bool, DomainError> {
+        let count = Entity::find()
+            .filter(Column::TailNumber.eq(tail_number))
+            .count(self.db.as_ref())
+            .await
+            .map_err(Self::map_db_err)?;
+
+        Ok(count > 0)
+    }
+
+    async fn list_aircraft(
+        &self,
+        airline_id: Option<i64>,
+    ) -> Result<Vec<DomainAircraft>, DomainError> {
+        let query = match airline_id {
+            Some(airline_id) => Entity::find().filter(Column::AirlineId.eq(airline_id)),
+            None => Entity::find(),
+        };
+        let models = query
+            .order_by_asc(Column::TailNumber)
+            .all(self.db.as_ref())
+            .await
+            .map_err(Self::map_db_err)?;
+
+        Ok(models
+            .into_iter()
+            .map(AircraftMapper::model_to_domain)
+            .collect())
+    }
+}
@@ -0,0 +1,145 @@
+use crate::domain::airline::airline_repository_interface::AirlineRepositoryInterface;
+use crate::domain::airline::entity::Airline as DomainAirline;
+use crate::domain::airline::error::AirlineDomainError;
+use crate::domain::error::DomainError;
+use crate::infrastructure::persistence::seaorm::entities::airline::{Column, Entity};
+use crate::infrastructure::persistence::seaorm::mappers::airline_mapper::AirlineMapper;
+use async_trait::async_trait;
+use sea_orm::{
+    ActiveModelTrait, ColumnTrait, DatabaseConnection, EntityTrait, PaginatorTrait, QueryFilter,
+    QueryOrder,
+};
+use std::sync::Arc;
+
+pub struct SeaOrmAirlineRepository {
+    pub db: Arc<DatabaseConnection>,
+}
+
+impl SeaOrmAirlineRepository {
+    pub fn new(db: Arc<DatabaseConnection>) -> Self {
+        Self { db }
+    }
+
+    fn map_db_err(e: sea_orm::DbErr) -> DomainError {
+        let airline_err = match e {
+            sea_orm::DbErr::RecordNotFound(detail) => AirlineDomainError::NotFound { detail },
+
+            sea_orm::DbErr::Query(err) => {
+                let msg = err.to_string().to_lowercase();
+
+                if msg.contains("duplicate key")
+                    || msg.contains("unique constraint")
+                    || msg.contains("duplicate entry")
+                {
+                    let field = map_conflict_field(&msg);
+                    return AirlineDomainError::Conflict {
+                        field,
+                        message: err.to_string(),
+                    }
+                    .into();
+                }
+
+                if msg.contains("foreign key") {
+                    return AirlineDomainError::BusinessRule {
+                        message: err.to_string(),
+                    }
+                    .into();
+                }
+
+                AirlineDomainError::Internal(err.to_string())
+            }
+
+            sea_orm::DbErr::Conn(err) => AirlineDomainError::Internal(err.to_string()),
+            sea_orm::DbErr::Exec(err) => AirlineDomainError::Internal(err.to_string()),
+            sea_orm::DbErr::Type(err) => AirlineDomainError::Internal(err.to_string()),
+            sea_orm::DbErr::Json(err) => AirlineDomainError::Internal(err.to_string()),
+            sea_orm::DbErr::Migration(err) => AirlineDomainError::Internal(err.to_string()),
+
+            other => AirlineDomainError::Internal(other.to_string()),
+        };
+
+        airline_err.into()
+    }
+}
+
+fn map_conflict_field(msg: &str) -> &'static str {
+    let msg = msg.to_lowercase();
+
+    if msg.contains("iata") {
+        "iata_code"
+    } else if msg.contains("icao") {
+        "icao_code"
+    } else {
+        "unknown"
+    }
+}
+
+#[async_trait]
+impl AirlineRepositoryInterface for SeaOrmAirlineRepository {
+    async fn create_airline(&self, airline: &DomainAirline) -> Result<i64, DomainError> {
+        let active_model = AirlineMapper::domain_to_active_model_for_create(airline);
+        let res = active_model
+            .insert(self.db.as_ref())
+            .await
+            .map_err(Self::map_db_err)?;
+        Ok(res.id)
+    }
+
+    async fn update_airline(&self, airline: &DomainAirline) -> Result<(), DomainError> {
+        let active_model = AirlineMapper::domain_to_active_model_for_update(airline);
+        active_model
+            .update(self.db.as_ref())
+            .await
+            .map_err(Self::map_db_err)?;
+        Ok(())
+    }
+
+    async fn find_airline_by_id(&self, id: i64) -> Result<Option<DomainAirline>, DomainError> {
+        let model = Entity::find_by_id(id)
+            .one(self.db.as_ref())
+            .await
+            .map_err(Self::map_db_err)?;
+
+        Ok(model.map(AirlineMapper::model_to_domain))
+    }
+
+    async fn find_airline_by_iata_code(
+        &self,
+        iata_code: &str,
+    ) -> Result<Option<DomainAirline>, DomainError> {
+        Ok(Entity::find()
+            .filter(Column::IataCode.eq(iata_code))
+            .one(self.db.as_ref())
+            .await
+            .map_err(Self::map_db_err)?
+            .map(AirlineMapper::model_to_domain))
+    }
+
+    async fn iata_code_exists(&self, iata_code: &str) -> Result<bool, DomainError> {
+        let count = Entity::find()
+            .filter(Column::IataCode.eq(iata_code))
+            .count(self.db.as_ref())
+            .await
+            .map_err(Self::map_db_err)?;
+
+        Ok(count > 0)
+    }
+
+    async fn list_airlines(&self, active_only: bool) -> Result<Vec<DomainAirline>, DomainError> {
+        let query = if active_only {
+            Entity::find().filter(Column::IsActive.eq(true))
+        } else {
+            Entity::find()
+        };
+        let models = query
+            .order_by_asc(Column::IataCode)
+            .all(self.db.as_ref())
+            .await
+            .map_err(Self::map_db_err)?;
+
+        Ok(models
+            .into_iter()
+            .map(AirlineMapper::model_to_domain)
+            .collect())
+    }
+}
@@ -1,13 +1,17 @@
 use crate::domain::airport::airport_repository_interface::AirportRepositoryInterface;
 use crate::domain::airport::entity::Airport as DomainAirport;
 use crate::domain::airport::error::AirportDomainError;
+use crate::domain::airport::geo::BoundingBox;
 use crate::domain::error::DomainError;
 use crate::infrastructure::persistence::seaorm::entities::airport::{ActiveModel, Column, Entity};
 use crate::infrastructure::persistence::seaorm::mappers::airport_mapper::AirportMapper;
 use async_trait::async_trait;
+use rust_decimal::Decimal;
+use rust_decimal::prelude::FromPrimitive;
+use sea_orm::sea_query::Expr;
 use sea_orm::{
-    ActiveModelTrait, ColumnTrait, DatabaseConnection, EntityTrait, PaginatorTrait, QueryFilter,
-    Select, Set,
+    ActiveModelTrait, ColumnTrait, Condition, DatabaseConnection, EntityTrait, Order,
+    PaginatorTrait, QueryFilter, QueryOrder, QuerySelect, Select, Set, TransactionTrait,
 };
 use std::sync::Arc;
 
@@ -77,6 +81,21 @@ impl SeaOrmAirportRepository {
     }
 }
 
+// Keeps each INSERT well below the Postgres bind parameter limit.
+const IMPORT_BATCH_SIZE: usize = 500;
+
+// Escapes LIKE wildcards in user input; Postgres uses backslash as the default escape.
+fn escape_like(value: &str) -> String {
+    value
+        .replace('\\', "\\\\")
+        .replace('%', "\\%")
+        .replace('_', "\\_")
+}
+
+fn coordinate(value: f64) -> Decimal {
+    Decimal::from_f64(value).unwrap_or_default()
+}
+
 fn map_conflict_field(msg: &str) -> &'static str {
     let msg = msg.to_lowercase();
 
@@ -178,4 +197,119 @@ impl AirportRepositoryInterface for SeaOrmAirportRepository {
             .map(AirportMapper::model_to_domain)
             .collect())
     }
+
+    async fn search_airport_candidates(
+        &self,
+        query: &str,
+        limit: u64,
+    ) -> Result<Vec<DomainAirport>, DomainError> {
+        let term = query.trim().to_lowercase();
+        let prefix = format!("{}%", escape_like(&term));
+        let substring = format!("%{}%", escape_like(&term));
+
+        // `<%` is pg_trgm word similarity, served by the trigram indexes on name and city.
+        let matches = Condition::any()
+            .add(Expr::cust_with_values(
+                "lower(iata_code) LIKE ?",
+                [prefix.clone()],
+            ))
+            .add(Expr::cust_with_values(
+                "lower(icao_code) LIKE ?",
+                [prefix.clone()],
+            ))
+            .add(Expr::cust_with_values(
+                "lower(name) LIKE ?",
+                [substring.clone()],
+            ))
+            .add(Expr::cust_with_values("lower(city) LIKE ?", [substring]))
+            .add(Expr::cust_with_values("? <% lower(name)", [term.clone()]))
+            .add(Expr::cust_with_values("? <% lower(city)", [term.clone()]));
+
+        // Code matches go first so the cut to `limit` cannot drop an airport whose code
+        // matches but whose name and city do not.
+        let models = Self::base_query()
+            .filter(matches)
+            .order_by(
+                Expr::cust_with_values(
+                    "CASE \
+                        WHEN lower(iata_code) = ? OR lower(icao_code) = ? THEN 0 \
+                        WHEN lower(iata_code) LIKE ? OR lower(icao_code) LIKE ? THEN 1 \
+                        ELSE 2 \
+                    END",
+                    [term.clone(), term.clone(), prefix.clone(), prefix],
+                ),
+                Order::Asc,
+            )
+            .order_by(
+                Expr::cust_with_values(
+                    "GREATEST(word_similarity(?, lower(name)), word_similarity(?, lower(city)))",
+                    [term.clone(), term],
+                ),
+                Order::Desc,
+            )
+            .limit(limit)
+            .all(self.db.as_ref())
+            .await
+            .map_err(Self::map_db_err)?;
+
+        Ok(models
+            .into_iter()
+            .map(AirportMapper::model_to_domain)
+            .collect())
+    }
+
+    async fn list_airports_in_bounding_box(
+        &self,
+        bounds: &BoundingBox,
+    ) -> Result<Vec<DomainAirport>, DomainError> {
+        let mut longitude = Condition::any();
+        for (min, max) in &bounds.longitude_ranges {
+            longitude =
+                longitude.add(Column::Longitude.between(coordinate(*min), coordinate(*max)));
+        }
+
+        let models = Self::base_query()
+            .filter(Column::Latitude.between(
+                coordinate(bounds.min_latitude),
+                coordinate(bounds.max_latitude),
+            ))
+            .filter(longitude)
+            .all(self.db.as_ref())
+            .await
+            .map_err(Self::map_db_err)?;
+
+        Ok(models
+            .into_iter()
+            .map(AirportMapper::model_to_domain)
+            .collect())
+    }
+
+    async fn import_airports(
+        &self,
+        inserts: &[DomainAirport],
+        updates: &[DomainAirport],
+    ) -> Result<(), DomainError> {
+        let txn = self.db.begin().await.map_err(Self::map_db_err)?;
+
+        for batch in inserts.chunks(IMPORT_BATCH_SIZE) {
+            Entity::insert_many(
+                batch
+                    .iter()
+                    .map(AirportMapper::domain_to_active_model_for_create),
+            )
+            .exec(&txn)
+            .await
+            .map_err(Self::map_db_err)?;
+        }
+
+        for airport in updates {
+            AirportMapper::domain_to_active_model_for_update(airport)
+                .update(&txn)
+                .await
+                .map_err(Self::map_db_err)?;
+        }
+
+        txn.commit().await.map_err(Self::map_db_err)?;
+        Ok(())
+    }
 }
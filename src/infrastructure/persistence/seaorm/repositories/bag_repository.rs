@@ -0,0 +1,198 @@
+use sea_orm::sea_query::Expr;
+use sea_orm::{
+    ActiveModelTrait, ColumnTrait, ConnectionTrait, DatabaseConnection, DbErr, EntityTrait,
+    ExprTrait, QueryFilter, QueryOrder, Statement, TransactionTrait,
+};
+use std::sync::Arc;
+
+use crate::core::context::request_context_provider::RequestContextProvider;
+use crate::domain::baggage::error::BaggageDomainError;
+use crate::domain::baggage::{
+    bag_repository_interface::BagRepositoryInterface,
+    entity::{Bag, BagStatusEntry},
+};
+use crate::domain::error::DomainError;
+use crate::infrastructure::persistence::seaorm::optimistic_lock::optimistic_ok;
+use crate::infrastructure::persistence::seaorm::{
+    base_behavior::Auditable, entities::bag as bag_orm,
+    entities::bag_status_event as bag_status_event_orm, mappers::bag_mapper::BagMapper,
+};
+
+pub struct SeaOrmBagRepository {
+    db: Arc<DatabaseConnection>,
+    ctx: Arc<dyn RequestContextProvider>,
+}
+
+impl SeaOrmBagRepository {
+    pub fn new(db: Arc<DatabaseConnection>, ctx: Arc<dyn RequestContextProvider>) -> Self {
+        Self { db, ctx }
+    }
+
+    fn map_db_err(e: DbErr) -> DomainError {
+        match e {
+            DbErr::RecordNotFound(detail) => BaggageDomainError::NotFound { detail }.into(),
+
+            DbErr::Query(err) => {
+                let msg = err.to_string().to_lowercase();
+
+                // ===== UNIQUE / conflict =====
+                if msg.contains("duplicate") || msg.contains("unique constraint") {
+                    return BaggageDomainError::Conflict {
+                        field: "tag_number",
+                        message: err.to_string(),
+                    }
+                    .into();
+                }
+
+                // ===== FK =====
+                if msg.contains("foreign key") {
+                    return BaggageDomainError::BusinessRule {
+                        message: err.to_string(),
+                    }
+                    .into();
+                }
+
+                BaggageDomainError::Internal(err.to_string()).into()
+            }
+
+            // Connection / pool / runtime errors -> Internal
+            DbErr::Conn(err) => BaggageDomainError::Internal(err.to_string()).into(),
+            DbErr::Exec(err) => BaggageDomainError::Internal(err.to_string()).into(),
+            DbErr::Type(err) => BaggageDomainError::Internal(err.to_string()).into(),
+            DbErr::Json(err) => BaggageDomainError::Internal(err.to_string()).into(),
+            DbErr::Migration(err) => BaggageDomainError::Internal(err.to_string()).into(),
+
+            other => BaggageDomainError::Internal(other.to_string()).into(),
+        }
+    }
+}
+
+#[async_trait::async_trait]
+impl BagRepositoryInterface for SeaOrmBagRepository {
+    async fn next_tag_serial(&self) -> Result<i64, DomainError> {
+        // A database sequence hands out serials without races between check-in counters.
+        let row = self
+            .db
+            .query_one_raw(Statement::from_string(
+                self.db.get_database_backend(),
+                "SELECT nextval('bag_tag_serial_seq') AS serial",
+            ))
+            .await
+            .map_err(Self::map_db_err)?
+            .ok_or_else(|| {
+                BaggageDomainError::Internal("bag tag sequence returned no row".into())
+            })?;
+
+        row.try_get::<i64>("", "serial").map_err(Self::map_db_err)
+    }
+
+    async fn create_bag(&self, bag: &Bag, entry: &BagStatusEntry) -> Result<i64, DomainError> {
+        let ctx = self.ctx.current();
+        let txn = self.db.begin().await.map_err(Self::map_db_err)?;
+
+        let mut active_model = BagMapper::domain_to_active_model_create(bag);
+        active_model.apply_create_audit(&ctx);
+        let bag_id = active_model
+            .insert(&txn)
+            .await
+            .map_err(Self::map_db_err)?
+            .id;
+
+        let mut entry_model = BagMapper::entry_to_active_model_create(bag_id, entry);
+        entry_model.apply_create_audit(&ctx);
+        entry_model.insert(&txn).await.map_err(Self::map_db_err)?;
+
+        txn.commit().await.map_err(Self::map_db_err)?;
+        Ok(bag_id)
+    }
+
+    async fn update_bag_status(
+        &self,
+        bag: &Bag,
+        expected_version: i32,
+        entry: &BagStatusEntry,
+    ) -> Result<(), DomainError> {
+        let ctx = self.ctx.current();
+        let txn = self.db.begin().await.map_err(Self::map_db_err)?;
+
+        let mut active_model = BagMapper::domain_to_active_model_update(bag);
+        active_model.apply_update_audit(&ctx);
+        let result = bag_orm::Entity::update_many()
+            .filter(bag_orm::Column::Id.eq(bag.id))
+            .filter(bag_orm::Column::Version.eq(expected_version))
+            .set(active_model)
+            .col_expr(
+                bag_orm::Column::Version,
+                Expr::col(bag_orm::Column::Version).add(1),
+            )
+            .exec(&txn)
+            .await
+            .map_err(Self::map_db_err)?;
+
+        if !optimistic_ok(result.rows_affected) {
+            return Err(BaggageDomainError::OptimisticLockConflict.into());
+        }
+
+        let mut entry_model = BagMapper::entry_to_active_model_create(bag.id, entry);
+        entry_model.apply_create_audit(&ctx);
+        entry_model.insert(&txn).await.map_err(Self::map_db_err)?;
+
+        txn.commit().await.map_err(Self::map_db_err)?;
+        Ok(())
+    }
+
+    async fn find_bag_by_tag(&self, tag_number: &str) -> Result<Option<Bag>, DomainError> {
+        // Serials wrap, so an old tag number can come back; the newest bag owns it.
+        Ok(bag_orm::Entity::find()
+            .filter(bag_orm::Column::TagNumber.eq(tag_number))
+            .order_by_desc(bag_orm::Column::Id)
+            .one(self.db.as_ref())
+            .await
+            .map_err(Self::map_db_err)?
+            .map(BagMapper::model_to_domain))
+    }
+
+    async fn list_bags_by_checkin(&self, checkin_id: i64) -> Result<Vec<Bag>, DomainError> {
+        Ok(bag_orm::Entity::find()
+            .filter(bag_orm::Column::CheckinId.eq(checkin_id))
+            .order_by_asc(bag_orm::Column::Id)
+            .all(self.db.as_ref())
+            .await
+            .map_err(Self::map_db_err)?
+            .into_iter()
+            .map(BagMapper::model_to_domain)
+            .collect())
+    }
+
+    async fn list_bags_by_booking(&self, booking_id: i64) -> Result<Vec<Bag>, DomainError> {
+        Ok(bag_orm::Entity::find()
+            .filter(bag_orm::Column::BookingId.eq(booking_id))
+            .order_by_asc(bag_orm::Column::Id)
+            .all(self.db.as_ref())
+            .await
+            .map_err(Self::map_db_err)?
+            .into_iter()
+            .map(BagMapper::model_to_domain)
+            .collect())
+    }
+
+    async fn list_status_history(
+        &self,
+        bag_ids: &[i64],
+    ) -> Result<Vec<BagStatusEntry>, DomainError> {
+        if bag_ids.is_empty() {
+            return Ok(Vec::new());
+        }
+
+        Ok(bag_status_event_orm::Entity::find()
+            .filter(bag_status_event_orm::Column::BagId.is_in(bag_ids.iter().copied()))
+            .order_by_asc(bag_status_event_orm::Column::RecordedAt)
+            .order_by_asc(bag_status_event_orm::Column::Id)
+            .all(self.db.as_ref())
+            .await
+            .map_err(Self::map_db_err)?
+            .into_iter()
+            .map(BagMapper::entry_model_to_domain)
+            .collect())
+    }
+}
@@ -9,6 +9,8 @@ use crate::infrastructure::persistence::seaorm::{
     base_behavior::Auditable, entities::boarding_pass as boarding_pass_orm,
     mappers::boarding_pass_mapper::BoardingPassMapper,
 };
+use chrono::NaiveDateTime;
+use sea_orm::sea_query::Expr;
 use sea_orm::{ActiveModelTrait, ColumnTrait, DatabaseConnection, DbErr, EntityTrait, QueryFilter};
 use std::sync::Arc;
 
@@ -141,4 +143,52 @@ impl BoardingPassRepositoryInterface for SeaOrmBoardingPassRepository {
             .map_err(Self::map_db_err)?
             .is_some())
     }
+
+    async fn revoke_boarding_pass(
+        &self,
+        id: i64,
+        revoked_at: NaiveDateTime,
+    ) -> Result<(), DomainError> {
+        let result = boarding_pass_orm::Entity::update_many()
+            .filter(boarding_pass_orm::Column::Id.eq(id))
+            .filter(boarding_pass_orm::Column::RevokedAt.is_null())
+            .col_expr(boarding_pass_orm::Column::RevokedAt, Expr::val(revoked_at))
+            .col_expr(boarding_pass_orm::Column::UpdatedAt, Expr::val(revoked_at))
+            .exec(self.db.as_ref())
+            .await
+            .map_err(Self::map_db_err)?;
+
+        if result.rows_affected == 0 {
+            return Err(BoardingPassDomainError::BoardingPassRevoked.into());
+        }
+
+        Ok(())
+    }
+
+    async fn update_wallet_pass(
+        &self,
+        boarding_pass: &DomainBoardingPass,
+        expected_version: i32,
+    ) -> Result<bool, DomainError> {
+        let result = boarding_pass_orm::Entity::update_many()
+            .filter(boarding_pass_orm::Column::Id.eq(boarding_pass.id))
+            .filter(boarding_pass_orm::Column::WalletPassVersion.eq(expected_version))
+            .col_expr(
+                boarding_pass_orm::Column::WalletPassVersion,
+                Expr::val(boarding_pass.wallet_pass_version),
+            )
+            .col_expr(
+                boarding_pass_orm::Column::WalletPassFingerprint,
+                Expr::val(boarding_pass.wallet_pass_fingerprint.clone()),
+            )
+            .col_expr(
+                boarding_pass_orm::Column::UpdatedAt,
+                Expr::val(chrono::Utc::now().naive_utc()),
+            )
+            .exec(self.db.as_ref())
+            .await
+            .map_err(Self::map_db_err)?;
+
+        Ok(result.rows_affected > 0)
+    }
 }
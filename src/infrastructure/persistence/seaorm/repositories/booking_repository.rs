@@ -1,7 +1,7 @@
 use sea_orm::sea_query::Expr;
 use sea_orm::{
     ActiveModelTrait, ColumnTrait, DatabaseConnection, DbErr, EntityTrait, ExprTrait,
-    PaginatorTrait, QueryFilter,
+    PaginatorTrait, QueryFilter, TransactionTrait,
 };
 use std::sync::Arc;
 
@@ -14,6 +14,7 @@ use crate::domain::error::DomainError;
 use crate::infrastructure::persistence::seaorm::{
     base_behavior::Auditable, entities::booking as booking_orm,
     mappers::booking_mapper::BookingMapper, optimistic_lock::optimistic_ok,
+    repositories::flight_repository::SeaOrmFlightRepository,
 };
 pub struct SeaOrmBookingRepository {
     db: Arc<DatabaseConnection>,
@@ -105,6 +106,42 @@ impl BookingRepositoryInterface for SeaOrmBookingRepository {
         Ok(())
     }
 
+    async fn cancel_booking(
+        &self,
+        booking: &DomainBooking,
+        expected_version: i32,
+        released_seats: i32,
+    ) -> Result<(), DomainError> {
+        let ctx = self.ctx.current();
+        let mut active_model = BookingMapper::domain_to_active_model_create(booking);
+        active_model.apply_update_audit(&ctx);
+
+        let txn = self.db.begin().await.map_err(Self::map_db_err)?;
+
+        let result = booking_orm::Entity::update_many()
+            .set(active_model)
+            .col_expr(
+                booking_orm::Column::Version,
+                Expr::col(booking_orm::Column::Version).add(1),
+            )
+            .filter(booking_orm::Column::Id.eq(booking.id))
+            .filter(booking_orm::Column::Version.eq(expected_version))
+            .exec(&txn)
+            .await
+            .map_err(Self::map_db_err)?;
+
+        if !optimistic_ok(result.rows_affected) {
+            return Err(BookingDomainError::OptimisticLockConflict.into());
+        }
+
+        if released_seats > 0 {
+            SeaOrmFlightRepository::release_seats(&txn, booking.flight_id, released_seats).await?;
+        }
+
+        txn.commit().await.map_err(Self::map_db_err)?;
+        Ok(())
+    }
+
     async fn find_booking_by_id(&self, id: i64) -> Result<Option<DomainBooking>, DomainError> {
         Ok(booking_orm::Entity::find_by_id(id)
             .one(self.db.as_ref())
@@ -146,6 +183,22 @@ impl BookingRepositoryInterface for SeaOrmBookingRepository {
             .collect())
     }
 
+    async fn list_bookings_by_flight(
+        &self,
+        flight_id: i64,
+    ) -> Result<Vec<DomainBooking>, DomainError> {
+        let models = booking_orm::Entity::find()
+            .filter(booking_orm::Column::FlightId.eq(flight_id))
+            .all(self.db.as_ref())
+            .await
+            .map_err(Self::map_db_err)?;
+
+        Ok(models
+            .into_iter()
+            .map(BookingMapper::model_to_domain)
+            .collect())
+    }
+
     async fn booking_code_exists(&self, booking_code: &str) -> Result<bool, DomainError> {
         Ok(booking_orm::Entity::find()
             .filter(booking_orm::Column::BookingCode.eq(booking_code))
@@ -1,4 +1,5 @@
 use crate::core::context::request_context_provider::RequestContextProvider;
+use crate::domain::boarding_pass::entity::BoardingPass as DomainBoardingPass;
 use crate::domain::checkin::error::CheckinDomainError;
 use crate::domain::checkin::{
     checkin_repository_interface::CheckinRepositoryInterface,
@@ -9,11 +10,12 @@ use crate::domain::flight::error::FlightDomainError;
 use crate::infrastructure::persistence::seaorm::optimistic_lock::optimistic_ok;
 use crate::infrastructure::persistence::seaorm::{
     base_behavior::Auditable, entities::checkin as checkin_orm,
-    mappers::checkin_mapper::CheckinMapper,
+    mappers::boarding_pass_mapper::BoardingPassMapper, mappers::checkin_mapper::CheckinMapper,
 };
 use sea_orm::sea_query::Expr;
 use sea_orm::{
     ActiveModelTrait, ColumnTrait, DatabaseConnection, DbErr, EntityTrait, ExprTrait, QueryFilter,
+    TransactionTrait,
 };
 use std::sync::Arc;
 
@@ -109,6 +111,46 @@ impl CheckinRepositoryInterface for SeaOrmCheckinRepository {
         Ok(())
     }
 
+    async fn complete_checkin(
+        &self,
+        checkin: &DomainCheckin,
+        expected_version: i32,
+        boarding_pass: &DomainBoardingPass,
+    ) -> Result<(), DomainError> {
+        let ctx = self.ctx.current();
+        let txn = self.db.begin().await.map_err(Self::map_db_err)?;
+
+        let mut active_model = CheckinMapper::domain_to_active_model_update(checkin);
+        active_model.apply_update_audit(&ctx);
+
+        let result = checkin_orm::Entity::update_many()
+            .filter(checkin_orm::Column::Id.eq(checkin.id))
+            .filter(checkin_orm::Column::Version.eq(expected_version))
+            .set(active_model)
+            .col_expr(
+                checkin_orm::Column::Version,
+                Expr::col(checkin_orm::Column::Version).add(1),
+            )
+            .exec(&txn)
+            .await
+            .map_err(Self::map_db_err)?;
+
+        if !optimistic_ok(result.rows_affected) {
+            return Err(FlightDomainError::OptimisticLockConflict.into());
+        }
+
+        let mut boarding_pass_model =
+            BoardingPassMapper::domain_to_active_model_create(boarding_pass);
+        boarding_pass_model.apply_create_audit(&ctx);
+        boarding_pass_model
+            .insert(&txn)
+            .await
+            .map_err(Self::map_db_err)?;
+
+        txn.commit().await.map_err(Self::map_db_err)?;
+        Ok(())
+    }
+
     async fn find_checkin_by_id(&self, id: i64) -> Result<Option<DomainCheckin>, DomainError> {
         Ok(checkin_orm::Entity::find_by_id(id)
             .one(self.db.as_ref())
@@ -131,6 +173,22 @@ impl CheckinRepositoryInterface for SeaOrmCheckinRepository {
             .map(CheckinMapper::model_to_domain))
     }
 
+    async fn list_checkins_by_booking(
+        &self,
+        booking_id: i64,
+    ) -> Result<Vec<DomainCheckin>, DomainError> {
+        let models = checkin_orm::Entity::find()
+            .filter(checkin_orm::Column::BookingId.eq(booking_id))
+            .all(self.db.as_ref())
+            .await
+            .map_err(Self::map_db_err)?;
+
+        Ok(models
+            .into_iter()
+            .map(CheckinMapper::model_to_domain)
+            .collect())
+    }
+
     async fn update_checkin_status(
         &self,
         checkin_id: i64,
@@ -0,0 +1,150 @@
+use sea_orm::sea_query::Expr;
+use sea_orm::{
+    ActiveModelTrait, ColumnTrait, DatabaseConnection, DbErr, EntityTrait, ExprTrait, QueryFilter,
+    QueryOrder,
+};
+use std::sync::Arc;
+
+use crate::core::context::request_context_provider::RequestContextProvider;
+use crate::domain::compensation::error::CompensationDomainError;
+use crate::domain::compensation::{
+    compensation_claim_repository_interface::CompensationClaimRepositoryInterface,
+    entity::{CompensationClaim, CompensationClaimStatus},
+};
+use crate::domain::error::DomainError;
+use crate::infrastructure::persistence::seaorm::optimistic_lock::optimistic_ok;
+use crate::infrastructure::persistence::seaorm::{
+    base_behavior::Auditable, entities::compensation_claim as claim_orm,
+    mappers::compensation_claim_mapper::CompensationClaimMapper,
+};
+
+pub struct SeaOrmCompensationClaimRepository {
+    db: Arc<DatabaseConnection>,
+    ctx: Arc<dyn RequestContextProvider>,
+}
+
+impl SeaOrmCompensationClaimRepository {
+    pub fn new(db: Arc<DatabaseConnection>, ctx: Arc<dyn RequestContextProvider>) -> Self {
+        Self { db, ctx }
+    }
+
+    fn map_db_err(e: DbErr) -> DomainError {
+        match e {
+            DbErr::RecordNotFound(detail) => CompensationDomainError::NotFound { detail }.into(),
+
+            DbErr::Query(err) => {
+                let msg = err.to_string().to_lowercase();
+
+                // ===== UNIQUE / conflict =====
+                if msg.contains("duplicate") || msg.contains("unique constraint") {
+                    return CompensationDomainError::Conflict {
+                        field: "booking_id",
+                        message: err.to_string(),
+                    }
+                    .into();
+                }
+
+                // ===== FK / CHECK =====
+                if msg.contains("foreign key") || msg.contains("check constraint") {
+                    return CompensationDomainError::BusinessRule {
+                        message: err.to_string(),
+                    }
+                    .into();
+                }
+
+                CompensationDomainError::Internal(err.to_string()).into()
+            }
+
+            // Connection / pool / runtime errors -> Internal
+            DbErr::Conn(err) => CompensationDomainError::Internal(err.to_string()).into(),
+            DbErr::Exec(err) => CompensationDomainError::Internal(err.to_string()).into(),
+            DbErr::Type(err) => CompensationDomainError::Internal(err.to_string()).into(),
+            DbErr::Json(err) => CompensationDomainError::Internal(err.to_string()).into(),
+            DbErr::Migration(err) => CompensationDomainError::Internal(err.to_string()).into(),
+
+            other => CompensationDomainError::Internal(other.to_string()).into(),
+        }
+    }
+}
+
+#[async_trait::async_trait]
+impl CompensationClaimRepositoryInterface for SeaOrmCompensationClaimRepository {
+    async fn create_claim(&self, claim: &CompensationClaim) -> Result<i64, DomainError> {
+        let mut active_model = CompensationClaimMapper::domain_to_active_model_create(claim);
+        active_model.apply_create_audit(&self.ctx.current());
+
+        Ok(active_model
+            .insert(self.db.as_ref())
+            .await
+            .map_err(Self::map_db_err)?
+            .id)
+    }
+
+    async fn update_claim(
+        &self,
+        claim: &CompensationClaim,
+        expected_version: i32,
+    ) -> Result<(), DomainError> {
+        let mut active_model = CompensationClaimMapper::domain_to_active_model_update(claim);
+        active_model.apply_update_audit(&self.ctx.current());
+
+        let result = claim_orm::Entity::update_many()
+            .filter(claim_orm::Column::Id.eq(claim.id))
+            .filter(claim_orm::Column::Version.eq(expected_version))
+            .set(active_model)
+            .col_expr(
+                claim_orm::Column::Version,
+                Expr::col(claim_orm::Column::Version).add(1),
+            )
+            .exec(self.db.as_ref())
+            .await
+            .map_err(Self::map_db_err)?;
+
+        if !optimistic_ok(result.rows_affected) {
+            return Err(CompensationDomainError::OptimisticLockConflict.into());
+        }
+        Ok(())
+    }
+
+    async fn find_claim_by_id(&self, id: i64) -> Result<Option<CompensationClaim>, DomainError> {
+        Ok(claim_orm::Entity::find_by_id(id)
+            .one(self.db.as_ref())
+            .await
+            .map_err(Self::map_db_err)?
+            .map(CompensationClaimMapper::model_to_domain))
+    }
+
+    async fn find_claim_by_booking(
+        &self,
+        booking_id: i64,
+    ) -> Result<Option<CompensationClaim>, DomainError> {
+        Ok(claim_orm::Entity::find()
+            .filter(claim_orm::Column::BookingId.eq(booking_id))
+            .one(self.db.as_ref())
+            .await
+            .map_err(Self::map_db_err)?
+            .map(CompensationClaimMapper::model_to_domain))
+    }
+
+    async fn list_claims(
+        &self,
+        status: Option<CompensationClaimStatus>,
+    ) -> Result<Vec<CompensationClaim>, DomainError> {
+        let mut query = claim_orm::Entity::find();
+        if let Some(status) = status {
+            query = query.filter(
+                claim_orm::Column::Status.eq(claim_orm::CompensationClaimStatus::from(status)),
+            );
+        }
+
+        Ok(query
+            .order_by_asc(claim_orm::Column::SubmittedAt)
+            .order_by_asc(claim_orm::Column::Id)
+            .all(self.db.as_ref())
+            .await
+            .map_err(Self::map_db_err)?
+            .into_iter()
+            .map(CompensationClaimMapper::model_to_domain)
+            .collect())
+    }
+}
@@ -0,0 +1,151 @@
+use sea_orm::sea_query::{Expr, OnConflict};
+use sea_orm::{
+    ColumnTrait, DatabaseConnection, DbErr, EntityTrait, ExprTrait, QueryFilter, QueryOrder,
+    QuerySelect,
+};
+use std::sync::Arc;
+
+use crate::core::context::request_context_provider::RequestContextProvider;
+use crate::domain::dead_letter::error::DeadLetterDomainError;
+use crate::domain::dead_letter::{
+    dead_letter_repository_interface::DeadLetterRepositoryInterface,
+    entity::{DeadLetter, DeadLetterStatus},
+};
+use crate::domain::error::DomainError;
+use crate::infrastructure::persistence::seaorm::optimistic_lock::optimistic_ok;
+use crate::infrastructure::persistence::seaorm::{
+    base_behavior::Auditable, entities::dead_letter as dead_letter_orm,
+    mappers::dead_letter_mapper::DeadLetterMapper,
+};
+
+pub struct SeaOrmDeadLetterRepository {
+    db: Arc<DatabaseConnection>,
+    ctx: Arc<dyn RequestContextProvider>,
+}
+
+impl SeaOrmDeadLetterRepository {
+    pub fn new(db: Arc<DatabaseConnection>, ctx: Arc<dyn RequestContextProvider>) -> Self {
+        Self { db, ctx }
+    }
+
+    fn map_db_err(e: DbErr) -> DomainError {
+        match e {
+            DbErr::RecordNotFound(detail) => DeadLetterDomainError::NotFound { detail }.into(),
+
+            DbErr::Query(err) => {
+                let msg = err.to_string().to_lowercase();
+
+                // ===== CHECK =====
+                if msg.contains("check constraint") {
+                    return DeadLetterDomainError::BusinessRule {
+                        message: err.to_string(),
+                    }
+                    .into();
+                }
+
+                DeadLetterDomainError::Internal(err.to_string()).into()
+            }
+
+            // Connection / pool / runtime errors -> Internal
+            DbErr::Conn(err) => DeadLetterDomainError::Internal(err.to_string()).into(),
+            DbErr::Exec(err) => DeadLetterDomainError::Internal(err.to_string()).into(),
+            DbErr::Type(err) => DeadLetterDomainError::Internal(err.to_string()).into(),
+            DbErr::Json(err) => DeadLetterDomainError::Internal(err.to_string()).into(),
+            DbErr::Migration(err) => DeadLetterDomainError::Internal(err.to_string()).into(),
+
+            other => DeadLetterDomainError::Internal(other.to_string()).into(),
+        }
+    }
+}
+
+#[async_trait::async_trait]
+impl DeadLetterRepositoryInterface for SeaOrmDeadLetterRepository {
+    async fn create_dead_letter(
+        &self,
+        dead_letter: &DeadLetter,
+    ) -> Result<Option<i64>, DomainError> {
+        let mut active_model = DeadLetterMapper::domain_to_active_model_create(dead_letter);
+        active_model.apply_create_audit(&self.ctx.current());
+
+        let result = dead_letter_orm::Entity::insert(active_model)
+            .on_conflict(
+                OnConflict::columns([
+                    dead_letter_orm::Column::DeadLetterTopic,
+                    dead_letter_orm::Column::DeadLetterPartition,
+                    dead_letter_orm::Column::DeadLetterOffset,
+                ])
+                .do_nothing()
+                .to_owned(),
+            )
+            .exec(self.db.as_ref())
+            .await;
+
+        match result {
+            Ok(inserted) => Ok(Some(inserted.last_insert_id)),
+            // read again after a restart: already archived
+            Err(DbErr::RecordNotInserted) => Ok(None),
+            Err(e) => Err(Self::map_db_err(e)),
+        }
+    }
+
+    async fn update_dead_letter(
+        &self,
+        dead_letter: &DeadLetter,
+        expected_version: i32,
+    ) -> Result<(), DomainError> {
+        let mut active_model = DeadLetterMapper::domain_to_active_model_update(dead_letter);
+        active_model.apply_update_audit(&self.ctx.current());
+
+        let result = dead_letter_orm::Entity::update_many()
+            .filter(dead_letter_orm::Column::Id.eq(dead_letter.id))
+            .filter(dead_letter_orm::Column::Version.eq(expected_version))
+            .set(active_model)
+            .col_expr(
+                dead_letter_orm::Column::Version,
+                Expr::col(dead_letter_orm::Column::Version).add(1),
+            )
+            .exec(self.db.as_ref())
+            .await
+            .map_err(Self::map_db_err)?;
+
+        if !optimistic_ok(result.rows_affected) {
+            return Err(DeadLetterDomainError::OptimisticLockConflict.into());
+        }
+        Ok(())
+    }
+
+    async fn find_dead_letter_by_id(&self, id: i64) -> Result<Option<DeadLetter>, DomainError> {
+        Ok(dead_letter_orm::Entity::find_by_id(id)
+            .one(self.db.as_ref())
+            .await
+            .map_err(Self::map_db_err)?
+            .map(DeadLetterMapper::model_to_domain))
+    }
+
+    async fn list_dead_letters(
+        &self,
+        status: Option<DeadLetterStatus>,
+        consumer_group: Option<String>,
+        limit: u64,
+    ) -> Result<Vec<DeadLetter>, DomainError> {
+        let mut query = dead_letter_orm::Entity::find();
+        if let Some(status) = status {
+            query = query.filter(
+                dead_letter_orm::Column::Status.eq(dead_letter_orm::DeadLetterStatus::from(status)),
+            );
+        }
+        if let Some(consumer_group) = consumer_group {
+            query = query.filter(dead_letter_orm::Column::ConsumerGroup.eq(consumer_group));
+        }
+
+        Ok(query
+            .order_by_desc(dead_letter_orm::Column::Id)
+            .limit(limit)
+            .all(self.db.as_ref())
+            .await
+            .map_err(Self::map_db_err)?
+            .into_iter()
+            .map(DeadLetterMapper::model_to_domain)
+            .collect())
+    }
+}
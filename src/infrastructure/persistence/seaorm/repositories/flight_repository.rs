@@ -2,17 +2,23 @@ use crate::core::context::request_context_provider::RequestContextProvider;
 use crate::domain::error::DomainError;
 use crate::domain::flight::error::FlightDomainError;
 use crate::domain::flight::{
-    entity::{Flight as DomainFlight, FlightStatus},
-    flight_repository_interface::FlightRepositoryInterface,
+    codeshare::FlightCodeshare, entity::Flight as DomainFlight,
+    flight_repository_interface::FlightRepositoryInterface, status_event::FlightStatusEvent,
 };
 use crate::infrastructure::persistence::seaorm::optimistic_lock::optimistic_ok;
 use crate::infrastructure::persistence::seaorm::{
-    base_behavior::Auditable, entities::flight as flight_orm, mappers::flight_mapper::FlightMapper,
+    base_behavior::Auditable,
+    entities::{
+        flight as flight_orm, flight_codeshare as codeshare_orm,
+        flight_status_event as flight_status_event_orm,
+    },
+    mappers::{flight_codeshare_mapper::FlightCodeshareMapper, flight_mapper::FlightMapper},
 };
-use chrono::NaiveDate;
+use chrono::{NaiveDate, NaiveDateTime};
 use sea_orm::sea_query::Expr;
 use sea_orm::{
-    ActiveModelTrait, ColumnTrait, DatabaseConnection, DbErr, EntityTrait, ExprTrait, QueryFilter,
+    ActiveModelTrait, ColumnTrait, ConnectionTrait, DatabaseConnection, DbErr, EntityTrait,
+    ExprTrait, QueryFilter, QueryOrder, TransactionTrait,
 };
 use std::sync::Arc;
 
@@ -63,6 +69,70 @@ impl SeaOrmFlightRepository {
             other => FlightDomainError::Internal(other.to_string()).into(),
         }
     }
+
+    /// Conditional decrement of the flight's seat inventory. Takes any connection so other
+    /// repositories can consume seats inside their own transaction.
+    pub(crate) async fn take_seats<C: ConnectionTrait>(
+        db: &C,
+        flight_id: i64,
+        seats: i32,
+    ) -> Result<(), DomainError> {
+        let result = flight_orm::Entity::update_many()
+            .col_expr(
+                flight_orm::Column::AvailableSeats,
+                Expr::col(flight_orm::Column::AvailableSeats).sub(seats),
+            )
+            .col_expr(
+                flight_orm::Column::Version,
+                Expr::col(flight_orm::Column::Version).add(1),
+            )
+            .filter(flight_orm::Column::Id.eq(flight_id))
+            .filter(flight_orm::Column::AvailableSeats.gte(seats))
+            .exec(db)
+            .await
+            .map_err(Self::map_db_err)?;
+
+        if !optimistic_ok(result.rows_affected) {
+            return Err(FlightDomainError::NoSeatsAvailable.into());
+        }
+
+        Ok(())
+    }
+
+    /// Counterpart of [`Self::take_seats`]; never lets available seats exceed total seats.
+    pub(crate) async fn release_seats<C: ConnectionTrait>(
+        db: &C,
+        flight_id: i64,
+        seats: i32,
+    ) -> Result<(), DomainError> {
+        let result = flight_orm::Entity::update_many()
+            .col_expr(
+                flight_orm::Column::AvailableSeats,
+                Expr::col(flight_orm::Column::AvailableSeats).add(seats),
+            )
+            .col_expr(
+                flight_orm::Column::Version,
+                Expr::col(flight_orm::Column::Version).add(1),
+            )
+            .filter(flight_orm::Column::Id.eq(flight_id))
+            .filter(
+                Expr::col(flight_orm::Column::AvailableSeats)
+                    .add(seats)
+                    .lte(Expr::col(flight_orm::Column::TotalSeats)),
+            )
+            .exec(db)
+            .await
+            .map_err(Self::map_db_err)?;
+
+        if !optimistic_ok(result.rows_affected) {
+            return Err(FlightDomainError::BusinessRule {
+                message: "Released seats would exceed total seats".to_string(),
+            }
+            .into());
+        }
+
+        Ok(())
+    }
 }
 
 #[async_trait::async_trait]
@@ -83,8 +153,10 @@ impl FlightRepositoryInterface for SeaOrmFlightRepository {
         &self,
         flight: &DomainFlight,
         expected_version: i32,
+        status_event: Option<&FlightStatusEvent>,
     ) -> Result<(), DomainError> {
         let ctx = self.ctx.current();
+        let txn = self.db.begin().await.map_err(Self::map_db_err)?;
 
         let mut active_model = FlightMapper::domain_to_active_model_update(flight);
         active_model.apply_update_audit(&ctx);
@@ -97,7 +169,7 @@ impl FlightRepositoryInterface for SeaOrmFlightRepository {
                 flight_orm::Column::Version,
                 Expr::col(flight_orm::Column::Version).add(1),
             )
-            .exec(self.db.as_ref())
+            .exec(&txn)
             .await
             .map_err(Self::map_db_err)?;
 
@@ -105,6 +177,13 @@ impl FlightRepositoryInterface for SeaOrmFlightRepository {
             return Err(FlightDomainError::OptimisticLockConflict.into());
         }
 
+        if let Some(event) = status_event {
+            let mut event_model = FlightMapper::status_event_to_active_model_create(event);
+            event_model.apply_create_audit(&ctx);
+            event_model.insert(&txn).await.map_err(Self::map_db_err)?;
+        }
+
+        txn.commit().await.map_err(Self::map_db_err)?;
         Ok(())
     }
 
@@ -120,12 +199,70 @@ impl FlightRepositoryInterface for SeaOrmFlightRepository {
         &self,
         key: &str,
     ) -> Result<Option<DomainFlight>, DomainError> {
-        Ok(flight_orm::Entity::find()
+        let operating = flight_orm::Entity::find()
             .filter(flight_orm::Column::FlightKey.eq(key))
             .one(self.db.as_ref())
             .await
+            .map_err(Self::map_db_err)?;
+        if let Some(model) = operating {
+            return Ok(Some(FlightMapper::model_to_domain(model)));
+        }
+
+        let codeshare = codeshare_orm::Entity::find()
+            .filter(codeshare_orm::Column::FlightKey.eq(key))
+            .one(self.db.as_ref())
+            .await
+            .map_err(Self::map_db_err)?;
+        match codeshare {
+            Some(codeshare) => self.find_flight_by_id(codeshare.flight_id).await,
+            None => Ok(None),
+        }
+    }
+
+    async fn create_codeshare(&self, codeshare: &FlightCodeshare) -> Result<i64, DomainError> {
+        let ctx = self.ctx.current();
+        let mut active_model = FlightCodeshareMapper::domain_to_active_model_create(codeshare);
+        active_model.apply_create_audit(&ctx);
+
+        Ok(active_model
+            .insert(self.db.as_ref())
+            .await
             .map_err(Self::map_db_err)?
-            .map(FlightMapper::model_to_domain))
+            .id)
+    }
+
+    async fn delete_codeshare(
+        &self,
+        flight_id: i64,
+        airline_code: &str,
+    ) -> Result<bool, DomainError> {
+        let result = codeshare_orm::Entity::delete_many()
+            .filter(codeshare_orm::Column::FlightId.eq(flight_id))
+            .filter(codeshare_orm::Column::AirlineCode.eq(airline_code))
+            .exec(self.db.as_ref())
+            .await
+            .map_err(Self::map_db_err)?;
+
+        Ok(result.rows_affected > 0)
+    }
+
+    async fn list_codeshares_by_flight_ids(
+        &self,
+        flight_ids: &[i64],
+    ) -> Result<Vec<FlightCodeshare>, DomainError> {
+        if flight_ids.is_empty() {
+            return Ok(Vec::new());
+        }
+
+        Ok(codeshare_orm::Entity::find()
+            .filter(codeshare_orm::Column::FlightId.is_in(flight_ids.iter().copied()))
+            .order_by_asc(codeshare_orm::Column::AirlineCode)
+            .all(self.db.as_ref())
+            .await
+            .map_err(Self::map_db_err)?
+            .into_iter()
+            .map(FlightCodeshareMapper::model_to_domain)
+            .collect())
     }
 
     async fn search_flights(
@@ -148,21 +285,49 @@ impl FlightRepositoryInterface for SeaOrmFlightRepository {
 
     async fn update_flight_status(
         &self,
-        flight_id: i64,
+        flight: &DomainFlight,
         expected_version: i32,
-        status: FlightStatus,
+        status_event: &FlightStatusEvent,
     ) -> Result<(), DomainError> {
-        let orm_status: flight_orm::FlightStatus = status.into();
+        let ctx = self.ctx.current();
+        let txn = self.db.begin().await.map_err(Self::map_db_err)?;
+
+        let orm_status: flight_orm::FlightStatus = flight.status.clone().into();
+        let times = &flight.operational_times;
 
         let result = flight_orm::Entity::update_many()
             .col_expr(flight_orm::Column::Status, Expr::value(orm_status))
+            .col_expr(
+                flight_orm::Column::EstimatedDeparture,
+                Expr::value(times.estimated_departure),
+            )
+            .col_expr(
+                flight_orm::Column::EstimatedArrival,
+                Expr::value(times.estimated_arrival),
+            )
+            .col_expr(
+                flight_orm::Column::ActualOffBlock,
+                Expr::value(times.actual_off_block),
+            )
+            .col_expr(
+                flight_orm::Column::ActualTakeoff,
+                Expr::value(times.actual_takeoff),
+            )
+            .col_expr(
+                flight_orm::Column::ActualLanding,
+                Expr::value(times.actual_landing),
+            )
+            .col_expr(
+                flight_orm::Column::ActualOnBlock,
+                Expr::value(times.actual_on_block),
+            )
             .col_expr(
                 flight_orm::Column::Version,
                 Expr::col(flight_orm::Column::Version).add(1),
             )
-            .filter(flight_orm::Column::Id.eq(flight_id))
+            .filter(flight_orm::Column::Id.eq(flight.id))
             .filter(flight_orm::Column::Version.eq(expected_version))
-            .exec(self.db.as_ref())
+            .exec(&txn)
             .await
             .map_err(Self::map_db_err)?;
 
@@ -170,27 +335,153 @@ impl FlightRepositoryInterface for SeaOrmFlightRepository {
             return Err(FlightDomainError::OptimisticLockConflict.into());
         }
 
+        let mut event_model = FlightMapper::status_event_to_active_model_create(status_event);
+        event_model.apply_create_audit(&ctx);
+        event_model.insert(&txn).await.map_err(Self::map_db_err)?;
+
+        txn.commit().await.map_err(Self::map_db_err)?;
         Ok(())
     }
 
+    async fn list_status_events(
+        &self,
+        flight_id: i64,
+    ) -> Result<Vec<FlightStatusEvent>, DomainError> {
+        Ok(flight_status_event_orm::Entity::find()
+            .filter(flight_status_event_orm::Column::FlightId.eq(flight_id))
+            .order_by_asc(flight_status_event_orm::Column::RecordedAt)
+            .order_by_asc(flight_status_event_orm::Column::Id)
+            .all(self.db.as_ref())
+            .await
+            .map_err(Self::map_db_err)?
+            .into_iter()
+            .map(FlightMapper::status_event_model_to_domain)
+            .collect())
+    }
+
     async fn decrease_available_seats(
         &self,
         flight_id: i64,
         seats: i32,
     ) -> Result<(), DomainError> {
+        Self::take_seats(self.db.as_ref(), flight_id, seats).await
+    }
+
+    async fn increase_available_seats(
+        &self,
+        flight_id: i64,
+        seats: i32,
+    ) -> Result<(), DomainError> {
+        Self::release_seats(self.db.as_ref(), flight_id, seats).await
+    }
+
+    async fn next_boarding_sequence(&self, flight_id: i64) -> Result<i32, DomainError> {
+        // A single UPDATE ... RETURNING keeps concurrent issues from sharing a number.
+        let updated = flight_orm::Entity::update_many()
+            .col_expr(
+                flight_orm::Column::BoardingSequence,
+                Expr::col(flight_orm::Column::BoardingSequence).add(1),
+            )
+            .filter(flight_orm::Column::Id.eq(flight_id))
+            .exec_with_returning(self.db.as_ref())
+            .await
+            .map_err(Self::map_db_err)?;
+
+        updated
+            .first()
+            .map(|model| model.boarding_sequence)
+            .ok_or_else(|| FlightDomainError::FlightNotFound { flight_id }.into())
+    }
+
+    async fn list_flights_by_tail_number_between(
+        &self,
+        tail_number: &str,
+        departure: NaiveDateTime,
+        arrival: NaiveDateTime,
+    ) -> Result<Vec<DomainFlight>, DomainError> {
+        Ok(flight_orm::Entity::find()
+            .filter(flight_orm::Column::TailNumber.eq(tail_number))
+            .filter(flight_orm::Column::Status.ne(flight_orm::FlightStatus::Cancelled))
+            .filter(flight_orm::Column::DepartureTime.lt(arrival))
+            .filter(flight_orm::Column::ArrivalTime.gt(departure))
+            .order_by_asc(flight_orm::Column::DepartureTime)
+            .all(self.db.as_ref())
+            .await
+            .map_err(Self::map_db_err)?
+            .into_iter()
+            .map(FlightMapper::model_to_domain)
+            .collect())
+    }
+
+    async fn find_previous_flight_by_tail_number(
+        &self,
+        tail_number: &str,
+        before: NaiveDateTime,
+        exclude_flight_id: i64,
+    ) -> Result<Option<DomainFlight>, DomainError> {
+        Ok(flight_orm::Entity::find()
+            .filter(flight_orm::Column::TailNumber.eq(tail_number))
+            .filter(flight_orm::Column::Status.ne(flight_orm::FlightStatus::Cancelled))
+            .filter(flight_orm::Column::ArrivalTime.lte(before))
+            .filter(flight_orm::Column::Id.ne(exclude_flight_id))
+            .order_by_desc(flight_orm::Column::ArrivalTime)
+            .one(self.db.as_ref())
+            .await
+            .map_err(Self::map_db_err)?
+            .map(FlightMapper::model_to_domain))
+    }
+
+    async fn find_next_flight_by_tail_number(
+        &self,
+        tail_number: &str,
+        after: NaiveDateTime,
+        exclude_flight_id: i64,
+    ) -> Result<Option<DomainFlight>, DomainError> {
+        Ok(flight_orm::Entity::find()
+            .filter(flight_orm::Column::TailNumber.eq(tail_number))
+            .filter(flight_orm::Column::Status.ne(flight_orm::FlightStatus::Cancelled))
+            .filter(flight_orm::Column::DepartureTime.gte(after))
+            .filter(flight_orm::Column::Id.ne(exclude_flight_id))
+            .order_by_asc(flight_orm::Column::DepartureTime)
+            .one(self.db.as_ref())
+            .await
+            .map_err(Self::map_db_err)?
+            .map(FlightMapper::model_to_domain))
+    }
+
+    async fn list_flights_pending_no_shows(&self) -> Result<Vec<DomainFlight>, DomainError> {
+        Ok(flight_orm::Entity::find()
+            .filter(flight_orm::Column::Status.is_in([
+                flight_orm::FlightStatus::Departed,
+                flight_orm::FlightStatus::Arrived,
+            ]))
+            .filter(flight_orm::Column::NoShowsProcessedAt.is_null())
+            .all(self.db.as_ref())
+            .await
+            .map_err(Self::map_db_err)?
+            .into_iter()
+            .map(FlightMapper::model_to_domain)
+            .collect())
+    }
+
+    async fn mark_no_shows_processed(
+        &self,
+        flight_id: i64,
+        processed_at: NaiveDateTime,
+    ) -> Result<(), DomainError> {
+        // Bookkeeping only, so the flight version is left alone.
         let result = flight_orm::Entity::update_many()
             .col_expr(
-                flight_orm::Column::AvailableSeats,
-                Expr::col(flight_orm::Column::AvailableSeats).sub(seats),
+                flight_orm::Column::NoShowsProcessedAt,
+                Expr::value(processed_at),
             )
             .filter(flight_orm::Column::Id.eq(flight_id))
-            .filter(flight_orm::Column::AvailableSeats.gte(seats))
             .exec(self.db.as_ref())
             .await
             .map_err(Self::map_db_err)?;
 
-        if !optimistic_ok(result.rows_affected) {
-            return Err(FlightDomainError::OptimisticLockConflict.into());
+        if result.rows_affected == 0 {
+            return Err(FlightDomainError::FlightNotFound { flight_id }.into());
         }
 
         Ok(())
@@ -5,4 +5,13 @@ pub mod boarding_pass_repository;
 pub mod booking_repository;
 pub mod checkin_repository;
 pub mod flight_repository;
-pub mod passenger_repository;
\ No newline at end of file
+pub mod no_show_repository;
+pub mod passenger_repository;
+pub mod bag_repository;
+pub mod airline_repository;
+pub mod aircraft_repository;
+pub mod route_repository;
+pub mod compensation_claim_repository;
+pub mod notification_repository;
+pub mod notification_preference_repository;
+pub mod dead_letter_repository;
\ No newline at end of file
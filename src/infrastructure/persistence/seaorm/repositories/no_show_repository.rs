@@ -0,0 +1,88 @@
+use sea_orm::{ActiveModelTrait, ColumnTrait, DatabaseConnection, DbErr, EntityTrait, QueryFilter};
+use std::sync::Arc;
+
+use crate::core::context::request_context_provider::RequestContextProvider;
+use crate::domain::booking::error::BookingDomainError;
+use crate::domain::booking::{
+    no_show::NoShow, no_show_repository_interface::NoShowRepositoryInterface,
+};
+use crate::domain::error::DomainError;
+use crate::infrastructure::persistence::seaorm::{
+    base_behavior::Auditable, entities::no_show as no_show_orm,
+    mappers::no_show_mapper::NoShowMapper,
+};
+
+pub struct SeaOrmNoShowRepository {
+    db: Arc<DatabaseConnection>,
+    ctx: Arc<dyn RequestContextProvider>,
+}
+
+impl SeaOrmNoShowRepository {
+    pub fn new(db: Arc<DatabaseConnection>, ctx: Arc<dyn RequestContextProvider>) -> Self {
+        Self { db, ctx }
+    }
+
+    fn map_db_err(e: DbErr) -> DomainError {
+        match e {
+            DbErr::RecordNotFound(detail) => BookingDomainError::NotFound { detail }.into(),
+
+            DbErr::Query(err) => {
+                let msg = err.to_string().to_lowercase();
+
+                // ===== UNIQUE / conflict =====
+                if msg.contains("duplicate") || msg.contains("unique constraint") {
+                    return BookingDomainError::Conflict {
+                        field: "passenger_id",
+                        message: err.to_string(),
+                    }
+                    .into();
+                }
+
+                // ===== FK =====
+                if msg.contains("foreign key") {
+                    return BookingDomainError::BusinessRule {
+                        message: err.to_string(),
+                    }
+                    .into();
+                }
+
+                BookingDomainError::Internal(err.to_string()).into()
+            }
+
+            // Connection / pool / runtime errors -> Internal
+            DbErr::Conn(err) => BookingDomainError::Internal(err.to_string()).into(),
+            DbErr::Exec(err) => BookingDomainError::Internal(err.to_string()).into(),
+            DbErr::Type(err) => BookingDomainError::Internal(err.to_string()).into(),
+            DbErr::Json(err) => BookingDomainError::Internal(err.to_string()).into(),
+            DbErr::Migration(err) => BookingDomainError::Internal(err.to_string()).into(),
+
+            other => BookingDomainError::Internal(other.to_string()).into(),
+        }
+    }
+}
+
+#[async_trait::async_trait]
+impl NoShowRepositoryInterface for SeaOrmNoShowRepository {
+    async fn create_no_show(&self, no_show: &NoShow) -> Result<i64, DomainError> {
+        let ctx = self.ctx.current();
+        let mut active_model = NoShowMapper::domain_to_active_model_create(no_show);
+        active_model.apply_create_audit(&ctx);
+
+        Ok(active_model
+            .insert(self.db.as_ref())
+            .await
+            .map_err(Self::map_db_err)?
+            .id)
+    }
+
+    async fn list_no_shows_by_flight(&self, flight_id: i64) -> Result<Vec<NoShow>, DomainError> {
+        Ok(no_show_orm::Entity::find()
+            .filter(no_show_orm::Column::FlightId.eq(flight_id))
+            .all(self.db.as_ref())
+            .await
+            .map_err(Self::map_db_err)?
+            .into_iter()
+            .map(NoShowMapper::model_to_domain)
+            .collect())
+    }
+}
@@ -0,0 +1,123 @@
+use sea_orm::sea_query::Expr;
+use sea_orm::{
+    ActiveModelTrait, ColumnTrait, DatabaseConnection, DbErr, EntityTrait, ExprTrait, QueryFilter,
+};
+use std::sync::Arc;
+
+use crate::core::context::request_context_provider::RequestContextProvider;
+use crate::domain::error::DomainError;
+use crate::domain::notification::error::NotificationDomainError;
+use crate::domain::notification::{
+    notification_preference_repository_interface::NotificationPreferenceRepositoryInterface,
+    preference::NotificationPreference,
+};
+use crate::infrastructure::persistence::seaorm::optimistic_lock::optimistic_ok;
+use crate::infrastructure::persistence::seaorm::{
+    base_behavior::Auditable, entities::notification_preference as preference_orm,
+    mappers::notification_mapper::NotificationPreferenceMapper,
+};
+
+pub struct SeaOrmNotificationPreferenceRepository {
+    db: Arc<DatabaseConnection>,
+    ctx: Arc<dyn RequestContextProvider>,
+}
+
+impl SeaOrmNotificationPreferenceRepository {
+    pub fn new(db: Arc<DatabaseConnection>, ctx: Arc<dyn RequestContextProvider>) -> Self {
+        Self { db, ctx }
+    }
+
+    fn map_db_err(e: DbErr) -> DomainError {
+        match e {
+            DbErr::RecordNotFound(detail) => NotificationDomainError::NotFound { detail }.into(),
+
+            DbErr::Query(err) => {
+                let msg = err.to_string().to_lowercase();
+
+                // ===== UNIQUE / conflict =====
+                if msg.contains("duplicate") || msg.contains("unique constraint") {
+                    return NotificationDomainError::Conflict {
+                        field: "user_id",
+                        message: err.to_string(),
+                    }
+                    .into();
+                }
+
+                // ===== FK / CHECK =====
+                if msg.contains("foreign key") || msg.contains("check constraint") {
+                    return NotificationDomainError::BusinessRule {
+                        message: err.to_string(),
+                    }
+                    .into();
+                }
+
+                NotificationDomainError::Internal(err.to_string()).into()
+            }
+
+            // Connection / pool / runtime errors -> Internal
+            DbErr::Conn(err) => NotificationDomainError::Internal(err.to_string()).into(),
+            DbErr::Exec(err) => NotificationDomainError::Internal(err.to_string()).into(),
+            DbErr::Type(err) => NotificationDomainError::Internal(err.to_string()).into(),
+            DbErr::Json(err) => NotificationDomainError::Internal(err.to_string()).into(),
+            DbErr::Migration(err) => NotificationDomainError::Internal(err.to_string()).into(),
+
+            other => NotificationDomainError::Internal(other.to_string()).into(),
+        }
+    }
+}
+
+#[async_trait::async_trait]
+impl NotificationPreferenceRepositoryInterface for SeaOrmNotificationPreferenceRepository {
+    async fn create_preference(
+        &self,
+        preference: &NotificationPreference,
+    ) -> Result<(), DomainError> {
+        let mut active_model =
+            NotificationPreferenceMapper::domain_to_active_model_create(preference);
+        active_model.apply_create_audit(&self.ctx.current());
+
+        active_model
+            .insert(self.db.as_ref())
+            .await
+            .map_err(Self::map_db_err)?;
+        Ok(())
+    }
+
+    async fn update_preference(
+        &self,
+        preference: &NotificationPreference,
+        expected_version: i32,
+    ) -> Result<(), DomainError> {
+        let mut active_model =
+            NotificationPreferenceMapper::domain_to_active_model_update(preference);
+        active_model.apply_update_audit(&self.ctx.current());
+
+        let result = preference_orm::Entity::update_many()
+            .filter(preference_orm::Column::UserId.eq(preference.user_id))
+            .filter(preference_orm::Column::Version.eq(expected_version))
+            .set(active_model)
+            .col_expr(
+                preference_orm::Column::Version,
+                Expr::col(preference_orm::Column::Version).add(1),
+            )
+            .exec(self.db.as_ref())
+            .await
+            .map_err(Self::map_db_err)?;
+
+        if !optimistic_ok(result.rows_affected) {
+            return Err(NotificationDomainError::OptimisticLockConflict.into());
+        }
+        Ok(())
+    }
+
+    async fn find_preference(
+        &self,
+        user_id: i64,
+    ) -> Result<Option<NotificationPreference>, DomainError> {
+        Ok(preference_orm::Entity::find_by_id(user_id)
+            .one(self.db.as_ref())
+            .await
+            .map_err(Self::map_db_err)?
+            .map(NotificationPreferenceMapper::model_to_domain))
+    }
+}
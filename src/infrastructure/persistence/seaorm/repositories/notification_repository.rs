@@ -0,0 +1,216 @@
+use chrono::NaiveDateTime;
+use sea_orm::sea_query::{Expr, LockBehavior, LockType, OnConflict};
+use sea_orm::{
+    ActiveModelTrait, ColumnTrait, DatabaseConnection, DbErr, EntityTrait, ExprTrait, QueryFilter,
+    QueryOrder, QuerySelect, QueryTrait,
+};
+use std::sync::Arc;
+
+use crate::core::context::request_context_provider::RequestContextProvider;
+use crate::domain::error::DomainError;
+use crate::domain::notification::error::NotificationDomainError;
+use crate::domain::notification::{
+    entity::{Notification, NotificationKind, NotificationStatus},
+    notification_repository_interface::NotificationRepositoryInterface,
+};
+use crate::infrastructure::persistence::seaorm::optimistic_lock::optimistic_ok;
+use crate::infrastructure::persistence::seaorm::{
+    base_behavior::Auditable, entities::notification as notification_orm,
+    mappers::notification_mapper::NotificationMapper,
+};
+
+pub struct SeaOrmNotificationRepository {
+    db: Arc<DatabaseConnection>,
+    ctx: Arc<dyn RequestContextProvider>,
+}
+
+impl SeaOrmNotificationRepository {
+    pub fn new(db: Arc<DatabaseConnection>, ctx: Arc<dyn RequestContextProvider>) -> Self {
+        Self { db, ctx }
+    }
+
+    fn map_db_err(e: DbErr) -> DomainError {
+        match e {
+            DbErr::RecordNotFound(detail) => NotificationDomainError::NotFound { detail }.into(),
+
+            DbErr::Query(err) => {
+                let msg = err.to_string().to_lowercase();
+
+                // ===== FK / CHECK =====
+                if msg.contains("foreign key") || msg.contains("check constraint") {
+                    return NotificationDomainError::BusinessRule {
+                        message: err.to_string(),
+                    }
+                    .into();
+                }
+
+                NotificationDomainError::Internal(err.to_string()).into()
+            }
+
+            // Connection / pool / runtime errors -> Internal
+            DbErr::Conn(err) => NotificationDomainError::Internal(err.to_string()).into(),
+            DbErr::Exec(err) => NotificationDomainError::Internal(err.to_string()).into(),
+            DbErr::Type(err) => NotificationDomainError::Internal(err.to_string()).into(),
+            DbErr::Json(err) => NotificationDomainError::Internal(err.to_string()).into(),
+            DbErr::Migration(err) => NotificationDomainError::Internal(err.to_string()).into(),
+
+            other => NotificationDomainError::Internal(other.to_string()).into(),
+        }
+    }
+}
+
+#[async_trait::async_trait]
+impl NotificationRepositoryInterface for SeaOrmNotificationRepository {
+    async fn create_notification(
+        &self,
+        notification: &Notification,
+    ) -> Result<Option<i64>, DomainError> {
+        let mut active_model = NotificationMapper::domain_to_active_model_create(notification);
+        active_model.apply_create_audit(&self.ctx.current());
+
+        let result = notification_orm::Entity::insert(active_model)
+            .on_conflict(
+                OnConflict::column(notification_orm::Column::DedupKey)
+                    .do_nothing()
+                    .to_owned(),
+            )
+            .exec(self.db.as_ref())
+            .await;
+
+        match result {
+            Ok(inserted) => Ok(Some(inserted.last_insert_id)),
+            // the dedup key is taken: this occasion was already notified
+            Err(DbErr::RecordNotInserted) => Ok(None),
+            Err(e) => Err(Self::map_db_err(e)),
+        }
+    }
+
+    async fn update_notification(
+        &self,
+        notification: &Notification,
+        expected_version: i32,
+    ) -> Result<(), DomainError> {
+        let mut active_model = NotificationMapper::domain_to_active_model_update(notification);
+        active_model.apply_update_audit(&self.ctx.current());
+
+        let result = notification_orm::Entity::update_many()
+            .filter(notification_orm::Column::Id.eq(notification.id))
+            .filter(notification_orm::Column::Version.eq(expected_version))
+            .set(active_model)
+            .col_expr(
+                notification_orm::Column::Version,
+                Expr::col(notification_orm::Column::Version).add(1),
+            )
+            .exec(self.db.as_ref())
+            .await
+            .map_err(Self::map_db_err)?;
+
+        if !optimistic_ok(result.rows_affected) {
+            return Err(NotificationDomainError::OptimisticLockConflict.into());
+        }
+        Ok(())
+    }
+
+    async fn lease_due_notifications(
+        &self,
+        now: NaiveDateTime,
+        lease_until: NaiveDateTime,
+        limit: u64,
+    ) -> Result<Vec<Notification>, DomainError> {
+        // SKIP LOCKED lets several instances lease disjoint batches at the same time.
+        let due = notification_orm::Entity::find()
+            .select_only()
+            .column(notification_orm::Column::Id)
+            .filter(
+                notification_orm::Column::Status.eq(notification_orm::NotificationStatus::Pending),
+            )
+            .filter(notification_orm::Column::ScheduledAt.lte(now))
+            .order_by_asc(notification_orm::Column::ScheduledAt)
+            .order_by_asc(notification_orm::Column::Id)
+            .limit(limit)
+            .lock_with_behavior(LockType::Update, LockBehavior::SkipLocked)
+            .into_query();
+
+        let mut leased = notification_orm::Entity::update_many()
+            .col_expr(
+                notification_orm::Column::ScheduledAt,
+                Expr::value(lease_until),
+            )
+            .col_expr(
+                notification_orm::Column::Version,
+                Expr::col(notification_orm::Column::Version).add(1),
+            )
+            .filter(notification_orm::Column::Id.in_subquery(due))
+            .exec_with_returning(self.db.as_ref())
+            .await
+            .map_err(Self::map_db_err)?;
+        leased.sort_by_key(|model| model.id);
+
+        Ok(leased
+            .into_iter()
+            .map(NotificationMapper::model_to_domain)
+            .collect())
+    }
+
+    async fn cancel_pending_for_booking(
+        &self,
+        booking_id: i64,
+        kind: Option<NotificationKind>,
+    ) -> Result<u64, DomainError> {
+        let mut active_model = notification_orm::ActiveModel {
+            status: sea_orm::ActiveValue::Set(notification_orm::NotificationStatus::Cancelled),
+            ..Default::default()
+        };
+        active_model.apply_update_audit(&self.ctx.current());
+
+        let mut query = notification_orm::Entity::update_many()
+            .filter(notification_orm::Column::BookingId.eq(booking_id))
+            .filter(
+                notification_orm::Column::Status.eq(notification_orm::NotificationStatus::Pending),
+            );
+        if let Some(kind) = kind {
+            query = query.filter(
+                notification_orm::Column::Kind.eq(notification_orm::NotificationKind::from(kind)),
+            );
+        }
+
+        Ok(query
+            .set(active_model)
+            .col_expr(
+                notification_orm::Column::Version,
+                Expr::col(notification_orm::Column::Version).add(1),
+            )
+            .exec(self.db.as_ref())
+            .await
+            .map_err(Self::map_db_err)?
+            .rows_affected)
+    }
+
+    async fn list_notifications(
+        &self,
+        user_id: Option<i64>,
+        status: Option<NotificationStatus>,
+        limit: u64,
+    ) -> Result<Vec<Notification>, DomainError> {
+        let mut query = notification_orm::Entity::find();
+        if let Some(user_id) = user_id {
+            query = query.filter(notification_orm::Column::UserId.eq(user_id));
+        }
+        if let Some(status) = status {
+            query = query.filter(
+                notification_orm::Column::Status
+                    .eq(notification_orm::NotificationStatus::from(status)),
+            );
+        }
+
+        Ok(query
+            .order_by_desc(notification_orm::Column::Id)
+            .limit(limit)
+            .all(self.db.as_ref())
+            .await
+            .map_err(Self::map_db_err)?
+            .into_iter()
+            .map(NotificationMapper::model_to_domain)
+            .collect())
+    }
+}
@@ -7,15 +7,25 @@ use crate::domain::passenger::{
 };
 use crate::infrastructure::persistence::seaorm::base_behavior::Auditable;
 use crate::infrastructure::persistence::seaorm::optimistic_lock::optimistic_ok;
+use crate::infrastructure::persistence::seaorm::repositories::flight_repository::SeaOrmFlightRepository;
 use crate::infrastructure::persistence::seaorm::{
     entities::passenger as passenger_orm, mappers::passenger_mapper::PassengerMapper,
 };
 use sea_orm::sea_query::Expr;
 use sea_orm::{
     ActiveModelTrait, ColumnTrait, DatabaseConnection, DbErr, EntityTrait, ExprTrait, QueryFilter,
+    TransactionTrait,
 };
 use std::sync::Arc;
 
+fn map_conflict_field(msg: &str) -> Option<&'static str> {
+    if msg.contains("uq_passengers_accompanying_adult_id") {
+        Some("accompanying_adult_id")
+    } else {
+        None
+    }
+}
+
 pub struct SeaOrmPassengerRepository {
     db: Arc<DatabaseConnection>,
     ctx: Arc<dyn RequestContextProvider>,
@@ -33,6 +43,20 @@ impl SeaOrmPassengerRepository {
             DbErr::Query(err) => {
                 let msg = err.to_string().to_lowercase();
 
+                // ===== UNIQUE / conflict =====
+                if msg.contains("duplicate") || msg.contains("unique constraint") {
+                    return match map_conflict_field(&msg) {
+                        Some(field) => PassengerDomainError::Conflict {
+                            field,
+                            message: err.to_string(),
+                        },
+                        None => PassengerDomainError::Duplicate {
+                            message: err.to_string(),
+                        },
+                    }
+                    .into();
+                }
+
                 // ===== FK =====
                 if msg.contains("foreign key") {
                     return PassengerDomainError::BusinessRule {
@@ -69,6 +93,30 @@ impl PassengerRepositoryInterface for SeaOrmPassengerRepository {
             .id)
     }
 
+    async fn add_passenger(
+        &self,
+        passenger: &DomainPassenger,
+        seat_flight_id: Option<i64>,
+    ) -> Result<i64, DomainError> {
+        let ctx = self.ctx.current();
+        let txn = self.db.begin().await.map_err(Self::map_db_err)?;
+
+        if let Some(flight_id) = seat_flight_id {
+            SeaOrmFlightRepository::take_seats(&txn, flight_id, 1).await?;
+        }
+
+        let mut active_model = PassengerMapper::domain_to_active_model_create(passenger);
+        active_model.apply_create_audit(&ctx);
+        let id = active_model
+            .insert(&txn)
+            .await
+            .map_err(Self::map_db_err)?
+            .id;
+
+        txn.commit().await.map_err(Self::map_db_err)?;
+        Ok(id)
+    }
+
     async fn create_passengers(
         &self,
         passengers: &[DomainPassenger],
@@ -159,6 +207,26 @@ impl PassengerRepositoryInterface for SeaOrmPassengerRepository {
         Ok(())
     }
 
+    async fn remove_passenger(
+        &self,
+        id: i64,
+        seat_flight_id: Option<i64>,
+    ) -> Result<(), DomainError> {
+        let txn = self.db.begin().await.map_err(Self::map_db_err)?;
+
+        passenger_orm::Entity::delete_by_id(id)
+            .exec(&txn)
+            .await
+            .map_err(Self::map_db_err)?;
+
+        if let Some(flight_id) = seat_flight_id {
+            SeaOrmFlightRepository::release_seats(&txn, flight_id, 1).await?;
+        }
+
+        txn.commit().await.map_err(Self::map_db_err)?;
+        Ok(())
+    }
+
     async fn delete_passengers_by_booking(&self, booking_id: i64) -> Result<(), DomainError> {
         passenger_orm::Entity::delete_many()
             .filter(passenger_orm::Column::BookingId.eq(booking_id))
@@ -0,0 +1,135 @@
+use crate::domain::error::DomainError;
+use crate::domain::route::entity::Route as DomainRoute;
+use crate::domain::route::error::RouteDomainError;
+use crate::domain::route::route_repository_interface::RouteRepositoryInterface;
+use crate::infrastructure::persistence::seaorm::entities::route::{Column, Entity};
+use crate::infrastructure::persistence::seaorm::mappers::route_mapper::RouteMapper;
+use async_trait::async_trait;
+use sea_orm::{
+    ActiveModelTrait, ColumnTrait, DatabaseConnection, EntityTrait, QueryFilter, QueryOrder,
+};
+use std::sync::Arc;
+
+pub struct SeaOrmRouteRepository {
+    pub db: Arc<DatabaseConnection>,
+}
+
+impl SeaOrmRouteRepository {
+    pub fn new(db: Arc<DatabaseConnection>) -> Self {
+        Self { db }
+    }
+
+    fn map_db_err(e: sea_orm::DbErr) -> DomainError {
+        let route_err = match e {
+            sea_orm::DbErr::RecordNotFound(detail) => RouteDomainError::NotFound { detail },
+
+            sea_orm::DbErr::Query(err) => {
+                let msg = err.to_string().to_lowercase();
+
+                if msg.contains("duplicate key")
+                    || msg.contains("unique constraint")
+                    || msg.contains("duplicate entry")
+                {
+                    return RouteDomainError::Conflict {
+                        field: "destination_airport_id",
+                        message: err.to_string(),
+                    }
+                    .into();
+                }
+
+                if msg.contains("foreign key") {
+                    return RouteDomainError::BusinessRule {
+                        message: err.to_string(),
+                    }
+                    .into();
+                }
+
+                RouteDomainError::Internal(err.to_string())
+            }
+
+            sea_orm::DbErr::Conn(err) => RouteDomainError::Internal(err.to_string()),
+            sea_orm::DbErr::Exec(err) => RouteDomainError::Internal(err.to_string()),
+            sea_orm::DbErr::Type(err) => RouteDomainError::Internal(err.to_string()),
+            sea_orm::DbErr::Json(err) => RouteDomainError::Internal(err.to_string()),
+            sea_orm::DbErr::Migration(err) => RouteDomainError::Internal(err.to_string()),
+
+            other => RouteDomainError::Internal(other.to_string()),
+        };
+
+        route_err.into()
+    }
+}
+
+#[async_trait]
+impl RouteRepositoryInterface for SeaOrmRouteRepository {
+    async fn create_route(&self, route: &DomainRoute) -> Result<i64, DomainError> {
+        let active_model = RouteMapper::domain_to_active_model_for_create(route);
+        let res = active_model
+            .insert(self.db.as_ref())
+            .await
+            .map_err(Self::map_db_err)?;
+        Ok(res.id)
+    }
+
+    async fn update_route(&self, route: &DomainRoute) -> Result<(), DomainError> {
+        let active_model = RouteMapper::domain_to_active_model_for_update(route);
+        active_model
+            .update(self.db.as_ref())
+            .await
+            .map_err(Self::map_db_err)?;
+        Ok(())
+    }
+
+    async fn find_route_by_id(&self, id: i64) -> Result<Option<DomainRoute>, DomainError> {
+        let model = Entity::find_by_id(id)
+            .one(self.db.as_ref())
+            .await
+            .map_err(Self::map_db_err)?;
+
+        Ok(model.map(RouteMapper::model_to_domain))
+    }
+
+    async fn find_route_by_airports(
+        &self,
+        origin_airport_id: i64,
+        destination_airport_id: i64,
+    ) -> Result<Option<DomainRoute>, DomainError> {
+        Ok(Entity::find()
+            .filter(Column::OriginAirportId.eq(origin_airport_id))
+            .filter(Column::DestinationAirportId.eq(destination_airport_id))
+            .one(self.db.as_ref())
+            .await
+            .map_err(Self::map_db_err)?
+            .map(RouteMapper::model_to_domain))
+    }
+
+    async fn list_routes(
+        &self,
+        origin_airport_id: Option<i64>,
+        destination_airport_id: Option<i64>,
+        active_only: bool,
+    ) -> Result<Vec<DomainRoute>, DomainError> {
+        let mut query = Entity::find();
+        if let Some(origin_airport_id) = origin_airport_id {
+            query = query.filter(Column::OriginAirportId.eq(origin_airport_id));
+        }
+        if let Some(destination_airport_id) = destination_airport_id {
+            query = query.filter(Column::DestinationAirportId.eq(destination_airport_id));
+        }
+        if active_only {
+            query = query.filter(Column::IsActive.eq(true));
+        }
+
+        let models = query
+            .order_by_asc(Column::OriginAirportId)
+            .order_by_asc(Column::DestinationAirportId)
+            .all(self.db.as_ref())
+            .await
+            .map_err(Self::map_db_err)?;
+
+        Ok(models
+            .into_iter()
+            .map(RouteMapper::model_to_domain)
+            .collect())
+    }
+}
@@ -1,4 +1,5 @@
 pub mod config;
 pub mod http;
 pub mod gateway_registry;
-pub mod request_context;
\ No newline at end of file
+pub mod request_context;
+pub mod shutdown;
\ No newline at end of file
@@ -0,0 +1,89 @@
+use std::sync::{LazyLock, Mutex};
+use std::time::Duration;
+
+use tokio::sync::watch;
+use tokio::task::JoinHandle;
+
+/// Process-wide shutdown: background workers watch it and are awaited after the HTTP
+/// server has stopped, so they can finish the message or batch in hand.
+struct Shutdown {
+    signal: watch::Sender<bool>,
+    tasks: Mutex<Vec<JoinHandle<()>>>,
+}
+
+static SHUTDOWN: LazyLock<Shutdown> = LazyLock::new(|| Shutdown {
+    signal: watch::channel(false).0,
+    tasks: Mutex::new(Vec::new()),
+});
+
+/// Resolves once shutdown has been requested.
+pub async fn requested() {
+    let mut rx = SHUTDOWN.signal.subscribe();
+    // the sender lives in a static, so the channel never closes
+    let _ = rx.wait_for(|stop| *stop).await;
+}
+
+pub fn is_requested() -> bool {
+    *SHUTDOWN.signal.borrow()
+}
+
+pub fn request() {
+    SHUTDOWN.signal.send_replace(true);
+}
+
+/// Waits for Ctrl-C or SIGTERM, then requests shutdown.
+pub async fn wait_for_signal() {
+    let ctrl_c = async {
+        let _ = tokio::signal::ctrl_c().await;
+    };
+    #[cfg(unix)]
+    let terminate = async {
+        match tokio::signal::unix::signal(tokio::signal::unix::SignalKind::terminate()) {
+            Ok(mut sigterm) => {
+                sigterm.recv().await;
+            }
+            Err(_) => std::future::pending::<()>().await,
+        }
+    };
+    #[cfg(not(unix))]
+    let terminate = std::future::pending::<()>();
+
+    tokio::select! {
+        _ = ctrl_c => {}
+        _ = terminate => {}
+        _ = requested() => {}
+    }
+    log::info!("Shutdown requested");
+    request();
+}
+
+/// Registers a background task to be awaited by [`drain`].
+pub fn track(task: JoinHandle<()>) {
+    SHUTDOWN
+        .tasks
+        .lock()
+        .unwrap_or_else(|poisoned| poisoned.into_inner())
+        .push(task);
+}
+
+/// Requests shutdown and waits up to `timeout` for the tracked tasks; stragglers are aborted.
+pub async fn drain(timeout: Duration) {
+    request();
+    let tasks = std::mem::take(
+        &mut *SHUTDOWN
+            .tasks
+            .lock()
+            .unwrap_or_else(|poisoned| poisoned.into_inner()),
+    );
+    let aborts: Vec<_> = tasks.iter().map(JoinHandle::abort_handle).collect();
+    if tokio::time::timeout(timeout, futures::future::join_all(tasks))
+        .await
+        .is_err()
+    {
+        log::warn!(
+            "Background tasks still running after {:?}, aborting",
+            timeout
+        );
+        aborts.iter().for_each(|task| task.abort());
+    }
+}
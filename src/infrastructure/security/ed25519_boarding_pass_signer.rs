@@ -0,0 +1,52 @@
+use std::collections::HashMap;
+
+use jsonwebtoken::{Algorithm, DecodingKey, EncodingKey, crypto};
+
+use crate::application::boarding_pass::boarding_pass_signer::BoardingPassSigner;
+use crate::application::common::use_case_error::{UseCaseError, UseCaseResult};
+use crate::domain::boarding_pass::bcbp::encoder;
+use crate::domain::boarding_pass::bcbp::model::{BcbpPass, BcbpSecurity};
+
+pub struct Ed25519BoardingPassSigner {
+    pub active_key_id: String,
+    pub signing_key: EncodingKey,
+    pub verifying_keys: HashMap<String, DecodingKey>,
+}
+
+impl Ed25519BoardingPassSigner {
+    // The signature covers the encoded pass without its security section.
+    fn signing_input(pass: &BcbpPass) -> UseCaseResult<String> {
+        let mut unsigned = pass.clone();
+        unsigned.security = None;
+        Ok(encoder::encode(&unsigned)?)
+    }
+}
+
+impl BoardingPassSigner for Ed25519BoardingPassSigner {
+    fn sign(&self, pass: &mut BcbpPass) -> UseCaseResult<()> {
+        let message = Self::signing_input(pass)?;
+        let signature = crypto::sign(message.as_bytes(), &self.signing_key, Algorithm::EdDSA)
+            .map_err(|e| UseCaseError::Unexpected(e.to_string()))?;
+
+        pass.security = Some(BcbpSecurity {
+            security_type: self.active_key_id.clone(),
+            data: signature,
+        });
+        Ok(())
+    }
+
+    fn verify(&self, pass: &BcbpPass) -> UseCaseResult<bool> {
+        let Some(security) = &pass.security else {
+            return Ok(false);
+        };
+        let Some(key) = self.verifying_keys.get(&security.security_type) else {
+            return Ok(false);
+        };
+
+        let message = Self::signing_input(pass)?;
+        Ok(
+            crypto::verify(&security.data, message.as_bytes(), key, Algorithm::EdDSA)
+                .unwrap_or(false),
+        )
+    }
+}
@@ -1,3 +1,4 @@
 pub mod secret;
 pub mod jwt_codec;
-pub mod argon2_password_hasher;
\ No newline at end of file
+pub mod argon2_password_hasher;
+pub mod ed25519_boarding_pass_signer;
@@ -0,0 +1,28 @@
+use crate::application::aircraft::aircraft_command::{
+    CreateAircraftCommand, UpdateAircraftCommand,
+};
+use crate::presentation::aircraft::aircraft_request::{
+    CreateAircraftRequest, UpdateAircraftRequest,
+};
+
+impl CreateAircraftRequest {
+    pub fn to_command(self) -> CreateAircraftCommand {
+        CreateAircraftCommand {
+            tail_number: self.tail_number,
+            aircraft_type: self.aircraft_type,
+            seat_configuration: self.seat_configuration,
+            airline_code: self.airline_code,
+        }
+    }
+}
+
+impl From<UpdateAircraftRequest> for UpdateAircraftCommand {
+    fn from(req: UpdateAircraftRequest) -> Self {
+        Self {
+            aircraft_type: req.aircraft_type,
+            seat_configuration: req.seat_configuration,
+            airline_code: req.airline_code,
+            status: req.status,
+        }
+    }
+}
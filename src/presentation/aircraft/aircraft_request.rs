@@ -0,0 +1,19 @@
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+
+#[derive(Debug, Deserialize, Serialize, ToSchema, Clone)]
+pub struct CreateAircraftRequest {
+    pub tail_number: String,
+    pub aircraft_type: String,
+    pub seat_configuration: String,
+    pub airline_code: String,
+}
+
+#[derive(Debug, Deserialize, Serialize, ToSchema, Clone)]
+pub struct UpdateAircraftRequest {
+    pub aircraft_type: Option<String>,
+    pub seat_configuration: Option<String>,
+    pub airline_code: Option<String>,
+    /// ACTIVE or MAINTENANCE
+    pub status: Option<String>,
+}
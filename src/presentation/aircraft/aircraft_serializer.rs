@@ -0,0 +1,26 @@
+use crate::application::aircraft::view::aircraft_view::AircraftView;
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+
+#[derive(Debug, Serialize, Deserialize, ToSchema, Clone)]
+pub struct AircraftSerializer {
+    pub id: i64,
+    pub tail_number: String,
+    pub aircraft_type: String,
+    pub seat_configuration: String,
+    pub airline_id: i64,
+    pub status: String,
+}
+
+impl From<AircraftView> for AircraftSerializer {
+    fn from(value: AircraftView) -> Self {
+        Self {
+            id: value.id,
+            tail_number: value.tail_number,
+            aircraft_type: value.aircraft_type,
+            seat_configuration: value.seat_configuration,
+            airline_id: value.airline_id,
+            status: value.status,
+        }
+    }
+}
@@ -0,0 +1,3 @@
+pub mod aircraft_mapper;
+pub mod aircraft_request;
+pub mod aircraft_serializer;
@@ -0,0 +1,29 @@
+use crate::application::airline::airline_command::{CreateAirlineCommand, UpdateAirlineCommand};
+use crate::presentation::airline::airline_request::{CreateAirlineRequest, UpdateAirlineRequest};
+
+impl CreateAirlineRequest {
+    pub fn to_command(self) -> CreateAirlineCommand {
+        CreateAirlineCommand {
+            iata_code: self.iata_code,
+            icao_code: self.icao_code,
+            name: self.name,
+            callsign: self.callsign,
+            country_code: self.country_code,
+            logo_url: self.logo_url,
+            is_active: self.is_active,
+        }
+    }
+}
+
+impl From<UpdateAirlineRequest> for UpdateAirlineCommand {
+    fn from(req: UpdateAirlineRequest) -> Self {
+        Self {
+            icao_code: req.icao_code,
+            name: req.name,
+            callsign: req.callsign,
+            country_code: req.country_code,
+            logo_url: req.logo_url,
+            is_active: req.is_active,
+        }
+    }
+}
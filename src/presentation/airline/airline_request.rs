@@ -0,0 +1,23 @@
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+
+#[derive(Debug, Deserialize, Serialize, ToSchema, Clone)]
+pub struct CreateAirlineRequest {
+    pub iata_code: String,
+    pub icao_code: Option<String>,
+    pub name: String,
+    pub callsign: Option<String>,
+    pub country_code: String,
+    pub logo_url: Option<String>,
+    pub is_active: bool,
+}
+
+#[derive(Debug, Deserialize, Serialize, ToSchema, Clone)]
+pub struct UpdateAirlineRequest {
+    pub icao_code: Option<String>,
+    pub name: Option<String>,
+    pub callsign: Option<String>,
+    pub country_code: Option<String>,
+    pub logo_url: Option<String>,
+    pub is_active: Option<bool>,
+}
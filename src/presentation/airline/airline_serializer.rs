@@ -0,0 +1,30 @@
+use crate::application::airline::view::airline_view::AirlineView;
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+
+#[derive(Debug, Serialize, Deserialize, ToSchema, Clone)]
+pub struct AirlineSerializer {
+    pub id: i64,
+    pub iata_code: String,
+    pub icao_code: Option<String>,
+    pub name: String,
+    pub callsign: Option<String>,
+    pub country_code: String,
+    pub logo_url: Option<String>,
+    pub is_active: bool,
+}
+
+impl From<AirlineView> for AirlineSerializer {
+    fn from(value: AirlineView) -> Self {
+        Self {
+            id: value.id,
+            iata_code: value.iata_code,
+            icao_code: value.icao_code,
+            name: value.name,
+            callsign: value.callsign,
+            country_code: value.country_code,
+            logo_url: value.logo_url,
+            is_active: value.is_active,
+        }
+    }
+}
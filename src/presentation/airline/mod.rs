@@ -0,0 +1,3 @@
+pub mod airline_mapper;
+pub mod airline_request;
+pub mod airline_serializer;
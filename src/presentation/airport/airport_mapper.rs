@@ -1,5 +1,13 @@
-use crate::application::airport::airport_command::{CreateAirportCommand, UpdateAirportCommand};
-use crate::presentation::airport::airport_request::{CreateAirportRequest, UpdateAirportRequest};
+use crate::application::airport::airport_command::{
+    CreateAirportCommand, ImportAirportsCommand, NearbyAirportsCommand, SearchAirportsCommand,
+    UpdateAirportCommand,
+};
+use crate::presentation::airport::airport_request::{
+    CreateAirportRequest, ImportAirportsRequest, NearbyAirportsQuery, SearchAirportsQuery,
+    UpdateAirportRequest,
+};
+use crate::presentation::http::error::HttpError;
+use axum_extra::extract::Multipart;
 
 impl CreateAirportRequest {
     pub fn to_command(self) -> CreateAirportCommand {
@@ -30,3 +38,64 @@ impl From<UpdateAirportRequest> for UpdateAirportCommand {
         }
     }
 }
+
+impl ImportAirportsRequest {
+    pub async fn from_multipart(mut multipart: Multipart) -> Result<Self, HttpError> {
+        let mut airports = None;
+        let mut countries = None;
+
+        while let Some(field) = multipart
+            .next_field()
+            .await
+            .map_err(|e| HttpError::InvalidPayload(e.to_string()))?
+        {
+            let name = field.name().unwrap_or_default().to_string();
+            let content = field
+                .text()
+                .await
+                .map_err(|e| HttpError::InvalidPayload(e.to_string()))?;
+            match name.as_str() {
+                "airports" => airports = Some(content),
+                "countries" => countries = Some(content),
+                _ => {}
+            }
+        }
+
+        let missing = |field: &str| HttpError::Validation {
+            field: field.to_string(),
+            message: format!("Missing {field} file"),
+        };
+        Ok(Self {
+            airports: airports.ok_or_else(|| missing("airports"))?,
+            countries: countries.ok_or_else(|| missing("countries"))?,
+        })
+    }
+
+    pub fn to_command(self, dry_run: bool) -> ImportAirportsCommand {
+        ImportAirportsCommand {
+            airports_csv: self.airports,
+            countries_csv: self.countries,
+            dry_run,
+        }
+    }
+}
+
+impl From<SearchAirportsQuery> for SearchAirportsCommand {
+    fn from(query: SearchAirportsQuery) -> Self {
+        Self {
+            query: query.q,
+            limit: query.limit,
+        }
+    }
+}
+
+impl From<NearbyAirportsQuery> for NearbyAirportsCommand {
+    fn from(query: NearbyAirportsQuery) -> Self {
+        Self {
+            latitude: query.lat,
+            longitude: query.lon,
+            radius_km: query.radius_km,
+            limit: query.limit,
+        }
+    }
+}
@@ -1,5 +1,5 @@
 use serde::{Deserialize, Serialize};
-use utoipa::ToSchema;
+use utoipa::{IntoParams, ToSchema};
 
 #[derive(Debug, Deserialize, Serialize, ToSchema, Clone)]
 pub struct CreateAirportRequest {
@@ -24,3 +24,41 @@ pub struct UpdateAirportRequest {
     pub longitude: Option<f64>,
     pub is_active: Option<bool>,
 }
+
+/// Multipart upload of the two OurAirports exports.
+#[derive(Debug, ToSchema, Clone)]
+pub struct ImportAirportsRequest {
+    #[schema(value_type = String, format = Binary)]
+    pub airports: String,
+    #[schema(value_type = String, format = Binary)]
+    pub countries: String,
+}
+
+#[derive(Debug, Deserialize, IntoParams)]
+pub struct ImportAirportsQuery {
+    /// Report what would change without writing; defaults to true.
+    #[serde(default = "default_dry_run")]
+    pub dry_run: bool,
+}
+
+fn default_dry_run() -> bool {
+    true
+}
+
+#[derive(Debug, Deserialize, IntoParams)]
+pub struct SearchAirportsQuery {
+    /// IATA/ICAO code, name or city; at least 2 characters.
+    pub q: String,
+    /// Maximum results, 1-50 (defaults to 10).
+    pub limit: Option<u64>,
+}
+
+#[derive(Debug, Deserialize, IntoParams)]
+pub struct NearbyAirportsQuery {
+    pub lat: f64,
+    pub lon: f64,
+    /// Search radius, 1-2000 km (defaults to 100).
+    pub radius_km: Option<f64>,
+    /// Maximum results, 1-100 (defaults to 20).
+    pub limit: Option<u64>,
+}
@@ -1,4 +1,8 @@
+use crate::application::airport::view::airport_import_view::{
+    AirportImportIssueView, AirportImportReportView,
+};
 use crate::application::airport::view::airport_view::AirportView;
+use crate::application::airport::view::nearby_airport_view::NearbyAirportView;
 use serde::{Deserialize, Serialize};
 use utoipa::ToSchema;
 
@@ -32,3 +36,62 @@ impl From<AirportView> for AirportSerializer {
         }
     }
 }
+
+#[derive(Debug, Serialize, Deserialize, ToSchema, Clone)]
+pub struct NearbyAirportSerializer {
+    pub airport: AirportSerializer,
+    pub distance_km: f64,
+}
+
+impl From<NearbyAirportView> for NearbyAirportSerializer {
+    fn from(value: NearbyAirportView) -> Self {
+        Self {
+            airport: value.airport.into(),
+            distance_km: value.distance_km,
+        }
+    }
+}
+
+#[derive(Debug, Serialize, Deserialize, ToSchema, Clone)]
+pub struct AirportImportIssueSerializer {
+    pub line: usize,
+    pub ident: String,
+    pub iata_code: String,
+    pub reason: String,
+}
+
+impl From<AirportImportIssueView> for AirportImportIssueSerializer {
+    fn from(value: AirportImportIssueView) -> Self {
+        Self {
+            line: value.line,
+            ident: value.ident,
+            iata_code: value.iata_code,
+            reason: value.reason,
+        }
+    }
+}
+
+#[derive(Debug, Serialize, Deserialize, ToSchema, Clone)]
+pub struct AirportImportReportSerializer {
+    pub dry_run: bool,
+    pub inserts: Vec<String>,
+    pub updates: Vec<String>,
+    pub unchanged: usize,
+    pub without_iata_code: usize,
+    pub skipped: Vec<AirportImportIssueSerializer>,
+    pub conflicts: Vec<AirportImportIssueSerializer>,
+}
+
+impl From<AirportImportReportView> for AirportImportReportSerializer {
+    fn from(value: AirportImportReportView) -> Self {
+        Self {
+            dry_run: value.dry_run,
+            inserts: value.inserts,
+            updates: value.updates,
+            unchanged: value.unchanged,
+            without_iata_code: value.without_iata_code,
+            skipped: value.skipped.into_iter().map(Into::into).collect(),
+            conflicts: value.conflicts.into_iter().map(Into::into).collect(),
+        }
+    }
+}
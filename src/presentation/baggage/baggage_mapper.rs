@@ -0,0 +1,23 @@
+use crate::application::baggage::baggage_command::{IssueBagTagCommand, UpdateBagStatusCommand};
+use crate::presentation::baggage::baggage_request::{IssueBagTagRequest, UpdateBagStatusRequest};
+use rust_decimal::Decimal;
+use std::str::FromStr;
+
+impl From<IssueBagTagRequest> for IssueBagTagCommand {
+    fn from(value: IssueBagTagRequest) -> Self {
+        Self {
+            weight: Decimal::from_str(value.weight.trim()).unwrap_or_default(),
+            weight_unit: value.weight_unit,
+        }
+    }
+}
+
+impl From<UpdateBagStatusRequest> for UpdateBagStatusCommand {
+    fn from(value: UpdateBagStatusRequest) -> Self {
+        Self {
+            status: value.status,
+            location: value.location,
+            note: value.note,
+        }
+    }
+}
@@ -0,0 +1,18 @@
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+
+#[derive(Debug, Deserialize, Serialize, ToSchema, Clone)]
+pub struct IssueBagTagRequest {
+    pub weight: String,
+    /// `KG` or `LB`.
+    pub weight_unit: String,
+}
+
+#[derive(Debug, Deserialize, Serialize, ToSchema, Clone)]
+pub struct UpdateBagStatusRequest {
+    /// `LOADED`, `TRANSFERRED`, `ARRIVED` or `DELAYED`.
+    pub status: String,
+    /// Airport or station where the scan happened, e.g. `SGN`.
+    pub location: Option<String>,
+    pub note: Option<String>,
+}
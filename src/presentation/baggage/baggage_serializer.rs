@@ -0,0 +1,59 @@
+use crate::application::baggage::view::bag_view::{BagStatusView, BagView};
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+
+#[derive(Debug, Serialize, Deserialize, ToSchema, Clone)]
+pub struct BagSerializer {
+    pub id: i64,
+    pub tag_number: String,
+    pub checkin_id: i64,
+    pub booking_id: i64,
+    pub passenger_id: i64,
+    pub flight_id: i64,
+    pub destination_airport_id: i64,
+    pub destination_iata_code: String,
+    pub weight_kg: String,
+    pub status: String,
+    pub last_location: Option<String>,
+    pub history: Vec<BagStatusSerializer>,
+    pub version: i32,
+}
+
+#[derive(Debug, Serialize, Deserialize, ToSchema, Clone)]
+pub struct BagStatusSerializer {
+    pub status: String,
+    pub location: Option<String>,
+    pub note: Option<String>,
+    pub recorded_at: String,
+}
+
+impl From<BagStatusView> for BagStatusSerializer {
+    fn from(value: BagStatusView) -> Self {
+        Self {
+            status: value.status,
+            location: value.location,
+            note: value.note,
+            recorded_at: value.recorded_at,
+        }
+    }
+}
+
+impl From<BagView> for BagSerializer {
+    fn from(value: BagView) -> Self {
+        Self {
+            id: value.id,
+            tag_number: value.tag_number,
+            checkin_id: value.checkin_id,
+            booking_id: value.booking_id,
+            passenger_id: value.passenger_id,
+            flight_id: value.flight_id,
+            destination_airport_id: value.destination_airport_id,
+            destination_iata_code: value.destination_iata_code,
+            weight_kg: value.weight_kg,
+            status: value.status,
+            last_location: value.last_location,
+            history: value.history.into_iter().map(Into::into).collect(),
+            version: value.version,
+        }
+    }
+}
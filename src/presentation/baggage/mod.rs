@@ -0,0 +1,3 @@
+pub mod baggage_mapper;
+pub mod baggage_request;
+pub mod baggage_serializer;
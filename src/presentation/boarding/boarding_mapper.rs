@@ -0,0 +1,25 @@
+use crate::application::boarding::boarding_command::{
+    ClearStandbyCommand, ScanBoardingPassCommand,
+};
+use crate::presentation::boarding::boarding_request::{
+    ClearStandbyRequest, ScanBoardingPassRequest,
+};
+
+impl ScanBoardingPassRequest {
+    pub fn to_command(self, flight_id: i64) -> ScanBoardingPassCommand {
+        ScanBoardingPassCommand {
+            flight_id,
+            barcode_payload: self.barcode_payload,
+        }
+    }
+}
+
+impl ClearStandbyRequest {
+    pub fn to_command(self, flight_id: i64, checkin_id: i64) -> ClearStandbyCommand {
+        ClearStandbyCommand {
+            flight_id,
+            checkin_id,
+            seat_no: self.seat_no,
+        }
+    }
+}
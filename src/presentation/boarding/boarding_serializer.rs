@@ -0,0 +1,79 @@
+use crate::application::boarding::view::boarding_view::{
+    BoardingReconciliationView, BoardingStatusView,
+};
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+
+#[derive(Debug, Serialize, Deserialize, ToSchema, Clone)]
+pub struct BoardingStatusSerializer {
+    pub checkin_id: i64,
+    pub booking_code: String,
+    pub passenger_id: i64,
+    pub passenger_name: String,
+    pub seat_no: Option<String>,
+    pub boarding_pass_code: Option<String>,
+    pub status: String,
+    pub boarded_at: Option<String>,
+}
+
+#[derive(Debug, Serialize, Deserialize, ToSchema, Clone)]
+pub struct BoardingReconciliationSerializer {
+    pub flight_id: i64,
+    pub airline_code: String,
+    pub flight_number: String,
+    pub departure_time: String,
+    pub total_seats: i32,
+    pub booked: usize,
+    pub not_checked_in: usize,
+    pub checked_in: usize,
+    pub boarded: usize,
+    pub no_show: usize,
+    pub offloaded: usize,
+    pub standby: usize,
+    pub unoccupied_seats: i32,
+    pub no_shows: Vec<BoardingStatusSerializer>,
+    pub standby_passengers: Vec<BoardingStatusSerializer>,
+    pub generated_at: String,
+}
+
+impl From<BoardingStatusView> for BoardingStatusSerializer {
+    fn from(value: BoardingStatusView) -> Self {
+        Self {
+            checkin_id: value.checkin_id,
+            booking_code: value.booking_code,
+            passenger_id: value.passenger_id,
+            passenger_name: value.passenger_name,
+            seat_no: value.seat_no,
+            boarding_pass_code: value.boarding_pass_code,
+            status: value.status,
+            boarded_at: value.boarded_at,
+        }
+    }
+}
+
+impl From<BoardingReconciliationView> for BoardingReconciliationSerializer {
+    fn from(value: BoardingReconciliationView) -> Self {
+        Self {
+            flight_id: value.flight_id,
+            airline_code: value.airline_code,
+            flight_number: value.flight_number,
+            departure_time: value.departure_time,
+            total_seats: value.total_seats,
+            booked: value.booked,
+            not_checked_in: value.not_checked_in,
+            checked_in: value.checked_in,
+            boarded: value.boarded,
+            no_show: value.no_show,
+            offloaded: value.offloaded,
+            standby: value.standby,
+            unoccupied_seats: value.unoccupied_seats,
+            no_shows: value.no_shows.into_iter().map(Into::into).collect(),
+            standby_passengers: value
+                .standby_passengers
+                .into_iter()
+                .map(Into::into)
+                .collect(),
+            generated_at: value.generated_at,
+        }
+    }
+}
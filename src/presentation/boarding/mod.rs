@@ -0,0 +1,3 @@
+pub mod boarding_mapper;
+pub mod boarding_request;
+pub mod boarding_serializer;
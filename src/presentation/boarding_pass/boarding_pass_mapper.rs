@@ -1,5 +1,9 @@
-use crate::application::boarding_pass::boarding_pass_command::IssueBoardingPassCommand;
-use crate::presentation::boarding_pass::boarding_pass_request::IssueBoardingPassRequest;
+use crate::application::boarding_pass::boarding_pass_command::{
+    IssueBoardingPassCommand, VerifyBoardingPassCommand,
+};
+use crate::presentation::boarding_pass::boarding_pass_request::{
+    IssueBoardingPassRequest, VerifyBoardingPassRequest,
+};
 
 impl IssueBoardingPassRequest {
     pub fn to_command(self) -> IssueBoardingPassCommand {
@@ -7,10 +11,15 @@ impl IssueBoardingPassRequest {
             checkin_id: self.checkin_id,
             gate: self.gate,
             terminal: self.terminal,
-            boarding_group: self.boarding_group,
-            sequence_no: self.sequence_no,
             boarding_time: self.boarding_time,
             barcode_format: self.barcode_format,
+        }
+    }
+}
+
+impl VerifyBoardingPassRequest {
+    pub fn to_command(self) -> VerifyBoardingPassCommand {
+        VerifyBoardingPassCommand {
             barcode_payload: self.barcode_payload,
         }
     }
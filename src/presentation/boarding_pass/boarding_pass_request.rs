@@ -7,14 +7,16 @@ pub struct IssueBoardingPassRequest {
     pub checkin_id: i64,
     pub gate: Option<String>,
     pub terminal: Option<String>,
-    pub boarding_group: Option<String>,
-    pub sequence_no: Option<String>,
     pub boarding_time: Option<NaiveDateTime>,
     pub barcode_format: String,
-    pub barcode_payload: Option<String>,
 }
 
 #[derive(Debug, Deserialize, Serialize, ToSchema, IntoParams, Clone)]
 pub struct ListBoardingPassesQuery {
     pub booking_id: i64,
 }
+
+#[derive(Debug, Deserialize, Serialize, ToSchema, Clone)]
+pub struct VerifyBoardingPassRequest {
+    pub barcode_payload: String,
+}
@@ -1,4 +1,6 @@
-use crate::application::boarding_pass::view::boarding_pass_view::BoardingPassView;
+use crate::application::boarding_pass::view::boarding_pass_view::{
+    BoardingPassVerificationView, BoardingPassView,
+};
 use serde::{Deserialize, Serialize};
 use utoipa::ToSchema;
 
@@ -15,6 +17,8 @@ pub struct BoardingPassSerializer {
     pub issued_at: String,
     pub barcode_format: String,
     pub barcode_payload: Option<String>,
+    pub revoked_at: Option<String>,
+    pub wallet_pass_version: i32,
 }
 
 impl From<BoardingPassView> for BoardingPassSerializer {
@@ -31,6 +35,39 @@ impl From<BoardingPassView> for BoardingPassSerializer {
             issued_at: value.issued_at,
             barcode_format: value.barcode_format,
             barcode_payload: value.barcode_payload,
+            revoked_at: value.revoked_at,
+            wallet_pass_version: value.wallet_pass_version,
+        }
+    }
+}
+
+#[derive(Debug, Serialize, Deserialize, ToSchema, Clone)]
+pub struct BoardingPassVerificationSerializer {
+    pub valid: bool,
+    pub reason: Option<String>,
+    pub boarding_pass_code: Option<String>,
+    pub key_id: Option<String>,
+    pub passenger_name: Option<String>,
+    pub flight: Option<String>,
+    pub from_airport: Option<String>,
+    pub to_airport: Option<String>,
+    pub seat: Option<String>,
+    pub expires_at: Option<String>,
+}
+
+impl From<BoardingPassVerificationView> for BoardingPassVerificationSerializer {
+    fn from(value: BoardingPassVerificationView) -> Self {
+        Self {
+            valid: value.valid,
+            reason: value.reason,
+            boarding_pass_code: value.boarding_pass_code,
+            key_id: value.key_id,
+            passenger_name: value.passenger_name,
+            flight: value.flight,
+            from_airport: value.from_airport,
+            to_airport: value.to_airport,
+            seat: value.seat,
+            expires_at: value.expires_at,
         }
     }
 }
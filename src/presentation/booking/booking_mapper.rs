@@ -17,12 +17,14 @@ impl CreateBookingRequest {
 
         CreateBookingCommand {
             flight_id: self.flight_id,
+            flight_designator: self.flight_designator,
             base_amount,
             taxes_amount,
             fees_amount,
             discount_amount,
             total_amount,
             currency: self.currency,
+            fare_family: self.fare_family,
             contact_email: self.contact_email,
             contact_phone: self.contact_phone,
             contact_full_name: self.contact_full_name,
@@ -4,12 +4,16 @@ use utoipa::{IntoParams, ToSchema};
 #[derive(Debug, Deserialize, Serialize, ToSchema, Clone)]
 pub struct CreateBookingRequest {
     pub flight_id: i64,
+    /// Designator sold, e.g. a partner's codeshare number (defaults to the operating flight)
+    pub flight_designator: Option<String>,
     pub base_amount: String,
     pub taxes_amount: String,
     pub fees_amount: String,
     pub discount_amount: String,
     pub total_amount: String,
     pub currency: String,
+    /// BASIC | STANDARD | FLEX (defaults to STANDARD)
+    pub fare_family: Option<String>,
     pub contact_email: String,
     pub contact_phone: Option<String>,
     pub contact_full_name: String,
@@ -8,6 +8,8 @@ pub struct BookingSerializer {
     pub booking_code: String,
     pub user_id: i64,
     pub flight_id: i64,
+    pub marketing_airline_code: String,
+    pub marketing_flight_number: String,
     pub status: String,
     pub cancellation_reason: Option<String>,
     pub base_amount: String,
@@ -16,6 +18,7 @@ pub struct BookingSerializer {
     pub discount_amount: String,
     pub total_amount: String,
     pub currency: String,
+    pub fare_family: String,
     pub contact_email: String,
     pub contact_phone: Option<String>,
     pub contact_full_name: String,
@@ -36,6 +39,8 @@ impl From<BookingView> for BookingSerializer {
             booking_code: value.booking_code,
             user_id: value.user_id,
             flight_id: value.flight_id,
+            marketing_airline_code: value.marketing_airline_code,
+            marketing_flight_number: value.marketing_flight_number,
             status: value.status,
             cancellation_reason: value.cancellation_reason,
             base_amount: value.base_amount,
@@ -44,6 +49,7 @@ impl From<BookingView> for BookingSerializer {
             discount_amount: value.discount_amount,
             total_amount: value.total_amount,
             currency: value.currency,
+            fare_family: value.fare_family,
             contact_email: value.contact_email,
             contact_phone: value.contact_phone,
             contact_full_name: value.contact_full_name,
@@ -1,12 +1,19 @@
 use crate::application::checkin::checkin_command::{
-    CancelCheckinCommand, CreateCheckinCommand, UpdateCheckinCommand,
+    CancelCheckinCommand, CreateCheckinCommand, PayExcessBaggageCommand, UpdateCheckinCommand,
 };
 use crate::presentation::checkin::checkin_request::{
-    CancelCheckinRequest, CreateCheckinRequest, UpdateCheckinRequest,
+    CancelCheckinRequest, CreateCheckinRequest, PayExcessBaggageRequest, UpdateCheckinRequest,
 };
 use rust_decimal::Decimal;
 use std::str::FromStr;
 
+fn parse_weights(values: &[String]) -> Vec<Decimal> {
+    values
+        .iter()
+        .filter_map(|v| Decimal::from_str(v.trim()).ok())
+        .collect()
+}
+
 impl CreateCheckinRequest {
     pub fn to_command(self) -> CreateCheckinCommand {
         let baggage_weight_total = Decimal::from_str(&self.baggage_weight_total).unwrap_or_default();
@@ -18,8 +25,10 @@ impl CreateCheckinRequest {
             baggage_count: self.baggage_count,
             baggage_weight_total,
             baggage_weight_unit: self.baggage_weight_unit,
+            baggage_piece_weights: parse_weights(&self.baggage_piece_weights),
             checkin_channel: self.checkin_channel,
             checked_in_ip: self.checked_in_ip,
+            has_valid_visa: self.has_valid_visa,
         }
     }
 }
@@ -33,6 +42,15 @@ impl From<UpdateCheckinRequest> for UpdateCheckinCommand {
             baggage_weight_total: value
                 .baggage_weight_total
                 .and_then(|v| Decimal::from_str(&v).ok()),
+            baggage_piece_weights: value.baggage_piece_weights.map(|v| parse_weights(&v)),
+        }
+    }
+}
+
+impl From<PayExcessBaggageRequest> for PayExcessBaggageCommand {
+    fn from(value: PayExcessBaggageRequest) -> Self {
+        Self {
+            payment_txn_id: value.payment_txn_id,
         }
     }
 }
@@ -10,8 +10,14 @@ pub struct CreateCheckinRequest {
     pub baggage_count: i32,
     pub baggage_weight_total: String,
     pub baggage_weight_unit: String,
+    // One weight per bag; required for more than one bag on a piece allowance.
+    #[serde(default)]
+    pub baggage_piece_weights: Vec<String>,
     pub checkin_channel: String,
     pub checked_in_ip: Option<String>,
+    // Staff and admins only: the agent has inspected the passenger's visa.
+    #[serde(default)]
+    pub has_valid_visa: bool,
 }
 
 #[derive(Debug, Deserialize, Serialize, ToSchema, Clone)]
@@ -20,6 +26,12 @@ pub struct UpdateCheckinRequest {
     pub seat_class: Option<String>,
     pub baggage_count: Option<i32>,
     pub baggage_weight_total: Option<String>,
+    pub baggage_piece_weights: Option<Vec<String>>,
+}
+
+#[derive(Debug, Deserialize, Serialize, ToSchema, Clone)]
+pub struct PayExcessBaggageRequest {
+    pub payment_txn_id: String,
 }
 
 #[derive(Debug, Deserialize, Serialize, ToSchema, Clone)]
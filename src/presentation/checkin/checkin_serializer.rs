@@ -13,7 +13,16 @@ pub struct CheckinSerializer {
     pub baggage_count: i32,
     pub baggage_weight_total: String,
     pub baggage_weight_unit: String,
+    pub baggage_piece_weights: Vec<String>,
+    pub baggage_allowance: Option<String>,
+    pub excess_baggage_pieces: i32,
+    pub excess_baggage_weight_kg: String,
+    pub excess_baggage_fee: String,
+    pub excess_baggage_currency: Option<String>,
+    pub excess_baggage_paid_at: Option<String>,
+    pub excess_baggage_payment_required: bool,
     pub checked_in_at: Option<String>,
+    pub boarded_at: Option<String>,
     pub checkin_channel: String,
     pub checked_in_ip: Option<String>,
     pub version: i32,
@@ -31,7 +40,16 @@ impl From<CheckinView> for CheckinSerializer {
             baggage_count: value.baggage_count,
             baggage_weight_total: value.baggage_weight_total,
             baggage_weight_unit: value.baggage_weight_unit,
+            baggage_piece_weights: value.baggage_piece_weights,
+            baggage_allowance: value.baggage_allowance,
+            excess_baggage_pieces: value.excess_baggage_pieces,
+            excess_baggage_weight_kg: value.excess_baggage_weight_kg,
+            excess_baggage_fee: value.excess_baggage_fee,
+            excess_baggage_currency: value.excess_baggage_currency,
+            excess_baggage_paid_at: value.excess_baggage_paid_at,
+            excess_baggage_payment_required: value.excess_baggage_payment_required,
             checked_in_at: value.checked_in_at,
+            boarded_at: value.boarded_at,
             checkin_channel: value.checkin_channel,
             checked_in_ip: value.checked_in_ip,
             version: value.version,
@@ -0,0 +1,21 @@
+use crate::application::compensation::compensation_command::{
+    DecideCompensationClaimCommand, SubmitCompensationClaimCommand,
+};
+use crate::presentation::compensation::compensation_request::{
+    DecideCompensationClaimRequest, SubmitCompensationClaimRequest,
+};
+
+impl From<SubmitCompensationClaimRequest> for SubmitCompensationClaimCommand {
+    fn from(value: SubmitCompensationClaimRequest) -> Self {
+        Self {
+            booking_code: value.booking_code,
+            note: value.note,
+        }
+    }
+}
+
+impl From<DecideCompensationClaimRequest> for DecideCompensationClaimCommand {
+    fn from(value: DecideCompensationClaimRequest) -> Self {
+        Self { note: value.note }
+    }
+}
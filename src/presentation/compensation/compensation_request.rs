@@ -0,0 +1,21 @@
+use serde::{Deserialize, Serialize};
+use utoipa::{IntoParams, ToSchema};
+
+#[derive(Debug, Deserialize, Serialize, ToSchema, Clone)]
+pub struct SubmitCompensationClaimRequest {
+    pub booking_code: String,
+    /// Anything the customer wants customer service to know.
+    pub note: Option<String>,
+}
+
+#[derive(Debug, Deserialize, Serialize, ToSchema, Clone)]
+pub struct DecideCompensationClaimRequest {
+    /// Shown to the customer; required when rejecting.
+    pub note: Option<String>,
+}
+
+#[derive(Debug, Deserialize, IntoParams)]
+pub struct ListCompensationClaimsQuery {
+    /// `SUBMITTED`, `APPROVED` or `REJECTED`; all claims when not set.
+    pub status: Option<String>,
+}
@@ -0,0 +1,103 @@
+use crate::application::compensation::view::compensation_view::{
+    CompensationAssessmentView, CompensationClaimView,
+};
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+
+#[derive(Debug, Serialize, Deserialize, ToSchema, Clone)]
+pub struct CompensationAssessmentSerializer {
+    pub booking_code: String,
+    pub flight_id: i64,
+    pub flight_key: String,
+    pub flight_status: String,
+    pub eligible: bool,
+    /// `DELAY` or `CANCELLATION`.
+    pub reason: Option<String>,
+    /// Why nothing is owed, e.g. `BELOW_DELAY_THRESHOLD` or `EXTRAORDINARY_CIRCUMSTANCES`.
+    pub ineligibility: Option<String>,
+    pub ineligibility_detail: Option<String>,
+    pub distance_km: i32,
+    pub band_from_km: i32,
+    /// Open-ended band when not set.
+    pub band_to_km: Option<i32>,
+    pub arrival_delay_minutes: Option<i64>,
+    pub reduced: bool,
+    pub passenger_count: i32,
+    pub amount_per_passenger: String,
+    pub total_amount: String,
+    pub currency: String,
+    /// Claim already filed for the booking, if any.
+    pub claim_id: Option<i64>,
+}
+
+#[derive(Debug, Serialize, Deserialize, ToSchema, Clone)]
+pub struct CompensationClaimSerializer {
+    pub id: i64,
+    pub booking_id: i64,
+    pub flight_id: i64,
+    pub user_id: i64,
+    pub reason: String,
+    pub status: String,
+    pub distance_km: i32,
+    pub arrival_delay_minutes: Option<i32>,
+    pub passenger_count: i32,
+    pub amount_per_passenger: String,
+    pub total_amount: String,
+    pub currency: String,
+    pub customer_note: Option<String>,
+    pub decision_note: Option<String>,
+    pub decided_by: Option<i64>,
+    pub submitted_at: String,
+    pub decided_at: Option<String>,
+    pub version: i32,
+}
+
+impl From<CompensationAssessmentView> for CompensationAssessmentSerializer {
+    fn from(value: CompensationAssessmentView) -> Self {
+        Self {
+            booking_code: value.booking_code,
+            flight_id: value.flight_id,
+            flight_key: value.flight_key,
+            flight_status: value.flight_status,
+            eligible: value.eligible,
+            reason: value.reason,
+            ineligibility: value.ineligibility,
+            ineligibility_detail: value.ineligibility_detail,
+            distance_km: value.distance_km,
+            band_from_km: value.band_from_km,
+            band_to_km: value.band_to_km,
+            arrival_delay_minutes: value.arrival_delay_minutes,
+            reduced: value.reduced,
+            passenger_count: value.passenger_count,
+            amount_per_passenger: value.amount_per_passenger,
+            total_amount: value.total_amount,
+            currency: value.currency,
+            claim_id: value.claim_id,
+        }
+    }
+}
+
+impl From<CompensationClaimView> for CompensationClaimSerializer {
+    fn from(value: CompensationClaimView) -> Self {
+        Self {
+            id: value.id,
+            booking_id: value.booking_id,
+            flight_id: value.flight_id,
+            user_id: value.user_id,
+            reason: value.reason,
+            status: value.status,
+            distance_km: value.distance_km,
+            arrival_delay_minutes: value.arrival_delay_minutes,
+            passenger_count: value.passenger_count,
+            amount_per_passenger: value.amount_per_passenger,
+            total_amount: value.total_amount,
+            currency: value.currency,
+            customer_note: value.customer_note,
+            decision_note: value.decision_note,
+            decided_by: value.decided_by,
+            submitted_at: value.submitted_at,
+            decided_at: value.decided_at,
+            version: value.version,
+        }
+    }
+}
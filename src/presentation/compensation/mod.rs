@@ -0,0 +1,3 @@
+pub mod compensation_mapper;
+pub mod compensation_request;
+pub mod compensation_serializer;
\ No newline at end of file
@@ -0,0 +1,8 @@
+use crate::application::dead_letter::dead_letter_command::ResolveDeadLetterCommand;
+use crate::presentation::dead_letter::dead_letter_request::ResolveDeadLetterRequest;
+
+impl From<ResolveDeadLetterRequest> for ResolveDeadLetterCommand {
+    fn from(value: ResolveDeadLetterRequest) -> Self {
+        Self { note: value.note }
+    }
+}
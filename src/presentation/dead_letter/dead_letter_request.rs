@@ -0,0 +1,16 @@
+use serde::{Deserialize, Serialize};
+use utoipa::{IntoParams, ToSchema};
+
+#[derive(Debug, Deserialize, Serialize, ToSchema, Clone)]
+pub struct ResolveDeadLetterRequest {
+    /// Why the message was replayed or discarded.
+    pub note: Option<String>,
+}
+
+#[derive(Debug, Deserialize, IntoParams)]
+pub struct ListDeadLettersQuery {
+    /// `PENDING`, `REPLAYED` or `DISCARDED`; all dead letters when not set.
+    pub status: Option<String>,
+    /// Only the dead letters of this consumer group, e.g. `flight-booking.notifications`.
+    pub consumer_group: Option<String>,
+}
@@ -0,0 +1,59 @@
+use crate::application::dead_letter::view::dead_letter_view::DeadLetterView;
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use utoipa::ToSchema;
+
+#[derive(Debug, Serialize, Deserialize, ToSchema, Clone)]
+pub struct DeadLetterSerializer {
+    pub id: i64,
+    pub consumer_group: String,
+    /// The handler that gave up, as `Handler<Event>`.
+    pub handler: String,
+    pub original_topic: String,
+    pub original_partition: i32,
+    pub original_offset: i64,
+    pub message_key: Option<String>,
+    /// The original payload as text; invalid UTF-8 is replaced.
+    pub payload: String,
+    /// The original headers plus the `x-` headers the consumer added.
+    pub headers: BTreeMap<String, String>,
+    pub error: String,
+    pub attempts: i32,
+    pub dead_letter_topic: String,
+    pub dead_letter_partition: i32,
+    pub dead_letter_offset: i64,
+    /// `PENDING`, `REPLAYED` or `DISCARDED`.
+    pub status: String,
+    pub failed_at: String,
+    pub resolved_at: Option<String>,
+    pub resolved_by: Option<i64>,
+    pub resolution_note: Option<String>,
+    pub version: i32,
+}
+
+impl From<DeadLetterView> for DeadLetterSerializer {
+    fn from(value: DeadLetterView) -> Self {
+        Self {
+            id: value.id,
+            consumer_group: value.consumer_group,
+            handler: value.handler,
+            original_topic: value.original_topic,
+            original_partition: value.original_partition,
+            original_offset: value.original_offset,
+            message_key: value.message_key,
+            payload: value.payload,
+            headers: value.headers,
+            error: value.error,
+            attempts: value.attempts,
+            dead_letter_topic: value.dead_letter_topic,
+            dead_letter_partition: value.dead_letter_partition,
+            dead_letter_offset: value.dead_letter_offset,
+            status: value.status,
+            failed_at: value.failed_at,
+            resolved_at: value.resolved_at,
+            resolved_by: value.resolved_by,
+            resolution_note: value.resolution_note,
+            version: value.version,
+        }
+    }
+}
@@ -0,0 +1,3 @@
+pub mod dead_letter_mapper;
+pub mod dead_letter_request;
+pub mod dead_letter_serializer;
\ No newline at end of file
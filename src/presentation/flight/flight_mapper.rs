@@ -1,8 +1,8 @@
 use crate::application::flight::flight_command::{
-    CreateFlightCommand, SearchFlightCommand, UpdateFlightCommand,
+    AddCodeshareCommand, CreateFlightCommand, SearchFlightCommand, UpdateFlightCommand,
 };
 use crate::presentation::flight::flight_request::{
-    CreateFlightRequest, SearchFlightQuery, UpdateFlightRequest,
+    AddCodeshareRequest, CreateFlightRequest, SearchFlightQuery, UpdateFlightRequest,
 };
 
 impl CreateFlightRequest {
@@ -42,6 +42,14 @@ impl From<UpdateFlightRequest> for UpdateFlightCommand {
             gate: req.gate,
             departure_time: req.departure_time,
             arrival_time: req.arrival_time,
+            estimated_departure: req.estimated_departure,
+            estimated_arrival: req.estimated_arrival,
+            actual_off_block: req.actual_off_block,
+            actual_takeoff: req.actual_takeoff,
+            actual_landing: req.actual_landing,
+            actual_on_block: req.actual_on_block,
+            delay_codes: req.delay_codes,
+            delay_reason: req.delay_reason,
         }
     }
 }
@@ -53,6 +61,16 @@ impl From<SearchFlightQuery> for SearchFlightCommand {
             destination_airport_id: value.destination_airport_id,
             departure_date: value.departure_date,
             status: value.status,
+            designator: value.designator,
+        }
+    }
+}
+
+impl From<AddCodeshareRequest> for AddCodeshareCommand {
+    fn from(req: AddCodeshareRequest) -> Self {
+        Self {
+            airline_code: req.airline_code,
+            flight_number: req.flight_number,
         }
     }
 }
@@ -35,6 +35,16 @@ pub struct UpdateFlightRequest {
     pub gate: Option<String>,
     pub departure_time: Option<NaiveDateTime>,
     pub arrival_time: Option<NaiveDateTime>,
+    // Operational times, UTC; only accepted together with `status`
+    pub estimated_departure: Option<NaiveDateTime>,
+    pub estimated_arrival: Option<NaiveDateTime>,
+    pub actual_off_block: Option<NaiveDateTime>,
+    pub actual_takeoff: Option<NaiveDateTime>,
+    pub actual_landing: Option<NaiveDateTime>,
+    pub actual_on_block: Option<NaiveDateTime>,
+    /// IATA delay codes, most significant first, e.g. `["93", "RA"]`
+    pub delay_codes: Option<Vec<String>>,
+    pub delay_reason: Option<String>,
 }
 
 #[derive(Debug, Deserialize, Serialize, ToSchema, IntoParams, Clone)]
@@ -43,4 +53,13 @@ pub struct SearchFlightQuery {
     pub destination_airport_id: Option<i64>,
     pub departure_date: Option<NaiveDate>,
     pub status: Option<String>,
+    /// Operating or codeshare designator, e.g. `LH400`; only `departure_date` is then required
+    pub designator: Option<String>,
+}
+
+#[derive(Debug, Deserialize, Serialize, ToSchema, Clone)]
+pub struct AddCodeshareRequest {
+    /// Marketing airline IATA designator
+    pub airline_code: String,
+    pub flight_number: String,
 }
@@ -1,4 +1,9 @@
-use crate::application::flight::view::flight_view::FlightView;
+use crate::application::flight::view::flight_timeline_view::{
+    FlightStatusEventView, FlightTimelineView,
+};
+use crate::application::flight::view::flight_view::{
+    FlightCodeshareView, FlightOperationalTimesView, FlightView,
+};
 use serde::{Deserialize, Serialize};
 use utoipa::ToSchema;
 
@@ -10,10 +15,13 @@ pub struct FlightSerializer {
     pub flight_key: String,
     pub origin_airport_id: i64,
     pub destination_airport_id: i64,
+    pub route_id: Option<i64>,
     pub departure_date: String,
     pub departure_time: String,
     pub arrival_time: String,
     pub status: String,
+    pub operational_times: FlightOperationalTimesSerializer,
+    pub delay_minutes: Option<i32>,
     pub aircraft_type: Option<String>,
     pub tail_number: Option<String>,
     pub terminal_departure: Option<String>,
@@ -25,6 +33,53 @@ pub struct FlightSerializer {
     pub total_seats: i32,
     pub available_seats: i32,
     pub version: i32,
+    pub codeshares: Vec<FlightCodeshareSerializer>,
+}
+
+#[derive(Debug, Serialize, Deserialize, ToSchema, Clone)]
+pub struct FlightCodeshareSerializer {
+    pub airline_code: String,
+    pub flight_number: String,
+    pub flight_key: String,
+}
+
+#[derive(Debug, Serialize, Deserialize, ToSchema, Clone)]
+pub struct FlightOperationalTimesSerializer {
+    pub estimated_departure: Option<String>,
+    pub estimated_arrival: Option<String>,
+    pub actual_off_block: Option<String>,
+    pub actual_takeoff: Option<String>,
+    pub actual_landing: Option<String>,
+    pub actual_on_block: Option<String>,
+}
+
+#[derive(Debug, Serialize, Deserialize, ToSchema, Clone)]
+pub struct FlightTimelineSerializer {
+    pub flight_id: i64,
+    pub flight_key: String,
+    pub status: String,
+    pub scheduled_departure: String,
+    pub scheduled_arrival: String,
+    pub operational_times: FlightOperationalTimesSerializer,
+    pub departure_delay_minutes: Option<i32>,
+    pub arrival_delay_minutes: Option<i32>,
+    pub events: Vec<FlightStatusEventSerializer>,
+}
+
+#[derive(Debug, Serialize, Deserialize, ToSchema, Clone)]
+pub struct FlightStatusEventSerializer {
+    pub id: i64,
+    pub from_status: String,
+    pub to_status: String,
+    pub scheduled_departure: String,
+    pub scheduled_arrival: String,
+    pub operational_times: FlightOperationalTimesSerializer,
+    pub delay_minutes: Option<i32>,
+    pub delay_codes: Vec<String>,
+    pub reason: Option<String>,
+    /// User who reported the change; null for system jobs
+    pub changed_by: Option<i64>,
+    pub recorded_at: String,
 }
 
 impl From<FlightView> for FlightSerializer {
@@ -36,10 +91,13 @@ impl From<FlightView> for FlightSerializer {
             flight_key: value.flight_key,
             origin_airport_id: value.origin_airport_id,
             destination_airport_id: value.destination_airport_id,
+            route_id: value.route_id,
             departure_date: value.departure_date,
             departure_time: value.departure_time,
             arrival_time: value.arrival_time,
             status: value.status,
+            operational_times: value.operational_times.into(),
+            delay_minutes: value.delay_minutes,
             aircraft_type: value.aircraft_type,
             tail_number: value.tail_number,
             terminal_departure: value.terminal_departure,
@@ -51,6 +109,64 @@ impl From<FlightView> for FlightSerializer {
             total_seats: value.total_seats,
             available_seats: value.available_seats,
             version: value.version,
+            codeshares: value.codeshares.into_iter().map(Into::into).collect(),
+        }
+    }
+}
+
+impl From<FlightCodeshareView> for FlightCodeshareSerializer {
+    fn from(value: FlightCodeshareView) -> Self {
+        Self {
+            airline_code: value.airline_code,
+            flight_number: value.flight_number,
+            flight_key: value.flight_key,
+        }
+    }
+}
+
+impl From<FlightOperationalTimesView> for FlightOperationalTimesSerializer {
+    fn from(value: FlightOperationalTimesView) -> Self {
+        Self {
+            estimated_departure: value.estimated_departure,
+            estimated_arrival: value.estimated_arrival,
+            actual_off_block: value.actual_off_block,
+            actual_takeoff: value.actual_takeoff,
+            actual_landing: value.actual_landing,
+            actual_on_block: value.actual_on_block,
+        }
+    }
+}
+
+impl From<FlightTimelineView> for FlightTimelineSerializer {
+    fn from(value: FlightTimelineView) -> Self {
+        Self {
+            flight_id: value.flight_id,
+            flight_key: value.flight_key,
+            status: value.status,
+            scheduled_departure: value.scheduled_departure,
+            scheduled_arrival: value.scheduled_arrival,
+            operational_times: value.operational_times.into(),
+            departure_delay_minutes: value.departure_delay_minutes,
+            arrival_delay_minutes: value.arrival_delay_minutes,
+            events: value.events.into_iter().map(Into::into).collect(),
+        }
+    }
+}
+
+impl From<FlightStatusEventView> for FlightStatusEventSerializer {
+    fn from(value: FlightStatusEventView) -> Self {
+        Self {
+            id: value.id,
+            from_status: value.from_status,
+            to_status: value.to_status,
+            scheduled_departure: value.scheduled_departure,
+            scheduled_arrival: value.scheduled_arrival,
+            operational_times: value.operational_times.into(),
+            delay_minutes: value.delay_minutes,
+            delay_codes: value.delay_codes,
+            reason: value.reason,
+            changed_by: value.changed_by,
+            recorded_at: value.recorded_at,
         }
     }
 }
@@ -0,0 +1,11 @@
+use crate::application::flight_status::flight_status_command::SubscribeFlightStatusCommand;
+use crate::presentation::flight_status::flight_status_request::FlightStatusStreamQuery;
+
+impl From<FlightStatusStreamQuery> for SubscribeFlightStatusCommand {
+    fn from(query: FlightStatusStreamQuery) -> Self {
+        Self {
+            flight_ids: query.flight_id,
+            booking_codes: query.booking_code,
+        }
+    }
+}
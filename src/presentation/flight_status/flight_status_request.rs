@@ -0,0 +1,27 @@
+use serde::{Deserialize, Serialize};
+use utoipa::{IntoParams, ToSchema};
+
+/// Repeat a parameter to follow several flights, e.g. `?flight_id=1&flight_id=2`.
+#[derive(Debug, Deserialize, IntoParams)]
+pub struct FlightStatusStreamQuery {
+    #[serde(default)]
+    pub flight_id: Vec<i64>,
+    /// Booking codes of the caller; follows the booked flight.
+    #[serde(default)]
+    pub booking_code: Vec<String>,
+}
+
+/// Messages a WebSocket client may send after connecting.
+#[derive(Debug, Deserialize, Serialize, ToSchema, Clone)]
+#[serde(tag = "action", rename_all = "snake_case")]
+pub enum FlightStatusSocketRequest {
+    Subscribe {
+        #[serde(default)]
+        flight_ids: Vec<i64>,
+        #[serde(default)]
+        booking_codes: Vec<String>,
+    },
+    Unsubscribe {
+        flight_ids: Vec<i64>,
+    },
+}
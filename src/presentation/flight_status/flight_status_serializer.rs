@@ -0,0 +1,45 @@
+use crate::application::flight_status::view::flight_status_view::FlightStatusUpdateView;
+use crate::presentation::flight::flight_serializer::FlightOperationalTimesSerializer;
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+
+#[derive(Debug, Serialize, Deserialize, ToSchema, Clone)]
+pub struct FlightStatusUpdateSerializer {
+    pub flight_id: i64,
+    pub flight_key: String,
+    pub status: String,
+    pub scheduled_departure: String,
+    pub scheduled_arrival: String,
+    pub operational_times: FlightOperationalTimesSerializer,
+    pub delay_minutes: Option<i32>,
+    pub terminal_departure: Option<String>,
+    pub gate: Option<String>,
+    pub boarding_time: Option<String>,
+    pub occurred_at: String,
+}
+
+/// Messages the WebSocket endpoint sends.
+#[derive(Debug, Serialize, Deserialize, ToSchema, Clone)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum FlightStatusSocketMessage {
+    FlightStatus(FlightStatusUpdateSerializer),
+    Error { message: String },
+}
+
+impl From<FlightStatusUpdateView> for FlightStatusUpdateSerializer {
+    fn from(value: FlightStatusUpdateView) -> Self {
+        Self {
+            flight_id: value.flight_id,
+            flight_key: value.flight_key,
+            status: value.status,
+            scheduled_departure: value.scheduled_departure,
+            scheduled_arrival: value.scheduled_arrival,
+            operational_times: value.operational_times.into(),
+            delay_minutes: value.delay_minutes,
+            terminal_departure: value.terminal_departure,
+            gate: value.gate,
+            boarding_time: value.boarding_time,
+            occurred_at: value.occurred_at,
+        }
+    }
+}
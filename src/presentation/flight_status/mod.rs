@@ -0,0 +1,3 @@
+pub mod flight_status_mapper;
+pub mod flight_status_request;
+pub mod flight_status_serializer;
\ No newline at end of file
@@ -7,11 +7,18 @@ impl From<DomainError> for HttpError {
             DomainError::User(err) => err.into(),
             DomainError::Address(err) => err.into(),
             DomainError::Airport(err) => err.into(),
+            DomainError::Airline(err) => err.into(),
+            DomainError::Aircraft(err) => err.into(),
+            DomainError::Route(err) => err.into(),
             DomainError::Flight(err) => err.into(),
             DomainError::Booking(err) => err.into(),
             DomainError::Passenger(err) => err.into(),
             DomainError::Checkin(err) => err.into(),
             DomainError::BoardingPass(err) => err.into(),
+            DomainError::Baggage(err) => err.into(),
+            DomainError::Compensation(err) => err.into(),
+            DomainError::Notification(err) => err.into(),
+            DomainError::DeadLetter(err) => err.into(),
         }
     }
 }
@@ -1,10 +1,17 @@
 use crate::domain::address::error::AddressDomainError;
+use crate::domain::aircraft::error::AircraftDomainError;
+use crate::domain::airline::error::AirlineDomainError;
 use crate::domain::airport::error::AirportDomainError;
+use crate::domain::baggage::error::BaggageDomainError;
 use crate::domain::boarding_pass::error::BoardingPassDomainError;
 use crate::domain::booking::error::BookingDomainError;
 use crate::domain::checkin::error::CheckinDomainError;
+use crate::domain::compensation::error::CompensationDomainError;
+use crate::domain::dead_letter::error::DeadLetterDomainError;
 use crate::domain::flight::error::FlightDomainError;
+use crate::domain::notification::error::NotificationDomainError;
 use crate::domain::passenger::error::PassengerDomainError;
+use crate::domain::route::error::RouteDomainError;
 use crate::domain::user::errors::UserDomainError;
 use crate::presentation::http::error::HttpError;
 
@@ -84,6 +91,104 @@ impl From<AirportDomainError> for HttpError {
     }
 }
 
+impl From<AirlineDomainError> for HttpError {
+    fn from(err: AirlineDomainError) -> Self {
+        match err {
+            AirlineDomainError::Validation { field, message } => HttpError::Validation {
+                field: field.to_string(),
+                message,
+            },
+
+            AirlineDomainError::Conflict { field, message } => HttpError::Conflict {
+                field: field.to_string(),
+                message,
+            },
+
+            AirlineDomainError::Unauthorized { .. } => HttpError::Unauthorized,
+
+            AirlineDomainError::BusinessRule { message } => HttpError::BadRequest(message),
+
+            AirlineDomainError::AirlineInactive { iata_code } => HttpError::BadRequest(format!(
+                "Airline {iata_code} is not active"
+            )),
+
+            AirlineDomainError::NotFound { detail } => HttpError::EntityNotFound { detail },
+
+            AirlineDomainError::Internal(_) => HttpError::Internal,
+        }
+    }
+}
+
+impl From<AircraftDomainError> for HttpError {
+    fn from(err: AircraftDomainError) -> Self {
+        match err {
+            AircraftDomainError::Validation { field, message } => HttpError::Validation {
+                field: field.to_string(),
+                message,
+            },
+
+            AircraftDomainError::Conflict { field, message } => HttpError::Conflict {
+                field: field.to_string(),
+                message,
+            },
+
+            AircraftDomainError::Unauthorized { .. } => HttpError::Unauthorized,
+
+            AircraftDomainError::BusinessRule { message } => HttpError::BadRequest(message),
+
+            AircraftDomainError::AircraftInMaintenance { tail_number } => {
+                HttpError::BadRequest(format!("Aircraft {tail_number} is in maintenance"))
+            }
+
+            AircraftDomainError::RotationConflict {
+                tail_number,
+                message,
+            } => HttpError::Conflict {
+                field: "tail_number".to_string(),
+                message: format!("Aircraft {tail_number}: {message}"),
+            },
+
+            AircraftDomainError::NotFound { detail } => HttpError::EntityNotFound { detail },
+
+            AircraftDomainError::Internal(_) => HttpError::Internal,
+        }
+    }
+}
+
+impl From<RouteDomainError> for HttpError {
+    fn from(err: RouteDomainError) -> Self {
+        match err {
+            RouteDomainError::Validation { field, message } => HttpError::Validation {
+                field: field.to_string(),
+                message,
+            },
+
+            RouteDomainError::Conflict { field, message } => HttpError::Conflict {
+                field: field.to_string(),
+                message,
+            },
+
+            RouteDomainError::Unauthorized { .. } => HttpError::Unauthorized,
+
+            RouteDomainError::BusinessRule { message } => HttpError::BadRequest(message),
+
+            RouteDomainError::RouteInactive {
+                origin,
+                destination,
+            } => HttpError::BadRequest(format!("Route {origin}-{destination} is not active")),
+
+            RouteDomainError::AirportWithoutCoordinates { iata_code } => HttpError::Validation {
+                field: "coordinates".to_string(),
+                message: format!("Airport {iata_code} has no coordinates to measure the route"),
+            },
+
+            RouteDomainError::NotFound { detail } => HttpError::EntityNotFound { detail },
+
+            RouteDomainError::Internal(_) => HttpError::Internal,
+        }
+    }
+}
+
 impl From<FlightDomainError> for HttpError {
     fn from(err: FlightDomainError) -> Self {
         match err {
@@ -233,6 +338,10 @@ impl From<PassengerDomainError> for HttpError {
                 message,
             },
 
+            PassengerDomainError::Duplicate { message } => {
+                HttpError::EntityAlreadyExists { detail: message }
+            }
+
             PassengerDomainError::OptimisticLockConflict => HttpError::OptimisticLockConflict,
 
             // ===== Ownership / relation conflict =====
@@ -312,6 +421,16 @@ impl From<CheckinDomainError> for HttpError {
                 HttpError::BadRequest(err.to_string())
             }
 
+            CheckinDomainError::ExcessBaggageFeeUnpaid { .. } => HttpError::Conflict {
+                field: "excess_baggage_fee".to_string(),
+                message: err.to_string(),
+            },
+
+            CheckinDomainError::AlreadyBoarded => HttpError::Conflict {
+                field: "status".to_string(),
+                message: err.to_string(),
+            },
+
             // ===== Not found =====
             CheckinDomainError::CheckinNotFound { checkin_id } => HttpError::EntityNotFound {
                 detail: format!("checkin_id={}", checkin_id),
@@ -360,3 +479,134 @@ impl From<BoardingPassDomainError> for HttpError {
         }
     }
 }
+
+impl From<BaggageDomainError> for HttpError {
+    fn from(err: BaggageDomainError) -> Self {
+        match err {
+            BaggageDomainError::Validation { field, message } => HttpError::Validation {
+                field: field.to_string(),
+                message,
+            },
+
+            BaggageDomainError::Conflict { field, message } => HttpError::Conflict {
+                field: field.to_string(),
+                message,
+            },
+
+            BaggageDomainError::OptimisticLockConflict => HttpError::OptimisticLockConflict,
+
+            BaggageDomainError::InvalidStatusTransition { from, to } => HttpError::Conflict {
+                field: "status".to_string(),
+                message: format!("invalid bag status transition: {:?} -> {:?}", from, to),
+            },
+
+            BaggageDomainError::BusinessRule { message } => HttpError::BadRequest(message),
+
+            BaggageDomainError::TagIssuerNotConfigured { .. } => {
+                HttpError::BadRequest(err.to_string())
+            }
+
+            BaggageDomainError::BagNotFound { tag_number } => HttpError::EntityNotFound {
+                detail: format!("tag_number={}", tag_number),
+            },
+
+            BaggageDomainError::NotFound { detail } => HttpError::EntityNotFound { detail },
+
+            BaggageDomainError::Internal(_) => HttpError::Internal,
+        }
+    }
+}
+
+impl From<CompensationDomainError> for HttpError {
+    fn from(err: CompensationDomainError) -> Self {
+        match err {
+            CompensationDomainError::Validation { field, message } => HttpError::Validation {
+                field: field.to_string(),
+                message,
+            },
+
+            CompensationDomainError::Conflict { field, message } => HttpError::Conflict {
+                field: field.to_string(),
+                message,
+            },
+
+            CompensationDomainError::OptimisticLockConflict => HttpError::OptimisticLockConflict,
+
+            CompensationDomainError::ClaimAlreadyDecided { .. } => HttpError::Conflict {
+                field: "status".to_string(),
+                message: err.to_string(),
+            },
+
+            CompensationDomainError::BusinessRule { message } => HttpError::BadRequest(message),
+
+            CompensationDomainError::NotEligible { .. } => HttpError::BadRequest(err.to_string()),
+
+            CompensationDomainError::ClaimNotFound { claim_id } => HttpError::EntityNotFound {
+                detail: format!("claim_id={}", claim_id),
+            },
+
+            CompensationDomainError::NotFound { detail } => HttpError::EntityNotFound { detail },
+
+            CompensationDomainError::Internal(_) => HttpError::Internal,
+        }
+    }
+}
+
+impl From<NotificationDomainError> for HttpError {
+    fn from(err: NotificationDomainError) -> Self {
+        match err {
+            NotificationDomainError::Validation { field, message } => HttpError::Validation {
+                field: field.to_string(),
+                message,
+            },
+
+            NotificationDomainError::Conflict { field, message } => HttpError::Conflict {
+                field: field.to_string(),
+                message,
+            },
+
+            NotificationDomainError::OptimisticLockConflict => HttpError::OptimisticLockConflict,
+
+            NotificationDomainError::BusinessRule { message } => HttpError::BadRequest(message),
+
+            NotificationDomainError::NotFound { detail } => HttpError::EntityNotFound { detail },
+
+            NotificationDomainError::Template { .. } | NotificationDomainError::Internal(_) => {
+                HttpError::Internal
+            }
+        }
+    }
+}
+
+impl From<DeadLetterDomainError> for HttpError {
+    fn from(err: DeadLetterDomainError) -> Self {
+        match err {
+            DeadLetterDomainError::Validation { field, message } => HttpError::Validation {
+                field: field.to_string(),
+                message,
+            },
+
+            DeadLetterDomainError::Conflict { field, message } => HttpError::Conflict {
+                field: field.to_string(),
+                message,
+            },
+
+            DeadLetterDomainError::OptimisticLockConflict => HttpError::OptimisticLockConflict,
+
+            DeadLetterDomainError::AlreadyResolved { .. } => HttpError::Conflict {
+                field: "status".to_string(),
+                message: err.to_string(),
+            },
+
+            DeadLetterDomainError::BusinessRule { message } => HttpError::BadRequest(message),
+
+            DeadLetterDomainError::DeadLetterNotFound { id } => HttpError::EntityNotFound {
+                detail: format!("dead_letter_id={}", id),
+            },
+
+            DeadLetterDomainError::NotFound { detail } => HttpError::EntityNotFound { detail },
+
+            DeadLetterDomainError::Internal(_) => HttpError::Internal,
+        }
+    }
+}
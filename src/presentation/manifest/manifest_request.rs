@@ -0,0 +1,8 @@
+use serde::{Deserialize, Serialize};
+use utoipa::{IntoParams, ToSchema};
+
+#[derive(Debug, Deserialize, Serialize, ToSchema, IntoParams, Clone)]
+pub struct ManifestExportQuery {
+    /// csv | json | paxlst (defaults to json)
+    pub format: Option<String>,
+}
@@ -0,0 +1,100 @@
+use crate::application::manifest::view::manifest_view::{
+    FlightManifestView, ManifestEntryView, ManifestIssueView,
+};
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+
+#[derive(Debug, Serialize, Deserialize, ToSchema, Clone)]
+pub struct FlightManifestSerializer {
+    pub flight_id: i64,
+    pub airline_code: String,
+    pub flight_number: String,
+    pub departure_date: String,
+    pub departure_time: String,
+    pub arrival_time: String,
+    pub origin_iata_code: String,
+    pub origin_country_code: String,
+    pub destination_iata_code: String,
+    pub destination_country_code: String,
+    pub generated_at: String,
+    pub passenger_count: usize,
+    pub entries: Vec<ManifestEntrySerializer>,
+    pub issues: Vec<ManifestIssueSerializer>,
+}
+
+#[derive(Debug, Serialize, Deserialize, ToSchema, Clone)]
+pub struct ManifestEntrySerializer {
+    pub booking_code: String,
+    pub passenger_id: i64,
+    pub passenger_type: String,
+    pub first_name: String,
+    pub last_name: String,
+    pub dob: String,
+    pub gender: String,
+    pub nationality_code: String,
+    pub passport_no: Option<String>,
+    pub passport_issuing_country_code: Option<String>,
+    pub passport_expiry_date: Option<String>,
+    pub seat_no: Option<String>,
+    pub checked_in: bool,
+}
+
+#[derive(Debug, Serialize, Deserialize, ToSchema, Clone)]
+pub struct ManifestIssueSerializer {
+    pub passenger_id: i64,
+    pub booking_code: String,
+    pub field: String,
+    pub message: String,
+}
+
+impl From<FlightManifestView> for FlightManifestSerializer {
+    fn from(value: FlightManifestView) -> Self {
+        Self {
+            flight_id: value.flight_id,
+            airline_code: value.airline_code,
+            flight_number: value.flight_number,
+            departure_date: value.departure_date,
+            departure_time: value.departure_time,
+            arrival_time: value.arrival_time,
+            origin_iata_code: value.origin_iata_code,
+            origin_country_code: value.origin_country_code,
+            destination_iata_code: value.destination_iata_code,
+            destination_country_code: value.destination_country_code,
+            generated_at: value.generated_at,
+            passenger_count: value.passenger_count,
+            entries: value.entries.into_iter().map(Into::into).collect(),
+            issues: value.issues.into_iter().map(Into::into).collect(),
+        }
+    }
+}
+
+impl From<ManifestEntryView> for ManifestEntrySerializer {
+    fn from(value: ManifestEntryView) -> Self {
+        Self {
+            booking_code: value.booking_code,
+            passenger_id: value.passenger_id,
+            passenger_type: value.passenger_type,
+            first_name: value.first_name,
+            last_name: value.last_name,
+            dob: value.dob,
+            gender: value.gender,
+            nationality_code: value.nationality_code,
+            passport_no: value.passport_no,
+            passport_issuing_country_code: value.passport_issuing_country_code,
+            passport_expiry_date: value.passport_expiry_date,
+            seat_no: value.seat_no,
+            checked_in: value.checked_in,
+        }
+    }
+}
+
+impl From<ManifestIssueView> for ManifestIssueSerializer {
+    fn from(value: ManifestIssueView) -> Self {
+        Self {
+            passenger_id: value.passenger_id,
+            booking_code: value.booking_code,
+            field: value.field,
+            message: value.message,
+        }
+    }
+}
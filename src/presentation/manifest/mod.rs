@@ -0,0 +1,2 @@
+pub mod manifest_request;
+pub mod manifest_serializer;
@@ -1,13 +1,24 @@
 pub mod address;
+pub mod airline;
+pub mod aircraft;
 pub mod airport;
 pub mod auth;
+pub mod baggage;
+pub mod boarding;
 pub mod boarding_pass;
 pub mod booking;
 pub mod checkin;
 pub mod common;
+pub mod compensation;
 pub mod context;
+pub mod dead_letter;
 pub mod flight;
+pub mod flight_status;
 pub mod gateway;
 pub mod http;
+pub mod manifest;
+pub mod no_show;
+pub mod notification;
 pub mod passenger;
+pub mod route;
 pub mod user;
@@ -0,0 +1,63 @@
+use crate::application::no_show::view::no_show_view::{NoShowProcessingView, NoShowView};
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+
+#[derive(Debug, Serialize, Deserialize, ToSchema, Clone)]
+pub struct NoShowSerializer {
+    pub id: i64,
+    pub flight_id: i64,
+    pub booking_id: i64,
+    pub booking_code: String,
+    pub passenger_id: i64,
+    pub passenger_name: String,
+    pub checkin_id: Option<i64>,
+    pub fare_family: String,
+    pub fare_amount: String,
+    pub penalty_amount: String,
+    pub currency: String,
+    pub recorded_at: String,
+}
+
+#[derive(Debug, Serialize, Deserialize, ToSchema, Clone)]
+pub struct NoShowProcessingSerializer {
+    pub flight_id: i64,
+    pub airline_code: String,
+    pub flight_number: String,
+    pub departure_time: String,
+    pub no_shows: Vec<NoShowSerializer>,
+    pub bookings_closed: usize,
+    pub processed_at: String,
+}
+
+impl From<NoShowView> for NoShowSerializer {
+    fn from(value: NoShowView) -> Self {
+        Self {
+            id: value.id,
+            flight_id: value.flight_id,
+            booking_id: value.booking_id,
+            booking_code: value.booking_code,
+            passenger_id: value.passenger_id,
+            passenger_name: value.passenger_name,
+            checkin_id: value.checkin_id,
+            fare_family: value.fare_family,
+            fare_amount: value.fare_amount,
+            penalty_amount: value.penalty_amount,
+            currency: value.currency,
+            recorded_at: value.recorded_at,
+        }
+    }
+}
+
+impl From<NoShowProcessingView> for NoShowProcessingSerializer {
+    fn from(value: NoShowProcessingView) -> Self {
+        Self {
+            flight_id: value.flight_id,
+            airline_code: value.airline_code,
+            flight_number: value.flight_number,
+            departure_time: value.departure_time,
+            no_shows: value.no_shows.into_iter().map(Into::into).collect(),
+            bookings_closed: value.bookings_closed,
+            processed_at: value.processed_at,
+        }
+    }
+}
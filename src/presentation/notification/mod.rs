@@ -0,0 +1,3 @@
+pub mod notification_mapper;
+pub mod notification_request;
+pub mod notification_serializer;
\ No newline at end of file
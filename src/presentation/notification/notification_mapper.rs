@@ -0,0 +1,14 @@
+use crate::application::notification::notification_command::UpdateNotificationPreferenceCommand;
+use crate::presentation::notification::notification_request::UpdateNotificationPreferenceRequest;
+
+impl From<UpdateNotificationPreferenceRequest> for UpdateNotificationPreferenceCommand {
+    fn from(value: UpdateNotificationPreferenceRequest) -> Self {
+        Self {
+            locale: value.locale,
+            email_enabled: value.email_enabled,
+            sms_enabled: value.sms_enabled,
+            push_enabled: value.push_enabled,
+            push_token: value.push_token,
+        }
+    }
+}
@@ -0,0 +1,21 @@
+use serde::{Deserialize, Serialize};
+use utoipa::{IntoParams, ToSchema};
+
+#[derive(Debug, Deserialize, Serialize, ToSchema, Clone)]
+pub struct UpdateNotificationPreferenceRequest {
+    /// Language tag such as `en` or `vi`; the default locale when not set.
+    pub locale: Option<String>,
+    pub email_enabled: bool,
+    pub sms_enabled: bool,
+    pub push_enabled: bool,
+    /// Device token for push notifications; required when push is enabled.
+    pub push_token: Option<String>,
+}
+
+#[derive(Debug, Deserialize, IntoParams)]
+pub struct ListNotificationsQuery {
+    /// Staff only; customers always see their own notifications.
+    pub user_id: Option<i64>,
+    /// `PENDING`, `SENT`, `FAILED` or `CANCELLED`; all notifications when not set.
+    pub status: Option<String>,
+}
@@ -0,0 +1,75 @@
+use crate::application::notification::view::notification_view::{
+    NotificationPreferenceView, NotificationView,
+};
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+
+#[derive(Debug, Serialize, Deserialize, ToSchema, Clone)]
+pub struct NotificationSerializer {
+    pub id: i64,
+    /// `EMAIL_VERIFICATION`, `BOOKING_CONFIRMATION`, `CHECKIN_REMINDER` or `FLIGHT_DISRUPTION`.
+    pub kind: String,
+    /// `EMAIL`, `SMS` or `PUSH`.
+    pub channel: String,
+    pub status: String,
+    pub user_id: Option<i64>,
+    pub booking_id: Option<i64>,
+    pub flight_id: Option<i64>,
+    pub recipient: String,
+    pub locale: String,
+    pub subject: Option<String>,
+    pub body: String,
+    pub attempts: i32,
+    pub last_error: Option<String>,
+    /// When the notification is (or was) due to be sent.
+    pub scheduled_at: String,
+    pub sent_at: Option<String>,
+}
+
+#[derive(Debug, Serialize, Deserialize, ToSchema, Clone)]
+pub struct NotificationPreferenceSerializer {
+    pub user_id: i64,
+    pub locale: Option<String>,
+    /// Locale notifications are written in after falling back to the available templates.
+    pub effective_locale: String,
+    pub email_enabled: bool,
+    pub sms_enabled: bool,
+    pub push_enabled: bool,
+    pub push_token: Option<String>,
+}
+
+impl From<NotificationView> for NotificationSerializer {
+    fn from(value: NotificationView) -> Self {
+        Self {
+            id: value.id,
+            kind: value.kind,
+            channel: value.channel,
+            status: value.status,
+            user_id: value.user_id,
+            booking_id: value.booking_id,
+            flight_id: value.flight_id,
+            recipient: value.recipient,
+            locale: value.locale,
+            subject: value.subject,
+            body: value.body,
+            attempts: value.attempts,
+            last_error: value.last_error,
+            scheduled_at: value.scheduled_at,
+            sent_at: value.sent_at,
+        }
+    }
+}
+
+impl From<NotificationPreferenceView> for NotificationPreferenceSerializer {
+    fn from(value: NotificationPreferenceView) -> Self {
+        Self {
+            user_id: value.user_id,
+            locale: value.locale,
+            effective_locale: value.effective_locale,
+            email_enabled: value.email_enabled,
+            sms_enabled: value.sms_enabled,
+            push_enabled: value.push_enabled,
+            push_token: value.push_token,
+        }
+    }
+}
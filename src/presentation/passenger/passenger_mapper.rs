@@ -23,6 +23,7 @@ impl CreatePassengerRequest {
             phone_number: self.phone_number,
             ff_airline_code: self.ff_airline_code,
             ff_number: self.ff_number,
+            accompanying_adult_id: self.accompanying_adult_id,
         }
     }
 }
@@ -43,6 +44,7 @@ impl From<UpdatePassengerRequest> for UpdatePassengerCommand {
             phone_number: value.phone_number,
             ff_airline_code: value.ff_airline_code,
             ff_number: value.ff_number,
+            accompanying_adult_id: value.accompanying_adult_id,
         }
     }
 }
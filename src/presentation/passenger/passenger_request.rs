@@ -19,6 +19,7 @@ pub struct CreatePassengerRequest {
     pub phone_number: Option<String>,
     pub ff_airline_code: Option<String>,
     pub ff_number: Option<String>,
+    pub accompanying_adult_id: Option<i64>,
 }
 
 #[derive(Debug, Deserialize, Serialize, ToSchema, Clone)]
@@ -36,6 +37,14 @@ pub struct UpdatePassengerRequest {
     pub phone_number: Option<String>,
     pub ff_airline_code: Option<String>,
     pub ff_number: Option<String>,
+    // absent keeps the link, `null` unlinks the accompanying adult
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        with = "::serde_with::rust::double_option"
+    )]
+    #[schema(value_type = Option<i64>)]
+    pub accompanying_adult_id: Option<Option<i64>>,
 }
 
 #[derive(Debug, Deserialize, Serialize, ToSchema, IntoParams, Clone)]
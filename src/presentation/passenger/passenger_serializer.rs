@@ -20,6 +20,7 @@ pub struct PassengerSerializer {
     pub phone_number: Option<String>,
     pub ff_airline_code: Option<String>,
     pub ff_number: Option<String>,
+    pub accompanying_adult_id: Option<i64>,
     pub version: i32,
 }
 
@@ -42,6 +43,7 @@ impl From<PassengerView> for PassengerSerializer {
             phone_number: value.phone_number,
             ff_airline_code: value.ff_airline_code,
             ff_number: value.ff_number,
+            accompanying_adult_id: value.accompanying_adult_id,
             version: value.version,
         }
     }
@@ -0,0 +1,3 @@
+pub mod route_mapper;
+pub mod route_request;
+pub mod route_serializer;
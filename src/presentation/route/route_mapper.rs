@@ -0,0 +1,21 @@
+use crate::application::route::route_command::{CreateRouteCommand, UpdateRouteCommand};
+use crate::presentation::route::route_request::{CreateRouteRequest, UpdateRouteRequest};
+
+impl CreateRouteRequest {
+    pub fn to_command(self) -> CreateRouteCommand {
+        CreateRouteCommand {
+            origin_iata_code: self.origin_iata_code,
+            destination_iata_code: self.destination_iata_code,
+            block_time_minutes: self.block_time_minutes,
+        }
+    }
+}
+
+impl From<UpdateRouteRequest> for UpdateRouteCommand {
+    fn from(req: UpdateRouteRequest) -> Self {
+        Self {
+            block_time_minutes: req.block_time_minutes,
+            is_active: req.is_active,
+        }
+    }
+}
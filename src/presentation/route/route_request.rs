@@ -0,0 +1,16 @@
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+
+#[derive(Debug, Deserialize, Serialize, ToSchema, Clone)]
+pub struct CreateRouteRequest {
+    pub origin_iata_code: String,
+    pub destination_iata_code: String,
+    /// Typical gate-to-gate time in minutes.
+    pub block_time_minutes: i32,
+}
+
+#[derive(Debug, Deserialize, Serialize, ToSchema, Clone)]
+pub struct UpdateRouteRequest {
+    pub block_time_minutes: Option<i32>,
+    pub is_active: Option<bool>,
+}
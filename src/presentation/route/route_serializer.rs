@@ -0,0 +1,28 @@
+use crate::application::route::view::route_view::RouteView;
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+
+#[derive(Debug, Serialize, Deserialize, ToSchema, Clone)]
+pub struct RouteSerializer {
+    pub id: i64,
+    pub origin_airport_id: i64,
+    pub destination_airport_id: i64,
+    pub distance_km: i32,
+    pub block_time_minutes: i32,
+    pub is_domestic: bool,
+    pub is_active: bool,
+}
+
+impl From<RouteView> for RouteSerializer {
+    fn from(value: RouteView) -> Self {
+        Self {
+            id: value.id,
+            origin_airport_id: value.origin_airport_id,
+            destination_airport_id: value.destination_airport_id,
+            distance_km: value.distance_km,
+            block_time_minutes: value.block_time_minutes,
+            is_domestic: value.is_domestic,
+            is_active: value.is_active,
+        }
+    }
+}
@@ -81,6 +81,27 @@ impl RedisConnectionPool {
         Ok(deleted)
     }
 
+    /// Deletes several keys in one round trip; returns how many existed
+    pub async fn delete_keys(&self, keys: &[String]) -> RedisResult<u64> {
+        use redis::AsyncCommands;
+        if keys.is_empty() {
+            return Ok(0);
+        }
+        let mut conn = self.connection.clone();
+        let prefixed_keys: Vec<String> = keys.iter().map(|k| self.prefixed_key(k)).collect();
+        let deleted: u64 = conn.del(&prefixed_keys).await?;
+        Ok(deleted)
+    }
+
+    /// Publishes to a pub/sub channel; returns how many subscribers received it
+    pub async fn publish(&self, channel: &str, message: &str) -> RedisResult<u64> {
+        use redis::AsyncCommands;
+        let mut conn = self.connection.clone();
+        let prefixed_channel = self.prefixed_key(channel);
+        let receivers: u64 = conn.publish(&prefixed_channel, message).await?;
+        Ok(receivers)
+    }
+
     pub async fn get_and_deserialize_key<T>(&self, key: &str, _type_name: &str) -> RedisResult<T>
     where
         T: serde::de::DeserializeOwned,